@@ -0,0 +1,108 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Throughput of accepting and composing consignments by the bucket runtime.
+//!
+//! Consignments are synthetic contract histories of the testkit fungible
+//! schema. The runtime keeps the data in memory and resolves the witness
+//! transactions with the mock chain having them mined, so the benchmarks need
+//! neither a running node nor electrum servers:
+//!
+//! ```console
+//! cargo bench --features testkit,test-utils
+//! ```
+//!
+//! History shapes are configured with `RGB_BENCH_SHAPES` environment variable
+//! as a comma-separated list of shapes in form of `<depth>x<width>`.
+
+use std::env;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rgb::{Consignment, Validity};
+use rgb_node::bucketd::{HistoryShape, Runtime, SyntheticHistory};
+use rgb_node::testkit::{fungible_history, regtest_runtime, MemStore, MockChain};
+use rgb_rpc::OutpointFilter;
+
+const DEFAULT_SHAPES: &str = "1x1,10x1,100x1,10x10";
+
+fn shapes() -> Vec<HistoryShape> {
+    env::var("RGB_BENCH_SHAPES")
+        .unwrap_or_else(|_| DEFAULT_SHAPES.to_owned())
+        .split(',')
+        .map(|shape| {
+            let (depth, width) =
+                shape.trim().split_once('x').expect("shape must be <depth>x<width>");
+            HistoryShape {
+                depth: depth.parse().expect("invalid history depth"),
+                width: width.parse().expect("invalid history width"),
+            }
+        })
+        .collect()
+}
+
+fn histories() -> Vec<(HistoryShape, SyntheticHistory)> {
+    shapes().into_iter().map(|shape| (shape, fungible_history(shape))).collect()
+}
+
+/// Runtime with the synthetic contracts registered and their witness
+/// transactions mined by the mock chain
+fn runtime(histories: &[(HistoryShape, SyntheticHistory)]) -> Runtime {
+    let chain = Arc::new(MockChain::new());
+    for (_, history) in histories {
+        for proof in &history.proofs.txes {
+            chain.mine(vec![proof.tx.clone()]);
+        }
+    }
+    let mut runtime = regtest_runtime("bench", chain, MemStore::new());
+    for (_, history) in histories {
+        let status = runtime
+            .accept_consignment(history.contract.clone(), false, None)
+            .expect("unable to register synthetic contract");
+        assert!(matches!(status.validity(), Validity::Valid), "invalid synthetic contract");
+    }
+    runtime
+}
+
+fn consignments(c: &mut Criterion) {
+    let histories = histories();
+    let mut runtime = runtime(&histories);
+
+    let mut group = c.benchmark_group("accept");
+    for (shape, history) in &histories {
+        group.throughput(Throughput::Elements(shape.depth as u64 * shape.width as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(shape), history, |b, history| {
+            b.iter(|| {
+                let status = runtime
+                    .accept_consignment(history.transfer.clone(), false, None)
+                    .expect("unable to accept synthetic transfer");
+                assert!(matches!(status.validity(), Validity::Valid), "invalid synthetic transfer");
+            })
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("compose");
+    for (shape, history) in &histories {
+        let contract_id = history.contract.contract_id();
+        group.throughput(Throughput::Elements(shape.depth as u64 * shape.width as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(shape), &contract_id, |b, id| {
+            b.iter(|| {
+                runtime
+                    .consign_transfer(*id, OutpointFilter::All)
+                    .expect("unable to compose synthetic transfer")
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, consignments);
+criterion_main!(benches);
@@ -32,6 +32,7 @@ fn main() -> Result<(), configure_me_codegen::Error> {
         generate_to(Bash, &mut app, &name, outdir)?;
         generate_to(PowerShell, &mut app, &name, outdir)?;
         generate_to(Zsh, &mut app, &name, outdir)?;
+        generate_to(Fish, &mut app, &name, outdir)?;
     }
     // configure_me_codegen::build_script_auto()
     Ok(())
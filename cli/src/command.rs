@@ -9,23 +9,43 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 use std::collections::BTreeSet;
-use std::{fs, io};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io, iter};
 
 use amplify::IoError;
-use bitcoin::consensus;
+use bitcoin::hashes::{sha256, Hash};
 use bitcoin::psbt::serialize::{Deserialize, Serialize};
+use bitcoin::secp256k1::{self, KeyPair, Secp256k1, SecretKey};
+use bitcoin::{consensus, Address};
 use colored::Colorize;
 use microservices::cli::LogStyle;
 use microservices::shell::Exec;
 use psbt::Psbt;
 use rgb::blank::BlankBundle;
 use rgb::psbt::{RgbExt, RgbInExt};
-use rgb::{Node, StateTransfer, Transition, TransitionBundle};
-use rgb_rpc::{Client, ContractValidity};
+use rgb::{
+    seal, Consignment, ContractId, Node, Schema, SealEndpoint, StateTransfer, Transition,
+    TransitionBundle,
+};
+use rgb_rpc::{
+    contract_precision, now, open_payload, seal_payload, verify_consignment, Annotation,
+    AnnotationTarget, Armor, ArmorError, Capability, Client, ConsistencyError, ContractPolicy,
+    ContractValidity, DecimalFormat, Invoice, InvoiceTolerance, IssueSpec, OutpointFilter,
+    ParseDecimalError, PayjoinContribution, PayjoinProposal, ProofBundle, RelayAuth, RelayFetchReq,
+    RelayPickupReq, RevealData, SettingSource, SignedCheckpoint, SignetChallenge, SwapAcceptance,
+    SwapOffer, SwapTerms, TransferFilter, UploadKind, ValidationReport,
+};
 use strict_encoding::{StrictDecode, StrictEncode};
 
-use crate::opts::{ContractCommand, OutpointCommand, TransferCommand};
-use crate::{Command, Opts};
+use crate::inspect::Inspected;
+use crate::opts::{
+    AccessCommand, CacheCommand, ConfigCommand, ConsignmentCommand, ContractCommand, LabelCommand,
+    OutpointCommand, PayjoinCommand, QuarantineCommand, RelayCommand, SchemaCommand, SwapCommand,
+    TransferCommand, WalletCommand,
+};
+use crate::qr::{self, QrError};
+use crate::{Command, ConfigError, Opts, Output};
 
 #[derive(Debug, Display, Error, From)]
 #[display(inner)]
@@ -37,6 +57,12 @@ pub enum Error {
     #[from]
     Rpc(rgb_rpc::Error),
 
+    #[from]
+    Consistency(ConsistencyError),
+
+    #[from]
+    Armor(ArmorError),
+
     #[from]
     StrictEncoding(strict_encoding::Error),
 
@@ -48,14 +74,75 @@ pub enum Error {
 
     #[from]
     Reallocation(rgb::blank::Error),
+
+    #[from]
+    Secp(secp256k1::Error),
+
+    #[from]
+    Config(ConfigError),
+
+    #[from]
+    Qr(QrError),
+
+    #[from]
+    Amount(ParseDecimalError),
+
+    /// Command can't be completed for the reason given in the message
+    Failed(String),
 }
 
 impl Command {
     pub fn action_string(&self) -> String {
         match self {
             Command::Contract(subcommand) => subcommand.action_string(),
+            Command::Schema(subcommand) => subcommand.action_string(),
+            Command::Issue { .. } => s!("Issuing contract"),
             Command::Transfer(subcommand) => subcommand.action_string(),
             Command::Outpoint(subcommand) => subcommand.action_string(),
+            Command::Pay {
+                invoice,
+                invoices,
+                amount,
+                ..
+            } => {
+                let contract_id = invoice.contract_id;
+                let invoices = iter::once(invoice).chain(invoices);
+                let open = invoices.clone().any(Invoice::is_open);
+                let total = invoices
+                    .filter(|invoice| !invoice.is_open())
+                    .try_fold(0u64, |total, invoice| total.checked_add(invoice.amount));
+                match (total, amount) {
+                    (None, _) => format!(
+                        "Paying invoices of {} with total amount exceeding {}",
+                        contract_id,
+                        u64::MAX
+                    ),
+                    (Some(total), Some(amount)) if open => format!(
+                        "Paying {} of {} plus {} to each open invoice",
+                        total, contract_id, amount
+                    ),
+                    (Some(total), _) => format!("Paying {} of {}", total, contract_id),
+                }
+            }
+            Command::Swap(subcommand) => subcommand.action_string(),
+            Command::Payjoin(subcommand) => subcommand.action_string(),
+            Command::Wallet(subcommand) => subcommand.action_string(),
+            Command::Label(subcommand) => subcommand.action_string(),
+            Command::Relay(subcommand) => subcommand.action_string(),
+            Command::Consignment(subcommand) => subcommand.action_string(),
+            Command::Quarantine(subcommand) => subcommand.action_string(),
+            Command::Cache(subcommand) => subcommand.action_string(),
+            Command::Gc => s!("Collecting garbage"),
+            Command::Prune { .. } => s!("Pruning expired data"),
+            Command::Health => s!("Querying node health"),
+            Command::Ping => s!("Checking node identity"),
+            Command::Config(ConfigCommand::Show { .. }) => s!("Querying node configuration"),
+            Command::Timestamp { .. } => s!("Committing to the stash contents"),
+            Command::Cancel { client_id } => format!("Cancelling request of client {}", client_id),
+            Command::Promote => s!("Promoting read replica to primary"),
+            Command::Access(subcommand) => subcommand.action_string(),
+            Command::Order { target } => format!("Retrieving order for {}", target),
+            Command::Init { .. } => s!("Writing configuration file template"),
         }
     }
 }
@@ -64,6 +151,209 @@ impl OutpointCommand {
     pub fn action_string(&self) -> String {
         match self {
             Self::State { .. } => s!("Listing outpoints"),
+            Self::Forget { outpoints, .. } => {
+                format!("Forgetting state of {} spent outpoint(s)", outpoints.len())
+            }
+        }
+    }
+}
+
+impl LabelCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Set { target, .. } => format!("Annotating {}", target),
+            Self::List { .. } => s!("Listing annotations"),
+        }
+    }
+}
+
+impl AccessCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Grant { identity, target } => {
+                format!("Granting {} access to {}", identity, target)
+            }
+            Self::Revoke { identity, target } => {
+                format!("Revoking access of {} to {}", identity, target)
+            }
+            Self::List => s!("Listing access grants"),
+        }
+    }
+}
+
+impl QuarantineCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::List => s!("Listing quarantined consignments"),
+            Self::Approve { consignment_id } => {
+                format!("Approving quarantined consignment {}", consignment_id)
+            }
+            Self::Revalidate { consignment_id } => {
+                format!("Re-validating quarantined consignment {}", consignment_id)
+            }
+            Self::Purge { consignment_id } => {
+                format!("Purging consignment {} from the quarantine", consignment_id)
+            }
+        }
+    }
+}
+
+impl SchemaCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::List => s!("Listing schemata"),
+            Self::Show { schema_id } => format!("Querying schema {}", schema_id),
+        }
+    }
+}
+
+impl CacheCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Rebuild { contract_id: None } => s!("Rebuilding state of all contracts"),
+            Self::Rebuild {
+                contract_id: Some(contract_id),
+            } => format!("Rebuilding state of {}", contract_id),
+        }
+    }
+}
+
+impl RelayCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Id { .. } => s!("Deriving relay identifier"),
+            Self::Deposit {
+                recipient,
+                consignment,
+                ..
+            } => format!("Depositing {} for {}", consignment.display(), recipient),
+            Self::Pickup { .. } => s!("Picking up consignments"),
+        }
+    }
+}
+
+impl ConsignmentCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Inspect { consignment, .. } => {
+                format!("Inspecting consignment {}", consignment.display())
+            }
+            Self::Qr { consignment, .. } => {
+                format!("Exporting consignment {} as QR codes", consignment.display())
+            }
+            Self::Assemble { parts, .. } => {
+                format!("Assembling consignment from QR codes in {}", parts.display())
+            }
+        }
+    }
+
+    /// Commands working with local files only and not requiring connection to
+    /// the node
+    fn exec(self, output: &Output) -> Result<(), Error> {
+        match self {
+            ConsignmentCommand::Inspect {
+                dot,
+                data_type,
+                consignment,
+            } => {
+                let inspected = Inspected::load(&consignment, data_type)?;
+                let report = inspected.report().expect("in-memory formatting");
+                output.text("report", report.trim_end());
+                if let Some(path) = dot {
+                    output.message(format!("Saving consignment graph to {}", path.display()));
+                    fs::write(path, inspected.dot().expect("in-memory formatting"))?;
+                }
+            }
+            ConsignmentCommand::Qr {
+                fragment_len,
+                parts,
+                output_dir,
+                consignment,
+            } => {
+                let encoder = qr::FountainEncoder::with(&fs::read(consignment)?, fragment_len);
+                let fragments = encoder.fragment_count() as u32;
+                let count = parts.unwrap_or(fragments);
+                if count < fragments {
+                    return Err(Error::Failed(format!(
+                        "consignment is split into {} fragments and can't be assembled from {} QR \
+                         codes",
+                        fragments, count
+                    )));
+                }
+                let parts = (1..=count).map(|seq| encoder.part(seq)).collect::<Vec<_>>();
+                match output_dir {
+                    Some(dir) => {
+                        fs::create_dir_all(&dir)?;
+                        for (no, part) in parts.iter().enumerate() {
+                            let path = dir.join(format!("part-{:04}.svg", no + 1));
+                            fs::write(path, qr::render_svg(part)?)?;
+                        }
+                        output.message(format!("Saved {} QR code(s) to {}", count, dir.display()));
+                    }
+                    None => {
+                        for (no, part) in parts.iter().enumerate() {
+                            output.message(format!("Part {} of {}", no + 1, count));
+                            output.message(qr::render_text(part)?);
+                        }
+                    }
+                }
+                output.record("parts", &parts);
+            }
+            ConsignmentCommand::Assemble { parts, consignment } => {
+                let mut decoder = qr::FountainDecoder::new();
+                for part in
+                    fs::read_to_string(parts)?.lines().filter(|line| !line.trim().is_empty())
+                {
+                    decoder.receive(part)?;
+                }
+                fs::write(&consignment, decoder.message()?)?;
+                let consignment_id = StateTransfer::armored_file_load(&consignment)?.id();
+                output.record("consignmentId", &consignment_id);
+                output.message(format!(
+                    "{}: consignment {} is saved to {}",
+                    "Success".ended(),
+                    consignment_id,
+                    consignment.display()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SwapCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Propose { give, take, .. } => format!("Proposing swap of {} for {}", give, take),
+            Self::Accept { offer, .. } => format!("Accepting swap offer {}", offer.display()),
+            Self::Complete { swap_id, .. } => format!("Completing swap {}", swap_id),
+            Self::Abort { swap_id } => format!("Aborting swap {}", swap_id),
+        }
+    }
+}
+
+impl PayjoinCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Propose { psbt, .. } => format!("Proposing payjoin for {}", psbt.display()),
+            Self::Contribute { proposal, .. } => {
+                format!("Contributing to payjoin proposal {}", proposal.display())
+            }
+            Self::Complete { contribution, .. } => {
+                format!("Completing payjoin with {}", contribution.display())
+            }
+        }
+    }
+}
+
+impl WalletCommand {
+    pub fn action_string(&self) -> String {
+        match self {
+            Self::Sync { .. } => s!("Synchronizing wallet"),
+            Self::Balance { .. } => s!("Querying wallet balance"),
+            Self::Create { .. } => s!("Creating wallet"),
+            Self::List => s!("Listing wallets"),
+            Self::Addresses { .. } => s!("Listing wallet addresses"),
         }
     }
 }
@@ -74,14 +364,63 @@ impl ContractCommand {
             Self::Register { contract, .. } => {
                 format!("Registering contract {}", contract.contract_id())
             }
+            Self::Import { .. } => s!("Importing contract genesis"),
             Self::List => s!("Listing contracts"),
             Self::State { contract_id } => format!("Querying state of {}", contract_id),
+            Self::Balance {
+                contract_id,
+                height,
+                ..
+            } => format!("Computing balance of {} at height {}", contract_id, height),
+            Self::Stats { contract_id } => format!("Collecting statistics of {}", contract_id),
+            Self::Allocations { contract_id } => format!("Listing allocations of {}", contract_id),
+            Self::Usage { .. } => s!("Collecting storage usage of contracts"),
+            Self::Digest { contract_id } => format!("Computing state digest of {}", contract_id),
+            Self::Compare {
+                contract_id,
+                remote,
+            } => format!("Comparing {} with the node at {}", contract_id, remote),
             Self::Consignment { contract_id, .. } => {
                 format!("Retrieving contract source for {}", contract_id)
             }
             Self::Embed { contract_id, .. } => {
                 format!("Embedding {} into PSBT", contract_id)
             }
+            Self::Checkpoint {
+                contract_id,
+                height,
+                ..
+            } => format!("Creating checkpoint of {} at height {}", contract_id, height),
+            Self::Policy {
+                contract_id,
+                min_allocation: None,
+            } => format!("Querying policy for {}", contract_id),
+            Self::Policy { contract_id, .. } => format!("Setting policy for {}", contract_id),
+            Self::ImportCheckpoint { checkpoint } => {
+                format!("Importing checkpoint from {}", checkpoint.display())
+            }
+            Self::Prove { contract, .. } => {
+                format!("Preparing transaction proofs for {}", contract.contract_id())
+            }
+            Self::Reveal { contract_id, .. } => format!("Revealing seal for {}", contract_id),
+            Self::Invoice { contract_id, .. } => format!("Creating invoice for {}", contract_id),
+            Self::Invoices { contract_id } => format!("Listing invoices of {}", contract_id),
+            Self::ExportReveals { contract_id, .. } => {
+                format!("Exporting reveal data for {}", contract_id)
+            }
+            Self::ImportReveals { file } => {
+                format!("Importing reveal data from {}", file.display())
+            }
+            Self::Journal { contract_id } => format!("Listing journal of {}", contract_id),
+            Self::Rewind {
+                contract_id,
+                consignment_id,
+            } => format!("Rewinding {} to the state before {}", contract_id, consignment_id),
+            Self::Replay {
+                contract_id,
+                consignments,
+            } => format!("Replaying {} consignment(s) of {}", consignments.len(), contract_id),
+            Self::Forget { contract_id, .. } => format!("Forgetting contract {}", contract_id),
         }
     }
 }
@@ -89,15 +428,26 @@ impl ContractCommand {
 impl TransferCommand {
     pub fn action_string(&self) -> String {
         match self {
+            Self::List { .. } => s!("Listing transfers"),
+            Self::History { contract_id } => {
+                format!("Retrieving known history of contract {}", contract_id)
+            }
             Self::Compose { contract_id, .. } => {
                 format!("Composing consignment for state transfer for contract {}", contract_id)
             }
             Self::Combine { .. } => s!("Preparing PSBT for the state transfer"),
+            Self::Consolidate { contract_id, .. } => {
+                format!("Consolidating allocations of {}", contract_id)
+            }
             Self::Finalize {
                 send: Some(addr), ..
             } => format!("Finalizing state transfer and sending it to {}", addr),
             Self::Finalize { send: None, .. } => s!("Finalizing state transfer"),
             Self::Consume { .. } => s!("Verifying and consuming state transfer"),
+            Self::Prove { .. } => s!("Preparing transaction proofs for state transfer"),
+            Self::Accelerate { consignment_id, .. } => {
+                format!("Accelerating witness transaction of transfer {}", consignment_id)
+            }
         }
     }
 }
@@ -107,66 +457,287 @@ impl Exec for Opts {
     type Error = Error;
 
     fn exec(self, client: &mut Self::Client) -> Result<(), Self::Error> {
-        if !client.hello()? {
-            eprintln!("Network mismatch");
+        let output = Output::new(
+            self.format.unwrap_or_default(),
+            self.id_format.unwrap_or_default(),
+            self.json,
+        );
+        let res = self.run(client, &output);
+        output.finish(res)
+    }
+}
+
+impl Opts {
+    fn run(self, client: &mut Client, output: &Output) -> Result<(), Error> {
+        if let Command::Consignment(subcommand) = self.command {
+            output.message(format!("{}...", subcommand.action_string()));
+            return subcommand.exec(output);
+        }
+        if let Command::Relay(RelayCommand::Id { key }) = &self.command {
+            output.message(format!("{}...", self.command.action_string()));
+            let keypair = load_keypair(&Secp256k1::new(), key)?;
+            output.text("recipient", keypair.x_only_public_key().0);
             return Ok(());
         }
 
-        println!("{}...", self.command.action_string());
+        if !client.hello()? {
+            return Err(Error::Failed(s!("network mismatch")));
+        }
+        if let Some(path) = &self.identity_key {
+            let secp = Secp256k1::new();
+            let keypair = load_keypair(&secp, path)?;
+            client.authenticate(&keypair)?;
+            client.set_order_key(Some(keypair));
+        }
+
+        output.message(format!("{}...", self.command.action_string()));
 
         let progress = |info| {
-            println!("{}", info);
+            output.message(info);
+        };
+
+        let decimals = DecimalFormat {
+            group_separator: self.group_separator,
+            ..default!()
         };
 
-        let report_validation = |status| match status {
-            ContractValidity::Valid => {
-                println!("{}: contract is valid and imported", "Success".ended())
-            }
-            ContractValidity::Invalid(status) => {
-                eprintln!("{}: contract is invalid. Detailed report:", "Error".err());
-                eprintln!("{}", serde_yaml::to_string(&status).unwrap());
-            }
-            ContractValidity::UnknownTxids(txids) => {
-                eprintln!(
-                    "{}: contract is valid, but some of underlying transactions are still not \
-                     mined",
-                    "Warning".bold().bright_yellow()
-                );
-                eprintln!("The list of non-mined transaction ids:");
-                for txid in txids {
-                    println!("- {}", txid);
-                }
-                eprintln!(
-                    "{}: contract was not imported. To import the contract, re-run the command \
-                     with {} argument",
-                    "Warning".bold().bright_yellow(),
-                    "--force".bold().bright_white(),
-                );
+        let report_issues = |report: &ValidationReport| {
+            for failure in &report.failures {
+                output.message(format!("- {}: {}", "failure".err(), failure));
+            }
+            for warning in &report.warnings {
+                output.message(format!("- {}: {}", "warning".bold().bright_yellow(), warning));
+            }
+            for info in &report.info {
+                output.message(format!("- info: {}", info));
+            }
+            if let Some(cost) = report.cost {
+                output.message(format!("- cost: {}", cost));
             }
         };
 
+        // Details of the validation are reported as messages, while the
+        // validity is a part of the command result
+        let report_validation = |status: ContractValidity| -> Result<(), Error> {
+            output.record("validity", &status);
+            match status {
+                ContractValidity::Valid(report) => {
+                    output
+                        .message(format!("{}: contract is valid and imported", "Success".ended()));
+                    if !report.warnings.is_empty() || !report.unmined_endpoint_txids.is_empty() {
+                        output.warning("validation has found non-fatal issues:");
+                        report_issues(&report);
+                        for txid in &report.unmined_endpoint_txids {
+                            output.message(format!(
+                                "- endpoint transaction {} is not mined yet",
+                                txid
+                            ));
+                        }
+                    }
+                    Ok(())
+                }
+                ContractValidity::Invalid(report) => {
+                    output.message("Detailed report:");
+                    report_issues(&report);
+                    for txid in &report.unresolved_txids {
+                        output.message(format!("- transaction {} can't be resolved", txid));
+                    }
+                    Err(Error::Failed(s!("contract is invalid")))
+                }
+                ContractValidity::UnknownTxids(report) => {
+                    report_issues(&report);
+                    output.message("The list of non-mined transaction ids:");
+                    for txid in report.unknown_txids() {
+                        output.message(format!("- {}", txid));
+                    }
+                    Err(Error::Failed(s!("contract is valid, but some of underlying \
+                                          transactions are still not mined, so it was not \
+                                          imported. To import the contract, re-run the command \
+                                          with `--force` argument")))
+                }
+            }
+        };
+
+        let report_annotations = |annotations: Vec<Annotation>| {
+            if annotations.is_empty() {
+                return;
+            }
+            output.data("annotations", &annotations);
+        };
+
         match self.command {
             Command::Contract(subcommand) => match subcommand {
-                ContractCommand::Register { contract, force } => {
-                    let status = client.register_contract(contract, force, progress)?;
-                    report_validation(status);
+                ContractCommand::Register {
+                    contract,
+                    force,
+                    timeout,
+                    proofs,
+                } => {
+                    verify_consignment(&contract)?;
+                    client.set_timeout(timeout);
+                    let proofs = proofs.map(ProofBundle::strict_file_load).transpose()?;
+                    let status = client.register_contract(contract, force, proofs, progress)?;
+                    report_validation(status)?;
+                }
+                ContractCommand::Import { genesis } => {
+                    let state = client.import_contract(genesis)?;
+                    output.data("state", &state);
+                    output.message(format!(
+                        "{}: imported contract {}",
+                        "Success".ended(),
+                        state.contract_id
+                    ));
+                }
+                ContractCommand::Prove {
+                    from_height,
+                    contract,
+                    output: path,
+                } => {
+                    let txids =
+                        contract.anchored_bundles().map(|(anchor, _)| anchor.txid).collect();
+                    let proofs = client.prove_transactions(txids, from_height, progress)?;
+                    output.message(format!("Proofs contain {}", proofs));
+                    proofs.strict_file_save(path)?;
                 }
                 ContractCommand::List => {
-                    client.list_contracts()?.iter().for_each(|id| println!("{}", id));
+                    output.list("contracts", client.list_contracts()?);
                 }
                 ContractCommand::State { contract_id } => {
                     let state = client.contract_state(contract_id)?;
-                    println!(
-                        "{}",
-                        serde_yaml::to_string(&state).expect("broken contract state serde")
-                    );
+                    output.data("state", &state.state);
+                    if !state.amounts.is_empty() {
+                        output.record("amounts", &state.amounts);
+                        for (outpoint, amount) in &state.amounts {
+                            output.message(format!("{}: {}", outpoint, amount.format(&decimals)));
+                        }
+                    }
+                    if let Some(total) = state.total {
+                        output.record("total", &total);
+                        output.message(format!(
+                            "total: {} ({} atomic)",
+                            total.format(&decimals),
+                            total.atomic
+                        ));
+                    }
+                    if client.supports(Capability::Annotations) {
+                        report_annotations(
+                            client
+                                .annotations(bset! { AnnotationTarget::Contract(contract_id) })?,
+                        );
+                    }
+                }
+                ContractCommand::Balance {
+                    descriptor,
+                    outpoints,
+                    contract_id,
+                    height,
+                } => {
+                    let balance = client.historical_balance(
+                        contract_id,
+                        height,
+                        descriptor,
+                        outpoints.into_iter().collect(),
+                        progress,
+                    )?;
+                    output.data("balance", &balance);
+                    let total = balance.decimal_amount();
+                    output.record("total", &total);
+                    output.message(format!(
+                        "total: {} ({} atomic)",
+                        total.format(&decimals),
+                        total.atomic
+                    ));
+                }
+                ContractCommand::Stats { contract_id } => {
+                    let stats = client.contract_stats(contract_id)?;
+                    output.data("stats", &stats);
+                }
+                ContractCommand::Deps { contract_id } => {
+                    let deps = client.contract_deps(contract_id)?;
+                    output.record("deps", &deps);
+                    for contract in deps {
+                        match contract.root_schema_id {
+                            Some(root_schema_id) => output.message(format!(
+                                "{} (schema {}, root schema {})",
+                                contract.contract_id, contract.schema_id, root_schema_id
+                            )),
+                            None => output.message(format!(
+                                "{} (schema {})",
+                                contract.contract_id, contract.schema_id
+                            )),
+                        }
+                        for id in contract.siblings {
+                            output.message(format!("  shares root schema with {}", id));
+                        }
+                        for id in contract.references {
+                            output.message(format!("  references {}", id));
+                        }
+                        for id in contract.referenced_by {
+                            output.message(format!("  referenced by {}", id));
+                        }
+                    }
+                }
+                ContractCommand::Allocations { contract_id } => {
+                    let status = client.allocation_status(contract_id)?;
+                    output.data("allocations", &status.allocations);
+                    if !status.spent.is_empty() {
+                        output.data("spent", &status.spent);
+                    }
+                }
+                ContractCommand::Usage { limit } => {
+                    let usage = client.storage_usage(limit)?;
+                    output.data("usage", &usage);
+                }
+                ContractCommand::Digest { contract_id } => {
+                    let digest = client.state_digest(contract_id)?;
+                    output.data("digest", &digest);
+                }
+                ContractCommand::Compare {
+                    contract_id,
+                    remote,
+                } => {
+                    let chain = self.chain.expect("chain is set by the configuration");
+                    let mut remote = Client::with(remote, s!("rgb-cli"), chain)?;
+                    remote.set_signet(self.signet_challenge.as_ref().map(SignetChallenge::magic));
+                    if !remote.hello()? {
+                        return Err(Error::Failed(s!("network mismatch of the remote node")));
+                    }
+                    let local_digest = client.state_digest(contract_id)?;
+                    let remote_digest = remote.state_digest(contract_id)?;
+                    if local_digest == remote_digest {
+                        output.record("digest", &local_digest);
+                        output.message(format!(
+                            "{}: both nodes have the same view of the contract",
+                            "Success".ended()
+                        ));
+                        return Ok(());
+                    }
+                    let remote_bundles =
+                        remote.diff_history(contract_id, empty!(), progress)?.local_only;
+                    let diff = client.diff_history(contract_id, remote_bundles, progress)?;
+                    if diff.is_empty() {
+                        output.message(
+                            "Nodes know the same contract history, but have different revealed \
+                             state data",
+                        );
+                    }
+                    output.data("diff", &diff);
                 }
                 ContractCommand::Consignment {
                     node_types,
+                    timeout,
+                    armor,
                     contract_id,
                 } => {
-                    let contract = client.contract(contract_id, node_types, progress)?;
-                    println!("{}", contract);
+                    client.set_timeout(timeout);
+                    if armor {
+                        let contract =
+                            client.contract_armored(contract_id, node_types, progress)?;
+                        output.text("contract", contract.trim_end());
+                    } else {
+                        let contract = client.contract(contract_id, node_types, progress)?;
+                        output.text("contract", contract);
+                    }
                 }
                 ContractCommand::Embed {
                     node_types,
@@ -178,43 +749,849 @@ impl Exec for Opts {
                     let psbt_bytes = fs::read(&psbt_in)?;
                     let mut psbt = Psbt::deserialize(&psbt_bytes)?;
                     if psbt.has_rgb_contract(contract_id) {
-                        eprintln!("Contract {} is already present in the PSBT", contract_id);
-                        return Ok(());
+                        return Err(Error::Failed(format!(
+                            "contract {} is already present in the PSBT",
+                            contract_id
+                        )));
                     }
                     psbt.set_rgb_contract(contract)?;
                     let psbt_bytes = psbt.serialize();
                     fs::write(psbt_out.unwrap_or(psbt_in), psbt_bytes)?;
                 }
+                ContractCommand::Checkpoint {
+                    contract_id,
+                    height,
+                    issuer_key,
+                    output: path,
+                } => {
+                    if issuer_key.is_none() && !client.supports(Capability::IssuerSigner) {
+                        return Err(Error::Failed(s!(
+                            "node has no issuer signer configured, so the issuer key must be given"
+                        )));
+                    }
+                    let checkpoint = client.create_checkpoint(contract_id, height)?;
+                    output.message(format!(
+                        "Checkpoint contains {} state transitions",
+                        checkpoint.frontier.len()
+                    ));
+                    let signed = match issuer_key {
+                        Some(issuer_key) => {
+                            let secp = Secp256k1::new();
+                            let keypair = load_keypair(&secp, &issuer_key)?;
+                            checkpoint.sign(&secp, &keypair)
+                        }
+                        None => client.sign_checkpoint(checkpoint)?,
+                    };
+                    signed.strict_file_save(path)?;
+                }
+                ContractCommand::Policy {
+                    contract_id,
+                    min_allocation: None,
+                } => {
+                    let policy = client.policy(contract_id)?;
+                    output.data("policy", &policy);
+                }
+                ContractCommand::Policy {
+                    contract_id,
+                    min_allocation: Some(min_allocation),
+                } => {
+                    client.set_policy(contract_id, ContractPolicy { min_allocation })?;
+                }
+                ContractCommand::ImportCheckpoint { checkpoint } => {
+                    let checkpoint = SignedCheckpoint::strict_file_load(checkpoint)?;
+                    client.import_checkpoint(checkpoint)?;
+                }
+                ContractCommand::Reveal {
+                    contract_id,
+                    reveal,
+                } => {
+                    let node_ids = client.reveal_seal(contract_id, reveal, progress)?;
+                    output.record("transitions", &node_ids);
+                    match node_ids.len() {
+                        0 => output.message(format!(
+                            "{}: no accepted state transition has the seal; the reveal is kept \
+                             until a consignment with the seal is accepted",
+                            "Pending".ended()
+                        )),
+                        count => output.message(format!(
+                            "{}: seal is revealed in {} state transition(s)",
+                            "Success".ended(),
+                            count
+                        )),
+                    }
+                }
+                ContractCommand::Invoice {
+                    method,
+                    transport,
+                    underpayment,
+                    overpayment,
+                    contract_id,
+                    amount,
+                    outpoint,
+                } => {
+                    let precision = precision(client, contract_id)?;
+                    let tolerance = InvoiceTolerance {
+                        underpayment: decimals.parse(&underpayment, precision)?,
+                        overpayment: decimals.parse(&overpayment, precision)?,
+                    };
+                    let invoice = client.create_invoice(
+                        contract_id,
+                        decimals.parse(&amount, precision)?,
+                        outpoint,
+                        method,
+                        transport,
+                        tolerance,
+                        progress,
+                    )?;
+                    output.text("invoice", invoice);
+                }
+                ContractCommand::Invoices { contract_id } => {
+                    output.data("invoices", &client.invoices(contract_id)?);
+                }
+                ContractCommand::ExportReveals {
+                    outpoints,
+                    contract_id,
+                    output: path,
+                } => {
+                    let data = client.export_reveals(
+                        contract_id,
+                        outpoints.into_iter().collect(),
+                        progress,
+                    )?;
+                    if data.reveals.is_empty() {
+                        return Err(Error::Failed(format!(
+                            "no revealed seals of {} are known on the given outpoints",
+                            contract_id
+                        )));
+                    }
+                    let mut options = fs::OpenOptions::new();
+                    options.write(true).create_new(true);
+                    #[cfg(unix)]
+                    {
+                        use std::os::unix::fs::OpenOptionsExt;
+                        options.mode(0o600);
+                    }
+                    data.strict_encode(options.open(&path)?)?;
+                    output.message(format!(
+                        "{}: reveal data for {} saved to {}",
+                        "Success".ended(),
+                        data,
+                        path.display()
+                    ));
+                }
+                ContractCommand::ImportReveals { file } => {
+                    let data = RevealData::strict_file_load(&file)?;
+                    let mut revealed = bset! {};
+                    let mut pending = 0usize;
+                    for reveal in data.reveals {
+                        let node_ids = client.reveal_seal(data.contract_id, reveal, progress)?;
+                        if node_ids.is_empty() {
+                            pending += 1;
+                        }
+                        revealed.extend(node_ids);
+                    }
+                    output.record("transitions", &revealed);
+                    output.message(format!(
+                        "{}: {} state transition(s) updated, {} reveal(s) kept pending",
+                        "Success".ended(),
+                        revealed.len(),
+                        pending
+                    ));
+                }
+                ContractCommand::Journal { contract_id } => {
+                    let journal = client.journal(contract_id)?;
+                    output.data("journal", &journal);
+                }
+                ContractCommand::Rewind {
+                    contract_id,
+                    consignment_id,
+                } => {
+                    let change = client.rewind_contract(contract_id, consignment_id, progress)?;
+                    output.data("change", &change);
+                }
+                ContractCommand::Replay {
+                    contract_id,
+                    consignments,
+                } => {
+                    let change = client.replay_consignments(contract_id, consignments, progress)?;
+                    output.data("change", &change);
+                }
+                ContractCommand::Forget {
+                    tombstone,
+                    yes,
+                    contract_id,
+                } => {
+                    if !yes {
+                        output.message(format!(
+                            "Contract {} with all its history and transfers will be removed from \
+                             the node.\nType the contract id to confirm:",
+                            contract_id
+                        ));
+                        let mut answer = String::new();
+                        io::stdin().read_line(&mut answer)?;
+                        if answer.trim() != contract_id.to_string() {
+                            return Err(Error::Failed(s!("removal is not confirmed")));
+                        }
+                    }
+                    let report = client.forget_contract(contract_id, tombstone, progress)?;
+                    output.record("removed", &report);
+                    output.message(format!("{}: removed {}", "Success".ended(), report));
+                }
             },
 
             Command::Outpoint(subcommand) => match subcommand {
-                OutpointCommand::State { outpoints } => {
-                    let state_map =
-                        client.outpoint_state(outpoints.into_iter().collect(), progress)?;
-                    println!(
-                        "{}",
-                        serde_yaml::to_string(&state_map).expect("broken outpoint state serde")
-                    );
+                OutpointCommand::State {
+                    outpoints,
+                    addresses,
+                    scripts,
+                } => {
+                    let targets = outpoints
+                        .iter()
+                        .copied()
+                        .map(AnnotationTarget::Outpoint)
+                        .collect::<BTreeSet<_>>();
+                    let outpoints = outpoints.into_iter().collect();
+                    let scripts = addresses
+                        .iter()
+                        .map(Address::script_pubkey)
+                        .chain(scripts)
+                        .collect::<BTreeSet<_>>();
+                    let status = match scripts.is_empty() {
+                        true => client.outpoint_status(outpoints, progress)?,
+                        false => client.query_outpoint_status(outpoints, scripts, progress)?,
+                    };
+                    output.data("outpoints", &status.state);
+                    if !status.spent.is_empty() {
+                        output.data("spent", &status.spent);
+                    }
+                    if !status.amounts.is_empty() {
+                        output.record("amounts", &status.amounts);
+                        for (contract_id, amount) in &status.amounts {
+                            output.message(format!(
+                                "{}: {}",
+                                contract_id,
+                                amount.format(&decimals)
+                            ));
+                        }
+                    }
+                    if !targets.is_empty() && client.supports(Capability::Annotations) {
+                        report_annotations(client.annotations(targets)?);
+                    }
+                }
+                OutpointCommand::Forget {
+                    descriptor,
+                    outpoints,
+                } => {
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    let outpoints = outpoints.into_iter().collect();
+                    let removed = client.forget_spent(descriptor, outpoints, progress)?;
+                    output.data("removed", &removed);
+                }
+            },
+
+            Command::Quarantine(subcommand) => match subcommand {
+                QuarantineCommand::List => {
+                    let list = client.quarantine()?;
+                    output.data("quarantine", &list);
+                }
+                QuarantineCommand::Approve { consignment_id } => {
+                    let status = client.approve_quarantined(consignment_id, progress)?;
+                    report_validation(status)?;
+                }
+                QuarantineCommand::Revalidate { consignment_id } => {
+                    let status = client.revalidate_quarantined(consignment_id, progress)?;
+                    report_validation(status)?;
+                }
+                QuarantineCommand::Purge { consignment_id } => {
+                    client.purge_quarantined(consignment_id)?;
+                    output.message("Success".ended());
+                }
+            },
+
+            Command::Schema(subcommand) => {
+                let schemata = client.list_schemata()?;
+                match subcommand {
+                    SchemaCommand::List => {
+                        output.list("schemata", schemata.iter().map(|info| info.schema_id));
+                    }
+                    SchemaCommand::Show { schema_id } => {
+                        let info = schemata.into_iter().find(|info| info.schema_id == schema_id);
+                        match info {
+                            Some(info) => output.data("schema", &info),
+                            None => {
+                                let msg = format!("schema {} is not known to the node", schema_id);
+                                return Err(Error::Failed(msg));
+                            }
+                        }
+                    }
+                }
+            }
+
+            Command::Cache(CacheCommand::Rebuild { contract_id }) => {
+                let report = client.rebuild_state(contract_id, progress)?;
+                output.record("rebuilt", &report);
+                output.message(format!("{}: rebuilt {}", "Success".ended(), report));
+            }
+
+            Command::Cache(CacheCommand::Revalidate { contract_id }) => {
+                let report = client.revalidate_contracts(contract_id, progress)?;
+                output.record("revalidated", &report);
+                for (contract_id, failures) in &report.invalid {
+                    output.message(format!("contract {} is invalid:", contract_id));
+                    for failure in failures {
+                        output.message(format!("  {}", failure));
+                    }
+                }
+                output.message(format!("{}: re-validated {}", "Success".ended(), report));
+            }
+
+            Command::Gc => {
+                let report = client.collect_garbage(progress)?;
+                output.record("removed", &report);
+                output.message(format!("{}: removed {}", "Success".ended(), report));
+            }
+
+            Command::Prune { dry_run } => {
+                let report = client.prune(dry_run, progress)?;
+                output.list("pruned", &report.items);
+                let action = if dry_run { "would remove" } else { "removed" };
+                output.message(format!("{}: {} {}", "Success".ended(), action, report));
+            }
+
+            Command::Issue {
+                spec,
+                schema,
+                dry_run,
+                contract: path,
+            } => {
+                let spec: IssueSpec =
+                    serde_yaml::from_str(&fs::read_to_string(spec)?).map_err(|err| {
+                        Error::Failed(format!("invalid issuance specification: {}", err))
+                    })?;
+                let schema = schema.map(Schema::strict_file_load).transpose()?;
+                if dry_run {
+                    let preview = client.preview_issue(spec, schema, progress)?;
+                    output.data("preview", &preview);
+                    return Ok(());
+                }
+                let contract = client.issue(spec, schema, progress)?;
+                let contract_id = contract.contract_id();
+                if let Some(path) = path {
+                    contract.strict_file_save(path)?;
+                }
+                output.record("contractId", &contract_id);
+                output.message(format!("{}: issued contract {}", "Success".ended(), contract_id));
+            }
+
+            Command::Health => {
+                let health = client.health()?;
+                output.data("health", &health);
+            }
+
+            Command::Ping => {
+                let pong = client.ping()?;
+                output.data("pong", &pong);
+            }
+
+            Command::Config(ConfigCommand::Show { effective }) => {
+                let settings = client
+                    .config()?
+                    .into_iter()
+                    .filter(|setting| effective || setting.source != SettingSource::Default)
+                    .collect::<Vec<_>>();
+                output.data("settings", &settings);
+            }
+
+            Command::Timestamp {
+                output: path,
+                anchor,
+            } => {
+                if anchor && !client.supports(Capability::Timestamping) {
+                    return Err(Error::Failed(s!("node has no timestamping calendar configured")));
+                }
+                let commitment = client.commit_stash(progress)?;
+                commitment.strict_file_save(&path)?;
+                output.data("commitment", &commitment);
+                if anchor {
+                    let digest = sha256::Hash::hash(&fs::read(&path)?);
+                    let proof = client.timestamp(digest)?;
+                    let mut ots = path.into_os_string();
+                    ots.push(".ots");
+                    fs::write(&ots, proof.ots_serialize())?;
+                    output.message(format!(
+                        "Pending timestamp from {} is saved to {}",
+                        proof.calendar,
+                        PathBuf::from(ots).display()
+                    ));
+                }
+            }
+
+            Command::Cancel { client_id } => {
+                client.cancel(client_id)?;
+                output.message("Success".ended());
+            }
+
+            Command::Promote => {
+                client.promote()?;
+                output.message("Success".ended());
+            }
+
+            Command::Access(subcommand) => match subcommand {
+                AccessCommand::Grant { identity, target } => {
+                    client.grant_access(identity, target)?;
+                    output.message("Success".ended());
+                }
+                AccessCommand::Revoke { identity, target } => {
+                    client.revoke_access(identity, target)?;
+                    output.message("Success".ended());
+                }
+                AccessCommand::List => {
+                    let grants = client.list_access()?;
+                    output.data("access", &grants);
+                }
+            },
+
+            Command::Order { target } => {
+                output.data("order", &client.order(target)?);
+            }
+
+            Command::Label(subcommand) => match subcommand {
+                LabelCommand::Set {
+                    target,
+                    label,
+                    tags,
+                } => {
+                    client.annotate(Annotation {
+                        target,
+                        label,
+                        tags: tags.into_iter().collect(),
+                    })?;
+                    output.message("Success".ended());
+                }
+                LabelCommand::List { targets } => {
+                    let annotations = client.annotations(targets.into_iter().collect())?;
+                    output.data("annotations", &annotations);
                 }
             },
 
+            Command::Wallet(subcommand) => match subcommand {
+                WalletCommand::Create { template, key } => {
+                    let descriptor = match key.contains('(') {
+                        true => key,
+                        false => template.descriptor(&key),
+                    };
+                    client.create_wallet(descriptor.clone())?;
+                    output.text("descriptor", descriptor);
+                }
+                WalletCommand::List => {
+                    output.data("wallets", &client.list_wallets()?);
+                }
+                WalletCommand::Addresses { unused, descriptor } => {
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    output.data("addresses", &client.wallet_addresses(descriptor, unused)?);
+                }
+                WalletCommand::Sync {
+                    gap_limit,
+                    rescan,
+                    descriptor,
+                } => {
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    let balance = client.sync_wallet(descriptor, gap_limit, rescan, progress)?;
+                    output.data("balance", &balance);
+                }
+                WalletCommand::Balance { descriptor } => {
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    let balance = client.wallet_balance(descriptor, progress)?;
+                    output.data("balance", &balance);
+                    for (contract_id, amount) in &balance.amounts {
+                        output.message(format!("{}: {}", contract_id, amount.format(&decimals)));
+                    }
+                }
+            },
+
+            Command::Pay {
+                descriptor,
+                fee,
+                dry_run,
+                amount,
+                invoices,
+                invoice,
+                consignment,
+            } => {
+                if !dry_run && !client.supports(Capability::Signer) {
+                    return Err(Error::Failed(s!(
+                        "node has no external signer configured, so the payment can't be signed"
+                    )));
+                }
+                let contract_id = invoice.contract_id;
+                let invoices = iter::once(invoice).chain(invoices).collect::<Vec<_>>();
+                let amount = match amount {
+                    Some(amount) => Some(decimals.parse(&amount, precision(client, contract_id)?)?),
+                    None => None,
+                };
+                let signet = self.signet_challenge.as_ref().map(SignetChallenge::magic);
+                let mut beneficiaries = bmap! {};
+                let mut total = 0u64;
+                for invoice in &invoices {
+                    if invoice.contract_id != contract_id {
+                        return Err(Error::Failed(s!(
+                            "invoices paid together must be issued for the same contract"
+                        )));
+                    }
+                    if !invoice.matches_signet(signet) {
+                        return Err(Error::Failed(s!(
+                            "invoice is issued for a different network than the node works with"
+                        )));
+                    }
+                    let value = match (invoice.is_open(), amount) {
+                        (false, _) => invoice.amount,
+                        (true, Some(amount)) => amount,
+                        (true, None) => {
+                            return Err(Error::Failed(format!(
+                                "invoice {} is open; the paid amount must be given with --amount",
+                                invoice
+                            )))
+                        }
+                    };
+                    total = total.checked_add(value).ok_or_else(|| {
+                        Error::Failed(format!(
+                            "total amount paid by the invoices exceeds {}",
+                            u64::MAX
+                        ))
+                    })?;
+                    if beneficiaries.insert(invoice.beneficiary, value).is_some() {
+                        let msg = format!(
+                            "beneficiary {} is used by several invoices",
+                            invoice.beneficiary
+                        );
+                        return Err(Error::Failed(msg));
+                    }
+                }
+                // The consignment can be sent only if all the beneficiaries
+                // wait for it on the same node under the same key
+                let first = &invoices[0];
+                let (transport, key) = match invoices
+                    .iter()
+                    .all(|invoice| invoice.transport == first.transport && invoice.key == first.key)
+                {
+                    true => (first.transport.clone(), first.key),
+                    false => (None, None),
+                };
+                if !dry_run && transport.is_none() && consignment.is_none() {
+                    return Err(Error::Failed(s!("invoices have no common transport node, so \
+                                                 the consignment must be saved to a file")));
+                }
+
+                let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                require_signer(client, !dry_run)?;
+                let endseals = beneficiaries.keys().copied().collect::<Vec<_>>();
+                let draft = client.compose_payment(
+                    descriptor,
+                    contract_id,
+                    beneficiaries,
+                    fee,
+                    progress,
+                )?;
+                output.message(format!(
+                    "Spending {} outpoints with allocations",
+                    draft.outpoints.len()
+                ));
+
+                let mut psbt = draft.psbt;
+                let node_id = draft.transition.node_id();
+                if !psbt.has_rgb_contract(contract_id) {
+                    let contract = client.contract(contract_id, vec![], progress)?;
+                    psbt.set_rgb_contract(contract)?;
+                }
+                psbt.push_rgb_transition(draft.transition)?;
+                for input in &mut psbt.inputs {
+                    if draft.outpoints.contains(&input.previous_outpoint) {
+                        input.set_rgb_consumer(contract_id, node_id)?;
+                    }
+                }
+                push_blank_transitions(client, &mut psbt, contract_id, progress)?;
+
+                let transfer =
+                    client.consign(contract_id, vec![], draft.outpoints, empty!(), progress)?;
+                if dry_run {
+                    let preview = client.preview_transfer(transfer, endseals, psbt, progress)?;
+                    output.data("preview", &preview);
+                    return Ok(());
+                }
+                // Consignment is delivered only once the witness transaction is published
+                let transfer = client.transfer(transfer, endseals, psbt, None, None, progress)?;
+                if let Some(path) = &consignment {
+                    transfer.consignment.strict_file_save(path)?;
+                }
+
+                let psbt = sign_psbt(client, transfer.psbt, true)?;
+                let txid = client.publish_tx(psbt, progress)?;
+                output.record("txid", &txid);
+                output.message(format!("{}: transaction {} is published", "Success".ended(), txid));
+                if let Some(addr) = transport {
+                    client.send_transfer(transfer.consignment, addr.clone(), key, progress)?;
+                    output.message(format!("Consignment is sent to {}", addr));
+                }
+                if let Some(path) = consignment {
+                    output.message(format!("Consignment is saved to {}", path.display()));
+                }
+            }
+
+            Command::Swap(subcommand) => match subcommand {
+                SwapCommand::Propose {
+                    give,
+                    take,
+                    timeout,
+                    consignment,
+                    psbt,
+                    offer,
+                } => {
+                    let psbt_bytes = fs::read(&psbt)?;
+                    let psbt = Psbt::deserialize(&psbt_bytes)?;
+                    let consignment =
+                        consignment.map(StateTransfer::armored_file_load).transpose()?;
+                    let terms = SwapTerms { give, take };
+                    let swap_offer =
+                        client.propose_swap(terms, timeout, psbt, consignment, progress)?;
+                    output.record("swapId", &swap_offer.swap_id.to_string());
+                    output.message(format!("Swap id: {}", swap_offer.swap_id));
+                    swap_offer.strict_file_save(offer)?;
+                }
+                SwapCommand::Accept {
+                    consignment,
+                    offer,
+                    psbt_in,
+                    psbt_out,
+                    consignment_out,
+                } => {
+                    let offer = SwapOffer::strict_file_load(offer)?;
+                    let psbt_bytes = fs::read(&psbt_in)?;
+                    let psbt = Psbt::deserialize(&psbt_bytes)?;
+                    let consignment =
+                        consignment.map(StateTransfer::armored_file_load).transpose()?;
+                    output.record("swapId", &offer.swap_id.to_string());
+                    output.message(format!("Swap id: {}", offer.swap_id));
+                    let acceptance = client.accept_swap(offer, psbt, consignment, progress)?;
+                    save_swap(acceptance, psbt_out.unwrap_or(psbt_in), consignment_out)?;
+                }
+                SwapCommand::Complete {
+                    swap_id,
+                    consignment,
+                    psbt_in,
+                    psbt_out,
+                    sign,
+                    consignment_out,
+                } => {
+                    let psbt_bytes = fs::read(&psbt_in)?;
+                    let psbt = Psbt::deserialize(&psbt_bytes)?;
+                    let consignment =
+                        consignment.map(StateTransfer::armored_file_load).transpose()?;
+                    require_signer(client, sign)?;
+                    let mut completion = client.complete_swap(
+                        SwapAcceptance {
+                            swap_id,
+                            psbt,
+                            consignment,
+                        },
+                        progress,
+                    )?;
+                    completion.psbt = sign_psbt(client, completion.psbt, sign)?;
+                    save_swap(completion, psbt_out.unwrap_or(psbt_in), consignment_out)?;
+                }
+                SwapCommand::Abort { swap_id } => {
+                    client.abort_swap(swap_id)?;
+                }
+            },
+
+            Command::Payjoin(subcommand) => match subcommand {
+                PayjoinCommand::Propose {
+                    consignment,
+                    psbt,
+                    proposal,
+                } => {
+                    let psbt_bytes = fs::read(&psbt)?;
+                    let psbt = Psbt::deserialize(&psbt_bytes)?;
+                    let consignment = StateTransfer::armored_file_load(consignment)?;
+                    let payjoin = client.propose_payjoin(psbt, consignment, progress)?;
+                    output.record("payjoinId", &payjoin.payjoin_id.to_string());
+                    output.message(format!("Payjoin id: {}", payjoin.payjoin_id));
+                    payjoin.strict_file_save(proposal)?;
+                }
+                PayjoinCommand::Contribute {
+                    descriptor,
+                    fee,
+                    endseals,
+                    sign,
+                    consignment,
+                    proposal,
+                    contribution,
+                    outpoints,
+                } => {
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    let proposal = PayjoinProposal::strict_file_load(proposal)?;
+                    output.record("payjoinId", &proposal.payjoin_id.to_string());
+                    output.message(format!("Payjoin id: {}", proposal.payjoin_id));
+                    let outpoints = outpoints.into_iter().collect();
+                    require_signer(client, sign)?;
+                    let mut payjoin = client.contribute_payjoin(
+                        proposal, descriptor, outpoints, endseals, fee, progress,
+                    )?;
+                    payjoin.psbt = sign_psbt(client, payjoin.psbt, sign)?;
+                    if let Some(path) = consignment {
+                        payjoin.consignment.strict_file_save(path)?;
+                    }
+                    payjoin.strict_file_save(contribution)?;
+                }
+                PayjoinCommand::Complete {
+                    contribution,
+                    psbt_out,
+                    sign,
+                } => {
+                    let contribution = PayjoinContribution::strict_file_load(contribution)?;
+                    require_signer(client, sign)?;
+                    let payjoin = client.complete_payjoin(contribution, progress)?;
+                    let psbt = sign_psbt(client, payjoin.psbt, sign)?;
+                    output.record("txid", &psbt.to_txid());
+                    fs::write(psbt_out, psbt.serialize())?;
+                }
+            },
+
+            Command::Relay(subcommand) => {
+                if !client.supports(Capability::Relay) {
+                    return Err(Error::Failed(s!("node does not run as a relay")));
+                }
+                match subcommand {
+                    RelayCommand::Deposit {
+                        ttl,
+                        no_forward,
+                        recipient,
+                        consignment,
+                    } => {
+                        let data = fs::read(consignment)?;
+                        let secp = Secp256k1::new();
+                        let one_time_key = SecretKey::new(&mut secp256k1::rand::thread_rng());
+                        let payload = seal_payload(&secp, recipient, one_time_key, &data);
+                        let envelope =
+                            client.relay_deposit(recipient, &payload, ttl, !no_forward)?;
+                        output.data("envelope", &envelope);
+                    }
+                    RelayCommand::Pickup { key, output_dir } => {
+                        let secp = Secp256k1::new();
+                        let keypair = load_keypair(&secp, &key)?;
+                        let auth = |request| RelayAuth::sign(&secp, &keypair, now(), request);
+                        let list = auth(RelayPickupReq::digest(&empty!()));
+                        let envelopes = client.relay_pickup(list, empty!())?;
+                        output.message(format!("Relay holds {} envelope(s)", envelopes.len()));
+                        let mut received = bset! {};
+                        let mut foreign = 0usize;
+                        for envelope in envelopes {
+                            let fetch = auth(RelayFetchReq::digest(envelope.id));
+                            let payload = client.relay_fetch(fetch, envelope)?;
+                            let consignment = match open_payload(&keypair, &payload) {
+                                Some(consignment) => consignment,
+                                None => {
+                                    output.warning(format!(
+                                        "envelope {} is not sealed to the key",
+                                        envelope.id
+                                    ));
+                                    foreign += 1;
+                                    continue;
+                                }
+                            };
+                            let path = output_dir.join(format!("{}.rgbc", envelope.id));
+                            fs::write(&path, consignment)?;
+                            output.message(format!(
+                                "Envelope {} is saved to {}",
+                                envelope.id,
+                                path.display()
+                            ));
+                            received.insert(envelope.id);
+                        }
+                        if !received.is_empty() {
+                            let ack = auth(RelayPickupReq::digest(&received));
+                            client.relay_pickup(ack, received.clone())?;
+                        }
+                        output.record("received", &received);
+                        if foreign > 0 {
+                            return Err(Error::Failed(format!(
+                                "{} envelope(s) are not sealed to the key and are left at the \
+                                 relay",
+                                foreign
+                            )));
+                        }
+                    }
+                    RelayCommand::Id { .. } => {
+                        unreachable!("offline commands are executed without connecting to the node")
+                    }
+                }
+            }
+
+            Command::Consignment(_) | Command::Init { .. } => {
+                unreachable!("offline commands are executed without connecting to the node")
+            }
+
             Command::Transfer(subcommand) => match subcommand {
+                TransferCommand::List {
+                    contract_id,
+                    direction,
+                    status,
+                } => {
+                    let transfers = client.transfers(TransferFilter {
+                        contract_id,
+                        direction,
+                        status,
+                    })?;
+                    output.data("transfers", &transfers);
+                }
+
+                TransferCommand::History { contract_id } => {
+                    output.list("history", client.known_history(contract_id)?);
+                }
+
+                TransferCommand::Receipt { consignment_id } => {
+                    output.data("receipt", &client.transfer_receipt(consignment_id)?);
+                }
+
                 TransferCommand::Compose {
                     node_types,
+                    known,
+                    timeout,
+                    wallet,
+                    from_index,
+                    to_index,
+                    armor,
                     contract_id,
                     outpoints,
-                    output,
+                    output: path,
                 } => {
-                    let transfer = client.consign(
-                        contract_id,
-                        node_types,
-                        outpoints.into_iter().collect(),
-                        progress,
-                    )?;
-                    println!("Saving consignment to {}", output.display());
-                    let file = fs::File::create(output)?;
-                    transfer.strict_encode(file)?;
-                    println!("{}", "Success".ended());
+                    client.set_timeout(timeout);
+                    let outpoints = match wallet {
+                        Some(descriptor) => OutpointFilter::Wallet {
+                            descriptor,
+                            from_index: from_index.unwrap_or_default(),
+                            to_index,
+                        },
+                        None => OutpointFilter::Only(outpoints.into_iter().collect()),
+                    };
+                    let known = known.into_iter().collect();
+                    if armor {
+                        let armored = client.consign_armored(
+                            contract_id,
+                            node_types,
+                            outpoints,
+                            known,
+                            progress,
+                        )?;
+                        output.message(format!("Saving consignment to {}", path.display()));
+                        fs::write(path, armored)?;
+                    } else {
+                        let transfer =
+                            client.consign(contract_id, node_types, outpoints, known, progress)?;
+                        output.message(format!("Saving consignment to {}", path.display()));
+                        let file = fs::File::create(path)?;
+                        transfer.strict_encode(file)?;
+                    }
+                    output.message("Success".ended());
                 }
 
                 TransferCommand::Combine {
@@ -237,27 +1614,86 @@ impl Exec for Opts {
                         }
                     }
 
-                    let outpoints: BTreeSet<_> =
+                    push_blank_transitions(client, &mut psbt, contract_id, progress)?;
+
+                    let psbt_bytes = psbt.serialize();
+                    fs::write(psbt_out.unwrap_or(psbt_in), psbt_bytes)?;
+                }
+
+                TransferCommand::Consolidate {
+                    contract_id,
+                    vout,
+                    method,
+                    psbt_in,
+                    psbt_out,
+                    consignment,
+                } => {
+                    let psbt_bytes = fs::read(&psbt_in)?;
+                    let mut psbt = Psbt::deserialize(&psbt_bytes)?;
+
+                    let inputs: BTreeSet<_> =
                         psbt.inputs.iter().map(|input| input.previous_outpoint).collect();
-                    let state_map = client.outpoint_state(outpoints, progress)?;
-                    for (cid, outpoint_map) in state_map {
-                        if cid == contract_id {
-                            continue;
-                        }
-                        let contract = client.contract(cid, vec![], progress)?;
+                    let outpoints = client
+                        .outpoint_state(inputs, progress)?
+                        .remove(&contract_id)
+                        .map(|outpoint_map| outpoint_map.into_keys().collect::<BTreeSet<_>>())
+                        .unwrap_or_default();
+                    if outpoints.is_empty() {
+                        return Err(Error::Failed(format!(
+                            "PSBT inputs have no allocations of {}",
+                            contract_id
+                        )));
+                    }
+                    output.message(format!(
+                        "Consolidating allocations from {} outpoints",
+                        outpoints.len()
+                    ));
+
+                    // The blinding is generated by the node, which reveals the seal in the
+                    // returned transition.
+                    let seal = seal::Revealed {
+                        method,
+                        blinding: 0,
+                        txid: None,
+                        vout,
+                    };
+                    let transition =
+                        client.consolidate(contract_id, outpoints.clone(), seal, progress)?;
+                    let seal =
+                        transition.filter_revealed_seals().into_iter().next().ok_or_else(|| {
+                            Error::Failed(s!("consolidating transition has no revealed seal"))
+                        })?;
+                    let node_id = transition.node_id();
+                    if !psbt.has_rgb_contract(contract_id) {
+                        let contract = client.contract(contract_id, vec![], progress)?;
                         psbt.set_rgb_contract(contract)?;
-                        let blank_bundle = TransitionBundle::blank(&outpoint_map, &bmap! {})?;
-                        for (transition, indexes) in blank_bundle.revealed_iter() {
-                            psbt.push_rgb_transition(transition.clone())?;
-                            for no in indexes {
-                                psbt.inputs[*no as usize]
-                                    .set_rgb_consumer(cid, transition.node_id())?;
-                            }
+                    }
+                    psbt.push_rgb_transition(transition)?;
+                    for input in &mut psbt.inputs {
+                        if outpoints.contains(&input.previous_outpoint) {
+                            input.set_rgb_consumer(contract_id, node_id)?;
                         }
                     }
+                    push_blank_transitions(client, &mut psbt, contract_id, progress)?;
 
-                    let psbt_bytes = psbt.serialize();
+                    let draft =
+                        client.consign(contract_id, vec![], outpoints, empty!(), progress)?;
+                    let transfer = client.transfer(
+                        draft,
+                        vec![SealEndpoint::from(seal)],
+                        psbt,
+                        None,
+                        None,
+                        progress,
+                    )?;
+                    transfer.consignment.strict_file_save(&consignment)?;
+                    let psbt_bytes = transfer.psbt.serialize();
                     fs::write(psbt_out.unwrap_or(psbt_in), psbt_bytes)?;
+                    output.message(format!(
+                        "{}: sign and publish the PSBT, then consume consignment {}",
+                        "Success".ended(),
+                        consignment.display()
+                    ));
                 }
 
                 TransferCommand::Finalize {
@@ -266,29 +1702,120 @@ impl Exec for Opts {
                     consignment_out,
                     endseals,
                     send,
+                    encrypt_to,
                     psbt_out,
+                    sign,
+                    dry_run,
                 } => {
                     let psbt_bytes = fs::read(&psbt_in)?;
                     let psbt = Psbt::deserialize(&psbt_bytes)?;
-                    let consignment = StateTransfer::strict_file_load(&consignment_in)?;
-                    let transfer = client.transfer(consignment, endseals, psbt, send, progress)?;
+                    let consignment = StateTransfer::armored_file_load(&consignment_in)?;
+                    if dry_run {
+                        let preview =
+                            client.preview_transfer(consignment, endseals, psbt, progress)?;
+                        output.data("preview", &preview);
+                        return Ok(());
+                    }
+                    require_signer(client, sign)?;
+                    let transfer =
+                        client.transfer(consignment, endseals, psbt, send, encrypt_to, progress)?;
 
                     transfer
                         .consignment
                         .strict_file_save(consignment_out.unwrap_or(consignment_in))?;
 
-                    let psbt_bytes = transfer.psbt.serialize();
+                    let psbt = sign_psbt(client, transfer.psbt, sign)?;
+                    let psbt_bytes = psbt.serialize();
                     fs::write(psbt_out.unwrap_or(psbt_in), psbt_bytes)?;
                 }
 
                 TransferCommand::Consume {
                     force,
+                    full,
+                    upload: false,
+                    timeout,
+                    proofs,
+                    consignment,
+                    reveal,
+                } => {
+                    client.set_timeout(timeout);
+                    let consignment = StateTransfer::armored_file_load(consignment)?;
+                    verify_consignment(&consignment)?;
+                    let proofs = proofs.map(ProofBundle::strict_file_load).transpose()?;
+                    let status = client.consume_transfer(
+                        consignment,
+                        force,
+                        full,
+                        reveal,
+                        proofs,
+                        progress,
+                    )?;
+                    report_validation(status)?;
+                }
+                TransferCommand::Consume {
+                    force,
+                    full,
+                    upload: true,
+                    timeout,
+                    proofs,
                     consignment,
                     reveal,
                 } => {
-                    let consignment = StateTransfer::strict_file_load(consignment)?;
-                    let status = client.consume_transfer(consignment, force, reveal, progress)?;
-                    report_validation(status);
+                    client.set_timeout(timeout);
+                    let data = fs::read(consignment)?;
+                    let proofs = proofs.map(ProofBundle::strict_file_load).transpose()?;
+                    let status = client.consume_upload(
+                        UploadKind::Transfer,
+                        &data,
+                        force,
+                        full,
+                        reveal,
+                        proofs,
+                        progress,
+                    )?;
+                    report_validation(status)?;
+                }
+                TransferCommand::Prove {
+                    from_height,
+                    consignment,
+                    output: path,
+                } => {
+                    let consignment = StateTransfer::armored_file_load(consignment)?;
+                    let txids =
+                        consignment.anchored_bundles().map(|(anchor, _)| anchor.txid).collect();
+                    let proofs = client.prove_transactions(txids, from_height, progress)?;
+                    output.message(format!("Proofs contain {}", proofs));
+                    proofs.strict_file_save(path)?;
+                }
+                TransferCommand::Accelerate {
+                    descriptor,
+                    fee_rate,
+                    consignment_id,
+                } => {
+                    if !client.supports(Capability::Signer) {
+                        return Err(Error::Failed(s!(
+                            "node has no external signer configured, so the child can't be signed"
+                        )));
+                    }
+                    let descriptor = descriptor.ok_or(ConfigError::NoWallet)?;
+                    let draft = client.accelerate_transfer(
+                        descriptor,
+                        consignment_id,
+                        fee_rate,
+                        progress,
+                    )?;
+                    output.message(format!(
+                        "Paying {} sats for transaction {} with a child transaction",
+                        draft.fee, draft.parent
+                    ));
+                    let psbt = sign_psbt(client, draft.psbt, true)?;
+                    let txid = client.publish_tx(psbt, progress)?;
+                    output.record("txid", &txid);
+                    output.message(format!(
+                        "{}: child transaction {} is published",
+                        "Success".ended(),
+                        txid
+                    ));
                 }
             },
         }
@@ -296,3 +1823,85 @@ impl Exec for Opts {
         Ok(())
     }
 }
+
+/// Adds blank state transitions to the PSBT for all contracts other than
+/// `contract_id` which have state assigned to the PSBT inputs.
+fn push_blank_transitions(
+    client: &mut Client,
+    psbt: &mut Psbt,
+    contract_id: ContractId,
+    progress: impl Fn(String) + Copy,
+) -> Result<(), Error> {
+    let outpoints: BTreeSet<_> = psbt.inputs.iter().map(|input| input.previous_outpoint).collect();
+    let state_map = client.outpoint_state(outpoints, progress)?;
+    for (cid, outpoint_map) in state_map {
+        if cid == contract_id {
+            continue;
+        }
+        let contract = client.contract(cid, vec![], progress)?;
+        psbt.set_rgb_contract(contract)?;
+        let blank_bundle = TransitionBundle::blank(&outpoint_map, &bmap! {})?;
+        for (transition, indexes) in blank_bundle.revealed_iter() {
+            psbt.push_rgb_transition(transition.clone())?;
+            for no in indexes {
+                psbt.inputs[*no as usize].set_rgb_consumer(cid, transition.node_id())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads number of decimal digits in the amounts of the contract known to the
+/// node.
+fn precision(client: &mut Client, contract_id: ContractId) -> Result<u8, Error> {
+    let state = client.contract_state(contract_id)?;
+    Ok(contract_precision(&state.state.metadata))
+}
+
+/// Reads secret key from a file containing its hex encoding
+fn load_keypair<C: secp256k1::Signing>(secp: &Secp256k1<C>, path: &Path) -> Result<KeyPair, Error> {
+    let secret_key = SecretKey::from_str(fs::read_to_string(path)?.trim())?;
+    Ok(KeyPair::from_secret_key(secp, &secret_key))
+}
+
+/// Fails if signing of the PSBT is requested, but the node has no external
+/// signer configured. Must be checked before the node is asked to draft the
+/// PSBT, so the command fails before the node changes its state.
+fn require_signer(client: &Client, sign: bool) -> Result<(), Error> {
+    if sign && !client.supports(Capability::Signer) {
+        return Err(Error::Failed(s!(
+            "node has no external signer configured, so the PSBT can't be signed"
+        )));
+    }
+    Ok(())
+}
+
+/// Signs the PSBT with the external signer of the node, if requested. The
+/// signer must be checked with [`require_signer`] beforehand.
+fn sign_psbt(client: &mut Client, psbt: Psbt, sign: bool) -> Result<Psbt, Error> {
+    match sign {
+        true => Ok(client.sign_psbt(psbt)?),
+        false => Ok(psbt),
+    }
+}
+
+/// Saves PSBT and consignment exchanged during the swap.
+fn save_swap(
+    swap: SwapAcceptance,
+    psbt_out: PathBuf,
+    consignment_out: Option<PathBuf>,
+) -> Result<(), Error> {
+    fs::write(psbt_out, swap.psbt.serialize())?;
+    match (swap.consignment, consignment_out) {
+        (Some(consignment), Some(path)) => {
+            consignment.strict_file_save(path)?;
+        }
+        (Some(_), None) => {
+            return Err(Error::Failed(s!(
+                "swap consignment is not saved since no output file is given"
+            )))
+        }
+        (None, _) => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,243 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+use bitcoin::XOnlyPublicKey;
+use internet2::addr::ServiceAddr;
+use lnpbp::chain::Chain;
+use rgb_rpc::{IdEncoding, SignetChallenge, RGB_NODE_RPC_ENDPOINT};
+use serde::Deserialize;
+
+use crate::opts::{OutpointCommand, OutputFormat, PayjoinCommand, TransferCommand, WalletCommand};
+use crate::{Command, Opts};
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+))]
+pub const RGB_CLI_CONFIG: &str = "~/.rgb_node/rgb_cli.toml";
+#[cfg(target_os = "macos")]
+pub const RGB_CLI_CONFIG: &str = "~/Library/Application Support/RGB Node/rgb_cli.toml";
+#[cfg(target_os = "windows")]
+pub const RGB_CLI_CONFIG: &str = "~\\AppData\\Local\\RGB Node\\rgb_cli.toml";
+#[cfg(target_os = "ios")]
+pub const RGB_CLI_CONFIG: &str = "~/Documents/rgb_cli.toml";
+#[cfg(target_os = "android")]
+pub const RGB_CLI_CONFIG: &str = "./rgb_cli.toml";
+
+const CONFIG_TEMPLATE: &str = "\
+# RGB node command-line tool configuration.
+#
+# Values given in the command line or with the environment variables take
+# precedence over the values from this file.
+
+# ZMQ socket for connecting daemon RPC interface: either TCP address in form
+# of `<ipv4 | ipv6>:<port>` or a path to an IPC file
+# (RGB_NODE_RPC_ENDPOINT)
+#rpc = \"0.0.0.0:63963\"
+
+# Blockchain to use (RGB_NETWORK)
+#chain = \"signet\"
+
+# Challenge script of a custom signet, in hex (RGB_SIGNET_CHALLENGE)
+#signet_challenge = \"\"
+
+# Wallet output descriptor used by `pay` and `wallet` commands when no
+# descriptor is given
+#wallet = \"\"
+
+# File containing hex-encoded secret key of the client identity, used to
+# authenticate to the node with access control enabled (RGB_CLI_IDENTITY_KEY)
+#identity_key = \"\"
+
+# Identity key which the node must have, in hex (RGB_CLI_NODE_IDENTITY)
+#node_identity = \"\"
+
+# Output format of the command results: `yaml` or `json` (RGB_CLI_FORMAT)
+#format = \"yaml\"
+
+# Encoding of the contract ids in the command output: `bech32` or `hex`
+# (RGB_CLI_ID_FORMAT)
+#id_format = \"bech32\"
+
+# Character separating groups of three digits in the decimal amounts, like
+# `,` or `_`; no separator is used by default (RGB_CLI_GROUP_SEPARATOR)
+#group_separator = \"\"
+";
+
+#[derive(Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ConfigError {
+    /// unable to access configuration file {0}: {1}
+    Io(String, io::Error),
+
+    /// configuration file {0} is invalid: {1}
+    Toml(String, toml::de::Error),
+
+    /// configuration file {0} has invalid value of `{1}`: {2}
+    InvalidValue(String, &'static str, String),
+
+    /// configuration file {0} already exists; use `--force` to overwrite it.
+    Exists(String),
+
+    /// no wallet descriptor is given in the command line or in the
+    /// configuration file.
+    NoWallet,
+}
+
+/// Configuration file contents
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    rpc: Option<String>,
+    chain: Option<String>,
+    signet_challenge: Option<String>,
+    wallet: Option<String>,
+    identity_key: Option<String>,
+    node_identity: Option<String>,
+    format: Option<String>,
+    id_format: Option<String>,
+    group_separator: Option<String>,
+}
+
+/// Settings read from the configuration file
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Config {
+    pub rpc: Option<ServiceAddr>,
+    pub chain: Option<Chain>,
+    pub signet_challenge: Option<SignetChallenge>,
+    pub wallet: Option<String>,
+    pub identity_key: Option<PathBuf>,
+    pub node_identity: Option<XOnlyPublicKey>,
+    pub format: Option<OutputFormat>,
+    pub id_format: Option<IdEncoding>,
+    pub group_separator: Option<char>,
+}
+
+impl Config {
+    /// Reads configuration from the file. If no file is given, the file at the
+    /// default location is used, if it exists.
+    pub fn load(path: Option<&Path>) -> Result<Config, ConfigError> {
+        let (path, required) = match path {
+            Some(path) => (path.to_owned(), true),
+            None => (default_path(), false),
+        };
+        let name = path.display().to_string();
+        let data = match fs::read_to_string(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => {
+                return Ok(default!())
+            }
+            Err(err) => return Err(ConfigError::Io(name, err)),
+        };
+        let file: ConfigFile =
+            toml::from_str(&data).map_err(|err| ConfigError::Toml(name.clone(), err))?;
+        Ok(Config {
+            rpc: parse(&name, "rpc", file.rpc)?,
+            chain: parse(&name, "chain", file.chain)?,
+            signet_challenge: parse(&name, "signet_challenge", file.signet_challenge)?,
+            wallet: file.wallet.filter(|wallet| !wallet.is_empty()),
+            identity_key: file
+                .identity_key
+                .filter(|path| !path.is_empty())
+                .map(|path| PathBuf::from(shellexpand::tilde(&path).to_string())),
+            node_identity: parse(&name, "node_identity", file.node_identity)?,
+            format: parse(&name, "format", file.format)?,
+            id_format: parse(&name, "id_format", file.id_format)?,
+            group_separator: parse(
+                &name,
+                "group_separator",
+                file.group_separator.filter(|separator| !separator.is_empty()),
+            )?,
+        })
+    }
+
+    /// Writes configuration file template, returning path to the file.
+    pub fn init(path: Option<&Path>, force: bool) -> Result<PathBuf, ConfigError> {
+        let path = path.map(Path::to_owned).unwrap_or_else(default_path);
+        let name = path.display().to_string();
+        if path.exists() && !force {
+            return Err(ConfigError::Exists(name));
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| ConfigError::Io(name.clone(), err))?;
+        }
+        fs::write(&path, CONFIG_TEMPLATE).map_err(|err| ConfigError::Io(name, err))?;
+        Ok(path)
+    }
+}
+
+impl Opts {
+    /// Fills settings not given in the command line or with the environment
+    /// variables with the values from the configuration file or the defaults.
+    pub fn apply_config(&mut self, config: Config) {
+        if self.connect.is_none() {
+            self.connect = Some(config.rpc.unwrap_or_else(|| {
+                ServiceAddr::from_str(RGB_NODE_RPC_ENDPOINT).expect("invalid default endpoint")
+            }));
+        }
+        if self.chain.is_none() {
+            self.chain = Some(config.chain.unwrap_or(Chain::Signet));
+        }
+        if self.signet_challenge.is_none() {
+            self.signet_challenge = config.signet_challenge;
+        }
+        if self.identity_key.is_none() {
+            self.identity_key = config.identity_key;
+        }
+        if self.node_identity.is_none() {
+            self.node_identity = config.node_identity;
+        }
+        if self.format.is_none() {
+            self.format = Some(config.format.unwrap_or_default());
+        }
+        if self.id_format.is_none() {
+            self.id_format = Some(config.id_format.unwrap_or_default());
+        }
+        if self.group_separator.is_none() {
+            self.group_separator = config.group_separator;
+        }
+        let wallet = match config.wallet {
+            Some(wallet) => wallet,
+            None => return,
+        };
+        match &mut self.command {
+            Command::Pay { descriptor, .. }
+            | Command::Transfer(TransferCommand::Accelerate { descriptor, .. })
+            | Command::Outpoint(OutpointCommand::Forget { descriptor, .. })
+            | Command::Payjoin(PayjoinCommand::Contribute { descriptor, .. })
+            | Command::Wallet(WalletCommand::Sync { descriptor, .. })
+            | Command::Wallet(WalletCommand::Balance { descriptor })
+            | Command::Wallet(WalletCommand::Addresses { descriptor, .. }) => {
+                descriptor.get_or_insert(wallet);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn default_path() -> PathBuf { PathBuf::from(shellexpand::tilde(RGB_CLI_CONFIG).to_string()) }
+
+fn parse<T>(name: &str, key: &'static str, value: Option<String>) -> Result<Option<T>, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .map(|value| T::from_str(&value))
+        .transpose()
+        .map_err(|err| ConfigError::InvalidValue(name.to_owned(), key, err.to_string()))
+}
@@ -0,0 +1,332 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Offline inspection of consignment files, not requiring connection to the
+//! node.
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Write};
+use std::fs;
+use std::path::Path;
+
+use colored::Colorize;
+use rgb::{
+    Consignment, ConsignmentType, Contract, Disclosure, InmemConsignment, Node, NodeId,
+    OwnedRights, StateTransfer,
+};
+use rgb_rpc::{Armor, ArmorError, ARMOR_BOUNDARY};
+use strict_encoding::StrictDecode;
+
+use crate::opts::InspectType;
+
+/// Data loaded from the inspected file
+pub enum Inspected {
+    Transfer(StateTransfer),
+    Contract(Contract),
+    Disclosure(Disclosure),
+}
+
+impl Inspected {
+    /// Loads the file as the data of the given type, detecting the type if it
+    /// is not given (see [`detect`]).
+    pub fn load(path: &Path, data_type: Option<InspectType>) -> Result<Self, ArmorError> {
+        let data_type = match data_type {
+            Some(data_type) => data_type,
+            None => detect(&fs::read(path)?),
+        };
+        Ok(match data_type {
+            InspectType::Transfer => Inspected::Transfer(StateTransfer::armored_file_load(path)?),
+            InspectType::Contract => Inspected::Contract(Contract::armored_file_load(path)?),
+            InspectType::Disclosure => Inspected::Disclosure(Disclosure::armored_file_load(path)?),
+        })
+    }
+
+    /// Returns human-readable description of the data structure.
+    pub fn report(&self) -> Result<String, fmt::Error> {
+        match self {
+            Inspected::Transfer(consignment) => report(consignment),
+            Inspected::Contract(consignment) => report(consignment),
+            Inspected::Disclosure(disclosure) => disclosure_report(disclosure),
+        }
+    }
+
+    /// Returns graph of the data in graphviz DOT format.
+    pub fn dot(&self) -> Result<String, fmt::Error> {
+        match self {
+            Inspected::Transfer(consignment) => dot(consignment),
+            Inspected::Contract(consignment) => dot(consignment),
+            Inspected::Disclosure(disclosure) => disclosure_dot(disclosure),
+        }
+    }
+}
+
+/// Detects type of the file data. ASCII-armored data are recognized by the
+/// armor header; binary data are read as consignments, which are considered
+/// contract consignments if they have no endpoints, and as disclosures if they
+/// are not valid consignments. Contract and transfer consignments share the
+/// same binary encoding, so they can't be told apart otherwise.
+pub fn detect(data: &[u8]) -> InspectType {
+    if let Ok(s) = std::str::from_utf8(data) {
+        let header = s.trim_start().lines().next().unwrap_or_default();
+        let armor_type = header
+            .strip_prefix(ARMOR_BOUNDARY)
+            .and_then(|header| header.strip_prefix("BEGIN RGB "))
+            .and_then(|header| header.strip_suffix(ARMOR_BOUNDARY));
+        match armor_type {
+            Some(Contract::ARMOR_TYPE) => return InspectType::Contract,
+            Some(Disclosure::ARMOR_TYPE) => return InspectType::Disclosure,
+            // Unknown armor types fail to load with the error naming the
+            // expected header
+            Some(_) => return InspectType::Transfer,
+            None => {}
+        }
+    }
+    match StateTransfer::strict_deserialize(data) {
+        Ok(consignment) if consignment.endpoints().count() == 0 => InspectType::Contract,
+        Ok(_) => InspectType::Transfer,
+        Err(_) if Disclosure::strict_deserialize(data).is_ok() => InspectType::Disclosure,
+        // Decoding error is reported when the file is loaded
+        Err(_) => InspectType::Transfer,
+    }
+}
+
+fn write_owned_rights(f: &mut String, owned_rights: &OwnedRights) -> fmt::Result {
+    for (ty, assignments) in owned_rights.iter() {
+        let revealed = assignments.revealed_seal_outputs();
+        write!(f, "      - type {}: {} assignment(s)", ty, assignments.len())?;
+        if !revealed.is_empty() {
+            let seals = revealed
+                .iter()
+                .map(|(seal, no)| format!("#{} {}", no, seal))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "; revealed seals: {}", seals)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+fn write_node(f: &mut String, node: &impl Node) -> fmt::Result {
+    let inputs = node.parent_outputs();
+    if !inputs.is_empty() {
+        writeln!(f, "    inputs:")?;
+        for input in inputs {
+            writeln!(f, "      - {}", input)?;
+        }
+    }
+    if !node.owned_rights().is_empty() {
+        writeln!(f, "    outputs:")?;
+        write_owned_rights(f, node.owned_rights())?;
+    }
+    Ok(())
+}
+
+/// Returns human-readable description of the consignment structure.
+pub fn report<T: ConsignmentType>(consignment: &InmemConsignment<T>) -> Result<String, fmt::Error> {
+    let mut f = String::new();
+
+    writeln!(f, "{} {}", "Consignment".bold(), consignment.id())?;
+    writeln!(f, "  version: {}", consignment.version())?;
+    writeln!(f, "  contract: {}", consignment.contract_id())?;
+    writeln!(f, "  schema: {}", consignment.schema_id())?;
+    if let Some(root_schema_id) = consignment.root_schema_id() {
+        writeln!(f, "  root schema: {}", root_schema_id)?;
+    }
+
+    let genesis = consignment.genesis();
+    writeln!(f, "\n{} {}", "Genesis".bold(), genesis.node_id())?;
+    writeln!(f, "    chain: {}", genesis.chain())?;
+    writeln!(f, "    metadata fields: {}", genesis.metadata().len())?;
+    write_node(&mut f, genesis)?;
+
+    let endpoint_bundles = consignment.endpoint_bundle_ids();
+    writeln!(f, "\n{} ({})", "Anchored bundles".bold(), consignment.anchored_bundles().count())?;
+    for (anchor, bundle) in consignment.anchored_bundles() {
+        let bundle_id = bundle.bundle_id();
+        let marker = if endpoint_bundles.contains(&bundle_id) { " (endpoint)" } else { "" };
+        writeln!(f, "  bundle {} in witness tx {}{}", bundle_id, anchor.txid, marker)?;
+        for (transition, inputs) in bundle.revealed_iter() {
+            let ty = transition.transition_type();
+            writeln!(f, "  {} {}, type {}", "Transition".bold(), transition.node_id(), ty)?;
+            writeln!(f, "    spends witness tx inputs: {:?}", inputs)?;
+            write_node(&mut f, transition)?;
+        }
+        for (node_id, inputs) in bundle.concealed_iter() {
+            writeln!(f, "  concealed transition {} spending inputs {:?}", node_id, inputs)?;
+        }
+    }
+
+    writeln!(f, "\n{} ({})", "State extensions".bold(), consignment.state_extensions().count())?;
+    for extension in consignment.state_extensions() {
+        let ty = extension.extension_type().unwrap_or_default();
+        writeln!(f, "  {} {}, type {}", "Extension".bold(), extension.node_id(), ty)?;
+        write_node(&mut f, extension)?;
+    }
+
+    writeln!(f, "\n{} ({})", "Endpoints".bold(), consignment.endpoints().count())?;
+    for (bundle_id, endseal) in consignment.endpoints() {
+        writeln!(f, "  - bundle {}: {}", bundle_id, endseal)?;
+    }
+
+    Ok(f)
+}
+
+/// Returns human-readable description of the disclosure structure.
+pub fn disclosure_report(disclosure: &Disclosure) -> Result<String, fmt::Error> {
+    let mut f = String::new();
+
+    writeln!(f, "{}", "Disclosure".bold())?;
+    writeln!(f, "\n{} ({})", "Anchors".bold(), disclosure.anchored_bundles().len())?;
+    for (anchor, bundles) in disclosure.anchored_bundles().values() {
+        writeln!(f, "  witness tx {}", anchor.txid)?;
+        for (contract_id, bundle) in bundles {
+            writeln!(f, "  bundle {} of contract {}", bundle.bundle_id(), contract_id)?;
+            for (transition, inputs) in bundle.revealed_iter() {
+                let ty = transition.transition_type();
+                writeln!(f, "  {} {}, type {}", "Transition".bold(), transition.node_id(), ty)?;
+                writeln!(f, "    spends witness tx inputs: {:?}", inputs)?;
+                write_node(&mut f, transition)?;
+            }
+            for (node_id, inputs) in bundle.concealed_iter() {
+                writeln!(f, "  concealed transition {} spending inputs {:?}", node_id, inputs)?;
+            }
+        }
+    }
+
+    Ok(f)
+}
+
+/// Shortened identifier used in graph labels
+fn short(id: impl Display) -> String { id.to_string().chars().take(8).collect() }
+
+fn dot_edges(f: &mut String, node: &impl Node, known: &BTreeSet<NodeId>) -> fmt::Result {
+    let node_id = node.node_id();
+    for input in node.parent_outputs() {
+        let parent_id = input.node_id;
+        if !known.contains(&parent_id) {
+            writeln!(
+                f,
+                "  \"{}\" [label=\"unknown\\n{}\", style=dashed];",
+                parent_id,
+                short(parent_id)
+            )?;
+        }
+        writeln!(
+            f,
+            "  \"{}\" -> \"{}\" [label=\"{}/{}\"];",
+            parent_id, node_id, input.ty, input.no
+        )?;
+    }
+    for (parent_id, _) in node.parent_public_rights().iter() {
+        writeln!(f, "  \"{}\" -> \"{}\" [style=dotted];", parent_id, node_id)?;
+    }
+    Ok(())
+}
+
+/// Returns consignment DAG in graphviz DOT format.
+pub fn dot<T: ConsignmentType>(consignment: &InmemConsignment<T>) -> Result<String, fmt::Error> {
+    let mut f = String::new();
+    let known = consignment.node_ids();
+    let endpoint_bundles = consignment.endpoint_bundle_ids();
+    let genesis = consignment.genesis();
+
+    writeln!(f, "digraph \"{}\" {{", consignment.contract_id())?;
+    writeln!(f, "  rankdir=LR;")?;
+    writeln!(f, "  node [shape=box, fontname=monospace];")?;
+    let genesis_id = genesis.node_id();
+    writeln!(f, "  \"{}\" [label=\"genesis\\n{}\", style=bold];", genesis_id, short(genesis_id))?;
+
+    for (no, (anchor, bundle)) in consignment.anchored_bundles().enumerate() {
+        let bundle_id = bundle.bundle_id();
+        writeln!(f, "  subgraph cluster_{} {{", no)?;
+        writeln!(f, "    label=\"witness {}\";", short(anchor.txid))?;
+        if endpoint_bundles.contains(&bundle_id) {
+            writeln!(f, "    style=filled; fillcolor=lightyellow;")?;
+        }
+        for (transition, _) in bundle.revealed_iter() {
+            let ty = transition.transition_type();
+            let node_id = transition.node_id();
+            writeln!(
+                f,
+                "    \"{}\" [label=\"transition #{}\\n{}\"];",
+                node_id,
+                ty,
+                short(node_id)
+            )?;
+        }
+        writeln!(f, "  }}")?;
+    }
+    for extension in consignment.state_extensions() {
+        let ty = extension.extension_type().unwrap_or_default();
+        let node_id = extension.node_id();
+        writeln!(
+            f,
+            "  \"{}\" [label=\"extension #{}\\n{}\", shape=ellipse];",
+            node_id,
+            ty,
+            short(node_id)
+        )?;
+    }
+
+    for (_, bundle) in consignment.anchored_bundles() {
+        for (transition, _) in bundle.revealed_iter() {
+            dot_edges(&mut f, transition, &known)?;
+        }
+    }
+    for extension in consignment.state_extensions() {
+        dot_edges(&mut f, extension, &known)?;
+    }
+
+    writeln!(f, "}}")?;
+    Ok(f)
+}
+
+/// Returns graph of the disclosed state transitions in graphviz DOT format.
+pub fn disclosure_dot(disclosure: &Disclosure) -> Result<String, fmt::Error> {
+    let mut f = String::new();
+    let bundles = disclosure
+        .anchored_bundles()
+        .values()
+        .flat_map(|(anchor, bundles)| bundles.values().map(move |bundle| (anchor.txid, bundle)))
+        .collect::<Vec<_>>();
+    let known = bundles
+        .iter()
+        .flat_map(|(_, bundle)| bundle.revealed_iter().map(|(transition, _)| transition.node_id()))
+        .collect();
+
+    writeln!(f, "digraph disclosure {{")?;
+    writeln!(f, "  rankdir=LR;")?;
+    writeln!(f, "  node [shape=box, fontname=monospace];")?;
+    for (no, (txid, bundle)) in bundles.iter().enumerate() {
+        writeln!(f, "  subgraph cluster_{} {{", no)?;
+        writeln!(f, "    label=\"witness {}\";", short(txid))?;
+        for (transition, _) in bundle.revealed_iter() {
+            let ty = transition.transition_type();
+            let node_id = transition.node_id();
+            writeln!(
+                f,
+                "    \"{}\" [label=\"transition #{}\\n{}\"];",
+                node_id,
+                ty,
+                short(node_id)
+            )?;
+        }
+        writeln!(f, "  }}")?;
+    }
+    for (_, bundle) in &bundles {
+        for (transition, _) in bundle.revealed_iter() {
+            dot_edges(&mut f, transition, &known)?;
+        }
+    }
+
+    writeln!(f, "}}")?;
+    Ok(f)
+}
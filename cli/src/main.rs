@@ -20,29 +20,60 @@ extern crate log;
 extern crate clap;
 
 mod command;
+mod config;
+mod inspect;
 mod opts;
+mod output;
+mod qr;
+
+use std::process;
 
 use clap::Parser;
 use internet2::addr::ServiceAddr;
 use microservices::cli::LogStyle;
 use microservices::shell::{Exec, LogLevel};
 use rgb_rpc::client::Client;
+use rgb_rpc::SignetChallenge;
 
+pub use crate::config::{Config, ConfigError};
 pub use crate::opts::{Command, Opts};
+pub use crate::output::Output;
 
 fn main() {
-    let opts = Opts::parse();
+    let mut opts = Opts::parse();
     LogLevel::from_verbosity_flag_count(opts.verbose).apply();
     trace!("Command-line arguments: {:#?}", &opts);
 
-    let mut connect = opts.connect.clone();
+    if let Command::Init { force } = opts.command {
+        let output = Output::new(default!(), default!(), opts.json);
+        let res = Config::init(opts.config.as_deref(), force).map(|path| {
+            output.record("config", &path);
+            output.message(format!("Configuration template is written to {}", path.display()));
+        });
+        output.finish(res).unwrap_or_else(|err| eprintln!("{}: {}\n", "Error".err(), err));
+        return;
+    }
+
+    let config = Config::load(opts.config.as_deref()).unwrap_or_else(|err| {
+        let output = Output::new(default!(), default!(), opts.json);
+        output.finish(Err(err)).unwrap_or_else(|err| eprintln!("{}: {}\n", "Error".err(), err));
+        process::exit(1);
+    });
+    trace!("Configuration file settings: {:#?}", &config);
+    opts.apply_config(config);
+
+    let mut connect = opts.connect.clone().expect("endpoint is set by the configuration");
     if let ServiceAddr::Ipc(ref mut path) = connect {
         *path = shellexpand::tilde(path).to_string();
     }
     debug!("RPC socket {}", connect);
 
-    let mut client = Client::with(connect, s!("rgb-cli"), opts.chain.clone())
-        .expect("Error initializing client");
+    let chain = opts.chain.clone().expect("chain is set by the configuration");
+    let mut client =
+        Client::with(connect, s!("rgb-cli"), chain).expect("Error initializing client");
+    client.set_signet(opts.signet_challenge.as_ref().map(SignetChallenge::magic));
+    client.pin_node_identity(opts.node_identity);
+    client.set_progress_handler(output::render_progress);
 
     trace!("Executing command: {}", opts.command);
     opts.exec(&mut client).unwrap_or_else(|err| eprintln!("{}: {}\n", "Error".err(), err));
@@ -9,13 +9,19 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 use std::path::PathBuf;
+use std::str::FromStr;
 
-use bitcoin::OutPoint;
+use bitcoin::{Address, OutPoint, Script, XOnlyPublicKey};
+use bp::seals::txout::CloseMethod;
+use clap::ValueHint;
 use internet2::addr::{NodeAddr, ServiceAddr};
 use lnpbp::chain::Chain;
 use rgb::schema::TransitionType;
-use rgb::{Contract, ContractId, SealEndpoint};
-use rgb_rpc::{Reveal, RGB_NODE_RPC_ENDPOINT};
+use rgb::{ConsignmentId, Contract, ContractId, NodeId, SchemaId, SealEndpoint};
+use rgb_rpc::{
+    parse_contract_id, parse_node_id, AccessTarget, AnnotationTarget, IdEncoding, Invoice,
+    OrderTarget, Reveal, SignetChallenge, SwapId, SwapLeg, TransferDirection, TransferStatus,
+};
 
 /// Command-line tool for working with RGB node
 #[derive(Parser, Clone, PartialEq, Eq, Debug)]
@@ -26,26 +32,82 @@ pub struct Opts {
     /// Socket can be either TCP address in form of `<ipv4 | ipv6>:<port>` – or a path
     /// to an IPC file.
     ///
-    /// Defaults to `0.0.0.0:61961`.
-    #[clap(
-        short = 'R',
-        long = "rpc",
-        global = true,
-        default_value = RGB_NODE_RPC_ENDPOINT,
-        env = "RGB_NODE_RPC_ENDPOINT"
-    )]
-    pub connect: ServiceAddr,
+    /// Defaults to the value from the configuration file or `0.0.0.0:63963`.
+    #[clap(short = 'R', long = "rpc", global = true, env = "RGB_NODE_RPC_ENDPOINT")]
+    pub connect: Option<ServiceAddr>,
+
+    /// Blockchain to use.
+    ///
+    /// Defaults to the value from the configuration file or `signet`.
+    #[clap(short = 'n', long, global = true, alias = "network", env = "RGB_NETWORK")]
+    pub chain: Option<Chain>,
+
+    /// Challenge script of a custom signet, in hex.
+    ///
+    /// Must match the challenge used by the node. Defaults to the value from
+    /// the configuration file.
+    #[clap(long, global = true, env = "RGB_SIGNET_CHALLENGE")]
+    pub signet_challenge: Option<SignetChallenge>,
 
-    /// Blockchain to use
+    /// Configuration file.
+    ///
+    /// Values given in the command line or with the environment variables take
+    /// precedence over the values from the file. Defaults to `rgb_cli.toml`
+    /// inside the node data directory; the file is not required to exist.
+    #[clap(long, global = true, env = "RGB_CLI_CONFIG", value_hint = ValueHint::FilePath)]
+    pub config: Option<PathBuf>,
+
+    /// File containing hex-encoded secret key of the client identity, used to
+    /// authenticate to the node with access control enabled and to sign
+    /// contract issue and transfer orders.
+    ///
+    /// Defaults to the value from the configuration file.
     #[clap(
-        short = 'n',
         long,
         global = true,
-        alias = "network",
-        default_value = "signet",
-        env = "RGB_NETWORK"
+        env = "RGB_CLI_IDENTITY_KEY",
+        value_hint = ValueHint::FilePath
     )]
-    pub chain: Chain,
+    pub identity_key: Option<PathBuf>,
+
+    /// Identity key which the node must have, in hex.
+    ///
+    /// The node has to prove it controls the key by signing a random nonce
+    /// before any command is executed, which protects against connecting to
+    /// an impostor node over the network. Defaults to the value from the
+    /// configuration file.
+    #[clap(long, global = true, env = "RGB_CLI_NODE_IDENTITY")]
+    pub node_identity: Option<XOnlyPublicKey>,
+
+    /// Output format of the command results: `yaml` or `json`.
+    ///
+    /// Defaults to the value from the configuration file or `yaml`.
+    #[clap(long, global = true, env = "RGB_CLI_FORMAT")]
+    pub format: Option<OutputFormat>,
+
+    /// Encoding of the contract ids in the command output: `bech32` or `hex`.
+    ///
+    /// Contract ids given as the command arguments are accepted in any of the
+    /// encodings. Defaults to the value from the configuration file or
+    /// `bech32`.
+    #[clap(long, global = true, env = "RGB_CLI_ID_FORMAT")]
+    pub id_format: Option<IdEncoding>,
+
+    /// Character separating groups of three digits in the integer part of
+    /// the decimal amounts.
+    ///
+    /// Applies both to the reported amounts and to the amounts given as the
+    /// command arguments. Defaults to the value from the configuration file;
+    /// no separator is used otherwise.
+    #[clap(long, global = true, env = "RGB_CLI_GROUP_SEPARATOR")]
+    pub group_separator: Option<char>,
+
+    /// Print command results and errors as a single JSON document.
+    ///
+    /// All other messages are written to the standard error, so the standard
+    /// output can be parsed by the scripts. Implies `--format json`.
+    #[clap(long, global = true)]
+    pub json: bool,
 
     /// Set verbosity level.
     ///
@@ -58,14 +120,140 @@ pub struct Opts {
     pub command: Command,
 }
 
+/// Format of the structured command output
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum OutputFormat {
+    #[display("yaml")]
+    Yaml,
+
+    #[display("json")]
+    Json,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self { OutputFormat::Yaml }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            "json" => Ok(OutputFormat::Json),
+            other => Err(format!("unknown output format `{}`", other)),
+        }
+    }
+}
+
+/// Type of the data in the files inspected offline
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum InspectType {
+    /// State transfer consignment
+    #[display("transfer")]
+    Transfer,
+
+    /// Contract consignment
+    #[display("contract")]
+    Contract,
+
+    /// Disclosure of the state transition bundles
+    #[display("disclosure")]
+    Disclosure,
+}
+
+impl FromStr for InspectType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "transfer" => Ok(InspectType::Transfer),
+            "contract" => Ok(InspectType::Contract),
+            "disclosure" => Ok(InspectType::Disclosure),
+            other => Err(format!("unknown consignment type `{}`", other)),
+        }
+    }
+}
+
+/// Template of the wallet descriptor created from an extended public key
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum DescriptorTemplate {
+    /// Native segwit v0 outputs
+    #[display("wpkh")]
+    Wpkh,
+
+    /// Taproot outputs spendable by the key path only
+    #[display("tr")]
+    Tr,
+}
+
+impl FromStr for DescriptorTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "wpkh" => Ok(DescriptorTemplate::Wpkh),
+            "tr" => Ok(DescriptorTemplate::Tr),
+            other => Err(format!("unknown descriptor template `{}`", other)),
+        }
+    }
+}
+
+impl DescriptorTemplate {
+    /// Creates wallet descriptor with the receive and change keychains from
+    /// the extended public key, which may be prefixed with the key origin.
+    pub fn descriptor(self, xpub: &str) -> String { format!("{}({}/<0;1>/*)", self, xpub) }
+}
+
 /// Command-line commands:
 #[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+#[allow(clippy::large_enum_variant)]
 pub enum Command {
+    /// Write configuration file template with all the settings commented out
+    #[display("init")]
+    Init {
+        /// Overwrite the existing configuration file
+        #[clap(long)]
+        force: bool,
+    },
+
     /// Contract management
     #[clap(subcommand)]
     #[display("contract {0}")]
     Contract(ContractCommand),
 
+    /// Schemata known to the node
+    #[clap(subcommand)]
+    #[display("schema {0}")]
+    Schema(SchemaCommand),
+
+    /// Issue new contract from the declarative specification of its genesis.
+    ///
+    /// The specification is a YAML or JSON file declaring the contract
+    /// schema, genesis metadata and assignments of the owned rights, including
+    /// asset allocations, inflation rights and renomination seals. The node
+    /// validates the specification against the schema, creates the genesis
+    /// and registers the issued contract.
+    #[display("issue ...")]
+    Issue {
+        /// YAML or JSON file with the issuance specification
+        #[clap(short, long)]
+        spec: PathBuf,
+
+        /// File with the strict-encoded contract schema. Required if the
+        /// schema is not yet known to the node.
+        #[clap(long)]
+        schema: Option<PathBuf>,
+
+        /// Validate the specification and report the resulting genesis
+        /// allocations and the contract size without registering the contract.
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Output file to save the issued contract consignment
+        contract: Option<PathBuf>,
+    },
+
     /// Information on outpoints
     #[clap(subcommand)]
     #[display("outpoint {0}")]
@@ -75,23 +263,774 @@ pub enum Command {
     #[clap(subcommand)]
     #[display("transfer {0}")]
     Transfer(TransferCommand),
+
+    /// Pay RGB invoice from the wallet tracked by the node.
+    ///
+    /// Selects wallet UTXOs with the allocations and bitcoins for the fee,
+    /// composes and finalizes the transfer, signs the witness transaction with
+    /// the external signer of the node, publishes it and sends the
+    /// consignment to the beneficiary node given in the invoice.
+    ///
+    /// Additional invoices of the same contract given with `--invoice` are
+    /// paid with the same state transition and witness transaction; all the
+    /// beneficiaries receive the same consignment.
+    #[display("pay {invoice}")]
+    Pay {
+        /// Wallet output descriptor with `<0;1>` derivation step, previously
+        /// synchronized with `wallet sync` command. Defaults to the wallet from
+        /// the configuration file.
+        #[clap(short, long)]
+        descriptor: Option<String>,
+
+        /// Fee of the witness transaction, in satoshis
+        #[clap(short, long, default_value = "1000")]
+        fee: u64,
+
+        /// Compose the payment and report the witness transaction size and
+        /// fee, the consignment size and the resulting allocations without
+        /// signing, publishing or sending anything.
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Amount paid to each of the open invoices, which have zero amount
+        /// and leave it to the payer, like donation invoices. Given as a
+        /// decimal number with at most the contract precision of fractional
+        /// digits, like `12.5`.
+        #[clap(short, long)]
+        amount: Option<String>,
+
+        /// Additional invoice of the same contract, paid with the same state
+        /// transition
+        #[clap(short, long = "invoice")]
+        invoices: Vec<Invoice>,
+
+        /// Invoice in form of `rgb:<contract_id>/<amount>/<seal>` with
+        /// optional `transport=<node_addr>`, `signet=<magic>` and
+        /// `key=<pubkey>` query parameters
+        invoice: Invoice,
+
+        /// Output file to save the transfer consignment. Required if the
+        /// invoices do not specify the same transport node of the
+        /// beneficiaries.
+        consignment: Option<PathBuf>,
+    },
+
+    /// Atomic two-party swaps of RGB assets and bitcoins
+    #[clap(subcommand)]
+    #[display("swap {0}")]
+    Swap(SwapCommand),
+
+    /// Payjoin transfers, where the beneficiary contributes its inputs to the
+    /// witness transaction
+    #[clap(subcommand)]
+    #[display("payjoin {0}")]
+    Payjoin(PayjoinCommand),
+
+    /// Bitcoin wallets tracked by the node
+    #[clap(subcommand)]
+    #[display("wallet {0}")]
+    Wallet(WalletCommand),
+
+    /// User-defined labels and tags for contracts, outpoints and transfers
+    #[clap(subcommand)]
+    #[display("label {0}")]
+    Label(LabelCommand),
+
+    /// Store-and-forward relay of consignments for offline recipients
+    #[clap(subcommand)]
+    #[display("relay {0}")]
+    Relay(RelayCommand),
+
+    /// Offline inspection of consignment files
+    #[clap(subcommand)]
+    #[display("consignment {0}")]
+    Consignment(ConsignmentCommand),
+
+    /// Consignments received from the remote nodes which await approval
+    #[clap(subcommand)]
+    #[display("quarantine {0}")]
+    Quarantine(QuarantineCommand),
+
+    /// Contract state kept by the node
+    #[clap(subcommand)]
+    #[display("cache {0}")]
+    Cache(CacheCommand),
+
+    /// Remove consignment containers which are no longer needed from the
+    /// store: containers of accepted consignments and containers kept longer
+    /// than the retention period of the node.
+    #[display("gc")]
+    Gc,
+
+    /// Prune data which retention period configured for the node has passed:
+    /// quarantined consignments, records of completed transfers, contract
+    /// journals and enclosed disclosures.
+    #[display("prune")]
+    Prune {
+        /// Report the data which would be pruned without removing them
+        #[clap(long)]
+        dry_run: bool,
+    },
+
+    /// Report status of the node worker daemons, including the number of
+    /// their restarts and the reason of the last failure.
+    #[display("health")]
+    Health,
+
+    /// Check that the node controls its identity key by asking it to sign a
+    /// random nonce.
+    #[display("ping")]
+    Ping,
+
+    /// Node configuration
+    #[clap(subcommand)]
+    #[display("config {0}")]
+    Config(ConfigCommand),
+
+    /// Commit to the digests of all contracts known to the node, proving to
+    /// auditors what the node knew at a point in time. The commitment may be
+    /// anchored into Bitcoin with the OpenTimestamps calendar configured for
+    /// the node, saving the pending timestamp next to the output file with
+    /// `.ots` extension; it can be upgraded and verified with the standard
+    /// OpenTimestamps tools.
+    #[display("timestamp ...")]
+    Timestamp {
+        /// Output file to save the stash commitment.
+        output: PathBuf,
+
+        /// Submit the commitment to the timestamping calendar.
+        #[clap(long)]
+        anchor: bool,
+    },
+
+    /// Cancel request of another client, which id is reported by the `health`
+    /// command. Queued request is dropped, and request processed by a worker
+    /// is interrupted at the next safe point.
+    #[display("cancel {client_id}")]
+    Cancel {
+        /// Id of the client which request must be cancelled
+        client_id: u64,
+    },
+
+    /// Promote read replica to primary. The node stops following its primary
+    /// and starts accepting requests modifying the stash. Promotion lasts
+    /// until the node restart, so `--replica-of` option must be removed from
+    /// the node configuration to keep it primary.
+    #[display("promote")]
+    Promote,
+
+    /// Access of the client identities to the contracts and wallets on a node
+    /// shared by multiple users
+    #[clap(subcommand)]
+    #[display("access {0}")]
+    Access(AccessCommand),
+
+    /// Show order of a contract issue or a transfer signed by the client,
+    /// which is kept in the node order journal
+    #[display("order {target}")]
+    Order {
+        /// Issued contract or transfer consignment in form of
+        /// `contract:<id>` or `transfer:<id>`
+        target: OrderTarget,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum ConfigCommand {
+    /// Show node settings given in the configuration file, with the
+    /// environment variables or in the command line, together with the
+    /// configuration layer providing each of them: `file`, `env` or `cli`
+    #[display("show")]
+    Show {
+        /// Show also the settings having default values, i.e. the complete
+        /// configuration the node runs with
+        #[clap(long)]
+        effective: bool,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum AccessCommand {
+    /// Grant client identity access to a contract or a wallet
+    #[display("grant {identity} {target}")]
+    Grant {
+        /// Public key of the client identity
+        identity: XOnlyPublicKey,
+
+        /// Contract or wallet in form of `contract:<id>` or
+        /// `wallet:<descriptor>`
+        target: AccessTarget,
+    },
+
+    /// Revoke access of a client identity to a contract or a wallet
+    #[display("revoke {identity} {target}")]
+    Revoke {
+        /// Public key of the client identity
+        identity: XOnlyPublicKey,
+
+        /// Contract or wallet in form of `contract:<id>` or
+        /// `wallet:<descriptor>`
+        target: AccessTarget,
+    },
+
+    /// List contracts and wallets accessible by each of the client identities
+    #[display("list")]
+    List,
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum QuarantineCommand {
+    /// List consignments kept in the quarantine
+    #[display("list")]
+    List,
+
+    /// Take consignment out of the quarantine and consume it, bypassing the
+    /// contract whitelist. Consignment failing validation is returned into
+    /// the quarantine.
+    #[display("approve {consignment_id}")]
+    Approve {
+        /// Id of the quarantined consignment
+        consignment_id: ConsignmentId,
+    },
+
+    /// Validate quarantined consignment once again, for instance after its
+    /// transactions got mined, and consume it if it passes validation
+    #[display("revalidate {consignment_id}")]
+    Revalidate {
+        /// Id of the quarantined consignment
+        consignment_id: ConsignmentId,
+    },
+
+    /// Drop consignment from the quarantine without consuming it
+    #[display("purge {consignment_id}")]
+    Purge {
+        /// Id of the quarantined consignment
+        consignment_id: ConsignmentId,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum SchemaCommand {
+    /// List ids of the schemata known to the node
+    #[display("list")]
+    List,
+
+    /// Report declared types, field formats and owned right states of the
+    /// schema together with the known contracts issued under it
+    #[display("show {schema_id}")]
+    Show {
+        /// Id of the schema to report
+        schema_id: SchemaId,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum CacheCommand {
+    /// Drop contract state and rebuild it from the geneses, state transitions
+    /// and extensions kept in the stash, together with the outpoint index.
+    ///
+    /// Fixes the state which diverged from the stash, for instance after a
+    /// crash of the node. Allocations removed with `outpoint forget` are
+    /// restored by the rebuild.
+    #[display("rebuild")]
+    Rebuild {
+        /// Rebuild state only of the given contract
+        #[clap(short, long = "contract", parse(try_from_str = parse_contract_id))]
+        contract_id: Option<ContractId>,
+    },
+
+    /// Re-validate contracts accepted with an older version of the validation
+    /// rules using the history kept in the stash.
+    ///
+    /// Contracts found invalid are reported, but their state is not changed;
+    /// use `contract forget` to remove them.
+    #[display("revalidate")]
+    Revalidate {
+        /// Re-validate only the given contract, even if it was validated with
+        /// the current rules
+        #[clap(short, long = "contract", parse(try_from_str = parse_contract_id))]
+        contract_id: Option<ContractId>,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum ConsignmentCommand {
+    /// Print structure of a consignment file: genesis, anchored state
+    /// transition bundles, state extensions and endpoints.
+    ///
+    /// Transfer and contract consignments and disclosures are supported. Does
+    /// not require connection to the node.
+    #[display("inspect ...")]
+    Inspect {
+        /// Save consignment graph in graphviz DOT format to the file
+        #[clap(long)]
+        dot: Option<PathBuf>,
+
+        /// Type of the file data: `transfer`, `contract` or `disclosure`.
+        ///
+        /// If not given, the type is detected from the header of ASCII-armored
+        /// files. Binary files are read as consignments, which are considered
+        /// contract consignments if they have no endpoints, or as disclosures
+        /// if they are not valid consignments.
+        #[clap(short = 't', long = "type")]
+        data_type: Option<InspectType>,
+
+        /// Consignment file to inspect
+        consignment: PathBuf,
+    },
+
+    /// Export consignment file as a numbered sequence of QR codes for
+    /// scanning by an air-gapped device.
+    ///
+    /// Codes beyond the number of consignment fragments are fountain-encoded
+    /// mixes of several fragments, so the consignment can be assembled from
+    /// any sufficient subset of the codes. Does not require connection to the
+    /// node.
+    #[display("qr ...")]
+    Qr {
+        /// Maximal number of consignment bytes per QR code
+        #[clap(long, default_value = "200")]
+        fragment_len: usize,
+
+        /// Total number of QR codes to produce; defaults to the number of
+        /// consignment fragments
+        #[clap(long)]
+        parts: Option<u32>,
+
+        /// Directory to save QR codes to as numbered SVG images instead of
+        /// printing them to the terminal
+        #[clap(short, long, value_hint = ValueHint::DirPath)]
+        output_dir: Option<PathBuf>,
+
+        /// Consignment file to export
+        consignment: PathBuf,
+    },
+
+    /// Assemble consignment from the contents of QR codes produced by `qr`
+    /// command and scanned in any order.
+    ///
+    /// Does not require connection to the node.
+    #[display("assemble ...")]
+    Assemble {
+        /// Text file with the scanned QR code contents, one per line
+        parts: PathBuf,
+
+        /// Output file to save the assembled consignment to
+        consignment: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum RelayCommand {
+    /// Print relay identifier of the recipient, i.e. x-only public key which
+    /// senders seal the consignments to.
+    ///
+    /// Does not require connection to the node.
+    #[display("id ...")]
+    Id {
+        /// File containing hex-encoded secret key of the recipient
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        key: PathBuf,
+    },
+
+    /// Seal consignment to the recipient key and deposit it to the relay
+    /// node, which holds it until the recipient picks it up.
+    #[display("deposit {recipient} ...")]
+    Deposit {
+        /// Number of seconds the relay holds the consignment. Defaults to the
+        /// maximum time configured for the relay.
+        #[clap(long)]
+        ttl: Option<u32>,
+
+        /// Do not forward the consignment to the peer relays of the node
+        #[clap(long)]
+        no_forward: bool,
+
+        /// Relay identifier of the recipient
+        recipient: XOnlyPublicKey,
+
+        /// Consignment file to deposit
+        consignment: PathBuf,
+    },
+
+    /// Pick up consignments held by the relay node for the recipient, saving
+    /// them as `<envelope_id>.rgbc` files. Received consignments are removed
+    /// from the relay.
+    #[display("pickup ...")]
+    Pickup {
+        /// File containing hex-encoded secret key of the recipient
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        key: PathBuf,
+
+        /// Directory to save the consignments to
+        #[clap(short, long, default_value = ".", value_hint = ValueHint::DirPath)]
+        output_dir: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum LabelCommand {
+    /// Assign label and tags to an object, replacing the existing ones.
+    ///
+    /// If neither label nor tags are given, removes the annotation.
+    #[display("set {target} ...")]
+    Set {
+        /// Object to annotate in form of `contract:<id>`, `outpoint:<txid>:<vout>`
+        /// or `transfer:<consignment_id>`
+        target: AnnotationTarget,
+
+        /// Free-form label
+        #[clap(short, long)]
+        label: Option<String>,
+
+        /// Tag to assign; can be used multiple times
+        #[clap(short, long = "tag")]
+        tags: Vec<String>,
+    },
+
+    /// List annotations for the given objects, or all known annotations if no
+    /// objects are given
+    #[display("list ...")]
+    List {
+        /// Objects to list annotations for
+        targets: Vec<AnnotationTarget>,
+    },
+}
+
+/// Command-line swap subcommands:
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum SwapCommand {
+    /// Propose swap to a counterparty (taker).
+    ///
+    /// The PSBT must spend the maker inputs and, when the maker gives an RGB
+    /// asset, contain its state transition prepared with `transfer combine`.
+    /// The saved offer must be passed to the taker.
+    #[display("propose {give} for {take} ...")]
+    Propose {
+        /// Asset given to the taker in form of
+        /// `<amount>@<contract_id>:<seal>[,<seal>...]` for RGB assets or
+        /// `<amount>sat@<address>` for bitcoins
+        give: SwapLeg,
+
+        /// Asset received from the taker, in the same form as `give`
+        take: SwapLeg,
+
+        /// Number of seconds during which the swap must be completed
+        #[clap(short, long, default_value = "3600")]
+        timeout: u32,
+
+        /// Consignment draft for the given RGB asset, prepared with `transfer
+        /// compose` command
+        #[clap(short, long)]
+        consignment: Option<PathBuf>,
+
+        /// PSBT of the swap witness transaction with the maker inputs
+        psbt: PathBuf,
+
+        /// Output file to save the swap offer
+        offer: PathBuf,
+    },
+
+    /// Accept swap offer.
+    ///
+    /// The PSBT must be the one from the offer extended with the taker
+    /// inputs and, when the taker gives an RGB asset, its state transition.
+    /// Saves the swap PSBT and the consignment, which must be passed to the
+    /// maker.
+    #[display("accept ...")]
+    Accept {
+        /// Consignment draft for the given RGB asset, prepared with `transfer
+        /// compose` command
+        #[clap(short, long)]
+        consignment: Option<PathBuf>,
+
+        /// Swap offer received from the maker
+        offer: PathBuf,
+
+        /// PSBT of the swap witness transaction with both maker and taker
+        /// inputs
+        psbt_in: PathBuf,
+
+        /// Output file to save the swap PSBT. If not given, the source PSBT
+        /// file is overwritten.
+        #[clap(short = 'o', long = "out")]
+        psbt_out: Option<PathBuf>,
+
+        /// Output file to save consignment for the maker
+        consignment_out: Option<PathBuf>,
+    },
+
+    /// Complete swap, checking PSBT and consignment received from the
+    /// counterparty against the swap terms.
+    ///
+    /// Once checked, the PSBT can be signed; the consignment for the asset
+    /// received by this party must be consumed after the swap transaction is
+    /// mined.
+    #[display("complete {swap_id} ...")]
+    Complete {
+        /// Swap id
+        swap_id: SwapId,
+
+        /// Consignment received from the taker (for the maker only)
+        #[clap(short, long)]
+        consignment: Option<PathBuf>,
+
+        /// PSBT received from the counterparty
+        psbt_in: PathBuf,
+
+        /// Output file to save the checked PSBT. If not given, the source PSBT
+        /// file is overwritten.
+        #[clap(short = 'o', long = "out")]
+        psbt_out: Option<PathBuf>,
+
+        /// Sign the PSBT with the external signer configured for the node.
+        #[clap(long)]
+        sign: bool,
+
+        /// Output file to save the consignment for the received RGB asset
+        consignment_out: Option<PathBuf>,
+    },
+
+    /// Abort swap; the node will refuse to complete it.
+    #[display("abort {swap_id}")]
+    Abort {
+        /// Swap id
+        swap_id: SwapId,
+    },
+}
+
+/// Command-line payjoin subcommands:
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum PayjoinCommand {
+    /// Propose payjoin to the beneficiary.
+    ///
+    /// The PSBT must spend the payer inputs and contain the state transition
+    /// for the paid asset prepared with `transfer combine`. The saved proposal
+    /// must be passed to the beneficiary.
+    #[display("propose ...")]
+    Propose {
+        /// Consignment draft for the paid asset, prepared with `transfer
+        /// compose` command
+        consignment: PathBuf,
+
+        /// PSBT of the witness transaction with the payer inputs
+        psbt: PathBuf,
+
+        /// Output file to save the payjoin proposal
+        proposal: PathBuf,
+    },
+
+    /// Contribute wallet UTXOs to the payjoin proposed by the payer.
+    ///
+    /// The contributed UTXOs and a change output are added to the witness
+    /// transaction; all RGB state assigned to the UTXOs is moved to the
+    /// change output with blank state transitions. Saves the payjoin
+    /// contribution, which must be passed back to the payer.
+    #[display("contribute ...")]
+    Contribute {
+        /// Wallet output descriptor with `<0;1>` derivation step, previously
+        /// synchronized with `wallet sync` command. Defaults to the wallet from
+        /// the configuration file.
+        #[clap(short, long)]
+        descriptor: Option<String>,
+
+        /// Fee paid by the beneficiary for its inputs and the change output,
+        /// in satoshis
+        #[clap(short, long, default_value = "0")]
+        fee: u64,
+
+        /// Beneficiary blinded TXO seal from the invoice paid by the payjoin.
+        #[clap(short, long = "endseal", required = true)]
+        endseals: Vec<SealEndpoint>,
+
+        /// Sign the beneficiary inputs with the external signer configured
+        /// for the node.
+        #[clap(long)]
+        sign: bool,
+
+        /// Output file to save the final consignment for the paid asset, which
+        /// must be consumed once the witness transaction is mined.
+        #[clap(short, long)]
+        consignment: Option<PathBuf>,
+
+        /// Payjoin proposal received from the payer
+        proposal: PathBuf,
+
+        /// Output file to save the payjoin contribution
+        contribution: PathBuf,
+
+        /// Wallet outpoints contributed to the witness transaction
+        #[clap(required = true)]
+        outpoints: Vec<OutPoint>,
+    },
+
+    /// Complete payjoin, checking the joint PSBT received from the
+    /// beneficiary against the proposal.
+    ///
+    /// Once checked, the PSBT can be signed by the payer and published.
+    #[display("complete ...")]
+    Complete {
+        /// Payjoin contribution received from the beneficiary
+        contribution: PathBuf,
+
+        /// Output file to save the joint PSBT
+        psbt_out: PathBuf,
+
+        /// Sign the PSBT with the external signer configured for the node.
+        #[clap(long)]
+        sign: bool,
+    },
+}
+
+#[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+pub enum WalletCommand {
+    /// Scan the blockchain for the wallet UTXOs and report wallet balance.
+    ///
+    /// The wallet is registered with the node on the first synchronization;
+    /// subsequent runs continue the address discovery from the last used
+    /// derivation index.
+    #[display("sync ...")]
+    Sync {
+        /// Number of consequent unused addresses after which the scan stops
+        #[clap(short, long, default_value = "20")]
+        gap_limit: u16,
+
+        /// Re-scan the wallet starting from the first derivation index
+        #[clap(long)]
+        rescan: bool,
+
+        /// Wallet output descriptor. Descriptors with `<0;1>` derivation step
+        /// are scanned for both receive and change addresses. Defaults to the
+        /// wallet from the configuration file.
+        descriptor: Option<String>,
+    },
+
+    /// Report balance of the previously synchronized wallet, including RGB
+    /// allocations on its UTXOs
+    #[display("balance ...")]
+    Balance {
+        /// Wallet output descriptor. Defaults to the wallet from the
+        /// configuration file.
+        descriptor: Option<String>,
+    },
+
+    /// Register wallet with the node without scanning the blockchain, so its
+    /// addresses can be listed before the wallet receives any funds.
+    #[display("create ...")]
+    Create {
+        /// Template of the descriptor created from the extended public key:
+        /// `wpkh` or `tr`. Ignored if an output descriptor is given.
+        #[clap(short, long, default_value = "wpkh")]
+        template: DescriptorTemplate,
+
+        /// Extended public key of the account, optionally prefixed with the
+        /// key origin, or a wallet output descriptor
+        key: String,
+    },
+
+    /// List wallets created or synchronized with the node
+    #[display("list")]
+    List,
+
+    /// List wallet addresses with their derivation indexes, together with
+    /// the wallet UTXOs on them and contracts having state assigned to these
+    /// UTXOs. UTXOs are reported as of the last wallet synchronization.
+    #[display("addresses ...")]
+    Addresses {
+        /// Number of unused addresses listed after the last used one in each
+        /// keychain
+        #[clap(short, long, default_value = "1")]
+        unused: u16,
+
+        /// Wallet output descriptor. Defaults to the wallet from the
+        /// configuration file.
+        descriptor: Option<String>,
+    },
 }
 
 /// Command-line OUTPOINT subcommands:
 #[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
 pub enum OutpointCommand {
-    /// Return known outpoint state
+    /// Return known outpoint state together with the spent status of the
+    /// wallet outpoints
     #[display("state ...")]
     State {
         /// Outpoints to return the state for
         #[clap(short, long = "outpoint")]
         outpoints: Vec<OutPoint>,
+
+        /// Return the state of the UTXOs controlled by the address. Requires
+        /// the wallet having the address to be synchronized with `wallet sync`
+        #[clap(short, long = "address")]
+        addresses: Vec<Address>,
+
+        /// Return the state of the UTXOs controlled by the scriptPubKey, given
+        /// in hex. Requires the wallet having the script to be synchronized
+        /// with `wallet sync`
+        #[clap(short, long = "script")]
+        scripts: Vec<Script>,
+    },
+
+    /// Remove contract state assigned to the wallet outpoints which were spent
+    /// without transferring it.
+    ///
+    /// The state is lost once the outpoint is spent by a transaction not
+    /// committing to a state transition (for instance, by a wallet unaware of
+    /// RGB). The node checks that each outpoint belongs to the wallet and is
+    /// spent by a mined transaction, and refuses outpoints spent by
+    /// transactions with a disclosure which is not yet enclosed.
+    #[display("forget ...")]
+    Forget {
+        /// Wallet output descriptor with `<0;1>` derivation step, previously
+        /// synchronized with `wallet sync` command. Defaults to the wallet from
+        /// the configuration file.
+        #[clap(short, long)]
+        descriptor: Option<String>,
+
+        /// Spent outpoints to remove the state from
+        #[clap(required = true)]
+        outpoints: Vec<OutPoint>,
     },
 }
 
 /// Command-line transfer subcommands:
 #[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
 pub enum TransferCommand {
+    /// List incoming and outgoing transfers known to the node
+    #[display("list ...")]
+    List {
+        /// Report only transfers of the given contract
+        #[clap(short, long, parse(try_from_str = parse_contract_id))]
+        contract_id: Option<ContractId>,
+
+        /// Report only transfers in the given direction (`incoming` or
+        /// `outgoing`)
+        #[clap(short, long)]
+        direction: Option<TransferDirection>,
+
+        /// Report only transfers with the given status (`finalized`, `sent`,
+        /// `published`, `pending`, `accepted`, `rejected` or `accelerated`)
+        #[clap(short, long)]
+        status: Option<TransferStatus>,
+    },
+
+    /// Report tips of the locally known contract history, which can be given
+    /// to the payer to exclude this history from the transfer consignment
+    #[display("history {contract_id}")]
+    History {
+        /// Contract id to report history for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// Get receipt for an incoming transfer signed by the node key, which can
+    /// be handed to the payer as a proof that the payment was accepted
+    #[display("receipt {consignment_id}")]
+    Receipt {
+        /// Id of the transfer consignment, as given in the transfer list
+        consignment_id: ConsignmentId,
+    },
+
     /// Build state transfer consignment draft
     #[display("compose {contract_id} ...")]
     Compose {
@@ -99,11 +1038,43 @@ pub enum TransferCommand {
         #[clap(short = 't', long = "node-type")]
         node_types: Vec<TransitionType>,
 
+        /// Tips of the contract history already known to the beneficiary, as
+        /// reported by its `transfer history` command. The history behind them
+        /// is not included into the consignment.
+        #[clap(short, long, parse(try_from_str = parse_node_id))]
+        known: Vec<NodeId>,
+
+        /// Number of seconds after which the node abandons the request
+        #[clap(long)]
+        timeout: Option<u32>,
+
+        /// Wallet output descriptor, previously synchronized with `wallet
+        /// sync` command, which UTXOs will be spent by the transfer. The node
+        /// expands the wallet into the UTXOs found during its last
+        /// synchronization, so the outpoints need not be listed.
+        #[clap(short, long, conflicts_with = "outpoints")]
+        wallet: Option<String>,
+
+        /// First derivation index of the wallet addresses which UTXOs are
+        /// spent; starts from the first address if not given
+        #[clap(long, requires = "wallet")]
+        from_index: Option<u32>,
+
+        /// Last derivation index of the wallet addresses which UTXOs are
+        /// spent; all addresses after the first index if not given
+        #[clap(long, requires = "wallet")]
+        to_index: Option<u32>,
+
+        /// Save the consignment in ASCII-armored form
+        #[clap(long)]
+        armor: bool,
+
         /// Contract id to read source
+        #[clap(parse(try_from_str = parse_contract_id))]
         contract_id: ContractId,
 
         /// Bitcoin transaction UTXOs which will be spent by the transfer
-        #[clap(required = true)]
+        #[clap(required_unless_present = "wallet")]
         outpoints: Vec<OutPoint>,
 
         /// Output file to save consignment prototype to
@@ -126,6 +1097,7 @@ pub enum TransferCommand {
     #[display("combine ...")]
     Combine {
         /// Contract id under which the main state transfer is happening.
+        #[clap(parse(try_from_str = parse_contract_id))]
         contract_id: ContractId,
 
         /// File containing main state transition for the transfer.
@@ -144,6 +1116,45 @@ pub enum TransferCommand {
         outpoints: Vec<OutPoint>,
     },
 
+    /// Move all allocations of the contract assigned to the PSBT inputs to a
+    /// single output of the PSBT transaction.
+    ///
+    /// Adds to the PSBT blank state transition for the contract and for all
+    /// other contracts with state assigned to the PSBT inputs, then finalizes
+    /// the self-transfer. The output must belong to the wallet; once the
+    /// transaction is signed and published, the saved consignment must be
+    /// consumed to update the contract state.
+    ///
+    /// Useful before large payments and to reduce the size of the
+    /// consignments for the future transfers.
+    #[display("consolidate --contract {contract_id} ...")]
+    Consolidate {
+        /// Contract which allocations are consolidated.
+        #[clap(short, long = "contract", parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Output of the PSBT transaction which will receive all the
+        /// allocations.
+        #[clap(long, default_value = "0")]
+        vout: u32,
+
+        /// Method for closing the single-use-seal of the receiving output.
+        #[clap(long, default_value = "tapret1st")]
+        method: CloseMethod,
+
+        /// Input file containing PSBT of the witness transaction spending
+        /// the outpoints with the allocations.
+        psbt_in: PathBuf,
+
+        /// Output file to save the finalized PSBT. If not given, the source
+        /// PSBT file is overwritten.
+        #[clap(short = 'o', long = "out")]
+        psbt_out: Option<PathBuf>,
+
+        /// Output file to save the self-transfer consignment.
+        consignment: PathBuf,
+    },
+
     /// Finalize and (optionally) send state transfer consignment to beneficiary.
     ///
     /// Finalize consignment with the information from the finalized PSBT file,
@@ -155,6 +1166,11 @@ pub enum TransferCommand {
         #[clap(short, long)]
         send: Option<NodeAddr>,
 
+        /// Encrypt the consignment sent to the beneficiary to this key, taken
+        /// from the `key` parameter of the invoice.
+        #[clap(long)]
+        encrypt_to: Option<XOnlyPublicKey>,
+
         /// Beneficiary blinded TXO seal - or witness transaction output numbers
         /// containing allocations for the beneficiary.
         #[clap(short, long = "endseal", required = true)]
@@ -168,6 +1184,16 @@ pub enum TransferCommand {
         #[clap(short = 'o', long = "out")]
         psbt_out: Option<PathBuf>,
 
+        /// Sign the finalized PSBT with the external signer configured for the
+        /// node.
+        #[clap(long)]
+        sign: bool,
+
+        /// Report the PSBT and consignment sizes, the fee and the resulting
+        /// allocations without saving the files or sending the consignment.
+        #[clap(long)]
+        dry_run: bool,
+
         /// State transfer consignment draft file prepared with `compose` command.
         consignment_in: PathBuf,
 
@@ -179,10 +1205,32 @@ pub enum TransferCommand {
     /// Validate incoming transfer consignment and consume it into the stash.
     #[display("consume ...")]
     Consume {
-        /// Consume even if the endpoint witness transaction is not yet mined.
+        /// Consume even if the endpoint witness transaction is not yet mined,
+        /// or the contract was forgotten with a tombstone.
         #[clap(short, long)]
         force: bool,
 
+        /// Validate the whole contract history, ignoring imported checkpoint
+        /// of the contract state. Requires consignment containing the full
+        /// history.
+        #[clap(long)]
+        full: bool,
+
+        /// Upload the consignment file to the node as is, without decoding it
+        /// locally.
+        #[clap(short, long)]
+        upload: bool,
+
+        /// Number of seconds after which the node abandons the request
+        #[clap(long)]
+        timeout: Option<u32>,
+
+        /// File with proofs of the witness transactions inclusion into the
+        /// blockchain, prepared with `transfer prove` command. Required when
+        /// the node runs in offline mode.
+        #[clap(long)]
+        proofs: Option<PathBuf>,
+
         /// State transfer consignment send by the payee.
         consignment: PathBuf,
 
@@ -198,10 +1246,50 @@ pub enum TransferCommand {
         #[clap(short, long)]
         reveal: Option<Reveal>,
     },
+
+    /// Prepare proofs of the consignment witness transactions inclusion into
+    /// the blockchain, allowing its validation by a node running offline.
+    #[display("prove ...")]
+    Prove {
+        /// Height of the header checkpoint of the node which will validate the
+        /// consignment. Proofs include all block headers following it.
+        #[clap(long = "from")]
+        from_height: u32,
+
+        /// State transfer consignment to prepare proofs for.
+        consignment: PathBuf,
+
+        /// Output file to save the proofs.
+        output: PathBuf,
+    },
+
+    /// Accelerate pending incoming transfer which witness transaction is stuck
+    /// in the mempool.
+    ///
+    /// Spends the wallet outputs of the witness transaction with a child
+    /// transaction paying the fee for both transactions (CPFP), signs it with
+    /// the external signer of the node and publishes it.
+    #[display("accelerate {consignment_id} ...")]
+    Accelerate {
+        /// Wallet output descriptor with `<0;1>` derivation step, previously
+        /// synchronized with `wallet sync` command. Defaults to the wallet from
+        /// the configuration file.
+        #[clap(short, long)]
+        descriptor: Option<String>,
+
+        /// Fee rate of the witness and child transactions package, in satoshis
+        /// per virtual byte
+        #[clap(short, long)]
+        fee_rate: u64,
+
+        /// Id of the incoming transfer consignment
+        consignment_id: ConsignmentId,
+    },
 }
 
 /// Command-line constract subcommands:
 #[derive(Subcommand, Clone, PartialEq, Eq, Debug, Display)]
+#[allow(clippy::large_enum_variant)]
 pub enum ContractCommand {
     /// List all known contract ids
     #[display("list")]
@@ -211,20 +1299,147 @@ pub enum ContractCommand {
     #[display("register ...")]
     Register {
         /// Force importing of valid contract containing non-mined transactions
+        /// or forgotten with a tombstone
         #[clap(long)]
         force: bool,
 
+        /// Number of seconds after which the node abandons the request
+        #[clap(long)]
+        timeout: Option<u32>,
+
+        /// File with proofs of the witness transactions inclusion into the
+        /// blockchain, prepared with `contract prove` command. Required when
+        /// the node runs in offline mode.
+        #[clap(long)]
+        proofs: Option<PathBuf>,
+
+        /// Contract source in Bech32m encoding (starting with `rgbc1...`)
+        contract: Contract,
+    },
+
+    /// Import contract from its genesis, as shared by the wallets which
+    /// exchange the contract genesis instead of the contract source.
+    ///
+    /// The contract schema must be already known to the node.
+    #[display("import ...")]
+    Import {
+        /// Contract genesis in Bech32 encoding, possibly ASCII-armored
+        genesis: String,
+    },
+
+    /// Prepare proofs of the contract witness transactions inclusion into the
+    /// blockchain, allowing its registration by a node running offline.
+    #[display("prove ...")]
+    Prove {
+        /// Height of the header checkpoint of the node which will register the
+        /// contract. Proofs include all block headers following it.
+        #[clap(long = "from")]
+        from_height: u32,
+
         /// Contract source in Bech32m encoding (starting with `rgbc1...`)
         contract: Contract,
+
+        /// Output file to save the proofs.
+        output: PathBuf,
     },
 
     /// Query contract state
     #[display("state {contract_id}")]
     State {
         /// Contract id to read state
+        #[clap(parse(try_from_str = parse_contract_id))]
         contract_id: ContractId,
     },
 
+    /// Report contract amounts allocated to the wallet and/or outpoints at
+    /// the given block height.
+    ///
+    /// The balance is computed by replaying the contract history mined up to
+    /// that height; only revealed amounts are accounted. If neither wallet nor
+    /// outpoints are given, all known allocations are reported.
+    #[display("balance {contract_id} {height} ...")]
+    Balance {
+        /// Output descriptor of a wallet previously synchronized with `wallet
+        /// sync`
+        #[clap(short, long)]
+        descriptor: Option<String>,
+
+        /// Outpoints to account allocations for
+        #[clap(short, long = "outpoint")]
+        outpoints: Vec<OutPoint>,
+
+        /// Contract id to report the balance for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Height of the last block which state transitions are accounted
+        height: u32,
+    },
+
+    /// Report aggregate statistics of the contract: number of allocations and
+    /// outpoints holding them, history size and depth, and the amount of data
+    /// kept in the stash
+    #[display("stats {contract_id}")]
+    Stats {
+        /// Contract id to report statistics for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// Show dependency graph of the contract: contracts sharing its root
+    /// schema and contracts referenced from its metadata or referencing it,
+    /// like the contracts which assets were burned to issue it. If no
+    /// contract is given, the graph of all known contracts is shown.
+    #[display("deps ...")]
+    Deps {
+        /// Contract id to show dependencies for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: Option<ContractId>,
+    },
+
+    /// List allocations of the contract state known to the node. Allocations
+    /// on the unspent outputs of the wallets registered with the node are
+    /// marked as owned; the rest belong to third parties. Outpoints spent since
+    /// the last wallet synchronization are reported with the spending
+    /// transaction.
+    #[display("allocations {contract_id}")]
+    Allocations {
+        /// Contract id to list allocations for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// List contracts taking the most of the store space, with the size of
+    /// their history, anchors and consignment containers kept by the node
+    #[display("usage")]
+    Usage {
+        /// Maximal number of contracts to list; zero lists all contracts
+        #[clap(short, long, default_value = "20")]
+        limit: u16,
+    },
+
+    /// Report canonical digest of the contract history and state known to the
+    /// node, which can be compared with the digest reported by another node
+    #[display("digest {contract_id}")]
+    Digest {
+        /// Contract id to report the digest for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// Compare contract history and state known to the node with another
+    /// node, listing transition bundles known to one of the nodes only
+    #[display("compare {contract_id} {remote}")]
+    Compare {
+        /// Contract id to compare
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// ZMQ socket of the RPC interface of the other node
+        #[clap(value_hint = ValueHint::FilePath)]
+        remote: ServiceAddr,
+    },
+
     /// Request contract consignment
     #[display("consignment {contract_id} ...")]
     Consignment {
@@ -232,7 +1447,16 @@ pub enum ContractCommand {
         #[clap(short = 't', long = "node-type")]
         node_types: Vec<TransitionType>,
 
+        /// Number of seconds after which the node abandons the request
+        #[clap(long)]
+        timeout: Option<u32>,
+
+        /// Print the consignment in ASCII-armored form instead of Bech32
+        #[clap(long)]
+        armor: bool,
+
         /// Contract id to read source
+        #[clap(parse(try_from_str = parse_contract_id))]
         contract_id: ContractId,
     },
 
@@ -244,6 +1468,7 @@ pub enum ContractCommand {
         node_types: Vec<TransitionType>,
 
         /// Contract id to read state
+        #[clap(parse(try_from_str = parse_contract_id))]
         contract_id: ContractId,
 
         /// Input file containing PSBT of the transfer witness transaction.
@@ -254,4 +1479,207 @@ pub enum ContractCommand {
         #[clap(short = 'o', long = "out")]
         psbt_out: Option<PathBuf>,
     },
+
+    /// Create checkpoint of the contract state signed by the contract issuer.
+    #[display("checkpoint {contract_id} {height} ...")]
+    Checkpoint {
+        /// Contract id to create checkpoint for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Height of the last block which state transitions must be included
+        /// into the checkpoint.
+        height: u32,
+
+        /// Output file to save the signed checkpoint.
+        output: PathBuf,
+
+        /// File containing hex-encoded issuer secret key used to sign the
+        /// checkpoint. If not given, the checkpoint is signed by the issuer
+        /// signer configured for the node, keeping the key off the host.
+        #[clap(long, value_hint = ValueHint::FilePath)]
+        issuer_key: Option<PathBuf>,
+    },
+
+    /// Show or change the policy applied by the node to the contract transfers.
+    #[display("policy {contract_id}")]
+    Policy {
+        /// Contract id to apply the policy to
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Refuse to finalize transfers creating fungible allocations smaller
+        /// than the given amount (in atomic units). Zero disables the check.
+        #[clap(long)]
+        min_allocation: Option<u64>,
+    },
+
+    /// Import contract state checkpoint signed by a trusted issuer.
+    ///
+    /// Transfers of the contract will be validated only down to the
+    /// checkpoint.
+    #[display("import-checkpoint ...")]
+    ImportCheckpoint {
+        /// File containing the signed checkpoint.
+        checkpoint: PathBuf,
+    },
+
+    /// Reveal seal of the contract assignments accepted with the seal being
+    /// concealed.
+    ///
+    /// Updates the contract state with the revealed allocations. If no
+    /// accepted state transition has the seal, the node keeps the reveal and
+    /// applies it once a consignment with the seal is accepted.
+    #[display("reveal {contract_id} ...")]
+    Reveal {
+        /// Contract id to reveal the seal for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Seal reveal information in the format
+        /// `<close_method>@<outpoint>#<blinding_factor>`
+        reveal: Reveal,
+    },
+
+    /// Create invoice for receiving the contract asset on a wallet outpoint.
+    ///
+    /// The node generates the seal blinding factor from its entropy source
+    /// and keeps the seal reveal, so the received allocation is known once
+    /// the consignment paying the invoice is accepted. The invoice is refused
+    /// if the entropy source of the node fails its health check.
+    ///
+    /// Invoices are kept in the invoice registry of the node, which records
+    /// the amounts they receive; see `contract invoices`.
+    #[display("invoice {contract_id} {amount} {outpoint} ...")]
+    Invoice {
+        /// Method for closing the single-use-seal of the outpoint.
+        #[clap(long, default_value = "tapret1st")]
+        method: CloseMethod,
+
+        /// Bifrost node which the payer sends the transfer consignment to.
+        #[clap(short, long)]
+        transport: Option<NodeAddr>,
+
+        /// Decimal amount which the payment may fall short of the invoice
+        /// amount by and still pay the invoice.
+        #[clap(long, default_value = "0")]
+        underpayment: String,
+
+        /// Decimal amount which the payment may exceed the invoice amount by
+        /// and still pay the invoice.
+        #[clap(long, default_value = "0")]
+        overpayment: String,
+
+        /// Contract id of the requested asset
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Amount to receive, as a decimal number with at most the contract
+        /// precision of fractional digits, like `12.5`. Zero creates an open
+        /// invoice, like a donation one, which accepts any amount chosen by
+        /// the payer.
+        amount: String,
+
+        /// Wallet outpoint receiving the asset
+        outpoint: OutPoint,
+    },
+
+    /// List invoices of the contract created by the node, with the amounts
+    /// they have received and their payment status.
+    #[display("invoices {contract_id}")]
+    Invoices {
+        /// Contract id to list invoices for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// Export reveal data for the contract seals defined on the given
+    /// outpoints, allowing the counterparty to reveal them.
+    ///
+    /// Reveal data contain blinding secrets of the seals; the file is created
+    /// readable by its owner only and existing files are never overwritten.
+    #[display("export-reveals {contract_id} ...")]
+    ExportReveals {
+        /// Outpoints which seals must be revealed
+        #[clap(short, long = "outpoint", required = true)]
+        outpoints: Vec<OutPoint>,
+
+        /// Contract id to export reveal data for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// File to save reveal data to
+        #[clap(value_hint = ValueHint::FilePath)]
+        output: PathBuf,
+    },
+
+    /// Import reveal data exported by the counterparty, revealing the contract
+    /// seals which were accepted in concealed form
+    #[display("import-reveals ...")]
+    ImportReveals {
+        /// File with reveal data
+        #[clap(value_hint = ValueHint::FilePath)]
+        file: PathBuf,
+    },
+
+    /// List consignments accepted for the contract, which are recorded into
+    /// the contract journal by nodes working with regtest
+    #[display("journal {contract_id}")]
+    Journal {
+        /// Contract id to list the journal for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
+
+    /// Rewind contract state to the moment before the consignment was
+    /// accepted, reporting the state change (regtest only).
+    ///
+    /// The contract is removed and the consignments recorded into its journal
+    /// before the given one are applied again. Transfer records, policies and
+    /// annotations of the contract are lost.
+    #[display("rewind {contract_id} {consignment_id}")]
+    Rewind {
+        /// Contract id to rewind
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Consignment from the contract journal, which must be unapplied
+        /// together with all the following consignments
+        consignment_id: ConsignmentId,
+    },
+
+    /// Apply consignments from the contract journal again in the given order,
+    /// reporting the state change (regtest only)
+    #[display("replay {contract_id} ...")]
+    Replay {
+        /// Contract id to replay consignments for
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+
+        /// Consignments from the contract journal to apply
+        #[clap(required = true)]
+        consignments: Vec<ConsignmentId>,
+    },
+
+    /// Remove contract from the node together with its history, state,
+    /// transfer records and other derived data.
+    ///
+    /// Anchors and disclosures are kept, since they may be shared with other
+    /// contracts. The removal can't be undone; the contract has to be
+    /// registered again to be used.
+    #[display("forget {contract_id}")]
+    Forget {
+        /// Keep a tombstone making the node refuse re-import of the contract
+        /// unless it is forced
+        #[clap(long)]
+        tombstone: bool,
+
+        /// Do not ask for confirmation
+        #[clap(short, long)]
+        yes: bool,
+
+        /// Contract id to remove
+        #[clap(parse(try_from_str = parse_contract_id))]
+        contract_id: ContractId,
+    },
 }
@@ -0,0 +1,176 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+use colored::Colorize;
+use rgb_rpc::{IdEncoding, ProgressStep};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::opts::OutputFormat;
+
+impl OutputFormat {
+    /// Serializes structured command output
+    pub fn serialize(self, data: &impl Serialize) -> String {
+        match self {
+            OutputFormat::Yaml => serde_yaml::to_string(data).expect("broken output serde"),
+            OutputFormat::Json => serde_json::to_string_pretty(data).expect("broken output serde"),
+        }
+    }
+}
+
+/// Renders contract ids inside the structured command output with the given
+/// encoding. Rust types serialize contract ids in Bech32, so only the string
+/// values and map keys need to be converted.
+fn render_ids(ids: IdEncoding, value: Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(ids.render_text(&s)),
+        Value::Array(items) => {
+            Value::Array(items.into_iter().map(|item| render_ids(ids, item)).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (ids.render_text(&key), render_ids(ids, value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Output of a command.
+///
+/// In JSON mode the command results are collected into a single JSON object,
+/// which is printed to the standard output once the command completes together
+/// with the command error, if any. Human-readable messages are written to the
+/// standard error in this mode.
+pub struct Output {
+    format: OutputFormat,
+    ids: IdEncoding,
+    json: bool,
+    document: RefCell<Map<String, Value>>,
+}
+
+impl Output {
+    pub fn new(format: OutputFormat, ids: IdEncoding, json: bool) -> Output {
+        Output {
+            format: if json { OutputFormat::Json } else { format },
+            ids,
+            json,
+            document: default!(),
+        }
+    }
+
+    /// Reports human-readable message
+    pub fn message(&self, msg: impl Display) {
+        let msg = self.ids.render_text(&msg.to_string());
+        match self.json {
+            true => eprintln!("{}", msg),
+            false => println!("{}", msg),
+        }
+    }
+
+    /// Reports warning, which does not fail the command. In JSON mode the
+    /// warnings are collected under the `warnings` key.
+    pub fn warning(&self, msg: impl Display) {
+        let msg = self.ids.render_text(&msg.to_string());
+        if !self.json {
+            eprintln!("{}: {}", "Warning".bold().bright_yellow(), msg);
+            return;
+        }
+        let mut document = self.document.borrow_mut();
+        let warnings = document.entry("warnings").or_insert_with(|| Value::Array(vec![]));
+        if let Value::Array(warnings) = warnings {
+            warnings.push(Value::String(msg));
+        }
+    }
+
+    /// Reports structured command result, which is kept under the given key
+    /// in JSON mode
+    pub fn data(&self, key: &str, data: &impl Serialize) {
+        match (self.json, self.ids) {
+            (true, _) => self.record(key, data),
+            (false, IdEncoding::Bech32) => println!("{}", self.format.serialize(data)),
+            (false, ids) => {
+                let value = serde_json::to_value(data).expect("broken output serde");
+                println!("{}", self.format.serialize(&render_ids(ids, value)))
+            }
+        }
+    }
+
+    /// Reports command result having textual representation only
+    pub fn text(&self, key: &str, text: impl Display) {
+        match self.json {
+            true => self.record(key, &text.to_string()),
+            false => println!("{}", text),
+        }
+    }
+
+    /// Reports list of command results having textual representation, one
+    /// per line
+    pub fn list<T: Display>(&self, key: &str, items: impl IntoIterator<Item = T>) {
+        let items = items
+            .into_iter()
+            .map(|item| self.ids.render_text(&item.to_string()))
+            .collect::<Vec<_>>();
+        match self.json {
+            true => self.record(key, &items),
+            false => items.iter().for_each(|item| println!("{}", item)),
+        }
+    }
+
+    /// Adds data to the JSON output. Used for the results which are reported
+    /// with human-readable messages otherwise.
+    pub fn record(&self, key: &str, data: &impl Serialize) {
+        if self.json {
+            let value = serde_json::to_value(data).expect("broken output serde");
+            let value = render_ids(self.ids, value);
+            self.document.borrow_mut().insert(key.to_owned(), value);
+        }
+    }
+
+    /// Completes the command output. In JSON mode the error is reported as a
+    /// part of the output and is not returned.
+    pub fn finish<E: Display>(self, res: Result<(), E>) -> Result<(), E> {
+        if !self.json {
+            return res;
+        }
+        let mut document = self.document.into_inner();
+        document.insert(s!("success"), Value::Bool(res.is_ok()));
+        if let Err(err) = res {
+            document.insert(s!("error"), Value::String(err.to_string()));
+        }
+        println!("{}", OutputFormat::Json.serialize(&document));
+        Ok(())
+    }
+}
+
+/// Width of the progress bar, in characters
+const PROGRESS_WIDTH: u32 = 40;
+
+/// Renders progress of a long-running operation as a progress bar on the
+/// standard error. The bar is rendered only if the standard error is a
+/// terminal, so the redirected output does not get cluttered.
+pub fn render_progress(step: &ProgressStep) {
+    if !atty::is(atty::Stream::Stderr) || step.total == 0 {
+        return;
+    }
+    let filled =
+        (step.done.min(step.total) as u64 * PROGRESS_WIDTH as u64 / step.total as u64) as usize;
+    let bar = format!("{}{}", "#".repeat(filled), "-".repeat(PROGRESS_WIDTH as usize - filled));
+    let mut stderr = io::stderr();
+    let _ = write!(stderr, "\r{} [{}] {}/{}", step.stage, bar, step.done, step.total);
+    if step.is_complete() {
+        let _ = writeln!(stderr);
+    }
+    let _ = stderr.flush();
+}
@@ -0,0 +1,267 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Transfer of consignments between air-gapped devices as a sequence of QR
+//! codes.
+//!
+//! Consignment data are split into fragments of equal length. Each QR code
+//! contains a single part in the form of
+//! `UR:RGB-CONSIGNMENT/<seq>-<fragments>/<length>-<checksum>/<hex data>`,
+//! using only the characters of the QR alphanumeric mode. The first parts
+//! carry the fragments one by one; the parts beyond the number of fragments
+//! are fountain-encoded: each of them is a XOR of several fragments selected
+//! deterministically from the part sequence number. Thus the receiving device
+//! may assemble the consignment from any sufficient subset of the parts, not
+//! depending on which of the codes were missed while scanning an animated
+//! sequence.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::hex::{FromHex, ToHex};
+use bitcoin::hashes::{sha256, Hash};
+use qrcode::render::{svg, unicode};
+use qrcode::QrCode;
+
+/// Type prefix of the QR code parts
+pub const UR_PREFIX: &str = "UR:RGB-CONSIGNMENT/";
+
+/// Maximal number of fragments mixed into a single fountain-encoded part
+const MAX_DEGREE: usize = 4;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum QrError {
+    /// `{0}` is not a consignment QR code part.
+    Part(String),
+
+    /// no consignment QR code parts were scanned.
+    NoParts,
+
+    /// QR code part {0} belongs to a different consignment than the
+    /// previously scanned parts.
+    Mismatch(u32),
+
+    /// only {0} of {1} consignment fragments are recovered from the scanned
+    /// parts; scan more QR codes.
+    Incomplete(usize, usize),
+
+    /// assembled consignment does not match its checksum.
+    Checksum,
+
+    /// data can't be encoded as a QR code. Details: {0}
+    Encoding(String),
+}
+
+/// Splits data into QR code parts.
+pub struct FountainEncoder {
+    fragments: Vec<Vec<u8>>,
+    len: usize,
+    checksum: u32,
+}
+
+impl FountainEncoder {
+    pub fn with(data: &[u8], fragment_len: usize) -> FountainEncoder {
+        let fragment_len = fragment_len.max(1);
+        let fragments = data
+            .chunks(fragment_len)
+            .map(|chunk| {
+                let mut fragment = chunk.to_vec();
+                fragment.resize(fragment_len, 0);
+                fragment
+            })
+            .collect::<Vec<_>>();
+        FountainEncoder {
+            fragments,
+            len: data.len(),
+            checksum: checksum(data),
+        }
+    }
+
+    /// Number of fragments the data are split into, which is the minimal
+    /// number of parts required to assemble the data.
+    pub fn fragment_count(&self) -> usize { self.fragments.len() }
+
+    /// Produces part with the given sequence number, starting from 1.
+    pub fn part(&self, seq: u32) -> String {
+        let count = self.fragments.len();
+        let mut data = vec![0u8; self.fragments.first().map(Vec::len).unwrap_or_default()];
+        for index in choose_fragments(seq, count, self.checksum) {
+            xor_into(&mut data, &self.fragments[index]);
+        }
+        format!(
+            "{}{}-{}/{}-{:08X}/{}",
+            UR_PREFIX,
+            seq,
+            count,
+            self.len,
+            self.checksum,
+            data.to_hex().to_uppercase()
+        )
+    }
+}
+
+/// Assembles data from the QR code parts received in any order.
+#[derive(Default)]
+pub struct FountainDecoder {
+    header: Option<(usize, usize, u32)>,
+    fragment_len: usize,
+    fragments: Vec<Option<Vec<u8>>>,
+    mixed: Vec<(BTreeSet<usize>, Vec<u8>)>,
+}
+
+impl FountainDecoder {
+    pub fn new() -> FountainDecoder { FountainDecoder::default() }
+
+    /// Processes scanned QR code part. Parts which are scanned more than
+    /// once are ignored.
+    pub fn receive(&mut self, part: &str) -> Result<(), QrError> {
+        let (seq, header, data) = parse_part(part)?;
+        let (count, _, checksum) = header;
+        match self.header {
+            None => {
+                self.header = Some(header);
+                self.fragment_len = data.len();
+                self.fragments = vec![None; count];
+            }
+            Some(known) if known != header || self.fragment_len != data.len() => {
+                return Err(QrError::Mismatch(seq))
+            }
+            Some(_) => {}
+        }
+        self.mixed.push((choose_fragments(seq, count, checksum), data));
+        self.reduce();
+        Ok(())
+    }
+
+    /// Number of the data fragments recovered so far
+    pub fn recovered(&self) -> usize { self.fragments.iter().flatten().count() }
+
+    /// Returns assembled data once all fragments are recovered.
+    pub fn message(&self) -> Result<Vec<u8>, QrError> {
+        let (count, len, checksum) = match self.header {
+            Some(header) => header,
+            None => return Err(QrError::NoParts),
+        };
+        if self.recovered() < count {
+            return Err(QrError::Incomplete(self.recovered(), count));
+        }
+        let mut data = self.fragments.iter().flatten().flatten().copied().collect::<Vec<_>>();
+        data.truncate(len);
+        if self::checksum(&data) != checksum {
+            return Err(QrError::Checksum);
+        }
+        Ok(data)
+    }
+
+    /// Removes known fragments from the mixed parts, recovering fragments
+    /// from the parts reduced to a single fragment, until no more progress
+    /// is possible.
+    fn reduce(&mut self) {
+        loop {
+            let mut progress = false;
+            for (indexes, data) in &mut self.mixed {
+                for index in indexes.clone() {
+                    if let Some(fragment) = &self.fragments[index] {
+                        xor_into(data, fragment);
+                        indexes.remove(&index);
+                    }
+                }
+                if indexes.len() == 1 {
+                    let index = *indexes.iter().next().expect("single index");
+                    self.fragments[index] = Some(data.clone());
+                    indexes.clear();
+                    progress = true;
+                }
+            }
+            self.mixed.retain(|(indexes, _)| !indexes.is_empty());
+            if !progress {
+                break;
+            }
+        }
+    }
+}
+
+/// Renders QR code part as a text to be printed into the terminal.
+pub fn render_text(part: &str) -> Result<String, QrError> {
+    let code = QrCode::new(part).map_err(|err| QrError::Encoding(err.to_string()))?;
+    Ok(code
+        .render::<unicode::Dense1x2>()
+        .dark_color(unicode::Dense1x2::Light)
+        .light_color(unicode::Dense1x2::Dark)
+        .build())
+}
+
+/// Renders QR code part as an SVG image.
+pub fn render_svg(part: &str) -> Result<String, QrError> {
+    let code = QrCode::new(part).map_err(|err| QrError::Encoding(err.to_string()))?;
+    Ok(code.render::<svg::Color>().min_dimensions(320, 320).build())
+}
+
+/// Parses part into its sequence number, header (number of fragments, data
+/// length and checksum) and the part data.
+fn parse_part(part: &str) -> Result<(u32, (usize, usize, u32), Vec<u8>), QrError> {
+    let err = || QrError::Part(part.to_owned());
+    let part = part.trim().to_uppercase();
+    let rest = part.strip_prefix(UR_PREFIX).ok_or_else(err)?;
+    let mut components = rest.split('/');
+    let (seq, count) = components.next().and_then(|s| s.split_once('-')).ok_or_else(err)?;
+    let (len, checksum) = components.next().and_then(|s| s.split_once('-')).ok_or_else(err)?;
+    let data = components.next().ok_or_else(err)?;
+    if components.next().is_some() {
+        return Err(err());
+    }
+    let seq = seq.parse::<u32>().map_err(|_| err())?;
+    let count = count.parse::<usize>().map_err(|_| err())?;
+    let len = len.parse::<usize>().map_err(|_| err())?;
+    let checksum = u32::from_str_radix(checksum, 16).map_err(|_| err())?;
+    let data = Vec::<u8>::from_hex(&data.to_lowercase()).map_err(|_| err())?;
+    if seq == 0 || count == 0 {
+        return Err(err());
+    }
+    Ok((seq, (count, len, checksum), data))
+}
+
+/// Selects fragments mixed into the part with the given sequence number. The
+/// first parts carry a single fragment each; the rest mix several fragments
+/// selected with a pseudo-random generator seeded by the sequence number and
+/// the data checksum.
+fn choose_fragments(seq: u32, count: usize, checksum: u32) -> BTreeSet<usize> {
+    if seq as usize <= count {
+        return bset! { seq as usize - 1 };
+    }
+    let mut seed = checksum.to_be_bytes().to_vec();
+    seed.extend(seq.to_be_bytes());
+    let hash = sha256::Hash::hash(&seed);
+    let mut state = u64::from_be_bytes(hash[..8].try_into().expect("fixed length")) | 1;
+    let mut next = || {
+        // xorshift64 generator
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state as usize
+    };
+    let degree = 1 + next() % count.min(MAX_DEGREE);
+    let mut indexes = bset! {};
+    while indexes.len() < degree {
+        indexes.insert(next() % count);
+    }
+    indexes
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    let hash = sha256::Hash::hash(data);
+    u32::from_be_bytes(hash[..4].try_into().expect("fixed length"))
+}
+
+fn xor_into(data: &mut [u8], fragment: &[u8]) {
+    for (byte, other) in data.iter_mut().zip(fragment) {
+        *byte ^= other;
+    }
+}
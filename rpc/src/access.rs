@@ -0,0 +1,192 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Access control of a node shared by multiple users.
+//!
+//! Once the node is configured with administrator keys, each client has to
+//! authenticate with its identity key by signing the node identity together
+//! with the single-use nonce which the node has issued in response to the
+//! `hello` request ([`ClientAuth`]). Client sees and operates on the contracts
+//! which it has issued, imported or received consignments for, and on the
+//! contracts and wallets granted to it by an administrator. Administrators
+//! have access to the whole stash and to the requests affecting the node as a
+//! whole.
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr, KeyPair, Message, Secp256k1, Signing, Verification};
+use bitcoin::XOnlyPublicKey;
+use rgb::ContractId;
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+use strict_encoding::StrictEncode;
+
+use crate::parse_contract_id;
+use crate::relay::tagged_engine;
+
+/// Tag used in the client authentication message
+const CLIENT_AUTH_TAG: &[u8] = b"rgb:node:client-auth";
+
+/// Proof that the client controls its identity key, bound to the node and to
+/// the connection by the node identity and the nonce issued by the node
+/// ([`crate::Welcome::auth_nonce`])
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display("{identity} for {node}")]
+pub struct ClientAuth {
+    pub identity: XOnlyPublicKey,
+    /// Identity key of the node the client authenticates to
+    pub node: XOnlyPublicKey,
+    /// Single-use nonce issued by the node in response to the `hello` request
+    pub nonce: u64,
+    pub signature: schnorr::Signature,
+}
+
+impl ClientAuth {
+    /// Returns message which is signed by the client.
+    pub fn commitment(identity: XOnlyPublicKey, node: XOnlyPublicKey, nonce: u64) -> Message {
+        let mut engine = tagged_engine(CLIENT_AUTH_TAG);
+        engine.input(&identity.serialize());
+        engine.input(&node.serialize());
+        nonce.strict_encode(&mut engine).expect("hash engines do not error");
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    pub fn sign<C: Signing>(
+        secp: &Secp256k1<C>,
+        keypair: &KeyPair,
+        node: XOnlyPublicKey,
+        nonce: u64,
+    ) -> ClientAuth {
+        let identity = keypair.x_only_public_key().0;
+        let message = ClientAuth::commitment(identity, node, nonce);
+        ClientAuth {
+            identity,
+            node,
+            nonce,
+            signature: secp.sign_schnorr_no_aux_rand(&message, keypair),
+        }
+    }
+
+    /// Checks the signature of the client identity key. Whether the node
+    /// identity and the nonce match the ones of the node must be checked
+    /// separately.
+    pub fn verify<C: Verification>(&self, secp: &Secp256k1<C>) -> bool {
+        let message = ClientAuth::commitment(self.identity, self.node, self.nonce);
+        secp.verify_schnorr(&self.signature, &message, &self.identity).is_ok()
+    }
+}
+
+/// Contract or wallet which access is granted to a client identity
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum AccessTarget {
+    #[display("contract:{0}")]
+    Contract(ContractId),
+
+    #[display("wallet:{0}")]
+    Wallet(String),
+}
+
+impl FromStr for AccessTarget {
+    type Err = ParseAccessTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, id) = s.split_once(':').ok_or(ParseAccessTargetError::Format)?;
+        match kind {
+            "contract" => parse_contract_id(id)
+                .map(AccessTarget::Contract)
+                .map_err(|_| ParseAccessTargetError::Id),
+            "wallet" if !id.is_empty() => Ok(AccessTarget::Wallet(id.to_owned())),
+            _ => Err(ParseAccessTargetError::Format),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseAccessTargetError {
+    /// access target must be in form of `contract:<id>` or
+    /// `wallet:<descriptor>`.
+    Format,
+
+    /// invalid contract id.
+    Id,
+}
+
+/// Request granting or revoking access of a client identity to a contract or
+/// a wallet
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("{identity}, {target}")]
+pub struct AccessReq {
+    pub identity: XOnlyPublicKey,
+    pub target: AccessTarget,
+}
+
+/// Contracts and wallets accessible by a client identity
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct AccessGrants {
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub identity: XOnlyPublicKey,
+    pub contracts: BTreeSet<ContractId>,
+    pub wallets: BTreeSet<String>,
+}
+
+impl AccessGrants {
+    pub fn with(identity: XOnlyPublicKey) -> AccessGrants {
+        AccessGrants {
+            identity,
+            contracts: empty!(),
+            wallets: empty!(),
+        }
+    }
+
+    /// Checks whether no contracts or wallets are granted to the identity
+    pub fn is_empty(&self) -> bool { self.contracts.is_empty() && self.wallets.is_empty() }
+
+    pub fn contains(&self, target: &AccessTarget) -> bool {
+        match target {
+            AccessTarget::Contract(contract_id) => self.contracts.contains(contract_id),
+            AccessTarget::Wallet(descriptor) => self.wallets.contains(descriptor),
+        }
+    }
+
+    /// Adds target to the grants, returning whether it was not granted before
+    pub fn insert(&mut self, target: AccessTarget) -> bool {
+        match target {
+            AccessTarget::Contract(contract_id) => self.contracts.insert(contract_id),
+            AccessTarget::Wallet(descriptor) => self.wallets.insert(descriptor),
+        }
+    }
+
+    /// Removes target from the grants, returning whether it was granted
+    pub fn remove(&mut self, target: &AccessTarget) -> bool {
+        match target {
+            AccessTarget::Contract(contract_id) => self.contracts.remove(contract_id),
+            AccessTarget::Wallet(descriptor) => self.wallets.remove(descriptor),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Conversion of the atomic fungible values into decimal amounts.
+//!
+//! Contract state keeps fungible amounts as integer numbers of atomic units;
+//! the number of decimal digits in the user-facing amount is defined by the
+//! contract precision. Conversions in both directions are done with integer
+//! arithmetics, so no precision is lost for any amount and a formatted amount
+//! is always parsed back into the same atomic value.
+
+use rgb::schema::FieldType;
+use rgb::{AtomicValue, Metadata};
+
+/// Genesis metadata field holding the number of decimal digits in the
+/// fungible amounts of the contract, as defined by RGB20 schema
+pub const PRECISION_FIELD: FieldType = 3;
+
+/// Reads precision of the contract from its genesis metadata. Contracts
+/// without precision field have integer amounts.
+pub fn contract_precision(metadata: &Metadata) -> u8 {
+    metadata.u8(PRECISION_FIELD).first().copied().unwrap_or_default()
+}
+
+/// Formats atomic value as a decimal string with the given precision using
+/// the default [`DecimalFormat`].
+pub fn format_decimal(value: AtomicValue, precision: u8) -> String {
+    DecimalFormat::default().format(value, precision)
+}
+
+/// Parses decimal string with the given precision into atomic value using
+/// the default [`DecimalFormat`].
+pub fn parse_decimal(s: &str, precision: u8) -> Result<AtomicValue, ParseDecimalError> {
+    DecimalFormat::default().parse(s, precision)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseDecimalError {
+    /// decimal amount must consist of digits, optionally separated into
+    /// groups and followed by a fractional part.
+    Format,
+
+    /// decimal amount has more fractional digits than the contract precision
+    /// of {0} allows.
+    Precision(u8),
+
+    /// decimal amount exceeds the maximal atomic value.
+    Overflow,
+}
+
+/// Locale-specific rules for formatting decimal amounts
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct DecimalFormat {
+    /// Character separating integer part of the amount from its fraction
+    pub decimal_point: char,
+    /// Character separating groups of three digits in the integer part, if
+    /// any
+    pub group_separator: Option<char>,
+}
+
+impl Default for DecimalFormat {
+    fn default() -> Self {
+        DecimalFormat {
+            decimal_point: '.',
+            group_separator: None,
+        }
+    }
+}
+
+impl DecimalFormat {
+    /// Formats atomic value as a decimal string having exactly `precision`
+    /// fractional digits.
+    pub fn format(&self, value: AtomicValue, precision: u8) -> String {
+        let precision = precision as usize;
+        let digits = format!("{:0>width$}", value, width = precision + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - precision);
+
+        let mut s = String::with_capacity(digits.len() * 2);
+        for (pos, digit) in integer.chars().enumerate() {
+            if pos > 0 && (integer.len() - pos) % 3 == 0 {
+                if let Some(separator) = self.group_separator {
+                    s.push(separator);
+                }
+            }
+            s.push(digit);
+        }
+        if !fraction.is_empty() {
+            s.push(self.decimal_point);
+            s.push_str(fraction);
+        }
+        s
+    }
+
+    /// Parses decimal string into atomic value. The string may have at most
+    /// `precision` fractional digits; group separators are accepted only in
+    /// the integer part and only if the format defines them.
+    pub fn parse(&self, s: &str, precision: u8) -> Result<AtomicValue, ParseDecimalError> {
+        let (integer, fraction) = s.split_once(self.decimal_point).unwrap_or((s, ""));
+        let integer = match self.group_separator {
+            Some(separator) => integer.replace(separator, ""),
+            None => integer.to_owned(),
+        };
+        let fraction = fraction.trim_end_matches('0');
+        let is_numeric = integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit());
+        if integer.is_empty() || !is_numeric {
+            return Err(ParseDecimalError::Format);
+        }
+        if fraction.len() > precision as usize {
+            return Err(ParseDecimalError::Precision(precision));
+        }
+
+        // Zero parts are not scaled, since the power of ten overflows for the
+        // precision above 19 digits
+        let scale = |value: u64, digits: usize| match value {
+            0 => Some(0),
+            value => 10u64.checked_pow(digits as u32).and_then(|scale| value.checked_mul(scale)),
+        };
+        let fraction_digits = precision as usize - fraction.len();
+        let integer = integer.parse::<u64>().map_err(|_| ParseDecimalError::Overflow)?;
+        let fraction = match fraction {
+            "" => 0,
+            digits => digits.parse::<u64>().map_err(|_| ParseDecimalError::Overflow)?,
+        };
+        scale(integer, precision as usize)
+            .zip(scale(fraction, fraction_digits))
+            .and_then(|(integer, fraction)| integer.checked_add(fraction))
+            .ok_or(ParseDecimalError::Overflow)
+    }
+}
+
+/// Fungible amount given both in atomic units and as a decimal string
+/// formatted with the contract precision
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{decimal}")]
+pub struct DecimalAmount {
+    pub atomic: AtomicValue,
+    /// Number of decimal digits in the contract amounts
+    pub precision: u8,
+    /// Amount formatted with the default [`DecimalFormat`]
+    pub decimal: String,
+}
+
+impl DecimalAmount {
+    pub fn with(atomic: AtomicValue, precision: u8) -> Self {
+        DecimalAmount {
+            atomic,
+            precision,
+            decimal: format_decimal(atomic, precision),
+        }
+    }
+
+    /// Formats the amount with the given locale-specific rules.
+    pub fn format(&self, format: &DecimalFormat) -> String {
+        format.format(self.atomic, self.precision)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format_parse_round_trip() {
+        let grouped = DecimalFormat {
+            decimal_point: ',',
+            group_separator: Some('.'),
+        };
+        for format in [DecimalFormat::default(), grouped] {
+            for precision in [0, 1, 2, 8, 18, 19, 20, 24] {
+                for value in [0, 1, 9, 10, 999, 1000, 123_456_789, u64::MAX / 3, u64::MAX] {
+                    let s = format.format(value, precision);
+                    assert_eq!(format.parse(&s, precision), Ok(value), "{} in {:?}", s, format);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn format_grouping() {
+        let format = DecimalFormat {
+            decimal_point: '.',
+            group_separator: Some(','),
+        };
+        assert_eq!(format.format(123_456_789, 2), "1,234,567.89");
+        assert_eq!(format.format(123_456, 0), "123,456");
+        assert_eq!(format.format(5, 3), "0.005");
+        assert_eq!(format_decimal(123_456_789, 2), "1234567.89");
+    }
+
+    #[test]
+    fn parse_large_precision() {
+        assert_eq!(parse_decimal("0", 20), Ok(0));
+        assert_eq!(parse_decimal("0.00", 24), Ok(0));
+        assert_eq!(parse_decimal("0.00000000000000000001", 20), Ok(1));
+        assert_eq!(parse_decimal("1", 20), Err(ParseDecimalError::Overflow));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse_decimal("", 2), Err(ParseDecimalError::Format));
+        assert_eq!(parse_decimal(".5", 2), Err(ParseDecimalError::Format));
+        assert_eq!(parse_decimal("1,000", 2), Err(ParseDecimalError::Format));
+        assert_eq!(parse_decimal("1.2a", 2), Err(ParseDecimalError::Format));
+        assert_eq!(parse_decimal("1.234", 2), Err(ParseDecimalError::Precision(2)));
+        assert_eq!(parse_decimal("1.230", 2), Ok(123));
+        assert_eq!(parse_decimal("18446744073709551616", 0), Err(ParseDecimalError::Overflow));
+        assert_eq!(parse_decimal("184467440737095516.16", 2), Err(ParseDecimalError::Overflow));
+    }
+}
@@ -0,0 +1,89 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use bitcoin::OutPoint;
+use rgb::{ConsignmentId, ContractId};
+
+use crate::parse_contract_id;
+
+/// Object which can be annotated with user-defined label and tags
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum AnnotationTarget {
+    #[display("contract:{0}")]
+    Contract(ContractId),
+
+    #[display("outpoint:{0}")]
+    Outpoint(OutPoint),
+
+    #[display("transfer:{0}")]
+    Transfer(ConsignmentId),
+}
+
+impl FromStr for AnnotationTarget {
+    type Err = ParseAnnotationTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, id) = s.split_once(':').ok_or(ParseAnnotationTargetError::Format)?;
+        match kind {
+            "contract" => parse_contract_id(id)
+                .map(AnnotationTarget::Contract)
+                .map_err(|_| ParseAnnotationTargetError::Id),
+            "outpoint" => OutPoint::from_str(id)
+                .map(AnnotationTarget::Outpoint)
+                .map_err(|_| ParseAnnotationTargetError::Id),
+            "transfer" => ConsignmentId::from_str(id)
+                .map(AnnotationTarget::Transfer)
+                .map_err(|_| ParseAnnotationTargetError::Id),
+            _ => Err(ParseAnnotationTargetError::Format),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseAnnotationTargetError {
+    /// annotation target must be in form of `contract:<id>`, `outpoint:<txid>:<vout>` or
+    /// `transfer:<id>`.
+    Format,
+
+    /// invalid identifier of the annotated object.
+    Id,
+}
+
+/// Free-form label and tags assigned by the user to a contract, outpoint or
+/// transfer
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("annotation({target}, ...)")]
+pub struct Annotation {
+    pub target: AnnotationTarget,
+    pub label: Option<String>,
+    pub tags: BTreeSet<String>,
+}
+
+impl Annotation {
+    /// Annotation without label and tags, which is used to remove the
+    /// previously set annotation
+    pub fn is_empty(&self) -> bool { self.label.is_none() && self.tags.is_empty() }
+}
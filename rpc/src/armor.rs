@@ -0,0 +1,165 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! ASCII armor for the strict-encoded RGB data, allowing to share it as a text.
+//!
+//! Armored data are framed with `-----BEGIN RGB <TYPE>-----` and
+//! `-----END RGB <TYPE>-----` lines, where the type header prevents loading
+//! data of one type as another. The frame starts with `Checksum` header
+//! containing SHA256 hash of the strict-encoded data, followed by an empty
+//! line and the data in base64 encoding split into 64-character lines.
+
+use std::fs;
+use std::path::Path;
+
+use amplify::IoError;
+use bitcoin::hashes::hex::ToHex;
+use bitcoin::hashes::{sha256, Hash};
+use rgb::{Contract, Disclosure, Genesis, StateTransfer};
+use strict_encoding::{StrictDecode, StrictEncode};
+
+/// Prefix of the armor header and footer lines
+pub const ARMOR_BOUNDARY: &str = "-----";
+
+/// Name of the armor header keeping checksum of the armored data
+pub const ARMOR_CHECKSUM_HEADER: &str = "Checksum";
+
+/// Length of the base64 lines inside the armor
+const ARMOR_LINE_LEN: usize = 64;
+
+#[derive(Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum ArmorError {
+    /// I/O error. Details: {0}
+    #[from(std::io::Error)]
+    #[from]
+    Io(IoError),
+
+    /// armored data must start with `-----BEGIN RGB {0}-----` line.
+    NoHeader(&'static str),
+
+    /// armored data must end with `-----END RGB {0}-----` line.
+    NoFooter(&'static str),
+
+    /// armored data checksum header is absent.
+    NoChecksum,
+
+    /// armored data checksum does not match the data.
+    ChecksumMismatch,
+
+    /// armored data are not a valid base64 string. Details: {0}
+    Base64(String),
+
+    /// armored data can't be decoded. Details: {0}
+    #[from]
+    Encoding(strict_encoding::Error),
+}
+
+/// Data which can be exported and imported in ASCII-armored form
+pub trait Armor: StrictEncode + StrictDecode {
+    /// Type name used in the armor header and footer lines
+    const ARMOR_TYPE: &'static str;
+
+    /// Returns ASCII-armored representation of the data.
+    fn to_armored_string(&self) -> String {
+        let data = self.strict_serialize().expect("in-memory encoding");
+        let checksum = sha256::Hash::hash(&data);
+        let mut s = format!(
+            "{}BEGIN RGB {}{}\n{}: {}\n\n",
+            ARMOR_BOUNDARY,
+            Self::ARMOR_TYPE,
+            ARMOR_BOUNDARY,
+            ARMOR_CHECKSUM_HEADER,
+            checksum.to_hex()
+        );
+        let encoded = base64::encode(&data);
+        for line in encoded.as_bytes().chunks(ARMOR_LINE_LEN) {
+            s.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+            s.push('\n');
+        }
+        s.push_str(&format!("{}END RGB {}{}\n", ARMOR_BOUNDARY, Self::ARMOR_TYPE, ARMOR_BOUNDARY));
+        s
+    }
+
+    /// Decodes data from their ASCII-armored representation, verifying the
+    /// type and the checksum.
+    fn from_armored_str(s: &str) -> Result<Self, ArmorError> {
+        let header = format!("{}BEGIN RGB {}{}", ARMOR_BOUNDARY, Self::ARMOR_TYPE, ARMOR_BOUNDARY);
+        let footer = format!("{}END RGB {}{}", ARMOR_BOUNDARY, Self::ARMOR_TYPE, ARMOR_BOUNDARY);
+
+        let mut lines = s.lines().map(str::trim).skip_while(|line| line.is_empty());
+        if lines.next() != Some(header.as_str()) {
+            return Err(ArmorError::NoHeader(Self::ARMOR_TYPE));
+        }
+        let mut checksum = None;
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim() == ARMOR_CHECKSUM_HEADER {
+                    checksum = Some(value.trim().to_owned());
+                }
+            }
+        }
+        let checksum = checksum.ok_or(ArmorError::NoChecksum)?;
+
+        let mut encoded = String::new();
+        let mut terminated = false;
+        for line in lines {
+            if line == footer {
+                terminated = true;
+                break;
+            }
+            encoded.push_str(line);
+        }
+        if !terminated {
+            return Err(ArmorError::NoFooter(Self::ARMOR_TYPE));
+        }
+
+        let data = base64::decode(&encoded).map_err(|err| ArmorError::Base64(err.to_string()))?;
+        if sha256::Hash::hash(&data).to_hex() != checksum.to_lowercase() {
+            return Err(ArmorError::ChecksumMismatch);
+        }
+        Self::strict_deserialize(data).map_err(ArmorError::from)
+    }
+
+    /// Loads data from a file, which may contain either ASCII-armored or
+    /// binary strict-encoded data.
+    fn armored_file_load(path: impl AsRef<Path>) -> Result<Self, ArmorError> {
+        let data = fs::read(path)?;
+        match std::str::from_utf8(&data) {
+            Ok(s) if s.trim_start().starts_with(ARMOR_BOUNDARY) => Self::from_armored_str(s),
+            _ => Self::strict_deserialize(data).map_err(ArmorError::from),
+        }
+    }
+
+    /// Saves data to a file in ASCII-armored form.
+    fn armored_file_save(&self, path: impl AsRef<Path>) -> Result<(), ArmorError> {
+        fs::write(path, self.to_armored_string())?;
+        Ok(())
+    }
+}
+
+impl Armor for Contract {
+    const ARMOR_TYPE: &'static str = "CONTRACT";
+}
+
+impl Armor for StateTransfer {
+    const ARMOR_TYPE: &'static str = "CONSIGNMENT";
+}
+
+impl Armor for Disclosure {
+    const ARMOR_TYPE: &'static str = "DISCLOSURE";
+}
+
+impl Armor for Genesis {
+    const ARMOR_TYPE: &'static str = "GENESIS";
+}
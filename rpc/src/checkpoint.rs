@@ -0,0 +1,84 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Contract state checkpoints.
+//!
+//! Checkpoint lists state transitions holding the contract state at some
+//! block height and is signed by the contract issuer. Nodes trusting the
+//! issuer validate incoming consignments only down to the checkpoint
+//! transitions, without going through the whole contract history.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr, KeyPair, Message, Secp256k1, Signing, Verification};
+use bitcoin::{Txid, XOnlyPublicKey};
+use rgb::{ContractId, Node, NodeId, Transition};
+use strict_encoding::StrictEncode;
+
+/// Tag used in the checkpoint commitment
+const CHECKPOINT_TAG: &[u8] = b"rgb:node:checkpoint";
+
+/// Contract state at some block height
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("checkpoint({contract_id}@{height})")]
+pub struct Checkpoint {
+    pub contract_id: ContractId,
+
+    /// Height of the last block which transitions are accounted for
+    pub height: u32,
+
+    /// Transitions with their witness transaction ids, which had unspent
+    /// assignments at the checkpoint height
+    pub frontier: Vec<(Transition, Txid)>,
+}
+
+impl Checkpoint {
+    pub fn frontier_ids(&self) -> BTreeSet<NodeId> {
+        self.frontier.iter().map(|(transition, _)| transition.node_id()).collect()
+    }
+
+    /// Returns message which is signed by the issuer.
+    pub fn commitment(&self) -> Message {
+        let tag = sha256::Hash::hash(CHECKPOINT_TAG);
+        let mut engine = sha256::Hash::engine();
+        engine.input(&tag[..]);
+        engine.input(&tag[..]);
+        self.strict_encode(&mut engine).expect("hash engines do not error");
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    pub fn sign<C: Signing>(self, secp: &Secp256k1<C>, keypair: &KeyPair) -> SignedCheckpoint {
+        let signature = secp.sign_schnorr_no_aux_rand(&self.commitment(), keypair);
+        SignedCheckpoint {
+            checkpoint: self,
+            issuer: keypair.x_only_public_key().0,
+            signature,
+        }
+    }
+}
+
+/// Checkpoint signed by the contract issuer
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("{checkpoint} signed by {issuer}")]
+pub struct SignedCheckpoint {
+    pub checkpoint: Checkpoint,
+    pub issuer: XOnlyPublicKey,
+    pub signature: schnorr::Signature,
+}
+
+impl SignedCheckpoint {
+    /// Checks that the signature matches the checkpoint and the issuer key.
+    /// Whether the issuer is trusted must be checked separately.
+    pub fn verify<C: Verification>(&self, secp: &Secp256k1<C>) -> bool {
+        secp.verify_schnorr(&self.signature, &self.checkpoint.commitment(), &self.issuer).is_ok()
+    }
+}
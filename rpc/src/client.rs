@@ -8,11 +8,14 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::thread::sleep;
 use std::time::Duration;
 
-use bitcoin::{OutPoint, Txid};
+use bitcoin::hashes::sha256;
+use bitcoin::secp256k1::{KeyPair, Secp256k1};
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
+use bp::seals::txout::CloseMethod;
 use internet2::addr::{NodeAddr, ServiceAddr};
 use internet2::ZmqSocketType;
 use lnpbp::chain::Chain;
@@ -20,12 +23,33 @@ use microservices::esb::{self, BusId, ClientId};
 use microservices::rpc;
 use psbt::Psbt;
 use rgb::schema::TransitionType;
-use rgb::{Contract, ContractId, ContractState, ContractStateMap, SealEndpoint, StateTransfer};
+use rgb::{
+    seal, AtomicValue, BundleId, ConsignmentId, Contract, ContractId, ContractState,
+    ContractStateMap, NodeId, Schema, SealEndpoint, StateTransfer, Transition,
+};
 
-use crate::messages::{FinalizeTransfersRes, HelloReq, TransferFinalize, TransfersReq};
+use crate::messages::{
+    AccelerateReq, AddressesReq, AllocationStatus, CpfpDraft, FinalizeTransfersRes, ForgetReport,
+    ForgetReq, ForgetSpentReq, GcReport, HelloReq, HistoricalBalance, HistoricalBalanceReq,
+    InvoiceReq, PaymentDraft, PaymentReq, RebuildReport, RevalidationReport, RevealExportReq,
+    RevealReq, TransferFinalize, TransfersReq, WalletAddress, WalletBalance, WalletInfo, WalletReq,
+};
 use crate::{
-    AcceptReq, BusMsg, ComposeReq, ContractValidity, Error, FailureCode, OutpointFilter, Reveal,
-    RpcMsg, ServiceId, TransferReq,
+    now, AcceptReq, AccessGrants, AccessReq, AccessTarget, Annotation, AnnotationTarget, BusMsg,
+    Capability, Checkpoint, CheckpointReq, ClientAuth, ComposeReq, Compression, ConsolidateReq,
+    ContractAllocation, ContractDeps, ContractPolicy, ContractStats, ContractValidity,
+    DecimalContractState, DiffReq, EnvelopeId, Error, FailureCode, HistoryDiff, Invoice,
+    InvoiceRecord, InvoiceTolerance, IssueReq, IssueSpec, JournalEntry, NetworkMagic, NodeHealth,
+    OperationPreview, Order, OrderDigest, OrderRecord, OrderTarget, OutpointFilter,
+    OutpointStateQuery, OutpointStatus, PayjoinCompleteReq, PayjoinContributeReq,
+    PayjoinContribution, PayjoinProposal, PayjoinProposeReq, PolicyReq, Pong, ProgressStep,
+    ProofBundle, ProveReq, PruneReport, PruneReq, PublishReq, QuarantineInfo, RelayAuth,
+    RelayDepositReq, RelayEnvelope, RelayFetchReq, RelayPickupReq, ReplayReq, Reveal, RevealData,
+    RewindReq, RpcEnvelope, RpcMsg, SchemaInfo, ServiceId, Setting, SignedCheckpoint, SignedOrder,
+    SignedReceipt, StashCommitment, StateChange, StateDigest, StorageUsage, SwapAcceptReq,
+    SwapAcceptance, SwapCompleteReq, SwapId, SwapOffer, SwapProposeReq, SwapTerms, TimestampProof,
+    TransferFilter, TransferPreviewReq, TransferRecord, TransferReq, TransferSendReq, UploadChunk,
+    UploadKind, UploadReq, Welcome, MAX_UPLOAD_SIZE, UPLOAD_CHUNK_SIZE,
 };
 
 // We have just a single service bus (RPC), so we can use any id
@@ -44,6 +68,14 @@ pub struct Client {
     client_id: ClientId,
     user_agent: String,
     network: Chain,
+    signet: Option<NetworkMagic>,
+    capabilities: BTreeSet<Capability>,
+    timeout: Option<u32>,
+    progress_handler: Option<Box<dyn Fn(&ProgressStep) + Send>>,
+    order_key: Option<KeyPair>,
+    pinned_identity: Option<XOnlyPublicKey>,
+    node_identity: Option<XOnlyPublicKey>,
+    auth_nonce: Option<u64>,
     response_queue: Vec<RpcMsg>,
     esb: esb::Controller<RpcBus, BusMsg, Handler>,
 }
@@ -77,6 +109,14 @@ impl Client {
             client_id,
             user_agent,
             network,
+            signet: None,
+            capabilities: empty!(),
+            timeout: None,
+            progress_handler: None,
+            order_key: None,
+            pinned_identity: None,
+            node_identity: None,
+            auth_nonce: None,
             response_queue: empty!(),
             esb,
         })
@@ -84,10 +124,65 @@ impl Client {
 
     pub fn client_id(&self) -> ClientId { self.client_id }
 
+    /// Optional features supported both by the client and the node, known
+    /// after the `hello` request.
+    pub fn capabilities(&self) -> &BTreeSet<Capability> { &self.capabilities }
+
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// Identity key of the node, known after the `hello` request.
+    pub fn node_identity(&self) -> Option<XOnlyPublicKey> { self.node_identity }
+
+    /// Pins identity key which the node must have and prove to control during
+    /// the `hello` request. Must be called before the `hello` request.
+    pub fn pin_node_identity(&mut self, identity: Option<XOnlyPublicKey>) {
+        self.pinned_identity = identity;
+    }
+
+    /// Sets number of seconds after which the node abandons consignment
+    /// composition and acceptance requests of the client.
+    pub fn set_timeout(&mut self, timeout: Option<u32>) { self.timeout = timeout; }
+
+    /// Sets magic of the custom signet the client works with, which must match
+    /// the signet of the node. Must be called before the `hello` request.
+    pub fn set_signet(&mut self, signet: Option<NetworkMagic>) { self.signet = signet; }
+
+    /// Sets handler receiving incremental progress of the long-running
+    /// operations. Without the handler the progress steps are reported as
+    /// plain progress messages.
+    pub fn set_progress_handler(&mut self, handler: impl Fn(&ProgressStep) + Send + 'static) {
+        self.progress_handler = Some(Box::new(handler));
+    }
+
+    /// Sets key which signs contract issue and asset transfer orders of the
+    /// client.
+    /// The key must be registered on the node if the node requires signed
+    /// orders.
+    pub fn set_order_key(&mut self, keypair: Option<KeyPair>) { self.order_key = keypair; }
+
+    fn sign_order<R: OrderDigest>(&self, req: &R) -> Option<SignedOrder> {
+        let keypair = self.order_key.as_ref()?;
+        let timestamp = now();
+        let order = Order {
+            kind: R::ORDER_KIND,
+            digest: req.order_digest(),
+            timestamp,
+        };
+        Some(order.sign(&Secp256k1::signing_only(), keypair))
+    }
+
     fn request(&mut self, req: impl Into<RpcMsg>) -> Result<(), Error> {
         let req = req.into();
         debug!("Executing {}", req);
-        self.esb.send_to(RpcBus, ServiceId::rgbd(), BusMsg::Rpc(req))?;
+        // Requests are compressed only once compression is negotiated with the node
+        let compression = match self.supports(Capability::Compression) {
+            true => Compression::Zstd,
+            false => Compression::None,
+        };
+        let envelope = RpcEnvelope::with_compression(&req, compression);
+        self.esb.send_to(RpcBus, ServiceId::rgbd(), BusMsg::Rpc(envelope))?;
         Ok(())
     }
 
@@ -98,13 +193,41 @@ impl Client {
                 return Ok(resp);
             } else {
                 for poll in self.esb.recv_poll()? {
-                    match poll.request {
-                        BusMsg::Rpc(msg) => self.response_queue.push(msg),
+                    let msg = match poll.request {
+                        BusMsg::Rpc(envelope) => envelope.open()?,
+                    };
+                    match (msg, &self.progress_handler) {
+                        (RpcMsg::ProgressStep(step), Some(handler)) => handler(&step),
+                        (RpcMsg::ProgressStep(step), None) => {
+                            self.response_queue.push(RpcMsg::Progress(step.to_string()))
+                        }
+                        (msg, _) => self.response_queue.push(msg),
                     }
                 }
             }
         }
     }
+
+    /// Uploads data to the node in chunks, to be consumed by the next request
+    fn upload(&mut self, data: &[u8]) -> Result<(), Error> {
+        if data.len() > MAX_UPLOAD_SIZE {
+            return Err(Error::LocalFailure {
+                code: FailureCode::Oversized,
+                message: format!("uploaded data exceed {} bytes", MAX_UPLOAD_SIZE),
+            });
+        }
+        for (no, chunk) in data.chunks(UPLOAD_CHUNK_SIZE).enumerate() {
+            self.request(RpcMsg::UploadConsignment(UploadChunk {
+                offset: (no * UPLOAD_CHUNK_SIZE) as u32,
+                data: chunk.to_vec(),
+            }))?;
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Success(_) => {}
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Client {
@@ -112,40 +235,118 @@ impl Client {
         self.request(HelloReq {
             user_agent: self.user_agent.clone(),
             network: self.network.clone(),
+            signet: self.signet,
+            capabilities: Capability::ALL.into_iter().collect(),
         })?;
         match self.response()? {
-            RpcMsg::Success(_) => Ok(true),
+            RpcMsg::Capabilities(capabilities) => {
+                self.capabilities = capabilities;
+                self.node_identity = None;
+                self.auth_nonce = None;
+            }
+            RpcMsg::Welcome(Welcome {
+                capabilities,
+                identity,
+                auth_nonce,
+            }) => {
+                self.capabilities = capabilities;
+                self.node_identity = Some(identity);
+                self.auth_nonce = Some(auth_nonce);
+            }
             RpcMsg::Failure(rpc::Failure {
                 code: rpc::FailureCode::Other(FailureCode::ChainMismatch),
                 ..
-            }) => Ok(false),
-            resp @ RpcMsg::Failure(_) => Err(resp.failure_to_error().unwrap_err()),
-            _ => Err(Error::UnexpectedServerResponse),
+            }) => return Ok(false),
+            resp @ RpcMsg::Failure(_) => return Err(resp.failure_to_error().unwrap_err()),
+            _ => return Err(Error::UnexpectedServerResponse),
+        }
+        if let Some(pinned) = self.pinned_identity {
+            match self.node_identity {
+                Some(identity) if identity == pinned => {}
+                Some(identity) => {
+                    return Err(Error::LocalFailure {
+                        code: FailureCode::Identity,
+                        message: format!(
+                            "node has identity {}, while {} was expected",
+                            identity, pinned
+                        ),
+                    })
+                }
+                None => {
+                    return Err(Error::LocalFailure {
+                        code: FailureCode::Identity,
+                        message: s!("node does not report its identity"),
+                    })
+                }
+            }
+            self.ping()?;
+        }
+        Ok(true)
+    }
+
+    /// Asks the node to sign a random nonce with its identity key, checking
+    /// that the signature is valid and that the identity matches the one
+    /// reported during the `hello` request or pinned by the client.
+    pub fn ping(&mut self) -> Result<Pong, Error> {
+        use rgb::secp256k1zkp::rand;
+
+        let nonce = rand::random();
+        self.request(RpcMsg::Ping(nonce))?;
+        let pong = match self.response()?.failure_to_error()? {
+            RpcMsg::Pong(pong) => pong,
+            _ => return Err(Error::UnexpectedServerResponse),
+        };
+        let expected = self.pinned_identity.or(self.node_identity);
+        if pong.nonce != nonce
+            || (expected.is_some() && expected != Some(pong.identity))
+            || !pong.verify(&Secp256k1::verification_only())
+        {
+            return Err(Error::LocalFailure {
+                code: FailureCode::Identity,
+                message: format!("node has failed to prove it controls identity {}", pong.identity),
+            });
         }
+        Ok(pong)
     }
 
     pub fn register_contract(
         &mut self,
         contract: Contract,
         force: bool,
+        proofs: Option<ProofBundle>,
         progress: impl Fn(String),
     ) -> Result<ContractValidity, Error> {
         self.request(RpcMsg::ConsumeContract(AcceptReq {
             consignment: contract,
             force,
+            full: false,
             reveal: None,
+            timeout: self.timeout,
+            proofs,
         }))?;
         loop {
             match self.response()?.failure_to_error()? {
-                RpcMsg::Invalid(status) => return Ok(ContractValidity::Invalid(status)),
-                RpcMsg::UnresolvedTxids(txids) => return Ok(ContractValidity::UnknownTxids(txids)),
-                RpcMsg::Success(_) => return Ok(ContractValidity::Valid),
+                RpcMsg::Valid(report) => return Ok(ContractValidity::Valid(report)),
+                RpcMsg::Invalid(report) => return Ok(ContractValidity::Invalid(report)),
+                RpcMsg::UnresolvedTxids(report) => {
+                    return Ok(ContractValidity::UnknownTxids(report))
+                }
                 RpcMsg::Progress(info) => progress(info),
                 _ => return Err(Error::UnexpectedServerResponse),
             }
         }
     }
 
+    /// Imports contract from its genesis given as a Bech32 string, which may
+    /// be ASCII-armored. The contract schema must be known to the node.
+    pub fn import_contract(&mut self, genesis: String) -> Result<ContractState, Error> {
+        self.request(RpcMsg::ImportContract(genesis))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::ContractState(state) => Ok(state),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
     pub fn list_contracts(&mut self) -> Result<BTreeSet<ContractId>, Error> {
         self.request(RpcMsg::ListContracts)?;
         match self.response()?.failure_to_error()? {
@@ -154,10 +355,141 @@ impl Client {
         }
     }
 
-    pub fn contract_state(&mut self, contract_id: ContractId) -> Result<ContractState, Error> {
+    /// Lists schemata known to the node together with their types and the
+    /// contracts using them.
+    pub fn list_schemata(&mut self) -> Result<Vec<SchemaInfo>, Error> {
+        self.request(RpcMsg::ListSchemata)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Schemata(schemata) => Ok(schemata),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Returns dependencies of the contract and of all other contracts
+    /// connected to it through shared root schemata or references. If no
+    /// contract is given, returns dependencies of all known contracts.
+    pub fn contract_deps(
+        &mut self,
+        contract_id: Option<ContractId>,
+    ) -> Result<Vec<ContractDeps>, Error> {
+        self.request(RpcMsg::GetContractDeps(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::ContractDeps(deps) => Ok(deps),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Reports contract state together with its fungible amounts. Amounts
+    /// are computed locally if the node does not support
+    /// [`Capability::DecimalAmounts`].
+    pub fn contract_state(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<DecimalContractState, Error> {
         self.request(RpcMsg::GetContractState(contract_id))?;
         match self.response()?.failure_to_error()? {
-            RpcMsg::ContractState(state) => Ok(state),
+            RpcMsg::DecimalContractState(state) => Ok(state),
+            RpcMsg::ContractState(state) => Ok(DecimalContractState::with(state)),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Reports aggregate statistics of the contract known to the node.
+    pub fn contract_stats(&mut self, contract_id: ContractId) -> Result<ContractStats, Error> {
+        self.request(RpcMsg::GetContractStats(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::ContractStats(stats) => Ok(stats),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists allocations of the contract state known to the node, flagging
+    /// the ones on the unspent outputs of the wallets registered with the
+    /// node as owned.
+    pub fn allocations(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<Vec<ContractAllocation>, Error> {
+        self.allocation_status(contract_id).map(|status| status.allocations)
+    }
+
+    /// Lists allocations of the contract state known to the node together
+    /// with the spent status of their outpoints. Nodes which do not support
+    /// [`Capability::SpentStatus`] report no spent status.
+    pub fn allocation_status(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<AllocationStatus, Error> {
+        self.request(RpcMsg::ListAllocations(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Allocations(allocations) => Ok(AllocationStatus {
+                allocations,
+                spent: empty!(),
+            }),
+            RpcMsg::AllocationStatus(status) => Ok(status),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists contracts taking the most of the store space, sorted by the size of
+    /// their data, up to `limit` of them; zero `limit` lists all contracts.
+    pub fn storage_usage(&mut self, limit: u16) -> Result<Vec<StorageUsage>, Error> {
+        self.request(RpcMsg::ListStorageUsage(limit))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::StorageUsage(usage) => Ok(usage),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Reports canonical digest of the contract history and state known to the
+    /// node, which can be compared with the digest reported by another node.
+    pub fn state_digest(&mut self, contract_id: ContractId) -> Result<StateDigest, Error> {
+        self.request(RpcMsg::GetStateDigest(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::StateDigest(digest) => Ok(digest),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Compares contract history known to the node with the transition
+    /// bundles known to another node. With an empty set of bundles reports all
+    /// bundles known to the node.
+    pub fn diff_history(
+        &mut self,
+        contract_id: ContractId,
+        bundles: BTreeSet<BundleId>,
+        progress: impl Fn(String),
+    ) -> Result<HistoryDiff, Error> {
+        self.request(RpcMsg::DiffHistory(DiffReq {
+            contract_id,
+            bundles,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::HistoryDiff(diff) => return Ok(diff),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Commits to the digests of all contracts known to the node
+    pub fn commit_stash(&mut self, progress: impl Fn(String)) -> Result<StashCommitment, Error> {
+        self.request(RpcMsg::CommitStash)?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::StashCommitment(commitment) => return Ok(commitment),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Submits digest to the OpenTimestamps calendar configured for the node
+    pub fn timestamp(&mut self, digest: sha256::Hash) -> Result<TimestampProof, Error> {
+        self.request(RpcMsg::Timestamp(digest))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::TimestampProof(proof) => Ok(proof),
             _ => Err(Error::UnexpectedServerResponse),
         }
     }
@@ -167,10 +499,105 @@ impl Client {
         outpoints: BTreeSet<OutPoint>,
         progress: impl Fn(String),
     ) -> Result<ContractStateMap, Error> {
+        self.outpoint_status(outpoints, progress).map(|status| status.state)
+    }
+
+    /// Reports state of the outpoints together with their spent status found
+    /// during the wallet synchronization. Nodes which do not support
+    /// [`Capability::SpentStatus`] report no spent status.
+    pub fn outpoint_status(
+        &mut self,
+        outpoints: BTreeSet<OutPoint>,
+        progress: impl Fn(String),
+    ) -> Result<OutpointStatus, Error> {
         self.request(RpcMsg::GetOutpointState(outpoints))?;
+        self.outpoint_status_response(progress)
+    }
+
+    /// Reports state of the outpoints, which may be given by the scripts
+    /// (addresses) controlling them. Scripts are resolved using the wallets
+    /// synchronized with the node.
+    pub fn query_outpoint_state(
+        &mut self,
+        outpoints: BTreeSet<OutPoint>,
+        scripts: BTreeSet<Script>,
+        progress: impl Fn(String),
+    ) -> Result<ContractStateMap, Error> {
+        self.query_outpoint_status(outpoints, scripts, progress).map(|status| status.state)
+    }
+
+    /// Reports state of the outpoints, which may be given by the scripts
+    /// (addresses) controlling them, together with the spent status of the
+    /// outpoints found during the wallet synchronization, such that the state
+    /// on the spent outpoints can be discarded without querying the chain.
+    /// Nodes which do not support [`Capability::SpentStatus`] report no spent
+    /// status.
+    pub fn query_outpoint_status(
+        &mut self,
+        outpoints: BTreeSet<OutPoint>,
+        scripts: BTreeSet<Script>,
+        progress: impl Fn(String),
+    ) -> Result<OutpointStatus, Error> {
+        self.request(RpcMsg::QueryOutpointState(OutpointStateQuery { outpoints, scripts }))?;
+        self.outpoint_status_response(progress)
+    }
+
+    fn outpoint_status_response(
+        &mut self,
+        progress: impl Fn(String),
+    ) -> Result<OutpointStatus, Error> {
         loop {
             match self.response()?.failure_to_error()? {
-                RpcMsg::OutpointState(outpoint_state) => return Ok(outpoint_state),
+                RpcMsg::OutpointState(state) => {
+                    return Ok(OutpointStatus {
+                        state,
+                        spent: empty!(),
+                        amounts: empty!(),
+                    })
+                }
+                RpcMsg::OutpointStatus(status) => return Ok(status),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn issue(
+        &mut self,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+        progress: impl Fn(String),
+    ) -> Result<Contract, Error> {
+        let mut req = IssueReq {
+            spec,
+            schema,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::IssueContract(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Contract(contract) => return Ok(contract),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn preview_issue(
+        &mut self,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+        progress: impl Fn(String),
+    ) -> Result<OperationPreview, Error> {
+        self.request(RpcMsg::PreviewIssue(IssueReq {
+            spec,
+            schema,
+            order: None,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Preview(preview) => return Ok(preview),
                 RpcMsg::Progress(info) => progress(info),
                 _ => return Err(Error::UnexpectedServerResponse),
             }
@@ -187,6 +614,9 @@ impl Client {
             contract_id,
             include: node_types.into_iter().collect(),
             outpoints: OutpointFilter::All,
+            known: empty!(),
+            timeout: self.timeout,
+            armored: false,
         }))?;
         loop {
             match self.response()?.failure_to_error()? {
@@ -197,17 +627,47 @@ impl Client {
         }
     }
 
+    /// Composes contract consignment, returning it in ASCII-armored form.
+    pub fn contract_armored(
+        &mut self,
+        contract_id: ContractId,
+        node_types: Vec<TransitionType>,
+        progress: impl Fn(String),
+    ) -> Result<String, Error> {
+        self.request(RpcMsg::ConsignContract(ComposeReq {
+            contract_id,
+            include: node_types.into_iter().collect(),
+            outpoints: OutpointFilter::All,
+            known: empty!(),
+            timeout: self.timeout,
+            armored: true,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Armored(armored) => return Ok(armored),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Composes state transfer consignment for the outpoints given either
+    /// explicitly or by a wallet previously synchronized with the node.
     pub fn consign(
         &mut self,
         contract_id: ContractId,
         node_types: Vec<TransitionType>,
-        outpoints: BTreeSet<OutPoint>,
+        outpoints: impl Into<OutpointFilter>,
+        known: BTreeSet<NodeId>,
         progress: impl Fn(String),
     ) -> Result<StateTransfer, Error> {
         self.request(RpcMsg::ConsignTransfer(ComposeReq {
             contract_id,
             include: node_types.into_iter().collect(),
-            outpoints: OutpointFilter::Only(outpoints),
+            outpoints: outpoints.into(),
+            known,
+            timeout: self.timeout,
+            armored: false,
         }))?;
         loop {
             match self.response()?.failure_to_error()? {
@@ -218,71 +678,1016 @@ impl Client {
         }
     }
 
-    pub fn transfer(
+    /// Composes state transfer consignment like [`Client::consign`],
+    /// returning it in ASCII-armored form.
+    pub fn consign_armored(
         &mut self,
-        consignment: StateTransfer,
-        endseals: Vec<SealEndpoint>,
-        psbt: Psbt,
-        beneficiary: Option<NodeAddr>,
+        contract_id: ContractId,
+        node_types: Vec<TransitionType>,
+        outpoints: impl Into<OutpointFilter>,
+        known: BTreeSet<NodeId>,
         progress: impl Fn(String),
-    ) -> Result<TransferFinalize, Error> {
-        self.request(RpcMsg::Transfer(TransferReq {
-            consignment,
-            endseals,
-            psbt,
-            beneficiary,
+    ) -> Result<String, Error> {
+        self.request(RpcMsg::ConsignTransfer(ComposeReq {
+            contract_id,
+            include: node_types.into_iter().collect(),
+            outpoints: outpoints.into(),
+            known,
+            timeout: self.timeout,
+            armored: true,
         }))?;
         loop {
             match self.response()?.failure_to_error()? {
-                RpcMsg::StateTransferFinalize(transfer) => return Ok(transfer),
+                RpcMsg::Armored(armored) => return Ok(armored),
                 RpcMsg::Progress(info) => progress(info),
                 _ => return Err(Error::UnexpectedServerResponse),
             }
         }
     }
 
-    pub fn finalize_transfers(
+    /// Returns tips of the locally known contract history, which may be given
+    /// to the payer to exclude already known history from the transfer
+    /// consignment.
+    pub fn known_history(&mut self, contract_id: ContractId) -> Result<BTreeSet<NodeId>, Error> {
+        self.request(RpcMsg::GetKnownHistory(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::KnownHistory(tips) => Ok(tips),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Requests unsigned checkpoint of the contract state at the given block
+    /// height. The checkpoint must be signed by the issuer before being
+    /// distributed.
+    pub fn create_checkpoint(
         &mut self,
-        transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
-        psbt: Psbt,
+        contract_id: ContractId,
+        height: u32,
+    ) -> Result<Checkpoint, Error> {
+        self.request(RpcMsg::CreateCheckpoint(CheckpointReq {
+            contract_id,
+            height,
+        }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Checkpoint(checkpoint) => Ok(checkpoint),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Signs checkpoint with the issuer signer configured for the node
+    pub fn sign_checkpoint(&mut self, checkpoint: Checkpoint) -> Result<SignedCheckpoint, Error> {
+        self.request(RpcMsg::SignCheckpoint(checkpoint))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::SignedCheckpoint(signed) => Ok(signed),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn import_checkpoint(&mut self, checkpoint: SignedCheckpoint) -> Result<(), Error> {
+        self.request(RpcMsg::ImportCheckpoint(checkpoint))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn set_policy(
+        &mut self,
+        contract_id: ContractId,
+        policy: ContractPolicy,
+    ) -> Result<(), Error> {
+        self.request(RpcMsg::SetPolicy(PolicyReq {
+            contract_id,
+            policy,
+        }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn policy(&mut self, contract_id: ContractId) -> Result<ContractPolicy, Error> {
+        self.request(RpcMsg::GetPolicy(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Policy(policy) => Ok(policy),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Constructs blank state transition moving all contract state assigned to
+    /// the given outpoints to a single seal.
+    pub fn consolidate(
+        &mut self,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+        seal: seal::Revealed,
         progress: impl Fn(String),
-    ) -> Result<FinalizeTransfersRes, Error> {
-        self.request(RpcMsg::FinalizeTransfers(TransfersReq { transfers, psbt }))?;
+    ) -> Result<Transition, Error> {
+        let mut req = ConsolidateReq {
+            contract_id,
+            outpoints,
+            seal,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::ConsolidateAllocations(req))?;
         loop {
             match self.response()?.failure_to_error()? {
-                RpcMsg::FinalizedTransfers(transfers) => return Ok(transfers),
+                RpcMsg::Transition(transition) => return Ok(transition),
                 RpcMsg::Progress(info) => progress(info),
                 _ => return Err(Error::UnexpectedServerResponse),
             }
         }
     }
 
-    pub fn consume_transfer(
+    pub fn transfer(
         &mut self,
-        transfer: StateTransfer,
-        force: bool,
-        reveal: Option<Reveal>,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+        beneficiary: Option<NodeAddr>,
+        recipient_key: Option<XOnlyPublicKey>,
         progress: impl Fn(String),
-    ) -> Result<ContractValidity, Error> {
-        self.request(RpcMsg::ConsumeTransfer(AcceptReq {
-            consignment: transfer,
-            reveal,
-            force,
-        }))?;
+    ) -> Result<TransferFinalize, Error> {
+        let mut req = TransferReq {
+            consignment,
+            endseals,
+            psbt,
+            beneficiary,
+            recipient_key,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::Transfer(req))?;
         loop {
             match self.response()?.failure_to_error()? {
-                RpcMsg::Invalid(status) => return Ok(ContractValidity::Invalid(status)),
-                RpcMsg::UnresolvedTxids(txids) => return Ok(ContractValidity::UnknownTxids(txids)),
-                RpcMsg::Success(_) => return Ok(ContractValidity::Valid),
+                RpcMsg::StateTransferFinalize(transfer) => return Ok(transfer),
                 RpcMsg::Progress(info) => progress(info),
                 _ => return Err(Error::UnexpectedServerResponse),
             }
         }
     }
 
-    pub fn process_disclosure(
+    /// Sends the finalized transfer consignment to the beneficiary node
+    pub fn send_transfer(
         &mut self,
-        txid: Txid,
+        consignment: StateTransfer,
+        beneficiary: NodeAddr,
+        recipient_key: Option<XOnlyPublicKey>,
+        progress: impl Fn(String),
+    ) -> Result<(), Error> {
+        self.request(RpcMsg::SendTransfer(TransferSendReq {
+            consignment,
+            beneficiary,
+            recipient_key,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Success(_) => return Ok(()),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn preview_transfer(
+        &mut self,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+        progress: impl Fn(String),
+    ) -> Result<OperationPreview, Error> {
+        self.request(RpcMsg::PreviewTransfer(TransferPreviewReq {
+            consignment,
+            endseals,
+            psbt,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Preview(preview) => return Ok(preview),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn finalize_transfers(
+        &mut self,
+        transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
+        psbt: Psbt,
+        progress: impl Fn(String),
+    ) -> Result<FinalizeTransfersRes, Error> {
+        let mut req = TransfersReq {
+            transfers,
+            psbt,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::FinalizeTransfers(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::FinalizedTransfers(transfers) => return Ok(transfers),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn sign_psbt(&mut self, psbt: Psbt) -> Result<Psbt, Error> {
+        self.request(RpcMsg::SignPsbt(psbt))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::SignedPsbt(psbt) => Ok(psbt),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Proposes asset swap; the returned offer must be passed to the taker.
+    pub fn propose_swap(
+        &mut self,
+        terms: SwapTerms,
+        timeout: u32,
+        psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        progress: impl Fn(String),
+    ) -> Result<SwapOffer, Error> {
+        let mut req = SwapProposeReq {
+            terms,
+            timeout,
+            psbt,
+            consignment,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::ProposeSwap(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::SwapOffer(offer) => return Ok(offer),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Accepts asset swap offer; the returned data must be passed to the
+    /// maker.
+    pub fn accept_swap(
+        &mut self,
+        offer: SwapOffer,
+        psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        progress: impl Fn(String),
+    ) -> Result<SwapAcceptance, Error> {
+        let mut req = SwapAcceptReq {
+            offer,
+            psbt,
+            consignment,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::AcceptSwap(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Swap(acceptance) => return Ok(acceptance),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Checks the swap data received from the counterparty against the swap
+    /// terms. Returns PSBT which is ready to be signed and the consignment
+    /// for the asset received by this party.
+    pub fn complete_swap(
+        &mut self,
+        acceptance: SwapAcceptance,
+        progress: impl Fn(String),
+    ) -> Result<SwapAcceptance, Error> {
+        let mut req = SwapCompleteReq {
+            acceptance,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::CompleteSwap(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Swap(completion) => return Ok(completion),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn abort_swap(&mut self, swap_id: SwapId) -> Result<(), Error> {
+        self.request(RpcMsg::AbortSwap(swap_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Proposes payjoin to the beneficiary; the returned proposal must be
+    /// passed to the beneficiary.
+    pub fn propose_payjoin(
+        &mut self,
+        psbt: Psbt,
+        consignment: StateTransfer,
+        progress: impl Fn(String),
+    ) -> Result<PayjoinProposal, Error> {
+        let mut req = PayjoinProposeReq {
+            psbt,
+            consignment,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::ProposePayjoin(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::PayjoinProposal(proposal) => return Ok(proposal),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Contributes wallet outpoints of the beneficiary to the payjoin; the
+    /// returned data must be passed to the payer.
+    pub fn contribute_payjoin(
+        &mut self,
+        proposal: PayjoinProposal,
+        descriptor: String,
+        outpoints: BTreeSet<OutPoint>,
+        endseals: Vec<SealEndpoint>,
+        fee: u64,
+        progress: impl Fn(String),
+    ) -> Result<PayjoinContribution, Error> {
+        let mut req = PayjoinContributeReq {
+            proposal,
+            descriptor,
+            outpoints,
+            endseals,
+            fee,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::ContributePayjoin(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Payjoin(contribution) => return Ok(contribution),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Checks the joint PSBT received from the beneficiary against the
+    /// payjoin proposal. Returns PSBT which is ready to be signed and the
+    /// final consignment.
+    pub fn complete_payjoin(
+        &mut self,
+        contribution: PayjoinContribution,
+        progress: impl Fn(String),
+    ) -> Result<PayjoinContribution, Error> {
+        let mut req = PayjoinCompleteReq {
+            contribution,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::CompletePayjoin(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Payjoin(completion) => return Ok(completion),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Selects wallet UTXOs with the allocations for the payment and
+    /// constructs PSBT and a single state transition paying to all the
+    /// beneficiaries.
+    pub fn compose_payment(
+        &mut self,
+        descriptor: String,
+        contract_id: ContractId,
+        beneficiaries: BTreeMap<SealEndpoint, AtomicValue>,
+        fee: u64,
+        progress: impl Fn(String),
+    ) -> Result<PaymentDraft, Error> {
+        let mut req = PaymentReq {
+            descriptor,
+            contract_id,
+            beneficiaries,
+            fee,
+            order: None,
+        };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::ComposePayment(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::PaymentDraft(draft) => return Ok(draft),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Finalizes signed PSBT and broadcasts the extracted transaction.
+    pub fn publish_tx(&mut self, psbt: Psbt, progress: impl Fn(String)) -> Result<Txid, Error> {
+        let mut req = PublishReq { psbt, order: None };
+        req.order = self.sign_order(&req);
+        self.request(RpcMsg::PublishTx(req))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::TxPublished(txid) => return Ok(txid),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Composes PSBT of a child transaction paying for the unconfirmed witness
+    /// transaction of a pending incoming transfer.
+    pub fn accelerate_transfer(
+        &mut self,
+        descriptor: String,
+        consignment_id: ConsignmentId,
+        fee_rate: u64,
+        progress: impl Fn(String),
+    ) -> Result<CpfpDraft, Error> {
+        self.request(RpcMsg::AccelerateTransfer(AccelerateReq {
+            consignment_id,
+            descriptor,
+            fee_rate,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::CpfpDraft(draft) => return Ok(draft),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Removes contract state assigned to the wallet outpoints which were
+    /// spent without transferring it, returning the removed state.
+    pub fn forget_spent(
+        &mut self,
+        descriptor: String,
+        outpoints: BTreeSet<OutPoint>,
+        progress: impl Fn(String),
+    ) -> Result<ContractStateMap, Error> {
+        self.request(RpcMsg::ForgetSpent(ForgetSpentReq {
+            descriptor,
+            outpoints,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::OutpointState(state) => return Ok(state),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn sync_wallet(
+        &mut self,
+        descriptor: String,
+        gap_limit: u16,
+        rescan: bool,
+        progress: impl Fn(String),
+    ) -> Result<WalletBalance, Error> {
+        self.request(RpcMsg::SyncWallet(WalletReq {
+            descriptor,
+            gap_limit,
+            rescan,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Balance(balance) => return Ok(balance),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn wallet_balance(
+        &mut self,
+        descriptor: String,
+        progress: impl Fn(String),
+    ) -> Result<WalletBalance, Error> {
+        self.request(RpcMsg::GetBalance(descriptor))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Balance(balance) => return Ok(balance),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Registers wallet with the node without scanning the chain, such that
+    /// its addresses can be listed before the wallet receives any funds.
+    pub fn create_wallet(&mut self, descriptor: String) -> Result<(), Error> {
+        self.request(RpcMsg::CreateWallet(descriptor))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn list_wallets(&mut self) -> Result<Vec<WalletInfo>, Error> {
+        self.request(RpcMsg::ListWallets)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Wallets(wallets) => Ok(wallets),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists wallet addresses up to the last used derivation index of each
+    /// keychain, followed by `unused` addresses which were not used yet.
+    pub fn wallet_addresses(
+        &mut self,
+        descriptor: String,
+        unused: u16,
+    ) -> Result<Vec<WalletAddress>, Error> {
+        self.request(RpcMsg::ListAddresses(AddressesReq { descriptor, unused }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Addresses(addresses) => Ok(addresses),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Reports contract amounts allocated to the wallet and/or outpoints at
+    /// the given block height.
+    pub fn historical_balance(
+        &mut self,
+        contract_id: ContractId,
+        height: u32,
+        descriptor: Option<String>,
+        outpoints: BTreeSet<OutPoint>,
+        progress: impl Fn(String),
+    ) -> Result<HistoricalBalance, Error> {
+        self.request(RpcMsg::GetHistoricalBalance(HistoricalBalanceReq {
+            contract_id,
+            height,
+            descriptor,
+            outpoints,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::HistoricalBalance(balance) => return Ok(balance),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn annotate(&mut self, annotation: Annotation) -> Result<(), Error> {
+        self.request(RpcMsg::Annotate(annotation))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Returns annotations for the given objects; if no objects are provided,
+    /// returns all known annotations.
+    pub fn annotations(
+        &mut self,
+        targets: BTreeSet<AnnotationTarget>,
+    ) -> Result<Vec<Annotation>, Error> {
+        self.request(RpcMsg::GetAnnotations(targets))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Annotations(annotations) => Ok(annotations),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Returns incoming and outgoing transfers known to the node which match
+    /// the filter, ordered by the time they became known.
+    pub fn transfers(&mut self, filter: TransferFilter) -> Result<Vec<TransferRecord>, Error> {
+        self.request(RpcMsg::ListTransfers(filter))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::TransferList(transfers) => Ok(transfers),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists invoices of the contract created by the node, with the amounts
+    /// they have received and their payment status.
+    pub fn invoices(&mut self, contract_id: ContractId) -> Result<Vec<InvoiceRecord>, Error> {
+        self.request(RpcMsg::ListInvoices(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::InvoiceList(invoices) => Ok(invoices),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Returns receipt for the transfer with the given consignment id, signed
+    /// by the node key and reporting the current transfer status.
+    pub fn transfer_receipt(
+        &mut self,
+        consignment_id: ConsignmentId,
+    ) -> Result<SignedReceipt, Error> {
+        self.request(RpcMsg::GetReceipt(consignment_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Receipt(receipt) => Ok(receipt),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Returns signed order of the contract issue or the transfer kept in the
+    /// order journal of the node.
+    pub fn order(&mut self, target: OrderTarget) -> Result<OrderRecord, Error> {
+        self.request(RpcMsg::GetOrder(target))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Order(record) => Ok(record),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists consignments received from the remote nodes, which are kept in
+    /// the quarantine.
+    pub fn quarantine(&mut self) -> Result<Vec<QuarantineInfo>, Error> {
+        self.request(RpcMsg::ListQuarantine)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::QuarantineList(list) => Ok(list),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Takes consignment out of the quarantine and consumes it, bypassing the
+    /// node acceptance policy. If the consignment fails validation, it is
+    /// returned into the quarantine.
+    pub fn approve_quarantined(
+        &mut self,
+        consignment_id: ConsignmentId,
+        progress: impl Fn(String),
+    ) -> Result<ContractValidity, Error> {
+        self.request(RpcMsg::ApproveQuarantined(consignment_id))?;
+        self.quarantine_validity(progress)
+    }
+
+    /// Validates quarantined consignment once again, for instance after its
+    /// transactions got mined, and consumes it if it passes validation.
+    pub fn revalidate_quarantined(
+        &mut self,
+        consignment_id: ConsignmentId,
+        progress: impl Fn(String),
+    ) -> Result<ContractValidity, Error> {
+        self.request(RpcMsg::RevalidateQuarantined(consignment_id))?;
+        self.quarantine_validity(progress)
+    }
+
+    fn quarantine_validity(
+        &mut self,
+        progress: impl Fn(String),
+    ) -> Result<ContractValidity, Error> {
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Valid(report) => return Ok(ContractValidity::Valid(report)),
+                RpcMsg::Invalid(report) => return Ok(ContractValidity::Invalid(report)),
+                RpcMsg::UnresolvedTxids(report) => {
+                    return Ok(ContractValidity::UnknownTxids(report))
+                }
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Drops consignment from the quarantine without processing it.
+    pub fn purge_quarantined(&mut self, consignment_id: ConsignmentId) -> Result<(), Error> {
+        self.request(RpcMsg::PurgeQuarantined(consignment_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Removes data of the consignment containers which are no longer needed
+    /// from the store.
+    pub fn collect_garbage(&mut self, progress: impl Fn(String)) -> Result<GcReport, Error> {
+        self.request(RpcMsg::CollectGarbage)?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::GarbageCollected(report) => return Ok(report),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Prunes data which retention period configured for the node has passed.
+    /// In dry-run mode reports the data which would be pruned without removing
+    /// them.
+    pub fn prune(
+        &mut self,
+        dry_run: bool,
+        progress: impl Fn(String),
+    ) -> Result<PruneReport, Error> {
+        self.request(RpcMsg::Prune(PruneReq { dry_run }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Pruned(report) => return Ok(report),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Removes the contract and all the data derived from it from the node.
+    pub fn forget_contract(
+        &mut self,
+        contract_id: ContractId,
+        tombstone: bool,
+        progress: impl Fn(String),
+    ) -> Result<ForgetReport, Error> {
+        self.request(RpcMsg::ForgetContract(ForgetReq {
+            contract_id,
+            tombstone,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::ContractForgotten(report) => return Ok(report),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Drops the state of the contract, or of all contracts if no contract id
+    /// is given, and reconstructs it from the genesis and state transitions
+    /// kept in the stash.
+    pub fn rebuild_state(
+        &mut self,
+        contract_id: Option<ContractId>,
+        progress: impl Fn(String),
+    ) -> Result<RebuildReport, Error> {
+        self.request(RpcMsg::RebuildState(contract_id))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::StateRebuilt(report) => return Ok(report),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Re-validates the contract, or all contracts validated by an older
+    /// version of the validation rules if no contract id is given, reporting
+    /// the contracts which are found invalid.
+    pub fn revalidate_contracts(
+        &mut self,
+        contract_id: Option<ContractId>,
+        progress: impl Fn(String),
+    ) -> Result<RevalidationReport, Error> {
+        self.request(RpcMsg::RevalidateContracts(contract_id))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::ContractsRevalidated(report) => return Ok(report),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Lists consignments accepted for the contract by a node working with
+    /// regtest, in the order of their acceptance.
+    pub fn journal(&mut self, contract_id: ContractId) -> Result<Vec<JournalEntry>, Error> {
+        self.request(RpcMsg::GetJournal(contract_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Journal(journal) => Ok(journal),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Rewinds contract state to the moment before the consignment was
+    /// accepted by a node working with regtest.
+    pub fn rewind_contract(
+        &mut self,
+        contract_id: ContractId,
+        consignment_id: ConsignmentId,
+        progress: impl Fn(String),
+    ) -> Result<StateChange, Error> {
+        self.request(RpcMsg::RewindContract(RewindReq {
+            contract_id,
+            consignment_id,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::StateChanged(change) => return Ok(change),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Re-applies consignments from the contract journal of a node working
+    /// with regtest.
+    pub fn replay_consignments(
+        &mut self,
+        contract_id: ContractId,
+        consignments: Vec<ConsignmentId>,
+        progress: impl Fn(String),
+    ) -> Result<StateChange, Error> {
+        self.request(RpcMsg::ReplayConsignments(ReplayReq {
+            contract_id,
+            consignments,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::StateChanged(change) => return Ok(change),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn health(&mut self) -> Result<NodeHealth, Error> {
+        self.request(RpcMsg::GetHealth)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Health(health) => Ok(health),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Reports settings of the node together with the configuration layers
+    /// providing them
+    pub fn config(&mut self) -> Result<Vec<Setting>, Error> {
+        self.request(RpcMsg::GetConfig)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Config(settings) => Ok(settings),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Cancels request of another client, identified by the client id. Ids
+    /// of the clients which requests are being processed are reported by
+    /// the node health information.
+    pub fn cancel(&mut self, client_id: ClientId) -> Result<(), Error> {
+        self.request(RpcMsg::Cancel(client_id))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Promotes read replica to primary: the node stops following the
+    /// primary node and starts accepting requests modifying its stash.
+    pub fn promote(&mut self) -> Result<(), Error> {
+        self.request(RpcMsg::Promote)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Authenticates the client identity on a node with access control
+    /// enabled by signing the nonce issued by the node in response to the
+    /// [`Client::hello`] request. The node accepts each nonce once, so a new
+    /// `hello` request is required to authenticate again.
+    pub fn authenticate(&mut self, keypair: &KeyPair) -> Result<(), Error> {
+        let (node, nonce) = match (self.node_identity, self.auth_nonce.take()) {
+            (Some(node), Some(nonce)) => (node, nonce),
+            _ => {
+                return Err(Error::LocalFailure {
+                    code: FailureCode::Access,
+                    message: s!("node has not issued authentication nonce in response to hello"),
+                })
+            }
+        };
+        let auth = ClientAuth::sign(&Secp256k1::signing_only(), keypair, node, nonce);
+        self.request(RpcMsg::Authenticate(auth))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn grant_access(
+        &mut self,
+        identity: XOnlyPublicKey,
+        target: AccessTarget,
+    ) -> Result<(), Error> {
+        self.request(RpcMsg::GrantAccess(AccessReq { identity, target }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn revoke_access(
+        &mut self,
+        identity: XOnlyPublicKey,
+        target: AccessTarget,
+    ) -> Result<(), Error> {
+        self.request(RpcMsg::RevokeAccess(AccessReq { identity, target }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::Success(_) => Ok(()),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists contracts and wallets accessible by each of the client
+    /// identities known to the node.
+    pub fn list_access(&mut self) -> Result<Vec<AccessGrants>, Error> {
+        self.request(RpcMsg::ListAccess)?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::AccessGrants(grants) => Ok(grants),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    pub fn consume_transfer(
+        &mut self,
+        transfer: StateTransfer,
+        force: bool,
+        full: bool,
+        reveal: Option<Reveal>,
+        proofs: Option<ProofBundle>,
+        progress: impl Fn(String),
+    ) -> Result<ContractValidity, Error> {
+        self.request(RpcMsg::ConsumeTransfer(AcceptReq {
+            consignment: transfer,
+            reveal,
+            force,
+            full,
+            timeout: self.timeout,
+            proofs,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Valid(report) => return Ok(ContractValidity::Valid(report)),
+                RpcMsg::Invalid(report) => return Ok(ContractValidity::Invalid(report)),
+                RpcMsg::UnresolvedTxids(report) => {
+                    return Ok(ContractValidity::UnknownTxids(report))
+                }
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Requests proofs of the transactions inclusion into the blockchain,
+    /// which can be verified by a node having header checkpoint at the given
+    /// height.
+    pub fn prove_transactions(
+        &mut self,
+        txids: BTreeSet<Txid>,
+        from_height: u32,
+        progress: impl Fn(String),
+    ) -> Result<ProofBundle, Error> {
+        self.request(RpcMsg::ProveTransactions(ProveReq { txids, from_height }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Proofs(proofs) => return Ok(proofs),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Uploads strict-encoded consignment to the node in chunks and consumes
+    /// it. Useful for consignments received out-of-band, which are not known
+    /// to the Storm container store and may be not decodable by the client.
+    pub fn consume_upload(
+        &mut self,
+        kind: UploadKind,
+        data: &[u8],
+        force: bool,
+        full: bool,
+        reveal: Option<Reveal>,
+        proofs: Option<ProofBundle>,
+        progress: impl Fn(String),
+    ) -> Result<ContractValidity, Error> {
+        self.upload(data)?;
+        progress(format!("Uploaded {} bytes", data.len()));
+        self.request(RpcMsg::ConsumeUpload(UploadReq {
+            kind,
+            size: data.len() as u32,
+            force,
+            full,
+            reveal,
+            timeout: self.timeout,
+            proofs,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Valid(report) => return Ok(ContractValidity::Valid(report)),
+                RpcMsg::Invalid(report) => return Ok(ContractValidity::Invalid(report)),
+                RpcMsg::UnresolvedTxids(report) => {
+                    return Ok(ContractValidity::UnknownTxids(report))
+                }
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    pub fn process_disclosure(
+        &mut self,
+        txid: Txid,
         progress: impl Fn(String),
     ) -> Result<bool, Error> {
         self.request(RpcMsg::ProcessDisclosure(txid))?;
@@ -294,6 +1699,150 @@ impl Client {
             }
         }
     }
+
+    /// Reveals seal of the contract assignments accepted with the seal being
+    /// concealed, returning ids of the updated state transitions. If no
+    /// accepted transition has the seal, the reveal is kept by the node and
+    /// applied once a consignment with the seal is accepted, and the returned
+    /// set is empty.
+    pub fn reveal_seal(
+        &mut self,
+        contract_id: ContractId,
+        reveal: Reveal,
+        progress: impl Fn(String),
+    ) -> Result<BTreeSet<NodeId>, Error> {
+        self.request(RpcMsg::RevealSeal(RevealReq {
+            contract_id,
+            reveal,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::SealRevealed(node_ids) => return Ok(node_ids),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Creates invoice for receiving the contract asset on the given
+    /// outpoint; zero amount creates an open invoice paid with the amount
+    /// chosen by the payer. The seal blinding factor is generated from the
+    /// entropy source of the node, which refuses to create the invoice if the
+    /// source fails its health check. The invoice is kept in the invoice
+    /// registry of the node, which records the amounts it receives.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_invoice(
+        &mut self,
+        contract_id: ContractId,
+        amount: AtomicValue,
+        outpoint: OutPoint,
+        close_method: CloseMethod,
+        transport: Option<NodeAddr>,
+        tolerance: InvoiceTolerance,
+        progress: impl Fn(String),
+    ) -> Result<Invoice, Error> {
+        self.request(RpcMsg::CreateInvoice(InvoiceReq {
+            contract_id,
+            amount,
+            outpoint,
+            close_method,
+            transport,
+            signet: self.signet,
+            tolerance,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::Invoice(invoice) => return Ok(invoice),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Exports reveal data for the contract seals defined on the given
+    /// outpoints, which can be passed to the counterparty holding the state
+    /// assigned to the seals in concealed form.
+    pub fn export_reveals(
+        &mut self,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+        progress: impl Fn(String),
+    ) -> Result<RevealData, Error> {
+        self.request(RpcMsg::ExportReveals(RevealExportReq {
+            contract_id,
+            outpoints,
+        }))?;
+        loop {
+            match self.response()?.failure_to_error()? {
+                RpcMsg::RevealData(data) => return Ok(data),
+                RpcMsg::Progress(info) => progress(info),
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+    }
+
+    /// Uploads payload sealed to the recipient key with [`seal_payload`] and
+    /// deposits it with the relay node.
+    ///
+    /// [`seal_payload`]: crate::seal_payload
+    pub fn relay_deposit(
+        &mut self,
+        recipient: XOnlyPublicKey,
+        payload: &[u8],
+        ttl: Option<u32>,
+        forward: bool,
+    ) -> Result<RelayEnvelope, Error> {
+        self.upload(payload)?;
+        self.request(RpcMsg::RelayDeposit(RelayDepositReq {
+            recipient,
+            size: payload.len() as u32,
+            ttl,
+            forward,
+        }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::RelayDeposited(envelope) => Ok(envelope),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Lists envelopes held by the relay for the recipient, removing the
+    /// acknowledged envelopes first.
+    pub fn relay_pickup(
+        &mut self,
+        auth: RelayAuth,
+        ack: BTreeSet<EnvelopeId>,
+    ) -> Result<Vec<RelayEnvelope>, Error> {
+        self.request(RpcMsg::RelayPickup(RelayPickupReq { auth, ack }))?;
+        match self.response()?.failure_to_error()? {
+            RpcMsg::RelayEnvelopes(envelopes) => Ok(envelopes),
+            _ => Err(Error::UnexpectedServerResponse),
+        }
+    }
+
+    /// Downloads sealed payload of the envelope held by the relay.
+    pub fn relay_fetch(
+        &mut self,
+        auth: RelayAuth,
+        envelope: RelayEnvelope,
+    ) -> Result<Vec<u8>, Error> {
+        let mut payload = Vec::with_capacity(envelope.size as usize);
+        while payload.len() < envelope.size as usize {
+            self.request(RpcMsg::RelayFetch(RelayFetchReq {
+                auth,
+                envelope_id: envelope.id,
+                offset: payload.len() as u32,
+            }))?;
+            match self.response()?.failure_to_error()? {
+                RpcMsg::RelayChunk(chunk)
+                    if chunk.offset as usize == payload.len() && !chunk.data.is_empty() =>
+                {
+                    payload.extend(chunk.data)
+                }
+                _ => return Err(Error::UnexpectedServerResponse),
+            }
+        }
+        Ok(payload)
+    }
 }
 
 pub struct Handler {
@@ -0,0 +1,19 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Wall clock used to timestamp node records, receipts and signed orders.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns current UNIX timestamp in seconds, or zero if the system clock is
+/// set before the UNIX epoch.
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
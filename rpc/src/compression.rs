@@ -0,0 +1,139 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Compression of large payloads: RPC messages carrying consignments and
+//! consignment containers relayed to the remote peers.
+
+use std::io::{self, Read, Write};
+
+use strict_encoding::{StrictDecode, StrictEncode};
+
+/// Payloads smaller than this size are not worth compressing
+pub const COMPRESSION_THRESHOLD: usize = 4096;
+
+/// Maximal size of decompressed data. Protects from malicious payloads
+/// expanding to the sizes exhausting the memory.
+pub const MAX_DECOMPRESSED_SIZE: u64 = 0x1_0000_0000;
+
+/// Compression algorithm applied to the payload
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+pub enum Compression {
+    /// Uncompressed payload
+    #[display("none")]
+    None,
+
+    /// Zstandard algorithm
+    #[display("zstd")]
+    Zstd,
+}
+
+impl Default for Compression {
+    fn default() -> Self { Compression::None }
+}
+
+impl Compression {
+    /// Returns streaming compressor writing compressed data into `writer`.
+    pub fn encoder<W: Write>(self, writer: W) -> Encoder<W> {
+        match self {
+            Compression::None => Encoder::Plain(writer),
+            Compression::Zstd => {
+                let encoder = zstd::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .expect("zstd context allocation failure");
+                Encoder::Zstd(Box::new(encoder))
+            }
+        }
+    }
+
+    /// Returns streaming decompressor reading compressed data from `reader`.
+    /// The decompressor fails to read data beyond [`MAX_DECOMPRESSED_SIZE`].
+    pub fn decoder<R: Read>(self, reader: R) -> Decoder<R> {
+        match self {
+            Compression::None => Decoder::Plain(reader),
+            Compression::Zstd => {
+                let decoder = zstd::Decoder::new(reader).expect("zstd context allocation failure");
+                Decoder::Zstd(Box::new(decoder.take(MAX_DECOMPRESSED_SIZE)))
+            }
+        }
+    }
+
+    /// Compresses data in memory.
+    pub fn compress(self, data: &[u8]) -> Vec<u8> {
+        let mut encoder = self.encoder(Vec::with_capacity(data.len()));
+        encoder.write_all(data).expect("in-memory writers do not error");
+        encoder.finish().expect("in-memory writers do not error")
+    }
+}
+
+/// Streaming compressor, see [`Compression::encoder`]
+pub enum Encoder<W: Write> {
+    Plain(W),
+    Zstd(Box<zstd::Encoder<'static, W>>),
+}
+
+impl<W: Write> Encoder<W> {
+    /// Writes all pending compressed data and returns the inner writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            Encoder::Plain(writer) => Ok(writer),
+            Encoder::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Encoder::Plain(writer) => writer.write(buf),
+            Encoder::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Encoder::Plain(writer) => writer.flush(),
+            Encoder::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// Streaming decompressor, see [`Compression::decoder`]
+pub enum Decoder<R: Read> {
+    Plain(R),
+    Zstd(Box<io::Take<zstd::Decoder<'static, io::BufReader<R>>>>),
+}
+
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Decoder::Plain(reader) => reader.read(buf),
+            Decoder::Zstd(decoder) => decoder.read(buf),
+        }
+    }
+}
+
+impl StrictEncode for Compression {
+    fn strict_encode<E: Write>(&self, e: E) -> Result<usize, strict_encoding::Error> {
+        match self {
+            Compression::None => 0u8,
+            Compression::Zstd => 1u8,
+        }
+        .strict_encode(e)
+    }
+}
+
+impl StrictDecode for Compression {
+    fn strict_decode<D: Read>(d: D) -> Result<Self, strict_encoding::Error> {
+        match u8::strict_decode(d)? {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Zstd),
+            value => Err(strict_encoding::Error::EnumValueNotKnown("Compression", value as usize)),
+        }
+    }
+}
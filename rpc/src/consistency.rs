@@ -0,0 +1,122 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Client-side verification of the consignment internal consistency.
+//!
+//! The checks do not require chain access or contract history known to the
+//! node and detect corrupted or tampered consignment files before they are
+//! sent to the node, which would otherwise report just a generic validation
+//! failure.
+
+use std::collections::BTreeSet;
+
+use bitcoin::Txid;
+use commit_verify::CommitConceal;
+use rgb::{
+    BundleId, Consignment, ConsignmentType, InmemConsignment, Node, SchemaId, SealEndpoint,
+    TransitionBundle,
+};
+
+use crate::{Error, FailureCode};
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ConsistencyError {
+    /// consignment genesis uses schema {genesis} while the consignment
+    /// provides schema {provided}.
+    SchemaMismatch {
+        genesis: SchemaId,
+        provided: SchemaId,
+    },
+
+    /// transition bundle {0} is not consistent with the transitions it
+    /// contains.
+    BundleInvalid(BundleId),
+
+    /// transition bundle {0} is present in the consignment more than once.
+    BundleDuplicated(BundleId),
+
+    /// anchor of the witness transaction {1} does not commit to the
+    /// transition bundle {0}.
+    AnchorMismatch(BundleId, Txid),
+
+    /// consignment endpoint references transition bundle {0} which is absent
+    /// in the consignment.
+    EndpointBundleAbsent(BundleId),
+
+    /// none of the transitions in bundle {0} assigns state to the consignment
+    /// endpoint {1}.
+    EndpointSealAbsent(BundleId, SealEndpoint),
+}
+
+impl From<&ConsistencyError> for FailureCode {
+    fn from(err: &ConsistencyError) -> Self {
+        match err {
+            ConsistencyError::AnchorMismatch(..) => FailureCode::InvalidAnchor,
+            _ => FailureCode::InvalidConsignment,
+        }
+    }
+}
+
+impl From<ConsistencyError> for Error {
+    fn from(err: ConsistencyError) -> Self {
+        Error::LocalFailure {
+            code: FailureCode::from(&err),
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Verifies internal consistency of the consignment: its schema, transition
+/// bundle ids, anchor commitments to the bundles and presence of the
+/// consignment endpoints.
+///
+/// Passing the verification does not mean the consignment is valid: the
+/// verification does not check witness transactions, schema rules and the
+/// contract history, which is done by the node.
+pub fn verify_consignment<C: ConsignmentType>(
+    consignment: &InmemConsignment<C>,
+) -> Result<(), ConsistencyError> {
+    let genesis = consignment.genesis().schema_id();
+    let provided = consignment.schema().schema_id();
+    if genesis != provided {
+        return Err(ConsistencyError::SchemaMismatch { genesis, provided });
+    }
+
+    let contract_id = consignment.contract_id();
+    let mut bundles = bmap! {};
+    for (anchor, bundle) in consignment.anchored_bundles() {
+        let bundle_id = bundle.bundle_id();
+        if !TransitionBundle::validate(bundle) {
+            return Err(ConsistencyError::BundleInvalid(bundle_id));
+        }
+        if anchor.to_merkle_block(contract_id, bundle_id.into()).is_err() {
+            return Err(ConsistencyError::AnchorMismatch(bundle_id, anchor.txid));
+        }
+        if bundles.insert(bundle_id, bundle).is_some() {
+            return Err(ConsistencyError::BundleDuplicated(bundle_id));
+        }
+    }
+
+    for (bundle_id, seal_endpoint) in consignment.endpoints() {
+        let bundle =
+            bundles.get(bundle_id).ok_or(ConsistencyError::EndpointBundleAbsent(*bundle_id))?;
+        let concealed = seal_endpoint.commit_conceal();
+        let seals = bundle
+            .known_transitions()
+            .flat_map(|transition| transition.to_confiential_seals())
+            .collect::<BTreeSet<_>>();
+        if !seals.contains(&concealed) {
+            return Err(ConsistencyError::EndpointSealAbsent(*bundle_id, *seal_endpoint));
+        }
+    }
+
+    Ok(())
+}
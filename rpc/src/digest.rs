@@ -0,0 +1,98 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Digests of the contract data known to a node, allowing two nodes (like
+//! payer and payee, or primary node and its replica) to check whether they
+//! share the same view of a contract without exchanging the contract history.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use rgb::{BundleId, ContractId, ContractState};
+use strict_encoding::StrictEncode;
+
+/// Tag used in the contract state digest
+const STATE_DIGEST_TAG: &[u8] = b"rgb:node:state-digest";
+
+/// Canonical digest of the contract history and state known to the node
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{digest} ({bundles} bundle(s))")]
+pub struct StateDigest {
+    pub contract_id: ContractId,
+    /// Tagged SHA256 hash of the ids of the known transition bundles and of the
+    /// strict-encoded contract state
+    pub digest: sha256::Hash,
+    /// Number of the known transition bundles
+    pub bundles: u32,
+}
+
+impl StateDigest {
+    /// Computes digest of the contract data. Bundle ids are committed in
+    /// their lexicographic order, so the digest does not depend on the order
+    /// in which the node has learned the contract history. Nodes knowing the
+    /// same history, but having different revealed state data, get different
+    /// digests.
+    pub fn compute(
+        contract_id: ContractId,
+        bundle_ids: &BTreeSet<BundleId>,
+        state: &ContractState,
+    ) -> StateDigest {
+        let tag = sha256::Hash::hash(STATE_DIGEST_TAG);
+        let mut engine = sha256::Hash::engine();
+        engine.input(&tag[..]);
+        engine.input(&tag[..]);
+        contract_id.strict_encode(&mut engine).expect("hash engines do not error");
+        bundle_ids.strict_encode(&mut engine).expect("hash engines do not error");
+        state.strict_encode(&mut engine).expect("hash engines do not error");
+        StateDigest {
+            contract_id,
+            digest: sha256::Hash::from_engine(engine),
+            bundles: bundle_ids.len() as u32,
+        }
+    }
+}
+
+/// Request comparing contract history known to the node with the history
+/// known to another node
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("diff_history({contract_id}, ...)")]
+pub struct DiffReq {
+    pub contract_id: ContractId,
+    /// Ids of the transition bundles known to the other node
+    pub bundles: BTreeSet<BundleId>,
+}
+
+/// Transition bundles known to one of the compared nodes only
+#[derive(Clone, PartialEq, Eq, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{} local and {} remote bundle(s)", self.local_only.len(), self.remote_only.len())]
+pub struct HistoryDiff {
+    /// Bundles known to the node, but not to the other node
+    pub local_only: BTreeSet<BundleId>,
+    /// Bundles known to the other node, but not to the node
+    pub remote_only: BTreeSet<BundleId>,
+}
+
+impl HistoryDiff {
+    /// Detects whether both nodes know the same contract history
+    pub fn is_empty(&self) -> bool { self.local_only.is_empty() && self.remote_only.is_empty() }
+}
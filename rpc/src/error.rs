@@ -12,34 +12,406 @@ use std::fmt::{self, Display, Formatter};
 
 use microservices::{esb, rpc};
 
-use crate::{RpcMsg, ServiceId};
+use crate::{RpcMsg, ServiceId, VersionError};
 
+/// Category of the failure codes, allowing clients to handle groups of related
+/// failures in the same way.
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Display)]
+#[display(lowercase)]
+pub enum FailureCategory {
+    /// RPC protocol, message encoding and request processing failures
+    Protocol,
+
+    /// Data store and stash failures
+    Store,
+
+    /// Chain backend failures and transactions not matching the chain state
+    Chain,
+
+    /// Invalid PSBTs, anchors, proofs, signatures and swap data
+    Validation,
+
+    /// Wallet, payment, swap and transfer failures
+    Wallet,
+
+    /// Violations of contract policies, the quarantine rules and rejections by
+    /// validation plugins
+    Policy,
+
+    /// Requests exceeding the rate, size or validation resource limits of the
+    /// node
+    RateLimit,
+
+    /// Failures of the node daemons, validation plugins and external services
+    Internal,
+}
+
+/// Codes of the failures reported by the node over RPC.
+///
+/// Generic codes below `0x100` are kept for compatibility with earlier clients
+/// and are used for the failures which do not have a more specific code. More
+/// specific codes are grouped by their [`FailureCategory`]:
+/// - `0x1xx`: store and stash;
+/// - `0x2xx`: chain backend;
+/// - `0x3xx`: validation;
+/// - `0x4xx`: wallet and transfers;
+/// - `0x5xx`: policy;
+/// - `0x6xx`: rate and size limits.
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
 pub enum FailureCode {
     /// Catch-all
     Unknown = 0xFFF,
 
+    /// Client and node use different chains
     ChainMismatch = 0x01,
 
+    /// RPC protocol version is not supported
+    UnsupportedVersion = 0x02,
+
     /// Encoding
     Encoding = 0x10,
 
+    /// Message bus error
     Esb = 0x11,
 
+    /// Data store error
     Store = 0x12,
 
+    /// Stash data are inconsistent or can't be processed
     Stash = 0x13,
 
+    /// Request referenced by the client is not known
     Absent = 0x14,
 
+    /// PSBT finalization error
     Finalize = 0x15,
 
+    /// Electrum server is not reachable
     ElectrumConnectivity = 0x16,
 
+    /// External signer error
+    Signer = 0x17,
+
+    /// Wallet descriptor or synchronization error
+    Wallet = 0x18,
+
+    /// Contract checkpoint creation or import error
+    Checkpoint = 0x19,
+
+    /// Contract policy violation or allocation consolidation error
+    Policy = 0x1A,
+
+    /// Asset swap error
+    Swap = 0x1B,
+
+    /// Payment coin selection, PSBT construction or publishing error
+    Payment = 0x1C,
+
+    /// Consignment is blacklisted or absent in the quarantine
+    Quarantine = 0x1D,
+
+    /// Consignment upload is incomplete, out of order or oversized
+    Upload = 0x1E,
+
+    /// Request was cancelled by the client or its deadline has expired
+    Cancelled = 0x1F,
+
+    /// Transaction proofs are invalid or can't be verified or constructed
+    Proof = 0x20,
+
+    /// Validation plugin can't be loaded or has failed
+    Plugin = 0x21,
+
+    /// Request modifies the stash of a read replica, or the node can't be
+    /// promoted to primary
+    Replica = 0x22,
+
+    /// Timestamping calendar is not configured or has failed
+    Timestamp = 0x23,
+
+    /// Contract time travel is not available for the chain, or the contract
+    /// journal misses the consignment
+    Simulation = 0x24,
+
+    /// Node does not run as a relay, or the relayed envelope is unknown,
+    /// expired or not accessible to the client
+    Relay = 0x25,
+
+    /// Payjoin is unknown or was already processed, or the contributed
+    /// outpoints can't be used for it
+    Payjoin = 0x26,
+
+    /// Node can't issue transfer receipts since it has no node key
+    Receipt = 0x27,
+
+    /// Client is not authenticated or has no access to the contract, wallet
+    /// or the request
+    Access = 0x28,
+
+    /// Node identity does not match the identity pinned by the client, or the
+    /// node has failed to prove it controls the identity key
+    Identity = 0x29,
+
+    /// Entropy source of the blinding factors is not available or has failed
+    /// the health check
+    Entropy = 0x2A,
+
+    /// Request is not supported by the node
     UnexpectedRequest = 0x80,
 
     /// Daemon launcher error
     Launcher = 0x81,
+
+    /// Worker daemon has terminated while processing the request
+    WorkerFailed = 0x82,
+
+    /// Container requested for the processing is absent in the store
+    ContainerAbsent = 0x100,
+
+    /// Contract is not known to the node
+    ContractUnknown = 0x101,
+
+    /// Contract was forgotten with a tombstone
+    ContractForgotten = 0x102,
+
+    /// Chain backend has failed to provide the requested data
+    ChainBackend = 0x200,
+
+    /// Transaction is not mined yet
+    TxUnmined = 0x201,
+
+    /// Transaction is already mined
+    TxMined = 0x202,
+
+    /// Transaction can't be published
+    Broadcast = 0x203,
+
+    /// Node has no header checkpoint required to verify transaction proofs
+    NoHeaderCheckpoint = 0x204,
+
+    /// PSBT is invalid or misses RGB data
+    InvalidPsbt = 0x300,
+
+    /// Anchor is invalid or does not commit to the contract
+    InvalidAnchor = 0x301,
+
+    /// Transaction proofs do not match the header chain
+    InvalidProof = 0x302,
+
+    /// Signature does not match the signed data
+    InvalidSignature = 0x303,
+
+    /// Data are signed by an issuer not trusted by the node
+    UntrustedIssuer = 0x304,
+
+    /// Swap PSBT or consignment does not match the swap terms
+    SwapMismatch = 0x305,
+
+    /// Consignment is not internally consistent
+    InvalidConsignment = 0x306,
+
+    /// Joint payjoin PSBT or consignment does not match the payer proposal
+    PayjoinMismatch = 0x307,
+
+    /// Issuance specification does not match the contract schema
+    InvalidIssueSpec = 0x308,
+
+    /// Wallet descriptor is invalid or not supported
+    InvalidDescriptor = 0x400,
+
+    /// Wallet was never synchronized
+    UnknownWallet = 0x401,
+
+    /// Wallet has not enough bitcoins
+    InsufficientFunds = 0x402,
+
+    /// Wallet has not enough assets
+    InsufficientAssets = 0x403,
+
+    /// Outpoint does not belong to the wallet
+    ForeignOutpoint = 0x404,
+
+    /// Outpoint is not spent
+    Unspent = 0x405,
+
+    /// Outpoint is spent by a transaction with a pending disclosure
+    PendingDisclosure = 0x406,
+
+    /// Transfer is not known to the node
+    UnknownTransfer = 0x407,
+
+    /// Transfer is not pending or its witness transaction pays sufficient fee
+    TransferState = 0x408,
+
+    /// State allocation is below the minimal allocation set by the contract
+    /// policy
+    DustAllocation = 0x500,
+
+    /// Contract is blacklisted
+    Blacklisted = 0x501,
+
+    /// Consignment requires manual approval
+    NotApproved = 0x502,
+
+    /// Contract state can't be consolidated
+    NotConsolidable = 0x503,
+
+    /// Consignment is rejected by a validation plugin
+    PluginRejected = 0x504,
+
+    /// Client has exceeded the request rate limit
+    RateLimited = 0x600,
+
+    /// Request data exceed the size limit
+    Oversized = 0x601,
+
+    /// Consignment exceeds the resource limits of the script validation
+    ScriptLimit = 0x602,
+}
+
+impl FailureCode {
+    /// All known failure codes
+    pub const ALL: [FailureCode; 66] = [
+        FailureCode::ChainMismatch,
+        FailureCode::UnsupportedVersion,
+        FailureCode::Encoding,
+        FailureCode::Esb,
+        FailureCode::Store,
+        FailureCode::Stash,
+        FailureCode::Absent,
+        FailureCode::Finalize,
+        FailureCode::ElectrumConnectivity,
+        FailureCode::Signer,
+        FailureCode::Wallet,
+        FailureCode::Checkpoint,
+        FailureCode::Policy,
+        FailureCode::Swap,
+        FailureCode::Payment,
+        FailureCode::Quarantine,
+        FailureCode::Upload,
+        FailureCode::Cancelled,
+        FailureCode::Proof,
+        FailureCode::Plugin,
+        FailureCode::Replica,
+        FailureCode::Timestamp,
+        FailureCode::Simulation,
+        FailureCode::Relay,
+        FailureCode::Payjoin,
+        FailureCode::Receipt,
+        FailureCode::Access,
+        FailureCode::Identity,
+        FailureCode::Entropy,
+        FailureCode::UnexpectedRequest,
+        FailureCode::Launcher,
+        FailureCode::WorkerFailed,
+        FailureCode::ContainerAbsent,
+        FailureCode::ContractUnknown,
+        FailureCode::ContractForgotten,
+        FailureCode::ChainBackend,
+        FailureCode::TxUnmined,
+        FailureCode::TxMined,
+        FailureCode::Broadcast,
+        FailureCode::NoHeaderCheckpoint,
+        FailureCode::InvalidPsbt,
+        FailureCode::InvalidAnchor,
+        FailureCode::InvalidProof,
+        FailureCode::InvalidSignature,
+        FailureCode::UntrustedIssuer,
+        FailureCode::SwapMismatch,
+        FailureCode::InvalidConsignment,
+        FailureCode::PayjoinMismatch,
+        FailureCode::InvalidIssueSpec,
+        FailureCode::InvalidDescriptor,
+        FailureCode::UnknownWallet,
+        FailureCode::InsufficientFunds,
+        FailureCode::InsufficientAssets,
+        FailureCode::ForeignOutpoint,
+        FailureCode::Unspent,
+        FailureCode::PendingDisclosure,
+        FailureCode::UnknownTransfer,
+        FailureCode::TransferState,
+        FailureCode::DustAllocation,
+        FailureCode::Blacklisted,
+        FailureCode::NotApproved,
+        FailureCode::NotConsolidable,
+        FailureCode::PluginRejected,
+        FailureCode::RateLimited,
+        FailureCode::Oversized,
+        FailureCode::ScriptLimit,
+    ];
+
+    /// Category of the failure
+    pub fn category(self) -> FailureCategory {
+        match self {
+            FailureCode::ChainMismatch
+            | FailureCode::UnsupportedVersion
+            | FailureCode::Encoding
+            | FailureCode::Esb
+            | FailureCode::Absent
+            | FailureCode::Cancelled
+            | FailureCode::Upload
+            | FailureCode::Replica
+            | FailureCode::Simulation
+            | FailureCode::Relay
+            | FailureCode::Access
+            | FailureCode::Identity
+            | FailureCode::UnexpectedRequest => FailureCategory::Protocol,
+            FailureCode::Store
+            | FailureCode::Stash
+            | FailureCode::ContainerAbsent
+            | FailureCode::ContractUnknown
+            | FailureCode::ContractForgotten => FailureCategory::Store,
+            FailureCode::ElectrumConnectivity
+            | FailureCode::ChainBackend
+            | FailureCode::TxUnmined
+            | FailureCode::TxMined
+            | FailureCode::Broadcast
+            | FailureCode::NoHeaderCheckpoint => FailureCategory::Chain,
+            FailureCode::Finalize
+            | FailureCode::Checkpoint
+            | FailureCode::Proof
+            | FailureCode::InvalidPsbt
+            | FailureCode::InvalidAnchor
+            | FailureCode::InvalidProof
+            | FailureCode::InvalidSignature
+            | FailureCode::UntrustedIssuer
+            | FailureCode::SwapMismatch
+            | FailureCode::InvalidConsignment
+            | FailureCode::PayjoinMismatch
+            | FailureCode::InvalidIssueSpec => FailureCategory::Validation,
+            FailureCode::Wallet
+            | FailureCode::Swap
+            | FailureCode::Payjoin
+            | FailureCode::Receipt
+            | FailureCode::Payment
+            | FailureCode::InvalidDescriptor
+            | FailureCode::UnknownWallet
+            | FailureCode::InsufficientFunds
+            | FailureCode::InsufficientAssets
+            | FailureCode::ForeignOutpoint
+            | FailureCode::Unspent
+            | FailureCode::PendingDisclosure
+            | FailureCode::UnknownTransfer
+            | FailureCode::TransferState => FailureCategory::Wallet,
+            FailureCode::Policy
+            | FailureCode::Quarantine
+            | FailureCode::DustAllocation
+            | FailureCode::Blacklisted
+            | FailureCode::NotApproved
+            | FailureCode::NotConsolidable
+            | FailureCode::PluginRejected => FailureCategory::Policy,
+            FailureCode::RateLimited | FailureCode::Oversized | FailureCode::ScriptLimit => {
+                FailureCategory::RateLimit
+            }
+            FailureCode::Unknown
+            | FailureCode::Signer
+            | FailureCode::Plugin
+            | FailureCode::Timestamp
+            | FailureCode::Entropy
+            | FailureCode::Launcher
+            | FailureCode::WorkerFailed => FailureCategory::Internal,
+        }
+    }
 }
 
 impl Display for FailureCode {
@@ -51,19 +423,10 @@ impl Display for FailureCode {
 
 impl From<u16> for FailureCode {
     fn from(value: u16) -> Self {
-        match value {
-            x if x == FailureCode::ChainMismatch as u16 => FailureCode::ChainMismatch,
-            x if x == FailureCode::Encoding as u16 => FailureCode::Encoding,
-            x if x == FailureCode::Esb as u16 => FailureCode::Esb,
-            x if x == FailureCode::Store as u16 => FailureCode::Store,
-            x if x == FailureCode::Stash as u16 => FailureCode::Stash,
-            x if x == FailureCode::Absent as u16 => FailureCode::Absent,
-            x if x == FailureCode::Finalize as u16 => FailureCode::Finalize,
-            x if x == FailureCode::ElectrumConnectivity as u16 => FailureCode::ElectrumConnectivity,
-            x if x == FailureCode::UnexpectedRequest as u16 => FailureCode::UnexpectedRequest,
-            x if x == FailureCode::Launcher as u16 => FailureCode::Launcher,
-            _ => FailureCode::Unknown,
-        }
+        FailureCode::ALL
+            .into_iter()
+            .find(|code| *code as u16 == value)
+            .unwrap_or(FailureCode::Unknown)
     }
 }
 
@@ -84,6 +447,10 @@ pub enum Error {
     #[from]
     Esb(esb::Error<ServiceId>),
 
+    #[display(inner)]
+    #[from]
+    Version(VersionError),
+
     /// (RGB#{code:06}) {message}
     LocalFailure { code: FailureCode, message: String },
 
@@ -97,6 +464,21 @@ pub enum Error {
     UnexpectedServerResponse,
 }
 
+impl Error {
+    /// Failure code reported by the node, allowing clients to branch on the
+    /// failure type without parsing the error message
+    pub fn failure_code(&self) -> Option<FailureCode> {
+        match self {
+            Error::LocalFailure { code, .. } => Some(*code),
+            Error::RemoteFailure {
+                code: rpc::FailureCode::Other(code),
+                ..
+            } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
 impl RpcMsg {
     pub fn failure_to_error(self) -> Result<RpcMsg, Error> {
         match self {
@@ -0,0 +1,52 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use rgb::{ConsignmentId, ContractId};
+
+use crate::TransferRecord;
+
+/// Event published by the node over the ZMQ PUB socket. Each event is sent
+/// as a two-frame message, where the first frame is the event topic, which
+/// can be used by the subscribers for filtering, and the second one is the
+/// event data.
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum NodeEvent {
+    /// Contract which was not known to the node is imported
+    #[display("contract_registered({0})")]
+    ContractRegistered(ContractId),
+
+    /// Consignment is validated and its data are added to the stash
+    #[display("consignment_accepted({id}, {contract_id})")]
+    ConsignmentAccepted {
+        id: ConsignmentId,
+        contract_id: ContractId,
+    },
+
+    /// Transfer is added to the transfer history or has changed its status
+    #[display("transfer_updated({0})")]
+    TransferUpdated(TransferRecord),
+}
+
+impl NodeEvent {
+    /// Topic of the event message
+    pub fn topic(&self) -> &'static str {
+        match self {
+            NodeEvent::ContractRegistered(_) => "contract",
+            NodeEvent::ConsignmentAccepted { .. } => "consignment",
+            NodeEvent::TransferUpdated(_) => "transfer",
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use bitcoin::{BlockHash, XOnlyPublicKey};
+use rgb::ConsignmentId;
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Status of a worker daemon supervised by the node
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum WorkerStatus {
+    /// Worker is launched, but has not connected to the node yet
+    Starting,
+
+    /// Worker awaits new tasks
+    Idle,
+
+    /// Worker processes a task
+    Busy,
+
+    /// Worker has terminated and will be restarted after a delay
+    Restarting,
+}
+
+/// Hit and miss counts of the in-memory caches of a worker daemon
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(
+    "state cache {state_hits}/{state_misses}, chain cache {chain_hits}/{chain_misses} hits/misses"
+)]
+pub struct CacheStats {
+    /// Contract states served from the state cache
+    pub state_hits: u64,
+    /// Contract states loaded from the store
+    pub state_misses: u64,
+    /// Transactions served from the chain data cache
+    pub chain_hits: u64,
+    /// Transactions requested from the electrum server
+    pub chain_misses: u64,
+}
+
+/// Health information about a worker daemon
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{daemon}<{daemon_id}> is {status}, restarted {restarts} time(s)")]
+pub struct WorkerHealth {
+    /// Name of the daemon
+    pub daemon: String,
+    pub daemon_id: u64,
+    pub status: WorkerStatus,
+    /// Id of the client which request is being processed by the worker
+    pub client_id: Option<u64>,
+    /// Number of restarts since the worker was running for a sufficient time
+    pub restarts: u32,
+    /// Number of seconds since the last (re)start of the worker
+    pub uptime: u64,
+    /// Reason of the last worker termination
+    pub last_failure: Option<String>,
+    /// Cache hits and misses since the last (re)start of the worker
+    pub caches: CacheStats,
+}
+
+/// Tip of the block header chain tracked by the node
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{block_hash} at height {height}")]
+pub struct HeaderTip {
+    pub height: u32,
+    pub block_hash: BlockHash,
+    /// Number of seconds since the last successful synchronization of the
+    /// header chain
+    pub synced: u64,
+}
+
+/// Status of the replication of the stash from the primary node
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("replica of {primary}, {mirrored} contract update(s) mirrored")]
+pub struct ReplicaStatus {
+    /// RPC endpoint of the primary node
+    pub primary: String,
+    /// Indicates whether the replica was promoted to primary and stopped
+    /// following the former primary node
+    pub promoted: bool,
+    /// Number of contract updates mirrored from the primary since the node
+    /// start
+    pub mirrored: u64,
+    /// Number of seconds since the last successful synchronization with the
+    /// primary; absent if the node was not synchronized yet
+    pub synced: Option<u64>,
+}
+
+/// Repairs of the store made at the node start, completing or rolling back
+/// consignments which were not completely stored when the node has stopped
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(
+    "{} consignment(s) completed, {} rolled back",
+    self.completed.len(),
+    self.rolled_back.len()
+)]
+pub struct StoreRecovery {
+    /// Consignments which storing was completed
+    pub completed: Vec<ConsignmentId>,
+    /// Consignments which were rolled back, since their data were lost
+    pub rolled_back: Vec<ConsignmentId>,
+}
+
+impl StoreRecovery {
+    /// Checks whether no repairs were made
+    pub fn is_empty(&self) -> bool { self.completed.is_empty() && self.rolled_back.is_empty() }
+}
+
+/// Health information about the node and its worker daemons
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{} worker(s), {queued} queued task(s)", self.workers.len())]
+pub struct NodeHealth {
+    /// Number of seconds since the node start
+    pub uptime: u64,
+    /// Number of tasks awaiting a free worker
+    pub queued: u32,
+    pub workers: Vec<WorkerHealth>,
+    /// Tip of the tracked header chain; absent if the node runs offline or
+    /// the chain was not synchronized yet
+    pub header_tip: Option<HeaderTip>,
+    /// Replication status, if the node runs as a read replica
+    pub replica: Option<ReplicaStatus>,
+    /// Repairs of the store made at the node start, if the node was not
+    /// stopped cleanly
+    pub recovery: Option<StoreRecovery>,
+    /// Number of contracts validated by an older version of the validation
+    /// rules, which should be re-validated
+    pub outdated_validations: u32,
+    /// Failure of the entropy source health check, if any; invoices, issues
+    /// and payments are refused while the source is failing
+    pub entropy_failure: Option<String>,
+    /// Persistent identity key of the node
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub identity: XOnlyPublicKey,
+}
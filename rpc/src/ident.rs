@@ -0,0 +1,121 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Rendering and parsing of the contract and node identifiers.
+//!
+//! Contract id is the id of the contract genesis node, so it can be given
+//! either in its Bech32 form with `rgb` prefix or in hex, matching the genesis
+//! node id. Node ids have hex form only. Identifiers are rendered using the
+//! selected [`IdEncoding`], while parsers accept any of the encodings, so an
+//! identifier printed under any setting is always accepted back.
+
+use std::str::FromStr;
+
+use amplify::Wrapper;
+use rgb::{ContractId, NodeId};
+
+/// Human-readable prefix of the Bech32-encoded contract ids
+pub const CONTRACT_ID_PREFIX: &str = "rgb1";
+
+/// Encoding used for rendering contract ids
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum IdEncoding {
+    /// Bech32 string with `rgb` prefix
+    #[display("bech32")]
+    Bech32,
+
+    /// Hex string, matching the id of the contract genesis node
+    #[display("hex")]
+    Hex,
+}
+
+impl Default for IdEncoding {
+    fn default() -> Self { IdEncoding::Bech32 }
+}
+
+impl FromStr for IdEncoding {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bech32" => Ok(IdEncoding::Bech32),
+            "hex" => Ok(IdEncoding::Hex),
+            other => Err(ParseIdError::Encoding(other.to_owned())),
+        }
+    }
+}
+
+impl IdEncoding {
+    /// Renders contract id with the encoding.
+    pub fn contract_id(self, contract_id: ContractId) -> String {
+        match self {
+            IdEncoding::Bech32 => contract_id.to_string(),
+            IdEncoding::Hex => NodeId::from_inner(contract_id.into_inner()).to_string(),
+        }
+    }
+
+    /// Renders all Bech32 contract ids found in a text with the encoding,
+    /// keeping the rest of the text intact.
+    pub fn render_text(self, text: &str) -> String {
+        if self == IdEncoding::Bech32 {
+            return text.to_owned();
+        }
+        let mut rendered = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(pos) = rest.find(CONTRACT_ID_PREFIX) {
+            let (head, tail) = rest.split_at(pos);
+            let len = tail.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(tail.len());
+            let (word, tail) = tail.split_at(len);
+            rendered.push_str(head);
+            match ContractId::from_str(word) {
+                Ok(contract_id) => rendered.push_str(&self.contract_id(contract_id)),
+                Err(_) => rendered.push_str(word),
+            }
+            rest = tail;
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+}
+
+/// Parses contract id given either in Bech32 or in hex encoding.
+pub fn parse_contract_id(s: &str) -> Result<ContractId, ParseIdError> {
+    if let Ok(contract_id) = ContractId::from_str(s) {
+        return Ok(contract_id);
+    }
+    NodeId::from_str(s)
+        .map(|node_id| ContractId::from_inner(node_id.into_inner()))
+        .map_err(|_| ParseIdError::ContractId(s.to_owned()))
+}
+
+/// Parses node id given in hex encoding or, for the genesis node, as a Bech32
+/// contract id.
+pub fn parse_node_id(s: &str) -> Result<NodeId, ParseIdError> {
+    if let Ok(node_id) = NodeId::from_str(s) {
+        return Ok(node_id);
+    }
+    ContractId::from_str(s)
+        .map(|contract_id| NodeId::from_inner(contract_id.into_inner()))
+        .map_err(|_| ParseIdError::NodeId(s.to_owned()))
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseIdError {
+    /// unknown identifier encoding `{0}`; must be either `bech32` or `hex`.
+    Encoding(String),
+
+    /// `{0}` is neither Bech32 nor hex-encoded contract id.
+    ContractId(String),
+
+    /// `{0}` is not a valid node id; node id must be given in hex or as a
+    /// contract id for the genesis node.
+    NodeId(String),
+}
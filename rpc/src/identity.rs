@@ -0,0 +1,94 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Node identity.
+//!
+//! Each node has a persistent identity key, which is reported to the clients
+//! which have negotiated [`Capability::NodeIdentity`] in response to the
+//! `hello` request ([`Welcome`]) and in the node health report. Since these
+//! responses are not signed, a client connecting over the network pins the
+//! identity it expects and proves that the node controls the identity key by
+//! asking it to sign a random nonce ([`Pong`]).
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr, KeyPair, Message, Secp256k1, Signing, Verification};
+use bitcoin::XOnlyPublicKey;
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+use strict_encoding::StrictEncode;
+
+use crate::relay::tagged_engine;
+use crate::Capability;
+
+/// Tag used in the signed pong message
+const PONG_TAG: &[u8] = b"rgb:node:pong";
+
+/// Response to the `hello` request of the clients supporting
+/// [`Capability::NodeIdentity`]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display("{identity}, ...")]
+pub struct Welcome {
+    /// Optional features supported both by the client and the node
+    pub capabilities: BTreeSet<Capability>,
+    pub identity: XOnlyPublicKey,
+    /// Nonce which the client signs to authenticate on the node
+    /// ([`crate::ClientAuth`]). Each `hello` request gets a new nonce, which
+    /// is accepted for a single authentication only.
+    pub auth_nonce: u64,
+}
+
+/// Nonce provided by the client signed with the node identity key
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("pong {nonce} signed by {identity}")]
+pub struct Pong {
+    pub nonce: u64,
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub identity: XOnlyPublicKey,
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub signature: schnorr::Signature,
+}
+
+impl Pong {
+    /// Returns message which is signed by the node.
+    pub fn commitment(identity: XOnlyPublicKey, nonce: u64) -> Message {
+        let mut engine = tagged_engine(PONG_TAG);
+        engine.input(&identity.serialize());
+        nonce.strict_encode(&mut engine).expect("hash engines do not error");
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    pub fn sign<C: Signing>(secp: &Secp256k1<C>, keypair: &KeyPair, nonce: u64) -> Pong {
+        let identity = keypair.x_only_public_key().0;
+        let signature = secp.sign_schnorr_no_aux_rand(&Pong::commitment(identity, nonce), keypair);
+        Pong {
+            nonce,
+            identity,
+            signature,
+        }
+    }
+
+    /// Checks the signature of the node identity key. Whether the identity
+    /// and the nonce match the expected ones must be checked separately.
+    pub fn verify<C: Verification>(&self, secp: &Secp256k1<C>) -> bool {
+        let message = Pong::commitment(self.identity, self.nonce);
+        secp.verify_schnorr(&self.signature, &message, &self.identity).is_ok()
+    }
+}
@@ -0,0 +1,284 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::XOnlyPublicKey;
+use commit_verify::CommitConceal;
+use internet2::addr::NodeAddr;
+use rgb::{seal, AtomicValue, ConsignmentId, ContractId, SealEndpoint};
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+use strict_encoding::StrictEncode;
+
+use crate::{parse_contract_id, NetworkMagic};
+
+/// Tag used in the invoice id commitment
+const INVOICE_ID_TAG: &[u8] = b"rgb:node:invoice-id";
+
+/// Request for a payment of some amount of a fungible RGB asset in form of
+/// `rgb:<contract_id>/<amount>/<seal>[?transport=<node_addr>][&signet=<magic>][&key=<pubkey>]`.
+/// Open invoices, like donation ones, have zero amount and are paid with the
+/// amount chosen by the payer.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+pub struct Invoice {
+    pub contract_id: ContractId,
+    /// Requested amount; zero for the open invoices
+    pub amount: AtomicValue,
+    /// Blinded UTXO or witness output receiving the payment
+    pub beneficiary: SealEndpoint,
+    /// Bifrost node of the beneficiary accepting the transfer consignment
+    pub transport: Option<NodeAddr>,
+    /// Magic of the custom signet the beneficiary seal belongs to
+    pub signet: Option<NetworkMagic>,
+    /// Key of the beneficiary which the consignment must be encrypted to, so
+    /// the nodes and relays delivering it learn nothing about the transfer
+    pub key: Option<XOnlyPublicKey>,
+}
+
+impl Display for Invoice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "rgb:{}/{}/{}", self.contract_id, self.amount, self.beneficiary)?;
+        let mut separator = '?';
+        if let Some(transport) = &self.transport {
+            write!(f, "{}transport={}", separator, transport)?;
+            separator = '&';
+        }
+        if let Some(signet) = &self.signet {
+            write!(f, "{}signet={}", separator, signet)?;
+            separator = '&';
+        }
+        if let Some(key) = &self.key {
+            write!(f, "{}key={}", separator, key)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Invoice {
+    type Err = ParseInvoiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("rgb:").ok_or(ParseInvoiceError::Format)?;
+        let (s, query) = s.split_once('?').unwrap_or((s, ""));
+        let mut transport = None;
+        let mut signet = None;
+        let mut key = None;
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            match param.split_once('=') {
+                Some(("transport", addr)) if transport.is_none() => {
+                    let addr =
+                        NodeAddr::from_str(addr).map_err(|_| ParseInvoiceError::Transport)?;
+                    transport = Some(addr);
+                }
+                Some(("signet", magic)) if signet.is_none() => {
+                    let magic =
+                        NetworkMagic::from_str(magic).map_err(|_| ParseInvoiceError::Signet)?;
+                    signet = Some(magic);
+                }
+                Some(("key", pubkey)) if key.is_none() => {
+                    let pubkey =
+                        XOnlyPublicKey::from_str(pubkey).map_err(|_| ParseInvoiceError::Key)?;
+                    key = Some(pubkey);
+                }
+                _ => return Err(ParseInvoiceError::Format),
+            }
+        }
+        let mut split = s.split('/');
+        match (split.next(), split.next(), split.next(), split.next()) {
+            (Some(contract_id), Some(amount), Some(beneficiary), None) => Ok(Invoice {
+                contract_id: parse_contract_id(contract_id)
+                    .map_err(|_| ParseInvoiceError::ContractId)?,
+                amount: amount.parse().map_err(|_| ParseInvoiceError::Amount)?,
+                beneficiary: SealEndpoint::from_str(beneficiary)
+                    .map_err(|_| ParseInvoiceError::Seal)?,
+                transport,
+                signet,
+                key,
+            }),
+            _ => Err(ParseInvoiceError::Format),
+        }
+    }
+}
+
+/// Identifier of an invoice in the invoice registry, committing to the
+/// contract and the concealed beneficiary seal. Transfers are matched to the
+/// invoices by the seals they assign state to, without revealing them.
+#[derive(Wrapper, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+#[derive(StrictEncode, StrictDecode)]
+#[wrapper(Debug, Display, BorrowSlice, FromStr)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", transparent)
+)]
+pub struct InvoiceId(sha256::Hash);
+
+impl InvoiceId {
+    pub fn with(contract_id: ContractId, seal: seal::Confidential) -> InvoiceId {
+        let tag = sha256::Hash::hash(INVOICE_ID_TAG);
+        let mut engine = sha256::Hash::engine();
+        engine.input(&tag[..]);
+        engine.input(&tag[..]);
+        contract_id.strict_encode(&mut engine).expect("hash engines do not error");
+        seal.strict_encode(&mut engine).expect("hash engines do not error");
+        InvoiceId(sha256::Hash::from_engine(engine))
+    }
+}
+
+impl Invoice {
+    /// Returns identifier of the invoice in the invoice registry
+    pub fn invoice_id(&self) -> InvoiceId {
+        InvoiceId::with(self.contract_id, self.beneficiary.commit_conceal())
+    }
+
+    /// Checks that the invoice is issued for the network the payer works
+    /// with, given by the magic of its custom signet, if any. Invoices without
+    /// the signet magic are issued for the standard networks.
+    pub fn matches_signet(&self, signet: Option<NetworkMagic>) -> bool { self.signet == signet }
+
+    /// Checks whether the invoice is open, i.e. leaves the amount to the
+    /// payer
+    pub fn is_open(&self) -> bool { self.amount == 0 }
+}
+
+/// Deviation of the received amount from the amount of the invoice, within
+/// which the invoice is considered paid. Open invoices accept any amount.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("-{underpayment}/+{overpayment}")]
+pub struct InvoiceTolerance {
+    /// Amount which the payment may fall short of the invoice amount
+    pub underpayment: AtomicValue,
+    /// Amount which the payment may exceed the invoice amount by
+    pub overpayment: AtomicValue,
+}
+
+/// Payment status of an invoice created by the node
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum InvoiceStatus {
+    /// No accepted transfer has paid the invoice yet
+    Pending,
+
+    /// Received amount is within the invoice tolerance, or any amount was
+    /// received by an open invoice
+    Paid,
+
+    /// Received amount is below the invoice tolerance
+    Underpaid,
+
+    /// Received amount is above the invoice tolerance
+    Overpaid,
+}
+
+/// Invoice created by the node, which is kept in the invoice registry
+/// together with the amount it has received
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{invoice} ({status})")]
+pub struct InvoiceRecord {
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub invoice: Invoice,
+    pub tolerance: InvoiceTolerance,
+    pub status: InvoiceStatus,
+    /// Sum of the revealed amounts received on the invoice seal
+    pub received: AtomicValue,
+    /// Accepted transfers which have paid to the invoice seal
+    pub payments: Vec<ConsignmentId>,
+    /// UNIX timestamp of the invoice creation
+    pub created: u64,
+    /// UNIX timestamp of the last payment
+    pub updated: u64,
+}
+
+impl InvoiceRecord {
+    /// Constructs registry record of a new invoice
+    pub fn with(invoice: Invoice, tolerance: InvoiceTolerance, timestamp: u64) -> InvoiceRecord {
+        InvoiceRecord {
+            invoice,
+            tolerance,
+            status: InvoiceStatus::Pending,
+            received: 0,
+            payments: vec![],
+            created: timestamp,
+            updated: timestamp,
+        }
+    }
+
+    /// Records amount received by the invoice seal with a transfer, updating
+    /// the invoice status. Transfers which were already recorded are
+    /// ignored.
+    pub fn receive(&mut self, transfer: ConsignmentId, amount: AtomicValue, timestamp: u64) {
+        if self.payments.contains(&transfer) {
+            return;
+        }
+        self.payments.push(transfer);
+        self.received = self.received.saturating_add(amount);
+        self.updated = timestamp;
+        let requested = self.invoice.amount;
+        self.status = if self.invoice.is_open() {
+            InvoiceStatus::Paid
+        } else if self.received.saturating_add(self.tolerance.underpayment) < requested {
+            InvoiceStatus::Underpaid
+        } else if self.received > requested.saturating_add(self.tolerance.overpayment) {
+            InvoiceStatus::Overpaid
+        } else {
+            InvoiceStatus::Paid
+        };
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseInvoiceError {
+    /// invoice must be in form of `rgb:<contract_id>/<amount>/<seal>` with
+    /// optional `transport=<node_addr>`, `signet=<magic>` and `key=<pubkey>`
+    /// query parameters.
+    Format,
+
+    /// invalid contract id in the invoice.
+    ContractId,
+
+    /// invalid amount in the invoice.
+    Amount,
+
+    /// invalid beneficiary seal in the invoice.
+    Seal,
+
+    /// invalid transport node address in the invoice.
+    Transport,
+
+    /// invalid signet magic in the invoice.
+    Signet,
+
+    /// invalid beneficiary key in the invoice.
+    Key,
+}
@@ -0,0 +1,112 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Declarative specification of the contract genesis.
+//!
+//! The specification is written by the issuer as a YAML or JSON file, which
+//! can be kept under version control and reviewed before the issuance:
+//!
+//! ```yaml
+//! schema: rgbsh18kp34t5nn5zu4hz6g7lqjdjskw8aaf84ecdntrtrdvzs7gn3rnzskscfq8
+//! metadata:
+//!   0: RGB20                # ticker
+//!   1: Test asset           # name
+//!   3: 8                    # precision
+//! assignments:
+//!   - type: 0               # inflation right
+//!     outpoint: 5f0e...c2a1:0
+//!     method: tapret1st
+//!     amount: 1000000
+//!   - type: 1               # renomination right
+//!     outpoint: 5f0e...c2a1:1
+//!     method: tapret1st
+//!   - type: 2               # issued asset
+//!     outpoint: 5f0e...c2a1:1
+//!     method: tapret1st
+//!     amount: 100000000
+//! ```
+//!
+//! Field and owned right types are the numeric types defined by the contract
+//! schema; the node validates the specification against the schema before
+//! creating the genesis.
+
+use std::collections::BTreeMap;
+
+use bitcoin::OutPoint;
+use bp::seals::txout::CloseMethod;
+use rgb::schema::{FieldType, OwnedRightType};
+use rgb::{AtomicValue, SchemaId};
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Declarative specification of the contract genesis
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{schema}, ...")]
+pub struct IssueSpec {
+    /// Schema of the issued contract
+    pub schema: SchemaId,
+
+    /// Genesis metadata values for each of the schema field types
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: BTreeMap<FieldType, Vec<MetadataValue>>,
+
+    /// Genesis assignments of the owned rights, including asset allocations,
+    /// inflation rights and renomination seals
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub assignments: Vec<AssignmentSpec>,
+}
+
+/// Metadata field value as written in the specification; the node converts
+/// it into the data format defined for the field by the contract schema
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", untagged)
+)]
+#[display(inner)]
+pub enum MetadataValue {
+    Unsigned(u64),
+    Integer(i64),
+    /// Text strings; for byte fields must be hex-encoded
+    Text(String),
+}
+
+/// Single genesis assignment of an owned right
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{right_type}@{method}:{outpoint}")]
+pub struct AssignmentSpec {
+    /// Owned right type defined by the schema
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    pub right_type: OwnedRightType,
+
+    /// Output receiving the assignment
+    pub outpoint: OutPoint,
+
+    /// Seal closing method
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub method: CloseMethod,
+
+    /// Assigned amount, required for the fungible rights and absent for the
+    /// declarative ones
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub amount: Option<AtomicValue>,
+}
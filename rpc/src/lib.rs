@@ -30,22 +30,120 @@ extern crate internet2;
 extern crate log;
 
 #[cfg(feature = "serde")]
+#[macro_use]
 extern crate serde_crate as serde;
 
+mod access;
+mod amount;
+mod annotation;
+mod armor;
+mod checkpoint;
+mod clock;
 pub mod client;
+pub mod compression;
+mod consistency;
+mod digest;
 mod error;
+mod event;
+mod health;
+mod ident;
+mod identity;
+mod invoice;
+mod issue;
 mod messages;
+mod network;
+mod order;
+mod payjoin;
+mod policy;
+mod progress;
+mod proof;
+mod quarantine;
+mod receipt;
+mod relay;
+mod retention;
 mod service_id;
 mod reveal;
+mod setting;
+pub mod signer;
+mod simulation;
+mod swap;
+mod timestamp;
+mod transfer;
+mod upload;
+mod version;
 
+pub use access::{AccessGrants, AccessReq, AccessTarget, ClientAuth, ParseAccessTargetError};
+pub use amount::{
+    contract_precision, format_decimal, parse_decimal, DecimalAmount, DecimalFormat,
+    ParseDecimalError, PRECISION_FIELD,
+};
+pub use annotation::{Annotation, AnnotationTarget, ParseAnnotationTargetError};
+pub use armor::{Armor, ArmorError, ARMOR_BOUNDARY, ARMOR_CHECKSUM_HEADER};
+pub use checkpoint::{Checkpoint, SignedCheckpoint};
 pub use client::Client;
-pub use error::{Error, FailureCode};
+pub use clock::now;
+pub use compression::Compression;
+pub use consistency::{verify_consignment, ConsistencyError};
+pub use digest::{DiffReq, HistoryDiff, StateDigest};
+pub use error::{Error, FailureCategory, FailureCode};
+pub use event::NodeEvent;
+pub use health::{
+    CacheStats, HeaderTip, NodeHealth, ReplicaStatus, StoreRecovery, WorkerHealth, WorkerStatus,
+};
+pub use ident::{parse_contract_id, parse_node_id, IdEncoding, ParseIdError, CONTRACT_ID_PREFIX};
+pub use identity::{Pong, Welcome};
+pub use invoice::{
+    Invoice, InvoiceId, InvoiceRecord, InvoiceStatus, InvoiceTolerance, ParseInvoiceError,
+};
+pub use issue::{AssignmentSpec, IssueSpec, MetadataValue};
 pub(crate) use messages::BusMsg;
 pub use messages::{
-    AcceptReq, ComposeReq, ContractValidity, FinalizeTransfersRes, HelloReq, OutpointFilter,
-    RpcMsg, TransferFinalize, TransferReq, TransfersReq,
+    AccelerateReq, AcceptReq, AddressesReq, AllocationPreview, AllocationStatus, CheckpointReq,
+    ComposeReq, ConsolidateReq, ContractAllocation, ContractDeps, ContractStats, ContractValidity,
+    CpfpDraft, DecimalContractState, FinalizeTransfersRes, ForgetReport, ForgetReq, ForgetSpentReq,
+    GcReport, HelloReq, HistoricalBalance, HistoricalBalanceReq, InvoiceReq, IssueReq,
+    OperationPreview, OutpointFilter, OutpointStateQuery, OutpointStatus, PayjoinCompleteReq,
+    PayjoinContributeReq, PayjoinProposeReq, PaymentDraft, PaymentReq, PolicyReq, ProveReq,
+    PublishReq, RebuildReport, RevalidationReport, RevealExportReq, RevealReq, RpcMsg, SchemaInfo,
+    SpentStatus, StorageUsage, SwapAcceptReq, SwapCompleteReq, SwapProposeReq, TransferFinalize,
+    TransferPreviewReq, TransferReq, TransferSendReq, TransfersReq, ValidationCost,
+    ValidationReport, WalletAddress, WalletBalance, WalletInfo, WalletReq, WalletUtxo,
+};
+pub use network::{NetworkMagic, SignetChallenge};
+pub use order::{
+    Order, OrderDigest, OrderKind, OrderRecord, OrderTarget, ParseOrderTargetError, SignedOrder,
+    ORDER_WINDOW,
 };
-pub use reveal::Reveal;
+pub use payjoin::{PayjoinContribution, PayjoinId, PayjoinProposal};
+pub use policy::ContractPolicy;
+pub use progress::ProgressStep;
+pub use proof::{
+    merkle_root, HeaderCheckpoint, ParseHeaderCheckpointError, ProofBundle, ProofError,
+    ProofHeader, TxProof,
+};
+pub use quarantine::{QuarantineInfo, QuarantineReason};
+pub use receipt::{Receipt, SignedReceipt, TransferId};
+pub use relay::{
+    open_payload, seal_payload, EnvelopeId, RelayAuth, RelayDepositReq, RelayEnvelope,
+    RelayFetchReq, RelayPickupReq, RELAY_AUTH_WINDOW,
+};
+pub use retention::{
+    ParseRetentionError, PruneReport, PruneReq, PrunedItem, RetentionCategory, RetentionPeriod,
+    BLOCKS_PER_DAY,
+};
+pub use reveal::{Reveal, RevealData};
 pub use service_id::ServiceId;
+pub use setting::{Setting, SettingSource};
+pub use signer::SignerMsg;
+pub use simulation::{JournalEntry, ReplayReq, RewindReq, StateChange};
+pub use swap::{ParseSwapLegError, SwapAcceptance, SwapId, SwapLeg, SwapOffer, SwapTerms};
+pub use timestamp::{StashCommitment, TimestampProof};
+pub use transfer::{
+    ParseTransferError, TransferDirection, TransferFilter, TransferRecord, TransferStatus,
+};
+pub use upload::{UploadChunk, UploadKind, UploadReq, MAX_UPLOAD_SIZE, UPLOAD_CHUNK_SIZE};
+pub use version::{
+    Capability, RpcEnvelope, VersionError, MAX_PAYLOAD_SIZE, RPC_MIN_VERSION, RPC_VERSION,
+};
 
 pub const RGB_NODE_RPC_ENDPOINT: &str = "0.0.0.0:63963";
@@ -8,117 +8,745 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
 
-use bitcoin::{OutPoint, Txid};
+use bitcoin::hashes::sha256;
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
+use bp::seals::txout::CloseMethod;
 use internet2::addr::NodeAddr;
 use internet2::presentation;
 use lnpbp::chain::Chain;
+use microservices::esb::ClientId;
 use microservices::rpc;
 use microservices::util::OptionDetails;
 use psbt::Psbt;
-use rgb::schema::TransitionType;
+use rgb::schema::{ExtensionType, FieldType, OwnedRightType, PublicRightType, TransitionType};
 use rgb::{
-    seal, validation, ConsignmentType, Contract, ContractConsignment, ContractId, ContractState,
-    ContractStateMap, InmemConsignment, SealEndpoint, StateTransfer, TransferConsignment,
+    seal, validation, AtomicValue, ConsignmentId, ConsignmentType, Contract, ContractConsignment,
+    ContractId, ContractState, ContractStateMap, InmemConsignment, NodeId, Schema, SchemaId,
+    SealEndpoint, StateTransfer, TransferConsignment, Transition,
 };
 
-use crate::{FailureCode, Reveal};
+use crate::{
+    contract_precision, AccessGrants, AccessReq, Annotation, AnnotationTarget, Capability,
+    Checkpoint, ClientAuth, ContractPolicy, DecimalAmount, DiffReq, FailureCode, HistoryDiff,
+    Invoice, InvoiceRecord, InvoiceTolerance, IssueSpec, JournalEntry, NetworkMagic, NodeHealth,
+    OrderRecord, OrderTarget, PayjoinContribution, PayjoinProposal, Pong, ProgressStep,
+    ProofBundle, PruneReport, PruneReq, QuarantineInfo, RelayDepositReq, RelayEnvelope,
+    RelayFetchReq, RelayPickupReq, ReplayReq, Reveal, RevealData, RewindReq, RpcEnvelope, Setting,
+    SignedCheckpoint, SignedOrder, SignedReceipt, StashCommitment, StateChange, StateDigest,
+    SwapAcceptance, SwapId, SwapOffer, SwapTerms, TimestampProof, TransferFilter, TransferRecord,
+    UploadChunk, UploadReq, Welcome,
+};
 
 /// We need this wrapper type to be compatible with RGB Node having multiple message buses
 #[derive(Clone, Debug, Display, From, Api)]
 #[api(encoding = "strict")]
 #[non_exhaustive]
 pub(crate) enum BusMsg {
-    #[api(type = 4)]
+    /// RPC messages in versioned envelopes; the type differs from the one of
+    /// the unversioned RPC messages of the older nodes
+    #[api(type = 7)]
     #[display(inner)]
     #[from]
-    Rpc(RpcMsg),
+    Rpc(RpcEnvelope),
+}
+
+impl From<RpcMsg> for BusMsg {
+    fn from(msg: RpcMsg) -> Self { BusMsg::Rpc(RpcEnvelope::with(&msg)) }
 }
 
 impl rpc::Request for BusMsg {}
 
+/// RPC messages. Message tags are pinned, so the tags of the existing
+/// messages never change: new messages must be appended with the next free
+/// tag, and any change of the message encoding requires a new
+/// [`crate::RPC_VERSION`].
 #[derive(Clone, Debug, Display, From)]
 #[derive(NetworkEncode, NetworkDecode)]
 #[display(inner)]
+#[allow(clippy::large_enum_variant)]
 pub enum RpcMsg {
     #[from]
+    #[network_encoding(value = 0x00)]
     Hello(HelloReq),
 
+    /// Authenticates the client with its identity key. Required by the nodes
+    /// with access control before any other request.
+    #[display("authenticate({0})")]
+    #[network_encoding(value = 0x18)]
+    Authenticate(ClientAuth),
+
     // Contract operations
     // -------------------
     #[display("list_contracts")]
+    #[network_encoding(value = 0x01)]
     ListContracts,
 
+    #[display("list_schemata")]
+    #[network_encoding(value = 0x19)]
+    ListSchemata,
+
+    /// Requests dependency graph of the contract, or of all known contracts
+    /// if no contract is given.
+    #[display("get_contract_deps(...)")]
+    #[network_encoding(value = 0x1A)]
+    GetContractDeps(Option<ContractId>),
+
     #[display("get_contract_state({0})")]
+    #[network_encoding(value = 0x02)]
     GetContractState(ContractId),
 
+    #[display("get_contract_stats({0})")]
+    #[network_encoding(value = 0x1B)]
+    GetContractStats(ContractId),
+
+    #[display("list_allocations({0})")]
+    #[network_encoding(value = 0x1C)]
+    ListAllocations(ContractId),
+
+    #[display("list_storage_usage({0})")]
+    #[network_encoding(value = 0x1D)]
+    ListStorageUsage(u16),
+
+    #[display("get_state_digest({0})")]
+    #[network_encoding(value = 0x1E)]
+    GetStateDigest(ContractId),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x1F)]
+    DiffHistory(DiffReq),
+
+    #[display("commit_stash")]
+    #[network_encoding(value = 0x20)]
+    CommitStash,
+
+    #[display("timestamp({0})")]
+    #[network_encoding(value = 0x21)]
+    Timestamp(sha256::Hash),
+
     #[display("get_outpoint_state(...)")]
+    #[network_encoding(value = 0x03)]
     GetOutpointState(BTreeSet<OutPoint>),
 
+    #[display("query_outpoint_state(...)")]
+    #[network_encoding(value = 0x22)]
+    QueryOutpointState(OutpointStateQuery),
+
     #[display("consign_contract({0})")]
+    #[network_encoding(value = 0x04)]
     ConsignContract(ComposeReq),
 
     #[display("consign_transfer({0})")]
+    #[network_encoding(value = 0x05)]
     ConsignTransfer(ComposeReq),
 
+    #[display("get_known_history({0})")]
+    #[network_encoding(value = 0x23)]
+    GetKnownHistory(ContractId),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x24)]
+    CreateCheckpoint(CheckpointReq),
+
+    #[display("import_checkpoint({0})")]
+    #[network_encoding(value = 0x25)]
+    ImportCheckpoint(SignedCheckpoint),
+
+    #[display("sign_checkpoint({0})")]
+    #[network_encoding(value = 0x26)]
+    SignCheckpoint(Checkpoint),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x27)]
+    SetPolicy(PolicyReq),
+
+    #[display("get_policy({0})")]
+    #[network_encoding(value = 0x28)]
+    GetPolicy(ContractId),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x29)]
+    ConsolidateAllocations(ConsolidateReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x2A)]
+    ForgetContract(ForgetReq),
+
+    #[display("get_journal({0})")]
+    #[network_encoding(value = 0x2B)]
+    GetJournal(ContractId),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x2C)]
+    RewindContract(RewindReq),
+
     #[display(inner)]
+    #[network_encoding(value = 0x2D)]
+    ReplayConsignments(ReplayReq),
+
+    #[display("rebuild_state(...)")]
+    #[network_encoding(value = 0x2E)]
+    RebuildState(Option<ContractId>),
+
+    /// Re-validates the contract, or all contracts validated by an older
+    /// version of the validation rules if no contract is given.
+    #[display("revalidate_contracts(...)")]
+    #[network_encoding(value = 0x2F)]
+    RevalidateContracts(Option<ContractId>),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x30)]
+    IssueContract(IssueReq),
+
+    #[display("preview_{0}")]
+    #[network_encoding(value = 0x31)]
+    PreviewIssue(IssueReq),
+
+    #[display("import_contract(...)")]
+    #[network_encoding(value = 0x32)]
+    ImportContract(String),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x06)]
     ConsumeContract(AcceptReq<ContractConsignment>),
 
     #[display("accept_transfer(...)")]
+    #[network_encoding(value = 0x07)]
     ConsumeTransfer(AcceptReq<TransferConsignment>),
 
+    #[display(inner)]
+    #[network_encoding(value = 0x33)]
+    UploadConsignment(UploadChunk),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x34)]
+    ConsumeUpload(UploadReq),
+
     #[display("process_disclosure({0})")]
+    #[network_encoding(value = 0x08)]
     ProcessDisclosure(Txid),
 
     #[display(inner)]
+    #[network_encoding(value = 0x35)]
+    RevealSeal(RevealReq),
+
+    /// Creates invoice for receiving the contract asset on an outpoint
+    /// controlled by the client, generating the seal blinding factor from the
+    /// entropy source of the node.
+    #[display(inner)]
+    #[network_encoding(value = 0x36)]
+    CreateInvoice(InvoiceReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x37)]
+    ExportReveals(RevealExportReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x38)]
+    ProveTransactions(ProveReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x09)]
     Transfer(TransferReq),
 
     #[display(inner)]
+    #[network_encoding(value = 0x0A)]
     FinalizeTransfers(TransfersReq),
 
+    #[display(inner)]
+    #[network_encoding(value = 0x39)]
+    PreviewTransfer(TransferPreviewReq),
+
+    /// Sends finalized transfer consignment to the beneficiary node. Payers
+    /// send the consignment only once the witness transaction is published.
+    #[display(inner)]
+    #[network_encoding(value = 0x9E)]
+    SendTransfer(TransferSendReq),
+
     #[display("memorize_seal({0})")]
+    #[network_encoding(value = 0x0B)]
     MemorizeSeal(seal::Revealed),
 
+    #[display("sign_psbt(...)")]
+    #[network_encoding(value = 0x3A)]
+    SignPsbt(Psbt),
+
+    // Swaps
+    // -----
+    #[display(inner)]
+    #[network_encoding(value = 0x3B)]
+    ProposeSwap(SwapProposeReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x3C)]
+    AcceptSwap(SwapAcceptReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x3D)]
+    CompleteSwap(SwapCompleteReq),
+
+    #[display("abort_swap({0})")]
+    #[network_encoding(value = 0x3E)]
+    AbortSwap(SwapId),
+
+    // Payjoins
+    // --------
+    #[display(inner)]
+    #[network_encoding(value = 0x3F)]
+    ProposePayjoin(PayjoinProposeReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x40)]
+    ContributePayjoin(PayjoinContributeReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x41)]
+    CompletePayjoin(PayjoinCompleteReq),
+
+    // Wallet operations
+    // -----------------
+    #[display(inner)]
+    #[network_encoding(value = 0x42)]
+    SyncWallet(WalletReq),
+
+    #[display("get_balance(...)")]
+    #[network_encoding(value = 0x43)]
+    GetBalance(String),
+
+    #[display("create_wallet(...)")]
+    #[network_encoding(value = 0x44)]
+    CreateWallet(String),
+
+    #[display("list_wallets")]
+    #[network_encoding(value = 0x45)]
+    ListWallets,
+
+    #[display(inner)]
+    #[network_encoding(value = 0x46)]
+    ListAddresses(AddressesReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x47)]
+    GetHistoricalBalance(HistoricalBalanceReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x48)]
+    ComposePayment(PaymentReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x49)]
+    PublishTx(PublishReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x4A)]
+    AccelerateTransfer(AccelerateReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x4B)]
+    ForgetSpent(ForgetSpentReq),
+
+    // Annotations
+    // -----------
+    #[display(inner)]
+    #[network_encoding(value = 0x4C)]
+    Annotate(Annotation),
+
+    #[display("get_annotations(...)")]
+    #[network_encoding(value = 0x4D)]
+    GetAnnotations(BTreeSet<AnnotationTarget>),
+
+    // Transfer history
+    // ----------------
+    #[display(inner)]
+    #[network_encoding(value = 0x4E)]
+    ListTransfers(TransferFilter),
+
+    /// Lists invoices of the contract created by the node together with the
+    /// amounts they have received
+    #[display("list_invoices({0})")]
+    #[network_encoding(value = 0x4F)]
+    ListInvoices(ContractId),
+
+    /// Requests receipt signed by the node for the transfer with the given
+    /// consignment id
+    #[display("get_receipt({0})")]
+    #[network_encoding(value = 0x50)]
+    GetReceipt(ConsignmentId),
+
+    /// Requests signed order of the contract issue or the transfer from the
+    /// order journal
+    #[display("get_order({0})")]
+    #[network_encoding(value = 0x51)]
+    GetOrder(OrderTarget),
+
+    // Quarantine
+    // ----------
+    #[display("list_quarantine")]
+    #[network_encoding(value = 0x52)]
+    ListQuarantine,
+
+    #[display("approve_quarantined({0})")]
+    #[network_encoding(value = 0x53)]
+    ApproveQuarantined(ConsignmentId),
+
+    #[display("revalidate_quarantined({0})")]
+    #[network_encoding(value = 0x54)]
+    RevalidateQuarantined(ConsignmentId),
+
+    #[display("purge_quarantined({0})")]
+    #[network_encoding(value = 0x55)]
+    PurgeQuarantined(ConsignmentId),
+
+    // Relay
+    // -----
+    #[display(inner)]
+    #[network_encoding(value = 0x56)]
+    RelayDeposit(RelayDepositReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x57)]
+    RelayPickup(RelayPickupReq),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x58)]
+    RelayFetch(RelayFetchReq),
+
+    // Maintenance
+    // -----------
+    #[display("collect_garbage")]
+    #[network_encoding(value = 0x59)]
+    CollectGarbage,
+
+    #[display(inner)]
+    #[network_encoding(value = 0x5A)]
+    Prune(PruneReq),
+
+    #[display("get_health")]
+    #[network_encoding(value = 0x5B)]
+    GetHealth,
+
+    #[display("ping({0})")]
+    #[network_encoding(value = 0x5C)]
+    Ping(u64),
+
+    #[display("get_config")]
+    #[network_encoding(value = 0x5D)]
+    GetConfig,
+
+    #[display("cancel({0})")]
+    #[network_encoding(value = 0x5E)]
+    Cancel(ClientId),
+
+    #[display("promote")]
+    #[network_encoding(value = 0x5F)]
+    Promote,
+
+    // Access control
+    // --------------
+    #[display("grant_access({0})")]
+    #[network_encoding(value = 0x60)]
+    GrantAccess(AccessReq),
+
+    #[display("revoke_access({0})")]
+    #[network_encoding(value = 0x61)]
+    RevokeAccess(AccessReq),
+
+    #[display("list_access")]
+    #[network_encoding(value = 0x62)]
+    ListAccess,
+
     // Responses to CLI
     // ----------------
     #[display("contract_ids(...)")]
+    #[network_encoding(value = 0x0C)]
     ContractIds(BTreeSet<ContractId>),
 
+    #[display("schemata(...)")]
+    #[network_encoding(value = 0x63)]
+    Schemata(Vec<SchemaInfo>),
+
+    #[display("contract_deps(...)")]
+    #[network_encoding(value = 0x64)]
+    ContractDeps(Vec<ContractDeps>),
+
     #[display("contract(...)")]
+    #[network_encoding(value = 0x0D)]
     Contract(Contract),
 
     #[display("contract_state(...)")]
+    #[network_encoding(value = 0x0E)]
     ContractState(ContractState),
 
+    #[display("decimal_contract_state(...)")]
+    #[network_encoding(value = 0x65)]
+    DecimalContractState(DecimalContractState),
+
+    #[display("contract_stats(...)")]
+    #[network_encoding(value = 0x66)]
+    ContractStats(ContractStats),
+
+    #[display("allocations(...)")]
+    #[network_encoding(value = 0x67)]
+    Allocations(Vec<ContractAllocation>),
+
+    #[display("allocation_status(...)")]
+    #[network_encoding(value = 0x68)]
+    AllocationStatus(AllocationStatus),
+
+    #[display("storage_usage(...)")]
+    #[network_encoding(value = 0x69)]
+    StorageUsage(Vec<StorageUsage>),
+
+    #[display("state_digest({0})")]
+    #[network_encoding(value = 0x6A)]
+    StateDigest(StateDigest),
+
+    #[display("history_diff({0})")]
+    #[network_encoding(value = 0x6B)]
+    HistoryDiff(HistoryDiff),
+
+    #[display("stash_commitment({0})")]
+    #[network_encoding(value = 0x6C)]
+    StashCommitment(StashCommitment),
+
+    #[display("timestamp_proof({0})")]
+    #[network_encoding(value = 0x6D)]
+    TimestampProof(TimestampProof),
+
     #[display("outpoint_state(...)")]
+    #[network_encoding(value = 0x0F)]
     OutpointState(ContractStateMap),
 
+    #[display("outpoint_status(...)")]
+    #[network_encoding(value = 0x6E)]
+    OutpointStatus(OutpointStatus),
+
     #[display("state_transfer(...)")]
+    #[network_encoding(value = 0x10)]
     StateTransfer(StateTransfer),
 
+    /// Contract or state transfer consignment in ASCII-armored form, sent
+    /// instead of [`RpcMsg::Contract`] and [`RpcMsg::StateTransfer`] when
+    /// requested with [`ComposeReq::armored`]
+    #[display("armored(...)")]
+    #[network_encoding(value = 0x6F)]
+    Armored(String),
+
     #[display("state_transfer_finalize(...)")]
+    #[network_encoding(value = 0x11)]
     StateTransferFinalize(TransferFinalize),
 
     #[display("state_transfer_finalize(...)")]
+    #[network_encoding(value = 0x12)]
     FinalizedTransfers(FinalizeTransfersRes),
 
+    #[display("signed_psbt(...)")]
+    #[network_encoding(value = 0x70)]
+    SignedPsbt(Psbt),
+
+    #[display("balance(...)")]
+    #[network_encoding(value = 0x71)]
+    Balance(WalletBalance),
+
+    #[display("wallets(...)")]
+    #[network_encoding(value = 0x72)]
+    Wallets(Vec<WalletInfo>),
+
+    #[display("addresses(...)")]
+    #[network_encoding(value = 0x73)]
+    Addresses(Vec<WalletAddress>),
+
+    #[display("historical_balance(...)")]
+    #[network_encoding(value = 0x74)]
+    HistoricalBalance(HistoricalBalance),
+
+    #[display("payment_draft(...)")]
+    #[network_encoding(value = 0x75)]
+    PaymentDraft(PaymentDraft),
+
+    #[display("tx_published({0})")]
+    #[network_encoding(value = 0x76)]
+    TxPublished(Txid),
+
+    #[display("cpfp_draft({0})")]
+    #[network_encoding(value = 0x77)]
+    CpfpDraft(CpfpDraft),
+
+    #[display("annotations(...)")]
+    #[network_encoding(value = 0x78)]
+    Annotations(Vec<Annotation>),
+
+    #[display("transfer_list(...)")]
+    #[network_encoding(value = 0x79)]
+    TransferList(Vec<TransferRecord>),
+
+    #[display("invoice_list(...)")]
+    #[network_encoding(value = 0x7A)]
+    InvoiceList(Vec<InvoiceRecord>),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x7B)]
+    Receipt(SignedReceipt),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x7C)]
+    Order(OrderRecord),
+
+    #[display("quarantine_list(...)")]
+    #[network_encoding(value = 0x7D)]
+    QuarantineList(Vec<QuarantineInfo>),
+
+    #[display("garbage_collected({0})")]
+    #[network_encoding(value = 0x7E)]
+    GarbageCollected(GcReport),
+
+    #[display("pruned({0})")]
+    #[network_encoding(value = 0x7F)]
+    Pruned(PruneReport),
+
+    #[display("contract_forgotten({0})")]
+    #[network_encoding(value = 0x80)]
+    ContractForgotten(ForgetReport),
+
+    #[display("state_rebuilt({0})")]
+    #[network_encoding(value = 0x81)]
+    StateRebuilt(RebuildReport),
+
+    #[display("contracts_revalidated({0})")]
+    #[network_encoding(value = 0x82)]
+    ContractsRevalidated(RevalidationReport),
+
+    #[display("journal(...)")]
+    #[network_encoding(value = 0x83)]
+    Journal(Vec<JournalEntry>),
+
+    #[display("state_changed({0})")]
+    #[network_encoding(value = 0x84)]
+    StateChanged(StateChange),
+
+    #[display("seal_revealed(...)")]
+    #[network_encoding(value = 0x85)]
+    SealRevealed(BTreeSet<NodeId>),
+
+    #[display("invoice({0})")]
+    #[network_encoding(value = 0x86)]
+    Invoice(Invoice),
+
+    #[display("reveal_data({0})")]
+    #[network_encoding(value = 0x87)]
+    RevealData(RevealData),
+
+    #[display("health({0})")]
+    #[network_encoding(value = 0x88)]
+    Health(NodeHealth),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x89)]
+    Pong(Pong),
+
+    #[display("config(...)")]
+    #[network_encoding(value = 0x8A)]
+    Config(Vec<Setting>),
+
+    #[display("relay_deposited({0})")]
+    #[network_encoding(value = 0x8B)]
+    RelayDeposited(RelayEnvelope),
+
+    #[display("relay_envelopes(...)")]
+    #[network_encoding(value = 0x8C)]
+    RelayEnvelopes(Vec<RelayEnvelope>),
+
+    #[display("relay_chunk(...)")]
+    #[network_encoding(value = 0x8D)]
+    RelayChunk(UploadChunk),
+
+    #[display("access_grants(...)")]
+    #[network_encoding(value = 0x8E)]
+    AccessGrants(Vec<AccessGrants>),
+
+    #[display("known_history(...)")]
+    #[network_encoding(value = 0x8F)]
+    KnownHistory(BTreeSet<NodeId>),
+
+    #[display("checkpoint({0})")]
+    #[network_encoding(value = 0x90)]
+    Checkpoint(Checkpoint),
+
+    #[display("signed_checkpoint({0})")]
+    #[network_encoding(value = 0x91)]
+    SignedCheckpoint(SignedCheckpoint),
+
+    #[display("proofs({0})")]
+    #[network_encoding(value = 0x92)]
+    Proofs(ProofBundle),
+
+    #[display("policy({0})")]
+    #[network_encoding(value = 0x93)]
+    Policy(ContractPolicy),
+
+    #[display("transition(...)")]
+    #[network_encoding(value = 0x94)]
+    Transition(Transition),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x95)]
+    SwapOffer(SwapOffer),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x96)]
+    Swap(SwapAcceptance),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x97)]
+    PayjoinProposal(PayjoinProposal),
+
+    #[display(inner)]
+    #[network_encoding(value = 0x98)]
+    Payjoin(PayjoinContribution),
+
+    #[display("capabilities(...)")]
+    #[network_encoding(value = 0x99)]
+    Capabilities(BTreeSet<Capability>),
+
+    #[display("welcome({0})")]
+    #[network_encoding(value = 0x9A)]
+    Welcome(Welcome),
+
+    #[display("preview(...)")]
+    #[network_encoding(value = 0x9B)]
+    Preview(OperationPreview),
+
     #[display("progress(\"{0}\")")]
     #[from]
+    #[network_encoding(value = 0x13)]
     Progress(String),
 
+    #[display("progress_step({0})")]
+    #[network_encoding(value = 0x9C)]
+    ProgressStep(ProgressStep),
+
     #[display("success{0}")]
+    #[network_encoding(value = 0x14)]
     Success(OptionDetails),
 
     #[display("failure({0:#})")]
     #[from]
+    #[network_encoding(value = 0x15)]
     Failure(rpc::Failure<FailureCode>),
 
-    #[display("unresolved_txids(...)")]
-    UnresolvedTxids(Vec<Txid>),
+    #[display("valid({0})")]
+    #[network_encoding(value = 0x9D)]
+    Valid(ValidationReport),
+
+    #[display("unresolved_txids({0})")]
+    #[network_encoding(value = 0x16)]
+    UnresolvedTxids(ValidationReport),
 
-    #[display("invalid(...)")]
-    Invalid(validation::Status),
+    #[display("invalid({0})")]
+    #[network_encoding(value = 0x17)]
+    Invalid(ValidationReport),
 }
 
 impl From<presentation::Error> for RpcMsg {
@@ -142,10 +770,102 @@ impl RpcMsg {
 
 #[derive(Clone, Debug)]
 #[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
 pub enum ContractValidity {
-    Valid,
-    Invalid(validation::Status),
-    UnknownTxids(Vec<Txid>),
+    /// Consignment is valid and accepted, possibly with warnings
+    Valid(ValidationReport),
+    /// Consignment is invalid and rejected
+    Invalid(ValidationReport),
+    /// Consignment is valid, but some of its transactions are not mined yet,
+    /// so it is not accepted unless it is forced
+    UnknownTxids(ValidationReport),
+}
+
+impl ContractValidity {
+    pub fn report(&self) -> &ValidationReport {
+        match self {
+            ContractValidity::Valid(report)
+            | ContractValidity::Invalid(report)
+            | ContractValidity::UnknownTxids(report) => report,
+        }
+    }
+}
+
+/// Consignment validation status with the issues rendered as text, such that
+/// the clients do not depend on the RGB Core validation types.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{} failure(s), {} warning(s)", self.failures.len(), self.warnings.len())]
+pub struct ValidationReport {
+    /// Issues making the consignment invalid
+    pub failures: Vec<String>,
+    /// Non-fatal issues, like usage of the schema features unknown to the
+    /// node or endpoint witness transactions which are not mined yet
+    pub warnings: Vec<String>,
+    pub info: Vec<String>,
+    /// Transactions which can't be found in the blockchain
+    pub unresolved_txids: Vec<Txid>,
+    /// Witness transactions of the consignment endpoints which are not mined
+    /// yet
+    pub unmined_endpoint_txids: Vec<Txid>,
+    /// Cost of the validation
+    pub cost: Option<ValidationCost>,
+}
+
+impl From<&validation::Status> for ValidationReport {
+    fn from(status: &validation::Status) -> Self {
+        ValidationReport {
+            failures: status.failures.iter().map(ToString::to_string).collect(),
+            warnings: status.warnings.iter().map(ToString::to_string).collect(),
+            info: status.info.iter().map(ToString::to_string).collect(),
+            unresolved_txids: status.unresolved_txids.clone(),
+            unmined_endpoint_txids: status.unmined_endpoint_txids.clone(),
+            cost: None,
+        }
+    }
+}
+
+impl ValidationReport {
+    /// Adds cost of the validation to the report
+    pub fn with_cost(mut self, cost: Option<ValidationCost>) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// Transactions which are not known to be mined, either unresolved or
+    /// being the endpoint witnesses
+    pub fn unknown_txids(&self) -> impl Iterator<Item = Txid> + '_ {
+        self.unresolved_txids.iter().chain(&self.unmined_endpoint_txids).copied()
+    }
+}
+
+/// Cost of the consignment validation. Contract scripts are run by RGB Core,
+/// which does not report the executed instructions or the used memory, so the
+/// cost is measured with the number of the script runs and the script size.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{script_runs} script run(s) of {script_size} byte(s) in {duration_ms} ms")]
+pub struct ValidationCost {
+    /// Number of the contract nodes which scripts were executed
+    pub script_runs: u32,
+    /// Size of the schema validation script, in bytes
+    pub script_size: u32,
+    /// Time spent on the validation, in milliseconds
+    pub duration_ms: u64,
 }
 
 #[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
@@ -153,24 +873,87 @@ pub enum ContractValidity {
 pub enum OutpointFilter {
     All,
     Only(BTreeSet<OutPoint>),
+    /// UTXOs of a wallet previously synchronized with the node, found during
+    /// its last synchronization on the addresses with derivation indexes in
+    /// the given range. The node expands the filter into the explicit
+    /// outpoints, so the clients do not need to enumerate them.
+    Wallet {
+        /// Wallet output descriptor; descriptors with `<0;1>` derivation step
+        /// cover both receive and change addresses
+        descriptor: String,
+        from_index: u32,
+        /// Last derivation index included; unbounded if absent
+        to_index: Option<u32>,
+    },
 }
 
 impl OutpointFilter {
+    /// Checks whether the filter includes the outpoint. Wallet filters must be
+    /// expanded by the node before the check and never include any outpoint.
     pub fn includes(&self, outpoint: OutPoint) -> bool {
         match self {
             OutpointFilter::All => true,
             OutpointFilter::Only(set) => set.contains(&outpoint),
+            OutpointFilter::Wallet { .. } => false,
+        }
+    }
+
+    /// Checks whether the derivation index belongs to the range of a wallet
+    /// filter
+    pub fn includes_index(&self, index: u32) -> bool {
+        match self {
+            OutpointFilter::Wallet {
+                from_index,
+                to_index,
+                ..
+            } => index >= *from_index && to_index.map_or(true, |to_index| index <= to_index),
+            _ => false,
         }
     }
 }
 
+impl From<BTreeSet<OutPoint>> for OutpointFilter {
+    fn from(outpoints: BTreeSet<OutPoint>) -> Self { OutpointFilter::Only(outpoints) }
+}
+
+/// Outpoints to report the RGB state for, given directly or by the
+/// scriptPubKey (address) controlling them
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[derive(NetworkEncode, NetworkDecode)]
+pub struct OutpointStateQuery {
+    pub outpoints: BTreeSet<OutPoint>,
+    /// Scripts resolved into the outpoints using the UTXOs discovered during
+    /// the wallet synchronization; scripts of the wallets which were never
+    /// synchronized with the node resolve into no outpoints
+    pub scripts: BTreeSet<Script>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
 #[derive(NetworkEncode, NetworkDecode)]
-#[display("accept(force: {force}, ...)")]
+#[display("accept(force: {force}, full: {full}, ...)")]
 pub struct AcceptReq<T: ConsignmentType> {
     pub consignment: InmemConsignment<T>,
     pub force: bool,
+    /// Validate the whole contract history even if the contract has a
+    /// checkpoint imported
+    pub full: bool,
     pub reveal: Option<Reveal>,
+    /// Number of seconds after which the node abandons the request if it is
+    /// not completed
+    pub timeout: Option<u32>,
+    /// Proofs of the consignment witness transactions, used instead of the
+    /// chain backend during validation
+    pub proofs: Option<ProofBundle>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("prove_transactions(..., {from_height})")]
+pub struct ProveReq {
+    pub txids: BTreeSet<Txid>,
+    /// Height of the header checkpoint of the node which will verify the
+    /// proofs
+    pub from_height: u32,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
@@ -179,6 +962,180 @@ pub struct AcceptReq<T: ConsignmentType> {
 pub struct HelloReq {
     pub user_agent: String,
     pub network: Chain,
+    /// Magic of the custom signet, if the client works with one
+    pub signet: Option<NetworkMagic>,
+    /// Optional features supported by the client
+    pub capabilities: BTreeSet<Capability>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("create_checkpoint({contract_id}, {height})")]
+pub struct CheckpointReq {
+    pub contract_id: ContractId,
+    /// Height of the last block which transitions are included
+    pub height: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("set_policy({contract_id}, {policy})")]
+pub struct PolicyReq {
+    pub contract_id: ContractId,
+    pub policy: ContractPolicy,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("forget_contract({contract_id}, tombstone: {tombstone})")]
+pub struct ForgetReq {
+    pub contract_id: ContractId,
+    /// Whether the node must keep a tombstone of the contract, refusing its
+    /// re-import unless it is forced
+    pub tombstone: bool,
+}
+
+/// Request revealing seal of the contract assignments, which were accepted
+/// with the seal being concealed
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("reveal_seal({contract_id}, ...)")]
+pub struct RevealReq {
+    pub contract_id: ContractId,
+    pub reveal: Reveal,
+}
+
+/// Request creating invoice for the contract asset. The seal blinding factor
+/// is generated by the node and the seal reveal is kept, so the state
+/// received by the invoice becomes spendable once the consignment is accepted.
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("create_invoice({contract_id}, {amount}, {outpoint}, ...)")]
+pub struct InvoiceReq {
+    pub contract_id: ContractId,
+    pub amount: AtomicValue,
+    /// Locally-controlled outpoint receiving the payment
+    pub outpoint: OutPoint,
+    pub close_method: CloseMethod,
+    /// Bifrost node accepting the transfer consignment
+    pub transport: Option<NodeAddr>,
+    /// Magic of the custom signet the outpoint belongs to
+    pub signet: Option<NetworkMagic>,
+    /// Tolerance of the received amount; ignored by the open invoices
+    pub tolerance: InvoiceTolerance,
+}
+
+/// Request exporting reveal data for the contract seals known to the node
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("export_reveals({contract_id}, ...)")]
+pub struct RevealExportReq {
+    pub contract_id: ContractId,
+    /// Outpoints which seals must be revealed
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("consolidate_allocations({contract_id}, ...)")]
+pub struct ConsolidateReq {
+    pub contract_id: ContractId,
+    /// Outpoints which contract state must be moved to the new seal
+    pub outpoints: BTreeSet<OutPoint>,
+    /// Seal receiving all the state; usually points to an output of the
+    /// witness transaction. Its blinding factor is ignored: the node generates
+    /// it from its entropy source, and the resulting seal is revealed in the
+    /// returned transition.
+    pub seal: seal::Revealed,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("propose_swap({terms}, timeout: {timeout})")]
+pub struct SwapProposeReq {
+    pub terms: SwapTerms,
+    /// Number of seconds during which the swap must be completed
+    pub timeout: u32,
+    pub psbt: Psbt,
+    pub consignment: Option<StateTransfer>,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("accept_{offer}")]
+pub struct SwapAcceptReq {
+    pub offer: SwapOffer,
+    /// Offer PSBT extended with the taker inputs and state transition
+    pub psbt: Psbt,
+    /// Draft consignment for the asset given by the taker, if it is an RGB
+    /// asset
+    pub consignment: Option<StateTransfer>,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("complete_{acceptance}")]
+pub struct SwapCompleteReq {
+    /// Swap acceptance received from the counterparty
+    pub acceptance: SwapAcceptance,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("propose_payjoin(...)")]
+pub struct PayjoinProposeReq {
+    /// PSBT spending payer inputs and containing its state transitions
+    pub psbt: Psbt,
+    /// Draft consignment for the paid asset
+    pub consignment: StateTransfer,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("contribute_payjoin({proposal}, fee: {fee}, ...)")]
+pub struct PayjoinContributeReq {
+    pub proposal: PayjoinProposal,
+    /// Descriptor of the beneficiary wallet owning the contributed outpoints
+    pub descriptor: String,
+    /// Wallet outpoints contributed to the witness transaction
+    pub outpoints: BTreeSet<OutPoint>,
+    /// Beneficiary seals of the payment
+    pub endseals: Vec<SealEndpoint>,
+    /// Fee paid by the beneficiary for its part of the transaction
+    pub fee: u64,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("complete_{contribution}")]
+pub struct PayjoinCompleteReq {
+    /// Payjoin contribution received from the beneficiary
+    pub contribution: PayjoinContribution,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("issue_contract({spec})")]
+pub struct IssueReq {
+    pub spec: IssueSpec,
+    /// Contract schema, required if the schema is not yet known to the node
+    pub schema: Option<Schema>,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
@@ -188,6 +1145,14 @@ pub struct ComposeReq {
     pub contract_id: ContractId,
     pub include: BTreeSet<TransitionType>,
     pub outpoints: OutpointFilter,
+    /// Tips of the contract history known to the beneficiary; these
+    /// transitions and their ancestors are not included into the consignment.
+    pub known: BTreeSet<NodeId>,
+    /// Number of seconds after which the node abandons the request if it is
+    /// not completed
+    pub timeout: Option<u32>,
+    /// Whether the consignment must be returned in ASCII-armored form
+    pub armored: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
@@ -198,6 +1163,30 @@ pub struct TransferReq {
     pub endseals: Vec<SealEndpoint>,
     pub psbt: Psbt,
     pub beneficiary: Option<NodeAddr>,
+    /// Key of the beneficiary which the consignment sent to the beneficiary
+    /// node is encrypted to
+    pub recipient_key: Option<XOnlyPublicKey>,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("send_transfer(..., {beneficiary})")]
+pub struct TransferSendReq {
+    pub consignment: StateTransfer,
+    pub beneficiary: NodeAddr,
+    /// Key of the beneficiary which the consignment is encrypted to
+    pub recipient_key: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("preview_transfer(...)")]
+pub struct TransferPreviewReq {
+    pub consignment: StateTransfer,
+    pub endseals: Vec<SealEndpoint>,
+    pub psbt: Psbt,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
@@ -214,6 +1203,8 @@ pub struct TransferFinalize {
 pub struct TransfersReq {
     pub transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
     pub psbt: Psbt,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
@@ -224,6 +1215,594 @@ pub struct FinalizeTransfersRes {
     pub psbt: Psbt,
 }
 
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("sync_wallet(gap_limit: {gap_limit}, rescan: {rescan}, ...)")]
+pub struct WalletReq {
+    /// Output descriptor of the wallet. Descriptors with `<0;1>` multipath
+    /// step are tracked as two keychains (receive and change).
+    pub descriptor: String,
+    pub gap_limit: u16,
+    /// Whether the wallet should be re-scanned from the first derivation index
+    pub rescan: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("compose_payment({contract_id}, fee: {fee}, ...)")]
+pub struct PaymentReq {
+    /// Output descriptor of the wallet paying for the transfer, which must be
+    /// previously synchronized with the node
+    pub descriptor: String,
+    pub contract_id: ContractId,
+    /// Amounts paid to each of the beneficiaries, which are all assigned by a
+    /// single state transition
+    pub beneficiaries: BTreeMap<SealEndpoint, AtomicValue>,
+    /// Fee of the witness transaction, in satoshis
+    pub fee: u64,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(NetworkEncode, NetworkDecode)]
+pub struct PaymentDraft {
+    /// PSBT spending the selected wallet UTXOs with a single change output,
+    /// which hosts the commitment and receives the change allocations
+    pub psbt: Psbt,
+    /// State transition paying to the beneficiaries
+    pub transition: Transition,
+    /// Wallet UTXOs spent by the state transition
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("publish_tx(...)")]
+pub struct PublishReq {
+    /// Signed PSBT of the witness transaction
+    pub psbt: Psbt,
+    /// Order signed with a key registered on the node
+    pub order: Option<SignedOrder>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("accelerate_transfer({consignment_id}, fee_rate: {fee_rate}, ...)")]
+pub struct AccelerateReq {
+    /// Pending incoming transfer which witness transaction must be
+    /// accelerated
+    pub consignment_id: ConsignmentId,
+    /// Output descriptor of the wallet owning an output of the witness
+    /// transaction, which must be previously synchronized with the node
+    pub descriptor: String,
+    /// Fee rate of the package of the witness and child transactions, in
+    /// satoshis per virtual byte
+    pub fee_rate: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("forget_spent(...)")]
+pub struct ForgetSpentReq {
+    /// Output descriptor of the wallet which has spent the outpoints, which
+    /// must be previously synchronized with the node
+    pub descriptor: String,
+    /// Outpoints spent without transferring the contract state assigned to
+    /// them
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("{parent}, fee: {fee}")]
+pub struct CpfpDraft {
+    /// PSBT of the child transaction spending the wallet outputs of the
+    /// witness transaction with a single change output
+    pub psbt: Psbt,
+    /// Witness transaction which is accelerated
+    pub parent: Txid,
+    /// Fee paid by the child transaction, in satoshis
+    pub fee: u64,
+}
+
+/// Results of the issuance or transfer reported by the node without
+/// persisting anything
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct OperationPreview {
+    /// Size of the PSBT with the state transitions committed into it, in
+    /// bytes; absent for the issuance
+    pub psbt_size: Option<u32>,
+    /// Estimated virtual size of the signed witness transaction
+    pub tx_vsize: Option<u64>,
+    /// Fee paid by the witness transaction, in satoshis
+    pub fee: Option<u64>,
+    /// Size of the consignment before the compression, in bytes
+    pub consignment_size: u32,
+    /// Contract state allocated by the operation
+    pub allocations: Vec<AllocationPreview>,
+}
+
+/// Contract state allocated by the previewed operation
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct AllocationPreview {
+    pub contract_id: ContractId,
+    pub right_type: OwnedRightType,
+    /// Outpoint of the allocation; absent for the concealed seals of the
+    /// beneficiaries
+    pub outpoint: Option<OutPoint>,
+    /// Allocated amount; absent for the state which is not fungible
+    pub amount: Option<AtomicValue>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct WalletUtxo {
+    pub outpoint: OutPoint,
+    pub value: u64,
+    /// Index of the keychain (terminal derivation step preceding the index)
+    pub keychain: u8,
+    pub index: u32,
+    /// Height of the block mining the transaction; zero for unconfirmed ones
+    pub height: u32,
+    pub confirmations: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct WalletBalance {
+    pub height: u32,
+    pub utxos: BTreeSet<WalletUtxo>,
+    /// RGB state assigned to the wallet UTXOs
+    pub allocations: ContractStateMap,
+    /// Total fungible amounts of each contract assigned to the wallet UTXOs
+    pub amounts: BTreeMap<ContractId, DecimalAmount>,
+}
+
+impl WalletBalance {
+    /// Total amount of bitcoins in the wallet UTXOs with at least the given
+    /// number of confirmations.
+    pub fn btc_amount(&self, min_confirmations: u32) -> u64 {
+        self.utxos
+            .iter()
+            .filter(|utxo| utxo.confirmations >= min_confirmations)
+            .map(|utxo| utxo.value)
+            .sum()
+    }
+}
+
+/// Wallet known to the node
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct WalletInfo {
+    pub descriptor: String,
+    /// Last derivation index having transaction history, per keychain. Empty
+    /// for the wallets which were never synchronized or have no history.
+    pub last_used: BTreeMap<u8, u32>,
+    /// Number of the wallet UTXOs found during the last synchronization
+    pub utxo_count: u32,
+    /// Amount of bitcoins in the wallet UTXOs, in satoshis
+    pub btc_amount: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("list_addresses(unused: {unused}, ...)")]
+pub struct AddressesReq {
+    /// Output descriptor of the wallet previously created or synchronized
+    /// with the node
+    pub descriptor: String,
+    /// Number of addresses following the last used one, which are listed in
+    /// addition to the addresses up to the last used derivation index
+    pub unused: u16,
+}
+
+/// Address derived from the wallet descriptor
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct WalletAddress {
+    /// Index of the keychain (terminal derivation step preceding the index)
+    pub keychain: u8,
+    pub index: u32,
+    pub address: String,
+    /// Wallet UTXOs on the address found during the last synchronization
+    pub utxos: BTreeSet<OutPoint>,
+    /// Contracts having state assigned to the address UTXOs
+    pub contracts: BTreeSet<ContractId>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("get_historical_balance({contract_id}, {height}, ...)")]
+pub struct HistoricalBalanceReq {
+    pub contract_id: ContractId,
+    /// Height of the last block which state transitions are accounted
+    pub height: u32,
+    /// Output descriptor of a wallet previously synchronized with the node;
+    /// allocations on the outputs of the wallet scripts are accounted
+    pub descriptor: Option<String>,
+    /// Outpoints which allocations are accounted in addition to the wallet
+    /// ones. If empty and no wallet is given, all allocations are accounted.
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct HistoricalBalance {
+    pub contract_id: ContractId,
+    pub height: u32,
+    /// Number of decimal digits in the contract amounts
+    pub precision: u8,
+    /// Revealed amounts allocated to the outpoints at the given height
+    pub allocations: BTreeMap<OutPoint, DecimalAmount>,
+}
+
+impl HistoricalBalance {
+    /// Total amount allocated to all the outpoints
+    pub fn amount(&self) -> AtomicValue {
+        self.allocations.values().map(|amount| amount.atomic).sum()
+    }
+
+    /// Total amount allocated to all the outpoints, formatted with the
+    /// contract precision
+    pub fn decimal_amount(&self) -> DecimalAmount {
+        DecimalAmount::with(self.amount(), self.precision)
+    }
+}
+
+/// Aggregate statistics of a contract known to the node
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct ContractStats {
+    pub contract_id: ContractId,
+    /// Number of known allocations of the contract state
+    pub allocations: u32,
+    /// Number of distinct outpoints having known allocations
+    pub outpoints: u32,
+    /// Number of state transitions kept in the stash
+    pub transitions: u32,
+    /// Maximal number of state transitions on a path from genesis; unknown
+    /// if some of the transitions are not indexed
+    pub depth: Option<u32>,
+    /// Total size of the contract genesis, state transitions and their
+    /// anchors kept in the stash
+    pub stored_bytes: u64,
+}
+
+/// Schema known to the node together with the contracts using it
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct SchemaInfo {
+    pub schema_id: SchemaId,
+    /// Root schema, if the schema is a subschema
+    pub root_schema_id: Option<SchemaId>,
+    /// Data format of each of the metadata fields
+    pub fields: BTreeMap<FieldType, String>,
+    /// State kept by each of the owned rights
+    pub owned_rights: BTreeMap<OwnedRightType, String>,
+    pub public_rights: BTreeSet<PublicRightType>,
+    pub transitions: BTreeSet<TransitionType>,
+    pub extensions: BTreeSet<ExtensionType>,
+    /// Known contracts issued under the schema
+    pub contracts: BTreeSet<ContractId>,
+}
+
+/// Dependencies of a contract known to the node on other known contracts
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct ContractDeps {
+    pub contract_id: ContractId,
+    pub schema_id: SchemaId,
+    /// Root schema, if the contract schema is a subschema
+    pub root_schema_id: Option<SchemaId>,
+    /// Known contracts which schema is the root schema of the contract
+    /// subschema, is a subschema of the contract schema or shares the same
+    /// root schema with it
+    pub siblings: BTreeSet<ContractId>,
+    /// Known contracts referenced from the contract metadata, like the
+    /// contracts which assets were burned to issue this one
+    pub references: BTreeSet<ContractId>,
+    /// Known contracts which metadata references this contract
+    pub referenced_by: BTreeSet<ContractId>,
+}
+
+/// Allocation of the contract state known to the node
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct ContractAllocation {
+    pub outpoint: OutPoint,
+    /// Contract node assigning the state
+    pub node_id: NodeId,
+    pub right_type: OwnedRightType,
+    /// Index of the assignment within the node assignments of the same type
+    pub no: u16,
+    /// Allocated amount; absent for the state which is not fungible
+    pub amount: Option<DecimalAmount>,
+    /// Whether the outpoint is an unspent output of one of the wallets
+    /// registered with the node, as found by their last synchronization.
+    /// Other allocations belong to third parties and are known to the node
+    /// from the consignments only.
+    pub owned: bool,
+}
+
+/// Spent status of an outpoint, as found by the node during the last
+/// synchronization of the wallets owning it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum SpentStatus {
+    /// Outpoint is an unspent output of a wallet
+    #[display("unspent")]
+    Unspent,
+
+    /// Outpoint is spent by a transaction mined at the given height
+    #[display("spent by {txid} at height {height}")]
+    Spent { txid: Txid, height: u32 },
+
+    /// Outpoint is spent by a transaction which is not mined yet
+    #[display("spent by {0} in mempool")]
+    Mempool(Txid),
+}
+
+/// Contract state assigned to the outpoints together with the spent status of
+/// these outpoints and the fungible amounts assigned to them. Sent instead of
+/// [`RpcMsg::OutpointState`] to the clients which have negotiated
+/// [`Capability::SpentStatus`] or [`Capability::DecimalAmounts`].
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct OutpointStatus {
+    pub state: ContractStateMap,
+    /// Spent status of the outpoints having the state. Outpoints which do not
+    /// belong to the wallets synchronized with the node are absent.
+    pub spent: BTreeMap<OutPoint, SpentStatus>,
+    /// Total fungible amounts of each contract assigned to the outpoints.
+    /// Empty unless [`Capability::DecimalAmounts`] is negotiated.
+    pub amounts: BTreeMap<ContractId, DecimalAmount>,
+}
+
+/// Contract state together with its fungible amounts formatted with the
+/// contract precision. Sent instead of [`RpcMsg::ContractState`] to the
+/// clients which have negotiated [`Capability::DecimalAmounts`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct DecimalContractState {
+    pub state: ContractState,
+    /// Revealed fungible amounts allocated to each of the outpoints
+    pub amounts: BTreeMap<OutPoint, DecimalAmount>,
+    /// Total revealed fungible amount of the contract, if the contract has
+    /// any fungible state
+    pub total: Option<DecimalAmount>,
+}
+
+impl DecimalContractState {
+    /// Sums up revealed fungible amounts of the contract state using the
+    /// precision from the contract genesis metadata.
+    pub fn with(state: ContractState) -> Self {
+        let precision = contract_precision(&state.metadata);
+        let mut values = BTreeMap::<OutPoint, AtomicValue>::new();
+        for assigned in &state.owned_values {
+            let value = values.entry(assigned.outpoint).or_default();
+            *value = value.saturating_add(assigned.state.value);
+        }
+        let total = match values.is_empty() {
+            true => None,
+            false => Some(values.values().copied().fold(0u64, u64::saturating_add)),
+        };
+        DecimalContractState {
+            state,
+            amounts: values
+                .into_iter()
+                .map(|(outpoint, value)| (outpoint, DecimalAmount::with(value, precision)))
+                .collect(),
+            total: total.map(|total| DecimalAmount::with(total, precision)),
+        }
+    }
+}
+
+/// Allocations of the contract state together with the spent status of their
+/// outpoints. Sent instead of [`RpcMsg::Allocations`] to the clients which
+/// have negotiated [`Capability::SpentStatus`].
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct AllocationStatus {
+    pub allocations: Vec<ContractAllocation>,
+    /// Spent status of the allocation outpoints. Outpoints which do not
+    /// belong to the wallets synchronized with the node are absent.
+    pub spent: BTreeMap<OutPoint, SpentStatus>,
+}
+
+/// Amount of data kept in the store for a contract. The node updates it each
+/// time the contract is modified.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct StorageUsage {
+    pub contract_id: ContractId,
+    /// Size of the contract genesis and state transitions, in bytes
+    pub transitions: u64,
+    /// Size of the anchors and transition bundles of the witness transactions,
+    /// in bytes; anchors shared with other contracts are accounted for each
+    /// of them
+    pub anchors: u64,
+    /// Size of the Storm containers with the contract consignments which are
+    /// not yet removed by the garbage collection, in bytes
+    pub attachments: u64,
+    /// UNIX timestamp of the last update
+    pub updated: u64,
+}
+
+impl StorageUsage {
+    /// Total size of the contract data kept in the store
+    pub fn total(&self) -> u64 { self.transitions + self.anchors + self.attachments }
+}
+
+/// Storm container data removed from the store by the garbage collection
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{containers} container(s) with {chunks} chunk(s), {bytes} bytes")]
+pub struct GcReport {
+    pub containers: u32,
+    pub chunks: u32,
+    /// Total size of the removed data
+    pub bytes: u64,
+}
+
+/// Contract data removed from the store when the contract is forgotten
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{nodes} contract node(s), {transfers} transfer(s), {bytes} bytes")]
+pub struct ForgetReport {
+    /// Number of state transitions and extensions
+    pub nodes: u32,
+    /// Number of incoming and outgoing transfer records
+    pub transfers: u32,
+    /// Total size of the removed data
+    pub bytes: u64,
+}
+
+/// Contract states rebuilt from the stash data
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct RebuildReport {
+    /// Number of contracts which state was rebuilt
+    pub contracts: u32,
+    /// Contracts which rebuilt state differs from the state kept by the node
+    pub diverged: BTreeSet<ContractId>,
+}
+
+impl Display for RebuildReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} contract(s), {} diverged", self.contracts, self.diverged.len())
+    }
+}
+
+/// Contracts re-validated with the current version of the validation rules
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub struct RevalidationReport {
+    /// Version of the validation rules used by the node
+    pub validator: u16,
+    /// Number of re-validated contracts
+    pub contracts: u32,
+    /// Contracts which are found invalid, with the validation failures
+    pub invalid: BTreeMap<ContractId, Vec<String>>,
+    /// Contracts which validation was not completed since some of their
+    /// witness transactions were not resolved
+    pub unresolved: BTreeSet<ContractId>,
+}
+
+impl Display for RevalidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} contract(s), {} invalid, {} unresolved",
+            self.contracts,
+            self.invalid.len(),
+            self.unresolved.len()
+        )
+    }
+}
+
 impl From<&str> for RpcMsg {
     fn from(s: &str) -> Self { RpcMsg::Progress(s.to_owned()) }
 }
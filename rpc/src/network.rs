@@ -0,0 +1,70 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Parameters of custom signets: private test networks which blocks are signed
+//! according to the network challenge script (BIP-325). All signets share
+//! the same genesis block, so they are distinguished by the network magic
+//! derived from the challenge.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use bitcoin::hashes::hex::{self, FromHex, ToHex};
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::{consensus, Script};
+
+/// Network magic of a custom signet
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(StrictEncode, StrictDecode)]
+pub struct NetworkMagic([u8; 4]);
+
+impl Display for NetworkMagic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { f.write_str(&self.0.to_hex()) }
+}
+
+impl FromStr for NetworkMagic {
+    type Err = hex::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = Vec::<u8>::from_hex(s)?;
+        let magic = <[u8; 4]>::try_from(data.as_slice())
+            .map_err(|_| hex::Error::InvalidLength(8, s.len()))?;
+        Ok(NetworkMagic(magic))
+    }
+}
+
+/// Challenge script of a custom signet, given in hex
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, From)]
+#[derive(StrictEncode, StrictDecode)]
+pub struct SignetChallenge(Script);
+
+impl Display for SignetChallenge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { write!(f, "{:x}", self.0) }
+}
+
+impl FromStr for SignetChallenge {
+    type Err = hex::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Vec::<u8>::from_hex(s).map(Script::from).map(SignetChallenge)
+    }
+}
+
+impl SignetChallenge {
+    /// Network magic of the signet, which consists of the first four bytes of
+    /// the double SHA256 hash of the challenge script serialized as a single
+    /// data push.
+    pub fn magic(&self) -> NetworkMagic {
+        let hash = sha256d::Hash::hash(&consensus::serialize(&self.0));
+        let mut magic = [0u8; 4];
+        magic.copy_from_slice(&hash[..4]);
+        NetworkMagic(magic)
+    }
+}
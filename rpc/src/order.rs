@@ -0,0 +1,270 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Signed orders for the requests changing the stash.
+//!
+//! Client may sign contract issue requests and the requests moving assets
+//! (transfers, payments, swaps and payjoins) with a key registered on the
+//! node. The node verifies the signature before processing the request and
+//! keeps the signed order in the order journal under the id of the issued
+//! contract or the finalized transfer consignment, so the node operator can
+//! prove which key has ordered the operation.
+
+use std::str::FromStr;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr, KeyPair, Message, Secp256k1, Signing, Verification};
+use bitcoin::XOnlyPublicKey;
+use rgb::{ConsignmentId, ContractId};
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+use strict_encoding::StrictEncode;
+
+use crate::relay::tagged_engine;
+use crate::{
+    parse_contract_id, ConsolidateReq, IssueReq, PayjoinCompleteReq, PayjoinContributeReq,
+    PayjoinProposeReq, PaymentReq, PublishReq, SwapAcceptReq, SwapCompleteReq, SwapProposeReq,
+    TransferReq, TransfersReq,
+};
+
+/// Tag used in the order request digest
+const ORDER_DIGEST_TAG: &[u8] = b"rgb:node:order-digest";
+
+/// Tag used in the order commitment
+const ORDER_TAG: &[u8] = b"rgb:node:order";
+
+/// Maximal difference between the time of the order signature and the node
+/// clock, in seconds
+pub const ORDER_WINDOW: u64 = 600;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum OrderKind {
+    #[display("issue")]
+    Issue,
+
+    #[display("transfer")]
+    Transfer,
+
+    /// Transfers of multiple contracts committed into a single PSBT
+    #[display("batch-transfer")]
+    BatchTransfer,
+
+    #[display("consolidation")]
+    Consolidation,
+
+    /// Payment composed from the wallet UTXOs
+    #[display("payment")]
+    Payment,
+
+    /// Publication of the signed witness transaction
+    #[display("publication")]
+    Publication,
+
+    #[display("swap-proposal")]
+    SwapProposal,
+
+    #[display("swap-acceptance")]
+    SwapAcceptance,
+
+    #[display("swap-completion")]
+    SwapCompletion,
+
+    #[display("payjoin-proposal")]
+    PayjoinProposal,
+
+    #[display("payjoin-contribution")]
+    PayjoinContribution,
+
+    #[display("payjoin-completion")]
+    PayjoinCompletion,
+}
+
+/// Operation which was ordered by a signed request
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum OrderTarget {
+    #[display("contract:{0}")]
+    Contract(ContractId),
+
+    #[display("transfer:{0}")]
+    Transfer(ConsignmentId),
+}
+
+impl FromStr for OrderTarget {
+    type Err = ParseOrderTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, id) = s.split_once(':').ok_or(ParseOrderTargetError::Format)?;
+        match kind {
+            "contract" => parse_contract_id(id)
+                .map(OrderTarget::Contract)
+                .map_err(|_| ParseOrderTargetError::Id),
+            "transfer" => ConsignmentId::from_str(id)
+                .map(OrderTarget::Transfer)
+                .map_err(|_| ParseOrderTargetError::Id),
+            _ => Err(ParseOrderTargetError::Format),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseOrderTargetError {
+    /// order target must be in form of `contract:<id>` or `transfer:<id>`.
+    Format,
+
+    /// invalid contract or consignment id.
+    Id,
+}
+
+/// Order for a contract issue or a transfer, committing to the request data
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{kind} order {digest} at {timestamp}")]
+pub struct Order {
+    pub kind: OrderKind,
+    /// Digest of the request data, not including the order signature
+    pub digest: sha256::Hash,
+    /// UNIX timestamp of the order, which must be within [`ORDER_WINDOW`]
+    /// from the node clock
+    pub timestamp: u64,
+}
+
+impl Order {
+    /// Returns message which is signed by the client.
+    pub fn commitment(&self) -> Message {
+        let mut engine = tagged_engine(ORDER_TAG);
+        self.strict_encode(&mut engine).expect("hash engines do not error");
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    pub fn sign<C: Signing>(self, secp: &Secp256k1<C>, keypair: &KeyPair) -> SignedOrder {
+        let signature = secp.sign_schnorr_no_aux_rand(&self.commitment(), keypair);
+        SignedOrder {
+            order: self,
+            signer: keypair.x_only_public_key().0,
+            signature,
+        }
+    }
+}
+
+/// Order signed by the client
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{order} signed by {signer}")]
+pub struct SignedOrder {
+    pub order: Order,
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub signer: XOnlyPublicKey,
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub signature: schnorr::Signature,
+}
+
+impl SignedOrder {
+    /// Checks that the signature matches the order and the signer key.
+    /// Whether the order matches the request and whether the signer key is
+    /// registered on the node must be checked separately.
+    pub fn verify<C: Verification>(&self, secp: &Secp256k1<C>) -> bool {
+        secp.verify_schnorr(&self.signature, &self.order.commitment(), &self.signer).is_ok()
+    }
+}
+
+/// Signed order kept in the order journal of the node
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{target}: {order}")]
+pub struct OrderRecord {
+    pub target: OrderTarget,
+    pub order: SignedOrder,
+    /// UNIX timestamp of the moment the ordered operation was completed
+    pub completed: u64,
+}
+
+/// Request which may be ordered with a signed order committing to the request
+/// data.
+pub trait OrderDigest {
+    /// Kind of the orders for the request
+    const ORDER_KIND: OrderKind;
+
+    /// Writes request data committed to by the order, not including the order
+    /// signature, into the digest engine.
+    fn commit_order_data(&self, engine: &mut sha256::HashEngine);
+
+    /// Returns digest of the request data committed to by the order
+    fn order_digest(&self) -> sha256::Hash {
+        let mut engine = tagged_engine(ORDER_DIGEST_TAG);
+        Self::ORDER_KIND.strict_encode(&mut engine).expect("hash engines do not error");
+        self.commit_order_data(&mut engine);
+        sha256::Hash::from_engine(engine)
+    }
+}
+
+macro_rules! impl_order_digest {
+    ($req:ty, $kind:ident, $($field:ident),+) => {
+        impl OrderDigest for $req {
+            const ORDER_KIND: OrderKind = OrderKind::$kind;
+
+            fn commit_order_data(&self, engine: &mut sha256::HashEngine) {
+                $(
+                    self.$field.strict_encode(&mut *engine).expect("hash engines do not error");
+                )+
+            }
+        }
+    };
+}
+
+impl_order_digest!(IssueReq, Issue, spec, schema);
+impl_order_digest!(TransferReq, Transfer, consignment, endseals, psbt, beneficiary, recipient_key);
+impl_order_digest!(TransfersReq, BatchTransfer, transfers, psbt);
+impl_order_digest!(ConsolidateReq, Consolidation, contract_id, outpoints, seal);
+impl_order_digest!(PaymentReq, Payment, descriptor, contract_id, beneficiaries, fee);
+impl_order_digest!(PublishReq, Publication, psbt);
+impl_order_digest!(SwapProposeReq, SwapProposal, terms, timeout, psbt, consignment);
+impl_order_digest!(SwapAcceptReq, SwapAcceptance, offer, psbt, consignment);
+impl_order_digest!(SwapCompleteReq, SwapCompletion, acceptance);
+impl_order_digest!(PayjoinProposeReq, PayjoinProposal, psbt, consignment);
+impl_order_digest!(
+    PayjoinContributeReq,
+    PayjoinContribution,
+    proposal,
+    descriptor,
+    outpoints,
+    endseals,
+    fee
+);
+impl_order_digest!(PayjoinCompleteReq, PayjoinCompletion, contribution);
@@ -0,0 +1,62 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Payjoin-style transfers, where the beneficiary contributes its inputs to
+//! the witness transaction.
+//!
+//! Common-input-ownership heuristic does not hold for such transactions, and
+//! the beneficiary may consolidate its UTXOs together with receiving the
+//! payment:
+//! 1. Payer proposes the payjoin with the PSBT spending its inputs and containing its state
+//!    transition(s), together with the draft consignment for the paid asset.
+//! 2. Beneficiary node adds the contributed wallet UTXOs to the PSBT with a change output, moves
+//!    all RGB state assigned to them to the change output with blank state transitions, commits to
+//!    all bundles and finalizes the consignment.
+//! 3. Payer completes the payjoin, checking that the joint PSBT keeps its inputs and outputs, and
+//!    signs the transaction.
+//!
+//! Both nodes keep the disclosure of the blank state transitions, which is
+//! enclosed into their stashes once the witness transaction is mined.
+
+use bitcoin::hashes::sha256;
+use psbt::Psbt;
+use rgb::{Disclosure, StateTransfer};
+
+/// Unique payjoin identifier
+#[derive(Wrapper, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+#[derive(StrictEncode, StrictDecode)]
+#[wrapper(Debug, Display, BorrowSlice, FromStr)]
+pub struct PayjoinId(sha256::Hash);
+
+/// Payjoin proposal created by the payer and sent to the beneficiary
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("payjoin_proposal({payjoin_id}, ...)")]
+pub struct PayjoinProposal {
+    pub payjoin_id: PayjoinId,
+    /// PSBT spending payer inputs and containing the payer state transitions
+    pub psbt: Psbt,
+    /// Draft consignment for the paid asset
+    pub consignment: StateTransfer,
+}
+
+/// Data returned by the beneficiary to the payer after contributing its
+/// inputs
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("payjoin({payjoin_id}, ...)")]
+pub struct PayjoinContribution {
+    pub payjoin_id: PayjoinId,
+    /// Joint PSBT with all state transitions committed into it
+    pub psbt: Psbt,
+    /// Final consignment for the paid asset
+    pub consignment: StateTransfer,
+    /// Disclosure of the state transitions for all other contracts, including
+    /// the blank transitions of both parties
+    pub disclosure: Disclosure,
+}
@@ -0,0 +1,32 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use rgb::AtomicValue;
+
+/// Per-contract rules applied by the node to the transfers it finalizes
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("policy(min_allocation: {min_allocation})")]
+pub struct ContractPolicy {
+    /// Minimal amount of a revealed fungible allocation created by a
+    /// transfer; smaller (dust) allocations are refused. Zero disables the
+    /// check.
+    pub min_allocation: AtomicValue,
+}
+
+impl ContractPolicy {
+    /// Checks whether an allocation of the given amount satisfies the policy.
+    pub fn allows(&self, amount: AtomicValue) -> bool { amount >= self.min_allocation }
+}
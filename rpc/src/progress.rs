@@ -0,0 +1,34 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+/// Incremental progress of a long-running operation, streamed by the node to
+/// the clients which have negotiated [`crate::Capability::ProgressSteps`]
+/// before the final response to the request
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{stage}: {done} of {total}")]
+pub struct ProgressStep {
+    /// Human-readable name of the operation stage
+    pub stage: String,
+    /// Number of processed items (bundles, consignments)
+    pub done: u32,
+    /// Total number of items to be processed at this stage
+    pub total: u32,
+}
+
+impl ProgressStep {
+    /// Detects whether this is the last step of the stage
+    pub fn is_complete(&self) -> bool { self.done >= self.total }
+}
@@ -0,0 +1,211 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Proofs of the transaction inclusion into the blockchain.
+//!
+//! Proof bundle accompanies a consignment and allows validating it without
+//! access to the chain backend. The bundle contains witness transactions with
+//! merkle paths to the blocks mining them, and the chain of block headers
+//! starting right after a header checkpoint trusted by the validating node.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::str::FromStr;
+
+use bitcoin::consensus::{Decodable, Encodable};
+use bitcoin::hashes::{sha256d, Hash, HashEngine};
+use bitcoin::{BlockHash, BlockHeader, Transaction, Txid};
+use strict_encoding::{StrictDecode, StrictEncode};
+
+use crate::FailureCode;
+
+/// Block header trusted by the node, which is the starting point of the header
+/// chains in the proof bundles
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("{height}:{block_hash}")]
+pub struct HeaderCheckpoint {
+    pub height: u32,
+    pub block_hash: BlockHash,
+}
+
+impl FromStr for HeaderCheckpoint {
+    type Err = ParseHeaderCheckpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (height, block_hash) = s.split_once(':').ok_or(ParseHeaderCheckpointError::Format)?;
+        Ok(HeaderCheckpoint {
+            height: height.parse().map_err(|_| ParseHeaderCheckpointError::Height)?,
+            block_hash: block_hash.parse().map_err(|_| ParseHeaderCheckpointError::BlockHash)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseHeaderCheckpointError {
+    /// header checkpoint must be in form of `<height>:<block_hash>`.
+    Format,
+
+    /// invalid block height.
+    Height,
+
+    /// invalid block hash.
+    BlockHash,
+}
+
+/// Block header in the proof bundle, strict-encoded with the bitcoin consensus
+/// encoding
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ProofHeader(pub BlockHeader);
+
+impl StrictEncode for ProofHeader {
+    fn strict_encode<E: io::Write>(&self, mut e: E) -> Result<usize, strict_encoding::Error> {
+        Ok(self.0.consensus_encode(&mut e)?)
+    }
+}
+
+impl StrictDecode for ProofHeader {
+    fn strict_decode<D: io::Read>(mut d: D) -> Result<Self, strict_encoding::Error> {
+        BlockHeader::consensus_decode(&mut d)
+            .map(ProofHeader)
+            .map_err(|err| strict_encoding::Error::DataIntegrityError(err.to_string()))
+    }
+}
+
+/// Proof of the transaction inclusion into the block at some height
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("{}@{height}", self.tx.txid())]
+pub struct TxProof {
+    pub tx: Transaction,
+    pub height: u32,
+    /// Position of the transaction in the block
+    pub pos: u32,
+    /// Merkle path from the transaction to the merkle root of the block
+    pub merkle_branch: Vec<sha256d::Hash>,
+}
+
+impl TxProof {
+    /// Computes merkle root of the block from the transaction id and the
+    /// merkle path.
+    pub fn merkle_root(&self) -> sha256d::Hash {
+        merkle_root(self.tx.txid(), self.pos, &self.merkle_branch)
+    }
+}
+
+/// Computes merkle root of the block from the id of a transaction, its position
+/// in the block and the merkle path from it.
+pub fn merkle_root(txid: Txid, pos: u32, merkle_branch: &[sha256d::Hash]) -> sha256d::Hash {
+    let mut hash = sha256d::Hash::from_inner(txid.into_inner());
+    for (depth, node) in merkle_branch.iter().enumerate() {
+        let mut engine = sha256d::Hash::engine();
+        if (pos >> depth) & 1 == 0 {
+            engine.input(&hash[..]);
+            engine.input(&node[..]);
+        } else {
+            engine.input(&node[..]);
+            engine.input(&hash[..]);
+        }
+        hash = sha256d::Hash::from_engine(engine);
+    }
+    hash
+}
+
+/// Transaction proofs accompanying a consignment
+#[derive(Clone, PartialEq, Eq, Debug, Default, Display, StrictEncode, StrictDecode)]
+#[display("{} transaction(s), {} header(s)", self.txes.len(), self.headers.len())]
+pub struct ProofBundle {
+    /// Block headers, starting from the one following the header checkpoint
+    pub headers: Vec<ProofHeader>,
+    pub txes: Vec<TxProof>,
+}
+
+impl ProofBundle {
+    /// Verifies that the header chain extends the checkpoint and has a valid
+    /// proof of work, and that all transactions are included into its blocks.
+    /// Returns the proven transactions.
+    ///
+    /// Difficulty adjustments are not verified, so the header checkpoint must
+    /// be recent enough to make the forging of the header chain impractical.
+    pub fn verify(
+        &self,
+        checkpoint: HeaderCheckpoint,
+    ) -> Result<BTreeMap<Txid, Transaction>, ProofError> {
+        let mut prev_hash = checkpoint.block_hash;
+        for (height, ProofHeader(header)) in (checkpoint.height + 1..).zip(&self.headers) {
+            if header.prev_blockhash != prev_hash {
+                return Err(ProofError::Disconnected(height));
+            }
+            prev_hash =
+                header.validate_pow(&header.target()).map_err(|_| ProofError::Work(height))?;
+        }
+
+        let mut txes = bmap! {};
+        for proof in &self.txes {
+            let txid = proof.tx.txid();
+            let ProofHeader(header) = proof
+                .height
+                .checked_sub(checkpoint.height + 1)
+                .and_then(|index| self.headers.get(index as usize))
+                .ok_or(ProofError::NoHeader(txid, proof.height))?;
+            if proof.merkle_root()[..] != header.merkle_root[..] {
+                return Err(ProofError::NotIncluded(txid, proof.height));
+            }
+            txes.insert(txid, proof.tx.clone());
+        }
+        Ok(txes)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ProofError {
+    /// the node has no header checkpoint configured, so it can't verify
+    /// transaction proofs.
+    NoCheckpoint,
+
+    /// block header at height {0} is not connected to the previous header or
+    /// the header checkpoint.
+    Disconnected(u32),
+
+    /// block header at height {0} has insufficient proof of work.
+    Work(u32),
+
+    /// proof bundle has no block header at height {1} mining transaction {0}.
+    NoHeader(Txid, u32),
+
+    /// transaction {0} is not included into the block at height {1}.
+    NotIncluded(Txid, u32),
+
+    /// transaction {0} is mined at height {1}, which is not above the header
+    /// checkpoint.
+    BelowCheckpoint(Txid, u32),
+
+    /// transaction {0} is not mined.
+    NotMined(Txid),
+
+    /// unable to retrieve transaction proofs from the chain backend. Details:
+    /// {0}
+    Chain(String),
+}
+
+impl From<&ProofError> for FailureCode {
+    fn from(err: &ProofError) -> Self {
+        match err {
+            ProofError::NoCheckpoint => FailureCode::NoHeaderCheckpoint,
+            ProofError::Disconnected(_)
+            | ProofError::Work(_)
+            | ProofError::NoHeader(..)
+            | ProofError::NotIncluded(..)
+            | ProofError::BelowCheckpoint(..) => FailureCode::InvalidProof,
+            ProofError::NotMined(_) => FailureCode::TxUnmined,
+            ProofError::Chain(_) => FailureCode::ChainBackend,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use rgb::{validation, ConsignmentId, ContractId, SchemaId};
+
+/// Reason for keeping a received consignment in the quarantine
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum QuarantineReason {
+    /// Neither the contract nor its schema is whitelisted for the automatic
+    /// acceptance
+    NotWhitelisted,
+
+    /// Some of the consignment transactions are not known to the blockchain
+    /// (yet)
+    Unresolved,
+
+    /// Consignment has not passed validation
+    Invalid,
+}
+
+/// Consignment received by the node, which awaits manual approval or
+/// re-validation
+#[derive(Clone, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("consignment {id} of {contract_id} ({reason})")]
+pub struct QuarantineInfo {
+    pub id: ConsignmentId,
+    pub contract_id: ContractId,
+    pub schema_id: SchemaId,
+    pub reason: QuarantineReason,
+    /// UNIX timestamp of the moment the consignment was quarantined
+    pub received: u64,
+    /// Report of the last failed validation
+    pub report: Option<validation::Status>,
+}
@@ -0,0 +1,154 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Transfer receipts.
+//!
+//! Receipt is a statement of the node about the status of a transfer, signed
+//! with the node key (the key given in the invoices with `key=<pubkey>`
+//! parameter). Merchants hand receipts to the customers as a proof that the
+//! payment was accepted; receipts can be verified by anyone knowing the node
+//! key, without access to the node.
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::{schnorr, KeyPair, Message, Secp256k1, Signing, Verification};
+use bitcoin::{Txid, XOnlyPublicKey};
+use commit_verify::CommitConceal;
+use rgb::{AtomicValue, ConsignmentId, ContractId, SealEndpoint};
+#[cfg(feature = "serde")]
+use serde_with::{serde_as, DisplayFromStr};
+use strict_encoding::StrictEncode;
+
+use crate::{TransferRecord, TransferStatus};
+
+/// Tag used in the transfer id commitment
+const TRANSFER_ID_TAG: &[u8] = b"rgb:node:transfer-id";
+
+/// Tag used in the receipt commitment
+const RECEIPT_TAG: &[u8] = b"rgb:node:receipt";
+
+fn tagged_engine(tag: &[u8]) -> sha256::HashEngine {
+    let tag = sha256::Hash::hash(tag);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag[..]);
+    engine.input(&tag[..]);
+    engine
+}
+
+/// Stable identifier of a transfer, committing to the consignment id and the
+/// transfer endpoints. Endpoints are committed in their concealed form, so the
+/// id does not depend on whether the seals are revealed to the node.
+#[derive(Wrapper, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+#[derive(StrictEncode, StrictDecode)]
+#[wrapper(Debug, Display, BorrowSlice, FromStr)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", transparent)
+)]
+pub struct TransferId(sha256::Hash);
+
+impl TransferId {
+    pub fn with(consignment_id: ConsignmentId, endpoints: &[SealEndpoint]) -> TransferId {
+        let mut seals = endpoints.iter().map(SealEndpoint::commit_conceal).collect::<Vec<_>>();
+        seals.sort();
+        seals.dedup();
+        let mut engine = tagged_engine(TRANSFER_ID_TAG);
+        consignment_id.strict_encode(&mut engine).expect("hash engines do not error");
+        seals.strict_encode(&mut engine).expect("hash engines do not error");
+        TransferId(sha256::Hash::from_engine(engine))
+    }
+}
+
+impl TransferRecord {
+    /// Returns stable identifier of the transfer
+    pub fn transfer_id(&self) -> TransferId { TransferId::with(self.id, &self.beneficiaries) }
+}
+
+/// Statement of the node about the transfer status
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("receipt for transfer {transfer_id} of {contract_id} ({status})")]
+pub struct Receipt {
+    pub transfer_id: TransferId,
+    pub consignment_id: ConsignmentId,
+    pub contract_id: ContractId,
+    /// Sum of the revealed amounts assigned to the beneficiaries, if any
+    pub amount: Option<AtomicValue>,
+    /// Witness transaction of the transfer
+    pub txid: Option<Txid>,
+    pub status: TransferStatus,
+    /// UNIX timestamp of the moment the receipt was issued
+    pub timestamp: u64,
+}
+
+impl Receipt {
+    /// Creates receipt reporting the current status of the transfer
+    pub fn with(record: &TransferRecord, timestamp: u64) -> Receipt {
+        Receipt {
+            transfer_id: record.transfer_id(),
+            consignment_id: record.id,
+            contract_id: record.contract_id,
+            amount: record.amount,
+            txid: record.txid,
+            status: record.status,
+            timestamp,
+        }
+    }
+
+    /// Returns message which is signed by the node.
+    pub fn commitment(&self) -> Message {
+        let mut engine = tagged_engine(RECEIPT_TAG);
+        self.strict_encode(&mut engine).expect("hash engines do not error");
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    pub fn sign<C: Signing>(self, secp: &Secp256k1<C>, keypair: &KeyPair) -> SignedReceipt {
+        let signature = secp.sign_schnorr_no_aux_rand(&self.commitment(), keypair);
+        SignedReceipt {
+            receipt: self,
+            signer: keypair.x_only_public_key().0,
+            signature,
+        }
+    }
+}
+
+/// Transfer receipt signed by the node
+#[cfg_attr(feature = "serde", serde_as)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{receipt} signed by {signer}")]
+pub struct SignedReceipt {
+    pub receipt: Receipt,
+    /// Node key, which is given in the invoices of the node
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub signer: XOnlyPublicKey,
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    pub signature: schnorr::Signature,
+}
+
+impl SignedReceipt {
+    /// Checks that the signature matches the receipt and the signer key.
+    /// Whether the signer is the key of the merchant node must be checked
+    /// separately.
+    pub fn verify<C: Verification>(&self, secp: &Secp256k1<C>) -> bool {
+        secp.verify_schnorr(&self.signature, &self.receipt.commitment(), &self.signer).is_ok()
+    }
+}
@@ -0,0 +1,261 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Store-and-forward relay of consignments for the recipients which are
+//! offline, like mobile wallets.
+//!
+//! Relay node holds envelopes with consignments encrypted to the recipient
+//! key until the recipient picks them up or the envelope expires, and may
+//! forward deposited envelopes to its peer relays, so the recipient can pick
+//! them up from any relay of the federation:
+//! 1. Sender seals the consignment to the recipient x-only public key with [`seal_payload`],
+//!    uploads the sealed data in [`UploadChunk`]s and deposits it with [`RelayDepositReq`].
+//! 2. Recipient signs the current time and the request with its key ([`RelayAuth`]), lists its
+//!    envelopes with [`RelayPickupReq`] and downloads each of them with [`RelayFetchReq`], opening
+//!    the data with [`open_payload`].
+//! 3. Recipient acknowledges the received envelopes with the next pickup request, which removes
+//!    them from the relay.
+//!
+//! Relay never sees the consignment data: the payload is encrypted with
+//! ChaCha20-Poly1305 under a key derived from ECDH between a one-time sender
+//! key and the recipient key.
+//!
+//! [`UploadChunk`]: crate::UploadChunk
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::ecdh::SharedSecret;
+use bitcoin::secp256k1::{
+    schnorr, KeyPair, Message, Parity, PublicKey, Secp256k1, SecretKey, Signing, Verification,
+};
+use bitcoin::XOnlyPublicKey;
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use strict_encoding::StrictEncode;
+
+/// Tag used in the envelope id commitment
+const RELAY_ENVELOPE_TAG: &[u8] = b"rgb:node:relay-envelope";
+
+/// Tag used in the pickup authentication message
+const RELAY_AUTH_TAG: &[u8] = b"rgb:node:relay-auth";
+
+/// Tag used in the digest of the pickup request
+const RELAY_PICKUP_TAG: &[u8] = b"rgb:node:relay-pickup";
+
+/// Tag used in the digest of the fetch request
+const RELAY_FETCH_TAG: &[u8] = b"rgb:node:relay-fetch";
+
+/// Tag used in the derivation of the payload encryption key
+const RELAY_SEAL_TAG: &[u8] = b"rgb:node:relay-seal";
+
+/// Maximal difference between the time signed by the recipient and the relay
+/// clock, in seconds
+pub const RELAY_AUTH_WINDOW: u64 = 600;
+
+pub(crate) fn tagged_engine(tag: &[u8]) -> sha256::HashEngine {
+    let tag = sha256::Hash::hash(tag);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag[..]);
+    engine.input(&tag[..]);
+    engine
+}
+
+/// Unique identifier of the relayed envelope, committing to the recipient and
+/// the sealed payload
+#[derive(Wrapper, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+#[derive(StrictEncode, StrictDecode)]
+#[wrapper(Debug, Display, BorrowSlice, FromStr)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(crate = "serde_crate", transparent))]
+pub struct EnvelopeId(sha256::Hash);
+
+impl EnvelopeId {
+    pub fn with(recipient: XOnlyPublicKey, payload: &[u8]) -> EnvelopeId {
+        let mut engine = tagged_engine(RELAY_ENVELOPE_TAG);
+        engine.input(&recipient.serialize());
+        engine.input(payload);
+        EnvelopeId(sha256::Hash::from_engine(engine))
+    }
+}
+
+/// Envelope held by the relay for its recipient
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{id} ({size} bytes, expires at {expires})")]
+pub struct RelayEnvelope {
+    pub id: EnvelopeId,
+    /// Size of the sealed payload
+    pub size: u32,
+    /// UNIX timestamp after which the envelope is removed by the relay
+    pub expires: u64,
+}
+
+/// Request depositing sealed payload uploaded by the client before the request
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("relay_deposit({recipient}, size: {size}, ...)")]
+pub struct RelayDepositReq {
+    pub recipient: XOnlyPublicKey,
+    /// Total size of the uploaded data, protecting from incomplete uploads
+    pub size: u32,
+    /// Number of seconds the envelope is held by the relay. The relay default
+    /// is used if not given; values above the relay maximum are reduced to
+    /// the maximum.
+    pub ttl: Option<u32>,
+    /// Forward the envelope to the peer relays. Relays do not forward the
+    /// envelopes received from their peers any further.
+    pub forward: bool,
+}
+
+/// Proof that the client controls the recipient key. Authentication commits
+/// to the digest of the request, so it can't be replayed with other request
+/// data.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display("{recipient} at {timestamp}")]
+pub struct RelayAuth {
+    pub recipient: XOnlyPublicKey,
+    /// UNIX timestamp of the authentication, which must be within
+    /// [`RELAY_AUTH_WINDOW`] from the relay clock
+    pub timestamp: u64,
+    pub signature: schnorr::Signature,
+}
+
+impl RelayAuth {
+    /// Returns message which is signed by the recipient.
+    pub fn commitment(recipient: XOnlyPublicKey, timestamp: u64, request: sha256::Hash) -> Message {
+        let mut engine = tagged_engine(RELAY_AUTH_TAG);
+        engine.input(&recipient.serialize());
+        timestamp.strict_encode(&mut engine).expect("hash engines do not error");
+        engine.input(&request[..]);
+        let hash = sha256::Hash::from_engine(engine);
+        Message::from_slice(&hash[..]).expect("hash has the size of the message")
+    }
+
+    /// Signs the request with the given digest, which is produced by
+    /// [`RelayPickupReq::digest`] or [`RelayFetchReq::digest`].
+    pub fn sign<C: Signing>(
+        secp: &Secp256k1<C>,
+        keypair: &KeyPair,
+        timestamp: u64,
+        request: sha256::Hash,
+    ) -> RelayAuth {
+        let recipient = keypair.x_only_public_key().0;
+        let message = RelayAuth::commitment(recipient, timestamp, request);
+        let signature = secp.sign_schnorr_no_aux_rand(&message, keypair);
+        RelayAuth {
+            recipient,
+            timestamp,
+            signature,
+        }
+    }
+
+    /// Checks the signature of the request with the given digest and that
+    /// the authentication is fresh relative to the given current time.
+    pub fn verify<C: Verification>(
+        &self,
+        secp: &Secp256k1<C>,
+        now: u64,
+        request: sha256::Hash,
+    ) -> bool {
+        let message = RelayAuth::commitment(self.recipient, self.timestamp, request);
+        let skew = self.timestamp.max(now) - self.timestamp.min(now);
+        skew <= RELAY_AUTH_WINDOW
+            && secp.verify_schnorr(&self.signature, &message, &self.recipient).is_ok()
+    }
+}
+
+/// Request listing envelopes held for the recipient
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("relay_pickup({auth}, ...)")]
+pub struct RelayPickupReq {
+    pub auth: RelayAuth,
+    /// Envelopes received by the recipient, which are removed by the relay
+    /// and not listed anymore
+    pub ack: BTreeSet<EnvelopeId>,
+}
+
+impl RelayPickupReq {
+    /// Digest of the pickup request acknowledging the given envelopes
+    pub fn digest(ack: &BTreeSet<EnvelopeId>) -> sha256::Hash {
+        let mut engine = tagged_engine(RELAY_PICKUP_TAG);
+        ack.strict_encode(&mut engine).expect("hash engines do not error");
+        sha256::Hash::from_engine(engine)
+    }
+}
+
+/// Request downloading part of the sealed payload
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("relay_fetch({envelope_id}, offset: {offset})")]
+pub struct RelayFetchReq {
+    pub auth: RelayAuth,
+    pub envelope_id: EnvelopeId,
+    /// Position of the requested chunk within the payload
+    pub offset: u32,
+}
+
+impl RelayFetchReq {
+    /// Digest of the fetch request for the given envelope, which is the same
+    /// for all chunks of its payload
+    pub fn digest(envelope_id: EnvelopeId) -> sha256::Hash {
+        let mut engine = tagged_engine(RELAY_FETCH_TAG);
+        engine.input(&envelope_id[..]);
+        sha256::Hash::from_engine(engine)
+    }
+}
+
+fn seal_key(shared: SharedSecret) -> ChaCha20Poly1305 {
+    let mut engine = tagged_engine(RELAY_SEAL_TAG);
+    engine.input(&shared.secret_bytes());
+    let key = sha256::Hash::from_engine(engine);
+    ChaCha20Poly1305::new(Key::from_slice(&key[..]))
+}
+
+/// Encrypts data to the recipient key. The one-time key must be random and
+/// must not be reused; the sealed payload starts with its public key.
+pub fn seal_payload<C: Signing>(
+    secp: &Secp256k1<C>,
+    recipient: XOnlyPublicKey,
+    one_time_key: SecretKey,
+    data: &[u8],
+) -> Vec<u8> {
+    let shared = SharedSecret::new(&recipient.public_key(Parity::Even), &one_time_key);
+    // The key is never reused, so the nonce may be constant
+    let ciphertext = seal_key(shared)
+        .encrypt(Nonce::from_slice(&[0u8; 12]), data)
+        .expect("in-memory encryption does not fail");
+    let mut payload = PublicKey::from_secret_key(secp, &one_time_key).serialize().to_vec();
+    payload.extend(ciphertext);
+    payload
+}
+
+/// Decrypts data sealed to the key of the recipient, returning `None` if the
+/// payload is not sealed to the key or is corrupted.
+pub fn open_payload(keypair: &KeyPair, payload: &[u8]) -> Option<Vec<u8>> {
+    if payload.len() < 33 {
+        return None;
+    }
+    let (one_time_key, ciphertext) = payload.split_at(33);
+    let one_time_key = PublicKey::from_slice(one_time_key).ok()?;
+    // Sender uses the recipient key with even y coordinate
+    let secret_key = match keypair.x_only_public_key().1 {
+        Parity::Even => keypair.secret_key(),
+        Parity::Odd => keypair.secret_key().negate(),
+    };
+    let shared = SharedSecret::new(&one_time_key, &secret_key);
+    seal_key(shared).decrypt(Nonce::from_slice(&[0u8; 12]), ciphertext).ok()
+}
@@ -0,0 +1,212 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Data retention: periods after which the auxiliary data, which are not part
+//! of the contract state, are pruned from the node store.
+
+use std::str::FromStr;
+
+use bitcoin::Txid;
+use rgb::{ConsignmentId, ContractId};
+
+/// Average number of blocks mined in a day, used to convert between retention
+/// periods given in days and blocks
+pub const BLOCKS_PER_DAY: u32 = 144;
+
+const SECONDS_IN_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseRetentionError {
+    /// unknown retention category `{0}`; it must be one of `quarantine`, `transfers`, `journal`
+    /// or `disclosures`.
+    Category(String),
+
+    /// invalid retention period `{0}`; it must be a number of days (like `30d`), a number of
+    /// blocks (like `4320b`) or `never`.
+    Period(String),
+
+    /// retention override `{0}` must have `<category>=<period>` form.
+    Override(String),
+}
+
+/// Category of the data subject to the retention policy
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum RetentionCategory {
+    /// Consignments kept in the quarantine, counting from the moment they
+    /// were received
+    Quarantine,
+
+    /// Records of the completed transfers and their CPFP packages, counting
+    /// from the last transfer status update
+    Transfers,
+
+    /// Journals of the contracts, counting from the last recorded consignment
+    Journal,
+
+    /// Disclosures which are already enclosed into the stash, counting from
+    /// the moment their witness transaction was mined
+    Disclosures,
+}
+
+impl RetentionCategory {
+    pub const ALL: [RetentionCategory; 4] = [
+        RetentionCategory::Quarantine,
+        RetentionCategory::Transfers,
+        RetentionCategory::Journal,
+        RetentionCategory::Disclosures,
+    ];
+}
+
+impl FromStr for RetentionCategory {
+    type Err = ParseRetentionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RetentionCategory::ALL
+            .into_iter()
+            .find(|category| category.to_string() == s)
+            .ok_or_else(|| ParseRetentionError::Category(s.to_owned()))
+    }
+}
+
+/// Period after which the data are pruned
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+pub enum RetentionPeriod {
+    #[display("{0}d")]
+    Days(u16),
+
+    #[display("{0}b")]
+    Blocks(u32),
+
+    /// Data are kept forever
+    #[display("never")]
+    Never,
+}
+
+impl FromStr for RetentionPeriod {
+    type Err = ParseRetentionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseRetentionError::Period(s.to_owned());
+        if s == "never" {
+            return Ok(RetentionPeriod::Never);
+        }
+        if let Some(days) = s.strip_suffix('d') {
+            return days.parse().map(RetentionPeriod::Days).map_err(|_| err());
+        }
+        if let Some(blocks) = s.strip_suffix('b') {
+            return blocks.parse().map(RetentionPeriod::Blocks).map_err(|_| err());
+        }
+        Err(err())
+    }
+}
+
+impl RetentionPeriod {
+    /// Parses retention period override given in form of
+    /// `<category>=<period>`.
+    pub fn parse_override(s: &str) -> Result<(RetentionCategory, Self), ParseRetentionError> {
+        let (category, period) =
+            s.split_once('=').ok_or_else(|| ParseRetentionError::Override(s.to_owned()))?;
+        Ok((category.parse()?, period.parse()?))
+    }
+
+    /// Detects whether the data created at the given UNIX timestamp have
+    /// expired. Periods given in blocks are converted into time assuming the
+    /// average block interval.
+    pub fn expired_since(self, timestamp: u64, now: u64) -> bool {
+        let seconds = match self {
+            RetentionPeriod::Days(days) => days as u64 * SECONDS_IN_DAY,
+            RetentionPeriod::Blocks(blocks) => {
+                blocks as u64 * SECONDS_IN_DAY / BLOCKS_PER_DAY as u64
+            }
+            RetentionPeriod::Never => return false,
+        };
+        now.saturating_sub(timestamp) >= seconds
+    }
+
+    /// Detects whether the data related to the transaction mined at the given
+    /// height have expired. Periods given in days are converted into blocks
+    /// assuming the average block interval.
+    pub fn expired_at(self, height: u32, tip: u32) -> bool {
+        let blocks = match self {
+            RetentionPeriod::Days(days) => days as u32 * BLOCKS_PER_DAY,
+            RetentionPeriod::Blocks(blocks) => blocks,
+            RetentionPeriod::Never => return false,
+        };
+        tip.saturating_sub(height) >= blocks
+    }
+}
+
+/// Request pruning the data which retention period has passed
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("prune(dry_run: {dry_run})")]
+pub struct PruneReq {
+    /// Report the data which would be pruned without removing them
+    pub dry_run: bool,
+}
+
+/// Data item pruned according to the retention policy
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum PrunedItem {
+    #[display("quarantined consignment {0}")]
+    Quarantined(ConsignmentId),
+
+    #[display("transfer {0}")]
+    Transfer(ConsignmentId),
+
+    #[display("journal of {0}")]
+    Journal(ContractId),
+
+    #[display("disclosure {0}")]
+    Disclosure(Txid),
+}
+
+impl PrunedItem {
+    pub fn category(self) -> RetentionCategory {
+        match self {
+            PrunedItem::Quarantined(_) => RetentionCategory::Quarantine,
+            PrunedItem::Transfer(_) => RetentionCategory::Transfers,
+            PrunedItem::Journal(_) => RetentionCategory::Journal,
+            PrunedItem::Disclosure(_) => RetentionCategory::Disclosures,
+        }
+    }
+}
+
+/// Data pruned (or, in dry-run mode, which would be pruned) according to the
+/// retention policy
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{} item(s), {bytes} bytes", self.items.len())]
+pub struct PruneReport {
+    pub dry_run: bool,
+    pub items: Vec<PrunedItem>,
+    /// Total size of the pruned data
+    pub bytes: u64,
+}
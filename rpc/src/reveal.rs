@@ -8,8 +8,9 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use bitcoin::OutPoint;
+use bitcoin::{OutPoint, Txid};
 use bp::seals::txout::CloseMethod;
+use rgb::{seal, ContractId};
 
 #[derive(From, PartialEq, Eq, Debug, Clone, StrictEncode, StrictDecode)]
 pub struct Reveal {
@@ -23,12 +24,46 @@ pub struct Reveal {
     pub close_method: CloseMethod,
 }
 
+impl Reveal {
+    /// Constructs reveal data for the seal. Seals defined relative to the
+    /// witness transaction get the witness transaction id.
+    pub fn with(seal: seal::Revealed, witness_txid: Txid) -> Reveal {
+        Reveal {
+            blinding_factor: seal.blinding,
+            outpoint: OutPoint::new(seal.txid.unwrap_or(witness_txid), seal.vout),
+            close_method: seal.method,
+        }
+    }
+
+    /// Returns the revealed seal, which is blinded with the reveal data
+    pub fn to_seal(&self) -> seal::Revealed {
+        seal::Revealed {
+            method: self.close_method,
+            blinding: self.blinding_factor,
+            txid: Some(self.outpoint.txid),
+            vout: self.outpoint.vout,
+        }
+    }
+}
+
 impl std::fmt::Display for Reveal {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}@{}#{}", self.close_method, self.outpoint, self.blinding_factor)
     }
 }
 
+/// Reveal data for the contract seals, which the node exports for the
+/// counterparty holding the state assigned to the seals in concealed form.
+///
+/// Reveal data contain blinding secrets and must be passed only to the
+/// parties which are allowed to learn the seal outpoints.
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("{} seal(s) of {contract_id}", self.reveals.len())]
+pub struct RevealData {
+    pub contract_id: ContractId,
+    pub reveals: Vec<Reveal>,
+}
+
 /// Parses a blinding factor.
 fn parse_blind(s: &str) -> Result<u64, ParseRevealError> {
     s.parse().map_err(ParseRevealError::BlindingFactor)
@@ -0,0 +1,55 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+/// Configuration layer providing value of a node setting. Layers are listed
+/// in the order of increasing precedence.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+pub enum SettingSource {
+    /// Default value defined by the node
+    #[display("default")]
+    Default,
+
+    /// Node configuration file
+    #[display("file")]
+    File,
+
+    /// Environment variable
+    #[display("env")]
+    Env,
+
+    /// Command-line option
+    #[display("cli")]
+    CommandLine,
+}
+
+/// Node setting resolved from the configuration layers
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{key} = {value} ({source})")]
+pub struct Setting {
+    /// Name of the setting, matching both the long command-line option and
+    /// the configuration file key
+    pub key: String,
+    /// Setting value. Multiple values are separated with commas; secrets are
+    /// masked.
+    pub value: String,
+    pub source: SettingSource,
+}
@@ -0,0 +1,61 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Protocol used by RGB Node to delegate PSBT signing to an external signer
+//! daemon, which may run on a separate hardened host.
+//!
+//! The node connects to the signer with ZMQ REQ socket (which can be either an
+//! IPC file or a TCP address) and sends a single strict-encoded
+//! [`SignerMsg::SignPsbt`] request, awaiting for [`SignerMsg::SignedPsbt`] or
+//! [`SignerMsg::Failure`] reply.
+//!
+//! The same protocol is used by the issuer signer, which keeps contract issuer
+//! keys (for instance, in a hardware security module accessed via PKCS#11) and
+//! replies to [`SignerMsg::SignCheckpoint`] requests with
+//! [`SignerMsg::SignedCheckpoint`].
+
+use microservices::rpc;
+use psbt::Psbt;
+
+use crate::{Checkpoint, FailureCode, SignedCheckpoint};
+
+/// Messages of the external signer protocol
+#[derive(Clone, Debug, Display, From, Api)]
+#[api(encoding = "strict")]
+#[non_exhaustive]
+pub enum SignerMsg {
+    /// Request to sign all inputs of the PSBT which are controlled by the signer
+    #[api(type = 0x0101)]
+    #[display("sign_psbt(...)")]
+    SignPsbt(Psbt),
+
+    /// PSBT with signatures added by the signer
+    #[api(type = 0x0102)]
+    #[display("signed_psbt(...)")]
+    SignedPsbt(Psbt),
+
+    /// Request to sign contract checkpoint with the issuer key
+    #[api(type = 0x0201)]
+    #[display("sign_checkpoint({0})")]
+    SignCheckpoint(Checkpoint),
+
+    /// Checkpoint signed with the issuer key
+    #[api(type = 0x0202)]
+    #[display("signed_checkpoint({0})")]
+    SignedCheckpoint(SignedCheckpoint),
+
+    /// Signer was unable or refused to sign the PSBT
+    #[api(type = 0x0000)]
+    #[display("failure({0:#})")]
+    #[from]
+    Failure(rpc::Failure<FailureCode>),
+}
+
+impl rpc::Request for SignerMsg {}
@@ -0,0 +1,88 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Contract time travel for regtest deployments. Nodes working with regtest
+//! keep journal of the consignments accepted for each contract, so the
+//! contract state can be rewound to the moment before some consignment and
+//! the recorded consignments can be re-applied, reproducing schema logic and
+//! node bugs.
+
+use std::collections::BTreeSet;
+
+use bitcoin::OutPoint;
+use rgb::{ConsignmentId, ContractId};
+
+use crate::StateDigest;
+
+/// Consignment recorded into the contract journal
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{consignment_id} at {timestamp}")]
+pub struct JournalEntry {
+    pub consignment_id: ConsignmentId,
+    /// Indicates contract consignment, as opposed to a state transfer
+    pub contract: bool,
+    /// UNIX timestamp of the moment the consignment was accepted
+    pub timestamp: u64,
+}
+
+/// Request rewinding contract state to the moment before the consignment was
+/// accepted
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("rewind_contract({contract_id}, {consignment_id})")]
+pub struct RewindReq {
+    pub contract_id: ContractId,
+    pub consignment_id: ConsignmentId,
+}
+
+/// Request re-applying consignments recorded into the contract journal
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("replay_consignments({contract_id}, ...)")]
+pub struct ReplayReq {
+    pub contract_id: ContractId,
+    /// Consignments to apply, in the order of application
+    pub consignments: Vec<ConsignmentId>,
+}
+
+/// Change of the contract state made by rewinding or replaying the contract
+/// history
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{} outpoint(s) assigned, {} unassigned", self.assigned.len(), self.unassigned.len())]
+pub struct StateChange {
+    pub contract_id: ContractId,
+    /// Digest of the contract before the change, if the contract was known
+    pub before: Option<StateDigest>,
+    /// Digest of the contract after the change, if the contract remains known
+    pub after: Option<StateDigest>,
+    /// Outpoints which got contract state assigned
+    pub assigned: BTreeSet<OutPoint>,
+    /// Outpoints which lost contract state assigned to them
+    pub unassigned: BTreeSet<OutPoint>,
+}
+
+impl StateChange {
+    /// Detects whether the contract state has remained the same
+    pub fn is_empty(&self) -> bool {
+        self.before.map(|digest| digest.digest) == self.after.map(|digest| digest.digest)
+    }
+}
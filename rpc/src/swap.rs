@@ -0,0 +1,151 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Atomic two-party asset swaps.
+//!
+//! Both parties of a swap move their assets within a single witness
+//! transaction, so either both transfers happen or none of them:
+//! 1. Maker proposes the swap terms together with the PSBT spending its inputs and the draft
+//!    consignment for the asset it gives.
+//! 2. Taker adds its inputs and state transition to the PSBT and accepts the offer; the node checks
+//!    the maker side, commits to both bundles and returns the consignment for the maker.
+//! 3. Both parties complete the swap, checking that the received consignment and the final PSBT
+//!    match the terms, and sign the transaction.
+//!
+//! Until the transaction is signed by both parties any of them may abort
+//! the swap; swaps not completed before their expiry are aborted
+//! automatically.
+
+use std::str::FromStr;
+
+use bitcoin::hashes::sha256;
+use bitcoin::{Address, Script};
+use psbt::Psbt;
+use rgb::{AtomicValue, ContractId, SealEndpoint, StateTransfer};
+
+use crate::parse_contract_id;
+
+/// Unique swap identifier
+#[derive(Wrapper, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+#[derive(StrictEncode, StrictDecode)]
+#[wrapper(Debug, Display, BorrowSlice, FromStr)]
+pub struct SwapId(sha256::Hash);
+
+/// One side of the swap: asset moved by a party and its beneficiary
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+pub enum SwapLeg {
+    /// RGB asset allocated to the seals of the counterparty
+    #[display("{amount} of {contract_id}")]
+    Rgb {
+        contract_id: ContractId,
+        amount: AtomicValue,
+        beneficiaries: Vec<SealEndpoint>,
+    },
+
+    /// Bitcoins paid to the script of the counterparty
+    #[display("{amount} sats")]
+    Bitcoin { amount: u64, script: Script },
+}
+
+impl SwapLeg {
+    pub fn contract_id(&self) -> Option<ContractId> {
+        match self {
+            SwapLeg::Rgb { contract_id, .. } => Some(*contract_id),
+            SwapLeg::Bitcoin { .. } => None,
+        }
+    }
+}
+
+impl FromStr for SwapLeg {
+    type Err = ParseSwapLegError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, beneficiary) = s.split_once('@').ok_or(ParseSwapLegError::Format)?;
+        if let Some(amount) = amount.strip_suffix("sat") {
+            let amount = amount.parse().map_err(|_| ParseSwapLegError::Amount)?;
+            let address = Address::from_str(beneficiary).map_err(|_| ParseSwapLegError::Address)?;
+            return Ok(SwapLeg::Bitcoin {
+                amount,
+                script: address.script_pubkey(),
+            });
+        }
+        let amount = amount.parse().map_err(|_| ParseSwapLegError::Amount)?;
+        let (contract_id, seals) = beneficiary.split_once(':').ok_or(ParseSwapLegError::Format)?;
+        let contract_id =
+            parse_contract_id(contract_id).map_err(|_| ParseSwapLegError::ContractId)?;
+        let beneficiaries = seals
+            .split(',')
+            .map(SealEndpoint::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|_| ParseSwapLegError::Seal)?;
+        Ok(SwapLeg::Rgb {
+            contract_id,
+            amount,
+            beneficiaries,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseSwapLegError {
+    /// swap leg must be in form of `<amount>@<contract_id>:<seal>[,<seal>...]` or
+    /// `<amount>sat@<address>`.
+    Format,
+
+    /// invalid swap amount.
+    Amount,
+
+    /// invalid bitcoin address.
+    Address,
+
+    /// invalid contract id.
+    ContractId,
+
+    /// invalid beneficiary seal.
+    Seal,
+}
+
+/// Swap terms from the point of view of the maker
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("give {give} for {take}")]
+pub struct SwapTerms {
+    /// Asset given by the maker to the taker
+    pub give: SwapLeg,
+    /// Asset given by the taker to the maker
+    pub take: SwapLeg,
+}
+
+/// Swap offer created by the maker and sent to the taker
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("swap_offer({swap_id}, {terms})")]
+pub struct SwapOffer {
+    pub swap_id: SwapId,
+    pub terms: SwapTerms,
+    /// UNIX timestamp after which the swap is aborted
+    pub expiry: u64,
+    /// PSBT spending maker inputs and containing the maker state transition
+    pub psbt: Psbt,
+    /// Draft consignment for the asset given by the maker, if it is an RGB
+    /// asset
+    pub consignment: Option<StateTransfer>,
+}
+
+/// Data exchanged by the parties after the swap is accepted
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("swap({swap_id}, ...)")]
+pub struct SwapAcceptance {
+    pub swap_id: SwapId,
+    /// Joint PSBT with both state transitions committed into it
+    pub psbt: Psbt,
+    /// Consignment for the asset received by the party, if it is an RGB
+    /// asset
+    pub consignment: Option<StateTransfer>,
+}
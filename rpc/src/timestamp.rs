@@ -0,0 +1,105 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Commitments to the stash contents, which may be anchored into Bitcoin
+//! with an OpenTimestamps calendar, proving to auditors what the node knew at
+//! a point in time.
+
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use strict_encoding::StrictEncode;
+
+use crate::StateDigest;
+
+/// Tag used in the stash commitment
+const STASH_COMMITMENT_TAG: &[u8] = b"rgb:node:stash-commitment";
+
+/// Magic bytes of the OpenTimestamps detached timestamp file
+const OTS_HEADER_MAGIC: &[u8] =
+    b"\x00OpenTimestamps\x00\x00Proof\x00\xbf\x89\xe2\xe8\x84\xe8\x92\x94";
+
+/// Major version of the OpenTimestamps file format
+const OTS_MAJOR_VERSION: u8 = 1;
+
+/// Tag of the SHA256 operation in the OpenTimestamps proofs
+const OTS_OP_SHA256: u8 = 0x08;
+
+/// Commitment to the contracts known to the node, made of the digests of
+/// their history and state
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{root} ({} contract(s) at {timestamp})", self.contracts.len())]
+pub struct StashCommitment {
+    /// UNIX timestamp of the moment the commitment was made by the node
+    pub timestamp: u64,
+    /// Tagged SHA256 hash of the contract digests
+    pub root: sha256::Hash,
+    /// Digests of all contracts known to the node, ordered by contract id
+    pub contracts: Vec<StateDigest>,
+}
+
+impl StashCommitment {
+    /// Commits to the contract digests, sorting them by contract id
+    pub fn compute(timestamp: u64, mut contracts: Vec<StateDigest>) -> StashCommitment {
+        contracts.sort_by_key(|digest| digest.contract_id);
+        StashCommitment {
+            timestamp,
+            root: Self::commit(&contracts),
+            contracts,
+        }
+    }
+
+    /// Checks that the root commits to the listed contract digests
+    pub fn verify(&self) -> bool { self.root == Self::commit(&self.contracts) }
+
+    fn commit(contracts: &[StateDigest]) -> sha256::Hash {
+        let tag = sha256::Hash::hash(STASH_COMMITMENT_TAG);
+        let mut engine = sha256::Hash::engine();
+        engine.input(&tag[..]);
+        engine.input(&tag[..]);
+        contracts.strict_encode(&mut engine).expect("hash engines do not error");
+        sha256::Hash::from_engine(engine)
+    }
+}
+
+/// Pending OpenTimestamps attestation of a digest, returned by a calendar
+/// server. The attestation gets anchored into Bitcoin once the calendar
+/// publishes its next transaction; it can be upgraded into a complete proof
+/// with the standard OpenTimestamps tools.
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display("{digest} @ {calendar}")]
+pub struct TimestampProof {
+    /// URL of the calendar server
+    pub calendar: String,
+    /// Timestamped digest
+    pub digest: sha256::Hash,
+    /// Serialized timestamp operations returned by the calendar, committing
+    /// to the digest
+    pub timestamp: Vec<u8>,
+}
+
+impl TimestampProof {
+    /// Serializes the proof as OpenTimestamps detached timestamp file for the
+    /// data with the SHA256 hash equal to the timestamped digest
+    pub fn ots_serialize(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(OTS_HEADER_MAGIC.len() + 34 + self.timestamp.len());
+        data.extend(OTS_HEADER_MAGIC);
+        data.push(OTS_MAJOR_VERSION);
+        data.push(OTS_OP_SHA256);
+        data.extend(&self.digest[..]);
+        data.extend(&self.timestamp);
+        data
+    }
+}
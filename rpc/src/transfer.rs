@@ -0,0 +1,150 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::str::FromStr;
+
+use bitcoin::Txid;
+use internet2::addr::NodeAddr;
+use rgb::{AtomicValue, ConsignmentId, ContractId, SealEndpoint};
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum TransferDirection {
+    /// Transfer received by the node
+    Incoming,
+
+    /// Transfer finalized by the node
+    Outgoing,
+}
+
+impl FromStr for TransferDirection {
+    type Err = ParseTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "incoming" => Ok(TransferDirection::Incoming),
+            "outgoing" => Ok(TransferDirection::Outgoing),
+            _ => Err(ParseTransferError::Direction(s.to_owned())),
+        }
+    }
+}
+
+/// Lifecycle state of a transfer
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display(lowercase)]
+pub enum TransferStatus {
+    /// Outgoing transfer is finalized and committed into the witness PSBT
+    Finalized,
+
+    /// Outgoing transfer consignment is sent to the beneficiary node
+    Sent,
+
+    /// Witness transaction of the outgoing transfer is published
+    Published,
+
+    /// Incoming transfer has witness transactions which are not yet mined
+    Pending,
+
+    /// Incoming transfer is valid and its state is consumed
+    Accepted,
+
+    /// Incoming transfer is invalid
+    Rejected,
+
+    /// Witness transaction of the pending incoming transfer is accelerated
+    /// with a published CPFP child transaction
+    Accelerated,
+}
+
+impl FromStr for TransferStatus {
+    type Err = ParseTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "finalized" => Ok(TransferStatus::Finalized),
+            "sent" => Ok(TransferStatus::Sent),
+            "published" => Ok(TransferStatus::Published),
+            "pending" => Ok(TransferStatus::Pending),
+            "accepted" => Ok(TransferStatus::Accepted),
+            "rejected" => Ok(TransferStatus::Rejected),
+            "accelerated" => Ok(TransferStatus::Accelerated),
+            _ => Err(ParseTransferError::Status(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ParseTransferError {
+    /// unknown transfer direction `{0}`; it must be either `incoming` or `outgoing`.
+    Direction(String),
+
+    /// unknown transfer status `{0}`; it must be one of `finalized`, `sent`, `published`,
+    /// `pending`, `accepted`, `rejected` or `accelerated`.
+    Status(String),
+}
+
+/// Transfer known to the node, which is kept in the node history
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate", rename_all = "camelCase")
+)]
+#[display("{direction} transfer {id} of {contract_id} ({status})")]
+pub struct TransferRecord {
+    pub id: ConsignmentId,
+    pub direction: TransferDirection,
+    pub status: TransferStatus,
+    pub contract_id: ContractId,
+    /// Witness transaction of the transfer
+    pub txid: Option<Txid>,
+    /// Sum of the revealed amounts assigned to the beneficiaries, if any
+    pub amount: Option<AtomicValue>,
+    pub beneficiaries: Vec<SealEndpoint>,
+    /// Remote node the consignment was sent to
+    pub peer: Option<NodeAddr>,
+    /// UNIX timestamp of the moment the transfer became known to the node
+    pub created: u64,
+    /// UNIX timestamp of the last status change
+    pub updated: u64,
+}
+
+/// Filter for the transfer history request; fields which are not set match
+/// all transfers
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display("transfer_filter(...)")]
+pub struct TransferFilter {
+    pub contract_id: Option<ContractId>,
+    pub direction: Option<TransferDirection>,
+    pub status: Option<TransferStatus>,
+}
+
+impl TransferFilter {
+    pub fn matches(&self, record: &TransferRecord) -> bool {
+        self.contract_id.map(|id| id == record.contract_id).unwrap_or(true)
+            && self.direction.map(|d| d == record.direction).unwrap_or(true)
+            && self.status.map(|s| s == record.status).unwrap_or(true)
+    }
+}
@@ -0,0 +1,65 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Upload of raw consignments received by the client out-of-band (as a file,
+//! email attachment etc), which are not present in the Storm container store.
+//!
+//! Strict-encoded consignment is sent as a sequence of [`UploadChunk`]s, each
+//! of which fits into the strict encoding limit for byte vectors, followed by
+//! [`UploadReq`] requesting the node to decode and consume the uploaded data.
+
+use crate::{ProofBundle, Reveal};
+
+/// Maximal size of the data carried by a single upload chunk
+pub const UPLOAD_CHUNK_SIZE: usize = u16::MAX as usize;
+
+/// Maximal size of the consignment which can be uploaded to the node
+pub const MAX_UPLOAD_SIZE: usize = 0x1000_0000;
+
+/// Type of the uploaded consignment
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[derive(StrictEncode, StrictDecode)]
+#[display(lowercase)]
+pub enum UploadKind {
+    /// Contract consignment
+    Contract,
+
+    /// State transfer consignment
+    Transfer,
+}
+
+/// Part of the uploaded consignment data
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("upload_chunk({offset}, ...)")]
+pub struct UploadChunk {
+    /// Position of the chunk data within the consignment. Chunk with zero
+    /// offset starts a new upload, discarding previously uploaded data.
+    pub offset: u32,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("consume_upload({kind}, size: {size}, force: {force}, full: {full}, ...)")]
+pub struct UploadReq {
+    pub kind: UploadKind,
+    /// Total size of the uploaded data, protecting from incomplete uploads
+    pub size: u32,
+    pub force: bool,
+    pub full: bool,
+    pub reveal: Option<Reveal>,
+    /// Number of seconds after which the node abandons the request if it is
+    /// not completed
+    pub timeout: Option<u32>,
+    /// Proofs of the consignment witness transactions, used instead of the
+    /// chain backend during validation
+    pub proofs: Option<ProofBundle>,
+}
@@ -0,0 +1,303 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! RPC protocol versioning.
+//!
+//! RPC messages are sent inside [`RpcEnvelope`], which carries protocol
+//! version and the strict-encoded message as an opaque payload. This allows
+//! both sides to check the version before decoding the message itself and to
+//! report unsupported versions without failing on the message decoding.
+//! Optional node features are negotiated with [`Capability`] set exchanged
+//! during the `hello` request.
+//!
+//! Protocol version 2 adds payload compression. Version 3 pins the tags of
+//! the RPC messages, changing the encoding of most of them, so the envelopes
+//! of the older versions are refused with [`VersionError::Unsupported`].
+//! Envelopes of version 1 have no compression field; their framing is still
+//! decoded, so the peer gets the version error and not a decoding failure.
+
+use std::io::{self, Read};
+
+use strict_encoding::{StrictDecode, StrictEncode};
+
+use crate::compression::{Compression, COMPRESSION_THRESHOLD};
+use crate::RpcMsg;
+
+/// RPC protocol version used by this library. Must be incremented on any
+/// change of the RPC message encoding.
+pub const RPC_VERSION: u16 = 3;
+/// Minimal RPC protocol version which is still supported by this library
+pub const RPC_MIN_VERSION: u16 = 3;
+
+/// First protocol version supporting compressed payloads
+const COMPRESSED_VERSION: u16 = 2;
+
+/// Maximal size of the envelope payload, checked before reading it
+pub const MAX_PAYLOAD_SIZE: usize = 0x1000_0000;
+
+/// Error opening RPC envelope
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum VersionError {
+    /// remote peer uses unsupported RPC protocol version {0}.
+    Unsupported(u16),
+
+    /// unable to decode RPC message of protocol version {0}. Details: {1}
+    Payload(u16, String),
+}
+
+/// Versioned container for RPC messages
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[display("envelope(v{version}, {compression}, ...)")]
+pub struct RpcEnvelope {
+    pub version: u16,
+    pub compression: Compression,
+    pub payload: Vec<u8>,
+}
+
+impl RpcEnvelope {
+    /// Wraps message into uncompressed envelope.
+    pub fn with(msg: &RpcMsg) -> RpcEnvelope {
+        RpcEnvelope::with_compression(msg, Compression::None)
+    }
+
+    /// Wraps message into envelope, compressing payloads larger than
+    /// [`COMPRESSION_THRESHOLD`]. Must be used only with peers which have
+    /// negotiated [`Capability::Compression`].
+    pub fn with_compression(msg: &RpcMsg, compression: Compression) -> RpcEnvelope {
+        let payload = msg.strict_serialize().expect("in-memory encoding failure");
+        if compression == Compression::None || payload.len() < COMPRESSION_THRESHOLD {
+            return RpcEnvelope {
+                version: RPC_VERSION,
+                compression: Compression::None,
+                payload,
+            };
+        }
+        RpcEnvelope {
+            version: RPC_VERSION,
+            compression,
+            payload: compression.compress(&payload),
+        }
+    }
+
+    /// Checks envelope version and decodes the message, decompressing it if
+    /// required.
+    pub fn open(&self) -> Result<RpcMsg, VersionError> {
+        if !self.is_supported() {
+            return Err(VersionError::Unsupported(self.version));
+        }
+        match self.compression {
+            Compression::None => RpcMsg::strict_deserialize(&self.payload),
+            compression => RpcMsg::strict_decode(compression.decoder(self.payload.as_slice())),
+        }
+        .map_err(|err| VersionError::Payload(self.version, err.to_string()))
+    }
+
+    pub fn is_supported(&self) -> bool { (RPC_MIN_VERSION..=RPC_VERSION).contains(&self.version) }
+}
+
+impl StrictEncode for RpcEnvelope {
+    fn strict_encode<E: io::Write>(&self, mut e: E) -> Result<usize, strict_encoding::Error> {
+        let len = self.payload.len();
+        if len > MAX_PAYLOAD_SIZE {
+            return Err(strict_encoding::Error::ExceedMaxItems(len));
+        }
+        let mut count = self.version.strict_encode(&mut e)?;
+        if self.version >= COMPRESSED_VERSION {
+            count += self.compression.strict_encode(&mut e)?;
+        }
+        count += (len as u32).strict_encode(&mut e)?;
+        e.write_all(&self.payload)?;
+        Ok(count + len)
+    }
+}
+
+impl StrictDecode for RpcEnvelope {
+    fn strict_decode<D: io::Read>(mut d: D) -> Result<Self, strict_encoding::Error> {
+        let version = u16::strict_decode(&mut d)?;
+        let compression = if version >= COMPRESSED_VERSION {
+            Compression::strict_decode(&mut d)?
+        } else {
+            Compression::None
+        };
+        let len = u32::strict_decode(&mut d)? as usize;
+        if len > MAX_PAYLOAD_SIZE {
+            return Err(strict_encoding::Error::ExceedMaxItems(len));
+        }
+        // The length is not trusted, so the payload buffer grows with the data actually read
+        let mut payload = Vec::new();
+        d.take(len as u64).read_to_end(&mut payload)?;
+        if payload.len() != len {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(RpcEnvelope {
+            version,
+            compression,
+            payload,
+        })
+    }
+}
+
+/// Optional node features, negotiated during `hello` request
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+pub enum Capability {
+    /// Bitcoin wallet synchronization and balance reporting
+    #[display("wallet")]
+    Wallet,
+
+    /// User-defined labels and tags
+    #[display("annotations")]
+    Annotations,
+
+    /// PSBT signing with an external signer
+    #[display("signer")]
+    Signer,
+
+    /// Compression of large message payloads
+    #[display("compression")]
+    Compression,
+
+    /// Signing of contract checkpoints with an external issuer signer
+    #[display("issuer-signer")]
+    IssuerSigner,
+
+    /// Anchoring of the stash commitments with an OpenTimestamps calendar
+    #[display("timestamping")]
+    Timestamping,
+
+    /// Store-and-forward relay of sealed consignments for offline recipients
+    #[display("relay")]
+    Relay,
+
+    /// Incremental progress reporting of long-running operations
+    #[display("progress-steps")]
+    ProgressSteps,
+
+    /// Reporting of the node identity key in the `hello` response
+    #[display("node-identity")]
+    NodeIdentity,
+
+    /// Reporting of the chain spent status of the outpoints in the state and
+    /// allocation responses
+    #[display("spent-status")]
+    SpentStatus,
+
+    /// Reporting of the fungible amounts formatted with the contract
+    /// precision in the contract and outpoint state responses
+    #[display("decimal-amounts")]
+    DecimalAmounts,
+
+    /// Capability introduced by a newer protocol version
+    #[display("unknown#{0}")]
+    Unknown(u8),
+}
+
+impl Capability {
+    /// All capabilities known to this library
+    pub const ALL: [Capability; 11] = [
+        Capability::Wallet,
+        Capability::Annotations,
+        Capability::Signer,
+        Capability::Compression,
+        Capability::IssuerSigner,
+        Capability::Timestamping,
+        Capability::Relay,
+        Capability::ProgressSteps,
+        Capability::NodeIdentity,
+        Capability::SpentStatus,
+        Capability::DecimalAmounts,
+    ];
+}
+
+impl From<Capability> for u8 {
+    fn from(capability: Capability) -> Self {
+        match capability {
+            Capability::Wallet => 1,
+            Capability::Annotations => 2,
+            Capability::Signer => 3,
+            Capability::Compression => 4,
+            Capability::IssuerSigner => 5,
+            Capability::Timestamping => 6,
+            Capability::Relay => 7,
+            Capability::ProgressSteps => 8,
+            Capability::NodeIdentity => 9,
+            Capability::SpentStatus => 10,
+            Capability::DecimalAmounts => 11,
+            Capability::Unknown(value) => value,
+        }
+    }
+}
+
+impl From<u8> for Capability {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Capability::Wallet,
+            2 => Capability::Annotations,
+            3 => Capability::Signer,
+            4 => Capability::Compression,
+            5 => Capability::IssuerSigner,
+            6 => Capability::Timestamping,
+            7 => Capability::Relay,
+            8 => Capability::ProgressSteps,
+            9 => Capability::NodeIdentity,
+            10 => Capability::SpentStatus,
+            11 => Capability::DecimalAmounts,
+            _ => Capability::Unknown(value),
+        }
+    }
+}
+
+// Capabilities are encoded as plain bytes, such that peers are able to decode
+// capabilities they do not know about
+impl StrictEncode for Capability {
+    fn strict_encode<E: io::Write>(&self, e: E) -> Result<usize, strict_encoding::Error> {
+        u8::from(*self).strict_encode(e)
+    }
+}
+
+impl StrictDecode for Capability {
+    fn strict_decode<D: io::Read>(d: D) -> Result<Self, strict_encoding::Error> {
+        u8::strict_decode(d).map(Capability::from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame(len: u32, payload: &[u8]) -> Vec<u8> {
+        let mut frame = RPC_VERSION.strict_serialize().unwrap();
+        frame.extend(Compression::None.strict_serialize().unwrap());
+        frame.extend(len.strict_serialize().unwrap());
+        frame.extend(payload);
+        frame
+    }
+
+    #[test]
+    fn envelope_roundtrip() {
+        let envelope = RpcEnvelope::with(&RpcMsg::ListContracts);
+        let data = envelope.strict_serialize().unwrap();
+        assert_eq!(RpcEnvelope::strict_deserialize(data).unwrap(), envelope);
+        assert!(matches!(envelope.open(), Ok(RpcMsg::ListContracts)));
+    }
+
+    #[test]
+    fn oversized_payload() {
+        assert!(matches!(
+            RpcEnvelope::strict_deserialize(frame(u32::MAX, &[])),
+            Err(strict_encoding::Error::ExceedMaxItems(_))
+        ));
+    }
+
+    #[test]
+    fn truncated_payload() {
+        assert!(RpcEnvelope::strict_deserialize(frame(16, &[0u8; 4])).is_err());
+    }
+}
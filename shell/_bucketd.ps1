@@ -23,14 +23,38 @@ Register-ArgumentCompleter -Native -CommandName 'bucketd' -ScriptBlock {
         'bucketd' {
             [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Data directory path')
             [CompletionResult]::new('--data-dir', 'data-dir', [CompletionResultType]::ParameterName, 'Data directory path')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Path to the node configuration file')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Path to the node configuration file')
             [CompletionResult]::new('-S', 'S', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting storage daemon')
             [CompletionResult]::new('--store', 'store', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting storage daemon')
             [CompletionResult]::new('-X', 'X', [CompletionResultType]::ParameterName, 'ZMQ socket for internal service bus')
             [CompletionResult]::new('--ctl', 'ctl', [CompletionResultType]::ParameterName, 'ZMQ socket for internal service bus')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
-            [CompletionResult]::new('--electrum-server', 'electrum-server', [CompletionResultType]::ParameterName, 'Electrum server to use')
-            [CompletionResult]::new('--electrum-port', 'electrum-port', [CompletionResultType]::ParameterName, 'Customize Electrum server port number. By default the wallet will use port matching the selected network')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex. Requires `--network signet`; clients and invoices must then refer to the same signet')
+            [CompletionResult]::new('--electrum-server', 'electrum-server', [CompletionResultType]::ParameterName, 'Electrum server to use, optionally followed by `:<port>`. Can be used multiple times; requests are then balanced between the servers, and a failing server is excluded from the use until it recovers')
+            [CompletionResult]::new('--electrum-port', 'electrum-port', [CompletionResultType]::ParameterName, 'Customize Electrum server port number for the servers given without port. By default the wallet will use port matching the selected network')
+            [CompletionResult]::new('--header-checkpoint', 'header-checkpoint', [CompletionResultType]::ParameterName, 'Trusted block header in form of `<height>:<block_hash>`. Header chains of the transaction proofs provided with consignments must start right after this header')
+            [CompletionResult]::new('--chain-cache-size', 'chain-cache-size', [CompletionResultType]::ParameterName, 'Maximum number of transactions kept in memory by the chain data cache used during validation. Setting to zero disables in-memory caching')
+            [CompletionResult]::new('--state-cache-size', 'state-cache-size', [CompletionResultType]::ParameterName, 'Maximum number of contract states kept in memory by each bucket daemon. Setting to zero disables the cache')
+            [CompletionResult]::new('--store-concurrency', 'store-concurrency', [CompletionResultType]::ParameterName, 'Number of parallel store connections used by each bucket daemon to retrieve contract history when composing consignments')
+            [CompletionResult]::new('--accept-contract', 'accept-contract', [CompletionResultType]::ParameterName, 'Contract which consignments received from the remote nodes are accepted automatically')
+            [CompletionResult]::new('--accept-schema', 'accept-schema', [CompletionResultType]::ParameterName, 'Schema which contracts are accepted automatically, in addition to the contracts whitelisted with `--accept-contract`. Can be used multiple times')
+            [CompletionResult]::new('--reject-contract', 'reject-contract', [CompletionResultType]::ParameterName, 'Contract which consignments received from the remote nodes are always rejected. Can be used multiple times')
+            [CompletionResult]::new('--hook', 'hook', [CompletionResultType]::ParameterName, 'Hook notified about node events: incoming allocations, confirmed transfers, validation failures and changes of the asset supply')
+            [CompletionResult]::new('--hook-secret', 'hook-secret', [CompletionResultType]::ParameterName, 'Secret key for HMAC-SHA256 signing of the hook payloads')
+            [CompletionResult]::new('--hook-retries', 'hook-retries', [CompletionResultType]::ParameterName, 'Number of repeated attempts to deliver event to a failing hook, with the delay doubling after each attempt')
+            [CompletionResult]::new('--container-retention', 'container-retention', [CompletionResultType]::ParameterName, 'Number of days consignment containers are kept in the store, unless the consignment is accepted, in which case they are removed immediately. Expired containers are removed with `gc` command')
+            [CompletionResult]::new('--retention', 'retention', [CompletionResultType]::ParameterName, 'Period after which quarantined consignments, records of completed transfers, contract journals and enclosed disclosures are pruned from the store, given as a number of days (`30d`), a number of blocks (`4320b`) or `never`. Pruning is performed automatically once an hour and with `prune` command')
+            [CompletionResult]::new('--retention-override', 'retention-override', [CompletionResultType]::ParameterName, 'Retention period for a specific category of data, overriding the one given with `--retention`, in form of `<category>=<period>`. Category is one of `quarantine`, `transfers`, `journal` or `disclosures`')
+            [CompletionResult]::new('--sql-export', 'sql-export', [CompletionResultType]::ParameterName, 'PostgreSQL connection string of a database receiving a mirror of the accepted contract data: contracts, state transitions and allocations. Requires node compiled with `sql` feature')
+            [CompletionResult]::new('--validation-plugin', 'validation-plugin', [CompletionResultType]::ParameterName, 'WebAssembly module validating consignments of the contracts with a given schema after the core validation, in form of `<schema_id>:<path>`')
+            [CompletionResult]::new('--plugin-fuel', 'plugin-fuel', [CompletionResultType]::ParameterName, 'Maximum amount of fuel, roughly corresponding to the number of executed instructions, which a validation plugin may consume per consignment')
+            [CompletionResult]::new('--max-script-runs', 'max-script-runs', [CompletionResultType]::ParameterName, 'Maximum number of contract nodes which scripts are executed during a consignment validation')
+            [CompletionResult]::new('--max-script-size', 'max-script-size', [CompletionResultType]::ParameterName, 'Maximum size of the contract schema validation script, in bytes')
+            [CompletionResult]::new('--decoy-change', 'decoy-change', [CompletionResultType]::ParameterName, 'Number of decoy change allocations added to the payments composed by the node')
+            [CompletionResult]::new('--entropy-source', 'entropy-source', [CompletionResultType]::ParameterName, 'Device, named pipe or file descriptor (as `/dev/fd/<no>`) providing the entropy for the seal and amount blinding factors, like a hardware RNG or a pipe fed by an HSM')
+            [CompletionResult]::new('--bdk-wallet', 'bdk-wallet', [CompletionResultType]::ParameterName, 'Output descriptor of a wallet delegated to an embedded BDK wallet')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'R')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'rpc')
             [CompletionResult]::new('-E', 'E', [CompletionResultType]::ParameterName, 'E')
@@ -41,6 +65,9 @@ Register-ArgumentCompleter -Native -CommandName 'bucketd' -ScriptBlock {
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version information')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--offline', 'offline', [CompletionResultType]::ParameterName, 'Do not connect to electrum servers. Consignments are then validated only with the transaction proofs provided together with them')
+            [CompletionResult]::new('--compress-relay', 'compress-relay', [CompletionResultType]::ParameterName, 'Compress consignments relayed to the beneficiaries with zstd')
+            [CompletionResult]::new('--consignment-key', 'consignment-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key opening consignments encrypted to the node')
             break
         }
     })
@@ -25,32 +25,111 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('-V', 'V', [CompletionResultType]::ParameterName, 'Print version information')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('init', 'init', [CompletionResultType]::ParameterValue, 'Write configuration file template with all the settings commented out')
             [CompletionResult]::new('contract', 'contract', [CompletionResultType]::ParameterValue, 'Contract management')
+            [CompletionResult]::new('schema', 'schema', [CompletionResultType]::ParameterValue, 'Schemata known to the node')
+            [CompletionResult]::new('issue', 'issue', [CompletionResultType]::ParameterValue, 'Issue new contract from the declarative specification of its genesis')
             [CompletionResult]::new('outpoint', 'outpoint', [CompletionResultType]::ParameterValue, 'Information on outpoints')
             [CompletionResult]::new('transfer', 'transfer', [CompletionResultType]::ParameterValue, 'Work with state transfers')
+            [CompletionResult]::new('pay', 'pay', [CompletionResultType]::ParameterValue, 'Pay RGB invoice from the wallet tracked by the node')
+            [CompletionResult]::new('swap', 'swap', [CompletionResultType]::ParameterValue, 'Atomic two-party swaps of RGB assets and bitcoins')
+            [CompletionResult]::new('payjoin', 'payjoin', [CompletionResultType]::ParameterValue, 'Payjoin transfers, where the beneficiary contributes its inputs to the witness transaction')
+            [CompletionResult]::new('wallet', 'wallet', [CompletionResultType]::ParameterValue, 'Bitcoin wallets tracked by the node')
+            [CompletionResult]::new('label', 'label', [CompletionResultType]::ParameterValue, 'User-defined labels and tags for contracts, outpoints and transfers')
+            [CompletionResult]::new('relay', 'relay', [CompletionResultType]::ParameterValue, 'Store-and-forward relay of consignments for offline recipients')
+            [CompletionResult]::new('consignment', 'consignment', [CompletionResultType]::ParameterValue, 'Offline inspection of consignment files')
+            [CompletionResult]::new('quarantine', 'quarantine', [CompletionResultType]::ParameterValue, 'Consignments received from the remote nodes which await approval')
+            [CompletionResult]::new('cache', 'cache', [CompletionResultType]::ParameterValue, 'Contract state kept by the node')
+            [CompletionResult]::new('gc', 'gc', [CompletionResultType]::ParameterValue, 'Remove consignment containers which are no longer needed from the store: containers of accepted consignments and containers kept longer than the retention period of the node')
+            [CompletionResult]::new('prune', 'prune', [CompletionResultType]::ParameterValue, 'Prune data which retention period configured for the node has passed: quarantined consignments, records of completed transfers, contract journals and enclosed disclosures')
+            [CompletionResult]::new('health', 'health', [CompletionResultType]::ParameterValue, 'Report status of the node worker daemons, including the number of their restarts and the reason of the last failure')
+            [CompletionResult]::new('ping', 'ping', [CompletionResultType]::ParameterValue, 'Check that the node controls its identity key by asking it to sign a random nonce')
+            [CompletionResult]::new('config', 'config', [CompletionResultType]::ParameterValue, 'Node configuration')
+            [CompletionResult]::new('timestamp', 'timestamp', [CompletionResultType]::ParameterValue, 'Commit to the digests of all contracts known to the node, proving to auditors what the node knew at a point in time. The commitment may be anchored into Bitcoin with the OpenTimestamps calendar configured for the node, saving the pending timestamp next to the output file with `.ots` extension; it can be upgraded and verified with the standard OpenTimestamps tools')
+            [CompletionResult]::new('cancel', 'cancel', [CompletionResultType]::ParameterValue, 'Cancel request of another client, which id is reported by the `health` command. Queued request is dropped, and request processed by a worker is interrupted at the next safe point')
+            [CompletionResult]::new('promote', 'promote', [CompletionResultType]::ParameterValue, 'Promote read replica to primary. The node stops following its primary and starts accepting requests modifying the stash. Promotion lasts until the node restart, so `--replica-of` option must be removed from the node configuration to keep it primary')
+            [CompletionResult]::new('access', 'access', [CompletionResultType]::ParameterValue, 'Access of the client identities to the contracts and wallets on a node shared by multiple users')
+            [CompletionResult]::new('order', 'order', [CompletionResultType]::ParameterValue, 'Show order of a contract issue or a transfer signed by the client, which is kept in the node order journal')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
+        'rgb-cli;init' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Overwrite the existing configuration file')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
         'rgb-cli;contract' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List all known contract ids')
             [CompletionResult]::new('register', 'register', [CompletionResultType]::ParameterValue, 'Add new contract to the node')
+            [CompletionResult]::new('import', 'import', [CompletionResultType]::ParameterValue, 'Import contract from its genesis, as shared by the wallets which exchange the contract genesis instead of the contract source')
             [CompletionResult]::new('state', 'state', [CompletionResultType]::ParameterValue, 'Query contract state')
+            [CompletionResult]::new('balance', 'balance', [CompletionResultType]::ParameterValue, 'Report contract amounts allocated to the wallet and/or outpoints at the given block height')
+            [CompletionResult]::new('stats', 'stats', [CompletionResultType]::ParameterValue, 'Report aggregate statistics of the contract: number of allocations and outpoints holding them, history size and depth, and the amount of data kept in the stash')
+            [CompletionResult]::new('deps', 'deps', [CompletionResultType]::ParameterValue, 'Show dependency graph of the contract: contracts sharing its root schema and contracts referenced from its metadata or referencing it, like the contracts which assets were burned to issue it. If no contract is given, the graph of all known contracts is shown')
+            [CompletionResult]::new('allocations', 'allocations', [CompletionResultType]::ParameterValue, 'List allocations of the contract state known to the node. Allocations on the unspent outputs of the wallets registered with the node are marked as owned; the rest belong to third parties. Outpoints spent since the last wallet synchronization are reported with the spending transaction')
+            [CompletionResult]::new('usage', 'usage', [CompletionResultType]::ParameterValue, 'List contracts taking the most of the store space, with the size of their history, anchors and consignment containers kept by the node')
+            [CompletionResult]::new('digest', 'digest', [CompletionResultType]::ParameterValue, 'Report canonical digest of the contract history and state known to the node, which can be compared with the digest reported by another node')
+            [CompletionResult]::new('compare', 'compare', [CompletionResultType]::ParameterValue, 'Compare contract history and state known to the node with another node, listing transition bundles known to one of the nodes only')
             [CompletionResult]::new('consignment', 'consignment', [CompletionResultType]::ParameterValue, 'Request contract consignment')
             [CompletionResult]::new('embed', 'embed', [CompletionResultType]::ParameterValue, 'Embed contract information into PSBT file')
+            [CompletionResult]::new('checkpoint', 'checkpoint', [CompletionResultType]::ParameterValue, 'Create checkpoint of the contract state signed by the contract issuer')
+            [CompletionResult]::new('policy', 'policy', [CompletionResultType]::ParameterValue, 'Show or change the policy applied by the node to the contract transfers')
+            [CompletionResult]::new('import-checkpoint', 'import-checkpoint', [CompletionResultType]::ParameterValue, 'Import contract state checkpoint signed by a trusted issuer')
+            [CompletionResult]::new('reveal', 'reveal', [CompletionResultType]::ParameterValue, 'Reveal seal of the contract assignments accepted with the seal being concealed')
+            [CompletionResult]::new('invoice', 'invoice', [CompletionResultType]::ParameterValue, 'Create invoice for receiving the contract asset on a wallet outpoint')
+            [CompletionResult]::new('invoices', 'invoices', [CompletionResultType]::ParameterValue, 'List invoices of the contract created by the node, with the amounts they have received and their payment status')
+            [CompletionResult]::new('export-reveals', 'export-reveals', [CompletionResultType]::ParameterValue, 'Export reveal data for the contract seals defined on the given outpoints, allowing the counterparty to reveal them')
+            [CompletionResult]::new('import-reveals', 'import-reveals', [CompletionResultType]::ParameterValue, 'Import reveal data exported by the counterparty, revealing the contract seals which were accepted in concealed form')
+            [CompletionResult]::new('journal', 'journal', [CompletionResultType]::ParameterValue, 'List consignments accepted for the contract, which are recorded into the contract journal by nodes working with regtest')
+            [CompletionResult]::new('rewind', 'rewind', [CompletionResultType]::ParameterValue, 'Rewind contract state to the moment before the consignment was accepted, reporting the state change (regtest only)')
+            [CompletionResult]::new('replay', 'replay', [CompletionResultType]::ParameterValue, 'Apply consignments from the contract journal again in the given order, reporting the state change (regtest only)')
+            [CompletionResult]::new('forget', 'forget', [CompletionResultType]::ParameterValue, 'Remove contract from the node together with its history, state, transfer records and other derived data')
+            [CompletionResult]::new('prove', 'prove', [CompletionResultType]::ParameterValue, 'Prepare proofs of the contract witness transactions inclusion into the blockchain, allowing its registration by a node running offline')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
@@ -59,20 +138,57 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
         'rgb-cli;contract;register' {
+            [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds after which the node abandons the request')
+            [CompletionResult]::new('--proofs', 'proofs', [CompletionResultType]::ParameterName, 'File with proofs of the witness transactions inclusion into the blockchain, prepared with `contract prove` command. Required when the node runs in offline mode')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Force importing of valid contract containing non-mined transactions or forgotten with a tombstone')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;import' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
-            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Force importing of valid contract containing non-mined transactions')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
@@ -82,8 +198,155 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;balance' {
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Output descriptor of a wallet previously synchronized with `wallet sync`')
+            [CompletionResult]::new('--descriptor', 'descriptor', [CompletionResultType]::ParameterName, 'Output descriptor of a wallet previously synchronized with `wallet sync`')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Outpoints to account allocations for')
+            [CompletionResult]::new('--outpoint', 'outpoint', [CompletionResultType]::ParameterName, 'Outpoints to account allocations for')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;stats' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;deps' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;allocations' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;usage' {
+            [CompletionResult]::new('-l', 'l', [CompletionResultType]::ParameterName, 'Maximal number of contracts to list; zero lists all contracts')
+            [CompletionResult]::new('--limit', 'limit', [CompletionResultType]::ParameterName, 'Maximal number of contracts to list; zero lists all contracts')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;digest' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;compare' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
@@ -91,12 +354,22 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
         'rgb-cli;contract;consignment' {
             [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Transition types to be always included into the consignment')
             [CompletionResult]::new('--node-type', 'node-type', [CompletionResultType]::ParameterName, 'Transition types to be always included into the consignment')
+            [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds after which the node abandons the request')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--armor', 'armor', [CompletionResultType]::ParameterName, 'Print the consignment in ASCII-armored form instead of Bech32')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
@@ -110,135 +383,1966 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;contract;help' {
+        'rgb-cli;contract;checkpoint' {
+            [CompletionResult]::new('--issuer-key', 'issuer-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded issuer secret key used to sign the checkpoint. If not given, the checkpoint is signed by the issuer signer configured for the node, keeping the key off the host')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;outpoint' {
+        'rgb-cli;contract;policy' {
+            [CompletionResult]::new('--min-allocation', 'min-allocation', [CompletionResultType]::ParameterName, 'Refuse to finalize transfers creating fungible allocations smaller than the given amount (in atomic units). Zero disables the check')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
-            [CompletionResult]::new('state', 'state', [CompletionResultType]::ParameterValue, 'Return known outpoint state')
-            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
-        'rgb-cli;outpoint;state' {
-            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Outpoints to return the state for')
-            [CompletionResult]::new('--outpoint', 'outpoint', [CompletionResultType]::ParameterName, 'Outpoints to return the state for')
+        'rgb-cli;contract;import-checkpoint' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;outpoint;help' {
+        'rgb-cli;contract;reveal' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;transfer' {
+        'rgb-cli;contract;invoice' {
+            [CompletionResult]::new('--method', 'method', [CompletionResultType]::ParameterName, 'Method for closing the single-use-seal of the outpoint')
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Bifrost node which the payer sends the transfer consignment to')
+            [CompletionResult]::new('--transport', 'transport', [CompletionResultType]::ParameterName, 'Bifrost node which the payer sends the transfer consignment to')
+            [CompletionResult]::new('--underpayment', 'underpayment', [CompletionResultType]::ParameterName, 'Decimal amount which the payment may fall short of the invoice amount by and still pay the invoice')
+            [CompletionResult]::new('--overpayment', 'overpayment', [CompletionResultType]::ParameterName, 'Decimal amount which the payment may exceed the invoice amount by and still pay the invoice')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
-            [CompletionResult]::new('compose', 'compose', [CompletionResultType]::ParameterValue, 'Build state transfer consignment draft')
-            [CompletionResult]::new('combine', 'combine', [CompletionResultType]::ParameterValue, 'Update PSBT with the information from the state transition')
-            [CompletionResult]::new('finalize', 'finalize', [CompletionResultType]::ParameterValue, 'Finalize and (optionally) send state transfer consignment to beneficiary')
-            [CompletionResult]::new('consume', 'consume', [CompletionResultType]::ParameterValue, 'Validate incoming transfer consignment and consume it into the stash')
+            break
+        }
+        'rgb-cli;contract;invoices' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;export-reveals' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Outpoints which seals must be revealed')
+            [CompletionResult]::new('--outpoint', 'outpoint', [CompletionResultType]::ParameterName, 'Outpoints which seals must be revealed')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;import-reveals' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;journal' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;rewind' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;replay' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;forget' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--tombstone', 'tombstone', [CompletionResultType]::ParameterName, 'Keep a tombstone making the node refuse re-import of the contract unless it is forced')
+            [CompletionResult]::new('-y', 'y', [CompletionResultType]::ParameterName, 'Do not ask for confirmation')
+            [CompletionResult]::new('--yes', 'yes', [CompletionResultType]::ParameterName, 'Do not ask for confirmation')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;prove' {
+            [CompletionResult]::new('--from', 'from', [CompletionResultType]::ParameterName, 'Height of the header checkpoint of the node which will register the contract. Proofs include all block headers following it')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;contract;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;issue' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'YAML or JSON file with the issuance specification')
+            [CompletionResult]::new('--spec', 'spec', [CompletionResultType]::ParameterName, 'YAML or JSON file with the issuance specification')
+            [CompletionResult]::new('--schema', 'schema', [CompletionResultType]::ParameterName, 'File with the strict-encoded contract schema. Required if the schema is not yet known to the node')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Validate the specification and report the resulting genesis allocations and the contract size without registering the contract')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;outpoint' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('state', 'state', [CompletionResultType]::ParameterValue, 'Return known outpoint state together with the spent status of the wallet outpoints')
+            [CompletionResult]::new('forget', 'forget', [CompletionResultType]::ParameterValue, 'Remove contract state assigned to the wallet outpoints which were spent without transferring it')
             [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
-        'rgb-cli;transfer;compose' {
-            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Transition types to be always included into the state transfer')
-            [CompletionResult]::new('--node-type', 'node-type', [CompletionResultType]::ParameterName, 'Transition types to be always included into the state transfer')
+        'rgb-cli;outpoint;state' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Outpoints to return the state for')
+            [CompletionResult]::new('--outpoint', 'outpoint', [CompletionResultType]::ParameterName, 'Outpoints to return the state for')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Return the state of the UTXOs controlled by the address. Requires the wallet having the address to be synchronized with `wallet sync`')
+            [CompletionResult]::new('--address', 'address', [CompletionResultType]::ParameterName, 'Return the state of the UTXOs controlled by the address. Requires the wallet having the address to be synchronized with `wallet sync`')
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Return the state of the UTXOs controlled by the scriptPubKey, given in hex. Requires the wallet having the script to be synchronized with `wallet sync`')
+            [CompletionResult]::new('--script', 'script', [CompletionResultType]::ParameterName, 'Return the state of the UTXOs controlled by the scriptPubKey, given in hex. Requires the wallet having the script to be synchronized with `wallet sync`')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;transfer;combine' {
-            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
-            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+        'rgb-cli;outpoint;forget' {
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('--descriptor', 'descriptor', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;transfer;finalize' {
-            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Bifrost server to send state transfer to')
-            [CompletionResult]::new('--send', 'send', [CompletionResultType]::ParameterName, 'Bifrost server to send state transfer to')
-            [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal - or witness transaction output numbers containing allocations for the beneficiary')
-            [CompletionResult]::new('--endseal', 'endseal', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal - or witness transaction output numbers containing allocations for the beneficiary')
-            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
-            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+        'rgb-cli;outpoint;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List incoming and outgoing transfers known to the node')
+            [CompletionResult]::new('history', 'history', [CompletionResultType]::ParameterValue, 'Report tips of the locally known contract history, which can be given to the payer to exclude this history from the transfer consignment')
+            [CompletionResult]::new('receipt', 'receipt', [CompletionResultType]::ParameterValue, 'Get receipt for an incoming transfer signed by the node key, which can be handed to the payer as a proof that the payment was accepted')
+            [CompletionResult]::new('compose', 'compose', [CompletionResultType]::ParameterValue, 'Build state transfer consignment draft')
+            [CompletionResult]::new('combine', 'combine', [CompletionResultType]::ParameterValue, 'Update PSBT with the information from the state transition')
+            [CompletionResult]::new('consolidate', 'consolidate', [CompletionResultType]::ParameterValue, 'Move all allocations of the contract assigned to the PSBT inputs to a single output of the PSBT transaction')
+            [CompletionResult]::new('finalize', 'finalize', [CompletionResultType]::ParameterValue, 'Finalize and (optionally) send state transfer consignment to beneficiary')
+            [CompletionResult]::new('consume', 'consume', [CompletionResultType]::ParameterValue, 'Validate incoming transfer consignment and consume it into the stash')
+            [CompletionResult]::new('prove', 'prove', [CompletionResultType]::ParameterValue, 'Prepare proofs of the consignment witness transactions inclusion into the blockchain, allowing its validation by a node running offline')
+            [CompletionResult]::new('accelerate', 'accelerate', [CompletionResultType]::ParameterValue, 'Accelerate pending incoming transfer which witness transaction is stuck in the mempool')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
             break
         }
-        'rgb-cli;transfer;consume' {
-            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Try reveal the conceal seal')
-            [CompletionResult]::new('--reveal', 'reveal', [CompletionResultType]::ParameterName, 'Try reveal the conceal seal')
+        'rgb-cli;transfer;list' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Report only transfers of the given contract')
+            [CompletionResult]::new('--contract-id', 'contract-id', [CompletionResultType]::ParameterName, 'Report only transfers of the given contract')
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Report only transfers in the given direction (`incoming` or `outgoing`)')
+            [CompletionResult]::new('--direction', 'direction', [CompletionResultType]::ParameterName, 'Report only transfers in the given direction (`incoming` or `outgoing`)')
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Report only transfers with the given status (`finalized`, `sent`, `published`, `pending`, `accepted`, `rejected` or `accelerated`)')
+            [CompletionResult]::new('--status', 'status', [CompletionResultType]::ParameterName, 'Report only transfers with the given status (`finalized`, `sent`, `published`, `pending`, `accepted`, `rejected` or `accelerated`)')
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
-            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Consume even if the endpoint witness transaction is not yet mined')
-            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Consume even if the endpoint witness transaction is not yet mined')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
         }
-        'rgb-cli;transfer;help' {
+        'rgb-cli;transfer;history' {
             [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;receipt' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;compose' {
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Transition types to be always included into the state transfer')
+            [CompletionResult]::new('--node-type', 'node-type', [CompletionResultType]::ParameterName, 'Transition types to be always included into the state transfer')
+            [CompletionResult]::new('-k', 'k', [CompletionResultType]::ParameterName, 'Tips of the contract history already known to the beneficiary, as reported by its `transfer history` command. The history behind them is not included into the consignment')
+            [CompletionResult]::new('--known', 'known', [CompletionResultType]::ParameterName, 'Tips of the contract history already known to the beneficiary, as reported by its `transfer history` command. The history behind them is not included into the consignment')
+            [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds after which the node abandons the request')
+            [CompletionResult]::new('-w', 'w', [CompletionResultType]::ParameterName, 'Wallet output descriptor, previously synchronized with `wallet sync` command, which UTXOs will be spent by the transfer. The node expands the wallet into the UTXOs found during its last synchronization, so the outpoints need not be listed')
+            [CompletionResult]::new('--wallet', 'wallet', [CompletionResultType]::ParameterName, 'Wallet output descriptor, previously synchronized with `wallet sync` command, which UTXOs will be spent by the transfer. The node expands the wallet into the UTXOs found during its last synchronization, so the outpoints need not be listed')
+            [CompletionResult]::new('--from-index', 'from-index', [CompletionResultType]::ParameterName, 'First derivation index of the wallet addresses which UTXOs are spent; starts from the first address if not given')
+            [CompletionResult]::new('--to-index', 'to-index', [CompletionResultType]::ParameterName, 'Last derivation index of the wallet addresses which UTXOs are spent; all addresses after the first index if not given')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--armor', 'armor', [CompletionResultType]::ParameterName, 'Save the consignment in ASCII-armored form')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;combine' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;consolidate' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Contract which allocations are consolidated')
+            [CompletionResult]::new('--contract', 'contract', [CompletionResultType]::ParameterName, 'Contract which allocations are consolidated')
+            [CompletionResult]::new('--vout', 'vout', [CompletionResultType]::ParameterName, 'Output of the PSBT transaction which will receive all the allocations')
+            [CompletionResult]::new('--method', 'method', [CompletionResultType]::ParameterName, 'Method for closing the single-use-seal of the receiving output')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the finalized PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the finalized PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;finalize' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'Bifrost server to send state transfer to')
+            [CompletionResult]::new('--send', 'send', [CompletionResultType]::ParameterName, 'Bifrost server to send state transfer to')
+            [CompletionResult]::new('--encrypt-to', 'encrypt-to', [CompletionResultType]::ParameterName, 'Encrypt the consignment sent to the beneficiary to this key, taken from the `key` parameter of the invoice')
+            [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal - or witness transaction output numbers containing allocations for the beneficiary')
+            [CompletionResult]::new('--endseal', 'endseal', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal - or witness transaction output numbers containing allocations for the beneficiary')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--sign', 'sign', [CompletionResultType]::ParameterName, 'Sign the finalized PSBT with the external signer configured for the node')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Report the PSBT and consignment sizes, the fee and the resulting allocations without saving the files or sending the consignment')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;consume' {
+            [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds after which the node abandons the request')
+            [CompletionResult]::new('--proofs', 'proofs', [CompletionResultType]::ParameterName, 'File with proofs of the witness transactions inclusion into the blockchain, prepared with `transfer prove` command. Required when the node runs in offline mode')
+            [CompletionResult]::new('-r', 'r', [CompletionResultType]::ParameterName, 'Try reveal the conceal seal')
+            [CompletionResult]::new('--reveal', 'reveal', [CompletionResultType]::ParameterName, 'Try reveal the conceal seal')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Consume even if the endpoint witness transaction is not yet mined, or the contract was forgotten with a tombstone')
+            [CompletionResult]::new('--force', 'force', [CompletionResultType]::ParameterName, 'Consume even if the endpoint witness transaction is not yet mined, or the contract was forgotten with a tombstone')
+            [CompletionResult]::new('--full', 'full', [CompletionResultType]::ParameterName, 'Validate the whole contract history, ignoring imported checkpoint of the contract state. Requires consignment containing the full history')
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Upload the consignment file to the node as is, without decoding it locally')
+            [CompletionResult]::new('--upload', 'upload', [CompletionResultType]::ParameterName, 'Upload the consignment file to the node as is, without decoding it locally')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;prove' {
+            [CompletionResult]::new('--from', 'from', [CompletionResultType]::ParameterName, 'Height of the header checkpoint of the node which will validate the consignment. Proofs include all block headers following it')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;accelerate' {
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('--descriptor', 'descriptor', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Fee rate of the witness and child transactions package, in satoshis per virtual byte')
+            [CompletionResult]::new('--fee-rate', 'fee-rate', [CompletionResultType]::ParameterName, 'Fee rate of the witness and child transactions package, in satoshis per virtual byte')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;transfer;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;pay' {
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('--descriptor', 'descriptor', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Fee of the witness transaction, in satoshis')
+            [CompletionResult]::new('--fee', 'fee', [CompletionResultType]::ParameterName, 'Fee of the witness transaction, in satoshis')
+            [CompletionResult]::new('-a', 'a', [CompletionResultType]::ParameterName, 'Amount paid to each of the open invoices, which have zero amount and leave it to the payer, like donation invoices. Given as a decimal number with at most the contract precision of fractional digits, like `12.5`')
+            [CompletionResult]::new('--amount', 'amount', [CompletionResultType]::ParameterName, 'Amount paid to each of the open invoices, which have zero amount and leave it to the payer, like donation invoices. Given as a decimal number with at most the contract precision of fractional digits, like `12.5`')
+            [CompletionResult]::new('-i', 'i', [CompletionResultType]::ParameterName, 'Additional invoice of the same contract, paid with the same state transition')
+            [CompletionResult]::new('--invoice', 'invoice', [CompletionResultType]::ParameterName, 'Additional invoice of the same contract, paid with the same state transition')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Compose the payment and report the witness transaction size and fee, the consignment size and the resulting allocations without signing, publishing or sending anything')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;swap' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('propose', 'propose', [CompletionResultType]::ParameterValue, 'Propose swap to a counterparty (taker)')
+            [CompletionResult]::new('accept', 'accept', [CompletionResultType]::ParameterValue, 'Accept swap offer')
+            [CompletionResult]::new('complete', 'complete', [CompletionResultType]::ParameterValue, 'Complete swap, checking PSBT and consignment received from the counterparty against the swap terms')
+            [CompletionResult]::new('abort', 'abort', [CompletionResultType]::ParameterValue, 'Abort swap; the node will refuse to complete it')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;swap;propose' {
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Number of seconds during which the swap must be completed')
+            [CompletionResult]::new('--timeout', 'timeout', [CompletionResultType]::ParameterName, 'Number of seconds during which the swap must be completed')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Consignment draft for the given RGB asset, prepared with `transfer compose` command')
+            [CompletionResult]::new('--consignment', 'consignment', [CompletionResultType]::ParameterName, 'Consignment draft for the given RGB asset, prepared with `transfer compose` command')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;swap;accept' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Consignment draft for the given RGB asset, prepared with `transfer compose` command')
+            [CompletionResult]::new('--consignment', 'consignment', [CompletionResultType]::ParameterName, 'Consignment draft for the given RGB asset, prepared with `transfer compose` command')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the swap PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the swap PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;swap;complete' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Consignment received from the taker (for the maker only)')
+            [CompletionResult]::new('--consignment', 'consignment', [CompletionResultType]::ParameterName, 'Consignment received from the taker (for the maker only)')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Output file to save the checked PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('--out', 'out', [CompletionResultType]::ParameterName, 'Output file to save the checked PSBT. If not given, the source PSBT file is overwritten')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--sign', 'sign', [CompletionResultType]::ParameterName, 'Sign the PSBT with the external signer configured for the node')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;swap;abort' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;swap;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;payjoin' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('propose', 'propose', [CompletionResultType]::ParameterValue, 'Propose payjoin to the beneficiary')
+            [CompletionResult]::new('contribute', 'contribute', [CompletionResultType]::ParameterValue, 'Contribute wallet UTXOs to the payjoin proposed by the payer')
+            [CompletionResult]::new('complete', 'complete', [CompletionResultType]::ParameterValue, 'Complete payjoin, checking the joint PSBT received from the beneficiary against the proposal')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;payjoin;propose' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;payjoin;contribute' {
+            [CompletionResult]::new('-d', 'd', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('--descriptor', 'descriptor', [CompletionResultType]::ParameterName, 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'Fee paid by the beneficiary for its inputs and the change output, in satoshis')
+            [CompletionResult]::new('--fee', 'fee', [CompletionResultType]::ParameterName, 'Fee paid by the beneficiary for its inputs and the change output, in satoshis')
+            [CompletionResult]::new('-e', 'e', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal from the invoice paid by the payjoin')
+            [CompletionResult]::new('--endseal', 'endseal', [CompletionResultType]::ParameterName, 'Beneficiary blinded TXO seal from the invoice paid by the payjoin')
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Output file to save the final consignment for the paid asset, which must be consumed once the witness transaction is mined')
+            [CompletionResult]::new('--consignment', 'consignment', [CompletionResultType]::ParameterName, 'Output file to save the final consignment for the paid asset, which must be consumed once the witness transaction is mined')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--sign', 'sign', [CompletionResultType]::ParameterName, 'Sign the beneficiary inputs with the external signer configured for the node')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;payjoin;complete' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--sign', 'sign', [CompletionResultType]::ParameterName, 'Sign the PSBT with the external signer configured for the node')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;payjoin;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('sync', 'sync', [CompletionResultType]::ParameterValue, 'Scan the blockchain for the wallet UTXOs and report wallet balance')
+            [CompletionResult]::new('balance', 'balance', [CompletionResultType]::ParameterValue, 'Report balance of the previously synchronized wallet, including RGB allocations on its UTXOs')
+            [CompletionResult]::new('create', 'create', [CompletionResultType]::ParameterValue, 'Register wallet with the node without scanning the blockchain, so its addresses can be listed before the wallet receives any funds')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List wallets created or synchronized with the node')
+            [CompletionResult]::new('addresses', 'addresses', [CompletionResultType]::ParameterValue, 'List wallet addresses with their derivation indexes, together with the wallet UTXOs on them and contracts having state assigned to these UTXOs. UTXOs are reported as of the last wallet synchronization')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;wallet;sync' {
+            [CompletionResult]::new('-g', 'g', [CompletionResultType]::ParameterName, 'Number of consequent unused addresses after which the scan stops')
+            [CompletionResult]::new('--gap-limit', 'gap-limit', [CompletionResultType]::ParameterName, 'Number of consequent unused addresses after which the scan stops')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--rescan', 'rescan', [CompletionResultType]::ParameterName, 'Re-scan the wallet starting from the first derivation index')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet;balance' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet;create' {
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Template of the descriptor created from the extended public key: `wpkh` or `tr`. Ignored if an output descriptor is given')
+            [CompletionResult]::new('--template', 'template', [CompletionResultType]::ParameterName, 'Template of the descriptor created from the extended public key: `wpkh` or `tr`. Ignored if an output descriptor is given')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet;list' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet;addresses' {
+            [CompletionResult]::new('-u', 'u', [CompletionResultType]::ParameterName, 'Number of unused addresses listed after the last used one in each keychain')
+            [CompletionResult]::new('--unused', 'unused', [CompletionResultType]::ParameterName, 'Number of unused addresses listed after the last used one in each keychain')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;wallet;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;label' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('set', 'set', [CompletionResultType]::ParameterValue, 'Assign label and tags to an object, replacing the existing ones')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List annotations for the given objects, or all known annotations if no objects are given')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;label;set' {
+            [CompletionResult]::new('-l', 'l', [CompletionResultType]::ParameterName, 'Free-form label')
+            [CompletionResult]::new('--label', 'label', [CompletionResultType]::ParameterName, 'Free-form label')
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Tag to assign; can be used multiple times')
+            [CompletionResult]::new('--tag', 'tag', [CompletionResultType]::ParameterName, 'Tag to assign; can be used multiple times')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;label;list' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;label;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;relay' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('id', 'id', [CompletionResultType]::ParameterValue, 'Print relay identifier of the recipient, i.e. x-only public key which senders seal the consignments to')
+            [CompletionResult]::new('deposit', 'deposit', [CompletionResultType]::ParameterValue, 'Seal consignment to the recipient key and deposit it to the relay node, which holds it until the recipient picks it up')
+            [CompletionResult]::new('pickup', 'pickup', [CompletionResultType]::ParameterValue, 'Pick up consignments held by the relay node for the recipient, saving them as `<envelope_id>.rgbc` files. Received consignments are removed from the relay')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;relay;id' {
+            [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the recipient')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;relay;deposit' {
+            [CompletionResult]::new('--ttl', 'ttl', [CompletionResultType]::ParameterName, 'Number of seconds the relay holds the consignment. Defaults to the maximum time configured for the relay')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--no-forward', 'no-forward', [CompletionResultType]::ParameterName, 'Do not forward the consignment to the peer relays of the node')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;relay;pickup' {
+            [CompletionResult]::new('--key', 'key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the recipient')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Directory to save the consignments to')
+            [CompletionResult]::new('--output-dir', 'output-dir', [CompletionResultType]::ParameterName, 'Directory to save the consignments to')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;relay;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;consignment' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('inspect', 'inspect', [CompletionResultType]::ParameterValue, 'Print structure of a consignment file: genesis, anchored state transition bundles, state extensions and endpoints')
+            [CompletionResult]::new('qr', 'qr', [CompletionResultType]::ParameterValue, 'Export consignment file as a numbered sequence of QR codes for scanning by an air-gapped device')
+            [CompletionResult]::new('assemble', 'assemble', [CompletionResultType]::ParameterValue, 'Assemble consignment from the contents of QR codes produced by `qr` command and scanned in any order')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;consignment;inspect' {
+            [CompletionResult]::new('--dot', 'dot', [CompletionResultType]::ParameterName, 'Save consignment graph in graphviz DOT format to the file')
+            [CompletionResult]::new('-t', 't', [CompletionResultType]::ParameterName, 'Type of the file data: `transfer`, `contract` or `disclosure`')
+            [CompletionResult]::new('--type', 'type', [CompletionResultType]::ParameterName, 'Type of the file data: `transfer`, `contract` or `disclosure`')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;consignment;qr' {
+            [CompletionResult]::new('--fragment-len', 'fragment-len', [CompletionResultType]::ParameterName, 'Maximal number of consignment bytes per QR code')
+            [CompletionResult]::new('--parts', 'parts', [CompletionResultType]::ParameterName, 'Total number of QR codes to produce; defaults to the number of consignment fragments')
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'Directory to save QR codes to as numbered SVG images instead of printing them to the terminal')
+            [CompletionResult]::new('--output-dir', 'output-dir', [CompletionResultType]::ParameterName, 'Directory to save QR codes to as numbered SVG images instead of printing them to the terminal')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;consignment;assemble' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;consignment;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;quarantine' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List consignments kept in the quarantine')
+            [CompletionResult]::new('approve', 'approve', [CompletionResultType]::ParameterValue, 'Take consignment out of the quarantine and consume it, bypassing the contract whitelist. Consignment failing validation is returned into the quarantine')
+            [CompletionResult]::new('revalidate', 'revalidate', [CompletionResultType]::ParameterValue, 'Validate quarantined consignment once again, for instance after its transactions got mined, and consume it if it passes validation')
+            [CompletionResult]::new('purge', 'purge', [CompletionResultType]::ParameterValue, 'Drop consignment from the quarantine without consuming it')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;quarantine;list' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;quarantine;approve' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;quarantine;revalidate' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;quarantine;purge' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;quarantine;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;cache' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('rebuild', 'rebuild', [CompletionResultType]::ParameterValue, 'Drop contract state and rebuild it from the geneses, state transitions and extensions kept in the stash, together with the outpoint index')
+            [CompletionResult]::new('revalidate', 'revalidate', [CompletionResultType]::ParameterValue, 'Re-validate contracts accepted with an older version of the validation rules using the history kept in the stash')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;cache;rebuild' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Rebuild state only of the given contract')
+            [CompletionResult]::new('--contract', 'contract', [CompletionResultType]::ParameterName, 'Rebuild state only of the given contract')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;cache;revalidate' {
+            [CompletionResult]::new('-c', 'c', [CompletionResultType]::ParameterName, 'Re-validate only the given contract, even if it was validated with the current rules')
+            [CompletionResult]::new('--contract', 'contract', [CompletionResultType]::ParameterName, 'Re-validate only the given contract, even if it was validated with the current rules')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;cache;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;access' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('grant', 'grant', [CompletionResultType]::ParameterValue, 'Grant client identity access to a contract or a wallet')
+            [CompletionResult]::new('revoke', 'revoke', [CompletionResultType]::ParameterValue, 'Revoke access of a client identity to a contract or a wallet')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List contracts and wallets accessible by each of the client identities')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;access;grant' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;access;revoke' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;access;list' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;access;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;order' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;schema' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('list', 'list', [CompletionResultType]::ParameterValue, 'List ids of the schemata known to the node')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Report declared types, field formats and owned right states of the schema together with the known contracts issued under it')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;schema;list' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;schema;show' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;schema;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;gc' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;prune' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--dry-run', 'dry-run', [CompletionResultType]::ParameterName, 'Report the data which would be pruned without removing them')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;health' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;ping' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;config' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('show', 'show', [CompletionResultType]::ParameterValue, 'Show node settings given in the configuration file, with the environment variables or in the command line, together with the configuration layer providing each of them: `file`, `env` or `cli`')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'rgb-cli;config;show' {
+            [CompletionResult]::new('--effective', 'effective', [CompletionResultType]::ParameterName, 'Show also the settings having default values, i.e. the complete configuration the node runs with')
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;config;help' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;timestamp' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--anchor', 'anchor', [CompletionResultType]::ParameterName, 'Submit the commitment to the timestamping calendar')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;cancel' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
+            [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
+            break
+        }
+        'rgb-cli;promote' {
+            [CompletionResult]::new('-R', 'R', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
+            [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
@@ -248,6 +2352,14 @@ Register-ArgumentCompleter -Native -CommandName 'rgb-cli' -ScriptBlock {
             [CompletionResult]::new('--rpc', 'rpc', [CompletionResultType]::ParameterName, 'ZMQ socket for connecting daemon RPC interface')
             [CompletionResult]::new('-n', 'n', [CompletionResultType]::ParameterName, 'Blockchain to use')
             [CompletionResult]::new('--chain', 'chain', [CompletionResultType]::ParameterName, 'Blockchain to use')
+            [CompletionResult]::new('--signet-challenge', 'signet-challenge', [CompletionResultType]::ParameterName, 'Challenge script of a custom signet, in hex')
+            [CompletionResult]::new('--config', 'config', [CompletionResultType]::ParameterName, 'Configuration file')
+            [CompletionResult]::new('--identity-key', 'identity-key', [CompletionResultType]::ParameterName, 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders')
+            [CompletionResult]::new('--node-identity', 'node-identity', [CompletionResultType]::ParameterName, 'Identity key which the node must have, in hex')
+            [CompletionResult]::new('--format', 'format', [CompletionResultType]::ParameterName, 'Output format of the command results: `yaml` or `json`')
+            [CompletionResult]::new('--id-format', 'id-format', [CompletionResultType]::ParameterName, 'Encoding of the contract ids in the command output: `bech32` or `hex`')
+            [CompletionResult]::new('--group-separator', 'group-separator', [CompletionResultType]::ParameterName, 'Character separating groups of three digits in the integer part of the decimal amounts')
+            [CompletionResult]::new('--json', 'json', [CompletionResultType]::ParameterName, 'Print command results and errors as a single JSON document')
             [CompletionResult]::new('-v', 'v', [CompletionResultType]::ParameterName, 'Set verbosity level')
             [CompletionResult]::new('--verbose', 'verbose', [CompletionResultType]::ParameterName, 'Set verbosity level')
             break
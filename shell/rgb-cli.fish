@@ -0,0 +1,1526 @@
+complete -c rgb-cli -n "__fish_use_subcommand" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_use_subcommand" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_use_subcommand" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_use_subcommand" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_use_subcommand" -s V -l version -d 'Print version information'
+complete -c rgb-cli -n "__fish_use_subcommand" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_use_subcommand" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "init" -d 'Write configuration file template with all the settings commented out'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "contract" -d 'Contract management'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "schema" -d 'Schemata known to the node'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "issue" -d 'Issue new contract from the declarative specification of its genesis'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "outpoint" -d 'Information on outpoints'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "transfer" -d 'Work with state transfers'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "pay" -d 'Pay RGB invoice from the wallet tracked by the node'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "swap" -d 'Atomic two-party swaps of RGB assets and bitcoins'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "payjoin" -d 'Payjoin transfers, where the beneficiary contributes its inputs to the witness transaction'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "wallet" -d 'Bitcoin wallets tracked by the node'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "label" -d 'User-defined labels and tags for contracts, outpoints and transfers'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "relay" -d 'Store-and-forward relay of consignments for offline recipients'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "consignment" -d 'Offline inspection of consignment files'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "quarantine" -d 'Consignments received from the remote nodes which await approval'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "cache" -d 'Contract state kept by the node'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "gc" -d 'Remove consignment containers which are no longer needed from the store: containers of accepted consignments and containers kept longer than the retention period of the node'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "prune" -d 'Prune data which retention period configured for the node has passed: quarantined consignments, records of completed transfers, contract journals and enclosed disclosures'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "health" -d 'Report status of the node worker daemons, including the number of their restarts and the reason of the last failure'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "ping" -d 'Check that the node controls its identity key by asking it to sign a random nonce'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "config" -d 'Node configuration'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "timestamp" -d 'Commit to the digests of all contracts known to the node, proving to auditors what the node knew at a point in time. The commitment may be anchored into Bitcoin with the OpenTimestamps calendar configured for the node, saving the pending timestamp next to the output file with `.ots` extension; it can be upgraded and verified with the standard OpenTimestamps tools'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "cancel" -d 'Cancel request of another client, which id is reported by the `health` command. Queued request is dropped, and request processed by a worker is interrupted at the next safe point'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "promote" -d 'Promote read replica to primary. The node stops following its primary and starts accepting requests modifying the stash. Promotion lasts until the node restart, so `--replica-of` option must be removed from the node configuration to keep it primary'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "access" -d 'Access of the client identities to the contracts and wallets on a node shared by multiple users'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "order" -d 'Show order of a contract issue or a transfer signed by the client, which is kept in the node order journal'
+complete -c rgb-cli -n "__fish_use_subcommand" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l force -d 'Overwrite the existing configuration file'
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from init" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List all known contract ids'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "register" -d 'Add new contract to the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "import" -d 'Import contract from its genesis, as shared by the wallets which exchange the contract genesis instead of the contract source'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "state" -d 'Query contract state'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "balance" -d 'Report contract amounts allocated to the wallet and/or outpoints at the given block height'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "stats" -d 'Report aggregate statistics of the contract: number of allocations and outpoints holding them, history size and depth, and the amount of data kept in the stash'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "deps" -d 'Show dependency graph of the contract: contracts sharing its root schema and contracts referenced from its metadata or referencing it, like the contracts which assets were burned to issue it. If no contract is given, the graph of all known contracts is shown'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "allocations" -d 'List allocations of the contract state known to the node. Allocations on the unspent outputs of the wallets registered with the node are marked as owned; the rest belong to third parties. Outpoints spent since the last wallet synchronization are reported with the spending transaction'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "usage" -d 'List contracts taking the most of the store space, with the size of their history, anchors and consignment containers kept by the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "digest" -d 'Report canonical digest of the contract history and state known to the node, which can be compared with the digest reported by another node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "compare" -d 'Compare contract history and state known to the node with another node, listing transition bundles known to one of the nodes only'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "consignment" -d 'Request contract consignment'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "embed" -d 'Embed contract information into PSBT file'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "checkpoint" -d 'Create checkpoint of the contract state signed by the contract issuer'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "policy" -d 'Show or change the policy applied by the node to the contract transfers'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "import-checkpoint" -d 'Import contract state checkpoint signed by a trusted issuer'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "reveal" -d 'Reveal seal of the contract assignments accepted with the seal being concealed'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "invoice" -d 'Create invoice for receiving the contract asset on a wallet outpoint'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "invoices" -d 'List invoices of the contract created by the node, with the amounts they have received and their payment status'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "export-reveals" -d 'Export reveal data for the contract seals defined on the given outpoints, allowing the counterparty to reveal them'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "import-reveals" -d 'Import reveal data exported by the counterparty, revealing the contract seals which were accepted in concealed form'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "journal" -d 'List consignments accepted for the contract, which are recorded into the contract journal by nodes working with regtest'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "rewind" -d 'Rewind contract state to the moment before the consignment was accepted, reporting the state change (regtest only)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "replay" -d 'Apply consignments from the contract journal again in the given order, reporting the state change (regtest only)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "forget" -d 'Remove contract from the node together with its history, state, transfer records and other derived data'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "prove" -d 'Prepare proofs of the contract witness transactions inclusion into the blockchain, allowing its registration by a node running offline'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from register; and not __fish_seen_subcommand_from import; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from deps; and not __fish_seen_subcommand_from allocations; and not __fish_seen_subcommand_from usage; and not __fish_seen_subcommand_from digest; and not __fish_seen_subcommand_from compare; and not __fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from embed; and not __fish_seen_subcommand_from checkpoint; and not __fish_seen_subcommand_from policy; and not __fish_seen_subcommand_from import-checkpoint; and not __fish_seen_subcommand_from reveal; and not __fish_seen_subcommand_from invoice; and not __fish_seen_subcommand_from invoices; and not __fish_seen_subcommand_from export-reveals; and not __fish_seen_subcommand_from import-reveals; and not __fish_seen_subcommand_from journal; and not __fish_seen_subcommand_from rewind; and not __fish_seen_subcommand_from replay; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l timeout -d 'Number of seconds after which the node abandons the request' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l proofs -d 'File with proofs of the witness transactions inclusion into the blockchain, prepared with `contract prove` command. Required when the node runs in offline mode' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l force -d 'Force importing of valid contract containing non-mined transactions or forgotten with a tombstone'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from register" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from state" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s d -l descriptor -d 'Output descriptor of a wallet previously synchronized with `wallet sync`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s o -l outpoint -d 'Outpoints to account allocations for' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from balance" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from stats" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from deps" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from allocations" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -s l -l limit -d 'Maximal number of contracts to list; zero lists all contracts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from usage" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from digest" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from compare" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -s t -l node-type -d 'Transition types to be always included into the consignment' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l timeout -d 'Number of seconds after which the node abandons the request' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l armor -d 'Print the consignment in ASCII-armored form instead of Bech32'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from consignment" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s t -l node-type -d 'Transition types to be always included into the consignment' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s o -l out -d 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from embed" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l issuer-key -d 'File containing hex-encoded issuer secret key used to sign the checkpoint. If not given, the checkpoint is signed by the issuer signer configured for the node, keeping the key off the host' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from checkpoint" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l min-allocation -d 'Refuse to finalize transfers creating fungible allocations smaller than the given amount (in atomic units). Zero disables the check' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from policy" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-checkpoint" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from reveal" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l method -d 'Method for closing the single-use-seal of the outpoint' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -s t -l transport -d 'Bifrost node which the payer sends the transfer consignment to' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l underpayment -d 'Decimal amount which the payment may fall short of the invoice amount by and still pay the invoice' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l overpayment -d 'Decimal amount which the payment may exceed the invoice amount by and still pay the invoice' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoice" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from invoices" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -s o -l outpoint -d 'Outpoints which seals must be revealed' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from export-reveals" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from import-reveals" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from journal" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from rewind" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from replay" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l tombstone -d 'Keep a tombstone making the node refuse re-import of the contract unless it is forced'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -s y -l yes -d 'Do not ask for confirmation'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from forget" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l from -d 'Height of the header checkpoint of the node which will register the contract. Proofs include all block headers following it' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from prove" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from contract; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -s s -l spec -d 'YAML or JSON file with the issuance specification' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l schema -d 'File with the strict-encoded contract schema. Required if the schema is not yet known to the node' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l dry-run -d 'Validate the specification and report the resulting genesis allocations and the contract size without registering the contract'
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from issue" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -f -a "state" -d 'Return known outpoint state together with the spent status of the wallet outpoints'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -f -a "forget" -d 'Remove contract state assigned to the wallet outpoints which were spent without transferring it'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and not __fish_seen_subcommand_from state; and not __fish_seen_subcommand_from forget; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s o -l outpoint -d 'Outpoints to return the state for' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s a -l address -d 'Return the state of the UTXOs controlled by the address. Requires the wallet having the address to be synchronized with `wallet sync`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s s -l script -d 'Return the state of the UTXOs controlled by the scriptPubKey, given in hex. Requires the wallet having the script to be synchronized with `wallet sync`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from state" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -s d -l descriptor -d 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from forget" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from outpoint; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List incoming and outgoing transfers known to the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "history" -d 'Report tips of the locally known contract history, which can be given to the payer to exclude this history from the transfer consignment'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "receipt" -d 'Get receipt for an incoming transfer signed by the node key, which can be handed to the payer as a proof that the payment was accepted'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "compose" -d 'Build state transfer consignment draft'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "combine" -d 'Update PSBT with the information from the state transition'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "consolidate" -d 'Move all allocations of the contract assigned to the PSBT inputs to a single output of the PSBT transaction'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "finalize" -d 'Finalize and (optionally) send state transfer consignment to beneficiary'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "consume" -d 'Validate incoming transfer consignment and consume it into the stash'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "prove" -d 'Prepare proofs of the consignment witness transactions inclusion into the blockchain, allowing its validation by a node running offline'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "accelerate" -d 'Accelerate pending incoming transfer which witness transaction is stuck in the mempool'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from history; and not __fish_seen_subcommand_from receipt; and not __fish_seen_subcommand_from compose; and not __fish_seen_subcommand_from combine; and not __fish_seen_subcommand_from consolidate; and not __fish_seen_subcommand_from finalize; and not __fish_seen_subcommand_from consume; and not __fish_seen_subcommand_from prove; and not __fish_seen_subcommand_from accelerate; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s c -l contract-id -d 'Report only transfers of the given contract' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s d -l direction -d 'Report only transfers in the given direction (`incoming` or `outgoing`)' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s s -l status -d 'Report only transfers with the given status (`finalized`, `sent`, `published`, `pending`, `accepted`, `rejected` or `accelerated`)' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from history" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from receipt" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s t -l node-type -d 'Transition types to be always included into the state transfer' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s k -l known -d 'Tips of the contract history already known to the beneficiary, as reported by its `transfer history` command. The history behind them is not included into the consignment' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l timeout -d 'Number of seconds after which the node abandons the request' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s w -l wallet -d 'Wallet output descriptor, previously synchronized with `wallet sync` command, which UTXOs will be spent by the transfer. The node expands the wallet into the UTXOs found during its last synchronization, so the outpoints need not be listed' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l from-index -d 'First derivation index of the wallet addresses which UTXOs are spent; starts from the first address if not given' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l to-index -d 'Last derivation index of the wallet addresses which UTXOs are spent; all addresses after the first index if not given' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l armor -d 'Save the consignment in ASCII-armored form'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from compose" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -s o -l out -d 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from combine" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s c -l contract -d 'Contract which allocations are consolidated' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l vout -d 'Output of the PSBT transaction which will receive all the allocations' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l method -d 'Method for closing the single-use-seal of the receiving output' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s o -l out -d 'Output file to save the finalized PSBT. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consolidate" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s s -l send -d 'Bifrost server to send state transfer to' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l encrypt-to -d 'Encrypt the consignment sent to the beneficiary to this key, taken from the `key` parameter of the invoice' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s e -l endseal -d 'Beneficiary blinded TXO seal - or witness transaction output numbers containing allocations for the beneficiary' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s o -l out -d 'Output file to save the PSBT updated with state transition(s) information. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l sign -d 'Sign the finalized PSBT with the external signer configured for the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l dry-run -d 'Report the PSBT and consignment sizes, the fee and the resulting allocations without saving the files or sending the consignment'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from finalize" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l timeout -d 'Number of seconds after which the node abandons the request' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l proofs -d 'File with proofs of the witness transactions inclusion into the blockchain, prepared with `transfer prove` command. Required when the node runs in offline mode' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s r -l reveal -d 'Try reveal the conceal seal' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s f -l force -d 'Consume even if the endpoint witness transaction is not yet mined, or the contract was forgotten with a tombstone'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l full -d 'Validate the whole contract history, ignoring imported checkpoint of the contract state. Requires consignment containing the full history'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s u -l upload -d 'Upload the consignment file to the node as is, without decoding it locally'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from consume" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l from -d 'Height of the header checkpoint of the node which will validate the consignment. Proofs include all block headers following it' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from prove" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s d -l descriptor -d 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s f -l fee-rate -d 'Fee rate of the witness and child transactions package, in satoshis per virtual byte' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from accelerate" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from transfer; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s d -l descriptor -d 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s f -l fee -d 'Fee of the witness transaction, in satoshis' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s a -l amount -d 'Amount paid to each of the open invoices, which have zero amount and leave it to the payer, like donation invoices. Given as a decimal number with at most the contract precision of fractional digits, like `12.5`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s i -l invoice -d 'Additional invoice of the same contract, paid with the same state transition' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l dry-run -d 'Compose the payment and report the witness transaction size and fee, the consignment size and the resulting allocations without signing, publishing or sending anything'
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from pay" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -f -a "propose" -d 'Propose swap to a counterparty (taker)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -f -a "accept" -d 'Accept swap offer'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -f -a "complete" -d 'Complete swap, checking PSBT and consignment received from the counterparty against the swap terms'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -f -a "abort" -d 'Abort swap; the node will refuse to complete it'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from accept; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from abort; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s t -l timeout -d 'Number of seconds during which the swap must be completed' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s c -l consignment -d 'Consignment draft for the given RGB asset, prepared with `transfer compose` command' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from propose" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s c -l consignment -d 'Consignment draft for the given RGB asset, prepared with `transfer compose` command' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s o -l out -d 'Output file to save the swap PSBT. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from accept" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s c -l consignment -d 'Consignment received from the taker (for the maker only)' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s o -l out -d 'Output file to save the checked PSBT. If not given, the source PSBT file is overwritten' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l sign -d 'Sign the PSBT with the external signer configured for the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from complete" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from abort" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from swap; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -f -a "propose" -d 'Propose payjoin to the beneficiary'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -f -a "contribute" -d 'Contribute wallet UTXOs to the payjoin proposed by the payer'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -f -a "complete" -d 'Complete payjoin, checking the joint PSBT received from the beneficiary against the proposal'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and not __fish_seen_subcommand_from propose; and not __fish_seen_subcommand_from contribute; and not __fish_seen_subcommand_from complete; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from propose" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s d -l descriptor -d 'Wallet output descriptor with `<0;1>` derivation step, previously synchronized with `wallet sync` command. Defaults to the wallet from the configuration file' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s f -l fee -d 'Fee paid by the beneficiary for its inputs and the change output, in satoshis' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s e -l endseal -d 'Beneficiary blinded TXO seal from the invoice paid by the payjoin' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s c -l consignment -d 'Output file to save the final consignment for the paid asset, which must be consumed once the witness transaction is mined' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l sign -d 'Sign the beneficiary inputs with the external signer configured for the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from contribute" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l sign -d 'Sign the PSBT with the external signer configured for the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from complete" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from payjoin; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "sync" -d 'Scan the blockchain for the wallet UTXOs and report wallet balance'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "balance" -d 'Report balance of the previously synchronized wallet, including RGB allocations on its UTXOs'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "create" -d 'Register wallet with the node without scanning the blockchain, so its addresses can be listed before the wallet receives any funds'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List wallets created or synchronized with the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "addresses" -d 'List wallet addresses with their derivation indexes, together with the wallet UTXOs on them and contracts having state assigned to these UTXOs. UTXOs are reported as of the last wallet synchronization'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and not __fish_seen_subcommand_from sync; and not __fish_seen_subcommand_from balance; and not __fish_seen_subcommand_from create; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from addresses; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -s g -l gap-limit -d 'Number of consequent unused addresses after which the scan stops' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l rescan -d 'Re-scan the wallet starting from the first derivation index'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from sync" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from balance" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -s t -l template -d 'Template of the descriptor created from the extended public key: `wpkh` or `tr`. Ignored if an output descriptor is given' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from create" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -s u -l unused -d 'Number of unused addresses listed after the last used one in each keychain' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from addresses" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from wallet; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "set" -d 'Assign label and tags to an object, replacing the existing ones'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List annotations for the given objects, or all known annotations if no objects are given'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and not __fish_seen_subcommand_from set; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s l -l label -d 'Free-form label' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s t -l tag -d 'Tag to assign; can be used multiple times' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from set" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from label; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -f -a "id" -d 'Print relay identifier of the recipient, i.e. x-only public key which senders seal the consignments to'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -f -a "deposit" -d 'Seal consignment to the recipient key and deposit it to the relay node, which holds it until the recipient picks it up'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -f -a "pickup" -d 'Pick up consignments held by the relay node for the recipient, saving them as `<envelope_id>.rgbc` files. Received consignments are removed from the relay'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and not __fish_seen_subcommand_from id; and not __fish_seen_subcommand_from deposit; and not __fish_seen_subcommand_from pickup; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l key -d 'File containing hex-encoded secret key of the recipient' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from id" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l ttl -d 'Number of seconds the relay holds the consignment. Defaults to the maximum time configured for the relay' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l no-forward -d 'Do not forward the consignment to the peer relays of the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from deposit" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l key -d 'File containing hex-encoded secret key of the recipient' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -s o -l output-dir -d 'Directory to save the consignments to' -r -f -a "(__fish_complete_directories)"
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from pickup" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from relay; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -f -a "inspect" -d 'Print structure of a consignment file: genesis, anchored state transition bundles, state extensions and endpoints'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -f -a "qr" -d 'Export consignment file as a numbered sequence of QR codes for scanning by an air-gapped device'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -f -a "assemble" -d 'Assemble consignment from the contents of QR codes produced by `qr` command and scanned in any order'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and not __fish_seen_subcommand_from inspect; and not __fish_seen_subcommand_from qr; and not __fish_seen_subcommand_from assemble; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l dot -d 'Save consignment graph in graphviz DOT format to the file' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -s t -l type -d 'Type of the file data: `transfer`, `contract` or `disclosure`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from inspect" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l fragment-len -d 'Maximal number of consignment bytes per QR code' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l parts -d 'Total number of QR codes to produce; defaults to the number of consignment fragments' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -s o -l output-dir -d 'Directory to save QR codes to as numbered SVG images instead of printing them to the terminal' -r -f -a "(__fish_complete_directories)"
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from qr" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from assemble" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from consignment; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List consignments kept in the quarantine'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -f -a "approve" -d 'Take consignment out of the quarantine and consume it, bypassing the contract whitelist. Consignment failing validation is returned into the quarantine'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -f -a "revalidate" -d 'Validate quarantined consignment once again, for instance after its transactions got mined, and consume it if it passes validation'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -f -a "purge" -d 'Drop consignment from the quarantine without consuming it'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from approve; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from purge; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from approve" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from revalidate" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from purge" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from quarantine; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -f -a "rebuild" -d 'Drop contract state and rebuild it from the geneses, state transitions and extensions kept in the stash, together with the outpoint index'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -f -a "revalidate" -d 'Re-validate contracts accepted with an older version of the validation rules using the history kept in the stash'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and not __fish_seen_subcommand_from rebuild; and not __fish_seen_subcommand_from revalidate; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -s c -l contract -d 'Rebuild state only of the given contract' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from rebuild" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -s c -l contract -d 'Re-validate only the given contract, even if it was validated with the current rules' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from revalidate" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cache; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "grant" -d 'Grant client identity access to a contract or a wallet'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "revoke" -d 'Revoke access of a client identity to a contract or a wallet'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List contracts and wallets accessible by each of the client identities'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and not __fish_seen_subcommand_from grant; and not __fish_seen_subcommand_from revoke; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from grant" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from revoke" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from access; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from order" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -f -a "list" -d 'List ids of the schemata known to the node'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -f -a "show" -d 'Report declared types, field formats and owned right states of the schema together with the known contracts issued under it'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and not __fish_seen_subcommand_from list; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from list" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from show" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from schema; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from gc" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l dry-run -d 'Report the data which would be pruned without removing them'
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from prune" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from health" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from ping" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -f -a "show" -d 'Show node settings given in the configuration file, with the environment variables or in the command line, together with the configuration layer providing each of them: `file`, `env` or `cli`'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from show; and not __fish_seen_subcommand_from help" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l effective -d 'Show also the settings having default values, i.e. the complete configuration the node runs with'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from show" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from config; and __fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l anchor -d 'Submit the commitment to the timestamping calendar'
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from timestamp" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from cancel" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -s h -l help -d 'Print help information'
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from promote" -s v -l verbose -d 'Set verbosity level'
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -s R -l rpc -d 'ZMQ socket for connecting daemon RPC interface' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -s n -l chain -d 'Blockchain to use' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l signet-challenge -d 'Challenge script of a custom signet, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l config -d 'Configuration file' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l identity-key -d 'File containing hex-encoded secret key of the client identity, used to authenticate to the node with access control enabled and to sign contract issue and transfer orders' -r -F
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l node-identity -d 'Identity key which the node must have, in hex' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l format -d 'Output format of the command results: `yaml` or `json`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l id-format -d 'Encoding of the contract ids in the command output: `bech32` or `hex`' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l group-separator -d 'Character separating groups of three digits in the integer part of the decimal amounts' -r
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -l json -d 'Print command results and errors as a single JSON document'
+complete -c rgb-cli -n "__fish_seen_subcommand_from help" -s v -l verbose -d 'Set verbosity level'
@@ -15,15 +15,14 @@
 #[macro_use]
 extern crate log;
 
-use clap::Parser;
 use microservices::error::BootstrapError;
 use rgb_node::bucketd::Opts;
-use rgb_node::{bucketd, Config, LaunchError};
+use rgb_node::{bucketd, settings, Config, LaunchError};
 
 fn main() -> Result<(), BootstrapError<LaunchError>> {
     println!("bucketd: RGB bucket microservice");
 
-    let mut opts = Opts::parse();
+    let (mut opts, _) = settings::parse::<Opts>().map_err(LaunchError::from)?;
     trace!("Command-line arguments: {:?}", opts);
     opts.process();
     trace!("Processed arguments: {:?}", opts);
@@ -35,15 +34,6 @@ fn main() -> Result<(), BootstrapError<LaunchError>> {
     debug!("STORE socket {}", config.store_endpoint);
     debug!("STORM socket {}", config.storm_endpoint);
 
-    /*
-    use self::internal::ResultExt;
-    let (config_from_file, _) =
-        internal::Config::custom_args_and_optional_files(std::iter::empty::<
-            &str,
-        >())
-        .unwrap_or_exit();
-     */
-
     debug!("Starting runtime ...");
     bucketd::run(config).expect("running bucketd runtime");
 
@@ -0,0 +1,58 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Generator of the deterministic fixtures for compatibility tests.
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use rgb_node::bucketd::{Fixture, FixtureError, Scenario};
+
+/// Generates or checks deterministic consignment, disclosure and PSBT fixtures
+#[derive(Parser, Clone, PartialEq, Eq, Debug)]
+#[clap(name = "rgb-fixtures", bin_name = "rgb-fixtures", author, version)]
+pub struct Opts {
+    /// Seed of the blinding factors
+    #[clap(long, default_value = "0")]
+    pub seed: u64,
+
+    /// Directory of the fixtures, with a subdirectory per scenario
+    #[clap(short, long, default_value = "fixtures")]
+    pub dir: PathBuf,
+
+    /// Check the golden files in the fixtures directory instead of
+    /// re-generating them
+    #[clap(long)]
+    pub check: bool,
+
+    /// Scenarios of the fixtures; all scenarios if omitted
+    pub scenarios: Vec<Scenario>,
+}
+
+fn main() -> Result<(), FixtureError> {
+    let opts = Opts::parse();
+    let scenarios = if opts.scenarios.is_empty() { Scenario::ALL.to_vec() } else { opts.scenarios };
+
+    for scenario in scenarios {
+        let fixture = Fixture::generate(scenario, opts.seed)?;
+        if opts.check {
+            fixture.check(&opts.dir)?;
+            println!("{}: golden files match", scenario);
+        } else {
+            fixture.save(&opts.dir)?;
+            println!("{}: fixtures saved", scenario);
+        }
+        for (name, id) in fixture.ids() {
+            println!("  {} {}", name, id);
+        }
+    }
+
+    Ok(())
+}
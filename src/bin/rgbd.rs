@@ -15,35 +15,26 @@
 #[macro_use]
 extern crate log;
 
-use clap::Parser;
 use microservices::error::BootstrapError;
 use rgb_node::rgbd::Opts;
-use rgb_node::{rgbd, Config, LaunchError};
+use rgb_node::{rgbd, settings, Config, LaunchError};
 
 fn main() -> Result<(), BootstrapError<LaunchError>> {
     println!("rgbd: RGB stash microservice");
 
-    let mut opts = Opts::parse();
+    let (mut opts, settings) = settings::parse::<Opts>().map_err(LaunchError::from)?;
     trace!("Command-line arguments: {:?}", opts);
     opts.process();
     trace!("Processed arguments: {:?}", opts);
 
-    let config = Config::from(opts);
+    let mut config = Config::from(opts);
+    config.settings = settings;
     trace!("Daemon configuration: {:?}", config);
     debug!("CTL socket {}", config.ctl_endpoint);
     debug!("RPC socket {}", config.rpc_endpoint);
     debug!("STORE socket {}", config.store_endpoint);
     debug!("STORM socket {}", config.storm_endpoint);
 
-    /*
-    use self::internal::ResultExt;
-    let (config_from_file, _) =
-        internal::Config::custom_args_and_optional_files(std::iter::empty::<
-            &str,
-        >())
-        .unwrap_or_exit();
-     */
-
     debug!("Starting runtime ...");
     rgbd::run(config).expect("running rgbd runtime");
 
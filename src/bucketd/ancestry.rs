@@ -0,0 +1,148 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use amplify::Wrapper;
+use rgb::{ContractId, Node, NodeId};
+
+use super::pool::StorePool;
+use crate::db::{self, Store, StoreRpcExt};
+use crate::DaemonError;
+
+/// Parents of the contract state transitions. Each transition has its own
+/// store entry keyed by the transition id, which is written once the
+/// transition is accepted, so indexing new transitions never rewrites the
+/// entries of the known ones and the index size is not limited.
+///
+/// Allows to find all transitions on the path from a set of endpoints to
+/// genesis with a single store request per history level.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub(crate) struct ContractAncestry(BTreeMap<NodeId, BTreeSet<NodeId>>);
+
+impl ContractAncestry {
+    /// Adds transition to the index.
+    pub fn index(store: &mut dyn Store, node: &impl Node) -> Result<(), DaemonError> {
+        let parents: BTreeSet<NodeId> =
+            node.parent_outputs().into_iter().map(|out| out.node_id).collect();
+        store.store_sten(db::ANCESTRY, node.node_id(), &parents)?;
+        Ok(())
+    }
+
+    /// Loads index entries of the given transitions. Transitions which are
+    /// not indexed (for instance, they were accepted before the index was
+    /// introduced) are skipped.
+    pub fn load(
+        store: &mut dyn Store,
+        node_ids: impl IntoIterator<Item = NodeId>,
+    ) -> Result<ContractAncestry, DaemonError> {
+        let mut ancestry = bmap! {};
+        for node_id in node_ids {
+            if let Some(parents) = store.retrieve_sten(db::ANCESTRY, node_id)? {
+                ancestry.insert(node_id, parents);
+            }
+        }
+        Ok(ContractAncestry(ancestry))
+    }
+
+    /// Returns all ancestors of the given nodes, excluding genesis and the
+    /// `known` nodes with their ancestors, or `None` if some of the
+    /// transitions on the path are not indexed.
+    pub fn ancestors(
+        pool: &mut StorePool,
+        contract_id: ContractId,
+        nodes: impl IntoIterator<Item = NodeId>,
+        known: &BTreeSet<NodeId>,
+    ) -> Result<Option<BTreeSet<NodeId>>, DaemonError> {
+        let mut ancestors = bset! {};
+        let mut level = nodes.into_iter().collect::<BTreeSet<_>>();
+        loop {
+            level.retain(|node_id| {
+                node_id.as_inner() != contract_id.as_inner()
+                    && !ancestors.contains(node_id)
+                    && !known.contains(node_id)
+            });
+            if level.is_empty() {
+                return Ok(Some(ancestors));
+            }
+            let node_ids = level.iter().copied().collect::<Vec<_>>();
+            let entries =
+                pool.retrieve_all::<_, BTreeSet<NodeId>>(db::ANCESTRY, node_ids.clone())?;
+            ancestors.extend(node_ids);
+            let mut parents = bset! {};
+            for entry in entries {
+                match entry {
+                    Some(entry) => parents.extend(entry),
+                    None => return Ok(None),
+                }
+            }
+            level = parents;
+        }
+    }
+
+    /// Returns transitions which are not parents of any other indexed
+    /// transition. Together with genesis they define the whole known contract
+    /// history.
+    pub fn tips(&self) -> BTreeSet<NodeId> {
+        let parents = self.0.values().flatten().collect::<BTreeSet<_>>();
+        self.0.keys().filter(|node_id| !parents.contains(node_id)).copied().collect()
+    }
+    /// Returns the maximal number of transitions on a path from genesis to
+    /// some history tip, or `None` if some of the transitions on the paths
+    /// are not indexed.
+    pub fn depth(&self, contract_id: ContractId) -> Option<u32> {
+        let mut depths: BTreeMap<NodeId, u32> = bmap! {};
+        for node_id in self.0.keys() {
+            let mut stack = vec![*node_id];
+            while let Some(node_id) = stack.last().copied() {
+                if depths.contains_key(&node_id) {
+                    stack.pop();
+                    continue;
+                }
+                let parents = self.0.get(&node_id)?;
+                let pending = parents
+                    .iter()
+                    .filter(|parent| {
+                        parent.as_inner() != contract_id.as_inner() && !depths.contains_key(parent)
+                    })
+                    .copied()
+                    .collect::<Vec<_>>();
+                if pending.is_empty() {
+                    let depth = parents.iter().filter_map(|parent| depths.get(parent)).max();
+                    depths.insert(node_id, depth.copied().unwrap_or_default() + 1);
+                    stack.pop();
+                } else {
+                    stack.extend(pending);
+                }
+            }
+        }
+        Some(depths.into_values().max().unwrap_or_default())
+    }
+
+    /// Expands history tips reported by some other party into the set of all
+    /// transitions known to it. Tips which history is not fully indexed are
+    /// included alone.
+    pub fn known_history(
+        pool: &mut StorePool,
+        contract_id: ContractId,
+        tips: impl IntoIterator<Item = NodeId>,
+    ) -> Result<BTreeSet<NodeId>, DaemonError> {
+        let mut known = bset! {};
+        for tip in tips {
+            match ContractAncestry::ancestors(pool, contract_id, [tip], &known)? {
+                Some(ancestors) => known.extend(ancestors),
+                None => {
+                    known.insert(tip);
+                }
+            }
+        }
+        Ok(known)
+    }
+}
@@ -0,0 +1,161 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::{OutPoint, Script, Txid};
+use bitcoin_onchain::ResolveTx;
+use bp::seals::txout::TxoSeal;
+use rgb::{ContractId, ContractStateMap, Genesis, Node, StateAtom};
+use rgb_rpc::{contract_precision, DecimalAmount, HistoricalBalance};
+
+use super::checkpoint::spent_outputs;
+use super::{Runtime, WalletError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Reads number of decimal digits in the fungible amounts of the contract
+    /// from its genesis.
+    pub(super) fn contract_precision(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<u8, DaemonError> {
+        let genesis: Genesis = self
+            .store
+            .retrieve_sten(db::GENESIS, contract_id)?
+            .ok_or(super::StashError::GenesisAbsent)?;
+        Ok(contract_precision(genesis.metadata()))
+    }
+
+    /// Sums up revealed fungible amounts of each contract in the outpoint
+    /// state, formatting them with the contract precision. Contracts without
+    /// fungible state are skipped.
+    pub(super) fn decimal_amounts(
+        &mut self,
+        state: &ContractStateMap,
+    ) -> Result<BTreeMap<ContractId, DecimalAmount>, DaemonError> {
+        let mut amounts = bmap! {};
+        for (contract_id, outpoint_state) in state {
+            let values = outpoint_state
+                .values()
+                .flatten()
+                .filter_map(|assigned| match assigned.state {
+                    StateAtom::Value(revealed) => Some(revealed.value),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if values.is_empty() {
+                continue;
+            }
+            let total = values.into_iter().fold(0u64, u64::saturating_add);
+            let precision = self.contract_precision(*contract_id)?;
+            amounts.insert(*contract_id, DecimalAmount::with(total, precision));
+        }
+        Ok(amounts)
+    }
+
+    /// Computes revealed amounts of the contract allocated at the given height
+    /// to the outpoints or to the outputs of the wallet scripts, by replaying
+    /// the contract history mined at or below that height. Empty outpoint set
+    /// and no wallet mean all known allocations.
+    ///
+    /// Genesis allocations are always accounted, since the genesis has no
+    /// witness transaction defining its height.
+    pub(super) fn historical_balance(
+        &mut self,
+        contract_id: ContractId,
+        height: u32,
+        descriptor: Option<String>,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<HistoricalBalance, DaemonError> {
+        let scripts = match descriptor {
+            Some(ref descriptor) => self.wallet_scripts(descriptor)?,
+            None => bset! {},
+        };
+        let genesis: Genesis = self
+            .store
+            .retrieve_sten(db::GENESIS, contract_id)?
+            .ok_or(super::StashError::GenesisAbsent)?;
+
+        self.chain_cache.sync_tip(&self.electrum, &mut self.store)?;
+        let mined = self.mined_transitions(contract_id, height)?;
+        let spent = spent_outputs(&mined);
+        let nodes = mined
+            .iter()
+            .map(|(transition, witness_txid)| {
+                (transition.node_id(), transition.owned_rights(), Some(*witness_txid))
+            })
+            .chain(Some((genesis.node_id(), genesis.owned_rights(), None)));
+
+        let unfiltered = outpoints.is_empty() && scripts.is_empty();
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let mut tx_scripts: BTreeMap<Txid, Vec<Script>> = bmap! {};
+        let mut allocations: BTreeMap<OutPoint, u64> = bmap! {};
+        for (node_id, owned_rights, witness_txid) in nodes {
+            for (owned_type, assignments) in owned_rights.iter() {
+                let value_assignments = assignments.to_value_assignments();
+                for (index, assignment) in value_assignments.into_iter().enumerate() {
+                    if spent.contains(&(node_id, *owned_type, index as u16)) {
+                        continue;
+                    }
+                    let (seal, value) =
+                        match (assignment.revealed_seal(), assignment.as_revealed_state()) {
+                            (Some(seal), Some(state)) => (seal, state.value),
+                            _ => continue,
+                        };
+                    let outpoint = match (witness_txid, seal.txid) {
+                        (Some(witness_txid), _) => seal.outpoint_or(witness_txid),
+                        (None, Some(txid)) => OutPoint::new(txid, seal.vout),
+                        (None, None) => continue,
+                    };
+
+                    let owned = if unfiltered || outpoints.contains(&outpoint) {
+                        true
+                    } else if scripts.is_empty() {
+                        false
+                    } else {
+                        if !tx_scripts.contains_key(&outpoint.txid) {
+                            let tx = resolver
+                                .resolve_tx(outpoint.txid)
+                                .map_err(|_| WalletError::UnresolvedTx(outpoint.txid))?;
+                            let outputs = tx.output.into_iter().map(|out| out.script_pubkey);
+                            tx_scripts.insert(outpoint.txid, outputs.collect());
+                        }
+                        tx_scripts[&outpoint.txid]
+                            .get(outpoint.vout as usize)
+                            .map(|script| scripts.contains(script))
+                            .unwrap_or_default()
+                    };
+                    if owned {
+                        *allocations.entry(outpoint).or_default() += value;
+                    }
+                }
+            }
+        }
+
+        debug!(
+            "Contract {} at height {} has {} allocation(s) matching the request",
+            contract_id,
+            height,
+            allocations.len()
+        );
+        let precision = contract_precision(genesis.metadata());
+        Ok(HistoricalBalance {
+            contract_id,
+            height,
+            precision,
+            allocations: allocations
+                .into_iter()
+                .map(|(outpoint, value)| (outpoint, DecimalAmount::with(value, precision)))
+                .collect(),
+        })
+    }
+}
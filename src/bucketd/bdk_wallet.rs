@@ -0,0 +1,231 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Embedded BDK wallets for the wallets registered in the node configuration.
+//!
+//! Registered wallets are synchronized by BDK instead of the node own address
+//! discovery, BDK coin selection chooses UTXOs paying the payment fees and, if
+//! the registered descriptor has private keys, BDK signs the PSBTs published
+//! through the node. Wallets keep no data between the node restarts: the
+//! chain state is re-discovered on each synchronization and the RGB state of
+//! the wallet outpoints is always taken from the stash.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bdk::blockchain::{ConfigurableBlockchain, ElectrumBlockchain, ElectrumBlockchainConfig};
+use bdk::database::{Database, MemoryDatabase};
+use bdk::wallet::coin_selection::{BranchAndBoundCoinSelection, CoinSelectionAlgorithm};
+use bdk::{FeeRate, KeychainKind, LocalUtxo, SignOptions, SyncOptions, Utxo, WeightedUtxo};
+use bitcoin::psbt::PartiallySignedTransaction;
+use bitcoin::secp256k1::SECP256K1;
+use bitcoin::{OutPoint, TxOut};
+use miniscript::descriptor::DescriptorPublicKey;
+use miniscript::Descriptor;
+use rgb_rpc::WalletUtxo;
+use storm::ChunkId;
+
+use super::wallet::{multipath, network, wallet_key};
+use super::{WalletError, WalletState};
+use crate::Config;
+
+/// Timeout for electrum server connection and requests used by BDK, in
+/// seconds
+const BDK_ELECTRUM_TIMEOUT: u8 = 4;
+
+type Wallet = bdk::Wallet<MemoryDatabase>;
+
+impl From<bdk::Error> for WalletError {
+    fn from(err: bdk::Error) -> Self { WalletError::Bdk(err.to_string()) }
+}
+
+/// BDK wallets indexed by the key of the wallet public descriptor, which is
+/// used by the node clients to refer the wallet
+pub(crate) struct BdkWallets {
+    electrum_urls: Vec<String>,
+    wallets: BTreeMap<ChunkId, Wallet>,
+}
+
+impl BdkWallets {
+    /// Creates BDK wallets for the descriptors registered in the node
+    /// configuration
+    pub fn load(config: &Config) -> Result<BdkWallets, WalletError> {
+        let mut wallets = bmap! {};
+        if !config.bdk_wallets.is_empty() {
+            let network = network(&config.chain)?;
+            for descriptor in &config.bdk_wallets {
+                let variants = multipath(descriptor);
+                let keychains = variants
+                    .iter()
+                    .map(|s| {
+                        Descriptor::<DescriptorPublicKey>::parse_descriptor(SECP256K1, s)
+                            .map(|(keychain, _)| keychain)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| WalletError::InvalidDescriptor(err.to_string()))?;
+                let key = wallet_key(&keychains);
+                let wallet = Wallet::new(
+                    variants[0].as_str(),
+                    variants.get(1).map(String::as_str),
+                    network,
+                    MemoryDatabase::new(),
+                )?;
+                debug!("BDK wallet is created for {}", keychains[0]);
+                wallets.insert(key, wallet);
+            }
+        }
+        Ok(BdkWallets {
+            electrum_urls: config.electrum_urls.clone(),
+            wallets,
+        })
+    }
+
+    /// Checks whether the wallet with the given key is registered
+    pub fn contains(&self, key: ChunkId) -> bool { self.wallets.contains_key(&key) }
+
+    /// Synchronizes registered wallet with BDK, returning its state in the
+    /// form used by the node
+    pub fn sync(&self, key: ChunkId, gap_limit: u16) -> Result<WalletState, WalletError> {
+        let wallet = self.wallets.get(&key).ok_or(WalletError::UnknownWallet)?;
+        let blockchain = self.blockchain(gap_limit)?;
+        wallet.sync(&blockchain, SyncOptions::default())?;
+
+        let heights = wallet
+            .list_transactions(false)?
+            .into_iter()
+            .map(|tx| (tx.txid, tx.confirmation_time.map(|time| time.height).unwrap_or_default()))
+            .collect::<BTreeMap<_, _>>();
+        let db = wallet.database();
+        let mut state = WalletState {
+            gap_limit,
+            ..default!()
+        };
+        for keychain in [KeychainKind::External, KeychainKind::Internal] {
+            if let Some(index) = db.get_last_index(keychain)? {
+                state.last_used.insert(keychain_no(keychain), index);
+            }
+        }
+        for utxo in wallet.list_unspent()? {
+            let (keychain, index) =
+                db.get_path_from_script_pubkey(&utxo.txout.script_pubkey)?.ok_or_else(|| {
+                    WalletError::Bdk(format!("unknown derivation of {}", utxo.outpoint))
+                })?;
+            state.utxos.insert(WalletUtxo {
+                outpoint: utxo.outpoint,
+                value: utxo.txout.value,
+                keychain: keychain_no(keychain),
+                index,
+                height: heights.get(&utxo.outpoint.txid).copied().unwrap_or_default(),
+                confirmations: 0,
+            });
+        }
+        Ok(state)
+    }
+
+    /// Selects UTXOs adding at least `target` sats to the payment inputs with
+    /// BDK coin selection. If the candidates can't cover the target, all of
+    /// them are returned, such that the caller reports the funds shortage.
+    pub fn select(
+        &self,
+        key: ChunkId,
+        candidates: &[&WalletUtxo],
+        target: u64,
+    ) -> Result<BTreeSet<OutPoint>, WalletError> {
+        let wallet = self.wallets.get(&key).ok_or(WalletError::UnknownWallet)?;
+        let optional = candidates
+            .iter()
+            .map(|utxo| {
+                let keychain = match utxo.keychain {
+                    0 => KeychainKind::External,
+                    _ => KeychainKind::Internal,
+                };
+                let descriptor = wallet.get_descriptor_for_keychain(keychain);
+                let satisfaction_weight = descriptor
+                    .max_satisfaction_weight()
+                    .map_err(|err| WalletError::InvalidDescriptor(err.to_string()))?;
+                let script_pubkey = descriptor.at_derivation_index(utxo.index).script_pubkey();
+                Ok(WeightedUtxo {
+                    satisfaction_weight,
+                    utxo: Utxo::Local(LocalUtxo {
+                        outpoint: utxo.outpoint,
+                        txout: TxOut {
+                            value: utxo.value,
+                            script_pubkey,
+                        },
+                        keychain,
+                        is_spent: false,
+                    }),
+                })
+            })
+            .collect::<Result<Vec<_>, WalletError>>()?;
+        let drain_script = wallet
+            .get_descriptor_for_keychain(KeychainKind::Internal)
+            .at_derivation_index(0)
+            .script_pubkey();
+        // Payment fee is already included into the target, so the coin
+        // selection is not asked to account for the fee rate
+        let res = BranchAndBoundCoinSelection::default().coin_select(
+            &*wallet.database(),
+            vec![],
+            optional,
+            FeeRate::from_sat_per_vb(0.0),
+            target,
+            &drain_script,
+        );
+        match res {
+            Ok(res) => Ok(res.selected.iter().map(Utxo::outpoint).collect()),
+            Err(bdk::Error::InsufficientFunds { .. }) => {
+                Ok(candidates.iter().map(|utxo| utxo.outpoint).collect())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Signs PSBT inputs with the private keys of the registered wallets.
+    /// Inputs not belonging to the wallets with private keys are left intact.
+    pub fn sign(&self, psbt: &mut PartiallySignedTransaction) -> Result<(), WalletError> {
+        for wallet in self.wallets.values() {
+            let options = SignOptions {
+                trust_witness_utxo: true,
+                try_finalize: false,
+                ..default!()
+            };
+            if wallet.sign(psbt, options)? {
+                debug!("PSBT is signed by BDK wallet");
+            }
+        }
+        Ok(())
+    }
+
+    fn blockchain(&self, gap_limit: u16) -> Result<ElectrumBlockchain, WalletError> {
+        let mut errors = vec![];
+        for url in &self.electrum_urls {
+            let config = ElectrumBlockchainConfig {
+                url: url.clone(),
+                socks5: None,
+                retry: 0,
+                timeout: Some(BDK_ELECTRUM_TIMEOUT),
+                stop_gap: gap_limit.max(1) as usize,
+                validate_domain: true,
+            };
+            match ElectrumBlockchain::from_config(&config) {
+                Ok(blockchain) => return Ok(blockchain),
+                Err(err) => errors.push(format!("{}: {}", url, err)),
+            }
+        }
+        Err(WalletError::Electrum(errors.join("; ")))
+    }
+}
+
+fn keychain_no(keychain: KeychainKind) -> u8 {
+    match keychain {
+        KeychainKind::External => 0,
+        KeychainKind::Internal => 1,
+    }
+}
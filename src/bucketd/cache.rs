@@ -0,0 +1,102 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use rgb::{ContractId, ContractState};
+
+use super::views;
+use crate::db::{self, Store, StoreRpcExt};
+use crate::lru::LruCache;
+use crate::DaemonError;
+
+/// In-memory cache of deserialized contract states.
+///
+/// Each bucket daemon keeps its own cache, so the states updated by one daemon
+/// must be invalidated in all others: the ids of the contracts updated since
+/// the last call to [`StateCache::take_updated`] are collected for this
+/// purpose.
+pub(crate) struct StateCache {
+    states: LruCache<ContractId, ContractState>,
+    updated: BTreeSet<ContractId>,
+    hits: u64,
+    misses: u64,
+}
+
+impl StateCache {
+    pub fn with_limit(limit: usize) -> Self {
+        StateCache {
+            states: LruCache::with_limit(limit),
+            updated: empty!(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns contract state from the cache, loading it from the store on
+    /// cache miss.
+    pub fn get(
+        &mut self,
+        store: &mut dyn Store,
+        contract_id: ContractId,
+    ) -> Result<Option<ContractState>, DaemonError> {
+        if let Some(state) = self.states.get(&contract_id) {
+            self.hits += 1;
+            return Ok(Some(state.clone()));
+        }
+        self.misses += 1;
+        let state: Option<ContractState> = store.retrieve_sten(db::CONTRACTS, contract_id)?;
+        if let Some(ref state) = state {
+            self.states.insert(contract_id, state.clone());
+        }
+        Ok(state)
+    }
+
+    /// Saves contract state to the store, updating the cache and the state
+    /// views.
+    pub fn store(
+        &mut self,
+        store: &mut dyn Store,
+        contract_id: ContractId,
+        state: &ContractState,
+    ) -> Result<(), DaemonError> {
+        // If the store fails we must not keep outdated state
+        let prev = match self.states.remove(&contract_id) {
+            Some(prev) => Some(prev),
+            None => store.retrieve_sten(db::CONTRACTS, contract_id)?,
+        };
+        self.updated.insert(contract_id);
+        store.store_sten(db::CONTRACTS, contract_id, state)?;
+        views::update(store, contract_id, prev.as_ref(), state)?;
+        self.states.insert(contract_id, state.clone());
+        Ok(())
+    }
+
+    /// Drops state of the forgotten contract, such that it gets dropped by
+    /// other daemons as well. The state must be removed from the store by the
+    /// caller.
+    pub fn forget(&mut self, contract_id: ContractId) {
+        self.states.remove(&contract_id);
+        self.updated.insert(contract_id);
+    }
+
+    /// Drops contract states updated by other daemons.
+    pub fn invalidate(&mut self, contract_ids: &BTreeSet<ContractId>) {
+        for contract_id in contract_ids {
+            self.states.remove(contract_id);
+        }
+    }
+
+    /// Returns ids of the contracts which states were updated since the last
+    /// call.
+    pub fn take_updated(&mut self) -> BTreeSet<ContractId> { std::mem::take(&mut self.updated) }
+
+    pub fn stats(&self) -> (u64, u64) { (self.hits, self.misses) }
+}
@@ -0,0 +1,495 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use bitcoin::{OutPoint, Transaction, Txid, XOnlyPublicKey};
+use bitcoin_onchain::ResolveTx;
+use bp::seals::txout::TxoSeal;
+use commit_verify::{lnpbp4, CommitConceal};
+use rgb::schema::{OwnedRightType, SchemaVerify};
+use rgb::validation::{Failure, Status, Warning};
+use rgb::{
+    Anchor, BundleId, Consignment, ContractId, Genesis, Node, NodeId, Schema, Transition,
+    TransitionBundle, TypedAssignments, Validity,
+};
+use rgb_rpc::{Checkpoint, FailureCode, SignedCheckpoint};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::Runtime;
+use crate::db::{self, Store, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum CheckpointError {
+    /// checkpoint signature does not match the checkpoint data.
+    InvalidSignature,
+
+    /// checkpoint issuer {1} is not trusted by the node for contract {0}.
+    UntrustedIssuer(ContractId, XOnlyPublicKey),
+
+    /// checkpoint height {0} is above the current chain tip; only mined
+    /// transitions can be checkpointed.
+    HeightAboveTip(u32),
+
+    /// unable to check whether witness transaction {1} of the state transition
+    /// {0} is mined.
+    UnknownWitness(NodeId, Txid),
+}
+
+impl From<&CheckpointError> for FailureCode {
+    fn from(err: &CheckpointError) -> Self {
+        match err {
+            CheckpointError::InvalidSignature => FailureCode::InvalidSignature,
+            CheckpointError::UntrustedIssuer(..) => FailureCode::UntrustedIssuer,
+            CheckpointError::HeightAboveTip(_) => FailureCode::Checkpoint,
+            CheckpointError::UnknownWitness(..) => FailureCode::ChainBackend,
+        }
+    }
+}
+
+impl Runtime {
+    /// Collects transitions holding the contract state at the given height.
+    /// The returned checkpoint must be signed by the issuer before it can be
+    /// imported by other nodes.
+    pub(super) fn create_checkpoint(
+        &mut self,
+        contract_id: ContractId,
+        height: u32,
+    ) -> Result<Checkpoint, DaemonError> {
+        self.chain_cache.sync_tip(&self.electrum, &mut self.store)?;
+        match self.chain_cache.tip_height() {
+            Some(tip) if tip >= height => {}
+            _ => return Err(CheckpointError::HeightAboveTip(height).into()),
+        }
+
+        let mined = self.mined_transitions(contract_id, height)?;
+
+        // Transitions which assignments are all spent below the checkpoint height do not
+        // contribute to the contract state
+        let spent = spent_outputs(&mined);
+        let frontier = mined
+            .into_iter()
+            .filter(|(transition, _)| {
+                let node_id = transition.node_id();
+                transition.owned_rights().iter().any(|(owned_type, assignments)| {
+                    (0..assignments.len() as u16)
+                        .any(|index| !spent.contains(&(node_id, *owned_type, index)))
+                })
+            })
+            .collect::<Vec<_>>();
+        debug!(
+            "Checkpoint for contract {} at height {} has {} transitions",
+            contract_id,
+            height,
+            frontier.len()
+        );
+
+        Ok(Checkpoint {
+            contract_id,
+            height,
+            frontier,
+        })
+    }
+
+    /// Returns contract transitions which witness transactions are mined at or
+    /// below the given height, together with the witness transaction ids.
+    pub(super) fn mined_transitions(
+        &mut self,
+        contract_id: ContractId,
+        height: u32,
+    ) -> Result<Vec<(Transition, Txid)>, DaemonError> {
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let transitions =
+            self.store_pool.retrieve_all::<_, Transition>(db::TRANSITIONS, node_ids.clone())?;
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+
+        let mut mined = vec![];
+        let records = node_ids.into_iter().zip(transitions).zip(witnesses);
+        for ((node_id, transition), witness_txid) in records {
+            let transition = transition.ok_or(super::StashError::TransitionAbsent(node_id))?;
+            let witness_txid =
+                witness_txid.ok_or(super::StashError::TransitionTxidAbsent(node_id))?;
+            match self.chain_cache.tx_height(&self.electrum, &mut self.store, witness_txid) {
+                Ok(Some(mined_at)) if mined_at <= height => mined.push((transition, witness_txid)),
+                Ok(_) => {}
+                Err(_) => return Err(CheckpointError::UnknownWitness(node_id, witness_txid).into()),
+            }
+        }
+        Ok(mined)
+    }
+
+    pub(super) fn contract_transitions(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<BTreeSet<NodeId>, DaemonError> {
+        contract_transitions(self.store.as_mut(), contract_id)
+    }
+}
+
+/// Lists all state transitions of the contract kept in the stash.
+pub(crate) fn contract_transitions(
+    store: &mut dyn Store,
+    contract_id: ContractId,
+) -> Result<BTreeSet<NodeId>, DaemonError> {
+    let genesis: Genesis =
+        store.retrieve_sten(db::GENESIS, contract_id)?.ok_or(super::StashError::GenesisAbsent)?;
+    let schema_id = genesis.schema_id();
+    let schema: Schema = store
+        .retrieve_sten(db::SCHEMATA, schema_id)?
+        .ok_or(super::StashError::SchemaAbsent(schema_id))?;
+    let mut node_ids = bset! {};
+    for transition_type in schema.transitions.keys() {
+        let chunk_id = ChunkId::with_fixed_fragments(contract_id, *transition_type);
+        let ids: BTreeSet<NodeId> =
+            store.retrieve_sten(db::CONTRACT_TRANSITIONS, chunk_id)?.unwrap_or_default();
+        node_ids.extend(ids);
+    }
+    Ok(node_ids)
+}
+
+/// Returns outputs of the contract nodes spent by the given transitions.
+pub(super) fn spent_outputs(
+    transitions: &[(Transition, Txid)],
+) -> BTreeSet<(NodeId, OwnedRightType, u16)> {
+    transitions
+        .iter()
+        .flat_map(|(transition, _)| transition.parent_owned_rights().iter())
+        .flat_map(|(node_id, assignments)| {
+            assignments.iter().flat_map(move |(owned_type, indexes)| {
+                indexes.iter().map(move |index| (*node_id, *owned_type, *index))
+            })
+        })
+        .collect()
+}
+
+/// Checks that the checkpoint is signed by one of the issuers trusted for its
+/// contract. Keys trusted for other contracts are rejected.
+pub(crate) fn verify_checkpoint(
+    checkpoint: &SignedCheckpoint,
+    trusted_issuers: &BTreeMap<ContractId, BTreeSet<XOnlyPublicKey>>,
+) -> Result<(), CheckpointError> {
+    let contract_id = checkpoint.checkpoint.contract_id;
+    if !trusted_issuers
+        .get(&contract_id)
+        .map(|issuers| issuers.contains(&checkpoint.issuer))
+        .unwrap_or_default()
+    {
+        return Err(CheckpointError::UntrustedIssuer(contract_id, checkpoint.issuer));
+    }
+    if !checkpoint.verify(&bitcoin::secp256k1::Secp256k1::verification_only()) {
+        return Err(CheckpointError::InvalidSignature);
+    }
+    Ok(())
+}
+
+/// Consignment validator which trusts the checkpoint transitions and checks
+/// only the part of the history which follows them.
+///
+/// Performs the same checks as [`rgb::Validator`] for each of the validated
+/// transitions, but stops walking the history once it reaches a checkpoint
+/// transition, which is used as a source of the parent state instead.
+pub(super) struct FastForward<'consignment, 'resolver, R: ResolveTx> {
+    status: Status,
+    contract_id: ContractId,
+    genesis_id: NodeId,
+    node_index: BTreeMap<NodeId, &'consignment dyn Node>,
+    anchor_index: BTreeMap<NodeId, &'consignment Anchor<lnpbp4::MerkleProof>>,
+    witness_index: BTreeMap<NodeId, Txid>,
+    trusted: BTreeSet<NodeId>,
+    end_transitions: Vec<(&'consignment Transition, BundleId)>,
+    validation_index: BTreeSet<NodeId>,
+    resolver: &'resolver R,
+}
+
+impl<'consignment, 'resolver, R: ResolveTx> FastForward<'consignment, 'resolver, R> {
+    pub fn validate<C: Consignment<'consignment>>(
+        consignment: &'consignment C,
+        checkpoint: &'consignment Checkpoint,
+        resolver: &'resolver R,
+    ) -> Status {
+        let mut validator = FastForward::init(consignment, checkpoint, resolver);
+
+        let schema = consignment.schema();
+        if let Some(root) = consignment.root_schema() {
+            validator.status += schema.schema_verify(root);
+        } else if schema.root_id != default!() {
+            validator.status.add_failure(Failure::SchemaRootRequired(schema.root_id));
+        }
+        let schema_id = consignment.genesis().schema_id();
+        if schema.schema_id() != schema_id {
+            validator.status.add_failure(Failure::SchemaUnknown(schema_id));
+        }
+        if validator.status.validity() == Validity::Invalid {
+            return validator.status;
+        }
+
+        validator.status +=
+            schema.validate(&validator.node_index, consignment.genesis(), &schema.script);
+        validator.validation_index.insert(validator.genesis_id);
+        for (transition, bundle_id) in validator.end_transitions.clone() {
+            validator.validate_branch(schema, transition, bundle_id);
+        }
+        validator.report_unmined_endpoints();
+        validator.status
+    }
+
+    fn init<C: Consignment<'consignment>>(
+        consignment: &'consignment C,
+        checkpoint: &'consignment Checkpoint,
+        resolver: &'resolver R,
+    ) -> Self {
+        let mut status = Status::default();
+        let genesis_id = consignment.genesis().node_id();
+
+        let mut node_index = BTreeMap::<NodeId, &dyn Node>::new();
+        let mut anchor_index = BTreeMap::new();
+        let mut witness_index = BTreeMap::new();
+        let mut bundles = BTreeMap::<BundleId, &TransitionBundle>::new();
+        for (anchor, bundle) in consignment.anchored_bundles() {
+            if !TransitionBundle::validate(bundle) {
+                status.add_failure(Failure::BundleInvalid(bundle.bundle_id()));
+            }
+            bundles.insert(bundle.bundle_id(), bundle);
+            for transition in bundle.known_transitions() {
+                let node_id = transition.node_id();
+                node_index.insert(node_id, transition);
+                anchor_index.insert(node_id, anchor);
+                witness_index.insert(node_id, anchor.txid);
+            }
+        }
+        node_index.insert(genesis_id, consignment.genesis());
+        for extension in consignment.state_extensions() {
+            node_index.insert(extension.node_id(), extension);
+        }
+        // Checkpoint transitions replace whatever the consignment provides for them
+        let mut trusted = bset! {};
+        for (transition, witness_txid) in &checkpoint.frontier {
+            let node_id = transition.node_id();
+            node_index.insert(node_id, transition);
+            anchor_index.remove(&node_id);
+            witness_index.insert(node_id, *witness_txid);
+            trusted.insert(node_id);
+        }
+
+        let mut end_transitions = Vec::<(&Transition, BundleId)>::new();
+        for (bundle_id, seal_endpoint) in consignment.endpoints() {
+            let bundle = match bundles.get(bundle_id) {
+                Some(bundle) => bundle,
+                None => {
+                    status.add_failure(Failure::BundleInvalid(*bundle_id));
+                    continue;
+                }
+            };
+            for transition in bundle.known_transitions() {
+                let node_id = transition.node_id();
+                if !transition.to_confiential_seals().contains(&seal_endpoint.commit_conceal()) {
+                    status.add_warning(Warning::EndpointTransitionSealNotFound(
+                        node_id,
+                        *seal_endpoint,
+                    ));
+                }
+                if end_transitions.iter().any(|(t, _)| t.node_id() == node_id) {
+                    status.add_warning(Warning::EndpointDuplication(node_id, *seal_endpoint));
+                } else {
+                    end_transitions.push((transition, *bundle_id));
+                }
+            }
+        }
+
+        FastForward {
+            status,
+            contract_id: consignment.genesis().contract_id(),
+            genesis_id,
+            node_index,
+            anchor_index,
+            witness_index,
+            trusted,
+            end_transitions,
+            validation_index: empty!(),
+            resolver,
+        }
+    }
+
+    fn validate_branch(
+        &mut self,
+        schema: &Schema,
+        transition: &'consignment Transition,
+        bundle_id: BundleId,
+    ) {
+        let mut queue: VecDeque<&dyn Node> = VecDeque::new();
+        queue.push_back(transition);
+        while let Some(node) = queue.pop_front() {
+            let node_id = node.node_id();
+            if self.trusted.contains(&node_id) || !self.validation_index.insert(node_id) {
+                continue;
+            }
+
+            self.status += schema.validate(&self.node_index, node, &schema.script);
+
+            if let Some(anchor) = self.anchor_index.get(&node_id).copied() {
+                if anchor.convolve(self.contract_id, bundle_id.into()).is_err() {
+                    self.status.add_failure(Failure::TransitionNotInAnchor(node_id, anchor.txid));
+                }
+                self.validate_witness(node, bundle_id, anchor);
+            } else if node.transition_type().is_some() {
+                self.status.add_failure(Failure::TransitionNotAnchored(node_id));
+            }
+
+            let parents = node
+                .parent_owned_rights()
+                .iter()
+                .map(|(id, _)| *id)
+                .chain(node.parent_public_rights().iter().map(|(id, _)| *id))
+                .collect::<Vec<_>>();
+            for parent_id in parents {
+                match self.node_index.get(&parent_id) {
+                    Some(parent) => queue.push_back(*parent),
+                    None => {
+                        self.status.add_failure(Failure::TransitionAbsent(parent_id));
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_witness(
+        &mut self,
+        node: &dyn Node,
+        bundle_id: BundleId,
+        anchor: &Anchor<lnpbp4::MerkleProof>,
+    ) {
+        let txid = anchor.txid;
+        let node_id = node.node_id();
+        let witness_tx = match self.resolver.resolve_tx(txid) {
+            Ok(witness_tx) => witness_tx,
+            Err(_) => {
+                self.status.unresolved_txids.push(txid);
+                self.status.add_failure(Failure::WitnessTransactionMissed(txid));
+                return;
+            }
+        };
+
+        if anchor.verify(self.contract_id, bundle_id.into(), witness_tx.clone()).is_err() {
+            self.status.add_failure(Failure::WitnessNoCommitment(node_id, txid));
+        }
+
+        for (ancestor_id, assignments) in node.parent_owned_rights().iter() {
+            let ancestor_id = *ancestor_id;
+            let ancestor = match self.node_index.get(&ancestor_id) {
+                Some(ancestor) => *ancestor,
+                None => {
+                    self.status.add_failure(Failure::TransitionAbsent(ancestor_id));
+                    continue;
+                }
+            };
+            for (assignment_type, indexes) in assignments {
+                let assignment_type = *assignment_type;
+                let variant = match ancestor.owned_rights_by_type(assignment_type) {
+                    Some(variant) => variant,
+                    None => {
+                        self.status.add_failure(Failure::TransitionParentWrongSealType {
+                            node_id,
+                            ancestor_id,
+                            assignment_type,
+                        });
+                        continue;
+                    }
+                };
+                for seal_index in indexes {
+                    self.validate_input(
+                        &witness_tx,
+                        node_id,
+                        ancestor_id,
+                        assignment_type,
+                        variant,
+                        *seal_index,
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_input(
+        &mut self,
+        witness_tx: &Transaction,
+        node_id: NodeId,
+        ancestor_id: NodeId,
+        assignment_type: OwnedRightType,
+        variant: &TypedAssignments,
+        seal_index: u16,
+    ) {
+        let outpoint =
+            match (variant.revealed_seal_at(seal_index), self.witness_index.get(&ancestor_id)) {
+                (Err(_), _) => {
+                    self.status.add_failure(Failure::TransitionParentWrongSeal {
+                        node_id,
+                        ancestor_id,
+                        assignment_type,
+                        seal_index,
+                    });
+                    return;
+                }
+                (Ok(None), _) => {
+                    self.status.add_failure(Failure::TransitionParentConfidentialSeal {
+                        node_id,
+                        ancestor_id,
+                        assignment_type,
+                        seal_index,
+                    });
+                    return;
+                }
+                (Ok(Some(seal)), Some(witness_txid)) => seal.outpoint_or(*witness_txid),
+                // Genesis seals always contain txid
+                (Ok(Some(seal)), None) => match seal.txid {
+                    Some(txid) => OutPoint::new(txid, seal.vout),
+                    None => {
+                        self.status.add_failure(Failure::TransitionParentWrongSeal {
+                            node_id,
+                            ancestor_id,
+                            assignment_type,
+                            seal_index,
+                        });
+                        return;
+                    }
+                },
+            };
+        if !witness_tx.input.iter().any(|txin| txin.previous_output == outpoint) {
+            self.status.add_failure(Failure::TransitionParentIsNotWitnessInput {
+                node_id,
+                ancestor_id,
+                assignment_type,
+                seal_index,
+                outpoint,
+            });
+        }
+    }
+
+    /// Replaces failures for not yet mined endpoint witness transactions with
+    /// warnings, in the same way as [`rgb::Validator`] does.
+    fn report_unmined_endpoints(&mut self) {
+        for (transition, _) in &self.end_transitions {
+            let txid = match self.anchor_index.get(&transition.node_id()) {
+                Some(anchor) => anchor.txid,
+                None => continue,
+            };
+            let missed = Failure::WitnessTransactionMissed(txid);
+            if let Some(pos) = self.status.failures.iter().position(|f| f == &missed) {
+                self.status.failures.remove(pos);
+                self.status.unresolved_txids.retain(|id| *id != txid);
+                self.status.unmined_endpoint_txids.push(txid);
+                self.status.warnings.push(Warning::EndpointTransactionMissed(txid));
+            }
+        }
+    }
+}
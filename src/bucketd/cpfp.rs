@@ -0,0 +1,225 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Acceleration of the stuck incoming transfers: the payee spends its outputs
+//! of the unconfirmed witness transaction with a child transaction paying fee
+//! for the whole package (child pays for parent, CPFP).
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::{Transaction, Txid};
+use bitcoin_hd::{SegmentIndexes, UnhardenedIndex};
+use bitcoin_scripts::PubkeyScript;
+use psbt::Psbt;
+use rgb::{ConsignmentId, SealEndpoint};
+use rgb_rpc::{CpfpDraft, TransferDirection, TransferRecord, TransferStatus, WalletUtxo};
+
+use super::payment::{account_descriptor, input_descriptor, MIN_CHANGE};
+use super::wallet::{keychains, wallet_key};
+use super::{PaymentError, Runtime, WalletError, WalletState};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Transaction overhead (version, locktime, input and output counts and segwit
+/// marker), in virtual bytes
+pub(super) const TX_OVERHEAD_VSIZE: u64 = 11;
+
+/// Upper estimation of a signed single-key input size, in virtual bytes
+pub(super) const INPUT_VSIZE: u64 = 68;
+
+/// Upper estimation of a change output size, in virtual bytes
+pub(super) const OUTPUT_VSIZE: u64 = 43;
+
+/// Package of the witness transaction of an incoming transfer and a child
+/// transaction accelerating it.
+#[derive(Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct CpfpPackage {
+    pub consignment_id: ConsignmentId,
+    /// Witness transaction of the transfer
+    pub parent: Txid,
+    /// Target fee rate of the package, in sats per virtual byte
+    pub fee_rate: u64,
+    /// Fee paid by the child transaction
+    pub fee: u64,
+    /// Child transaction, once it is published
+    pub child: Option<Txid>,
+}
+
+impl Runtime {
+    /// Constructs PSBT of a child transaction spending wallet outputs of the
+    /// unconfirmed witness transaction of a pending incoming transfer, such that
+    /// the package of both transactions pays the requested fee rate. Wallet
+    /// UTXOs without RGB state are added if the witness outputs can't pay the
+    /// fee.
+    pub(super) fn accelerate_transfer(
+        &mut self,
+        consignment_id: ConsignmentId,
+        descriptor: &str,
+        fee_rate: u64,
+    ) -> Result<CpfpDraft, DaemonError> {
+        let record: TransferRecord = self
+            .store
+            .retrieve_sten(db::TRANSFERS, consignment_id)?
+            .ok_or(PaymentError::UnknownTransfer(consignment_id))?;
+        if record.direction != TransferDirection::Incoming
+            || !matches!(record.status, TransferStatus::Pending | TransferStatus::Accelerated)
+        {
+            return Err(PaymentError::NotPending(consignment_id).into());
+        }
+        let txid = record.txid.ok_or(PaymentError::NoWitnessTx(consignment_id))?;
+        if self
+            .chain_cache
+            .tx_height(&self.electrum, &mut self.store, txid)
+            .map_err(|err| PaymentError::Chain(err.to_string()))?
+            .is_some()
+        {
+            return Err(PaymentError::WitnessMined(txid).into());
+        }
+
+        // 1. Measure the witness transaction
+        let (parent, parent_fee) = self
+            .electrum
+            .call(|client| {
+                let parent = client.transaction_get(&txid)?;
+                let inputs = parent
+                    .input
+                    .iter()
+                    .map(|input| {
+                        let prevout = input.previous_output;
+                        let prev_tx = client.transaction_get(&prevout.txid)?;
+                        prev_tx.output.get(prevout.vout as usize).map(|out| out.value).ok_or_else(
+                            || electrum_client::Error::Message(format!("no output {}", prevout)),
+                        )
+                    })
+                    .sum::<Result<u64, _>>()?;
+                let outputs = parent.output.iter().map(|out| out.value).sum::<u64>();
+                Ok((parent, inputs.saturating_sub(outputs)))
+            })
+            .map_err(|err| PaymentError::Chain(err.to_string()))?;
+        let parent_vsize = (parent.weight() as u64 + 3) / 4;
+        if parent_fee >= fee_rate * parent_vsize {
+            return Err(PaymentError::FeeSufficient {
+                txid,
+                fee_rate: parent_fee / parent_vsize,
+            }
+            .into());
+        }
+
+        // 2. Select wallet outputs of the witness transaction which carry no RGB state
+        let account = account_descriptor(descriptor)?;
+        let key = wallet_key(&keychains(descriptor)?);
+        let wallet: WalletState =
+            self.store.retrieve_sten(db::WALLETS, key)?.ok_or(WalletError::UnknownWallet)?;
+        let beneficiary_vouts = record
+            .beneficiaries
+            .iter()
+            .filter_map(|seal| match seal {
+                SealEndpoint::WitnessVout { vout, .. } => Some(*vout),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        let outpoints = wallet.utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+        let occupied = match outpoints.is_empty() {
+            true => bset! {},
+            false => self
+                .outpoint_state(outpoints)?
+                .into_values()
+                .flat_map(BTreeMap::into_keys)
+                .collect::<BTreeSet<_>>(),
+        };
+        let (mut spent, mut free): (Vec<&WalletUtxo>, Vec<&WalletUtxo>) = wallet
+            .utxos
+            .iter()
+            .filter(|utxo| !occupied.contains(&utxo.outpoint))
+            .partition(|utxo| utxo.outpoint.txid == txid);
+        spent.retain(|utxo| !beneficiary_vouts.contains(&utxo.outpoint.vout));
+        if spent.is_empty() {
+            return Err(PaymentError::NoWitnessOutput(txid).into());
+        }
+
+        // 3. Add other UTXOs until the child can pay for the whole package
+        free.sort_by_key(|utxo| Reverse(utxo.value));
+        let mut free = free.into_iter();
+        let fee = loop {
+            let child_vsize = TX_OVERHEAD_VSIZE + INPUT_VSIZE * spent.len() as u64 + OUTPUT_VSIZE;
+            let fee = (fee_rate * (parent_vsize + child_vsize))
+                .saturating_sub(parent_fee)
+                .max(fee_rate * child_vsize);
+            let required = fee.saturating_add(MIN_CHANGE);
+            let available = spent.iter().map(|utxo| utxo.value).sum::<u64>();
+            if available >= required {
+                break fee;
+            }
+            match free.next() {
+                Some(utxo) => spent.push(utxo),
+                None => {
+                    return Err(PaymentError::InsufficientFunds {
+                        available,
+                        required,
+                    }
+                    .into())
+                }
+            }
+        };
+        debug!(
+            "Accelerating {} with {} sats fee at {} sat/vB using {} UTXOs",
+            txid,
+            fee,
+            fee_rate,
+            spent.len()
+        );
+
+        // 4. Construct PSBT sending everything to the change output
+        let inputs = spent.iter().copied().map(input_descriptor).collect::<Result<Vec<_>, _>>()?;
+        let outputs: &[(PubkeyScript, u64)] = &[];
+        let change_index = wallet.last_used.get(&1).map(|index| index + 1).unwrap_or_default();
+        let change_index = UnhardenedIndex::from_index(change_index)
+            .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let psbt = Psbt::construct(&account, &inputs, outputs, change_index, fee, None, &resolver)
+            .map_err(|err| PaymentError::Construct(err.to_string()))?;
+
+        let package = CpfpPackage {
+            consignment_id,
+            parent: txid,
+            fee_rate,
+            fee,
+            child: None,
+        };
+        self.store.store_sten(db::PACKAGES, consignment_id, &package)?;
+
+        Ok(CpfpDraft {
+            psbt,
+            parent: txid,
+            fee,
+        })
+    }
+
+    /// Detects whether the published transaction is a child of the tracked
+    /// packages and marks the corresponding transfers as accelerated.
+    pub(super) fn packages_published(&mut self, tx: &Transaction) -> Result<(), DaemonError> {
+        let parents =
+            tx.input.iter().map(|input| input.previous_output.txid).collect::<BTreeSet<_>>();
+        for key in self.store.ids(db::PACKAGES)? {
+            let mut package = match self.store.retrieve_sten::<CpfpPackage>(db::PACKAGES, key)? {
+                Some(package) => package,
+                None => continue,
+            };
+            if !parents.contains(&package.parent) {
+                continue;
+            }
+            package.child = Some(tx.txid());
+            self.store.store_sten(db::PACKAGES, key, &package)?;
+            self.transfer_accelerated(package.consignment_id)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Dependency graph of the contracts known to the node. Contracts depend on
+//! each other when one of them uses a subschema of the other contract schema
+//! (or both use subschemata of the same root schema), or when the metadata of
+//! one contract references the other one, like a proof of burn of the assets
+//! replaced by the contract.
+//!
+//! References are detected as 32-byte metadata values matching the id of a
+//! known contract. Since the referenced contract may become known after the
+//! referencing one, candidate references are indexed when the consignment is
+//! stored and are matched against the known contracts once the graph is
+//! requested.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use rgb::{
+    Consignment, ConsignmentType, ContractId, Genesis, InmemConsignment, Metadata, Schema, SchemaId,
+};
+use rgb_rpc::ContractDeps;
+
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Collects metadata values which may be ids of other contracts
+pub(super) fn reference_candidates(
+    metadata: &Metadata,
+    contract_id: ContractId,
+) -> BTreeSet<ContractId> {
+    let mut candidates = bset! {};
+    for field_type in metadata.as_inner().keys() {
+        let bytes = metadata
+            .bytes(*field_type)
+            .into_iter()
+            .filter_map(|bytes| <[u8; 32]>::try_from(bytes.as_slice()).ok());
+        let hashes = metadata.sha256(*field_type).into_iter().map(|hash| hash.into_inner());
+        candidates
+            .extend(bytes.chain(hashes).map(|id| ContractId::from_inner(Hash::from_inner(id))));
+    }
+    candidates.remove(&contract_id);
+    candidates
+}
+
+impl Runtime {
+    /// Adds candidate references to the index of the contracts referenced by
+    /// the contract.
+    pub(super) fn index_references(
+        &mut self,
+        contract_id: ContractId,
+        candidates: BTreeSet<ContractId>,
+    ) -> Result<(), DaemonError> {
+        if candidates.is_empty() {
+            return Ok(());
+        }
+        let mut refs: BTreeSet<ContractId> =
+            self.store.retrieve_sten(db::CONTRACT_REFS, contract_id)?.unwrap_or_default();
+        let count = refs.len();
+        refs.extend(candidates);
+        if refs.len() > count {
+            debug!("Contract {} may reference {} other contracts", contract_id, refs.len());
+            self.store.store_sten(db::CONTRACT_REFS, contract_id, &refs)?;
+        }
+        Ok(())
+    }
+
+    /// Loads the schema together with its root schema, if the schema is a
+    /// subschema.
+    pub(super) fn dependent_schemata(
+        &mut self,
+        schema_id: SchemaId,
+    ) -> Result<(Schema, Option<Schema>), DaemonError> {
+        let schema: Schema = self
+            .store
+            .retrieve_sten(db::SCHEMATA, schema_id)?
+            .ok_or(StashError::SchemaAbsent(schema_id))?;
+        let root_schema_id = schema.root_id;
+        let root_schema = if root_schema_id != zero!() {
+            Some(
+                self.store
+                    .retrieve_sten(db::SCHEMATA, root_schema_id)?
+                    .ok_or(StashError::SchemaAbsent(root_schema_id))?,
+            )
+        } else {
+            None
+        };
+        Ok((schema, root_schema))
+    }
+
+    /// Adds the root schema known to the node to the consignment which
+    /// contract uses a subschema, if the sender has omitted it. Otherwise the
+    /// consignment is returned unchanged, and its validation reports the
+    /// missing root schema.
+    pub(super) fn with_root_schema<C: ConsignmentType>(
+        &mut self,
+        consignment: InmemConsignment<C>,
+    ) -> Result<InmemConsignment<C>, DaemonError> {
+        let root_schema_id = consignment.schema().root_id;
+        if root_schema_id == zero!() || consignment.root_schema().is_some() {
+            return Ok(consignment);
+        }
+        let root_schema: Schema = match self.store.retrieve_sten(db::SCHEMATA, root_schema_id)? {
+            Some(root_schema) => root_schema,
+            None => return Ok(consignment),
+        };
+        debug!("Restoring root schema {} omitted from the consignment", root_schema_id);
+
+        let anchored_bundles = consignment
+            .anchored_bundles()
+            .map(|(anchor, bundle)| (anchor.clone(), bundle.clone()))
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| StashError::Outsizedbundle)?;
+        let state_extensions = consignment
+            .state_extensions()
+            .cloned()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| StashError::Outsizedbundle)?;
+        Ok(InmemConsignment::<C>::with(
+            consignment.schema().clone(),
+            Some(root_schema),
+            consignment.genesis().clone(),
+            consignment.endpoints().cloned().collect(),
+            anchored_bundles,
+            state_extensions,
+        ))
+    }
+
+    /// Builds dependency graph of the contract, consisting of the contract
+    /// and all known contracts connected to it directly or through other
+    /// contracts. If no contract is given, reports all known contracts.
+    pub(super) fn contract_deps(
+        &mut self,
+        contract_id: Option<ContractId>,
+    ) -> Result<Vec<ContractDeps>, DaemonError> {
+        let mut roots: BTreeMap<SchemaId, Option<SchemaId>> = bmap! {};
+        let mut graph: BTreeMap<ContractId, ContractDeps> = bmap! {};
+        for key in self.store.ids(db::GENESIS)? {
+            let id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            // Forgotten contracts have their genesis wiped
+            let genesis = match self.store.retrieve_sten::<Genesis>(db::GENESIS, id)? {
+                Some(genesis) => genesis,
+                None => continue,
+            };
+            let schema_id = genesis.schema_id();
+            let root_schema_id = match roots.get(&schema_id) {
+                Some(root_schema_id) => *root_schema_id,
+                None => {
+                    let root_schema_id = self
+                        .store
+                        .retrieve_sten::<Schema>(db::SCHEMATA, schema_id)?
+                        .map(|schema| schema.root_id)
+                        .filter(|root_id| *root_id != zero!());
+                    roots.insert(schema_id, root_schema_id);
+                    root_schema_id
+                }
+            };
+            graph.insert(id, ContractDeps {
+                contract_id: id,
+                schema_id,
+                root_schema_id,
+                siblings: bset! {},
+                references: bset! {},
+                referenced_by: bset! {},
+            });
+        }
+        if let Some(contract_id) = contract_id {
+            if !graph.contains_key(&contract_id) {
+                return Err(StashError::GenesisAbsent.into());
+            }
+        }
+
+        // Contracts using a subschema share the family with the contracts
+        // using its root schema
+        let mut families: BTreeMap<SchemaId, BTreeSet<ContractId>> = bmap! {};
+        let mut derived = bset! {};
+        for deps in graph.values() {
+            let family = deps.root_schema_id.unwrap_or(deps.schema_id);
+            families.entry(family).or_default().insert(deps.contract_id);
+            if deps.root_schema_id.is_some() {
+                derived.insert(deps.contract_id);
+            }
+        }
+        let mut referenced_by: BTreeMap<ContractId, BTreeSet<ContractId>> = bmap! {};
+        let ids = graph.keys().copied().collect::<BTreeSet<_>>();
+        for (id, deps) in &mut graph {
+            // Contracts of the same schema without subschemata are unrelated
+            deps.siblings = families[&deps.root_schema_id.unwrap_or(deps.schema_id)]
+                .iter()
+                .filter(|sibling| {
+                    *sibling != id && (derived.contains(id) || derived.contains(*sibling))
+                })
+                .copied()
+                .collect();
+            let refs: BTreeSet<ContractId> =
+                self.store.retrieve_sten(db::CONTRACT_REFS, *id)?.unwrap_or_default();
+            deps.references = refs.intersection(&ids).copied().collect();
+            for reference in &deps.references {
+                referenced_by.entry(*reference).or_default().insert(*id);
+            }
+        }
+        for (id, deps) in &mut graph {
+            deps.referenced_by = referenced_by.remove(id).unwrap_or_default();
+        }
+
+        let contract_id = match contract_id {
+            Some(contract_id) => contract_id,
+            None => return Ok(graph.into_values().collect()),
+        };
+        let mut connected = bset! {contract_id};
+        let mut queue = vec![contract_id];
+        while let Some(id) = queue.pop() {
+            let deps = &graph[&id];
+            for next in deps.siblings.iter().chain(&deps.references).chain(&deps.referenced_by) {
+                if connected.insert(*next) {
+                    queue.push(*next);
+                }
+            }
+        }
+        Ok(graph.into_values().filter(|deps| connected.contains(&deps.contract_id)).collect())
+    }
+}
@@ -0,0 +1,94 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use bitcoin::Txid;
+use rgb::{BundleId, ContractId, TransitionBundle};
+use rgb_rpc::{now, HistoryDiff, StashCommitment, StateDigest};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::{ForgottenContract, Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Computes canonical digest of the contract history and state known to
+    /// the node.
+    pub(super) fn state_digest(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<StateDigest, DaemonError> {
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        let bundle_ids = self.contract_bundles(contract_id)?;
+        Ok(StateDigest::compute(contract_id, &bundle_ids, &state))
+    }
+
+    /// Lists transition bundles known to the node and absent in the given set,
+    /// and bundles from the set unknown to the node.
+    pub(super) fn diff_history(
+        &mut self,
+        contract_id: ContractId,
+        remote: BTreeSet<BundleId>,
+    ) -> Result<HistoryDiff, DaemonError> {
+        let local = self.contract_bundles(contract_id)?;
+        Ok(HistoryDiff {
+            local_only: local.difference(&remote).copied().collect(),
+            remote_only: remote.difference(&local).copied().collect(),
+        })
+    }
+
+    /// Commits to the digests of all contracts known to the node. Forgotten
+    /// contracts are not included.
+    pub(super) fn commit_stash(&mut self) -> Result<StashCommitment, DaemonError> {
+        let mut contracts = vec![];
+        for key in self.store.ids(db::CONTRACTS)? {
+            self.interrupt.check()?;
+            let contract_id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            let forgotten: Option<ForgottenContract> =
+                self.store.retrieve_sten(db::FORGOTTEN, contract_id)?;
+            if forgotten.is_some() {
+                continue;
+            }
+            contracts.push(self.state_digest(contract_id)?);
+        }
+        debug!("Committing to the state of {} contract(s)", contracts.len());
+        Ok(StashCommitment::compute(now(), contracts))
+    }
+
+    /// Collects ids of the bundles containing known state transitions of the
+    /// contract. Transitions imported with a checkpoint have no bundles and
+    /// are not accounted.
+    fn contract_bundles(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<BTreeSet<BundleId>, DaemonError> {
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let witnesses = self
+            .store_pool
+            .retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids)?
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        let chunk_ids = witnesses
+            .into_iter()
+            .map(|txid| ChunkId::with_fixed_fragments(contract_id, txid))
+            .collect();
+        let bundles =
+            self.store_pool.retrieve_all::<_, TransitionBundle>(db::BUNDLES, chunk_ids)?;
+        Ok(bundles.into_iter().flatten().map(|bundle| bundle.bundle_id()).collect())
+    }
+}
@@ -0,0 +1,411 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Mirroring of the accepted contract data into an external PostgreSQL
+//! database, which can be queried by block explorers and other services not
+//! aware of the strict encoding.
+//!
+//! The database is written by a dedicated thread, so the consignment
+//! processing never waits for the database: the runtime only converts the
+//! consignment data into table rows and queues them for the export.
+//!
+//! Contract ids are always exported in Bech32 encoding and node ids in hex,
+//! independently from the encoding selected by the clients, since they key
+//! the rows shared by all nodes writing to the database.
+
+use std::collections::BTreeSet;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use bitcoin::{OutPoint, Txid};
+use postgres::{Client, NoTls, Transaction};
+use rgb::{Consignment, ConsignmentType, ContractId, InmemConsignment, Node};
+use rgb_rpc::IdEncoding;
+
+use super::Runtime;
+
+/// Migrations of the exported data schema, applied in order. Each node
+/// sharing the database applies the migrations it doesn't find in
+/// `rgb_migrations` table, so nodes of different versions can export into the
+/// same database as long as the migrations are only appended.
+///
+/// Genesis is exported as a transition without transition type and witness
+/// transaction; allocations are identified by the node, owned right type and
+/// the index of the assignment.
+const MIGRATIONS: &[&str] = &[
+    "
+    CREATE TABLE IF NOT EXISTS rgb_contracts (
+        contract_id TEXT PRIMARY KEY,
+        schema_id TEXT NOT NULL,
+        root_schema_id TEXT,
+        chain TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS rgb_transitions (
+        node_id TEXT PRIMARY KEY,
+        contract_id TEXT NOT NULL REFERENCES rgb_contracts (contract_id),
+        kind TEXT NOT NULL,
+        node_type INTEGER,
+        witness_txid TEXT,
+        height INTEGER
+    );
+    CREATE TABLE IF NOT EXISTS rgb_allocations (
+        node_id TEXT NOT NULL REFERENCES rgb_transitions (node_id),
+        owned_right_type INTEGER NOT NULL,
+        idx INTEGER NOT NULL,
+        contract_id TEXT NOT NULL REFERENCES rgb_contracts (contract_id),
+        txid TEXT NOT NULL,
+        vout INTEGER NOT NULL,
+        amount NUMERIC(20),
+        height INTEGER,
+        spent_by TEXT,
+        PRIMARY KEY (node_id, owned_right_type, idx)
+    );
+    CREATE INDEX IF NOT EXISTS rgb_allocations_outpoint ON rgb_allocations (txid, vout);
+    CREATE INDEX IF NOT EXISTS rgb_allocations_contract ON rgb_allocations (contract_id);
+    ",
+    "
+    CREATE INDEX IF NOT EXISTS rgb_transitions_contract ON rgb_transitions (contract_id);
+    ",
+];
+
+/// Arbitrary key of the advisory lock serializing schema migrations between
+/// the nodes sharing the database
+const MIGRATION_LOCK: i64 = 0x7267_625f_6d69_67;
+
+/// Handle of the thread writing to the database receiving the contract
+/// data. The connection is established lazily and re-established after
+/// failures, so the database unavailability never affects consignment
+/// processing.
+pub(crate) struct SqlExporter {
+    chain: String,
+    sender: Sender<ExportJob>,
+}
+
+impl SqlExporter {
+    pub fn with(url: String, chain: String) -> SqlExporter {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || ExportWorker::with(url).run(receiver));
+        SqlExporter { chain, sender }
+    }
+
+    fn queue(&self, job: ExportJob) {
+        if self.sender.send(job).is_err() {
+            error!("SQL export thread has terminated; contract data are not exported");
+        }
+    }
+}
+
+/// Database update performed by the export thread
+enum ExportJob {
+    Export {
+        contract: ContractRow,
+        nodes: Vec<NodeRow>,
+    },
+    Remove(String),
+}
+
+struct ContractRow {
+    contract_id: String,
+    schema_id: String,
+    root_schema_id: Option<String>,
+    chain: String,
+}
+
+/// Node of the contract history with the allocations it defines and the
+/// allocations it spends
+struct NodeRow {
+    node_id: String,
+    kind: &'static str,
+    node_type: Option<i32>,
+    witness_txid: Option<String>,
+    height: Option<i32>,
+    allocations: Vec<AllocationRow>,
+    spent: Vec<(String, i32, i32)>,
+}
+
+struct AllocationRow {
+    owned_right_type: i32,
+    idx: i32,
+    txid: String,
+    vout: i64,
+    amount: Option<String>,
+}
+
+struct ExportWorker {
+    url: String,
+    client: Option<Client>,
+}
+
+impl ExportWorker {
+    fn with(url: String) -> ExportWorker { ExportWorker { url, client: None } }
+
+    fn client(&mut self) -> Result<&mut Client, postgres::Error> {
+        if self.client.is_none() {
+            debug!("Connecting to SQL export database");
+            let mut client = Client::connect(&self.url, NoTls)?;
+            migrate(&mut client)?;
+            self.client = Some(client);
+        }
+        Ok(self.client.as_mut().expect("client is just set"))
+    }
+
+    /// Processes queued jobs until the runtime is dropped
+    fn run(mut self, receiver: Receiver<ExportJob>) {
+        for job in receiver {
+            let res = match job {
+                ExportJob::Export { contract, nodes } => self.export(contract, nodes),
+                ExportJob::Remove(contract_id) => self.remove(contract_id),
+            };
+            if let Err(err) = res {
+                warn!("Unable to update SQL export database: {}", err);
+                // Connection is re-established on the next job
+                self.client = None;
+            }
+        }
+    }
+
+    /// Exports contract nodes, skipping the ones which were already exported
+    /// together with their final height, so the amount of writes is
+    /// proportional to the new data rather than to the size of the contract
+    /// history.
+    fn export(
+        &mut self,
+        contract: ContractRow,
+        nodes: Vec<NodeRow>,
+    ) -> Result<(), postgres::Error> {
+        let mut tx = self.client()?.transaction()?;
+        let exported = exported_nodes(&mut tx, &contract.contract_id)?;
+        export_contract(&mut tx, &contract)?;
+        let mut count = 0usize;
+        for node in nodes.iter().filter(|node| !exported.contains(&node.node_id)) {
+            export_node(&mut tx, &contract.contract_id, node)?;
+            count += 1;
+        }
+        tx.commit()?;
+        debug!(
+            "Contract {} data are exported to SQL database ({} new nodes)",
+            contract.contract_id, count
+        );
+        Ok(())
+    }
+
+    fn remove(&mut self, contract_id: String) -> Result<(), postgres::Error> {
+        let mut tx = self.client()?.transaction()?;
+        tx.execute("DELETE FROM rgb_allocations WHERE contract_id = $1", &[&contract_id])?;
+        tx.execute("DELETE FROM rgb_transitions WHERE contract_id = $1", &[&contract_id])?;
+        tx.execute("DELETE FROM rgb_contracts WHERE contract_id = $1", &[&contract_id])?;
+        tx.commit()?;
+        debug!("Contract {} data are removed from SQL database", contract_id);
+        Ok(())
+    }
+}
+
+impl Runtime {
+    /// Queues contract data from the accepted consignment for the export into
+    /// the SQL database. Only the data publicly present in the consignment
+    /// are exported; seals revealed by the node itself are kept private.
+    pub(super) fn export_consignment<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+    ) {
+        let chain = match self.exporter {
+            Some(ref exporter) => exporter.chain.clone(),
+            None => return,
+        };
+
+        let mut nodes = vec![node_row(consignment.genesis(), "genesis", None)];
+        for (anchor, bundle) in consignment.anchored_bundles() {
+            let height = self
+                .chain_cache
+                .tx_height(&self.electrum, &mut self.store, anchor.txid)
+                .unwrap_or_else(|err| {
+                    warn!("Unable to resolve height of {}: {}", anchor.txid, err);
+                    None
+                });
+            nodes.extend(
+                bundle.known_transitions().map(|transition| {
+                    node_row(transition, "transition", Some((anchor.txid, height)))
+                }),
+            );
+        }
+        nodes.extend(
+            consignment.state_extensions().map(|extension| node_row(extension, "extension", None)),
+        );
+
+        let contract = ContractRow {
+            contract_id: IdEncoding::Bech32.contract_id(consignment.contract_id()),
+            schema_id: consignment.schema_id().to_string(),
+            root_schema_id: consignment.root_schema().map(|schema| schema.schema_id().to_string()),
+            chain,
+        };
+        if let Some(ref exporter) = self.exporter {
+            exporter.queue(ExportJob::Export { contract, nodes });
+        }
+    }
+
+    /// Queues removal of the forgotten contract data from the SQL export
+    /// database.
+    pub(super) fn unexport_contract(&mut self, contract_id: ContractId) {
+        if let Some(ref exporter) = self.exporter {
+            exporter.queue(ExportJob::Remove(IdEncoding::Bech32.contract_id(contract_id)));
+        }
+    }
+}
+
+/// Applies schema migrations which were not applied yet by this or other
+/// nodes sharing the database.
+fn migrate(client: &mut Client) -> Result<(), postgres::Error> {
+    let mut tx = client.transaction()?;
+    tx.execute("SELECT pg_advisory_xact_lock($1)", &[&MIGRATION_LOCK])?;
+    tx.batch_execute(
+        "CREATE TABLE IF NOT EXISTS rgb_migrations (
+            version INTEGER PRIMARY KEY,
+            applied TIMESTAMP NOT NULL DEFAULT now()
+        )",
+    )?;
+    let version: i32 =
+        tx.query_one("SELECT COALESCE(MAX(version), 0) FROM rgb_migrations", &[])?.get(0);
+    for (no, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let version = no as i32 + 1;
+        info!("Applying migration {} to SQL export database", version);
+        tx.batch_execute(migration)?;
+        tx.execute("INSERT INTO rgb_migrations (version) VALUES ($1)", &[&version])?;
+    }
+    tx.commit()
+}
+
+/// Lists ids of the contract nodes which don't require updates: the ones
+/// which were exported together with the height of their witness transaction
+/// and the ones not having witness transaction at all.
+fn exported_nodes(
+    tx: &mut Transaction,
+    contract_id: &str,
+) -> Result<BTreeSet<String>, postgres::Error> {
+    let rows = tx.query(
+        "SELECT node_id FROM rgb_transitions
+         WHERE contract_id = $1 AND (height IS NOT NULL OR witness_txid IS NULL)",
+        &[&contract_id],
+    )?;
+    Ok(rows.iter().map(|row| row.get(0)).collect())
+}
+
+/// Converts node of the contract history into the table rows. Witness
+/// transaction is given together with the height at which it was mined.
+fn node_row(node: &dyn Node, kind: &'static str, witness: Option<(Txid, Option<u32>)>) -> NodeRow {
+    let node_id = node.node_id();
+    let witness_txid = witness.map(|(txid, _)| txid);
+
+    let mut allocations = vec![];
+    for (owned_type, assignments) in node.owned_rights().iter() {
+        let values = assignments.to_value_assignments();
+        for index in 0..assignments.len() as u16 {
+            let seal = match assignments.revealed_seal_at(index) {
+                Ok(Some(seal)) => seal,
+                _ => continue,
+            };
+            let outpoint = match (witness_txid, seal.txid) {
+                (Some(witness_txid), _) => seal.outpoint_or(witness_txid),
+                (None, Some(txid)) => OutPoint::new(txid, seal.vout),
+                (None, None) => continue,
+            };
+            let amount = values
+                .get(index as usize)
+                .and_then(|assignment| assignment.as_revealed_state())
+                .map(|state| state.value.to_string());
+            allocations.push(AllocationRow {
+                owned_right_type: *owned_type as i32,
+                idx: index as i32,
+                txid: outpoint.txid.to_string(),
+                vout: outpoint.vout as i64,
+                amount,
+            });
+        }
+    }
+
+    let mut spent = vec![];
+    for (parent_id, parent_rights) in node.parent_owned_rights().iter() {
+        for (owned_type, indexes) in parent_rights.iter() {
+            for index in indexes {
+                spent.push((parent_id.to_string(), *owned_type as i32, *index as i32));
+            }
+        }
+    }
+
+    NodeRow {
+        node_id: node_id.to_string(),
+        kind,
+        node_type: node.transition_type().or_else(|| node.extension_type()).map(|ty| ty as i32),
+        witness_txid: witness_txid.map(|txid| txid.to_string()),
+        height: witness.and_then(|(_, height)| height).map(|height| height as i32),
+        allocations,
+        spent,
+    }
+}
+
+fn export_contract(tx: &mut Transaction, contract: &ContractRow) -> Result<(), postgres::Error> {
+    tx.execute(
+        "INSERT INTO rgb_contracts (contract_id, schema_id, root_schema_id, chain)
+         VALUES ($1, $2, $3, $4) ON CONFLICT (contract_id) DO NOTHING",
+        &[&contract.contract_id, &contract.schema_id, &contract.root_schema_id, &contract.chain],
+    )?;
+    Ok(())
+}
+
+fn export_node(
+    tx: &mut Transaction,
+    contract_id: &str,
+    node: &NodeRow,
+) -> Result<(), postgres::Error> {
+    // Height of the nodes which were not mined at the moment of their
+    // previous export is updated
+    tx.execute(
+        "INSERT INTO rgb_transitions (node_id, contract_id, kind, node_type, witness_txid, height)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (node_id) DO UPDATE SET height = EXCLUDED.height",
+        &[
+            &node.node_id,
+            &contract_id,
+            &node.kind,
+            &node.node_type,
+            &node.witness_txid,
+            &node.height,
+        ],
+    )?;
+
+    for allocation in &node.allocations {
+        tx.execute(
+            "INSERT INTO rgb_allocations
+             (node_id, owned_right_type, idx, contract_id, txid, vout, amount, height)
+             VALUES ($1, $2, $3, $4, $5, $6, $7::TEXT::NUMERIC, $8)
+             ON CONFLICT (node_id, owned_right_type, idx)
+             DO UPDATE SET height = EXCLUDED.height",
+            &[
+                &node.node_id,
+                &allocation.owned_right_type,
+                &allocation.idx,
+                &contract_id,
+                &allocation.txid,
+                &allocation.vout,
+                &allocation.amount,
+                &node.height,
+            ],
+        )?;
+    }
+
+    for (parent_id, owned_type, index) in &node.spent {
+        tx.execute(
+            "UPDATE rgb_allocations SET spent_by = $1
+             WHERE node_id = $2 AND owned_right_type = $3 AND idx = $4",
+            &[&node.node_id, parent_id, owned_type, index],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,531 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Deterministic fixtures for the compatibility tests.
+//!
+//! Fixtures are consignments, disclosures and PSBTs of the synthetic contracts
+//! of [`fixture_schema`] generated for a set of scenarios with the blinding
+//! factors derived from a seed. Being generated with the same seed, they have
+//! the same identifiers each time, so the golden files saved by one version of
+//! the node can be decoded and validated by the other versions and compared
+//! with the re-generated ones. Golden files of the default seed are kept in
+//! the `fixtures` directory of the repository and are re-generated with the
+//! `rgb-fixtures` tool.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+use bitcoin::blockdata::constants::genesis_block;
+use bitcoin::consensus::{deserialize, serialize};
+use bitcoin::psbt::PartiallySignedTransaction;
+use bitcoin::{Network, OutPoint, PackedLockTime, Transaction};
+use bp::seals::txout::CloseMethod;
+use lnpbp::chain::Chain;
+use psbt::Psbt;
+use rgb::schema::{
+    DiscreteFiniteFieldFormat, GenesisSchema, Occurrences, OwnedRightType, StateSchema,
+    TransitionSchema, TransitionType,
+};
+use rgb::secp256k1zkp::rand::rngs::StdRng;
+use rgb::secp256k1zkp::rand::{Rng, SeedableRng};
+use rgb::{
+    data, seal, value, Assignment, Consignment, Contract, Disclosure, Genesis, Node, OwnedRights,
+    ParentOwnedRights, Schema, StateTransfer, Transition, TypedAssignments,
+};
+use rgb_rpc::{AssignmentSpec, IssueSpec, ProofBundle};
+use strict_encoding::{StrictDecode, StrictEncode};
+
+use super::issue::genesis_owned_rights;
+use super::synthetic::{seal_outputs, HeaderChain, SyntheticIssue, SyntheticWitness};
+use super::{SyntheticError, SYNTHETIC_AMOUNT};
+
+/// Name of the file listing identifiers of the fixtures of a scenario
+const IDS_FILE: &str = "ids.txt";
+
+/// Fungible assets of the fixture schema
+const ASSETS_RIGHT: OwnedRightType = 0;
+/// Allowance of the secondary issuance of the fixture schema assets
+const INFLATION_RIGHT: OwnedRightType = 1;
+/// Non-fungible token of the fixture schema
+const TOKEN_RIGHT: OwnedRightType = 2;
+
+/// Transfer of the fixture schema assets
+const TRANSFER_TRANSITION: TransitionType = 0x100;
+/// Secondary issuance of the fixture schema assets
+const ISSUE_TRANSITION: TransitionType = 0x101;
+/// Transfer of the fixture schema token
+const TOKEN_TRANSFER_TRANSITION: TransitionType = 0x102;
+
+/// Secondary issuance allowance allocated by the genesis of the inflation
+/// scenario
+const INFLATION_ALLOWANCE: u64 = 1_000_000;
+
+/// Schema of the fixture contracts: fungible assets with an optional
+/// secondary issuance allowance and an optional non-fungible token. The schema
+/// has no validation scripts, so the fixtures do not depend on the script
+/// engine of the node.
+pub fn fixture_schema() -> Schema {
+    Schema {
+        owned_right_types: bmap! {
+            ASSETS_RIGHT => StateSchema::DiscreteFiniteField(DiscreteFiniteFieldFormat::Unsigned64bit),
+            INFLATION_RIGHT => StateSchema::DiscreteFiniteField(DiscreteFiniteFieldFormat::Unsigned64bit),
+            TOKEN_RIGHT => StateSchema::Declarative
+        },
+        genesis: GenesisSchema {
+            owned_rights: bmap! {
+                ASSETS_RIGHT => Occurrences::OnceOrMore,
+                INFLATION_RIGHT => Occurrences::NoneOrOnce,
+                TOKEN_RIGHT => Occurrences::NoneOrOnce
+            },
+            ..default!()
+        },
+        transitions: bmap! {
+            TRANSFER_TRANSITION => TransitionSchema {
+                closes: bmap! { ASSETS_RIGHT => Occurrences::OnceOrMore },
+                owned_rights: bmap! { ASSETS_RIGHT => Occurrences::NoneOrMore },
+                ..default!()
+            },
+            ISSUE_TRANSITION => TransitionSchema {
+                closes: bmap! { INFLATION_RIGHT => Occurrences::Once },
+                owned_rights: bmap! {
+                    ASSETS_RIGHT => Occurrences::OnceOrMore,
+                    INFLATION_RIGHT => Occurrences::NoneOrOnce
+                },
+                ..default!()
+            },
+            TOKEN_TRANSFER_TRANSITION => TransitionSchema {
+                closes: bmap! { TOKEN_RIGHT => Occurrences::Once },
+                owned_rights: bmap! { TOKEN_RIGHT => Occurrences::Once },
+                ..default!()
+            }
+        },
+        ..default!()
+    }
+}
+
+#[derive(Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum FixtureError {
+    /// I/O error. Details: {0}
+    #[from]
+    Io(io::Error),
+
+    /// unable to decode fixture. Details: {0}
+    #[from]
+    Encoding(strict_encoding::Error),
+
+    /// unable to decode PSBT fixture. Details: {0}
+    Psbt(String),
+
+    /// {0}
+    #[from]
+    Synthetic(SyntheticError),
+
+    /// unknown fixture scenario `{0}`.
+    UnknownScenario(String),
+
+    /// fixture file {0} differs from the re-generated one.
+    Mismatch(PathBuf),
+
+    /// identifier of {0} is {1}, while the golden files list {2}.
+    IdMismatch(String, String, String),
+}
+
+/// Scenario of the fixtures
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+pub enum Scenario {
+    /// Transfer of a single allocation
+    #[display("simple-transfer")]
+    SimpleTransfer,
+
+    /// Witness transaction spending allocations of two contracts
+    #[display("multi-asset")]
+    MultiAsset,
+
+    /// Secondary issuance closing the inflation allowance
+    #[display("inflation")]
+    Inflation,
+
+    /// Transfer of a non-fungible token
+    #[display("nft")]
+    Nft,
+}
+
+impl Scenario {
+    pub const ALL: [Scenario; 4] =
+        [Scenario::SimpleTransfer, Scenario::MultiAsset, Scenario::Inflation, Scenario::Nft];
+
+    /// Number of contracts of the scenario fixtures
+    fn contracts(self) -> usize {
+        match self {
+            Scenario::MultiAsset => 2,
+            Scenario::SimpleTransfer | Scenario::Inflation | Scenario::Nft => 1,
+        }
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = FixtureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scenario::ALL
+            .into_iter()
+            .find(|scenario| scenario.to_string() == s)
+            .ok_or_else(|| FixtureError::UnknownScenario(s.to_owned()))
+    }
+}
+
+/// Fixtures of a scenario
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub scenario: Scenario,
+    pub contracts: Vec<Contract>,
+    /// Transfers of the contracts, in the same order
+    pub transfers: Vec<StateTransfer>,
+    /// Disclosure of the bundles of all the contracts committed by the
+    /// witness transaction
+    pub disclosure: Disclosure,
+    /// PSBT of the witness transaction before the anchor is committed into it
+    pub psbt: Psbt,
+    /// Proofs of the witness transaction, mined after the regtest genesis
+    pub proofs: ProofBundle,
+}
+
+impl Fixture {
+    /// Generates fixtures of the scenario with the blinding factors derived
+    /// from the seed.
+    pub fn generate(scenario: Scenario, seed: u64) -> Result<Fixture, FixtureError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (contracts, transfers, witness) = match scenario {
+            Scenario::SimpleTransfer | Scenario::MultiAsset => {
+                transfer_assets(scenario.contracts(), &mut rng)?
+            }
+            Scenario::Inflation => inflate_assets(&mut rng)?,
+            Scenario::Nft => transfer_token(&mut rng)?,
+        };
+        let (header, proof) = HeaderChain::new().mine(witness.tx.clone());
+        let proofs = ProofBundle {
+            headers: vec![header],
+            txes: vec![proof],
+        };
+        let disclosure = Disclosure::with(witness.anchor.clone(), witness.bundles.clone(), None);
+        Ok(Fixture {
+            scenario,
+            contracts,
+            transfers,
+            disclosure,
+            psbt: witness.psbt,
+            proofs,
+        })
+    }
+
+    /// Lists fixture files with their identifiers
+    pub fn ids(&self) -> BTreeMap<String, String> {
+        let mut ids = bmap! {};
+        for (no, contract) in self.contracts.iter().enumerate() {
+            ids.insert(format!("contract-{}.rgb", no), contract.contract_id().to_string());
+        }
+        for (no, transfer) in self.transfers.iter().enumerate() {
+            ids.insert(format!("transfer-{}.rgb", no), transfer.id().to_string());
+        }
+        let txid = PartiallySignedTransaction::from(self.psbt.clone()).unsigned_tx.txid();
+        ids.insert(s!("disclosure.rgb"), txid.to_string());
+        ids.insert(s!("witness.psbt"), txid.to_string());
+        ids
+    }
+
+    /// Encodes fixture files
+    fn files(&self) -> Result<BTreeMap<String, Vec<u8>>, FixtureError> {
+        let mut files = bmap! {};
+        for (no, contract) in self.contracts.iter().enumerate() {
+            files.insert(format!("contract-{}.rgb", no), contract.strict_serialize()?);
+        }
+        for (no, transfer) in self.transfers.iter().enumerate() {
+            files.insert(format!("transfer-{}.rgb", no), transfer.strict_serialize()?);
+        }
+        files.insert(s!("disclosure.rgb"), self.disclosure.strict_serialize()?);
+        files.insert(s!("proofs.rgb"), self.proofs.strict_serialize()?);
+        files.insert(
+            s!("witness.psbt"),
+            serialize(&PartiallySignedTransaction::from(self.psbt.clone())),
+        );
+        let ids = self
+            .ids()
+            .into_iter()
+            .map(|(name, id)| format!("{} {}\n", name, id))
+            .collect::<String>();
+        files.insert(s!(IDS_FILE), ids.into_bytes());
+        Ok(files)
+    }
+
+    /// Saves fixture files into the scenario subdirectory, overwriting the
+    /// existing ones.
+    pub fn save(&self, dir: impl AsRef<Path>) -> Result<(), FixtureError> {
+        let dir = dir.as_ref().join(self.scenario.to_string());
+        fs::create_dir_all(&dir)?;
+        for (name, data) in self.files()? {
+            fs::write(dir.join(name), data)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the golden files in the scenario subdirectory are decoded
+    /// with the listed identifiers and match the re-generated fixtures.
+    pub fn check(&self, dir: impl AsRef<Path>) -> Result<(), FixtureError> {
+        let dir = dir.as_ref().join(self.scenario.to_string());
+        let golden = Fixture::load(self.scenario, &dir)?;
+        let listed = fs::read_to_string(dir.join(IDS_FILE))?;
+        let listed = listed
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(name, id)| (name.to_owned(), id.to_owned()))
+            .collect::<BTreeMap<_, _>>();
+        for (name, id) in golden.ids() {
+            match listed.get(&name) {
+                Some(listed) if *listed == id => {}
+                listed => {
+                    return Err(FixtureError::IdMismatch(
+                        name,
+                        id,
+                        listed.cloned().unwrap_or_default(),
+                    ))
+                }
+            }
+        }
+        for (name, data) in self.files()? {
+            if fs::read(dir.join(&name))? != data {
+                return Err(FixtureError::Mismatch(dir.join(name)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes golden files of the scenario from the scenario directory.
+    pub fn load(scenario: Scenario, dir: impl AsRef<Path>) -> Result<Fixture, FixtureError> {
+        let dir = dir.as_ref();
+        let mut contracts = vec![];
+        let mut transfers = vec![];
+        for no in 0..scenario.contracts() {
+            contracts.push(Contract::strict_file_load(dir.join(format!("contract-{}.rgb", no)))?);
+            transfers
+                .push(StateTransfer::strict_file_load(dir.join(format!("transfer-{}.rgb", no)))?);
+        }
+        let psbt: PartiallySignedTransaction = deserialize(&fs::read(dir.join("witness.psbt"))?)
+            .map_err(|err| FixtureError::Psbt(err.to_string()))?;
+        Ok(Fixture {
+            scenario,
+            contracts,
+            transfers,
+            disclosure: Disclosure::strict_file_load(dir.join("disclosure.rgb"))?,
+            psbt: Psbt::from(psbt),
+            proofs: ProofBundle::strict_file_load(dir.join("proofs.rgb"))?,
+        })
+    }
+}
+
+/// Fixture contracts with their transfers and the witness transaction closing
+/// their allocations
+type Transfers = (Vec<Contract>, Vec<StateTransfer>, SyntheticWitness);
+
+/// Transfers the assets of each of the contracts by the same witness
+/// transaction.
+fn transfer_assets(count: usize, rng: &mut StdRng) -> Result<Transfers, FixtureError> {
+    let spec = IssueSpec {
+        schema: fixture_schema().schema_id(),
+        metadata: empty!(),
+        assignments: empty!(),
+    };
+    let mut issues = (0..count as u32)
+        .map(|index| SyntheticIssue::with(fixture_schema(), spec.clone(), 1, index, rng))
+        .collect::<Result<Vec<_>, _>>()?;
+    let witness = SyntheticWitness::close(&issues.iter().collect::<Vec<_>>(), rng)?;
+    for issue in &mut issues {
+        witness.push_to(issue, true)?;
+    }
+    let (contracts, transfers) =
+        issues.into_iter().map(|issue| (issue.contract, issue.transfer)).unzip();
+    Ok((contracts, transfers, witness))
+}
+
+/// Issues more assets, closing the inflation allowance and assigning its
+/// remainder to a new seal.
+fn inflate_assets(rng: &mut StdRng) -> Result<Transfers, FixtureError> {
+    let (genesis, outpoint) = issue_genesis(INFLATION_RIGHT, Some(INFLATION_ALLOWANCE), rng)?;
+    let issued = TypedAssignments::Value(vec![Assignment::Revealed {
+        seal: witness_seal(1, rng),
+        state: value::Revealed::with_amount(SYNTHETIC_AMOUNT, rng),
+    }]);
+    let allowance = TypedAssignments::Value(vec![Assignment::Revealed {
+        seal: witness_seal(2, rng),
+        state: value::Revealed::with_amount(INFLATION_ALLOWANCE - SYNTHETIC_AMOUNT, rng),
+    }]);
+    let transition = Transition::with(
+        ISSUE_TRANSITION,
+        empty!(),
+        empty!(),
+        OwnedRights::from(bmap! { ASSETS_RIGHT => issued, INFLATION_RIGHT => allowance }),
+        empty!(),
+        ParentOwnedRights::from(
+            bmap! { genesis.node_id() => bmap! { INFLATION_RIGHT => vec![0] } },
+        ),
+    );
+    close_genesis(genesis, outpoint, transition, 2)
+}
+
+/// Transfers the non-fungible token to a new seal.
+fn transfer_token(rng: &mut StdRng) -> Result<Transfers, FixtureError> {
+    let (genesis, outpoint) = issue_genesis(TOKEN_RIGHT, None, rng)?;
+    let token = TypedAssignments::Void(vec![Assignment::Revealed {
+        seal: witness_seal(1, rng),
+        state: data::Void(),
+    }]);
+    let transition = Transition::with(
+        TOKEN_TRANSFER_TRANSITION,
+        empty!(),
+        empty!(),
+        OwnedRights::from(bmap! { TOKEN_RIGHT => token }),
+        empty!(),
+        ParentOwnedRights::from(bmap! { genesis.node_id() => bmap! { TOKEN_RIGHT => vec![0] } }),
+    );
+    close_genesis(genesis, outpoint, transition, 1)
+}
+
+/// Genesis of the fixture schema allocating the assets to the first output of
+/// a funding transaction and the right of the given type to the second one,
+/// which is returned together with the genesis.
+fn issue_genesis(
+    right_type: OwnedRightType,
+    amount: Option<u64>,
+    rng: &mut StdRng,
+) -> Result<(Genesis, OutPoint), FixtureError> {
+    let schema = fixture_schema();
+    let funding = Transaction {
+        version: 2,
+        lock_time: PackedLockTime::ZERO,
+        input: vec![],
+        output: seal_outputs(2, false),
+    };
+    let assignment = |right_type, vout, amount| AssignmentSpec {
+        right_type,
+        outpoint: OutPoint::new(funding.txid(), vout),
+        method: CloseMethod::OpretFirst,
+        amount,
+    };
+    let spec = IssueSpec {
+        schema: schema.schema_id(),
+        metadata: empty!(),
+        assignments: vec![
+            assignment(ASSETS_RIGHT, 0, Some(SYNTHETIC_AMOUNT)),
+            assignment(right_type, 1, amount),
+        ],
+    };
+    let owned_rights = genesis_owned_rights(&schema, &spec, rng).map_err(SyntheticError::from)?;
+    let genesis = Genesis::with(
+        schema.schema_id(),
+        Chain::Regtest(genesis_block(Network::Regtest).block_hash()),
+        empty!(),
+        owned_rights,
+        empty!(),
+    );
+    Ok((genesis, OutPoint::new(funding.txid(), 1)))
+}
+
+/// Seal of the witness transaction output with the given number
+fn witness_seal(vout: u32, rng: &mut StdRng) -> seal::Revealed {
+    seal::Revealed {
+        method: CloseMethod::OpretFirst,
+        blinding: rng.gen(),
+        txid: None,
+        vout,
+    }
+}
+
+/// Closes the genesis allocation by the transition, which seals become the
+/// transfer endpoints.
+fn close_genesis(
+    genesis: Genesis,
+    outpoint: OutPoint,
+    transition: Transition,
+    seals: u16,
+) -> Result<Transfers, FixtureError> {
+    let schema = fixture_schema();
+    let contract =
+        Contract::with(schema.clone(), None, genesis.clone(), empty!(), empty!(), empty!());
+    let mut transfer = StateTransfer::with(schema, None, genesis, empty!(), empty!(), empty!());
+    let witness = SyntheticWitness::spend(vec![(&contract, outpoint, transition)], seals)?;
+    witness.push_bundle(&mut transfer, contract.contract_id(), true)?;
+    Ok((vec![contract], vec![transfer], witness))
+}
+
+#[cfg(test)]
+mod test {
+    use rgb::{ConsignmentType, InmemConsignment, Validator, Validity};
+
+    use super::*;
+    use crate::bucketd::proofs::ProofResolver;
+
+    /// Seed of the golden files, which is the default one of `rgb-fixtures`
+    const GOLDEN_SEED: u64 = 0;
+
+    fn validate<C: ConsignmentType>(consignment: &InmemConsignment<C>, resolver: &ProofResolver) {
+        let status = Validator::validate(consignment, resolver);
+        assert!(matches!(status.validity(), Validity::Valid), "{:?}", status);
+    }
+
+    /// Checks that the fixtures in the directory are decoded, match the
+    /// re-generated ones and are valid.
+    fn check_fixtures(dir: &Path) {
+        for scenario in Scenario::ALL {
+            let fixture = Fixture::generate(scenario, GOLDEN_SEED).expect("fixture is generated");
+            fixture.check(dir).expect("fixture files match");
+
+            let loaded = Fixture::load(scenario, dir.join(scenario.to_string()))
+                .expect("fixture files are decoded");
+            assert_eq!(loaded.ids(), fixture.ids());
+            let txes = loaded
+                .proofs
+                .verify(HeaderChain::checkpoint())
+                .expect("fixture proofs are verified");
+            let resolver = ProofResolver(txes);
+            for contract in &loaded.contracts {
+                validate(contract, &resolver);
+            }
+            for transfer in &loaded.transfers {
+                validate(transfer, &resolver);
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic_fixtures() {
+        for scenario in Scenario::ALL {
+            let fixture = Fixture::generate(scenario, GOLDEN_SEED).unwrap();
+            assert_eq!(fixture.contracts.len(), scenario.contracts());
+            assert_eq!(Fixture::generate(scenario, GOLDEN_SEED).unwrap().ids(), fixture.ids());
+            assert_ne!(Fixture::generate(scenario, GOLDEN_SEED + 1).unwrap().ids(), fixture.ids());
+        }
+    }
+
+    #[test]
+    fn saved_fixtures() {
+        let dir = std::env::temp_dir().join("rgb-node-fixtures");
+        for scenario in Scenario::ALL {
+            Fixture::generate(scenario, GOLDEN_SEED).unwrap().save(&dir).unwrap();
+        }
+        check_fixtures(&dir);
+    }
+
+    #[test]
+    fn golden_fixtures() {
+        check_fixtures(&Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures"));
+    }
+}
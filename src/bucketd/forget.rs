@@ -0,0 +1,306 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Removal of a contract with all the data derived from it, and of the
+//! contract state assigned to the outpoints spent without transferring it.
+
+use std::collections::BTreeSet;
+
+use amplify::Wrapper;
+use bitcoin::{OutPoint, Script, Txid};
+use rgb::{
+    ContractId, ContractStateMap, Disclosure, Extension, Genesis, Node, NodeId, Schema, Transition,
+};
+use rgb_rpc::{now, AnnotationTarget, ForgetReport, InvoiceRecord, TransferRecord};
+use storm::ChunkId;
+
+use super::{views, ContainerRef, QuarantineEntry, Runtime, StashError, WalletError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Record of a contract removed from the node. Records with the tombstone make
+/// the node refuse re-import of the contract unless it is forced.
+#[derive(Clone, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct ForgottenContract {
+    pub contract_id: ContractId,
+    /// UNIX timestamp of the removal
+    pub timestamp: u64,
+    pub tombstone: bool,
+}
+
+impl Runtime {
+    /// Removes the contract together with its history, state, indexes,
+    /// transfer records, quarantined consignments and exported SQL data.
+    /// Anchors and disclosures are kept, since they may be shared with other
+    /// contracts.
+    pub(super) fn forget_contract(
+        &mut self,
+        contract_id: ContractId,
+        tombstone: bool,
+    ) -> Result<ForgetReport, DaemonError> {
+        if self.state_cache.get(&mut self.store, contract_id)?.is_none() {
+            return Err(StashError::StateAbsent(contract_id).into());
+        }
+        let genesis: Genesis =
+            self.store.retrieve_sten(db::GENESIS, contract_id)?.ok_or(StashError::GenesisAbsent)?;
+        let schema_id = genesis.schema_id();
+        let mut report = ForgetReport::default();
+
+        // 1. Collect contract history
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let transitions =
+            self.store_pool.retrieve_all::<_, Transition>(db::TRANSITIONS, node_ids.clone())?;
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+        let mut extension_ids = transitions
+            .iter()
+            .flatten()
+            .flat_map(|transition| transition.parent_public_rights().keys().copied())
+            .filter(|node_id| node_id.as_inner() != contract_id.as_inner())
+            .collect::<BTreeSet<_>>();
+        let mut queue = extension_ids.iter().copied().collect::<Vec<_>>();
+        while let Some(node_id) = queue.pop() {
+            let extension: Option<Extension> = self.store.retrieve_sten(db::EXTENSIONS, node_id)?;
+            for parent_id in extension.iter().flat_map(|ext| ext.parent_public_rights().keys()) {
+                if parent_id.as_inner() != contract_id.as_inner()
+                    && extension_ids.insert(*parent_id)
+                {
+                    queue.push(*parent_id);
+                }
+            }
+        }
+        self.interrupt.check()?;
+
+        // 2. Remove the contract nodes from the outpoint index
+        let contract_node = NodeId::from_inner(contract_id.into_inner());
+        let mut nodes = node_ids.iter().copied().collect::<BTreeSet<_>>();
+        nodes.insert(contract_node);
+        let mut seals = genesis
+            .revealed_seals()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|seal| seal.txid.map(|txid| ChunkId::with_fixed_fragments(txid, seal.vout)))
+            .collect::<BTreeSet<_>>();
+        for (transition, witness) in transitions.iter().zip(&witnesses) {
+            if let (Some(transition), Some(witness)) = (transition, witness) {
+                seals.extend(transition.filter_revealed_seals().into_iter().map(|seal| {
+                    ChunkId::with_fixed_fragments(seal.txid.unwrap_or(*witness), seal.vout)
+                }));
+            }
+        }
+        for index_id in seals {
+            let mut set: BTreeSet<NodeId> =
+                match self.store.retrieve_sten(db::OUTPOINTS, index_id)? {
+                    Some(set) => set,
+                    None => continue,
+                };
+            set.retain(|node_id| !nodes.contains(node_id));
+            if set.is_empty() {
+                self.store.wipe_chunk(db::OUTPOINTS, index_id)?;
+            } else {
+                self.store.store_sten(db::OUTPOINTS, index_id, &set)?;
+            }
+        }
+        self.outpoint_index.invalidate();
+
+        // 3. Remove the contract history
+        for node_id in &node_ids {
+            if let Some(size) = self.store.wipe_chunk(db::TRANSITIONS, *node_id)? {
+                report.nodes += 1;
+                report.bytes += size as u64;
+            }
+            self.store.wipe_chunk(db::TRANSITION_WITNESS, *node_id)?;
+            self.store.wipe_chunk(db::ANCESTRY, *node_id)?;
+            self.store.wipe_chunk(db::NODE_CONTRACTS, *node_id)?;
+        }
+        for node_id in extension_ids {
+            if let Some(size) = self.store.wipe_chunk(db::EXTENSIONS, node_id)? {
+                report.nodes += 1;
+                report.bytes += size as u64;
+            }
+            self.store.wipe_chunk(db::NODE_CONTRACTS, node_id)?;
+        }
+        for txid in witnesses.into_iter().flatten().collect::<BTreeSet<_>>() {
+            let chunk_id = ChunkId::with_fixed_fragments(contract_id, txid);
+            if let Some(size) = self.store.wipe_chunk(db::BUNDLES, chunk_id)? {
+                report.bytes += size as u64;
+            }
+        }
+        let schema = self.store.retrieve_sten::<Schema>(db::SCHEMATA, schema_id)?;
+        for transition_type in schema.iter().flat_map(|schema| schema.transitions.keys()) {
+            let chunk_id = ChunkId::with_fixed_fragments(contract_id, *transition_type);
+            self.store.wipe_chunk(db::CONTRACT_TRANSITIONS, chunk_id)?;
+        }
+        views::forget(&mut self.store, contract_id)?;
+        for table in [
+            db::GENESIS,
+            db::NODE_CONTRACTS,
+            db::CONTRACT_REFS,
+            db::VALIDATIONS,
+            db::ANCESTRY,
+            db::CHECKPOINTS,
+            db::POLICIES,
+            db::PENDING_REVEALS,
+            db::STORAGE_USAGE,
+            db::CONTRACTS,
+        ] {
+            if let Some(size) = self.store.wipe_chunk(table, contract_id)? {
+                report.bytes += size as u64;
+            }
+        }
+        self.state_cache.forget(contract_id);
+
+        // 4. Remove invoices, transfers, consignments kept for them and annotations
+        let mut consignments = bset! {};
+        for key in self.store.ids(db::TRANSFERS)? {
+            let record = match self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, key)? {
+                Some(record) if record.contract_id == contract_id => record,
+                _ => continue,
+            };
+            consignments.insert(record.id);
+            self.store.wipe_chunk(db::TRANSFERS, key)?;
+            self.store.wipe_chunk(db::PACKAGES, record.id)?;
+            report.transfers += 1;
+        }
+        for key in self.store.ids(db::INVOICES)? {
+            match self.store.retrieve_sten::<InvoiceRecord>(db::INVOICES, key)? {
+                Some(record) if record.invoice.contract_id == contract_id => {
+                    self.store.wipe_chunk(db::INVOICES, key)?;
+                }
+                _ => {}
+            }
+        }
+        for key in self.store.ids(db::QUARANTINE)? {
+            match self.store.retrieve_sten::<QuarantineEntry>(db::QUARANTINE, key)? {
+                Some(entry) if entry.info.contract_id == contract_id => {
+                    consignments.insert(entry.info.id);
+                    if let Some(size) = self.store.wipe_chunk(db::QUARANTINE, key)? {
+                        report.bytes += size as u64;
+                    }
+                }
+                _ => {}
+            }
+        }
+        for key in self.store.ids(db::CONTAINER_REFS)? {
+            let container_ref =
+                match self.store.retrieve_sten::<ContainerRef>(db::CONTAINER_REFS, key)? {
+                    Some(container_ref) if !container_ref.collected => container_ref,
+                    _ => continue,
+                };
+            if let Some(consignment_id) = container_ref.consignment_id {
+                if consignments.contains(&consignment_id) {
+                    let gc = self.release_container(container_ref.container_id, consignment_id)?;
+                    report.bytes += gc.bytes;
+                }
+            }
+        }
+        let targets = consignments
+            .into_iter()
+            .map(AnnotationTarget::Transfer)
+            .chain(Some(AnnotationTarget::Contract(contract_id)));
+        for target in targets {
+            let key = ChunkId::with_fixed_fragments(db::ANNOTATIONS, target);
+            self.store.wipe_chunk(db::ANNOTATIONS, key)?;
+        }
+
+        let record = ForgottenContract {
+            contract_id,
+            timestamp: now(),
+            tombstone,
+        };
+        self.store.store_sten(db::FORGOTTEN, contract_id, &record)?;
+        #[cfg(feature = "sql")]
+        self.unexport_contract(contract_id);
+        info!("Contract {} is forgotten, removing {}", contract_id, report);
+        Ok(report)
+    }
+
+    /// Removes contract state assigned to the wallet outpoints which were
+    /// spent by mined transactions without transferring the state (i.e. the
+    /// state was burned or abandoned), returning the removed state.
+    ///
+    /// Outpoints spent by a transaction with a disclosure which is not yet
+    /// enclosed are refused, since their state is transferred rather than
+    /// lost.
+    pub(super) fn forget_spent(
+        &mut self,
+        descriptor: &str,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<ContractStateMap, DaemonError> {
+        if outpoints.is_empty() {
+            return Ok(bmap! {});
+        }
+        let scripts = self.wallet_scripts(descriptor)?;
+        for outpoint in &outpoints {
+            let txid = self.spending_tx(*outpoint, &scripts)?;
+            let disclosure: Option<Disclosure> = self.store.retrieve_sten(db::DISCLOSURES, txid)?;
+            let node_ids = disclosure
+                .iter()
+                .flat_map(|disclosure| disclosure.anchored_bundles().values())
+                .flat_map(|(_, bundles)| bundles.values())
+                .flat_map(|bundle| bundle.known_transitions())
+                .map(Transition::node_id)
+                .collect::<Vec<_>>();
+            for node_id in node_ids {
+                if self.store.retrieve_sten::<ContractId>(db::NODE_CONTRACTS, node_id)?.is_none() {
+                    return Err(WalletError::PendingDisclosure(*outpoint, txid).into());
+                }
+            }
+        }
+
+        let removed = self.outpoint_state(outpoints.clone())?;
+        for contract_id in removed.keys() {
+            let mut state = self
+                .state_cache
+                .get(&mut self.store, *contract_id)?
+                .ok_or(StashError::StateAbsent(*contract_id))?;
+            state.owned_rights.retain(|assigned| !outpoints.contains(&assigned.outpoint));
+            state.owned_values.retain(|assigned| !outpoints.contains(&assigned.outpoint));
+            state.owned_data.retain(|assigned| !outpoints.contains(&assigned.outpoint));
+            state.owned_attachments.retain(|assigned| !outpoints.contains(&assigned.outpoint));
+            self.state_cache.store(&mut self.store, *contract_id, &state)?;
+        }
+        for outpoint in &outpoints {
+            let index_id = ChunkId::with_fixed_fragments(outpoint.txid, outpoint.vout);
+            self.store.wipe_chunk(db::OUTPOINTS, index_id)?;
+        }
+        self.outpoint_index.invalidate();
+
+        info!(
+            "State of {} contract(s) assigned to {} spent outpoint(s) is forgotten",
+            removed.len(),
+            outpoints.len()
+        );
+        Ok(removed)
+    }
+
+    /// Finds mined transaction spending the wallet outpoint
+    fn spending_tx(
+        &mut self,
+        outpoint: OutPoint,
+        scripts: &BTreeSet<Script>,
+    ) -> Result<Txid, WalletError> {
+        let tx = self
+            .electrum
+            .call(|client| client.transaction_get(&outpoint.txid))
+            .map_err(|_| WalletError::UnresolvedTx(outpoint.txid))?;
+        let script = tx
+            .output
+            .get(outpoint.vout as usize)
+            .map(|out| out.script_pubkey.clone())
+            .filter(|script| scripts.contains(script))
+            .ok_or(WalletError::ForeignOutpoint(outpoint))?;
+        match self.spender(&script, outpoint)? {
+            Some((txid, 0)) => Err(WalletError::SpendUnconfirmed(outpoint, txid)),
+            Some((txid, _)) => Ok(txid),
+            None => Err(WalletError::Unspent(outpoint)),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use rgb::ConsignmentId;
+use rgb_rpc::{now, GcReport};
+use storm::{ChunkId, Container, ContainerId};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+const SECONDS_IN_DAY: u64 = 24 * 60 * 60;
+
+/// Storm container with a consignment, which data are removed from the store
+/// once the consignment is no longer needed.
+#[derive(Clone, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct ContainerRef {
+    pub container_id: ContainerId,
+    /// Consignment kept in the container; unknown if the container data can't
+    /// be decoded
+    pub consignment_id: Option<ConsignmentId>,
+    pub chunks: BTreeSet<ChunkId>,
+    /// UNIX timestamp after which the container data may be removed
+    pub expires: u64,
+    /// Whether the container data were already removed
+    pub collected: bool,
+}
+
+impl Runtime {
+    /// Starts tracking container, which data are kept in the store for the
+    /// retention period.
+    pub(super) fn track_container(
+        &mut self,
+        container_id: ContainerId,
+        container: &Container,
+        consignment_id: Option<ConsignmentId>,
+    ) -> Result<(), DaemonError> {
+        let container_ref = ContainerRef {
+            container_id,
+            consignment_id,
+            chunks: container.chunks.iter().copied().collect(),
+            expires: now() + self.container_retention as u64 * SECONDS_IN_DAY,
+            collected: false,
+        };
+        self.store.store_sten(db::CONTAINER_REFS, container_id, &container_ref)?;
+        Ok(())
+    }
+
+    /// Removes container data once the consignment it holds is consumed into
+    /// the stash.
+    pub(super) fn release_container(
+        &mut self,
+        container_id: ContainerId,
+        consignment_id: ConsignmentId,
+    ) -> Result<GcReport, DaemonError> {
+        let container_ref: Option<ContainerRef> =
+            self.store.retrieve_sten(db::CONTAINER_REFS, container_id)?;
+        let mut container_ref = match container_ref {
+            Some(container_ref) if !container_ref.collected => container_ref,
+            _ => return Ok(default!()),
+        };
+        container_ref.consignment_id = Some(consignment_id);
+        container_ref.expires = now();
+        self.store.store_sten(db::CONTAINER_REFS, container_id, &container_ref)?;
+        self.collect_garbage(Some(container_id))
+    }
+
+    /// Removes data of the expired containers from the store. If `only` is
+    /// given, other containers are not collected. Chunks shared with the
+    /// containers which are still alive are kept.
+    pub(super) fn collect_garbage(
+        &mut self,
+        only: Option<ContainerId>,
+    ) -> Result<GcReport, DaemonError> {
+        let timestamp = now();
+        let mut expired = vec![];
+        let mut alive = BTreeSet::new();
+        for key in self.store.ids(db::CONTAINER_REFS)? {
+            let container_ref =
+                match self.store.retrieve_sten::<ContainerRef>(db::CONTAINER_REFS, key)? {
+                    Some(container_ref) if !container_ref.collected => container_ref,
+                    _ => continue,
+                };
+            let skipped = only.map(|id| id != container_ref.container_id).unwrap_or_default();
+            if container_ref.expires > timestamp || skipped {
+                alive.extend(container_ref.chunks);
+            } else {
+                expired.push(container_ref);
+            }
+        }
+
+        let mut report = GcReport::default();
+        for mut container_ref in expired {
+            let container_id = container_ref.container_id;
+            debug!("Collecting container {}", container_id);
+            for chunk_id in container_ref.chunks.difference(&alive) {
+                if let Some(size) = self.store.wipe_chunk(storm_rpc::DB_TABLE_CHUNKS, *chunk_id)? {
+                    report.chunks += 1;
+                    report.bytes += size as u64;
+                }
+            }
+            for table in [storm_rpc::DB_TABLE_CONTAINERS, storm_rpc::DB_TABLE_CONTAINER_HEADERS] {
+                if let Some(size) = self.store.wipe_chunk(table, container_id)? {
+                    report.bytes += size as u64;
+                }
+            }
+            report.containers += 1;
+            container_ref.collected = true;
+            self.store.store_sten(db::CONTAINER_REFS, container_id, &container_ref)?;
+        }
+        if report.containers > 0 {
+            info!("Garbage collection has removed {}", report);
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,99 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Import of a contract from its genesis shared either as a Bech32 string or
+//! in ASCII-armored form. The contract schema must be already known to the
+//! node.
+
+use std::str::FromStr;
+
+use rgb::{Consignment, Contract, ContractState, Genesis, Schema, SchemaId, Validity};
+use rgb_rpc::{Armor, FailureCode, ARMOR_BOUNDARY};
+
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ImportError {
+    /// contract genesis can't be decoded from the given string. Details: {0}
+    Encoding(String),
+
+    /// schema {0} of the imported contract is not known to the node; register a contract issued
+    /// under the schema first.
+    SchemaUnknown(SchemaId),
+
+    /// imported contract is not valid. Details: {0}
+    Invalid(String),
+}
+
+impl From<&ImportError> for FailureCode {
+    fn from(err: &ImportError) -> Self {
+        match err {
+            ImportError::Encoding(_) => FailureCode::Encoding,
+            ImportError::SchemaUnknown(_) => FailureCode::Absent,
+            ImportError::Invalid(_) => FailureCode::InvalidConsignment,
+        }
+    }
+}
+
+impl Runtime {
+    /// Decodes contract genesis, validates the contract against its schema
+    /// and stores it to the stash, returning the contract state.
+    pub(super) fn import_contract(&mut self, genesis: &str) -> Result<ContractState, DaemonError> {
+        let genesis = decode_genesis(genesis)?;
+        let schema_id = genesis.schema_id();
+        let schema: Schema = self
+            .store
+            .retrieve_sten(db::SCHEMATA, schema_id)?
+            .ok_or(ImportError::SchemaUnknown(schema_id))?;
+        let root_schema = if schema.root_id != zero!() {
+            Some(
+                self.store
+                    .retrieve_sten(db::SCHEMATA, schema.root_id)?
+                    .ok_or(ImportError::SchemaUnknown(schema.root_id))?,
+            )
+        } else {
+            None
+        };
+
+        let contract = Contract::with(schema, root_schema, genesis, empty!(), empty!(), empty!());
+        let contract_id = contract.contract_id();
+        info!("Importing contract {} with schema {}", contract_id, schema_id);
+
+        let status = self.process_consignment(contract, false, true, None, None)?;
+        if status.validity() != Validity::Valid {
+            let failures = status.failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Err(ImportError::Invalid(failures.join("; ")).into());
+        }
+
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        Ok(state)
+    }
+}
+
+/// Decodes genesis from its ASCII-armored form or from Bech32 string. Bech32
+/// string may be split into multiple lines and framed with armor header and
+/// footer lines, which are skipped.
+pub(crate) fn decode_genesis(s: &str) -> Result<Genesis, ImportError> {
+    if let Ok(genesis) = Genesis::from_armored_str(s) {
+        return Ok(genesis);
+    }
+    let data = s
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with(ARMOR_BOUNDARY))
+        .collect::<String>();
+    Genesis::from_str(&data).map_err(|err| ImportError::Encoding(err.to_string()))
+}
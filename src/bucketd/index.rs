@@ -0,0 +1,132 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::Hash;
+use storm::ChunkId;
+
+use crate::db::{self, Store};
+use crate::DaemonError;
+
+/// Number of filter bits allocated per indexed item; with the optimal number
+/// of hash functions gives ~1% false positive rate.
+const BITS_PER_ITEM: usize = 10;
+/// Number of hash functions used by the filter
+const HASHES: u64 = 7;
+/// Minimal filter size, in 64-bit words
+const MIN_WORDS: usize = 1024;
+
+/// In-memory bloom filter over the ids of the `OUTPOINTS` table, allowing to
+/// skip store requests for outpoints which were never indexed.
+///
+/// The filter is built from the list of the table ids on the first use and is
+/// updated with the outpoints indexed by this daemon. The ids indexed since
+/// the last call to [`OutpointIndex::take_added`] are collected, such that
+/// other bucket daemons can add them to their filters with
+/// [`OutpointIndex::extend`] when this daemon reports contract state update.
+pub(crate) struct OutpointIndex {
+    bits: Vec<u64>,
+    capacity: usize,
+    items: usize,
+    stale: bool,
+    added: BTreeSet<ChunkId>,
+}
+
+impl Default for OutpointIndex {
+    fn default() -> Self {
+        OutpointIndex {
+            bits: vec![],
+            capacity: 0,
+            items: 0,
+            stale: true,
+            added: empty!(),
+        }
+    }
+}
+
+impl OutpointIndex {
+    /// Adds outpoint chunk id indexed by this daemon to the filter.
+    pub fn insert(&mut self, id: ChunkId) {
+        self.added.insert(id);
+        self.add(id);
+    }
+
+    /// Adds outpoint chunk ids indexed by other daemons to the filter.
+    pub fn extend(&mut self, ids: impl IntoIterator<Item = ChunkId>) {
+        for id in ids {
+            self.add(id);
+        }
+    }
+
+    /// Returns outpoint chunk ids indexed by this daemon since the last call.
+    pub fn take_added(&mut self) -> BTreeSet<ChunkId> { std::mem::take(&mut self.added) }
+
+    fn add(&mut self, id: ChunkId) {
+        if self.stale {
+            return;
+        }
+        self.items += 1;
+        if self.items > self.capacity {
+            // False positive rate grows too high; rebuild with larger filter
+            self.stale = true;
+            return;
+        }
+        for bit in self.bit_positions(id) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Forces filter to be rebuilt from the store on the next use.
+    pub fn invalidate(&mut self) { self.stale = true; }
+
+    /// Returns those of the given outpoint chunk ids which may be present in
+    /// the `OUTPOINTS` table. May return false positives, but never misses
+    /// present ids.
+    pub fn filter(
+        &mut self,
+        store: &mut dyn Store,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, DaemonError> {
+        if self.stale {
+            self.rebuild(store)?;
+        }
+        Ok(ids.into_iter().filter(|id| self.contains(*id)).collect())
+    }
+
+    fn rebuild(&mut self, store: &mut dyn Store) -> Result<(), DaemonError> {
+        let ids = store.ids(db::OUTPOINTS)?;
+        // We reserve the space for the twice as many outpoints as already known
+        self.capacity = (ids.len() * 2).max(MIN_WORDS * 64 / BITS_PER_ITEM);
+        self.bits = vec![0u64; self.capacity * BITS_PER_ITEM / 64 + 1];
+        self.items = 0;
+        self.stale = false;
+        debug!("Building outpoint index filter for {} outpoints", ids.len());
+        self.extend(ids);
+        Ok(())
+    }
+
+    fn contains(&self, id: ChunkId) -> bool {
+        self.bit_positions(id).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    fn bit_positions(&self, id: ChunkId) -> impl Iterator<Item = usize> {
+        // Chunk ids are hashes, so we use their parts as independent hash values
+        let bytes = id.into_inner();
+        let mut h1 = [0u8; 8];
+        let mut h2 = [0u8; 8];
+        h1.copy_from_slice(&bytes[..8]);
+        h2.copy_from_slice(&bytes[8..16]);
+        let h1 = u64::from_le_bytes(h1);
+        let h2 = u64::from_le_bytes(h2);
+        let len = self.bits.len() as u64 * 64;
+        (0..HASHES).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize)
+    }
+}
@@ -0,0 +1,149 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+
+use internet2::ZmqSocketType;
+use microservices::esb::{self, ClientId, EndpointList};
+use microservices::node::TryService;
+use rgb_rpc::now;
+
+use crate::bus::{BusMsg, CtlMsg, DaemonId, ServiceBus, ServiceId};
+use crate::{Config, DaemonError};
+
+/// Converts request timeout, in seconds, into the request deadline
+pub(crate) fn deadline(timeout: Option<u32>) -> Option<u64> {
+    timeout.map(|timeout| now() + timeout as u64)
+}
+
+/// Detects whether the request deadline has passed
+pub(crate) fn expired(deadline: Option<u64>) -> bool {
+    matches!(deadline, Some(deadline) if deadline <= now())
+}
+
+/// Cooperative interruption of the requests processed by the bucket daemon.
+///
+/// The daemon processes a single request at a time and is unable to receive
+/// messages until it completes it, so cancellations are received by a
+/// separate listener thread connected to the CTL bus. Long-running operations
+/// check for the cancellation and the request deadline between the processed
+/// bundles and return an error, leaving the stash in a consistent state.
+#[derive(Clone, Default)]
+pub(crate) struct Interrupt {
+    cancelled: Arc<Mutex<BTreeSet<ClientId>>>,
+    /// Client and deadline of the request being processed
+    request: Option<(ClientId, Option<u64>)>,
+}
+
+impl Interrupt {
+    /// Starts listener thread receiving cancellations from RGBd
+    pub fn listen(config: &Config, daemon_id: DaemonId) -> Interrupt {
+        let interrupt = Interrupt::default();
+        let listener = Listener {
+            daemon_id,
+            cancelled: interrupt.cancelled.clone(),
+        };
+        let ctl_endpoint = config.ctl_endpoint.clone();
+        thread::Builder::new()
+            .name(s!("interrupts"))
+            .spawn(move || {
+                let controller = match esb::Controller::with(
+                    map! {
+                        ServiceBus::Ctl => esb::BusConfig::with_addr(
+                            ctl_endpoint,
+                            ZmqSocketType::RouterConnect,
+                            Some(ServiceId::rgbd())
+                        )
+                    },
+                    listener,
+                ) {
+                    Ok(controller) => controller,
+                    Err(err) => {
+                        error!("Interrupt listener is unable to connect to CTL bus: {}", err);
+                        return;
+                    }
+                };
+                controller.run_or_panic("interrupts");
+            })
+            .expect("unable to start interrupt listener thread");
+        interrupt
+    }
+
+    /// Registers start of the request processing. Cancellations received
+    /// after the completion of the previous requests are outdated and are
+    /// dropped.
+    pub fn start(&mut self, client_id: ClientId, deadline: Option<u64>) {
+        self.cancelled().clear();
+        self.request = Some((client_id, deadline));
+    }
+
+    /// Registers completion of the request processing
+    pub fn finish(&mut self) { self.request = None; }
+
+    /// Returns error if the request being processed is cancelled or its
+    /// deadline has passed
+    pub fn check(&self) -> Result<(), DaemonError> {
+        let (client_id, deadline) = match self.request {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+        if self.cancelled().contains(&client_id) {
+            warn!("Request of client {} is cancelled", client_id);
+            return Err(DaemonError::Cancelled(client_id));
+        }
+        if expired(deadline) {
+            warn!("Deadline of the request of client {} has expired", client_id);
+            return Err(DaemonError::DeadlineExpired);
+        }
+        Ok(())
+    }
+
+    fn cancelled(&self) -> MutexGuard<BTreeSet<ClientId>> {
+        self.cancelled.lock().expect("interrupt state is poisoned")
+    }
+}
+
+/// Handler of the interrupt listener CTL bus connection
+struct Listener {
+    daemon_id: DaemonId,
+    cancelled: Arc<Mutex<BTreeSet<ClientId>>>,
+}
+
+impl esb::Handler<ServiceBus> for Listener {
+    type Request = BusMsg;
+    type Error = esb::Error<ServiceId>;
+
+    fn identity(&self) -> ServiceId { ServiceId::BucketInterrupts(self.daemon_id) }
+
+    fn handle(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        _: ServiceBus,
+        _: ServiceId,
+        request: BusMsg,
+    ) -> Result<(), Self::Error> {
+        if let BusMsg::Ctl(CtlMsg::Cancel(client_id)) = request {
+            debug!("Received cancellation of the request of client {}", client_id);
+            self.cancelled.lock().expect("interrupt state is poisoned").insert(client_id);
+        }
+        Ok(())
+    }
+
+    fn handle_err(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        _: esb::Error<ServiceId>,
+    ) -> Result<(), Self::Error> {
+        // Errors are already reported by the controller
+        Ok(())
+    }
+}
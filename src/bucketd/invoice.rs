@@ -0,0 +1,127 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Invoices created by the node, which generates the seal blinding factor
+//! from its entropy source and keeps the seal reveal, so the received state
+//! is spendable once the consignment paying the invoice is accepted.
+//!
+//! Created invoices are kept in the invoice registry, keyed by the invoice
+//! id committing to the contract and the concealed invoice seal. Each
+//! accepted transfer assigning state to an invoice seal adds the revealed
+//! amount to the invoice, which status then tells whether the payment fits
+//! the invoice tolerance; open invoices accept any amount.
+
+use std::collections::BTreeMap;
+
+use bitcoin::OutPoint;
+use bp::seals::txout::CloseMethod;
+use internet2::addr::NodeAddr;
+use rgb::secp256k1zkp::rand::Rng;
+use rgb::{
+    AtomicValue, Consignment, ConsignmentType, ContractId, Genesis, InmemConsignment, Node,
+    SealEndpoint, TransitionBundle, TypedAssignments,
+};
+use rgb_rpc::{
+    now, Invoice, InvoiceId, InvoiceRecord, InvoiceStatus, InvoiceTolerance, NetworkMagic, Reveal,
+};
+
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Creates invoice for the contract asset paid to a blinded seal on the
+    /// given outpoint and adds it to the invoice registry. The invoice is
+    /// refused if the entropy source fails its health check.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_invoice(
+        &mut self,
+        contract_id: ContractId,
+        amount: AtomicValue,
+        outpoint: OutPoint,
+        close_method: CloseMethod,
+        transport: Option<NodeAddr>,
+        signet: Option<NetworkMagic>,
+        tolerance: InvoiceTolerance,
+    ) -> Result<Invoice, DaemonError> {
+        let _: Genesis =
+            self.store.retrieve_sten(db::GENESIS, contract_id)?.ok_or(StashError::GenesisAbsent)?;
+
+        let reveal = Reveal {
+            blinding_factor: self.entropy.rng()?.gen(),
+            outpoint,
+            close_method,
+        };
+        let seal = reveal.to_seal();
+        self.reveal_seal(contract_id, reveal)?;
+
+        let invoice = Invoice {
+            contract_id,
+            amount,
+            beneficiary: SealEndpoint::from(seal),
+            transport,
+            signet,
+            key: self.consignment_key.map(|key| key.x_only_public_key().0),
+        };
+        let record = InvoiceRecord::with(invoice.clone(), tolerance, now());
+        self.store.store_sten(db::INVOICES, invoice.invoice_id(), &record)?;
+        match invoice.is_open() {
+            true => info!("Created open invoice for {} on {}", contract_id, outpoint),
+            false => info!("Created invoice for {} of {} on {}", amount, contract_id, outpoint),
+        }
+
+        Ok(invoice)
+    }
+
+    /// Records amounts which the accepted transfer assigns to the seals of
+    /// the registered invoices. Amounts are recorded as they arrive, even if
+    /// they do not fit the invoice tolerance.
+    pub(super) fn match_invoices<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        let mut received: BTreeMap<InvoiceId, AtomicValue> = bmap! {};
+        for assignment in consignment
+            .endpoint_bundles()
+            .into_iter()
+            .flat_map(TransitionBundle::known_transitions)
+            .flat_map(|transition| transition.owned_rights().values())
+            .flat_map(TypedAssignments::to_value_assignments)
+        {
+            let invoice_id = InvoiceId::with(contract_id, assignment.to_confidential_seal());
+            let amount =
+                assignment.as_revealed_state().map(|state| state.value).unwrap_or_default();
+            let sum = received.entry(invoice_id).or_default();
+            *sum = sum.saturating_add(amount);
+        }
+
+        let timestamp = now();
+        for (invoice_id, amount) in received {
+            let mut record: InvoiceRecord =
+                match self.store.retrieve_sten(db::INVOICES, invoice_id)? {
+                    Some(record) => record,
+                    None => continue,
+                };
+            record.receive(consignment.id(), amount, timestamp);
+            self.store.store_sten(db::INVOICES, invoice_id, &record)?;
+            match record.status {
+                InvoiceStatus::Paid => {
+                    info!("Invoice {} is paid with {}", record.invoice, record.received)
+                }
+                status => warn!(
+                    "Invoice {} is {}: received {} of {}",
+                    record.invoice, status, record.received, record.invoice.amount
+                ),
+            }
+        }
+        Ok(())
+    }
+}
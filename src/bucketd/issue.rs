@@ -0,0 +1,301 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Contract issuance from the declarative specification of its genesis.
+
+use std::collections::BTreeMap;
+
+use bitcoin::hashes::hex::FromHex;
+use rgb::schema::{Bits, DataFormat, FieldType, OwnedRightType, StateSchema};
+use rgb::secp256k1zkp::rand::{CryptoRng, Rng};
+use rgb::{
+    data, seal, value, Assignment, Consignment, Contract, FieldValues, Genesis, Metadata,
+    OwnedRights, Schema, SchemaId, TypedAssignments, Validity,
+};
+use rgb_rpc::{FailureCode, IssueSpec, MetadataValue};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum IssueError {
+    /// schema {0} is not known to the node; provide the schema file together with the issuance
+    /// specification.
+    SchemaUnknown(SchemaId),
+
+    /// provided schema {found} does not match schema {expected} of the issuance specification.
+    SchemaMismatch { expected: SchemaId, found: SchemaId },
+
+    /// schema does not allow metadata field {0} in the contract genesis.
+    UnexpectedField(FieldType),
+
+    /// contract genesis must have from {min} to {max} values of the metadata field {field}, while
+    /// the specification provides {found}.
+    FieldOccurrences {
+        field: FieldType,
+        min: u16,
+        max: u16,
+        found: usize,
+    },
+
+    /// value `{value}` does not match the format of the metadata field {field} defined by the
+    /// schema.
+    FieldFormat { field: FieldType, value: String },
+
+    /// schema does not allow owned right {0} in the contract genesis.
+    UnexpectedRight(OwnedRightType),
+
+    /// contract genesis must have from {min} to {max} assignments of the owned right {right},
+    /// while the specification provides {found}.
+    RightOccurrences {
+        right: OwnedRightType,
+        min: u16,
+        max: u16,
+        found: usize,
+    },
+
+    /// owned right {0} has fungible state and requires an amount.
+    AmountRequired(OwnedRightType),
+
+    /// owned right {0} is declarative and can't be assigned an amount.
+    AmountUnexpected(OwnedRightType),
+
+    /// owned right {0} has a state type which is not supported by issuance specifications.
+    UnsupportedState(OwnedRightType),
+
+    /// issued contract is not valid. Details: {0}
+    Invalid(String),
+}
+
+impl From<&IssueError> for FailureCode {
+    fn from(err: &IssueError) -> Self {
+        match err {
+            IssueError::SchemaUnknown(_) => FailureCode::Absent,
+            IssueError::Invalid(_) => FailureCode::InvalidConsignment,
+            _ => FailureCode::InvalidIssueSpec,
+        }
+    }
+}
+
+impl Runtime {
+    /// Creates contract genesis from the issuance specification, validates
+    /// the contract and stores it to the stash.
+    pub(super) fn issue_contract(
+        &mut self,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+    ) -> Result<Contract, DaemonError> {
+        let contract = self.compose_contract(spec, schema)?;
+        info!("Issuing contract {} with schema {}", contract.contract_id(), contract.schema_id());
+
+        let status = self.process_consignment(contract.clone(), false, true, None, None)?;
+        if status.validity() != Validity::Valid {
+            let failures = status.failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Err(IssueError::Invalid(failures.join("; ")).into());
+        }
+
+        Ok(contract)
+    }
+
+    /// Creates contract with the genesis matching the issuance specification,
+    /// without validating or storing it.
+    pub(super) fn compose_contract(
+        &mut self,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+    ) -> Result<Contract, DaemonError> {
+        let schema = match schema {
+            Some(schema) if schema.schema_id() != spec.schema => {
+                return Err(IssueError::SchemaMismatch {
+                    expected: spec.schema,
+                    found: schema.schema_id(),
+                }
+                .into())
+            }
+            Some(schema) => schema,
+            None => self
+                .store
+                .retrieve_sten(db::SCHEMATA, spec.schema)?
+                .ok_or(IssueError::SchemaUnknown(spec.schema))?,
+        };
+        let root_schema_id = schema.root_id;
+        let root_schema = if root_schema_id != zero!() {
+            Some(
+                self.store
+                    .retrieve_sten(db::SCHEMATA, root_schema_id)?
+                    .ok_or(IssueError::SchemaUnknown(root_schema_id))?,
+            )
+        } else {
+            None
+        };
+
+        let metadata = genesis_metadata(&schema, &spec)?;
+        let owned_rights = genesis_owned_rights(&schema, &spec, &mut self.entropy.rng()?)?;
+        let genesis =
+            Genesis::with(spec.schema, self.chain.clone(), metadata, owned_rights, empty!());
+        Ok(Contract::with(schema, root_schema, genesis, empty!(), empty!(), empty!()))
+    }
+}
+
+pub(super) fn genesis_metadata(schema: &Schema, spec: &IssueSpec) -> Result<Metadata, IssueError> {
+    if let Some(field) = spec.metadata.keys().find(|f| !schema.genesis.metadata.contains_key(f)) {
+        return Err(IssueError::UnexpectedField(*field));
+    }
+
+    let mut metadata = bmap! {};
+    for (field, occ) in &schema.genesis.metadata {
+        let values = spec.metadata.get(field).map(Vec::as_slice).unwrap_or_default();
+        if values.len() < occ.min_value() as usize || values.len() > occ.max_value() as usize {
+            return Err(IssueError::FieldOccurrences {
+                field: *field,
+                min: occ.min_value(),
+                max: occ.max_value(),
+                found: values.len(),
+            });
+        }
+        if values.is_empty() {
+            continue;
+        }
+        let format = schema.field_types.get(field).ok_or(IssueError::UnexpectedField(*field))?;
+        let values = values
+            .iter()
+            .map(|value| field_value(*field, format, value))
+            .collect::<Result<Vec<_>, _>>()?;
+        metadata.insert(*field, FieldValues::from(values));
+    }
+    Ok(Metadata::from(metadata))
+}
+
+fn field_value(
+    field: FieldType,
+    format: &DataFormat,
+    value: &MetadataValue,
+) -> Result<data::Revealed, IssueError> {
+    let mismatch = || IssueError::FieldFormat {
+        field,
+        value: value.to_string(),
+    };
+    Ok(match (format, value) {
+        (DataFormat::Unsigned(bits, min, max), MetadataValue::Unsigned(v)) => {
+            if !(*min..=*max).contains(&(*v as u128)) {
+                return Err(mismatch());
+            }
+            match bits {
+                Bits::Bit8 => data::Revealed::U8(u8::try_from(*v).map_err(|_| mismatch())?),
+                Bits::Bit16 => data::Revealed::U16(u16::try_from(*v).map_err(|_| mismatch())?),
+                Bits::Bit32 => data::Revealed::U32(u32::try_from(*v).map_err(|_| mismatch())?),
+                Bits::Bit64 => data::Revealed::U64(*v),
+                _ => return Err(mismatch()),
+            }
+        }
+        (DataFormat::Integer(bits, min, max), _) => {
+            // Non-negative numbers are always parsed from the specification as unsigned
+            let v = match value {
+                MetadataValue::Unsigned(v) => i64::try_from(*v).map_err(|_| mismatch())?,
+                MetadataValue::Integer(v) => *v,
+                MetadataValue::Text(_) => return Err(mismatch()),
+            };
+            if !(*min..=*max).contains(&(v as i128)) {
+                return Err(mismatch());
+            }
+            match bits {
+                Bits::Bit8 => data::Revealed::I8(i8::try_from(v).map_err(|_| mismatch())?),
+                Bits::Bit16 => data::Revealed::I16(i16::try_from(v).map_err(|_| mismatch())?),
+                Bits::Bit32 => data::Revealed::I32(i32::try_from(v).map_err(|_| mismatch())?),
+                Bits::Bit64 => data::Revealed::I64(v),
+                _ => return Err(mismatch()),
+            }
+        }
+        (DataFormat::String(len), MetadataValue::Text(s)) if s.len() <= *len as usize => {
+            if s.is_ascii() {
+                data::Revealed::AsciiString(s.clone())
+            } else {
+                data::Revealed::UnicodeString(s.clone())
+            }
+        }
+        (DataFormat::Bytes(len), MetadataValue::Text(s)) => {
+            let bytes = Vec::<u8>::from_hex(s).map_err(|_| mismatch())?;
+            if bytes.len() > *len as usize {
+                return Err(mismatch());
+            }
+            data::Revealed::Bytes(bytes)
+        }
+        _ => return Err(mismatch()),
+    })
+}
+
+/// Constructs genesis assignments, blinding the seals and the amounts with the
+/// given random number generator.
+pub(super) fn genesis_owned_rights(
+    schema: &Schema,
+    spec: &IssueSpec,
+    rng: &mut (impl Rng + CryptoRng),
+) -> Result<OwnedRights, IssueError> {
+    let mut assignments = BTreeMap::<OwnedRightType, Vec<_>>::new();
+    for assignment in &spec.assignments {
+        if !schema.genesis.owned_rights.contains_key(&assignment.right_type) {
+            return Err(IssueError::UnexpectedRight(assignment.right_type));
+        }
+        let seal = seal::Revealed {
+            method: assignment.method,
+            blinding: rng.gen(),
+            txid: Some(assignment.outpoint.txid),
+            vout: assignment.outpoint.vout,
+        };
+        assignments.entry(assignment.right_type).or_default().push((seal, assignment.amount));
+    }
+
+    let mut owned_rights = bmap! {};
+    for (right_type, occ) in &schema.genesis.owned_rights {
+        let assigned = assignments.remove(right_type).unwrap_or_default();
+        if assigned.len() < occ.min_value() as usize || assigned.len() > occ.max_value() as usize {
+            return Err(IssueError::RightOccurrences {
+                right: *right_type,
+                min: occ.min_value(),
+                max: occ.max_value(),
+                found: assigned.len(),
+            });
+        }
+        if assigned.is_empty() {
+            continue;
+        }
+        let typed_assignments = match schema.owned_right_types.get(right_type) {
+            Some(StateSchema::Declarative) => TypedAssignments::Void(
+                assigned
+                    .into_iter()
+                    .map(|(seal, amount)| match amount {
+                        None => Ok(Assignment::Revealed {
+                            seal,
+                            state: data::Void(),
+                        }),
+                        Some(_) => Err(IssueError::AmountUnexpected(*right_type)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            Some(StateSchema::DiscreteFiniteField(_)) => TypedAssignments::Value(
+                assigned
+                    .into_iter()
+                    .map(|(seal, amount)| match amount {
+                        Some(amount) => Ok(Assignment::Revealed {
+                            seal,
+                            state: value::Revealed::with_amount(amount, rng),
+                        }),
+                        None => Err(IssueError::AmountRequired(*right_type)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => return Err(IssueError::UnsupportedState(*right_type)),
+        };
+        owned_rights.insert(*right_type, typed_assignments);
+    }
+    Ok(OwnedRights::from(owned_rights))
+}
@@ -0,0 +1,100 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Limits of the contract script execution during the consignment validation.
+//! Scripts are executed by RGB Core once per each validated contract node,
+//! without instruction or memory accounting exposed to the node, so the
+//! consignments are measured before the validation by the number of the
+//! script runs and the script size, and refused if they exceed the limits.
+
+use std::io;
+
+use rgb::{Consignment, ConsignmentId, ConsignmentType, InmemConsignment, SchemaId};
+use rgb_rpc::{FailureCode, ValidationCost};
+use strict_encoding::StrictEncode;
+
+use crate::Config;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ScriptLimitError {
+    /// validation of consignment {consignment_id} requires {runs} script runs,
+    /// exceeding the node limit of {limit} runs.
+    TooManyRuns {
+        consignment_id: ConsignmentId,
+        runs: u32,
+        limit: u32,
+    },
+
+    /// validation script of schema {schema_id} has {size} bytes, exceeding the
+    /// node limit of {limit} bytes.
+    ScriptSize {
+        schema_id: SchemaId,
+        size: u32,
+        limit: u32,
+    },
+}
+
+impl From<&ScriptLimitError> for FailureCode {
+    fn from(_: &ScriptLimitError) -> Self { FailureCode::ScriptLimit }
+}
+
+/// Limits of the script execution applied to each validated consignment
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ScriptLimits {
+    /// Maximum number of the contract nodes which scripts are executed
+    pub max_runs: u32,
+    /// Maximum size of the schema validation script, in bytes
+    pub max_size: u32,
+}
+
+impl From<&Config> for ScriptLimits {
+    fn from(config: &Config) -> Self {
+        ScriptLimits {
+            max_runs: config.max_script_runs,
+            max_size: config.max_script_size,
+        }
+    }
+}
+
+impl ScriptLimits {
+    /// Measures the script execution required to validate the consignment,
+    /// failing if it exceeds the limits. Validation duration is left zero.
+    pub fn check<C: ConsignmentType>(
+        &self,
+        consignment: &InmemConsignment<C>,
+    ) -> Result<ValidationCost, ScriptLimitError> {
+        let schema = consignment.schema();
+        let size = schema.script.strict_encode(io::sink()).expect("in-memory encoding failure");
+        let size = u32::try_from(size).unwrap_or(u32::MAX);
+        if size > self.max_size {
+            return Err(ScriptLimitError::ScriptSize {
+                schema_id: schema.schema_id(),
+                size,
+                limit: self.max_size,
+            });
+        }
+
+        let runs = u32::try_from(consignment.node_ids().len()).unwrap_or(u32::MAX);
+        if runs > self.max_runs {
+            return Err(ScriptLimitError::TooManyRuns {
+                consignment_id: consignment.id(),
+                runs,
+                limit: self.max_runs,
+            });
+        }
+
+        Ok(ValidationCost {
+            script_runs: runs,
+            script_size: size,
+            duration_ms: 0,
+        })
+    }
+}
@@ -12,8 +12,83 @@ mod service;
 #[cfg(feature = "server")]
 mod opts;
 mod processor;
+mod ancestry;
+mod checkpoint;
+mod balance;
+mod stats;
+mod usage;
+mod digest;
+mod cache;
+mod views;
+mod index;
+mod pool;
+mod policy;
+mod swap;
+mod payjoin;
+mod issue;
+mod import;
+mod preview;
+mod payment;
+mod quarantine;
+mod gc;
+mod retention;
+mod transfers;
+mod wallet;
+mod interrupt;
+mod progress;
+mod proofs;
+mod cpfp;
+mod forget;
+mod reveal;
+mod invoice;
+mod rebuild;
+mod revalidation;
+mod schemata;
+mod deps;
+mod simulation;
+#[cfg(feature = "test-utils")]
+mod synthetic;
+#[cfg(feature = "test-utils")]
+mod fixtures;
+mod wal;
+#[cfg(feature = "bdk-wallet")]
+mod bdk_wallet;
+mod limits;
+#[cfg(feature = "sql")]
+mod export;
 
+pub(crate) use ancestry::ContractAncestry;
+pub use checkpoint::CheckpointError;
+pub(crate) use checkpoint::{contract_transitions, verify_checkpoint};
+pub(crate) use cpfp::CpfpPackage;
+#[cfg(feature = "test-utils")]
+pub use fixtures::{fixture_schema, Fixture, FixtureError, Scenario};
+pub(crate) use forget::ForgottenContract;
+pub(crate) use gc::ContainerRef;
+pub(crate) use import::decode_genesis;
+pub use import::ImportError;
+pub(crate) use interrupt::{deadline, expired};
+pub use issue::IssueError;
+pub use limits::ScriptLimitError;
+pub(crate) use limits::ScriptLimits;
 #[cfg(feature = "server")]
 pub use opts::Opts;
+pub use payjoin::PayjoinError;
+pub(crate) use payjoin::PayjoinRecord;
+pub use payment::PaymentError;
+pub use policy::PolicyError;
 pub use processor::{FinalizeError, StashError};
+pub use quarantine::QuarantineError;
+pub(crate) use quarantine::{take_quarantined, QuarantineEntry};
+pub(crate) use revalidation::ValidationStamp;
+#[cfg(feature = "testkit")]
+pub use service::run_with_backend;
 pub use service::{run, Runtime};
+pub use simulation::SimulationError;
+pub use swap::SwapError;
+pub(crate) use swap::{SwapRecord, SwapStatus};
+#[cfg(feature = "test-utils")]
+pub use synthetic::{HistoryShape, SyntheticError, SyntheticHistory, SYNTHETIC_AMOUNT};
+pub(crate) use wal::WalEntry;
+pub use wallet::WalletError;
+pub(crate) use wallet::{wallet_id, WalletState};
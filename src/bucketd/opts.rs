@@ -10,6 +10,7 @@
 
 use clap::Parser;
 
+use crate::bus::DaemonId;
 use crate::opts::Opts as SharedOpts;
 
 /// Command-line arguments
@@ -29,6 +30,52 @@ pub struct Opts {
     #[doc(hidden)]
     #[clap(short = 'E', long = "storm", hide = true)]
     pub storm_endpoint: Option<String>,
+
+    /// Identifier assigned to the daemon by RGBd
+    #[clap(long, hide = true)]
+    pub daemon_id: Option<DaemonId>,
+
+    // RGBd options, which are passed to the bucket daemons launched as
+    // processes and are not used by them
+    #[doc(hidden)]
+    #[clap(long = "rpc-listen", hide = true)]
+    pub rpc_listen: Vec<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "rpc-socket-mode", hide = true)]
+    pub rpc_socket_mode: Option<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "signer", hide = true)]
+    pub signer_endpoint: Option<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "trusted-issuer", hide = true)]
+    pub trusted_issuers: Vec<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "admin-key", hide = true)]
+    pub admin_keys: Vec<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "order-key", hide = true)]
+    pub order_keys: Vec<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "events", hide = true)]
+    pub events_endpoint: Option<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "events-json", hide = true)]
+    pub events_json: bool,
+
+    #[doc(hidden)]
+    #[clap(long = "interactive-workers", hide = true)]
+    pub interactive_workers: Option<String>,
+
+    #[doc(hidden)]
+    #[clap(long = "batch-workers", hide = true)]
+    pub batch_workers: Option<String>,
 }
 
 #[cfg(feature = "server")]
@@ -0,0 +1,457 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::psbt::PartiallySignedTransaction;
+use bitcoin::secp256k1::rand::random;
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
+use bitcoin_hd::{SegmentIndexes, UnhardenedIndex};
+use bitcoin_scripts::PubkeyScript;
+use bp::dbc::Proof;
+use bp::seals::txout::CloseMethod;
+use commit_verify::lnpbp4;
+use miniscript::Descriptor;
+use psbt::Psbt;
+use rgb::psbt::RgbExt;
+use rgb::secp256k1zkp::rand::Rng;
+use rgb::{
+    seal, Anchor, BundleId, Consignment, ContractConsignment, ContractId, Disclosure, Node,
+    SealEndpoint, StateTransfer, TransitionBundle,
+};
+use rgb_rpc::{
+    FailureCode, OutpointFilter, PayjoinContribution, PayjoinId, PayjoinProposal,
+    TransferDirection, TransferStatus,
+};
+use strict_encoding::StrictEncode;
+
+use super::payment::{account_descriptor, input_descriptor, MIN_CHANGE};
+use super::swap::psbt_inputs;
+use super::transfers::transfer_record;
+use super::wallet::{keychains, wallet_key};
+use super::{FinalizeError, PaymentError, Runtime, WalletError, WalletState};
+use crate::amplify::Wrapper;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum PayjoinError {
+    /// payjoin {0} is not known to the node.
+    Unknown(PayjoinId),
+
+    /// payjoin {0} was already contributed or completed.
+    AlreadyProcessed(PayjoinId),
+
+    /// no outpoints are contributed to the payjoin.
+    NoInputs,
+
+    /// outpoint {0} is already spent by the payer.
+    InputsOverlap(OutPoint),
+
+    /// contributed outpoints have state of the paid contract {0}, which can't
+    /// be moved by the payjoin beneficiary.
+    PaidContract(ContractId),
+
+    /// joint PSBT does not spend the payer inputs.
+    InputsMismatch,
+
+    /// joint PSBT does not keep the payer outputs.
+    OutputsMismatch,
+
+    /// joint PSBT commits to the payer bundle with an output which doesn't
+    /// belong to the payer.
+    HostMismatch,
+
+    /// joint PSBT changes the payer state transition bundle.
+    BundleMismatch,
+
+    /// consignment or disclosure does not match the joint PSBT.
+    TxidMismatch,
+}
+
+impl From<&PayjoinError> for FailureCode {
+    fn from(err: &PayjoinError) -> Self {
+        match err {
+            PayjoinError::Unknown(_)
+            | PayjoinError::AlreadyProcessed(_)
+            | PayjoinError::NoInputs
+            | PayjoinError::InputsOverlap(_)
+            | PayjoinError::PaidContract(_) => FailureCode::Payjoin,
+            PayjoinError::InputsMismatch
+            | PayjoinError::OutputsMismatch
+            | PayjoinError::HostMismatch
+            | PayjoinError::BundleMismatch
+            | PayjoinError::TxidMismatch => FailureCode::PayjoinMismatch,
+        }
+    }
+}
+
+/// Party of the payjoin run by this node
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display(lowercase)]
+pub(crate) enum PayjoinRole {
+    Payer,
+    Beneficiary,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display(lowercase)]
+pub(crate) enum PayjoinStatus {
+    Proposed,
+    Contributed,
+    Completed,
+}
+
+/// Payer output, which must be kept by the joint transaction
+#[derive(Clone, PartialEq, Eq, Hash, Debug, StrictEncode, StrictDecode)]
+pub(crate) enum PayerOutput {
+    /// Output which script is not changed by the commitment
+    Script(Script, u64),
+
+    /// Output hosting tapret commitment. Its script changes once the bundles
+    /// are committed, but only by the tweak of the internal key, which stays
+    /// the same.
+    TapretHost(Option<XOnlyPublicKey>, u64),
+
+    /// Output hosting opret commitment, which script is replaced with the
+    /// commitment.
+    OpretHost(u64),
+}
+
+/// Payjoin data kept in the store between the payjoin protocol steps.
+#[derive(Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct PayjoinRecord {
+    pub role: PayjoinRole,
+    pub status: PayjoinStatus,
+    pub contract_id: ContractId,
+    /// Inputs of this party, which must be spent by the joint transaction
+    pub inputs: BTreeSet<OutPoint>,
+    /// Payer outputs, which must be kept by the joint transaction
+    pub outputs: Vec<PayerOutput>,
+    /// Bundle of the payer state transitions, which must be kept unchanged
+    /// by the joint transaction
+    pub bundle_id: Option<BundleId>,
+    /// Witness transaction id, known once the state transitions are committed
+    pub txid: Option<Txid>,
+    /// Identity of the client which has created the payjoin, if the client
+    /// was authenticated. Only this client may complete the payjoin.
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+/// Lists PSBT outputs with their amounts. Outputs hosting the commitment are
+/// listed with their internal keys instead of the scripts, since the scripts
+/// change once the bundles are committed.
+fn psbt_outputs(psbt: &Psbt) -> Vec<PayerOutput> {
+    psbt.outputs
+        .iter()
+        .map(|output| {
+            if output.is_tapret_host() {
+                PayerOutput::TapretHost(output.tap_internal_key, output.amount)
+            } else if output.is_opret_host() {
+                PayerOutput::OpretHost(output.amount)
+            } else {
+                PayerOutput::Script(output.script.as_inner().clone(), output.amount)
+            }
+        })
+        .collect()
+}
+
+/// Checks that the host outputs of the joint PSBT differ from the payer
+/// outputs only by the commitment to the given anchored bundle: tapret
+/// commitment must tweak the internal key of the payer output, and opret
+/// commitment must be unspendable.
+fn check_hosts(
+    psbt: &Psbt,
+    outputs: &[PayerOutput],
+    anchor: &Anchor<lnpbp4::MerkleProof>,
+    contract_id: ContractId,
+    bundle_id: BundleId,
+) -> Result<(), PayjoinError> {
+    let tx = PartiallySignedTransaction::from(psbt.clone()).unsigned_tx;
+    if anchor.txid != tx.txid() || anchor.verify(contract_id, bundle_id.into(), tx).is_err() {
+        return Err(PayjoinError::HostMismatch);
+    }
+    for (output, payer) in psbt.outputs.iter().zip(outputs) {
+        match (payer, &anchor.dbc_proof) {
+            (PayerOutput::Script(..), _) => {}
+            (PayerOutput::TapretHost(internal_key, _), Proof::TapretFirst(proof))
+                if *internal_key == Some(proof.internal_pk) => {}
+            (PayerOutput::OpretHost(_), _) if output.script.is_op_return() => {}
+            _ => return Err(PayjoinError::HostMismatch),
+        }
+    }
+    Ok(())
+}
+
+/// Appends inputs and outputs of the beneficiary PSBT to the payer PSBT.
+fn join_psbt(payer: Psbt, beneficiary: Psbt) -> Psbt {
+    let mut joint = PartiallySignedTransaction::from(payer);
+    let part = PartiallySignedTransaction::from(beneficiary);
+    joint.unsigned_tx.input.extend(part.unsigned_tx.input);
+    joint.unsigned_tx.output.extend(part.unsigned_tx.output);
+    joint.inputs.extend(part.inputs);
+    joint.outputs.extend(part.outputs);
+    Psbt::from(joint)
+}
+
+impl Runtime {
+    fn payjoin_record(&mut self, payjoin_id: PayjoinId) -> Result<PayjoinRecord, DaemonError> {
+        let record: PayjoinRecord = self
+            .store
+            .retrieve_sten(db::PAYJOINS, payjoin_id)?
+            .ok_or(PayjoinError::Unknown(payjoin_id))?;
+        match record.status {
+            PayjoinStatus::Proposed => Ok(record),
+            _ => Err(PayjoinError::AlreadyProcessed(payjoin_id).into()),
+        }
+    }
+
+    /// Creates payjoin proposal, remembering the payer inputs and outputs
+    /// which must be kept by the joint transaction.
+    pub(super) fn propose_payjoin(
+        &mut self,
+        psbt: Psbt,
+        consignment: StateTransfer,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<PayjoinProposal, DaemonError> {
+        let contract_id = consignment.contract_id();
+        let bundle_id = psbt
+            .rgb_bundles()?
+            .get(&contract_id)
+            .map(TransitionBundle::bundle_id)
+            .ok_or(FinalizeError::ContractBundleMissed)?;
+
+        let mut engine = sha256::Hash::engine();
+        strict_encode_list!(engine; contract_id, psbt.to_txid(), random::<u64>());
+        let payjoin_id = PayjoinId::from(sha256::Hash::from_engine(engine));
+        info!("Proposing payjoin {} for {}", payjoin_id, contract_id);
+
+        let record = PayjoinRecord {
+            role: PayjoinRole::Payer,
+            status: PayjoinStatus::Proposed,
+            contract_id,
+            inputs: psbt_inputs(&psbt),
+            outputs: psbt_outputs(&psbt),
+            bundle_id: Some(bundle_id),
+            txid: None,
+            owner,
+        };
+        self.store.store_sten(db::PAYJOINS, payjoin_id, &record)?;
+
+        Ok(PayjoinProposal {
+            payjoin_id,
+            psbt,
+            consignment,
+        })
+    }
+
+    /// Contributes wallet outpoints to the payjoin, moving all their RGB
+    /// state to the beneficiary change output with blank state transitions.
+    /// Commits to all bundles and finalizes the consignment for the paid
+    /// asset.
+    pub(super) fn contribute_payjoin(
+        &mut self,
+        proposal: PayjoinProposal,
+        descriptor: &str,
+        outpoints: BTreeSet<OutPoint>,
+        endseals: Vec<SealEndpoint>,
+        fee: u64,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<PayjoinContribution, DaemonError> {
+        let payjoin_id = proposal.payjoin_id;
+        if self.store.retrieve_sten::<PayjoinRecord>(db::PAYJOINS, payjoin_id)?.is_some() {
+            return Err(PayjoinError::AlreadyProcessed(payjoin_id).into());
+        }
+        if outpoints.is_empty() {
+            return Err(PayjoinError::NoInputs.into());
+        }
+        let contract_id = proposal.consignment.contract_id();
+        info!("Contributing {} outpoints to payjoin {}", outpoints.len(), payjoin_id);
+
+        // 1. Check that the contributed outpoints belong to the wallet and do not hold the paid
+        //    asset.
+        let account = account_descriptor(descriptor)?;
+        let key = wallet_key(&keychains(descriptor)?);
+        let wallet: WalletState =
+            self.store.retrieve_sten(db::WALLETS, key)?.ok_or(WalletError::UnknownWallet)?;
+        let payer_inputs = psbt_inputs(&proposal.psbt);
+        let mut spent = vec![];
+        for outpoint in &outpoints {
+            if payer_inputs.contains(outpoint) {
+                return Err(PayjoinError::InputsOverlap(*outpoint).into());
+            }
+            let utxo = wallet
+                .utxos
+                .iter()
+                .find(|utxo| utxo.outpoint == *outpoint)
+                .ok_or(WalletError::ForeignOutpoint(*outpoint))?;
+            spent.push(utxo);
+        }
+        let mut state = self.outpoint_state(outpoints.clone())?;
+        state.retain(|_, outpoint_map| !outpoint_map.is_empty());
+        if state.contains_key(&contract_id) {
+            return Err(PayjoinError::PaidContract(contract_id).into());
+        }
+
+        // 2. Construct the beneficiary part of the transaction spending the contributed outpoints
+        //    to the change output and join it with the payer part.
+        let required = fee.saturating_add(MIN_CHANGE);
+        let available = spent.iter().map(|utxo| utxo.value).sum::<u64>();
+        if available < required {
+            return Err(PaymentError::InsufficientFunds {
+                available,
+                required,
+            }
+            .into());
+        }
+        let inputs = spent.iter().copied().map(input_descriptor).collect::<Result<Vec<_>, _>>()?;
+        let outputs: Vec<(PubkeyScript, u64)> = vec![];
+        let change_index = wallet.last_used.get(&1).map(|index| index + 1).unwrap_or_default();
+        let change_index = UnhardenedIndex::from_index(change_index)
+            .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let part = Psbt::construct(&account, &inputs, &outputs, change_index, fee, None, &resolver)
+            .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        let mut psbt = join_psbt(proposal.psbt, part);
+
+        // 3. Move the state of the contributed outpoints to the change output with blank state
+        //    transitions.
+        let method = match account {
+            Descriptor::Tr(_) => CloseMethod::TapretFirst,
+            _ => CloseMethod::OpretFirst,
+        };
+        let seal = seal::Revealed {
+            method,
+            blinding: self.entropy.rng()?.gen(),
+            txid: None,
+            vout: (psbt.outputs.len() - 1) as u32,
+        };
+        for (cid, outpoint_map) in &state {
+            let transition = self.consolidate_allocations(*cid, outpoints.clone(), seal)?;
+            let node_id = transition.node_id();
+            if !psbt.has_rgb_contract(*cid) {
+                let contract = self.compose_consignment(
+                    *cid,
+                    empty!(),
+                    OutpointFilter::All,
+                    empty!(),
+                    ContractConsignment,
+                )?;
+                psbt.set_rgb_contract(contract)?;
+            }
+            psbt.push_rgb_transition(transition)?;
+            for input in &mut psbt.inputs {
+                if outpoint_map.contains_key(&input.previous_outpoint) {
+                    input.set_rgb_consumer(*cid, node_id)?;
+                }
+            }
+        }
+        debug!("Added blank transitions for {} contracts", state.len());
+
+        // 4. Commit to all bundles and finalize consignment for the paid asset.
+        let mut bundles = psbt.rgb_bundles()?;
+        let anchor = Anchor::commit(&mut psbt)?;
+        trace!("Anchor: {:?}", anchor);
+
+        let mut consignment = proposal.consignment;
+        let bundle = bundles.remove(&contract_id).ok_or(FinalizeError::ContractBundleMissed)?;
+        self.check_policy(contract_id, &bundle)?;
+        let bundle_id = bundle.bundle_id();
+        consignment.push_anchored_bundle(anchor.to_merkle_proof(contract_id)?, bundle)?;
+        for endseal in endseals {
+            consignment.push_seal_endpoint(bundle_id, endseal);
+        }
+
+        // 5. Construct and store disclosure for the blank transfers.
+        let txid = anchor.txid;
+        let disclosure = Disclosure::with(anchor, bundles, None);
+        self.store.store_sten(db::DISCLOSURES, txid, &disclosure)?;
+
+        let record = PayjoinRecord {
+            role: PayjoinRole::Beneficiary,
+            status: PayjoinStatus::Contributed,
+            contract_id,
+            inputs: outpoints,
+            outputs: vec![],
+            bundle_id: None,
+            txid: Some(txid),
+            owner,
+        };
+        self.store.store_sten(db::PAYJOINS, payjoin_id, &record)?;
+
+        Ok(PayjoinContribution {
+            payjoin_id,
+            psbt,
+            consignment,
+            disclosure,
+        })
+    }
+
+    /// Checks that the joint PSBT keeps the payer inputs and outputs and
+    /// marks the payjoin as completed. Returns PSBT which can be signed by
+    /// the payer.
+    pub(super) fn complete_payjoin(
+        &mut self,
+        contribution: PayjoinContribution,
+    ) -> Result<PayjoinContribution, DaemonError> {
+        let payjoin_id = contribution.payjoin_id;
+        let mut record = self.payjoin_record(payjoin_id)?;
+        if record.role != PayjoinRole::Payer {
+            return Err(PayjoinError::AlreadyProcessed(payjoin_id).into());
+        }
+        let txid = contribution.psbt.to_txid();
+        info!("Completing payjoin {} with transaction {}", payjoin_id, txid);
+
+        if !psbt_inputs(&contribution.psbt).is_superset(&record.inputs) {
+            return Err(PayjoinError::InputsMismatch.into());
+        }
+        // Beneficiary outputs are appended after the payer outputs
+        if !psbt_outputs(&contribution.psbt).starts_with(&record.outputs) {
+            return Err(PayjoinError::OutputsMismatch.into());
+        }
+        let consignment = &contribution.consignment;
+        if consignment.contract_id() != record.contract_id || !consignment.txids().contains(&txid) {
+            return Err(PayjoinError::TxidMismatch.into());
+        }
+        // The beneficiary commits to the bundles, so it must not have replaced the payer state
+        // transitions, nor redirected the payer change hosting the commitment
+        let bundles = contribution.psbt.rgb_bundles()?;
+        let bundle_id = match bundles.get(&record.contract_id).map(TransitionBundle::bundle_id) {
+            Some(bundle_id) if Some(bundle_id) == record.bundle_id => bundle_id,
+            _ => return Err(PayjoinError::BundleMismatch.into()),
+        };
+        let (anchor, _) = consignment
+            .anchored_bundles()
+            .find(|(anchor, bundle)| anchor.txid == txid && bundle.bundle_id() == bundle_id)
+            .ok_or(PayjoinError::TxidMismatch)?;
+        check_hosts(&contribution.psbt, &record.outputs, anchor, record.contract_id, bundle_id)?;
+        if contribution
+            .disclosure
+            .anchored_bundles()
+            .values()
+            .any(|(anchor, _)| anchor.txid != txid)
+        {
+            return Err(PayjoinError::TxidMismatch.into());
+        }
+
+        self.store.store_sten(db::DISCLOSURES, txid, &contribution.disclosure)?;
+        let transfer =
+            transfer_record(consignment, TransferDirection::Outgoing, TransferStatus::Finalized);
+        self.record_transfer(transfer)?;
+
+        record.status = PayjoinStatus::Completed;
+        record.txid = Some(txid);
+        self.store.store_sten(db::PAYJOINS, payjoin_id, &record)?;
+
+        Ok(contribution)
+    }
+}
@@ -0,0 +1,565 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use bitcoin::psbt::PartiallySignedTransaction;
+use bitcoin::secp256k1::SECP256K1;
+use bitcoin::{EcdsaSighashType, Script, Txid};
+use bitcoin_blockchain::locks::SeqNo;
+use bitcoin_hd::{DerivationAccount, SegmentIndexes, UnhardenedIndex};
+use bitcoin_scripts::taproot::DfsPath;
+use bitcoin_scripts::PubkeyScript;
+use bp::seals::txout::CloseMethod;
+use descriptors::InputDescriptor;
+use miniscript::psbt::PsbtExt;
+use miniscript::Descriptor;
+use psbt::Psbt;
+use rgb::blank::BLANK_TRANSITION_TYPE;
+use rgb::schema::{OwnedRightType, TransitionSchema, TransitionType};
+use rgb::secp256k1zkp::key::{SecretKey, ONE_KEY};
+use rgb::secp256k1zkp::rand::Rng;
+use rgb::secp256k1zkp::{ContextFlag, Secp256k1};
+use rgb::{
+    seal, value, Assignment, AtomicValue, ConsignmentId, ContractId, NodeId, OwnedRights,
+    ParentOwnedRights, Schema, SchemaId, SealEndpoint, StateAtom, Transition, TypedAssignments,
+};
+use rgb_rpc::{FailureCode, PaymentDraft, WalletUtxo};
+
+use super::policy::merge_assignments;
+use super::wallet::{keychains, script, wallet_key};
+use super::{Runtime, StashError, WalletError, WalletState};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Minimal amount of the change output, which hosts the commitment and keeps
+/// the change allocations. Is above the dust limit for all script types.
+pub(super) const MIN_CHANGE: u64 = 1000;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum PaymentError {
+    /// wallet has only {available} units of {contract_id} which can be spent, while the
+    /// payment requires {required} units.
+    InsufficientAssets {
+        contract_id: ContractId,
+        available: AtomicValue,
+        required: AtomicValue,
+    },
+
+    /// wallet has only {available} sats which can be spent, while the payment fee and the
+    /// change output require {required} sats.
+    InsufficientFunds { available: u64, required: u64 },
+
+    /// schema {0} does not define state transition which can be used for the payment.
+    NoTransferType(SchemaId),
+
+    /// wallet descriptor must have `<0;1>` derivation step, such that the change can be
+    /// sent to the change keychain.
+    NoChangeKeychain,
+
+    /// payments to the outputs of the witness transaction are not supported; the invoice
+    /// beneficiary must be a blinded UTXO.
+    WitnessBeneficiary,
+
+    /// payment must have at least one beneficiary.
+    NoBeneficiaries,
+
+    /// amount paid to beneficiary {0} is not given; open invoices must be paid with the
+    /// amount chosen by the payer.
+    ZeroAmount(SealEndpoint),
+
+    /// total amount paid to the beneficiaries or allocated to the spent outputs exceeds the
+    /// maximal amount of {0} units.
+    AmountOverflow(AtomicValue),
+
+    /// unable to construct payment PSBT. Details: {0}
+    Construct(String),
+
+    /// unable to finalize or broadcast the transaction. Details: {0}
+    Broadcast(String),
+
+    /// transfer {0} is unknown to the node.
+    UnknownTransfer(ConsignmentId),
+
+    /// transfer {0} is not a pending incoming transfer and can't be accelerated.
+    NotPending(ConsignmentId),
+
+    /// witness transaction of transfer {0} is not known.
+    NoWitnessTx(ConsignmentId),
+
+    /// witness transaction {0} is already mined.
+    WitnessMined(Txid),
+
+    /// wallet has no outputs of the witness transaction {0} which can be spent without RGB
+    /// state.
+    NoWitnessOutput(Txid),
+
+    /// witness transaction {txid} already pays {fee_rate} sat/vB, which is not below the
+    /// requested fee rate.
+    FeeSufficient { txid: Txid, fee_rate: u64 },
+
+    /// unable to retrieve witness transaction data. Details: {0}
+    Chain(String),
+}
+
+impl From<&PaymentError> for FailureCode {
+    fn from(err: &PaymentError) -> Self {
+        match err {
+            PaymentError::InsufficientAssets { .. } => FailureCode::InsufficientAssets,
+            PaymentError::InsufficientFunds { .. } => FailureCode::InsufficientFunds,
+            PaymentError::NoChangeKeychain => FailureCode::InvalidDescriptor,
+            PaymentError::NoTransferType(_)
+            | PaymentError::WitnessBeneficiary
+            | PaymentError::NoBeneficiaries
+            | PaymentError::ZeroAmount(_)
+            | PaymentError::AmountOverflow(_)
+            | PaymentError::Construct(_) => FailureCode::Payment,
+            PaymentError::Broadcast(_) => FailureCode::Broadcast,
+            PaymentError::UnknownTransfer(_) | PaymentError::NoWitnessTx(_) => {
+                FailureCode::UnknownTransfer
+            }
+            PaymentError::NotPending(_)
+            | PaymentError::NoWitnessOutput(_)
+            | PaymentError::FeeSufficient { .. } => FailureCode::TransferState,
+            PaymentError::WitnessMined(_) => FailureCode::TxMined,
+            PaymentError::Chain(_) => FailureCode::ChainBackend,
+        }
+    }
+}
+
+/// Constructs value assignments to our seals and to the beneficiaries, which
+/// blinding factors sum up to the blinding factors of the inputs. Unlike
+/// `TypedAssignments::zero_balanced`, takes the blinding factors from the
+/// given RNG instead of the thread RNG.
+fn zero_balanced(
+    inputs: &[value::Revealed],
+    ours: BTreeMap<seal::Revealed, AtomicValue>,
+    theirs: BTreeMap<SealEndpoint, AtomicValue>,
+    rng: &mut impl Rng,
+) -> Result<TypedAssignments, PaymentError> {
+    let count = ours.len() + theirs.len();
+    if count == 0 {
+        return Ok(TypedAssignments::Value(vec![]));
+    }
+    let secp = Secp256k1::with_caps(ContextFlag::Commit);
+    let mut blindings = (1..count).map(|_| SecretKey::new(&secp, &mut *rng)).collect::<Vec<_>>();
+    let mut positive = inputs.iter().map(|input| input.blinding.into()).collect::<Vec<_>>();
+    if positive.is_empty() {
+        positive.push(ONE_KEY);
+    }
+    // The last blinding factor corrects the sum of the random ones
+    let correction = secp
+        .blind_sum(positive, blindings.clone())
+        .map_err(|err| PaymentError::Construct(err.to_string()))?;
+    blindings.push(correction);
+
+    let mut blindings = blindings.into_iter();
+    let mut state = |value| value::Revealed {
+        value,
+        blinding: blindings.next().expect("blinding factor for each assignment").into(),
+    };
+    let mut assignments = ours
+        .into_iter()
+        .map(|(seal, value)| Assignment::Revealed {
+            seal,
+            state: state(value),
+        })
+        .collect::<Vec<_>>();
+    assignments.extend(theirs.into_iter().map(|(endpoint, value)| match endpoint {
+        SealEndpoint::ConcealedUtxo(seal) => Assignment::ConfidentialSeal {
+            seal,
+            state: state(value),
+        },
+        SealEndpoint::WitnessVout {
+            method,
+            vout,
+            blinding,
+        } => Assignment::Revealed {
+            seal: seal::Revealed {
+                method,
+                txid: None,
+                vout,
+                blinding,
+            },
+            state: state(value),
+        },
+    }));
+    Ok(TypedAssignments::Value(assignments))
+}
+
+/// Splits the change into the given number of parts with random non-zero
+/// amounts. The number of parts must not exceed the change.
+fn split_change(change: AtomicValue, parts: usize, rng: &mut impl Rng) -> Vec<AtomicValue> {
+    let mut cuts = bset! {};
+    while cuts.len() + 1 < parts {
+        cuts.insert(rng.gen::<u64>() % (change - 1) + 1);
+    }
+    cuts.insert(change);
+    let mut prev = 0;
+    cuts.into_iter()
+        .map(|cut| {
+            let part = cut - prev;
+            prev = cut;
+            part
+        })
+        .collect()
+}
+
+/// Parses wallet descriptor into account descriptor used for PSBT
+/// construction.
+pub(super) fn account_descriptor(
+    descriptor: &str,
+) -> Result<Descriptor<DerivationAccount>, DaemonError> {
+    let descriptor = descriptor.trim();
+    if !descriptor.contains("<0;1>") {
+        return Err(PaymentError::NoChangeKeychain.into());
+    }
+    let descriptor = descriptor.split('#').next().unwrap_or_default().replace("<0;1>", "0-1");
+    Descriptor::from_str(&descriptor)
+        .map_err(|err| WalletError::InvalidDescriptor(err.to_string()).into())
+}
+
+/// Finds state transition type which can move the fungible state between
+/// the seals without requiring any metadata or public rights: a transfer
+/// transition in terms of most schemata.
+pub(super) fn transfer_type(
+    schema: &Schema,
+) -> Option<(TransitionType, &TransitionSchema, OwnedRightType)> {
+    schema
+        .transitions
+        .iter()
+        .filter(|(ty, _)| **ty != BLANK_TRANSITION_TYPE)
+        .filter(|(_, t)| t.public_rights.is_empty())
+        .filter(|(_, t)| t.metadata.values().all(|occ| occ.min_value() == 0))
+        .filter_map(|(ty, t)| {
+            let right_type = *t.closes.keys().find(|right_type| {
+                t.owned_rights.contains_key(right_type) &&
+                    matches!(
+                        schema.owned_right_types.get(right_type),
+                        Some(rgb::schema::StateSchema::DiscreteFiniteField(_))
+                    )
+            })?;
+            let optional = |(other, occ): (&OwnedRightType, &rgb::schema::Occurrences)| {
+                *other == right_type || occ.min_value() == 0
+            };
+            if !t.closes.iter().all(optional) || !t.owned_rights.iter().all(optional) {
+                return None;
+            }
+            Some((*ty, t, right_type))
+        })
+        // Transition closing less rights is the more specific one
+        .min_by_key(|(_, t, _)| t.closes.len())
+}
+
+impl Runtime {
+    /// Selects wallet UTXOs with enough allocations of the contract and
+    /// bitcoins to pay the fee, constructs PSBT spending them with a change
+    /// output and the state transition paying to all the beneficiaries and
+    /// moving the rest of the state to the change output. Change addresses
+    /// used by the PSBT are marked as used in the wallet.
+    pub(super) fn compose_payment(
+        &mut self,
+        descriptor: &str,
+        contract_id: ContractId,
+        beneficiaries: BTreeMap<SealEndpoint, AtomicValue>,
+        fee: u64,
+    ) -> Result<PaymentDraft, DaemonError> {
+        if beneficiaries.is_empty() {
+            return Err(PaymentError::NoBeneficiaries.into());
+        }
+        if beneficiaries.keys().any(|seal| matches!(seal, SealEndpoint::WitnessVout { .. })) {
+            return Err(PaymentError::WitnessBeneficiary.into());
+        }
+        if let Some((seal, _)) = beneficiaries.iter().find(|(_, value)| **value == 0) {
+            return Err(PaymentError::ZeroAmount(*seal).into());
+        }
+        let amount = beneficiaries
+            .values()
+            .try_fold(0u64, |sum, value| sum.checked_add(*value))
+            .ok_or(PaymentError::AmountOverflow(AtomicValue::MAX))?;
+        let mut rng = self.entropy.rng()?;
+        let account = account_descriptor(descriptor)?;
+        let keychains = keychains(descriptor)?;
+        let key = wallet_key(&keychains);
+        let mut wallet: WalletState =
+            self.store.retrieve_sten(db::WALLETS, key)?.ok_or(WalletError::UnknownWallet)?;
+
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        let schema: Schema = self
+            .store
+            .retrieve_sten(db::SCHEMATA, state.schema_id)?
+            .ok_or(StashError::SchemaAbsent(state.schema_id))?;
+        let (transition_type, transition_schema, right_type) =
+            transfer_type(&schema).ok_or(PaymentError::NoTransferType(state.schema_id))?;
+
+        // 1. Select outpoints with the largest allocations first. Outpoints having state which
+        //    can't be closed by the transition are skipped.
+        let outpoints = wallet.utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+        let outpoint_state = state.filter_outpoint_state(&outpoints);
+        let mut candidates = outpoint_state
+            .iter()
+            .filter(|(_, set)| {
+                set.iter().all(|s| {
+                    transition_schema.closes.contains_key(&s.node_outpoint.ty)
+                        && transition_schema.owned_rights.contains_key(&s.node_outpoint.ty)
+                })
+            })
+            .map(|(outpoint, set)| -> Result<_, PaymentError> {
+                let value = set
+                    .iter()
+                    .filter(|s| s.node_outpoint.ty == right_type)
+                    .filter_map(|s| match s.state {
+                        StateAtom::Value(revealed) => Some(revealed.value),
+                        _ => None,
+                    })
+                    .try_fold(0u64, u64::checked_add)
+                    .ok_or(PaymentError::AmountOverflow(AtomicValue::MAX))?;
+                Ok((value, *outpoint))
+            })
+            .filter(|res| !matches!(res, Ok((0, _))))
+            .collect::<Result<Vec<_>, _>>()?;
+        candidates.sort_by_key(|candidate| Reverse(*candidate));
+        let mut total = 0u64;
+        let mut selected = bset! {};
+        for (value, outpoint) in candidates {
+            if total >= amount {
+                break;
+            }
+            total =
+                total.checked_add(value).ok_or(PaymentError::AmountOverflow(AtomicValue::MAX))?;
+            selected.insert(outpoint);
+        }
+        if total < amount {
+            return Err(PaymentError::InsufficientAssets {
+                contract_id,
+                available: total,
+                required: amount,
+            }
+            .into());
+        }
+
+        // Change is split into decoy allocations on separate outputs, each
+        // getting at least a single unit of the state
+        let change = total - amount;
+        let decoys = (self.decoy_change as u64).min(change.saturating_sub(1)) as usize;
+
+        // 2. Add UTXOs without any RGB state if the selected ones can't pay the fee and the decoy
+        //    outputs
+        let mut spent = wallet
+            .utxos
+            .iter()
+            .filter(|utxo| selected.contains(&utxo.outpoint))
+            .collect::<Vec<_>>();
+        let required = fee.saturating_add(MIN_CHANGE * (decoys as u64 + 1));
+        let mut available = spent.iter().map(|utxo| utxo.value).sum::<u64>();
+        if available < required {
+            let rest = outpoints.difference(&selected).copied().collect::<BTreeSet<_>>();
+            // Empty outpoint set means "all outpoints" for the outpoint state request
+            let occupied = match rest.is_empty() {
+                true => bset! {},
+                false => self
+                    .outpoint_state(rest.clone())?
+                    .into_values()
+                    .flat_map(BTreeMap::into_keys)
+                    .collect::<BTreeSet<_>>(),
+            };
+            let mut free = wallet
+                .utxos
+                .iter()
+                .filter(|utxo| rest.contains(&utxo.outpoint) && !occupied.contains(&utxo.outpoint))
+                .collect::<Vec<_>>();
+            free.sort_by_key(|utxo| Reverse(utxo.value));
+            #[cfg(feature = "bdk-wallet")]
+            if self.bdk_wallets.contains(key) {
+                let chosen = self.bdk_wallets.select(key, &free, required - available)?;
+                free.retain(|utxo| chosen.contains(&utxo.outpoint));
+            }
+            for utxo in free {
+                if available >= required {
+                    break;
+                }
+                available += utxo.value;
+                spent.push(utxo);
+            }
+        }
+        if available < required {
+            return Err(PaymentError::InsufficientFunds {
+                available,
+                required,
+            }
+            .into());
+        }
+        debug!(
+            "Paying {} of {} to {} beneficiaries from {} outpoints using {} UTXOs",
+            amount,
+            contract_id,
+            beneficiaries.len(),
+            selected.len(),
+            spent.len()
+        );
+
+        // 3. Construct PSBT with the change output hosting the commitment, preceded by the decoy
+        //    outputs on the next change addresses
+        let (method, tapret, mut outputs) = match account {
+            Descriptor::Tr(_) => (CloseMethod::TapretFirst, Some(DfsPath::new()), vec![]),
+            _ => (CloseMethod::OpretFirst, None, vec![(
+                PubkeyScript::from(Script::new_op_return(&[])),
+                0u64,
+            )]),
+        };
+        let inputs = spent.iter().copied().map(input_descriptor).collect::<Result<Vec<_>, _>>()?;
+        let change_index = wallet.last_used.get(&1).map(|index| index + 1).unwrap_or_default();
+        let mut decoy_vouts = vec![];
+        for no in 1..=decoys as u32 {
+            decoy_vouts.push(outputs.len() as u32);
+            let script = script(&keychains[1], change_index + no);
+            outputs.push((PubkeyScript::from(script), MIN_CHANGE));
+        }
+        let last_change = change_index + decoys as u32;
+        let change_index = UnhardenedIndex::from_index(change_index)
+            .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let mut psbt = Psbt::construct(
+            &account,
+            &inputs,
+            &outputs,
+            change_index,
+            fee,
+            tapret.as_ref(),
+            &resolver,
+        )
+        .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        if method == CloseMethod::OpretFirst {
+            psbt.outputs[0]
+                .set_opret_host()
+                .map_err(|err| PaymentError::Construct(err.to_string()))?;
+        }
+        // Change addresses are reserved once the payment is composed, since
+        // the last used index advances only with the wallet synchronization,
+        // and the next payment would reuse them otherwise
+        wallet.last_used.insert(1, last_change);
+        self.store.store_sten(db::WALLETS, key, &wallet)?;
+
+        // 4. Construct state transition paying to the beneficiaries, each getting its own
+        //    assignment
+        let change_seal = seal::Revealed {
+            method,
+            blinding: rng.gen(),
+            txid: None,
+            vout: (psbt.outputs.len() - 1) as u32,
+        };
+        let mut parent_owned_rights: BTreeMap<NodeId, BTreeMap<OwnedRightType, Vec<u16>>> =
+            bmap! {};
+        let mut owned_rights: BTreeMap<OwnedRightType, TypedAssignments> = bmap! {};
+        let mut inputs = vec![];
+        for set in outpoint_state.iter().filter(|(op, _)| selected.contains(op)).map(|(_, s)| s) {
+            for input in set {
+                let node_outpoint = input.node_outpoint;
+                parent_owned_rights
+                    .entry(node_outpoint.node_id)
+                    .or_default()
+                    .entry(node_outpoint.ty)
+                    .or_default()
+                    .push(node_outpoint.no);
+                match input.state {
+                    StateAtom::Value(revealed) if node_outpoint.ty == right_type => {
+                        inputs.push(revealed)
+                    }
+                    ref atom => {
+                        let assignments = atom.to_revealed_assignment_vec(change_seal);
+                        match owned_rights.get_mut(&node_outpoint.ty) {
+                            Some(existing) => merge_assignments(existing, assignments),
+                            None => {
+                                owned_rights.insert(node_outpoint.ty, assignments);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let ours = match change {
+            0 => bmap! {},
+            change => split_change(change, decoys + 1, &mut rng)
+                .into_iter()
+                .zip(Some(change_seal.vout).into_iter().chain(decoy_vouts))
+                .map(|(part, vout)| {
+                    let seal = seal::Revealed {
+                        blinding: rng.gen(),
+                        vout,
+                        ..change_seal
+                    };
+                    (seal, part)
+                })
+                .collect(),
+        };
+        let payment = zero_balanced(&inputs, ours, beneficiaries, &mut rng)?;
+        match owned_rights.get_mut(&right_type) {
+            Some(existing) => merge_assignments(existing, payment),
+            None => {
+                owned_rights.insert(right_type, payment);
+            }
+        }
+        let transition = Transition::with(
+            transition_type,
+            empty!(),
+            empty!(),
+            OwnedRights::from(owned_rights),
+            empty!(),
+            ParentOwnedRights::from(parent_owned_rights),
+        );
+
+        Ok(PaymentDraft {
+            psbt,
+            transition,
+            outpoints: selected,
+        })
+    }
+
+    /// Finalizes signed PSBT and broadcasts the extracted transaction.
+    pub(super) fn publish_tx(&mut self, psbt: Psbt) -> Result<Txid, DaemonError> {
+        let mut psbt = PartiallySignedTransaction::from(psbt);
+        #[cfg(feature = "bdk-wallet")]
+        self.bdk_wallets.sign(&mut psbt)?;
+        psbt.finalize_mut(SECP256K1).map_err(|errs| {
+            let errs = errs.iter().map(ToString::to_string).collect::<Vec<_>>();
+            PaymentError::Broadcast(errs.join("; "))
+        })?;
+        let tx = psbt.extract_tx();
+        let txid = self
+            .electrum
+            .call(|client| client.transaction_broadcast(&tx))
+            .map_err(|err| PaymentError::Broadcast(err.to_string()))?;
+        info!("Transaction {} is published", txid);
+        self.transfers_published(txid)?;
+        self.packages_published(&tx)?;
+        Ok(txid)
+    }
+}
+
+pub(super) fn input_descriptor(utxo: &WalletUtxo) -> Result<InputDescriptor, PaymentError> {
+    let terminal = [utxo.keychain as u32, utxo.index]
+        .into_iter()
+        .map(UnhardenedIndex::from_index)
+        .collect::<Result<_, _>>()
+        .map_err(|err| PaymentError::Construct(err.to_string()))?;
+    Ok(InputDescriptor {
+        outpoint: utxo.outpoint,
+        terminal,
+        seq_no: SeqNo::default(),
+        tweak: None,
+        sighash_type: EcdsaSighashType::All,
+    })
+}
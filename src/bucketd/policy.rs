@@ -0,0 +1,167 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::OutPoint;
+use rgb::blank::BLANK_TRANSITION_TYPE;
+use rgb::schema::OwnedRightType;
+use rgb::{
+    seal, AtomicValue, ContractId, Node, NodeId, OwnedRights, ParentOwnedRights, Schema, SchemaId,
+    Transition, TransitionBundle, TypedAssignments,
+};
+use rgb_rpc::{ContractPolicy, FailureCode};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum PolicyError {
+    /// state transition {node_id} creates allocation of {amount} units, which is below the
+    /// minimal allocation of {min_allocation} units set for the contract {contract_id}.
+    DustAllocation {
+        contract_id: ContractId,
+        node_id: NodeId,
+        amount: AtomicValue,
+        min_allocation: AtomicValue,
+    },
+
+    /// none of the provided outpoints has state of the contract {0} assigned.
+    NothingToConsolidate(ContractId),
+
+    /// schema {0} does not support blank state transitions, so the contract
+    /// state can't be consolidated.
+    BlankUnsupported(SchemaId),
+}
+
+impl From<&PolicyError> for FailureCode {
+    fn from(err: &PolicyError) -> Self {
+        match err {
+            PolicyError::DustAllocation { .. } => FailureCode::DustAllocation,
+            PolicyError::NothingToConsolidate(_) | PolicyError::BlankUnsupported(_) => {
+                FailureCode::NotConsolidable
+            }
+        }
+    }
+}
+
+impl Runtime {
+    /// Checks that the state transitions created by a transfer follow the
+    /// contract policy.
+    pub(super) fn check_policy(
+        &mut self,
+        contract_id: ContractId,
+        bundle: &TransitionBundle,
+    ) -> Result<(), DaemonError> {
+        let policy: ContractPolicy =
+            self.store.retrieve_sten(db::POLICIES, contract_id)?.unwrap_or_default();
+        for transition in bundle.known_transitions() {
+            // Blank transitions only move the existing allocations
+            if transition.transition_type() == BLANK_TRANSITION_TYPE {
+                continue;
+            }
+            for assignments in transition.owned_rights().values() {
+                // Confidential amounts can't be checked, and only the payer can reveal them
+                for amount in assignments.filter_revealed_state_values().iter().map(|s| s.value) {
+                    if !policy.allows(amount) {
+                        return Err(PolicyError::DustAllocation {
+                            contract_id,
+                            node_id: transition.node_id(),
+                            amount,
+                            min_allocation: policy.min_allocation,
+                        }
+                        .into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Constructs blank state transition moving all contract state assigned
+    /// to the outpoints to a single seal.
+    pub(super) fn consolidate_allocations(
+        &mut self,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+        seal: seal::Revealed,
+    ) -> Result<Transition, DaemonError> {
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(super::StashError::StateAbsent(contract_id))?;
+        let schema: Schema = self
+            .store
+            .retrieve_sten(db::SCHEMATA, state.schema_id)?
+            .ok_or(super::StashError::SchemaAbsent(state.schema_id))?;
+        if !schema.transitions.contains_key(&BLANK_TRANSITION_TYPE) {
+            return Err(PolicyError::BlankUnsupported(state.schema_id).into());
+        }
+
+        let mut parent_owned_rights: BTreeMap<NodeId, BTreeMap<OwnedRightType, Vec<u16>>> =
+            bmap! {};
+        let mut owned_rights: BTreeMap<OwnedRightType, TypedAssignments> = bmap! {};
+        let outpoint_state = state.filter_outpoint_state(&outpoints);
+        for inputs in outpoint_state.values() {
+            for input in inputs {
+                let node_outpoint = input.node_outpoint;
+                parent_owned_rights
+                    .entry(node_outpoint.node_id)
+                    .or_default()
+                    .entry(node_outpoint.ty)
+                    .or_default()
+                    .push(node_outpoint.no);
+                let assignments = input.state.to_revealed_assignment_vec(seal);
+                match owned_rights.get_mut(&node_outpoint.ty) {
+                    Some(existing) => merge_assignments(existing, assignments),
+                    None => {
+                        owned_rights.insert(node_outpoint.ty, assignments);
+                    }
+                }
+            }
+        }
+        if parent_owned_rights.is_empty() {
+            return Err(PolicyError::NothingToConsolidate(contract_id).into());
+        }
+        debug!(
+            "Consolidating {} allocations of {} from {} outpoints",
+            outpoint_state.values().map(BTreeSet::len).sum::<usize>(),
+            contract_id,
+            outpoint_state.len()
+        );
+
+        Ok(Transition::with(
+            BLANK_TRANSITION_TYPE,
+            empty!(),
+            empty!(),
+            OwnedRights::from(owned_rights),
+            empty!(),
+            ParentOwnedRights::from(parent_owned_rights),
+        ))
+    }
+}
+
+/// Appends assignments of the same owned right type. Assignments of a single
+/// owned right type always share the same state type.
+pub(super) fn merge_assignments(existing: &mut TypedAssignments, other: TypedAssignments) {
+    match (existing, other) {
+        (TypedAssignments::Void(existing), TypedAssignments::Void(other)) => existing.extend(other),
+        (TypedAssignments::Value(existing), TypedAssignments::Value(other)) => {
+            existing.extend(other)
+        }
+        (TypedAssignments::Data(existing), TypedAssignments::Data(other)) => existing.extend(other),
+        (TypedAssignments::Attachment(existing), TypedAssignments::Attachment(other)) => {
+            existing.extend(other)
+        }
+        _ => unreachable!("owned right type with different state types"),
+    }
+}
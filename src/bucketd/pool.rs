@@ -0,0 +1,128 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use store_rpc::PrimaryKey;
+
+use crate::db::{Store, StoreError, StoreRpcExt, StrictEncodedChunk};
+use crate::DaemonError;
+
+/// Store request executed by a pool worker with its store connection
+type Job = Box<dyn FnOnce(&mut dyn Store) + Send>;
+
+/// Set of store connections used to retrieve batches of objects in parallel.
+///
+/// Each connection is owned by a worker thread living as long as the pool and
+/// taking the requests from the shared queue, so the number of connections
+/// bounds the number of concurrent store requests.
+pub(crate) struct StorePool {
+    jobs: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl StorePool {
+    /// Opens `concurrency` connections to the store (at least one) and starts
+    /// a worker for each of them.
+    pub fn connect(
+        concurrency: usize,
+        connect: impl Fn() -> Result<Box<dyn Store>, StoreError>,
+    ) -> Result<Self, StoreError> {
+        let clients = (0..concurrency.max(1)).map(|_| connect()).collect::<Result<Vec<_>, _>>()?;
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = clients
+            .into_iter()
+            .map(|mut client| {
+                let receiver = receiver.clone();
+                thread::spawn(move || loop {
+                    // The lock is released before the job is run, so other
+                    // workers may take the next jobs meanwhile
+                    let job = receiver.lock().expect("store pool queue is poisoned").recv();
+                    match job {
+                        Ok(job) => job(client.as_mut()),
+                        // The pool is dropped
+                        Err(_) => break,
+                    }
+                })
+            })
+            .collect();
+        Ok(StorePool {
+            jobs: Some(sender),
+            workers,
+        })
+    }
+
+    /// Retrieves objects with the given keys from the table, returning them in
+    /// the same order as the keys.
+    pub fn retrieve_all<K, T>(
+        &mut self,
+        table: &'static str,
+        keys: Vec<K>,
+    ) -> Result<Vec<Option<T>>, DaemonError>
+    where
+        K: PrimaryKey + Send + 'static,
+        T: StrictEncodedChunk + Send + 'static,
+    {
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let jobs = self.jobs.as_ref().expect("store pool is used after being dropped");
+        let batch_size = (keys.len() + self.workers.len() - 1) / self.workers.len();
+        let (sender, receiver) = mpsc::channel();
+        let mut keys = keys.into_iter();
+        let mut batches = 0usize;
+        while keys.len() > 0 {
+            let batch = keys.by_ref().take(batch_size).collect::<Vec<_>>();
+            let sender = sender.clone();
+            let no = batches;
+            jobs.send(Box::new(move |client: &mut dyn Store| {
+                let res = batch
+                    .into_iter()
+                    .map(|key| client.retrieve_sten(table, key))
+                    .collect::<Result<Vec<_>, _>>();
+                // The requester may have already failed on other batch
+                let _ = sender.send((no, res));
+            }))
+            .expect("store pool workers have terminated");
+            batches += 1;
+        }
+        drop(sender);
+
+        // We wait for all batches, such that no jobs of the failed request are
+        // left in the queue
+        let mut results = Vec::with_capacity(batches);
+        let mut error = None;
+        for _ in 0..batches {
+            let (no, res) = receiver.recv().expect("store worker thread has panicked");
+            match res {
+                Ok(batch) => results.push((no, batch)),
+                Err(err) => error = error.or(Some(err)),
+            }
+        }
+        if let Some(err) = error {
+            return Err(err);
+        }
+        results.sort_by_key(|(no, _)| *no);
+        Ok(results.into_iter().flat_map(|(_, batch)| batch).collect())
+    }
+}
+
+impl Drop for StorePool {
+    fn drop(&mut self) {
+        // Closing the queue terminates the workers
+        self.jobs = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Dry-run of the issuance and transfer operations, reporting their results
+//! without persisting anything to the stash.
+
+use bitcoin::{OutPoint, Txid};
+use psbt::Psbt;
+use rgb::{
+    Consignment, ContractId, Node, Schema, SealEndpoint, StateTransfer, TransitionBundle, Validity,
+};
+use rgb_rpc::{AllocationPreview, IssueSpec, OperationPreview};
+use strict_encoding::StrictEncode;
+
+use super::cpfp::{INPUT_VSIZE, OUTPUT_VSIZE, TX_OVERHEAD_VSIZE};
+use super::processor::validate;
+use super::{IssueError, Runtime};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Composes and validates the contract from the issuance specification,
+    /// without storing it.
+    pub(super) fn preview_issue(
+        &mut self,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+    ) -> Result<OperationPreview, DaemonError> {
+        let contract = self.compose_contract(spec, schema)?;
+        let contract_id = contract.contract_id();
+        debug!("Previewing issuance of contract {}", contract_id);
+
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let status = validate(&contract, None, &resolver);
+        if status.validity() != Validity::Valid {
+            let failures = status.failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return Err(IssueError::Invalid(failures.join("; ")).into());
+        }
+
+        Ok(OperationPreview {
+            psbt_size: None,
+            tx_vsize: None,
+            fee: None,
+            consignment_size: contract.strict_serialize()?.len() as u32,
+            allocations: node_allocations(contract_id, contract.genesis(), None),
+        })
+    }
+
+    /// Finalizes the transfer in memory, reporting the resulting PSBT,
+    /// consignment and allocations without recording the transfer or storing
+    /// the disclosure.
+    pub(super) fn preview_transfer(
+        &mut self,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+    ) -> Result<OperationPreview, DaemonError> {
+        let contract_id = consignment.contract_id();
+        debug!("Previewing transfer for {}", contract_id);
+
+        let (finalize, anchor, bundles) = self.draft_transfer(consignment, endseals, psbt)?;
+        let txid = anchor.txid;
+
+        let mut allocations = bundle_allocations(
+            contract_id,
+            finalize
+                .consignment
+                .anchored_bundles()
+                .filter(|(anchor, _)| anchor.txid == txid)
+                .map(|(_, bundle)| bundle),
+            txid,
+        );
+        for (contract_id, bundle) in &bundles {
+            allocations.extend(bundle_allocations(*contract_id, Some(bundle), txid));
+        }
+
+        let psbt = &finalize.psbt;
+        Ok(OperationPreview {
+            psbt_size: Some(psbt.strict_serialize()?.len() as u32),
+            tx_vsize: Some(
+                TX_OVERHEAD_VSIZE
+                    + INPUT_VSIZE * psbt.inputs.len() as u64
+                    + OUTPUT_VSIZE * psbt.outputs.len() as u64,
+            ),
+            fee: psbt_fee(psbt),
+            consignment_size: finalize.consignment.strict_serialize()?.len() as u32,
+            allocations,
+        })
+    }
+}
+
+/// Fee paid by the PSBT, if all of its inputs have the spent outputs known.
+fn psbt_fee(psbt: &Psbt) -> Option<u64> {
+    let inputs = psbt
+        .inputs
+        .iter()
+        .map(|input| {
+            input.witness_utxo.as_ref().map(|txout| txout.value).or_else(|| {
+                input
+                    .non_witness_utxo
+                    .as_ref()
+                    .and_then(|tx| tx.output.get(input.previous_outpoint.vout as usize))
+                    .map(|txout| txout.value)
+            })
+        })
+        .sum::<Option<u64>>()?;
+    let outputs = psbt.outputs.iter().map(|output| output.amount).sum::<u64>();
+    inputs.checked_sub(outputs)
+}
+
+fn bundle_allocations<'bundle>(
+    contract_id: ContractId,
+    bundles: impl IntoIterator<Item = &'bundle TransitionBundle>,
+    witness_txid: Txid,
+) -> Vec<AllocationPreview> {
+    bundles
+        .into_iter()
+        .flat_map(TransitionBundle::known_transitions)
+        .flat_map(|transition| node_allocations(contract_id, transition, Some(witness_txid)))
+        .collect()
+}
+
+/// Lists state assigned by the node; seals without txid belong to the
+/// witness transaction, if known.
+fn node_allocations(
+    contract_id: ContractId,
+    node: &impl Node,
+    witness_txid: Option<Txid>,
+) -> Vec<AllocationPreview> {
+    let mut allocations = vec![];
+    for (right_type, assignments) in node.owned_rights().iter() {
+        let values = assignments.to_value_assignments();
+        for index in 0..assignments.len() as u16 {
+            let outpoint = match (assignments.revealed_seal_at(index), witness_txid) {
+                (Ok(Some(seal)), Some(witness_txid)) => Some(seal.outpoint_or(witness_txid)),
+                (Ok(Some(seal)), None) => seal.txid.map(|txid| OutPoint::new(txid, seal.vout)),
+                _ => None,
+            };
+            let amount = values
+                .get(index as usize)
+                .and_then(|assignment| assignment.as_revealed_state())
+                .map(|state| state.value);
+            allocations.push(AllocationPreview {
+                contract_id,
+                right_type: *right_type,
+                outpoint,
+                amount,
+            });
+        }
+    }
+    allocations
+}
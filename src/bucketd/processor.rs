@@ -11,27 +11,46 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::io;
 use std::io::Write;
+use std::time::Instant;
 
 use bitcoin::{OutPoint, Txid};
+use bitcoin_onchain::ResolveTx;
 use commit_verify::{lnpbp4, CommitConceal, TaggedHash};
 use psbt::Psbt;
+use rgb::blank::BLANK_TRANSITION_TYPE;
 use rgb::psbt::RgbExt;
-use rgb::schema::{OwnedRightType, TransitionType};
-use rgb::seal::Revealed;
+use rgb::schema::TransitionType;
 use rgb::{
-    bundle, validation, Anchor, Assignment, AttachmentStrategy, BundleId, Consignment,
-    ConsignmentType, ContractId, ContractState, ContractStateMap, Disclosure, Genesis,
-    InmemConsignment, Node, NodeId, OwnedRights, PedersenStrategy, Schema, SchemaId, SealEndpoint,
-    StateTransfer, Transition, TransitionBundle, TypedAssignments, Validator, Validity,
+    bundle, validation, Anchor, BundleId, Consignment, ConsignmentType, ContractId, ContractState,
+    ContractStateMap, Disclosure, Genesis, InmemConsignment, Node, NodeId, Schema, SchemaId,
+    SealEndpoint, StateTransfer, Transition, TransitionBundle, TypedAssignments, Validator,
+    Validity,
 };
-use rgb_rpc::{FinalizeTransfersRes, OutpointFilter, Reveal, TransferFinalize};
+use rgb_rpc::{
+    open_payload, Checkpoint, Compression, FailureCode, FinalizeTransfersRes, NodeEvent,
+    OutpointFilter, ProofBundle, Reveal, SignedCheckpoint, TransferDirection, TransferFinalize,
+    TransferStatus,
+};
+use store_rpc::PrimaryKey;
 use storm::chunk::ChunkIdExt;
 use storm::{ChunkId, Container, ContainerId};
 use strict_encoding::StrictDecode;
 
-use super::Runtime;
+use super::ancestry::ContractAncestry;
+use super::checkpoint::FastForward;
+use super::forget::ForgottenContract;
+use super::interrupt::Interrupt;
+use super::payment::transfer_type;
+use super::pool::StorePool;
+use super::reveal::reveal_transition;
+use super::transfers::transfer_record;
+use super::wal::fault_point;
+use super::{deps, views, Runtime};
 use crate::amplify::Wrapper;
 use crate::db::{self, StoreRpcExt};
+use crate::hooks::HookEvent;
+#[cfg(feature = "plugins")]
+use crate::plugins::PluginError;
 use crate::DaemonError;
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display, Error, From)]
@@ -43,6 +62,10 @@ pub enum StashError {
     /// contract is unknown. Probably you haven't imported the contract yet.
     GenesisAbsent,
 
+    /// contract {0} was forgotten by the node with a tombstone, so its re-import is refused; use
+    /// `--force` to import it anyway.
+    Forgotten(ContractId),
+
     /// schema {0} is unknown.
     ///
     /// It may happen due to RGB Node bug, or indicate internal stash inconsistency and compromised
@@ -108,6 +131,16 @@ pub enum StashError {
     Outsizedbundle,
 }
 
+impl From<&StashError> for FailureCode {
+    fn from(err: &StashError) -> Self {
+        match err {
+            StashError::StateAbsent(_) | StashError::GenesisAbsent => FailureCode::ContractUnknown,
+            StashError::Forgotten(_) => FailureCode::ContractForgotten,
+            _ => FailureCode::Stash,
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error, From)]
 #[display(doc_comments)]
 pub enum FinalizeError {
@@ -126,60 +159,204 @@ pub enum FinalizeError {
     Conceal,
 }
 
+impl From<&FinalizeError> for FailureCode {
+    fn from(err: &FinalizeError) -> Self {
+        match err {
+            FinalizeError::ContractBundleMissed | FinalizeError::Psbt(_) => {
+                FailureCode::InvalidPsbt
+            }
+            FinalizeError::Anchor(_) => FailureCode::InvalidAnchor,
+            FinalizeError::Conceal => FailureCode::Finalize,
+        }
+    }
+}
+
+/// MIME type of the containers with strict-encoded consignments
+pub(super) const CONSIGNMENT_MIME: &str = "application/vnd.lnpbp.rgb.consignment";
+/// MIME type of the containers with compressed strict-encoded consignments
+pub(super) const CONSIGNMENT_ZSTD_MIME: &str = "application/vnd.lnpbp.rgb.consignment+zstd";
+/// Suffix of the MIME type of the containers with consignments encrypted to
+/// the key of the beneficiary
+pub(super) const SEALED_MIME_SUFFIX: &str = "+sealed";
+
 impl Runtime {
-    /// Processes incoming transfer downloaded as a container locally
+    /// Processes consignment received from a remote node, unless the
+    /// acceptance policy puts it into the quarantine.
     pub(super) fn process_container(
         &mut self,
         container_id: ContainerId,
-    ) -> Result<validation::Status, DaemonError> {
+    ) -> Result<Option<validation::Status>, DaemonError> {
         // Assemble consignment
         // TODO: Make this procedure part of Storm Core (assembling data from a container)
         let container_chunk = self
             .store
-            .retrieve_chunk(storm_rpc::DB_TABLE_CONTAINERS, container_id)?
+            .retrieve_chunk(storm_rpc::DB_TABLE_CONTAINERS, container_id.into_slice32())?
             .ok_or(DaemonError::NoContainer(container_id))?;
         let container = Container::strict_deserialize(container_chunk)?;
+        self.track_container(container_id, &container, None)?;
         let data = Vec::with_capacity(container.header.size as usize);
         let mut writer = io::Cursor::new(data);
         for chunk_id in container.chunks {
             let chunk = self
                 .store
-                .retrieve_chunk(storm_rpc::DB_TABLE_CHUNKS, chunk_id)?
+                .retrieve_chunk(storm_rpc::DB_TABLE_CHUNKS, chunk_id.into_slice32())?
                 .unwrap_or_else(|| panic!("Chunk {} is absent", chunk_id));
             writer.write_all(chunk.as_slice()).expect("memory writers do not error");
         }
 
-        let consignment = StateTransfer::strict_deserialize(writer.into_inner())?;
-        self.process_consignment(consignment, true, None)
+        let mut data = writer.into_inner();
+        let mut mime = container.header.mime.as_str();
+        if let Some(inner) = mime.strip_suffix(SEALED_MIME_SUFFIX) {
+            data = self
+                .consignment_key
+                .as_ref()
+                .and_then(|keypair| open_payload(keypair, &data))
+                .ok_or(DaemonError::SealedContainer(container_id))?;
+            mime = inner;
+        }
+        let consignment = match mime {
+            CONSIGNMENT_ZSTD_MIME => {
+                // Decompress while decoding, without keeping the whole decompressed consignment
+                StateTransfer::strict_decode(Compression::Zstd.decoder(data.as_slice()))?
+            }
+            _ => StateTransfer::strict_deserialize(data)?,
+        };
+        let consignment_id = consignment.id();
+        let status = match self.screen_consignment(consignment)? {
+            Some(consignment) => self.process_received(consignment)?,
+            None => return Ok(None),
+        };
+        // Quarantined consignments keep their own copy, so the container is
+        // needed only until the retention period expires
+        if matches!(status.validity(), Validity::Valid | Validity::ValidExceptEndpoints) {
+            self.release_container(container_id, consignment_id)?;
+        }
+        Ok(Some(status))
     }
 
     pub(super) fn process_consignment<C: ConsignmentType>(
         &mut self,
-        consignment: InmemConsignment<C>,
+        mut consignment: InmemConsignment<C>,
         force: bool,
+        full: bool,
         reveal: Option<Reveal>,
+        proofs: Option<ProofBundle>,
     ) -> Result<validation::Status, DaemonError> {
         let contract_id = consignment.contract_id();
         let id = consignment.id();
+        self.validation_cost = None;
 
         info!("Registering consignment {} for contract {}", id, contract_id);
 
-        let mut state =
-            self.store.retrieve_sten(db::CONTRACTS, contract_id)?.unwrap_or_else(|| {
-                debug!("Contract {} was previously unknown", contract_id);
-                ContractState::with(
-                    consignment.schema_id(),
-                    consignment.root_schema_id(),
-                    contract_id,
-                    consignment.genesis(),
-                )
-            });
+        let forgotten: Option<ForgottenContract> =
+            self.store.retrieve_sten(db::FORGOTTEN, contract_id)?;
+        if let Some(ref record) = forgotten {
+            if record.tombstone && !force {
+                return Err(StashError::Forgotten(contract_id).into());
+            }
+            debug!("Re-importing contract {} which was previously forgotten", contract_id);
+        }
+
+        let checkpoint = if full {
+            None
+        } else {
+            self.store
+                .retrieve_sten::<SignedCheckpoint>(db::CHECKPOINTS, contract_id)?
+                .map(|signed| signed.checkpoint)
+        };
+        let frontier = checkpoint.as_ref().map(Checkpoint::frontier_ids).unwrap_or_default();
+        if let Some(ref checkpoint) = checkpoint {
+            debug!("Using {} for fast-forward validation", checkpoint);
+        }
+
+        // Sender may omit the root schema of the contract subschema if we already know it
+        consignment = self.with_root_schema(consignment)?;
+
+        // Sender may omit part of the history which we have reported as known, but validation
+        // requires the complete history, so we restore it from the stash
+        let present = consignment.node_ids();
+        let missing = consignment
+            .anchored_bundles()
+            .flat_map(|(_, bundle)| bundle.known_transitions())
+            .flat_map(|transition| transition.parent_outputs())
+            .map(|out| out.node_id)
+            .filter(|node_id| {
+                node_id.as_inner() != contract_id.as_inner()
+                    && !present.contains(node_id)
+                    && !frontier.contains(node_id)
+            })
+            .collect::<BTreeSet<_>>();
+        if !missing.is_empty() {
+            self.complete_consignment(&mut consignment, missing)?;
+        }
+        self.interrupt.check()?;
+        let mut cost = self.script_limits.check(&consignment)?;
+
+        let known_state = self.state_cache.get(&mut self.store, contract_id)?;
+        let new_contract = known_state.is_none();
+        let mut state = known_state.unwrap_or_else(|| {
+            debug!("Contract {} was previously unknown", contract_id);
+            let mut state = ContractState::with(
+                consignment.schema_id(),
+                consignment.root_schema_id(),
+                contract_id,
+                consignment.genesis(),
+            );
+            // History preceding the checkpoint is never known to us, so the contract state
+            // starts from the checkpoint transitions
+            for (transition, witness_txid) in checkpoint.iter().flat_map(|c| &c.frontier) {
+                state.add_transition(*witness_txid, transition);
+            }
+            state
+        });
         trace!("Starting with contract state {:?}", state);
 
         debug!("Validating consignment {} for contract {}", id, contract_id);
-        let status = Validator::validate(&consignment, &self.electrum);
+        let bundle_count = consignment.anchored_bundles().count();
+        self.progress.stage("Validating bundles", bundle_count);
+        let started = Instant::now();
+        let status = match proofs {
+            Some(ref proofs) => {
+                debug!("Using transaction proofs with {}", proofs);
+                let resolver = self.proof_resolver(proofs)?;
+                validate(&consignment, checkpoint.as_ref(), &self.progress.track(&resolver))
+            }
+            None => {
+                self.chain_cache.sync_tip(&self.electrum, &mut self.store)?;
+                let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+                let resolver = self.progress.track(&resolver);
+                let status = validate(&consignment, checkpoint.as_ref(), &resolver);
+                let (hits, misses) = self.chain_cache.stats();
+                debug!("Chain data cache has {} hits and {} misses in total", hits, misses);
+                status
+            }
+        };
+        cost.duration_ms = started.elapsed().as_millis() as u64;
         info!("Consignment validation result is {}", status.validity());
+        debug!("Validation cost: {}", cost);
+        self.validation_cost = Some(cost);
+
+        #[cfg(feature = "plugins")]
+        if matches!(status.validity(), Validity::Valid | Validity::ValidExceptEndpoints) {
+            if let Err(err) = self.plugins.validate(consignment.schema_id(), &consignment) {
+                error!("Consignment {} is not accepted: {}", id, err);
+                if let PluginError::Rejected { ref reason, .. } = err {
+                    self.notifier.notify(HookEvent::ValidationFailure {
+                        consignment_id: id,
+                        contract_id,
+                        failures: vec![reason.clone()],
+                    });
+                }
+                return Err(err.into());
+            }
+        }
+
+        // Validated consignment is always stored completely, so the request may not be
+        // interrupted after this point
+        self.interrupt.check()?;
 
+        // Contract consignments have no endpoints and are not transfers
+        let is_transfer = consignment.endpoints().next().is_some();
         match status.validity() {
             Validity::Valid => {
                 info!("Consignment is fully valid");
@@ -189,34 +366,41 @@ impl Runtime {
             }
             Validity::UnresolvedTransactions | Validity::ValidExceptEndpoints => {
                 error!("Some of consignment-related transactions were not found: {:?}", status);
+                if is_transfer {
+                    let record = transfer_record(
+                        &consignment,
+                        TransferDirection::Incoming,
+                        TransferStatus::Pending,
+                    );
+                    self.record_transfer(record)?;
+                }
                 return Ok(status);
             }
             Validity::Invalid => {
                 error!("Invalid consignment: {:?}", status);
+                if is_transfer {
+                    let record = transfer_record(
+                        &consignment,
+                        TransferDirection::Incoming,
+                        TransferStatus::Rejected,
+                    );
+                    self.record_transfer(record)?;
+                }
+                self.notifier.notify(HookEvent::ValidationFailure {
+                    consignment_id: id,
+                    contract_id,
+                    failures: status.failures.iter().map(ToString::to_string).collect(),
+                });
                 return Ok(status);
             }
         }
 
-        info!("Storing consignment {} into database", id);
-        trace!("Schema: {:?}", consignment.schema());
-        self.store.store_sten(db::SCHEMATA, consignment.schema_id(), consignment.schema())?;
-        if let Some(root_schema) = consignment.root_schema() {
-            trace!("Root schema: {:?}", root_schema);
-            self.store.store_sten(db::SCHEMATA, root_schema.schema_id(), root_schema)?;
-        }
+        // Supply changes are detected against the nodes known before storing the consignment,
+        // but are reported only once it is committed
+        let supply_events = self.supply_changes(&consignment)?;
 
-        if let Some(Reveal {
-            blinding_factor,
-            outpoint,
-            close_method,
-        }) = reveal
-        {
-            let reveal_outpoint = Revealed {
-                method: close_method,
-                blinding: blinding_factor,
-                txid: Some(outpoint.txid),
-                vout: outpoint.vout,
-            };
+        if let Some(ref reveal) = reveal {
+            let reveal_outpoint = reveal.to_seal();
 
             let concealed_seals = consignment
                 .endpoints()
@@ -230,7 +414,55 @@ impl Runtime {
                 );
                 Err(DaemonError::Finalize(FinalizeError::Conceal))?
             }
-        };
+        }
+
+        info!("Storing consignment {} into database", id);
+        self.wal_begin(&consignment, reveal.as_ref(), &state, new_contract, forgotten.is_some())?;
+        fault_point("wal-written");
+        self.store_consignment(&consignment, reveal.as_ref(), &mut state)?;
+        fault_point("bundles-stored");
+        self.commit_consignment(&consignment, &state, new_contract, forgotten.is_some())?;
+
+        // Transfer is recorded as accepted, and the invoices it pays are updated, only after the
+        // consignment is committed to the stash
+        if is_transfer {
+            let transfer_status = match status.validity() {
+                Validity::Valid => TransferStatus::Accepted,
+                _ => TransferStatus::Pending,
+            };
+            let record =
+                transfer_record(&consignment, TransferDirection::Incoming, transfer_status);
+            self.record_transfer(record.clone())?;
+            self.match_invoices(&consignment)?;
+            self.notifier.notify(HookEvent::Allocation(record.clone()));
+            if transfer_status == TransferStatus::Accepted {
+                self.notifier.notify(HookEvent::Confirmed(record));
+            }
+        }
+        for event in supply_events {
+            self.notifier.notify(event);
+        }
+
+        info!("Consignment processing complete for {}", id);
+        Ok(status)
+    }
+
+    /// Writes consignment data into the store, applying them to the contract
+    /// state. All the writes are idempotent, so the procedure may be repeated
+    /// for a partially stored consignment.
+    pub(super) fn store_consignment<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+        reveal: Option<&Reveal>,
+        state: &mut ContractState,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        trace!("Schema: {:?}", consignment.schema());
+        self.store.store_sten(db::SCHEMATA, consignment.schema_id(), consignment.schema())?;
+        if let Some(root_schema) = consignment.root_schema() {
+            trace!("Root schema: {:?}", root_schema);
+            self.store.store_sten(db::SCHEMATA, root_schema.schema_id(), root_schema)?;
+        }
 
         let genesis = consignment.genesis();
         debug!("Indexing genesis");
@@ -242,11 +474,19 @@ impl Runtime {
                 seal.txid.expect("genesis with vout-based seal which passed schema validation"),
                 seal.vout,
             );
-            self.store.insert_into_set(db::OUTPOINTS, index_id, contract_id)?;
+            self.store.insert_into_set(
+                db::OUTPOINTS,
+                index_id.into_slice32(),
+                contract_id.into_slice32(),
+            )?;
+            self.outpoint_index.insert(index_id);
         }
         debug!("Storing contract self-reference");
         self.store.store_sten(db::NODE_CONTRACTS, contract_id, &contract_id)?;
+        let mut references = deps::reference_candidates(genesis.metadata(), contract_id);
 
+        let bundle_count = consignment.anchored_bundles().count();
+        self.progress.stage("Storing bundles", bundle_count);
         for (anchor, bundle) in consignment.anchored_bundles() {
             let bundle_id = bundle.bundle_id();
             let witness_txid = anchor.txid;
@@ -266,92 +506,15 @@ impl Runtime {
                 let transition_type = transition.transition_type();
                 debug!("Processing state transition {}", node_id);
 
-                // TODO: refactoring this and move to rgb-core
-                let new_transition = match reveal {
-                    Some(Reveal {
-                        blinding_factor,
-                        outpoint,
-                        close_method,
-                    }) => {
-                        let reveal_outpoint = Revealed {
-                            method: close_method,
-                            blinding: blinding_factor,
-                            txid: Some(outpoint.txid),
-                            vout: outpoint.vout,
-                        };
-
-                        let mut owned_rights: BTreeMap<OwnedRightType, TypedAssignments> = bmap! {};
-                        for (owned_type, assignments) in transition.owned_rights().iter() {
-                            match assignments {
-                                TypedAssignments::Value(outpoints) => {
-                                    let mut assignment: Vec<Assignment<PedersenStrategy>> =
-                                        empty!();
-
-                                    for out in outpoints.clone() {
-                                        if out.commit_conceal().to_confidential_seal()
-                                            != reveal_outpoint.to_concealed_seal()
-                                        {
-                                            assignment.push(out);
-                                        } else {
-                                            let accept = match out.as_revealed_state() {
-                                                Some(seal) => Assignment::Revealed {
-                                                    seal: reveal_outpoint,
-                                                    state: *seal,
-                                                },
-                                                _ => out,
-                                            };
-                                            assignment.push(accept);
-                                        }
-                                    }
-
-                                    owned_rights
-                                        .insert(*owned_type, TypedAssignments::Value(assignment));
-                                }
-                                TypedAssignments::Attachment(outpoints) => {
-                                    let mut assignment: Vec<Assignment<AttachmentStrategy>> =
-                                        empty!();
-
-                                    for out in outpoints.clone() {
-                                        if out.commit_conceal().to_confidential_seal()
-                                            != reveal_outpoint.to_concealed_seal()
-                                        {
-                                            assignment.push(out);
-                                        } else {
-                                            let accept = match out.as_revealed_state() {
-                                                Some(seal) => Assignment::Revealed {
-                                                    seal: reveal_outpoint,
-                                                    state: seal.clone(),
-                                                },
-                                                _ => out,
-                                            };
-                                            assignment.push(accept);
-                                        }
-                                    }
-
-                                    owned_rights.insert(
-                                        *owned_type,
-                                        TypedAssignments::Attachment(assignment),
-                                    );
-                                }
-                                _ => {}
-                            }
-                        }
-
-                        let tmp: Transition = Transition::with(
-                            transition.transition_type(),
-                            transition.metadata().clone(),
-                            transition.parent_public_rights().clone(),
-                            OwnedRights::from(owned_rights),
-                            transition.public_rights().clone(),
-                            transition.parent_owned_rights().clone(),
-                        );
-
-                        tmp
-                    }
-                    _ => transition.to_owned(),
-                };
+                let new_transition = reveal
+                    .and_then(|reveal| {
+                        reveal_transition(transition, witness_txid, reveal.to_seal())
+                    })
+                    .unwrap_or_else(|| transition.to_owned());
 
                 trace!("State transition: {:?}", new_transition);
+                references
+                    .extend(deps::reference_candidates(new_transition.metadata(), contract_id));
                 state.add_transition(witness_txid, &new_transition);
                 trace!("Contract state now is {:?}", state);
 
@@ -359,13 +522,14 @@ impl Runtime {
                 revealed.insert(new_transition.clone(), inputs.clone());
                 self.store.store_merge(db::TRANSITIONS, node_id, new_transition.clone())?;
                 self.store.store_sten(db::TRANSITION_WITNESS, node_id, &witness_txid)?;
+                ContractAncestry::index(self.store.as_mut(), &new_transition)?;
 
                 trace!("Indexing transition");
                 let index_id = ChunkId::with_fixed_fragments(contract_id, transition_type);
                 self.store.insert_into_set(
                     db::CONTRACT_TRANSITIONS,
-                    index_id,
-                    node_id.into_array(),
+                    index_id.into_slice32(),
+                    node_id.into_slice32(),
                 )?;
 
                 self.store.store_sten(db::NODE_CONTRACTS, node_id, &contract_id)?;
@@ -373,19 +537,26 @@ impl Runtime {
                 for seal in new_transition.filter_revealed_seals() {
                     let index_id =
                         ChunkId::with_fixed_fragments(seal.txid.unwrap_or(witness_txid), seal.vout);
-                    self.store.insert_into_set(db::OUTPOINTS, index_id, node_id.into_array())?;
+                    self.store.insert_into_set(
+                        db::OUTPOINTS,
+                        index_id.into_slice32(),
+                        node_id.into_slice32(),
+                    )?;
+                    self.outpoint_index.insert(index_id);
                 }
             }
             let data = TransitionBundle::with(revealed, concealed)
                 .expect("enough data should be available to create bundle");
             let chunk_id = ChunkId::with_fixed_fragments(contract_id, witness_txid);
             self.store.store_sten(db::BUNDLES, chunk_id, &data)?;
+            self.progress.advance();
         }
         for extension in consignment.state_extensions() {
             let node_id = extension.node_id();
             debug!("Processing state extension {}", node_id);
             trace!("State transition: {:?}", extension);
 
+            references.extend(deps::reference_candidates(extension.metadata(), contract_id));
             state.add_extension(extension);
             trace!("Contract state now is {:?}", state);
 
@@ -396,13 +567,91 @@ impl Runtime {
             // database Extension rights are always closed seals, since the extension
             // can get into the history only through closing by a state transition
         }
+        self.index_references(contract_id, references)?;
+        Ok(())
+    }
 
+    /// Stores the final contract state and completes storing of the
+    /// consignment, removing it from the write-ahead log.
+    fn commit_consignment<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+        state: &ContractState,
+        new_contract: bool,
+        forgotten: bool,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        let id = consignment.id();
         debug!("Storing contract state for {}", contract_id);
         trace!("Final contract state is {:?}", state);
-        self.store.store_sten(db::CONTRACTS, contract_id, &state)?;
+        self.state_cache.store(&mut self.store, contract_id, state)?;
+        fault_point("state-stored");
+        if forgotten {
+            self.store.wipe_chunk(db::FORGOTTEN, contract_id)?;
+        }
+        self.apply_pending_reveals(contract_id)?;
+        self.stamp_validation(contract_id)?;
+        if self.journal {
+            self.record_journal(consignment)?;
+        }
+        self.wal_commit(contract_id)?;
 
-        info!("Consignment processing complete for {}", id);
-        Ok(status)
+        #[cfg(feature = "sql")]
+        self.export_consignment(consignment);
+
+        if new_contract {
+            self.events.push(NodeEvent::ContractRegistered(contract_id));
+        }
+        self.events.push(NodeEvent::ConsignmentAccepted { id, contract_id });
+        Ok(())
+    }
+
+    /// Detects previously unknown state transitions and extensions assigning
+    /// fungible state other than by transfers, i.e. issuing or burning assets.
+    fn supply_changes<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+    ) -> Result<Vec<HookEvent>, DaemonError> {
+        if !self.notifier.is_active() {
+            return Ok(vec![]);
+        }
+
+        let contract_id = consignment.contract_id();
+        let transfer = transfer_type(consignment.schema()).map(|(ty, ..)| ty);
+        let transitions = consignment
+            .anchored_bundles()
+            .flat_map(|(_, bundle)| bundle.known_transitions())
+            .filter(|transition| {
+                let ty = transition.transition_type();
+                ty != BLANK_TRANSITION_TYPE && Some(ty) != transfer
+            })
+            .map(|transition| (transition.node_id(), transition.owned_rights()));
+        let extensions = consignment
+            .state_extensions()
+            .map(|extension| (extension.node_id(), extension.owned_rights()));
+
+        let mut events = vec![];
+        for (node_id, owned_rights) in transitions.chain(extensions) {
+            let assignments = owned_rights
+                .values()
+                .flat_map(TypedAssignments::to_value_assignments)
+                .collect::<Vec<_>>();
+            if assignments.is_empty()
+                || self.store.retrieve_sten::<ContractId>(db::NODE_CONTRACTS, node_id)?.is_some()
+            {
+                continue;
+            }
+            let amount = assignments
+                .iter()
+                .filter_map(|assignment| assignment.as_revealed_state().map(|state| state.value))
+                .sum();
+            events.push(HookEvent::SupplyChange {
+                contract_id,
+                node_id,
+                amount,
+            });
+        }
+        Ok(events)
     }
 
     pub(super) fn process_disclosure(&mut self, txid: Txid) -> Result<(), DaemonError> {
@@ -414,8 +663,8 @@ impl Runtime {
         for (anchor, bundle_map) in disclosure.anchored_bundles().values() {
             for (contract_id, bundle) in bundle_map {
                 let mut state: ContractState = self
-                    .store
-                    .retrieve_sten(db::CONTRACTS, *contract_id)?
+                    .state_cache
+                    .get(&mut self.store, *contract_id)?
                     .ok_or(StashError::StateAbsent(*contract_id))?;
                 trace!("Starting with contract state {:?}", state);
 
@@ -441,13 +690,14 @@ impl Runtime {
                     revealed.insert(transition.clone(), inputs.clone());
                     self.store.store_merge(db::TRANSITIONS, node_id, transition.clone())?;
                     self.store.store_sten(db::TRANSITION_WITNESS, node_id, &witness_txid)?;
+                    ContractAncestry::index(self.store.as_mut(), transition)?;
 
                     trace!("Indexing transition");
                     let index_id = ChunkId::with_fixed_fragments(*contract_id, transition_type);
                     self.store.insert_into_set(
                         db::CONTRACT_TRANSITIONS,
-                        index_id,
-                        node_id.into_array(),
+                        index_id.into_slice32(),
+                        node_id.into_slice32(),
                     )?;
 
                     self.store.store_sten(db::NODE_CONTRACTS, node_id, contract_id)?;
@@ -459,9 +709,10 @@ impl Runtime {
                         );
                         self.store.insert_into_set(
                             db::OUTPOINTS,
-                            index_id,
-                            node_id.into_array(),
+                            index_id.into_slice32(),
+                            node_id.into_slice32(),
                         )?;
+                        self.outpoint_index.insert(index_id);
                     }
                 }
                 let data = TransitionBundle::with(revealed, concealed)
@@ -469,40 +720,69 @@ impl Runtime {
                 let chunk_id = ChunkId::with_fixed_fragments(*contract_id, witness_txid);
                 self.store.store_sten(db::BUNDLES, chunk_id, &data)?;
 
-                self.store.store_sten(db::CONTRACTS, *contract_id, &state)?;
+                self.state_cache.store(&mut self.store, *contract_id, &state)?;
             }
         }
 
         Ok(())
     }
 
+    fn complete_consignment<C: ConsignmentType>(
+        &mut self,
+        consignment: &mut InmemConsignment<C>,
+        missing: BTreeSet<NodeId>,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        debug!("Restoring {} transitions omitted from the consignment", missing.len());
+
+        let bundle_ids = consignment
+            .anchored_bundles()
+            .map(|(_, bundle)| bundle.bundle_id())
+            .collect::<BTreeSet<_>>();
+        let mut collector = Collector::new(contract_id, consignment.node_ids());
+        collector.process(&mut self.store_pool, missing, &OutpointFilter::All)?;
+        let collector = collector.iterate(&mut self.store_pool, &self.interrupt)?;
+
+        for (anchor, bundle) in collector.anchored_bundles.into_values() {
+            if !bundle_ids.contains(&bundle.bundle_id()) {
+                consignment.push_anchored_bundle(anchor, bundle)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(super) fn compose_consignment<T: ConsignmentType>(
         &mut self,
         contract_id: ContractId,
         always_include: BTreeSet<TransitionType>,
         outpoint_filter: OutpointFilter,
+        known: BTreeSet<NodeId>,
         _phantom: T,
     ) -> Result<InmemConsignment<T>, DaemonError> {
+        let outpoint_filter = self.expand_outpoint_filter(outpoint_filter)?;
         let genesis: Genesis =
             self.store.retrieve_sten(db::GENESIS, contract_id)?.ok_or(StashError::GenesisAbsent)?;
-        let schema_id = genesis.schema_id();
-        let schema: Schema = self
-            .store
-            .retrieve_sten(db::SCHEMATA, schema_id)?
-            .ok_or(StashError::SchemaAbsent(schema_id))?;
-        let root_schema_id = schema.root_id;
-        let root_schema = if root_schema_id != zero!() {
-            Some(
-                self.store
-                    .retrieve_sten(db::SCHEMATA, root_schema_id)?
-                    .ok_or(StashError::SchemaAbsent(root_schema_id))?,
-            )
-        } else {
-            None
-        };
+        let (schema, root_schema) = self.dependent_schemata(genesis.schema_id())?;
+
+        // Beneficiary reports only the tips of its history, so we expand them into the full
+        // set of transitions it already has and which we do not need to send
+        let mut known = ContractAncestry::known_history(&mut self.store_pool, contract_id, known)?;
+        // Transitions imported with a checkpoint have no anchors in the stash and can't be sent;
+        // the beneficiary must have the same checkpoint
+        let checkpoint: Option<SignedCheckpoint> =
+            self.store.retrieve_sten(db::CHECKPOINTS, contract_id)?;
+        for node_id in checkpoint.iter().flat_map(|signed| signed.checkpoint.frontier_ids()) {
+            if self.store.retrieve_sten::<Transition>(db::TRANSITIONS, node_id)?.is_none() {
+                known.insert(node_id);
+            }
+        }
+        if !known.is_empty() {
+            debug!("Excluding {} transitions known to the beneficiary", known.len());
+        }
 
-        let mut collector = Collector::new(contract_id);
+        let mut collector = Collector::new(contract_id, known);
         let outpoints_all = OutpointFilter::All;
+        self.progress.stage("Collecting transitions", schema.transitions.len());
         for transition_type in schema.transitions.keys() {
             let chunk_id = ChunkId::with_fixed_fragments(contract_id, *transition_type);
             let node_ids: BTreeSet<NodeId> =
@@ -512,10 +792,12 @@ impl Runtime {
             } else {
                 &outpoint_filter
             };
-            collector.process(&mut self.store, node_ids, filter)?;
+            collector.process(&mut self.store_pool, node_ids, filter)?;
+            self.interrupt.check()?;
+            self.progress.advance();
         }
 
-        collector = collector.iterate(&mut self.store)?;
+        collector = collector.iterate(&mut self.store_pool, &self.interrupt)?;
 
         collector.into_consignment(schema, root_schema, genesis)
     }
@@ -529,10 +811,13 @@ impl Runtime {
         let indexes = if outpoints.is_empty() {
             self.store.ids(db::OUTPOINTS)?
         } else {
-            outpoints
+            let ids = outpoints
                 .iter()
                 .map(|outpoint| ChunkId::with_fixed_fragments(outpoint.txid, outpoint.vout))
-                .collect()
+                .collect();
+            let candidates = self.outpoint_index.filter(&mut self.store, ids)?;
+            // Filter may give false positives, which we remove with a single store request
+            self.store.filter_known(db::OUTPOINTS, candidates)?
         };
 
         for index in &indexes {
@@ -544,14 +829,30 @@ impl Runtime {
                     .retrieve_sten(db::NODE_CONTRACTS, node_id)?
                     .ok_or(StashError::NodeContractAbsent(node_id))?;
 
+                if res.contains_key(&contract_id) {
+                    continue;
+                }
+
+                // Targeted queries are answered from the state views, if they are built
+                if !outpoints.is_empty() {
+                    if let Some(map) =
+                        views::outpoint_state(&mut self.store, contract_id, &outpoints)?
+                    {
+                        res.insert(contract_id, map);
+                        continue;
+                    }
+                }
+
                 let state: ContractState = self
-                    .store
-                    .retrieve_sten(db::CONTRACTS, contract_id)?
+                    .state_cache
+                    .get(&mut self.store, contract_id)?
                     .ok_or(StashError::StateAbsent(contract_id))?;
 
                 let map = if outpoints.is_empty() {
                     state.all_outpoint_state()
                 } else {
+                    // Views of the contract were never built, so we do it now
+                    views::update(&mut self.store, contract_id, None, &state)?;
                     state.filter_outpoint_state(&outpoints)
                 };
 
@@ -559,17 +860,54 @@ impl Runtime {
             }
         }
 
+        let (hits, misses) = self.state_cache.stats();
+        debug!("Contract state cache has {} hits and {} misses in total", hits, misses);
+
         Ok(res)
     }
 
     pub(super) fn finalize_transfer(
+        &mut self,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+    ) -> Result<TransferFinalize, DaemonError> {
+        info!("Finalizing transfer for {}", consignment.contract_id());
+
+        let (finalize, anchor, bundles) = self.draft_transfer(consignment, endseals, psbt)?;
+        let record = transfer_record(
+            &finalize.consignment,
+            TransferDirection::Outgoing,
+            TransferStatus::Finalized,
+        );
+        self.record_transfer(record)?;
+
+        // 4. Conceal all the state not related to the transfer.
+        // TODO: Conceal all the amounts except the last transition
+        // TODO: Conceal all seals outside of the paths from the endpoint to genesis
+
+        // 5. Construct and store disclosure for the blank transfers.
+        let txid = anchor.txid;
+        let disclosure = Disclosure::with(anchor, bundles, None);
+        self.store.store_sten(db::DISCLOSURES, txid, &disclosure)?;
+
+        Ok(finalize)
+    }
+
+    /// Packs the anchor into the PSBT and the contract bundle into the
+    /// consignment without persisting anything to the stash. Returns the
+    /// finalized transfer together with the anchor and the remaining (blank)
+    /// bundles of other contracts.
+    pub(super) fn draft_transfer(
         &mut self,
         mut consignment: StateTransfer,
         endseals: Vec<SealEndpoint>,
         mut psbt: Psbt,
-    ) -> Result<TransferFinalize, DaemonError> {
+    ) -> Result<
+        (TransferFinalize, Anchor<lnpbp4::MerkleBlock>, BTreeMap<ContractId, TransitionBundle>),
+        DaemonError,
+    > {
         let contract_id = consignment.contract_id();
-        info!("Finalizing transfer for {}", contract_id);
 
         // 1. Pack LNPBP-4 and anchor information.
         let mut bundles = psbt.rgb_bundles()?;
@@ -579,9 +917,9 @@ impl Runtime {
         let anchor = Anchor::commit(&mut psbt)?;
         trace!("Anchor: {:?}", anchor);
 
-        // 2. Extract contract-related state transition from PSBT and put it
-        //    into consignment.
+        // 2. Extract contract-related state transition from PSBT and put it into consignment.
         let bundle = bundles.remove(&contract_id).ok_or(FinalizeError::ContractBundleMissed)?;
+        self.check_policy(contract_id, &bundle)?;
         let bundle_id = bundle.bundle_id();
         consignment.push_anchored_bundle(anchor.to_merkle_proof(contract_id)?, bundle)?;
 
@@ -590,16 +928,7 @@ impl Runtime {
             consignment.push_seal_endpoint(bundle_id, endseal);
         }
 
-        // 4. Conceal all the state not related to the transfer.
-        // TODO: Conceal all the amounts except the last transition
-        // TODO: Conceal all seals outside of the paths from the endpoint to genesis
-
-        // 5. Construct and store disclosure for the blank transfers.
-        let txid = anchor.txid;
-        let disclosure = Disclosure::with(anchor, bundles, None);
-        self.store.store_sten(db::DISCLOSURES, txid, &disclosure)?;
-
-        Ok(TransferFinalize { consignment, psbt })
+        Ok((TransferFinalize { consignment, psbt }, anchor, bundles))
     }
 
     pub(super) fn finalize_transfers(
@@ -621,9 +950,9 @@ impl Runtime {
             let contract_id = consignment.contract_id();
             info!("Finalizing transfer for {}", contract_id);
 
-            // 2. Extract contract-related state transition from PSBT and put it
-            //    into consignment.
+            // 2. Extract contract-related state transition from PSBT and put it into consignment.
             let bundle = bundles.remove(&contract_id).ok_or(FinalizeError::ContractBundleMissed)?;
+            self.check_policy(contract_id, &bundle)?;
             let bundle_id = bundle.bundle_id();
             consignment.push_anchored_bundle(anchor.to_merkle_proof(contract_id)?, bundle)?;
 
@@ -632,6 +961,12 @@ impl Runtime {
             for endseal in endseals {
                 consignment.push_seal_endpoint(bundle_id, endseal);
             }
+            let record = transfer_record(
+                &consignment,
+                TransferDirection::Outgoing,
+                TransferStatus::Finalized,
+            );
+            self.record_transfer(record)?;
 
             consignments.push(consignment);
         }
@@ -654,12 +989,15 @@ struct Collector {
     pub anchored_bundles: BTreeMap<Txid, (Anchor<lnpbp4::MerkleProof>, TransitionBundle)>,
     pub endpoints: Vec<(BundleId, SealEndpoint)>,
     pub endpoint_inputs: Vec<NodeId>,
+    /// Transitions which history must not be collected
+    pub known: BTreeSet<NodeId>,
 }
 
 impl Collector {
-    pub fn new(contract_id: ContractId) -> Self {
+    pub fn new(contract_id: ContractId, known: BTreeSet<NodeId>) -> Self {
         Collector {
             contract_id,
+            known,
             anchored_bundles: empty![],
             endpoints: vec![],
             endpoint_inputs: vec![],
@@ -669,38 +1007,56 @@ impl Collector {
     // TODO: Support state extensions
     pub fn process(
         &mut self,
-        store: &mut store_rpc::Client,
+        pool: &mut StorePool,
         node_ids: impl IntoIterator<Item = NodeId>,
         outpoint_filter: &OutpointFilter,
     ) -> Result<(), DaemonError> {
         let contract_id = self.contract_id;
 
-        for transition_id in node_ids {
-            if transition_id.as_inner() == contract_id.as_inner() {
-                continue;
-            }
-            let transition: Transition = store
-                .retrieve_sten(db::TRANSITIONS, transition_id)?
-                .ok_or(StashError::TransitionAbsent(transition_id))?;
+        let node_ids = node_ids
+            .into_iter()
+            .filter(|id| id.as_inner() != contract_id.as_inner())
+            .collect::<Vec<_>>();
+
+        // First, we retrieve all transitions with their witness txids and then all
+        // not yet known anchors and bundles, each in a parallel batch
+        let transitions = pool.retrieve_all::<_, Transition>(db::TRANSITIONS, node_ids.clone())?;
+        let witnesses = pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+        let mut items = Vec::with_capacity(node_ids.len());
+        for ((transition_id, transition), witness_txid) in
+            node_ids.into_iter().zip(transitions).zip(witnesses)
+        {
+            let transition = transition.ok_or(StashError::TransitionAbsent(transition_id))?;
+            let witness_txid =
+                witness_txid.ok_or(StashError::TransitionTxidAbsent(transition_id))?;
+            items.push((transition, witness_txid));
+        }
 
-            let witness_txid: Txid = store
-                .retrieve_sten(db::TRANSITION_WITNESS, transition_id)?
-                .ok_or(StashError::TransitionTxidAbsent(transition_id))?;
+        let witness_txids = items
+            .iter()
+            .map(|(_, txid)| *txid)
+            .filter(|txid| !self.anchored_bundles.contains_key(txid))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let chunk_ids = witness_txids
+            .iter()
+            .map(|txid| ChunkId::with_fixed_fragments(contract_id, *txid))
+            .collect();
+        let anchors = pool
+            .retrieve_all::<_, Anchor<lnpbp4::MerkleBlock>>(db::ANCHORS, witness_txids.clone())?;
+        let bundles = pool.retrieve_all::<_, TransitionBundle>(db::BUNDLES, chunk_ids)?;
+        for ((witness_txid, anchor), bundle) in witness_txids.into_iter().zip(anchors).zip(bundles)
+        {
+            let anchor = anchor.ok_or(StashError::AnchorAbsent(witness_txid))?;
+            let bundle = bundle.ok_or(StashError::BundleAbsent(contract_id, witness_txid))?;
+            let anchor = anchor.to_merkle_proof(contract_id)?;
+            self.anchored_bundles.insert(witness_txid, (anchor, bundle));
+        }
 
-            let bundle = if let Some((_, bundle)) = self.anchored_bundles.get_mut(&witness_txid) {
-                bundle
-            } else {
-                let anchor: Anchor<lnpbp4::MerkleBlock> = store
-                    .retrieve_sten(db::ANCHORS, witness_txid)?
-                    .ok_or(StashError::AnchorAbsent(witness_txid))?;
-                let chunk_id = ChunkId::with_fixed_fragments(contract_id, witness_txid);
-                let bundle: TransitionBundle = store
-                    .retrieve_sten(db::BUNDLES, chunk_id)?
-                    .ok_or(StashError::BundleAbsent(contract_id, witness_txid))?;
-                let anchor = anchor.to_merkle_proof(contract_id)?;
-                self.anchored_bundles.insert(witness_txid, (anchor, bundle));
-                &mut self.anchored_bundles.get_mut(&witness_txid).expect("stdlib is broken").1
-            };
+        for (transition, witness_txid) in items {
+            let bundle =
+                &mut self.anchored_bundles.get_mut(&witness_txid).expect("bundle is retrieved").1;
 
             let bundle_id = bundle.bundle_id();
             for (_, assignments) in transition.owned_rights().iter() {
@@ -722,12 +1078,37 @@ impl Collector {
         Ok(())
     }
 
-    pub fn iterate(mut self, store: &mut store_rpc::Client) -> Result<Self, DaemonError> {
+    pub fn iterate(
+        mut self,
+        pool: &mut StorePool,
+        interrupt: &Interrupt,
+    ) -> Result<Self, DaemonError> {
         // Collect all transitions between endpoints and genesis independently from their type
-        loop {
-            let node_ids = self.endpoint_inputs;
+        let ancestors = ContractAncestry::ancestors(
+            pool,
+            self.contract_id,
+            self.endpoint_inputs.iter().copied(),
+            &self.known,
+        )?;
+        if let Some(node_ids) = ancestors {
+            self.process(pool, node_ids, &OutpointFilter::All)?;
+            // All parents of the processed transitions are already processed
             self.endpoint_inputs = vec![];
-            self.process(store, node_ids, &OutpointFilter::All)?;
+            return Ok(self);
+        }
+        // Contract history is not fully indexed, so we have to walk it level by level
+        loop {
+            let known = &self.known;
+            let node_ids = self
+                .endpoint_inputs
+                .drain(..)
+                .filter(|node_id| !known.contains(node_id))
+                .collect::<BTreeSet<_>>();
+            if node_ids.is_empty() {
+                break;
+            }
+            self.process(pool, node_ids, &OutpointFilter::All)?;
+            interrupt.check()?;
             if self.endpoint_inputs.is_empty() {
                 break;
             }
@@ -758,3 +1139,176 @@ impl Collector {
         ))
     }
 }
+
+/// Direct access to the consignment processing for the benchmarks and the
+/// tests of the downstream software, which run the runtime without the
+/// message buses.
+#[cfg(feature = "testkit")]
+impl Runtime {
+    /// Validates the consignment and stores it, if accepted, like the
+    /// consignments sent by the clients.
+    pub fn accept_consignment<C: ConsignmentType>(
+        &mut self,
+        consignment: InmemConsignment<C>,
+        force: bool,
+        proofs: Option<ProofBundle>,
+    ) -> Result<validation::Status, rgb_rpc::Error> {
+        self.process_consignment(consignment, force, true, None, proofs).map_err(rpc_error)
+    }
+
+    /// Composes transfer consignment of the contract with the state allocated
+    /// to the outpoints.
+    pub fn consign_transfer(
+        &mut self,
+        contract_id: ContractId,
+        outpoints: OutpointFilter,
+    ) -> Result<StateTransfer, rgb_rpc::Error> {
+        self.compose_consignment(
+            contract_id,
+            empty!(),
+            outpoints,
+            empty!(),
+            rgb::TransferConsignment,
+        )
+        .map_err(rpc_error)
+    }
+}
+
+/// Converts daemon error into the error reported to the RPC clients
+#[cfg(feature = "testkit")]
+fn rpc_error(err: DaemonError) -> rgb_rpc::Error {
+    rgb_rpc::RpcMsg::from(err).failure_to_error().expect_err("daemon errors are failures")
+}
+
+/// Validates consignment with the given transaction resolver, going only down
+/// to the contract checkpoint transitions if a checkpoint is provided.
+pub(super) fn validate<C: ConsignmentType, R: ResolveTx>(
+    consignment: &InmemConsignment<C>,
+    checkpoint: Option<&Checkpoint>,
+    resolver: &R,
+) -> validation::Status {
+    match checkpoint {
+        Some(checkpoint) => FastForward::validate(consignment, checkpoint, resolver),
+        None => Validator::validate(consignment, resolver),
+    }
+}
+
+#[cfg(all(test, feature = "testkit", feature = "test-utils"))]
+mod test {
+    use std::sync::Arc;
+
+    use bitcoin::Transaction;
+    use rgb_rpc::TransferRecord;
+    use strict_encoding::StrictEncode;
+
+    use super::*;
+    use crate::bucketd::{HistoryShape, SyntheticHistory, SYNTHETIC_AMOUNT};
+    use crate::testkit::{
+        fungible_history, regtest_runtime, MemStore, MockChain, MockFailure, MockRequest,
+    };
+
+    fn history() -> SyntheticHistory { fungible_history(HistoryShape { depth: 2, width: 1 }) }
+
+    /// Runtime resolving the transactions with the mock chain, which has the
+    /// synthetic contract registered.
+    fn runtime(name: &str, chain: &Arc<MockChain>, history: &SyntheticHistory) -> Runtime {
+        let mut runtime =
+            regtest_runtime(&format!("processor-{}", name), chain.clone(), MemStore::new());
+        let status = runtime
+            .process_consignment(history.contract.clone(), false, true, None, None)
+            .expect("contract is registered");
+        assert!(matches!(status.validity(), Validity::Valid));
+        runtime
+    }
+
+    fn witnesses(history: &SyntheticHistory) -> Vec<Transaction> {
+        history.proofs.txes.iter().map(|proof| proof.tx.clone()).collect()
+    }
+
+    fn accept(runtime: &mut Runtime, history: &SyntheticHistory) -> validation::Status {
+        runtime
+            .process_consignment(history.transfer.clone(), false, true, None, None)
+            .expect("transfer is processed")
+    }
+
+    fn record(runtime: &mut Runtime, history: &SyntheticHistory) -> Option<TransferRecord> {
+        runtime.store.retrieve_sten(db::TRANSFERS, history.transfer.id()).unwrap()
+    }
+
+    fn contract_state(runtime: &mut Runtime, history: &SyntheticHistory) -> Vec<u8> {
+        runtime
+            .state_cache
+            .get(runtime.store.as_mut(), history.contract.contract_id())
+            .expect("contract state is readable")
+            .expect("contract is known")
+            .strict_serialize()
+            .expect("in-memory encoding")
+    }
+
+    #[test]
+    fn accept_mined_transfer() {
+        let history = history();
+        let chain = Arc::new(MockChain::new());
+        for tx in witnesses(&history) {
+            chain.mine(vec![tx]);
+        }
+        let mut runtime = runtime("mined", &chain, &history);
+
+        let status = accept(&mut runtime, &history);
+        assert!(matches!(status.validity(), Validity::Valid));
+        let record = record(&mut runtime, &history).expect("transfer is recorded");
+        assert_eq!(record.status, TransferStatus::Accepted);
+        assert_eq!(record.direction, TransferDirection::Incoming);
+        assert_eq!(record.txid, witnesses(&history).last().map(Transaction::txid));
+        assert_eq!(record.amount, Some(SYNTHETIC_AMOUNT));
+    }
+
+    #[test]
+    fn track_endpoint_mining() {
+        let history = history();
+        let chain = Arc::new(MockChain::new());
+        let mut witnesses = witnesses(&history);
+        let endpoint_tx = witnesses.pop().expect("history has witness transactions");
+        chain.mine(witnesses);
+        let mut runtime = runtime("unmined", &chain, &history);
+        let registered = contract_state(&mut runtime, &history);
+
+        // Endpoint witness transaction is not known to the chain yet
+        let status = accept(&mut runtime, &history);
+        assert!(matches!(status.validity(), Validity::ValidExceptEndpoints));
+        assert_eq!(status.unmined_endpoint_txids, vec![endpoint_tx.txid()]);
+        let pending = record(&mut runtime, &history).expect("transfer is recorded");
+        assert_eq!(pending.status, TransferStatus::Pending);
+        assert_eq!(contract_state(&mut runtime, &history), registered);
+
+        chain.mine(vec![endpoint_tx]);
+        let status = accept(&mut runtime, &history);
+        assert!(matches!(status.validity(), Validity::Valid));
+        let accepted = record(&mut runtime, &history).expect("transfer is recorded");
+        assert_eq!(accepted.status, TransferStatus::Accepted);
+        assert_eq!(accepted.created, pending.created);
+        assert_ne!(contract_state(&mut runtime, &history), registered);
+    }
+
+    #[test]
+    fn retry_failed_resolution() {
+        let history = history();
+        let chain = Arc::new(MockChain::new());
+        for tx in witnesses(&history) {
+            chain.mine(vec![tx]);
+        }
+        let mut runtime = runtime("failing", &chain, &history);
+        let registered = contract_state(&mut runtime, &history);
+
+        let failure = MockFailure::Protocol(s!("transaction is not available"));
+        chain.fail(MockRequest::TransactionGet, failure, history.proofs.txes.len());
+        let status = accept(&mut runtime, &history);
+        assert!(matches!(status.validity(), Validity::UnresolvedTransactions));
+        assert_eq!(contract_state(&mut runtime, &history), registered);
+
+        let status = accept(&mut runtime, &history);
+        assert!(matches!(status.validity(), Validity::Valid));
+        let record = record(&mut runtime, &history).expect("transfer is recorded");
+        assert_eq!(record.status, TransferStatus::Accepted);
+    }
+}
@@ -0,0 +1,175 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+use bitcoin::{Transaction, Txid};
+use bitcoin_onchain::{ResolveTx, TxResolverError};
+use internet2::ZmqSocketType;
+use microservices::esb::{self, ClientId, EndpointList};
+use rgb_rpc::{Compression, ProgressStep, RpcEnvelope, RpcMsg};
+
+use crate::bus::{BusMsg, DaemonId, ServiceBus, ServiceId};
+use crate::Config;
+
+/// Minimal interval between progress steps sent to the client within a
+/// single stage
+const STEP_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Streaming of incremental progress of the long-running requests.
+///
+/// Progress steps are sent to the client directly over the RPC bus while the
+/// request is being processed, using a separate connection, since the daemon
+/// endpoints are not available to the processing routines. Steps are sent only
+/// to the clients which have negotiated progress reporting, and are throttled
+/// such that a large contract does not flood the client.
+pub(crate) struct Progress {
+    esb: Option<esb::Controller<ServiceBus, BusMsg, Sender>>,
+    /// Client receiving progress of the request being processed
+    client_id: Option<ClientId>,
+    step: ProgressStep,
+    last_sent: Option<Instant>,
+}
+
+impl Progress {
+    /// Connects progress reporter to the RPC bus. Progress reporting is
+    /// non-essential, so a failure to connect disables it without failing the
+    /// daemon.
+    pub fn connect(config: &Config, daemon_id: DaemonId) -> Progress {
+        let esb = esb::Controller::with(
+            map! {
+                ServiceBus::Rpc => esb::BusConfig::with_addr(
+                    config.rpc_endpoint.clone(),
+                    ZmqSocketType::RouterConnect,
+                    Some(ServiceId::rgbd())
+                )
+            },
+            Sender { daemon_id },
+        )
+        .map_err(|err| warn!("Progress reporter is unable to connect to RPC bus: {}", err))
+        .ok();
+        Progress {
+            esb,
+            client_id: None,
+            step: ProgressStep {
+                stage: empty!(),
+                done: 0,
+                total: 0,
+            },
+            last_sent: None,
+        }
+    }
+
+    /// Registers start of the request processing. Progress is reported only
+    /// if the client has negotiated it.
+    pub fn start(&mut self, client_id: ClientId, enabled: bool) {
+        self.client_id = if enabled { Some(client_id) } else { None };
+        self.last_sent = None;
+    }
+
+    /// Registers completion of the request processing
+    pub fn finish(&mut self) { self.client_id = None; }
+
+    /// Starts new stage of the request processing with the given number of
+    /// items to process
+    pub fn stage(&mut self, stage: impl ToString, total: usize) {
+        self.step = ProgressStep {
+            stage: stage.to_string(),
+            done: 0,
+            total: total as u32,
+        };
+        self.last_sent = None;
+        self.send();
+    }
+
+    /// Registers processing of the next item within the current stage
+    pub fn advance(&mut self) {
+        self.step.done = (self.step.done + 1).min(self.step.total);
+        let due = match self.last_sent {
+            Some(last_sent) => last_sent.elapsed() >= STEP_INTERVAL,
+            None => true,
+        };
+        if due || self.step.is_complete() {
+            self.send();
+        }
+    }
+
+    /// Wraps transaction resolver used by the validator, such that each
+    /// resolved witness transaction advances the current stage
+    pub fn track<'a, R: ResolveTx>(&'a mut self, resolver: &'a R) -> TrackingResolver<'a, R> {
+        TrackingResolver {
+            resolver,
+            progress: RefCell::new(self),
+        }
+    }
+
+    fn send(&mut self) {
+        let (esb, client_id) = match (&mut self.esb, self.client_id) {
+            (Some(esb), Some(client_id)) if self.step.total > 0 => (esb, client_id),
+            _ => return,
+        };
+        let msg = RpcMsg::ProgressStep(self.step.clone());
+        let envelope = RpcEnvelope::with_compression(&msg, Compression::None);
+        if let Err(err) =
+            esb.send_to(ServiceBus::Rpc, ServiceId::Client(client_id), BusMsg::Rpc(envelope))
+        {
+            debug!("Unable to report progress to client {}: {}", client_id, err);
+        }
+        self.last_sent = Some(Instant::now());
+    }
+}
+
+/// Transaction resolver reporting validation progress, since the validator
+/// resolves witness transaction of each of the consignment bundles
+pub(crate) struct TrackingResolver<'a, R: ResolveTx> {
+    resolver: &'a R,
+    progress: RefCell<&'a mut Progress>,
+}
+
+impl<'a, R: ResolveTx> ResolveTx for TrackingResolver<'a, R> {
+    fn resolve_tx(&self, txid: Txid) -> Result<Transaction, TxResolverError> {
+        let res = self.resolver.resolve_tx(txid);
+        self.progress.borrow_mut().advance();
+        res
+    }
+}
+
+/// Handler of the progress reporter RPC bus connection, which is used only for
+/// sending messages
+struct Sender {
+    daemon_id: DaemonId,
+}
+
+impl esb::Handler<ServiceBus> for Sender {
+    type Request = BusMsg;
+    type Error = esb::Error<ServiceId>;
+
+    fn identity(&self) -> ServiceId { ServiceId::BucketProgress(self.daemon_id) }
+
+    fn handle(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        _: ServiceBus,
+        _: ServiceId,
+        _: BusMsg,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn handle_err(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        _: esb::Error<ServiceId>,
+    ) -> Result<(), Self::Error> {
+        // Errors are already reported by the controller
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Validation of consignments with transaction proofs instead of the chain
+//! backend, and construction of such proofs for the consignments sent to the
+//! nodes working offline.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::{Transaction, Txid};
+use bitcoin_onchain::{ResolveTx, TxResolverError};
+use rgb_rpc::{ProofBundle, ProofError, ProofHeader};
+
+use super::Runtime;
+use crate::DaemonError;
+
+/// Maximal number of block headers requested from electrum server at once
+const MAX_HEADERS: u32 = 2016;
+
+/// Transaction resolver used by the validator for the consignments provided
+/// with transaction proofs. Resolves only the transactions which inclusion into
+/// the blockchain was proven.
+pub(crate) struct ProofResolver(pub(super) BTreeMap<Txid, Transaction>);
+
+impl ResolveTx for ProofResolver {
+    fn resolve_tx(&self, txid: Txid) -> Result<Transaction, TxResolverError> {
+        self.0.get(&txid).cloned().ok_or(TxResolverError { txid, err: None })
+    }
+}
+
+impl Runtime {
+    /// Verifies transaction proofs against the header checkpoint of the node
+    pub(super) fn proof_resolver(&self, proofs: &ProofBundle) -> Result<ProofResolver, ProofError> {
+        let checkpoint = self.header_checkpoint.ok_or(ProofError::NoCheckpoint)?;
+        let txes = proofs.verify(checkpoint)?;
+        debug!("Transaction proofs are verified against header checkpoint {}", checkpoint);
+        Ok(ProofResolver(txes))
+    }
+
+    /// Constructs proofs of the transactions inclusion into the blockchain,
+    /// with the header chain starting after the given height.
+    pub(super) fn prove_transactions(
+        &mut self,
+        txids: BTreeSet<Txid>,
+        from_height: u32,
+    ) -> Result<ProofBundle, DaemonError> {
+        let mut txes = Vec::with_capacity(txids.len());
+        for txid in txids {
+            let proof = self
+                .chain_cache
+                .tx_proof(&self.electrum, &mut self.store, txid)
+                .map_err(|err| ProofError::Chain(err.to_string()))?
+                .ok_or(ProofError::NotMined(txid))?;
+            if proof.height <= from_height {
+                return Err(ProofError::BelowCheckpoint(txid, proof.height).into());
+            }
+            txes.push(proof);
+        }
+
+        let till = txes.iter().map(|proof| proof.height).max().unwrap_or(from_height);
+        let mut headers = Vec::with_capacity(till.saturating_sub(from_height) as usize);
+        let mut height = from_height + 1;
+        while height <= till {
+            let count = (till - height + 1).min(MAX_HEADERS);
+            let res = self
+                .electrum
+                .call(|client| client.block_headers(height as usize, count as usize))
+                .map_err(|err| ProofError::Chain(err.to_string()))?;
+            if res.headers.is_empty() {
+                return Err(ProofError::Chain(format!("no block headers after {}", height)).into());
+            }
+            height += res.headers.len() as u32;
+            headers.extend(res.headers.into_iter().map(ProofHeader));
+        }
+
+        let proofs = ProofBundle { headers, txes };
+        info!("Constructed transaction proofs with {}", proofs);
+        Ok(proofs)
+    }
+}
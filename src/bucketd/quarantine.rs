@@ -0,0 +1,177 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use rgb::{validation, ConsignmentId, ContractId, SchemaId, StateTransfer, Validity};
+use rgb_rpc::{now, FailureCode, QuarantineInfo, QuarantineReason};
+
+use super::Runtime;
+use crate::db::{self, Store, StoreRpcExt};
+use crate::{Config, DaemonError};
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum QuarantineError {
+    /// contract {0} is blacklisted; its consignments are not accepted by the node.
+    Blacklisted(ContractId),
+
+    /// consignment {0} is not present in the quarantine.
+    Unknown(ConsignmentId),
+
+    /// contract of the consignment {0} is not whitelisted; the consignment must be approved
+    /// manually.
+    NotApproved(ConsignmentId),
+}
+
+impl From<&QuarantineError> for FailureCode {
+    fn from(err: &QuarantineError) -> Self {
+        match err {
+            QuarantineError::Blacklisted(_) => FailureCode::Blacklisted,
+            QuarantineError::Unknown(_) => FailureCode::Quarantine,
+            QuarantineError::NotApproved(_) => FailureCode::NotApproved,
+        }
+    }
+}
+
+/// Quarantined consignment kept in the store until it is approved or purged.
+#[derive(Clone, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct QuarantineEntry {
+    pub info: QuarantineInfo,
+    /// Consignment data, which are dropped once the consignment leaves the
+    /// quarantine
+    pub consignment: Option<StateTransfer>,
+}
+
+/// Rules for the automatic acceptance of the consignments received from the
+/// remote nodes.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub(crate) struct AcceptancePolicy {
+    contracts: BTreeSet<ContractId>,
+    schemata: BTreeSet<SchemaId>,
+    blacklist: BTreeSet<ContractId>,
+}
+
+impl From<&Config> for AcceptancePolicy {
+    fn from(config: &Config) -> Self {
+        AcceptancePolicy {
+            contracts: config.accept_contracts.clone(),
+            schemata: config.accept_schemata.clone(),
+            blacklist: config.reject_contracts.clone(),
+        }
+    }
+}
+
+impl AcceptancePolicy {
+    /// Empty whitelist allows any contract which is not blacklisted.
+    fn is_whitelisted(&self, contract_id: ContractId, schema_id: SchemaId) -> bool {
+        (self.contracts.is_empty() && self.schemata.is_empty())
+            || self.contracts.contains(&contract_id)
+            || self.schemata.contains(&schema_id)
+    }
+}
+
+/// Takes consignment out of the quarantine, keeping its description in the
+/// store.
+pub(crate) fn take_quarantined(
+    store: &mut dyn Store,
+    id: ConsignmentId,
+) -> Result<(QuarantineInfo, StateTransfer), DaemonError> {
+    let mut entry: QuarantineEntry =
+        store.retrieve_sten(db::QUARANTINE, id)?.ok_or(QuarantineError::Unknown(id))?;
+    let consignment = entry.consignment.take().ok_or(QuarantineError::Unknown(id))?;
+    store.store_sten(db::QUARANTINE, id, &entry)?;
+    Ok((entry.info, consignment))
+}
+
+impl Runtime {
+    /// Checks consignment received from a remote node against the acceptance
+    /// policy. Returns the consignment back if it can be processed, or `None`
+    /// if it was put into the quarantine.
+    pub(super) fn screen_consignment(
+        &mut self,
+        consignment: StateTransfer,
+    ) -> Result<Option<StateTransfer>, DaemonError> {
+        let contract_id = consignment.contract_id();
+        if self.acceptance.blacklist.contains(&contract_id) {
+            return Err(QuarantineError::Blacklisted(contract_id).into());
+        }
+        if self.acceptance.is_whitelisted(contract_id, consignment.schema_id()) {
+            return Ok(Some(consignment));
+        }
+        self.quarantine(consignment, QuarantineReason::NotWhitelisted, None)?;
+        Ok(None)
+    }
+
+    /// Processes consignment received from a remote node. Consignments which
+    /// have not passed validation, or have transactions unknown to the
+    /// blockchain, are put into the quarantine for later re-validation.
+    pub(super) fn process_received(
+        &mut self,
+        consignment: StateTransfer,
+    ) -> Result<validation::Status, DaemonError> {
+        let status = self.process_consignment(consignment.clone(), true, false, None, None)?;
+        let reason = match status.validity() {
+            Validity::Invalid => QuarantineReason::Invalid,
+            Validity::UnresolvedTransactions => QuarantineReason::Unresolved,
+            Validity::Valid | Validity::ValidExceptEndpoints => return Ok(status),
+        };
+        self.quarantine(consignment, reason, Some(status.clone()))?;
+        Ok(status)
+    }
+
+    /// Takes consignment out of the quarantine for processing. Unless the
+    /// consignment is approved by the user, it is kept in the quarantine if
+    /// the contract is still not whitelisted.
+    pub(super) fn release_quarantined(
+        &mut self,
+        id: ConsignmentId,
+        approved: bool,
+    ) -> Result<StateTransfer, DaemonError> {
+        if !approved {
+            let entry: QuarantineEntry = self
+                .store
+                .retrieve_sten(db::QUARANTINE, id)?
+                .ok_or(QuarantineError::Unknown(id))?;
+            let info = entry.info;
+            if info.reason == QuarantineReason::NotWhitelisted
+                && !self.acceptance.is_whitelisted(info.contract_id, info.schema_id)
+            {
+                return Err(QuarantineError::NotApproved(id).into());
+            }
+        }
+        let (info, consignment) = take_quarantined(&mut self.store, id)?;
+        info!("Consignment {} leaves the quarantine", info.id);
+        Ok(consignment)
+    }
+
+    pub(super) fn quarantine(
+        &mut self,
+        consignment: StateTransfer,
+        reason: QuarantineReason,
+        report: Option<validation::Status>,
+    ) -> Result<(), DaemonError> {
+        let info = QuarantineInfo {
+            id: consignment.id(),
+            contract_id: consignment.contract_id(),
+            schema_id: consignment.schema_id(),
+            reason,
+            received: now(),
+            report,
+        };
+        warn!("Putting {} into the quarantine", info);
+        let entry = QuarantineEntry {
+            info,
+            consignment: Some(consignment),
+        };
+        self.store.store_sten(db::QUARANTINE, entry.info.id, &entry)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Reconstruction of the contract state from the genesis, state transitions
+//! and extensions kept in the stash, fixing the state which diverged from the
+//! stash data (for instance after a crash in the middle of consignment
+//! processing).
+
+use std::collections::BTreeMap;
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use bitcoin::Txid;
+use rgb::{ContractId, ContractState, Extension, Genesis, Node, NodeId, Transition};
+use rgb_rpc::{RebuildReport, SignedCheckpoint};
+use store_rpc::PrimaryKey;
+use storm::ChunkId;
+
+use super::reveal::replay_order;
+use super::{deps, views, Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Rebuilds state of the contract, or of all known contracts, and the
+    /// outpoint index from the stash data. Allocations removed from the state
+    /// without a state transition, like forgotten spent outpoints, are
+    /// restored by the rebuild.
+    pub(super) fn rebuild_state(
+        &mut self,
+        contract_id: Option<ContractId>,
+    ) -> Result<RebuildReport, DaemonError> {
+        let contract_ids = match contract_id {
+            Some(contract_id) => {
+                if self.store.retrieve_sten::<Genesis>(db::GENESIS, contract_id)?.is_none() {
+                    return Err(StashError::GenesisAbsent.into());
+                }
+                vec![contract_id]
+            }
+            None => self
+                .store
+                .ids(db::GENESIS)?
+                .into_iter()
+                .map(|key| ContractId::from_inner(Hash::from_inner(key.into_inner())))
+                .collect(),
+        };
+
+        let mut report = RebuildReport::default();
+        self.progress.stage("Rebuilding contract state", contract_ids.len());
+        for contract_id in contract_ids {
+            self.interrupt.check()?;
+            // Forgotten contracts have their genesis wiped
+            let genesis = match self.store.retrieve_sten::<Genesis>(db::GENESIS, contract_id)? {
+                Some(genesis) => genesis,
+                None => continue,
+            };
+            if self.rebuild_contract(contract_id, &genesis)? {
+                warn!("State of contract {} has diverged from the stash data", contract_id);
+                report.diverged.insert(contract_id);
+            }
+            report.contracts += 1;
+            self.progress.advance();
+        }
+        self.outpoint_index.invalidate();
+
+        info!("Contract state is rebuilt for {}", report);
+        Ok(report)
+    }
+
+    /// Rebuilds state of a single contract, returning whether the previously
+    /// kept state differs from the rebuilt one.
+    fn rebuild_contract(
+        &mut self,
+        contract_id: ContractId,
+        genesis: &Genesis,
+    ) -> Result<bool, DaemonError> {
+        debug!("Rebuilding state of contract {}", contract_id);
+
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let transitions =
+            self.store_pool.retrieve_all::<_, Transition>(db::TRANSITIONS, node_ids.clone())?;
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+        let mut known: BTreeMap<NodeId, (Transition, Txid)> = bmap! {};
+        for (node_id, pair) in node_ids.into_iter().zip(transitions.into_iter().zip(witnesses)) {
+            match pair {
+                (Some(transition), Some(witness)) => {
+                    known.insert(node_id, (transition, witness));
+                }
+                (None, _) => return Err(StashError::TransitionAbsent(node_id).into()),
+                (_, None) => return Err(StashError::TransitionTxidAbsent(node_id).into()),
+            }
+        }
+        // Transitions imported with a checkpoint are not kept in the stash and
+        // start the contract history
+        let frontier = self
+            .store
+            .retrieve_sten::<SignedCheckpoint>(db::CHECKPOINTS, contract_id)?
+            .map(|signed| signed.checkpoint.frontier)
+            .unwrap_or_default();
+        for (transition, witness_txid) in frontier {
+            known.entry(transition.node_id()).or_insert((transition, witness_txid));
+        }
+
+        let mut extensions: BTreeMap<NodeId, Extension> = bmap! {};
+        let mut queue = known
+            .values()
+            .flat_map(|(transition, _)| transition.parent_public_rights().keys().copied())
+            .collect::<Vec<_>>();
+        while let Some(node_id) = queue.pop() {
+            if node_id.as_inner() == contract_id.as_inner() || extensions.contains_key(&node_id) {
+                continue;
+            }
+            if let Some(extension) =
+                self.store.retrieve_sten::<Extension>(db::EXTENSIONS, node_id)?
+            {
+                queue.extend(extension.parent_public_rights().keys().copied());
+                extensions.insert(node_id, extension);
+            }
+        }
+        self.interrupt.check()?;
+
+        let mut state = ContractState::with(
+            genesis.schema_id(),
+            genesis.root_schema_id(),
+            contract_id,
+            genesis,
+        );
+        // Extensions are added first, so the transitions spending their
+        // assignments remove them from the state
+        for extension in extensions.values() {
+            state.add_extension(extension);
+        }
+        for node_id in replay_order(&known, known.keys().copied()) {
+            let (transition, witness_txid) = &known[&node_id];
+            state.add_transition(*witness_txid, transition);
+        }
+        trace!("Rebuilt contract state is {:?}", state);
+
+        for seal in genesis.revealed_seals().unwrap_or_default() {
+            if let Some(txid) = seal.txid {
+                let index_id = ChunkId::with_fixed_fragments(txid, seal.vout);
+                self.store.insert_into_set(
+                    db::OUTPOINTS,
+                    index_id.into_slice32(),
+                    contract_id.into_slice32(),
+                )?;
+            }
+        }
+        self.store.store_sten(db::NODE_CONTRACTS, contract_id, &contract_id)?;
+        for (node_id, (transition, witness_txid)) in &known {
+            for seal in transition.filter_revealed_seals() {
+                let index_id =
+                    ChunkId::with_fixed_fragments(seal.txid.unwrap_or(*witness_txid), seal.vout);
+                self.store.insert_into_set(
+                    db::OUTPOINTS,
+                    index_id.into_slice32(),
+                    node_id.into_slice32(),
+                )?;
+            }
+            self.store.store_sten(db::NODE_CONTRACTS, *node_id, &contract_id)?;
+        }
+        for node_id in extensions.keys() {
+            self.store.store_sten(db::NODE_CONTRACTS, *node_id, &contract_id)?;
+        }
+        let references = known
+            .values()
+            .map(|(transition, _)| transition.metadata())
+            .chain(extensions.values().map(Extension::metadata))
+            .chain(Some(genesis.metadata()))
+            .flat_map(|metadata| deps::reference_candidates(metadata, contract_id))
+            .collect();
+        self.index_references(contract_id, references)?;
+
+        let diverged = self.state_cache.get(&mut self.store, contract_id)?.as_ref() != Some(&state);
+        if diverged {
+            self.state_cache.store(&mut self.store, contract_id, &state)?;
+        } else {
+            // State views may be damaged independently from the state itself
+            views::update(&mut self.store, contract_id, None, &state)?;
+        }
+        Ok(diverged)
+    }
+}
@@ -0,0 +1,231 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Pruning of the auxiliary data which retention period has passed. Contract
+//! state and the data it depends on are never pruned.
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use rgb::{ContractId, Disclosure, NodeId, Transition};
+use rgb_rpc::{
+    now, AnnotationTarget, JournalEntry, PruneReport, PrunedItem, RetentionCategory,
+    RetentionPeriod, TransferDirection, TransferRecord, TransferStatus,
+};
+use store_rpc::PrimaryKey;
+use storm::ChunkId;
+
+use super::{QuarantineEntry, Runtime};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Prunes data which retention period has passed. In dry-run mode the data
+    /// are only reported.
+    pub(super) fn prune_expired(&mut self, dry_run: bool) -> Result<PruneReport, DaemonError> {
+        // Without a connection to electrum server periods given in blocks are
+        // applied to the timestamps, and the disclosures are not pruned
+        let tip = match self.chain_cache.sync_tip(&self.electrum, &mut self.store) {
+            Ok(_) => self.chain_cache.tip_height(),
+            Err(_) => None,
+        };
+
+        let mut report = PruneReport {
+            dry_run,
+            ..default!()
+        };
+        if let Some(period) = self.retention.get(&RetentionCategory::Quarantine).copied() {
+            self.prune_quarantine(period, &mut report)?;
+        }
+        if let Some(period) = self.retention.get(&RetentionCategory::Transfers).copied() {
+            self.prune_transfers(period, tip, &mut report)?;
+        }
+        if let Some(period) = self.retention.get(&RetentionCategory::Journal).copied() {
+            self.prune_journals(period, &mut report)?;
+        }
+        match (self.retention.get(&RetentionCategory::Disclosures).copied(), tip) {
+            (Some(period), Some(tip)) => self.prune_disclosures(period, tip, &mut report)?,
+            (Some(_), None) => warn!("Chain tip is unknown; skipping pruning of disclosures"),
+            (None, _) => {}
+        }
+
+        if !report.items.is_empty() {
+            match dry_run {
+                true => info!("Pruning would remove {}", report),
+                false => info!("Pruning has removed {}", report),
+            }
+        }
+        Ok(report)
+    }
+
+    fn prune_quarantine(
+        &mut self,
+        period: RetentionPeriod,
+        report: &mut PruneReport,
+    ) -> Result<(), DaemonError> {
+        let timestamp = now();
+        for key in self.store.ids(db::QUARANTINE)? {
+            let info = match self.store.retrieve_sten::<QuarantineEntry>(db::QUARANTINE, key)? {
+                Some(entry) if entry.consignment.is_some() => entry.info,
+                _ => continue,
+            };
+            if !period.expired_since(info.received, timestamp) {
+                continue;
+            }
+            debug!("Pruning quarantined consignment {}", info.id);
+            report.bytes += self.prune_chunk(db::QUARANTINE, key, report.dry_run)?;
+            report.items.push(PrunedItem::Quarantined(info.id));
+        }
+        Ok(())
+    }
+
+    /// Prunes records of the transfers which are no longer going to change:
+    /// accepted or rejected incoming transfers and published outgoing ones.
+    fn prune_transfers(
+        &mut self,
+        period: RetentionPeriod,
+        tip: Option<u32>,
+        report: &mut PruneReport,
+    ) -> Result<(), DaemonError> {
+        let timestamp = now();
+        for key in self.store.ids(db::TRANSFERS)? {
+            let record = match self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, key)? {
+                Some(record) if is_completed(&record) => record,
+                _ => continue,
+            };
+            let height = match (period, record.txid, tip) {
+                (RetentionPeriod::Blocks(_), Some(txid), Some(_)) => {
+                    self.chain_cache.tx_height(&self.electrum, &mut self.store, txid).ok().flatten()
+                }
+                _ => None,
+            };
+            let expired = match (height, tip) {
+                (Some(height), Some(tip)) => period.expired_at(height, tip),
+                _ => period.expired_since(record.updated, timestamp),
+            };
+            if !expired {
+                continue;
+            }
+            debug!("Pruning {}", record);
+            report.bytes += self.prune_chunk(db::TRANSFERS, key, report.dry_run)?;
+            report.bytes += self.prune_chunk(db::PACKAGES, record.id, report.dry_run)?;
+            let target = AnnotationTarget::Transfer(record.id);
+            let annotation_key = ChunkId::with_fixed_fragments(db::ANNOTATIONS, target);
+            report.bytes += self.prune_chunk(db::ANNOTATIONS, annotation_key, report.dry_run)?;
+            report.items.push(PrunedItem::Transfer(record.id));
+        }
+        Ok(())
+    }
+
+    /// Prunes journals of the contracts which had no consignments recorded
+    /// within the retention period. Journals are pruned as a whole, since the
+    /// contract can't be rewound once its first consignments are removed.
+    fn prune_journals(
+        &mut self,
+        period: RetentionPeriod,
+        report: &mut PruneReport,
+    ) -> Result<(), DaemonError> {
+        let timestamp = now();
+        for key in self.store.ids(db::JOURNAL)? {
+            let journal = match self.store.retrieve_sten::<Vec<JournalEntry>>(db::JOURNAL, key)? {
+                Some(journal) => journal,
+                None => continue,
+            };
+            let last = journal.iter().map(|entry| entry.timestamp).max().unwrap_or_default();
+            if !period.expired_since(last, timestamp) {
+                continue;
+            }
+            let contract_id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            debug!("Pruning journal of contract {}", contract_id);
+            for id in journal.iter().map(|entry| entry.consignment_id) {
+                report.bytes += self.prune_chunk(db::JOURNAL_CONSIGNMENTS, id, report.dry_run)?;
+            }
+            report.bytes += self.prune_chunk(db::JOURNAL, key, report.dry_run)?;
+            report.items.push(PrunedItem::Journal(contract_id));
+        }
+        Ok(())
+    }
+
+    /// Prunes disclosures which transitions are all enclosed into the stash
+    /// and which witness transaction is mined for the retention period.
+    fn prune_disclosures(
+        &mut self,
+        period: RetentionPeriod,
+        tip: u32,
+        report: &mut PruneReport,
+    ) -> Result<(), DaemonError> {
+        for key in self.store.ids(db::DISCLOSURES)? {
+            let disclosure = match self.store.retrieve_sten::<Disclosure>(db::DISCLOSURES, key)? {
+                Some(disclosure) => disclosure,
+                None => continue,
+            };
+            let txid = match disclosure.anchored_bundles().values().next() {
+                Some((anchor, _)) => anchor.txid,
+                None => continue,
+            };
+            if !self.is_enclosed(&disclosure)? {
+                continue;
+            }
+            match self.chain_cache.tx_height(&self.electrum, &mut self.store, txid) {
+                Ok(Some(height)) if period.expired_at(height, tip) => {}
+                _ => continue,
+            }
+            debug!("Pruning disclosure {}", txid);
+            report.bytes += self.prune_chunk(db::DISCLOSURES, key, report.dry_run)?;
+            report.items.push(PrunedItem::Disclosure(txid));
+        }
+        Ok(())
+    }
+
+    /// Checks that all transitions of the disclosure are known to the stash.
+    fn is_enclosed(&mut self, disclosure: &Disclosure) -> Result<bool, DaemonError> {
+        let node_ids = disclosure
+            .anchored_bundles()
+            .values()
+            .flat_map(|(_, bundles)| bundles.values())
+            .flat_map(|bundle| bundle.known_transitions())
+            .map(Transition::node_id)
+            .collect::<Vec<NodeId>>();
+        for node_id in node_ids {
+            if self.store.retrieve_sten::<ContractId>(db::NODE_CONTRACTS, node_id)?.is_none() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Removes the chunk from the store, returning the size of the removed
+    /// data. In dry-run mode the chunk is kept.
+    fn prune_chunk(
+        &mut self,
+        table: &str,
+        key: impl PrimaryKey,
+        dry_run: bool,
+    ) -> Result<u64, DaemonError> {
+        if !dry_run {
+            return Ok(self.store.wipe_chunk(table, key)?.unwrap_or_default() as u64);
+        }
+        let size = self
+            .store
+            .retrieve_chunk(table, key.into_slice32())?
+            .map(|chunk| chunk.as_slice().len() as u64)
+            .unwrap_or_default();
+        Ok(size)
+    }
+}
+
+/// Detects transfers which status is final
+fn is_completed(record: &TransferRecord) -> bool {
+    matches!(
+        (record.direction, record.status),
+        (TransferDirection::Incoming, TransferStatus::Accepted)
+            | (TransferDirection::Incoming, TransferStatus::Rejected)
+            | (TransferDirection::Outgoing, TransferStatus::Published)
+    )
+}
@@ -0,0 +1,154 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Tracking of the validation rules version used to accept each contract.
+//!
+//! Upgrades of the validation library may detect invalidity missed by its
+//! older versions. Contracts accepted before the upgrade are reported in the
+//! node health and can be re-validated with the current rules from the stash
+//! data, without asking their senders for the consignments again.
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use rgb::{Consignment, ContractConsignment, ContractId, Genesis, Validity};
+use rgb_rpc::{now, OutpointFilter, RevalidationReport, SignedCheckpoint};
+
+use super::processor::validate;
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::hooks::HookEvent;
+use crate::DaemonError;
+
+/// Version of the consignment validation rules implemented by the node. Must
+/// be incremented each time the validation library is upgraded or the
+/// validation is changed in a way which may reject previously accepted
+/// contracts.
+pub const VALIDATOR_VERSION: u16 = 1;
+
+/// Validation rules used for the last validation of the contract.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct ValidationStamp {
+    /// Version of the validation rules
+    pub version: u16,
+    /// UNIX timestamp of the validation
+    pub timestamp: u64,
+}
+
+impl Runtime {
+    /// Records that the contract was validated with the current validation
+    /// rules.
+    pub(super) fn stamp_validation(&mut self, contract_id: ContractId) -> Result<(), DaemonError> {
+        let stamp = ValidationStamp {
+            version: VALIDATOR_VERSION,
+            timestamp: now(),
+        };
+        self.store.store_sten(db::VALIDATIONS, contract_id, &stamp)?;
+        Ok(())
+    }
+
+    /// Lists contracts validated by an older version of the validation rules.
+    /// Contracts accepted before the versions were recorded are considered
+    /// validated by the version zero.
+    pub(super) fn outdated_validations(&mut self) -> Result<Vec<ContractId>, DaemonError> {
+        let mut outdated = vec![];
+        for key in self.store.ids(db::GENESIS)? {
+            let contract_id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            // Forgotten contracts have their genesis wiped
+            if self.store.retrieve_sten::<Genesis>(db::GENESIS, contract_id)?.is_none() {
+                continue;
+            }
+            let version = self
+                .store
+                .retrieve_sten::<ValidationStamp>(db::VALIDATIONS, contract_id)?
+                .map(|stamp| stamp.version)
+                .unwrap_or_default();
+            if version < VALIDATOR_VERSION {
+                outdated.push(contract_id);
+            }
+        }
+        Ok(outdated)
+    }
+
+    /// Re-validates the contract, or all contracts validated by an older
+    /// version of the validation rules, using the history kept in the stash.
+    ///
+    /// Contracts found invalid are reported to the hooks and keep their old
+    /// validation version, so they remain reported in the node health until
+    /// forgotten. Their state is not changed.
+    pub(super) fn revalidate_contracts(
+        &mut self,
+        contract_id: Option<ContractId>,
+    ) -> Result<RevalidationReport, DaemonError> {
+        let contract_ids = match contract_id {
+            Some(contract_id) => {
+                if self.store.retrieve_sten::<Genesis>(db::GENESIS, contract_id)?.is_none() {
+                    return Err(StashError::GenesisAbsent.into());
+                }
+                vec![contract_id]
+            }
+            None => self.outdated_validations()?,
+        };
+
+        let mut report = RevalidationReport {
+            validator: VALIDATOR_VERSION,
+            ..default!()
+        };
+        for contract_id in contract_ids {
+            self.interrupt.check()?;
+            debug!("Re-validating contract {}", contract_id);
+            let consignment = self.compose_consignment(
+                contract_id,
+                bset! {},
+                OutpointFilter::All,
+                bset! {},
+                ContractConsignment,
+            )?;
+            // History preceding the checkpoint is not kept in the stash
+            let checkpoint = self
+                .store
+                .retrieve_sten::<SignedCheckpoint>(db::CHECKPOINTS, contract_id)?
+                .map(|signed| signed.checkpoint);
+
+            self.progress.stage("Validating bundles", consignment.anchored_bundles().count());
+            self.chain_cache.sync_tip(&self.electrum, &mut self.store)?;
+            let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+            let status =
+                validate(&consignment, checkpoint.as_ref(), &self.progress.track(&resolver));
+            report.contracts += 1;
+
+            match status.validity() {
+                Validity::Valid | Validity::ValidExceptEndpoints => {
+                    self.stamp_validation(contract_id)?;
+                }
+                Validity::UnresolvedTransactions => {
+                    warn!(
+                        "Re-validation of contract {} is not completed: {:?}",
+                        contract_id, status
+                    );
+                    report.unresolved.insert(contract_id);
+                }
+                Validity::Invalid => {
+                    error!("Contract {} is found invalid: {:?}", contract_id, status);
+                    let failures =
+                        status.failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+                    self.notifier.notify(HookEvent::ValidationFailure {
+                        consignment_id: consignment.id(),
+                        contract_id,
+                        failures: failures.clone(),
+                    });
+                    report.invalid.insert(contract_id, failures);
+                }
+            }
+        }
+
+        info!("Contracts are re-validated: {}", report);
+        Ok(report)
+    }
+}
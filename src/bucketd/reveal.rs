@@ -0,0 +1,349 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Post-factum reveal of the seals of contract assignments which were accepted
+//! while being concealed, for instance when the counterparty discloses the
+//! seal after the transfer.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use amplify::Wrapper;
+use bitcoin::{OutPoint, Txid};
+use commit_verify::TaggedHash;
+use rgb::schema::OwnedRightType;
+use rgb::seal::Revealed;
+use rgb::{
+    Assignment, ContractId, ContractState, Genesis, Node, NodeId, OwnedRights, Transition,
+    TransitionBundle, TypedAssignments,
+};
+use rgb_rpc::{Reveal, RevealData};
+use store_rpc::PrimaryKey;
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Reveals seal of the accepted contract assignments, updating the
+    /// contract state, the outpoint index and the stored transitions with
+    /// their bundles. If none of the accepted transitions has the seal, the
+    /// reveal is kept pending and applied once a consignment with the seal
+    /// is accepted.
+    pub(super) fn reveal_seal(
+        &mut self,
+        contract_id: ContractId,
+        reveal: Reveal,
+    ) -> Result<BTreeSet<NodeId>, DaemonError> {
+        let node_ids = self.apply_reveal(contract_id, &reveal)?;
+        if node_ids.is_empty() {
+            let mut pending: Vec<Reveal> =
+                self.store.retrieve_sten(db::PENDING_REVEALS, contract_id)?.unwrap_or_default();
+            if !pending.contains(&reveal) {
+                pending.push(reveal);
+                self.store.store_sten(db::PENDING_REVEALS, contract_id, &pending)?;
+            }
+            info!(
+                "Seal is not known to the accepted transitions of {}; reveal is kept pending",
+                contract_id
+            );
+        }
+        Ok(node_ids)
+    }
+
+    /// Applies reveals kept pending for the contract, removing those which
+    /// have matched accepted transitions.
+    pub(super) fn apply_pending_reveals(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        let pending: Vec<Reveal> =
+            match self.store.retrieve_sten(db::PENDING_REVEALS, contract_id)? {
+                Some(pending) => pending,
+                None => return Ok(()),
+            };
+        let count = pending.len();
+        let mut remaining = vec![];
+        for reveal in pending {
+            if self.apply_reveal(contract_id, &reveal)?.is_empty() {
+                remaining.push(reveal);
+            }
+        }
+        if remaining.len() == count {
+            return Ok(());
+        }
+        debug!("Applied {} pending reveal(s) for {}", count - remaining.len(), contract_id);
+        if remaining.is_empty() {
+            self.store.wipe_chunk(db::PENDING_REVEALS, contract_id)?;
+        } else {
+            self.store.store_sten(db::PENDING_REVEALS, contract_id, &remaining)?;
+        }
+        Ok(())
+    }
+
+    /// Collects reveal data for the contract seals defined on the given
+    /// outpoints, which are known to the node in revealed form.
+    pub(super) fn export_reveals(
+        &mut self,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<RevealData, DaemonError> {
+        let mut reveals = vec![];
+        for outpoint in outpoints {
+            let index_id = ChunkId::with_fixed_fragments(outpoint.txid, outpoint.vout);
+            let node_ids: BTreeSet<NodeId> =
+                self.store.retrieve_sten(db::OUTPOINTS, index_id)?.unwrap_or_default();
+            for node_id in node_ids {
+                let seals = if node_id.as_inner() == contract_id.as_inner() {
+                    let genesis: Genesis = self
+                        .store
+                        .retrieve_sten(db::GENESIS, contract_id)?
+                        .ok_or(StashError::GenesisAbsent)?;
+                    genesis
+                        .revealed_seals()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|seal| seal.txid.map(|txid| Reveal::with(seal, txid)))
+                        .collect::<Vec<_>>()
+                } else {
+                    let node_contract: Option<ContractId> =
+                        self.store.retrieve_sten(db::NODE_CONTRACTS, node_id)?;
+                    if node_contract != Some(contract_id) {
+                        continue;
+                    }
+                    let transition: Option<Transition> =
+                        self.store.retrieve_sten(db::TRANSITIONS, node_id)?;
+                    let witness_txid: Option<Txid> =
+                        self.store.retrieve_sten(db::TRANSITION_WITNESS, node_id)?;
+                    match (transition, witness_txid) {
+                        (Some(transition), Some(witness_txid)) => transition
+                            .filter_revealed_seals()
+                            .into_iter()
+                            .map(|seal| Reveal::with(seal, witness_txid))
+                            .collect(),
+                        _ => continue,
+                    }
+                };
+                for reveal in seals {
+                    if reveal.outpoint == outpoint && !reveals.contains(&reveal) {
+                        reveals.push(reveal);
+                    }
+                }
+            }
+        }
+        debug!("Exporting reveal data for {} seal(s) of {}", reveals.len(), contract_id);
+        Ok(RevealData {
+            contract_id,
+            reveals,
+        })
+    }
+
+    fn apply_reveal(
+        &mut self,
+        contract_id: ContractId,
+        reveal: &Reveal,
+    ) -> Result<BTreeSet<NodeId>, DaemonError> {
+        let mut state: ContractState = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        let seal = reveal.to_seal();
+
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let transitions =
+            self.store_pool.retrieve_all::<_, Transition>(db::TRANSITIONS, node_ids.clone())?;
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+        let mut known: BTreeMap<NodeId, (Transition, Txid)> = node_ids
+            .into_iter()
+            .zip(transitions.into_iter().zip(witnesses))
+            .filter_map(|(node_id, pair)| match pair {
+                (Some(transition), Some(witness)) => Some((node_id, (transition, witness))),
+                _ => None,
+            })
+            .collect();
+        self.interrupt.check()?;
+
+        let revealed = known
+            .iter()
+            .filter_map(|(node_id, (transition, witness_txid))| {
+                reveal_transition(transition, *witness_txid, seal)
+                    .map(|transition| (*node_id, transition))
+            })
+            .collect::<BTreeMap<_, _>>();
+        if revealed.is_empty() {
+            return Ok(bset! {});
+        }
+
+        for (node_id, transition) in &revealed {
+            let witness_txid = known[node_id].1;
+            debug!("Revealing seal {} in state transition {}", seal, node_id);
+            self.store.store_merge(db::TRANSITIONS, *node_id, transition.clone())?;
+
+            let index_id =
+                ChunkId::with_fixed_fragments(seal.txid.unwrap_or(witness_txid), seal.vout);
+            self.store.insert_into_set(
+                db::OUTPOINTS,
+                index_id.into_slice32(),
+                node_id.into_slice32(),
+            )?;
+            self.outpoint_index.insert(index_id);
+
+            // Transitions imported with a checkpoint have no bundles
+            let chunk_id = ChunkId::with_fixed_fragments(contract_id, witness_txid);
+            if let Some(bundle) =
+                self.store.retrieve_sten::<TransitionBundle>(db::BUNDLES, chunk_id)?
+            {
+                let concealed: BTreeMap<NodeId, BTreeSet<u16>> =
+                    bundle.concealed_iter().map(|(id, set)| (*id, set.clone())).collect();
+                let revealed: BTreeMap<Transition, BTreeSet<u16>> = bundle
+                    .revealed_iter()
+                    .map(|(known, inputs)| match known.node_id() == *node_id {
+                        true => (transition.clone(), inputs.clone()),
+                        false => (known.clone(), inputs.clone()),
+                    })
+                    .collect();
+                let data = TransitionBundle::with(revealed, concealed)
+                    .expect("bundle is constructed from the valid bundle data");
+                self.store.store_sten(db::BUNDLES, chunk_id, &data)?;
+            }
+
+            if let Some(entry) = known.get_mut(node_id) {
+                entry.0 = transition.clone();
+            }
+        }
+
+        // Adding transition to the state restores all its allocations, so the
+        // known descendants are replayed to remove those which they have spent
+        for node_id in replay_order(&known, revealed.keys().copied()) {
+            let (transition, witness_txid) = &known[&node_id];
+            state.add_transition(*witness_txid, transition);
+        }
+        trace!("Contract state now is {:?}", state);
+        self.state_cache.store(&mut self.store, contract_id, &state)?;
+
+        info!("Seal {} is revealed in {} state transition(s)", seal, revealed.len());
+        Ok(revealed.into_keys().collect())
+    }
+}
+
+/// Reveals seal of the transition assignments having the seal concealed and
+/// the state revealed. Returns `None` if the transition has no such
+/// assignments.
+pub(super) fn reveal_transition(
+    transition: &Transition,
+    witness_txid: Txid,
+    seal: Revealed,
+) -> Option<Transition> {
+    // Seals on the witness transaction outputs (like change seals) are defined without txid,
+    // which can't be expressed by the reveal data
+    let mut concealed = bmap! { seal.to_concealed_seal() => seal };
+    if seal.txid == Some(witness_txid) {
+        let seal = Revealed { txid: None, ..seal };
+        concealed.insert(seal.to_concealed_seal(), seal);
+    }
+    let mut found = false;
+    let mut owned_rights: BTreeMap<OwnedRightType, TypedAssignments> = bmap! {};
+    for (owned_type, assignments) in transition.owned_rights().iter() {
+        let assignments = match assignments {
+            TypedAssignments::Value(outputs) => TypedAssignments::Value(
+                outputs
+                    .iter()
+                    .map(|out| match out {
+                        Assignment::ConfidentialSeal {
+                            seal: confidential,
+                            state,
+                        } if concealed.contains_key(confidential) => {
+                            found = true;
+                            Assignment::Revealed {
+                                seal: concealed[confidential],
+                                state: *state,
+                            }
+                        }
+                        _ => out.clone(),
+                    })
+                    .collect(),
+            ),
+            TypedAssignments::Attachment(outputs) => TypedAssignments::Attachment(
+                outputs
+                    .iter()
+                    .map(|out| match out {
+                        Assignment::ConfidentialSeal {
+                            seal: confidential,
+                            state,
+                        } if concealed.contains_key(confidential) => {
+                            found = true;
+                            Assignment::Revealed {
+                                seal: concealed[confidential],
+                                state: state.clone(),
+                            }
+                        }
+                        _ => out.clone(),
+                    })
+                    .collect(),
+            ),
+            other => other.clone(),
+        };
+        owned_rights.insert(*owned_type, assignments);
+    }
+    if !found {
+        return None;
+    }
+    Some(Transition::with(
+        transition.transition_type(),
+        transition.metadata().clone(),
+        transition.parent_public_rights().clone(),
+        OwnedRights::from(owned_rights),
+        transition.public_rights().clone(),
+        transition.parent_owned_rights().clone(),
+    ))
+}
+
+/// Orders the given transitions with all their known descendants, placing
+/// each transition after all of its parents.
+pub(super) fn replay_order(
+    known: &BTreeMap<NodeId, (Transition, Txid)>,
+    roots: impl IntoIterator<Item = NodeId>,
+) -> Vec<NodeId> {
+    let mut children: BTreeMap<NodeId, BTreeSet<NodeId>> = bmap! {};
+    for (node_id, (transition, _)) in known {
+        for parent in transition.parent_outputs() {
+            children.entry(parent.node_id).or_default().insert(*node_id);
+        }
+    }
+
+    let mut nodes = bset! {};
+    let mut queue = roots.into_iter().collect::<Vec<_>>();
+    while let Some(node_id) = queue.pop() {
+        if nodes.insert(node_id) {
+            queue.extend(children.get(&node_id).into_iter().flatten());
+        }
+    }
+
+    let mut order = Vec::with_capacity(nodes.len());
+    let mut done = bset! {};
+    while done.len() < nodes.len() {
+        for node_id in &nodes {
+            if done.contains(node_id) {
+                continue;
+            }
+            let ready =
+                known[node_id].0.parent_outputs().into_iter().all(|parent| {
+                    !nodes.contains(&parent.node_id) || done.contains(&parent.node_id)
+                });
+            if ready {
+                order.push(*node_id);
+                done.insert(*node_id);
+            }
+        }
+    }
+    order
+}
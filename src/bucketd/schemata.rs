@@ -0,0 +1,66 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Reporting of the schemata kept in the stash together with the contracts
+//! issued under each of them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use rgb::{ContractId, Genesis, Schema, SchemaId};
+use rgb_rpc::SchemaInfo;
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Lists schemata known to the node. Contracts forgotten by the node are
+    /// not reported among the contracts using a schema.
+    pub(super) fn list_schemata(&mut self) -> Result<Vec<SchemaInfo>, DaemonError> {
+        let mut contracts: BTreeMap<SchemaId, BTreeSet<ContractId>> = bmap! {};
+        for key in self.store.ids(db::GENESIS)? {
+            let contract_id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            // Forgotten contracts have their genesis wiped
+            if let Some(genesis) = self.store.retrieve_sten::<Genesis>(db::GENESIS, contract_id)? {
+                contracts.entry(genesis.schema_id()).or_default().insert(contract_id);
+            }
+        }
+
+        let mut schemata = vec![];
+        for key in self.store.ids(db::SCHEMATA)? {
+            let schema_id = SchemaId::from_inner(Hash::from_inner(key.into_inner()));
+            let schema = match self.store.retrieve_sten::<Schema>(db::SCHEMATA, schema_id)? {
+                Some(schema) => schema,
+                None => continue,
+            };
+            schemata.push(SchemaInfo {
+                schema_id,
+                root_schema_id: Some(schema.root_id).filter(|root_id| *root_id != zero!()),
+                fields: schema
+                    .field_types
+                    .iter()
+                    .map(|(field, format)| (*field, format!("{:?}", format)))
+                    .collect(),
+                owned_rights: schema
+                    .owned_right_types
+                    .iter()
+                    .map(|(right_type, state)| (*right_type, format!("{:?}", state)))
+                    .collect(),
+                public_rights: schema.public_right_types.clone(),
+                transitions: schema.transitions.keys().copied().collect(),
+                extensions: schema.extensions.keys().copied().collect(),
+                contracts: contracts.remove(&schema_id).unwrap_or_default(),
+            });
+        }
+        Ok(schemata)
+    }
+}
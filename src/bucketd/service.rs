@@ -8,30 +8,43 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
+#[cfg(feature = "testkit")]
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
 use amplify::num::u24;
-use bitcoin::secp256k1::rand::random;
-use bitcoin::{OutPoint, Txid};
+use bitcoin::hashes::sha256;
+use bitcoin::secp256k1::rand::{random, thread_rng};
+use bitcoin::secp256k1::{KeyPair, SecretKey, SECP256K1};
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
+use bp::seals::txout::CloseMethod;
 use commit_verify::ConsensusCommit;
-use electrum_client::{Client as ElectrumClient, ConfigBuilder};
-use internet2::addr::NodeAddr;
+use internet2::addr::{NodeAddr, ServiceAddr};
 use internet2::ZmqSocketType;
+use lnpbp::chain::Chain;
 use microservices::error::BootstrapError;
 use microservices::esb;
 use microservices::esb::{ClientId, EndpointList, Error};
 use microservices::node::TryService;
 use psbt::Psbt;
 use rgb::schema::TransitionType;
+use rgb::secp256k1zkp::rand::Rng;
 use rgb::{
-    ConsignmentType, ContractConsignment, ContractId, InmemConsignment, SealEndpoint,
-    StateTransfer, TransferConsignment, Validity,
+    seal, validation, AtomicValue, BundleId, ConsignmentId, ConsignmentType, ContractConsignment,
+    ContractId, InmemConsignment, NodeId, Schema, SealEndpoint, StateTransfer, TransferConsignment,
+    Validity,
+};
+use rgb_rpc::{
+    seal_payload, Armor, CacheStats, Checkpoint, Compression, HeaderCheckpoint, InvoiceTolerance,
+    IssueSpec, NetworkMagic, NodeEvent, OutpointFilter, PayjoinContribution, PayjoinProposal,
+    ProofBundle, RetentionCategory, RetentionPeriod, Reveal, RpcMsg, SwapAcceptance, SwapOffer,
+    SwapTerms, ValidationCost, ValidationReport,
 };
-use rgb_rpc::{OutpointFilter, Reveal, RpcMsg};
 use stens::AsciiString;
+use store_rpc::PrimaryKey;
 use storm::{
     Chunk, Container, ContainerFullId, ContainerHeader, ContainerId, ContainerInfo, MesgId,
 };
@@ -39,20 +52,70 @@ use storm_ext::ExtMsg as StormMsg;
 use storm_rpc::AddressedMsg;
 use strict_encoding::{MediumVec, StrictEncode};
 
+#[cfg(feature = "bdk-wallet")]
+use super::bdk_wallet::BdkWallets;
+use super::cache::StateCache;
+#[cfg(feature = "sql")]
+use super::export::SqlExporter;
+use super::index::OutpointIndex;
+use super::interrupt::Interrupt;
+use super::pool::StorePool;
+use super::processor::{CONSIGNMENT_MIME, CONSIGNMENT_ZSTD_MIME, SEALED_MIME_SUFFIX};
+use super::progress::Progress;
+use super::quarantine::AcceptancePolicy;
+use super::ScriptLimits;
 use crate::bus::{
-    BusMsg, ConsignReq, CtlMsg, DaemonId, Endpoints, FinalizeTransferReq, FinalizeTransfersReq,
-    OutpointStateReq, ProcessDisclosureReq, ProcessReq, Responder, ServiceBus, ServiceId,
-    ValidityResp,
+    AccelerateTransferReq, AcceptSwapReq, BusMsg, CompletePayjoinReq, CompleteSwapReq,
+    ComposePaymentReq, ConsignReq, ConsolidateAllocationsReq, ContractAllocationsReq,
+    ContractDepsReq, ContractStatsReq, ContributePayjoinReq, CreateCheckpointReq, CreateInvoiceReq,
+    CreateWalletReq, CtlMsg, DaemonId, DiffHistoryReq, Endpoints, ExportRevealsReq,
+    FinalizeTransferReq, FinalizeTransfersReq, ForgetContractReq, ForgetSpentOutpointsReq,
+    ImportContractReq, IssueContractReq, ListWalletsReq, OutpointStateReq, PreviewTransferReq,
+    ProcessDisclosureReq, ProcessReq, ProposePayjoinReq, ProposeSwapReq, ProveTransactionsReq,
+    PruneDataReq, PublishTxReq, QuarantineReq, RebuildStateReq, ReplayBalanceReq,
+    ReplayConsignmentsReq, Responder, RevalidateContractsReq, RevealSealReq, RewindContractReq,
+    SendTransferReq, ServiceBus, ServiceId, SignCheckpointReq, SignPsbtReq, StateDigestReq,
+    StateUpdates, StorageUsageReq, SyncWalletReq, TimestampReq, ValidityResp, WalletAddressesReq,
+    WalletBalanceReq,
+};
+use crate::chain::ChainCache;
+use crate::db::{Store, StoreError};
+#[cfg(feature = "testkit")]
+use crate::electrum::ChainBackend;
+use crate::electrum::ElectrumPool;
+use crate::entropy::Entropy;
+use crate::hooks::Notifier;
+#[cfg(not(feature = "plugins"))]
+use crate::plugins::check_plugins;
+#[cfg(feature = "plugins")]
+use crate::plugins::PluginHost;
+use crate::signer::SignerClient;
+use crate::{
+    timestamp, Config, DaemonError, IssuerSigner, LaunchError, SignerError, TimestampError,
 };
-use crate::{Config, DaemonError, LaunchError};
-
-const ELECTRUM_TIMEOUT: u8 = 4;
 
 pub fn run(config: Config) -> Result<(), BootstrapError<LaunchError>> {
+    run_with(config, Runtime::init)
+}
+
+/// Runs bucket daemon accessing the chain through the given backend instead
+/// of the electrum servers.
+#[cfg(feature = "testkit")]
+pub fn run_with_backend(
+    config: Config,
+    backend: Arc<dyn ChainBackend>,
+) -> Result<(), BootstrapError<LaunchError>> {
+    run_with(config, |config| Runtime::with_backend(config, backend))
+}
+
+fn run_with(
+    config: Config,
+    init: impl FnOnce(Config) -> Result<Runtime, BootstrapError<LaunchError>>,
+) -> Result<(), BootstrapError<LaunchError>> {
     let storm_endpoint = config.storm_endpoint.clone();
     let rpc_endpoint = config.rpc_endpoint.clone();
     let ctl_endpoint = config.ctl_endpoint.clone();
-    let runtime = Runtime::init(config)?;
+    let runtime = init(config)?;
 
     debug!("Connecting to service buses {}, {}", rpc_endpoint, ctl_endpoint);
     let controller = esb::Controller::with(
@@ -82,28 +145,192 @@ pub fn run(config: Config) -> Result<(), BootstrapError<LaunchError>> {
     unreachable!()
 }
 
+fn connect_store(endpoint: &ServiceAddr) -> Result<Box<dyn Store>, StoreError> {
+    store_rpc::Client::with(endpoint).map(|client| Box::new(client) as Box<dyn Store>)
+}
+
 pub struct Runtime {
     id: DaemonId,
 
-    pub(crate) electrum: ElectrumClient,
+    pub(crate) electrum: ElectrumPool,
+
+    pub(crate) chain_cache: ChainCache,
+
+    pub(crate) state_cache: StateCache,
+
+    pub(crate) outpoint_index: OutpointIndex,
+
+    pub(crate) store: Box<dyn Store>,
+
+    pub(crate) store_pool: StorePool,
+
+    /// Chain used by the node, defining the network of the wallet addresses
+    pub(crate) chain: Chain,
+
+    /// Clients which have negotiated compression of RPC messages
+    pub(crate) compressed_clients: BTreeSet<ClientId>,
+
+    /// Compression applied to the consignments relayed to the beneficiaries
+    pub(crate) relay_compression: Compression,
+
+    /// Key opening consignments received in containers encrypted to the node
+    pub(crate) consignment_key: Option<KeyPair>,
+
+    /// Hooks notified about the events of the processed consignments
+    pub(crate) notifier: Notifier,
+
+    /// Events which are not yet reported to RGBd for publishing
+    pub(crate) events: Vec<NodeEvent>,
+
+    /// Rules for the automatic acceptance of the consignments received from
+    /// the remote nodes
+    pub(crate) acceptance: AcceptancePolicy,
+
+    /// Number of days the consignment containers are kept in the store unless
+    /// the consignment is accepted
+    pub(crate) container_retention: u16,
+
+    /// Retention periods of the data categories which are subject to pruning
+    pub(crate) retention: BTreeMap<RetentionCategory, RetentionPeriod>,
+
+    /// Cancellation and deadline of the request being processed
+    pub(crate) interrupt: Interrupt,
+
+    /// Progress of the request being processed, reported to the client
+    pub(crate) progress: Progress,
+
+    /// External signer daemon signing PSBTs for the clients
+    pub(crate) signer_endpoint: Option<ServiceAddr>,
+
+    /// Signer of the contract checkpoints holding the issuer keys
+    pub(crate) issuer_signer: Option<IssuerSigner>,
+
+    /// OpenTimestamps calendar anchoring the stash commitments
+    pub(crate) timestamp_calendar: Option<String>,
+
+    /// Trusted block header used for the verification of transaction proofs
+    pub(crate) header_checkpoint: Option<HeaderCheckpoint>,
+
+    /// Limits of the contract script execution during the validation
+    pub(crate) script_limits: ScriptLimits,
+
+    /// Whether accepted consignments are recorded into the contract journals,
+    /// which is done on regtest only
+    pub(crate) journal: bool,
+
+    /// Number of decoy change allocations, each on a separate change output,
+    /// added to the composed payments
+    pub(crate) decoy_change: u8,
 
-    pub(crate) store: store_rpc::Client,
+    /// Entropy source of the blinding factors
+    pub(crate) entropy: Entropy,
+
+    /// Cost of the last validated consignment, which is reported to the
+    /// client together with the validation status
+    pub(crate) validation_cost: Option<ValidationCost>,
+
+    /// Cache hits and misses last reported to RGBd
+    pub(crate) reported_caches: CacheStats,
+
+    /// Connector mirroring accepted contract data into the SQL database
+    #[cfg(feature = "sql")]
+    pub(crate) exporter: Option<SqlExporter>,
+
+    /// Validation plugins run for the consignments passing the core validation
+    #[cfg(feature = "plugins")]
+    pub(crate) plugins: PluginHost,
+
+    /// Embedded BDK wallets for the wallets registered in the configuration
+    #[cfg(feature = "bdk-wallet")]
+    pub(crate) bdk_wallets: BdkWallets,
 }
 
 impl Runtime {
     pub fn init(config: Config) -> Result<Self, BootstrapError<LaunchError>> {
+        let electrum = match config.offline {
+            true => ElectrumPool::offline(),
+            false => ElectrumPool::with(&config.electrum_urls)
+                .map_err(|e| LaunchError::ElectrumConnectivity(e.to_string()))?,
+        };
+        let endpoint = config.store_endpoint.clone();
+        Self::init_with(config, electrum, move || connect_store(&endpoint))
+    }
+
+    /// Constructs runtime accessing the chain through the given backend
+    /// instead of the electrum servers.
+    #[cfg(feature = "testkit")]
+    pub fn with_backend(
+        config: Config,
+        backend: Arc<dyn ChainBackend>,
+    ) -> Result<Self, BootstrapError<LaunchError>> {
+        let endpoint = config.store_endpoint.clone();
+        Self::init_with(config, ElectrumPool::with_backend(backend), move || {
+            connect_store(&endpoint)
+        })
+    }
+
+    /// Constructs runtime accessing the chain and the store through the given
+    /// backends instead of the electrum servers and the store service. Each
+    /// store connection used by the runtime is a clone of the given store.
+    #[cfg(feature = "testkit")]
+    pub fn with_backends(
+        config: Config,
+        chain: Arc<dyn ChainBackend>,
+        store: impl Store + Clone + 'static,
+    ) -> Result<Self, BootstrapError<LaunchError>> {
+        Self::init_with(config, ElectrumPool::with_backend(chain), move || {
+            Ok(Box::new(store.clone()) as Box<dyn Store>)
+        })
+    }
+
+    fn init_with(
+        config: Config,
+        electrum: ElectrumPool,
+        connect: impl Fn() -> Result<Box<dyn Store>, StoreError>,
+    ) -> Result<Self, BootstrapError<LaunchError>> {
         debug!("Connecting to store service at {}", config.store_endpoint);
 
-        let store = store_rpc::Client::with(&config.store_endpoint).map_err(LaunchError::from)?;
+        let store = connect().map_err(LaunchError::from)?;
+
+        let id = config.daemon_id.unwrap_or_else(random);
 
-        let id = random();
+        let chain_cache = ChainCache::with_limit(config.chain_cache_size);
+        let state_cache = StateCache::with_limit(config.state_cache_size);
+        let store_pool =
+            StorePool::connect(config.store_concurrency, connect).map_err(LaunchError::from)?;
+        let acceptance = AcceptancePolicy::from(&config);
+        let interrupt = Interrupt::listen(&config, id);
+        let progress = Progress::connect(&config, id);
+        let relay_compression = match config.compress_relay {
+            true => Compression::Zstd,
+            false => Compression::None,
+        };
+        let consignment_key = config.consignment_keypair()?;
+        #[cfg(feature = "sql")]
+        let exporter =
+            config.sql_export.clone().map(|url| SqlExporter::with(url, config.chain.to_string()));
+        #[cfg(not(feature = "sql"))]
+        if config.sql_export.is_some() {
+            warn!("SQL export is requested, but the node is compiled without `sql` feature");
+        }
+        #[cfg(feature = "plugins")]
+        let plugins = PluginHost::load(&config).map_err(LaunchError::from)?;
+        #[cfg(not(feature = "plugins"))]
+        check_plugins(&config).map_err(LaunchError::from)?;
 
-        let electrum_config = ConfigBuilder::new()
-            .timeout(Some(ELECTRUM_TIMEOUT))
-            .expect("cannot fail since socks5 is unset")
-            .build();
-        let electrum = ElectrumClient::from_config(&config.electrum_url, electrum_config)
-            .map_err(|e| LaunchError::ElectrumConnectivity(e.to_string()))?;
+        #[cfg(feature = "bdk-wallet")]
+        let bdk_wallets = BdkWallets::load(&config).map_err(LaunchError::from)?;
+        #[cfg(not(feature = "bdk-wallet"))]
+        if !config.bdk_wallets.is_empty() {
+            warn!(
+                "BDK wallets are registered, but the node is compiled without `bdk-wallet` feature"
+            );
+        }
+        let script_limits = ScriptLimits::from(&config);
+        let retention = RetentionCategory::ALL
+            .into_iter()
+            .filter_map(|category| Some((category, config.retention_period(category)?)))
+            .collect();
 
         info!("Bucket runtime started successfully");
 
@@ -111,11 +338,49 @@ impl Runtime {
             id,
             store,
             electrum,
+            chain_cache,
+            state_cache,
+            outpoint_index: default!(),
+            store_pool,
+            chain: config.chain.clone(),
+            compressed_clients: empty!(),
+            relay_compression,
+            consignment_key,
+            notifier: Notifier::with(config.hooks, config.hook_secret, config.hook_retries),
+            events: empty!(),
+            acceptance,
+            container_retention: config.container_retention,
+            retention,
+            interrupt,
+            progress,
+            signer_endpoint: config.signer_endpoint,
+            issuer_signer: config.issuer_signer,
+            timestamp_calendar: config.timestamp_calendar,
+            header_checkpoint: config.header_checkpoint,
+            script_limits,
+            journal: matches!(config.chain, Chain::Regtest(_)),
+            decoy_change: config.decoy_change,
+            entropy: Entropy::with(config.entropy_source),
+            validation_cost: None,
+            reported_caches: default!(),
+            #[cfg(feature = "sql")]
+            exporter,
+            #[cfg(feature = "plugins")]
+            plugins,
+            #[cfg(feature = "bdk-wallet")]
+            bdk_wallets,
         })
     }
 }
 
-impl Responder for Runtime {}
+impl Responder for Runtime {
+    fn rpc_compression(&self, client_id: ClientId) -> Compression {
+        match self.compressed_clients.contains(&client_id) {
+            true => Compression::Zstd,
+            false => Compression::None,
+        }
+    }
+}
 
 impl esb::Handler<ServiceBus> for Runtime {
     type Request = BusMsg;
@@ -136,13 +401,23 @@ impl esb::Handler<ServiceBus> for Runtime {
         source: ServiceId,
         request: Self::Request,
     ) -> Result<(), Self::Error> {
-        match (bus_id, request, source) {
-            (ServiceBus::Rpc, BusMsg::Rpc(msg), ServiceId::Client(client_id)) => {
-                self.handle_rpc(endpoints, client_id, msg)
+        let res = match (bus_id, request, source) {
+            (ServiceBus::Rpc, BusMsg::Rpc(envelope), ServiceId::Client(client_id)) => {
+                match self.open_rpc(endpoints, client_id, envelope) {
+                    Some(msg) => self.handle_rpc(endpoints, client_id, msg),
+                    None => Ok(()),
+                }
             }
             (ServiceBus::Ctl, BusMsg::Ctl(msg), source) => self.handle_ctl(endpoints, source, msg),
             (bus, msg, _) => Err(DaemonError::wrong_esb_msg(bus, &msg)),
+        };
+        self.interrupt.finish();
+        self.progress.finish();
+        if !self.events.is_empty() {
+            let events = self.events.drain(..).collect();
+            self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::Events(events))?;
         }
+        res
     }
 
     fn handle_err(
@@ -179,38 +454,134 @@ impl Runtime {
                 client_id,
                 consignment,
                 force,
+                full,
+                deadline,
+                proofs,
+                progress,
                 ..
             }) => {
-                self.handle_consignment(endpoints, client_id, consignment, force, None)?;
+                self.interrupt.start(client_id, deadline);
+                self.progress.start(client_id, progress);
+                self.handle_consignment(
+                    endpoints,
+                    client_id,
+                    consignment,
+                    force,
+                    full,
+                    None,
+                    proofs,
+                )?;
             }
             CtlMsg::ProcessTransfer(ProcessReq {
                 client_id,
                 consignment,
                 force,
+                full,
                 reveal,
+                deadline,
+                proofs,
+                progress,
             }) => {
-                self.handle_consignment(endpoints, client_id, consignment, force, reveal)?;
+                self.interrupt.start(client_id, deadline);
+                self.progress.start(client_id, progress);
+                self.handle_consignment(
+                    endpoints,
+                    client_id,
+                    consignment,
+                    force,
+                    full,
+                    reveal,
+                    proofs,
+                )?;
             }
             CtlMsg::ProcessDisclosure(ProcessDisclosureReq { client_id, txid }) => {
                 self.handle_disclosure(endpoints, client_id, txid)?;
             }
+            CtlMsg::IssueContract(IssueContractReq {
+                client_id,
+                spec,
+                schema,
+            }) => {
+                self.handle_issue(endpoints, client_id, spec, schema)?;
+            }
+            CtlMsg::PreviewIssue(IssueContractReq {
+                client_id,
+                spec,
+                schema,
+            }) => {
+                self.handle_preview_issue(endpoints, client_id, spec, schema)?;
+            }
+            CtlMsg::ImportContract(ImportContractReq { client_id, genesis }) => {
+                self.handle_import(endpoints, client_id, genesis)?;
+            }
+            CtlMsg::RevealSeal(RevealSealReq {
+                client_id,
+                contract_id,
+                reveal,
+            }) => {
+                self.handle_reveal_seal(endpoints, client_id, contract_id, reveal)?;
+            }
+            CtlMsg::CreateInvoice(CreateInvoiceReq {
+                client_id,
+                contract_id,
+                amount,
+                outpoint,
+                close_method,
+                transport,
+                signet,
+                tolerance,
+            }) => {
+                self.handle_create_invoice(
+                    endpoints,
+                    client_id,
+                    contract_id,
+                    amount,
+                    outpoint,
+                    close_method,
+                    transport,
+                    signet,
+                    tolerance,
+                )?;
+            }
+            CtlMsg::ExportReveals(ExportRevealsReq {
+                client_id,
+                contract_id,
+                outpoints,
+            }) => {
+                self.handle_export_reveals(endpoints, client_id, contract_id, outpoints)?;
+            }
             CtlMsg::ProcessTransferContainer(container_id) => {
                 self.handle_container(endpoints, container_id)?;
             }
+            CtlMsg::ProveTransactions(ProveTransactionsReq {
+                client_id,
+                txids,
+                from_height,
+            }) => {
+                self.handle_prove_transactions(endpoints, client_id, txids, from_height)?;
+            }
 
             CtlMsg::ConsignContract(ConsignReq {
                 client_id,
                 contract_id,
                 include,
                 outpoints,
+                known,
+                deadline,
+                progress,
+                armored,
                 _phantom,
             }) => {
+                self.interrupt.start(client_id, deadline);
+                self.progress.start(client_id, progress);
                 self.handle_consign_contract(
                     endpoints,
                     client_id,
                     contract_id,
                     include,
                     outpoints,
+                    known,
+                    armored,
                 )?;
             }
             CtlMsg::ConsignTranfer(ConsignReq {
@@ -218,22 +589,42 @@ impl Runtime {
                 contract_id,
                 include,
                 outpoints,
+                known,
+                deadline,
+                progress,
+                armored,
                 _phantom,
             }) => {
+                self.interrupt.start(client_id, deadline);
+                self.progress.start(client_id, progress);
                 self.handle_consign_transfer(
                     endpoints,
                     client_id,
                     contract_id,
                     include,
                     outpoints,
+                    known,
+                    armored,
                 )?;
             }
 
             CtlMsg::OutpointState(OutpointStateReq {
                 client_id,
                 outpoints,
+                scripts,
+                spent_status,
+                decimal_amounts,
+                contracts,
             }) => {
-                self.handle_outpoint_state(endpoints, client_id, outpoints)?;
+                self.handle_outpoint_state(
+                    endpoints,
+                    client_id,
+                    outpoints,
+                    scripts,
+                    spent_status,
+                    decimal_amounts,
+                    contracts,
+                )?;
             }
 
             CtlMsg::FinalizeTransfer(FinalizeTransferReq {
@@ -242,6 +633,7 @@ impl Runtime {
                 endseals,
                 psbt,
                 beneficiary,
+                recipient_key,
             }) => {
                 self.handle_finalize_transfer(
                     endpoints,
@@ -250,6 +642,7 @@ impl Runtime {
                     endseals,
                     psbt,
                     beneficiary,
+                    recipient_key,
                 )?;
             }
 
@@ -261,257 +654,1751 @@ impl Runtime {
                 self.handle_finalize_transfers(endpoints, client_id, transfers, psbt)?;
             }
 
-            wrong_msg => {
-                error!("Request is not supported by the CTL interface");
-                return Err(DaemonError::wrong_esb_msg(ServiceBus::Ctl, &wrong_msg));
+            CtlMsg::SendTransfer(SendTransferReq {
+                client_id,
+                consignment,
+                beneficiary,
+                recipient_key,
+            }) => {
+                self.handle_send_transfer(
+                    endpoints,
+                    client_id,
+                    consignment,
+                    beneficiary,
+                    recipient_key,
+                )?;
             }
-        }
-
-        Ok(())
-    }
-}
 
-impl Runtime {
-    fn handle_container(
-        &mut self,
-        endpoints: &mut Endpoints,
-        container_id: ContainerId,
-    ) -> Result<(), DaemonError> {
-        match self.process_container(container_id) {
-            Err(err) => {
-                error!("Invalid consignment in the container {}: {}", container_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::PreviewTransfer(PreviewTransferReq {
+                client_id,
+                consignment,
+                endseals,
+                psbt,
+            }) => {
+                self.handle_preview_transfer(endpoints, client_id, consignment, endseals, psbt)?;
             }
-            Ok(status) => {
-                info!(
-                    "Consignment from container {} is processed with status {}",
-                    container_id,
-                    status.validity()
-                );
-                debug!("Validation report: {}", status);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+
+            CtlMsg::SyncWallet(SyncWalletReq {
+                client_id,
+                descriptor,
+                gap_limit,
+                rescan,
+            }) => {
+                self.handle_sync_wallet(endpoints, client_id, descriptor, gap_limit, rescan)?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_consignment<C: ConsignmentType>(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
-        consignment: InmemConsignment<C>,
-        force: bool,
-        reveal: Option<Reveal>,
-    ) -> Result<(), DaemonError> {
-        let id = consignment.consensus_commit();
-        match self.process_consignment(consignment, force, reveal) {
-            Err(err) => {
-                let _ = self.send_rpc(endpoints, client_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::WalletBalance(WalletBalanceReq {
+                client_id,
+                descriptor,
+            }) => {
+                self.handle_wallet_balance(endpoints, client_id, descriptor)?;
             }
-            Ok(status) => {
-                // We ignore client reporting if it fails
-                let msg = match status.validity() {
-                    Validity::UnresolvedTransactions => {
-                        RpcMsg::UnresolvedTxids(status.unresolved_txids.clone())
-                    }
-                    Validity::Invalid => RpcMsg::Invalid(status.clone()),
-                    Validity::ValidExceptEndpoints if force => RpcMsg::Success(
-                        s!("consumed notwithstanding non-mined endpoint transactions").into(),
-                    ),
-                    Validity::ValidExceptEndpoints => {
-                        RpcMsg::UnresolvedTxids(status.unmined_endpoint_txids.clone())
-                    }
-                    Validity::Valid => RpcMsg::success(),
-                };
-                let _ = self.send_rpc(endpoints, client_id, msg);
-                self.send_ctl(endpoints, ServiceId::rgbd(), ValidityResp {
-                    client_id,
-                    consignment_id: id,
-                    status,
-                })?
+
+            CtlMsg::CreateWallet(CreateWalletReq {
+                client_id,
+                descriptor,
+            }) => {
+                self.handle_create_wallet(endpoints, client_id, descriptor)?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_disclosure(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
-        txid: Txid,
-    ) -> Result<(), DaemonError> {
-        match self.process_disclosure(txid) {
-            Err(err) => {
-                let _ = self.send_rpc(endpoints, client_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::ListWallets(ListWalletsReq { client_id, wallets }) => {
+                self.handle_list_wallets(endpoints, client_id, wallets)?;
             }
-            Ok(_) => {
-                let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+
+            CtlMsg::ListSchemata(client_id) => {
+                self.handle_list_schemata(endpoints, client_id)?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_consign_contract(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
-        contract_id: ContractId,
-        include: BTreeSet<TransitionType>,
-        outpoints: OutpointFilter,
-    ) -> Result<(), DaemonError> {
-        match self.compose_consignment(contract_id, include, outpoints, ContractConsignment) {
-            Err(err) => {
-                let _ = self.send_rpc(endpoints, client_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::WalletAddresses(WalletAddressesReq {
+                client_id,
+                descriptor,
+                unused,
+            }) => {
+                self.handle_wallet_addresses(endpoints, client_id, descriptor, unused)?;
             }
-            Ok(consignment) => {
-                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Contract(consignment));
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+
+            CtlMsg::ReplayBalance(ReplayBalanceReq {
+                client_id,
+                contract_id,
+                height,
+                descriptor,
+                outpoints,
+            }) => {
+                self.handle_replay_balance(
+                    endpoints,
+                    client_id,
+                    contract_id,
+                    height,
+                    descriptor,
+                    outpoints,
+                )?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_consign_transfer(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
-        contract_id: ContractId,
-        include: BTreeSet<TransitionType>,
-        outpoints: OutpointFilter,
-    ) -> Result<(), DaemonError> {
-        match self.compose_consignment(contract_id, include, outpoints, TransferConsignment) {
-            Err(err) => {
-                let _ = self.send_rpc(endpoints, client_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::ContractStats(ContractStatsReq {
+                client_id,
+                contract_id,
+            }) => {
+                self.handle_contract_stats(endpoints, client_id, contract_id)?;
             }
-            Ok(consignment) => {
-                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StateTransfer(consignment));
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+
+            CtlMsg::ContractDeps(ContractDepsReq {
+                client_id,
+                contract_id,
+            }) => {
+                self.handle_contract_deps(endpoints, client_id, contract_id)?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_outpoint_state(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
-        outpoints: BTreeSet<OutPoint>,
-    ) -> Result<(), DaemonError> {
-        match self.outpoint_state(outpoints) {
-            Err(err) => {
-                let _ = self.send_rpc(endpoints, client_id, err);
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            CtlMsg::ContractAllocations(ContractAllocationsReq {
+                client_id,
+                contract_id,
+                spent_status,
+            }) => {
+                self.handle_contract_allocations(endpoints, client_id, contract_id, spent_status)?;
             }
-            Ok(transitions_info) => {
-                let _ =
-                    self.send_rpc(endpoints, client_id, RpcMsg::OutpointState(transitions_info));
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+
+            CtlMsg::StorageUsage(StorageUsageReq { client_id, limit }) => {
+                self.handle_storage_usage(endpoints, client_id, limit)?;
             }
-        }
-        Ok(())
-    }
 
-    fn handle_finalize_transfer(
-        &mut self,
+            CtlMsg::StateDigest(StateDigestReq {
+                client_id,
+                contract_id,
+            }) => {
+                self.handle_state_digest(endpoints, client_id, contract_id)?;
+            }
+
+            CtlMsg::DiffHistory(DiffHistoryReq {
+                client_id,
+                contract_id,
+                bundles,
+            }) => {
+                self.handle_diff_history(endpoints, client_id, contract_id, bundles)?;
+            }
+
+            CtlMsg::CommitStash(client_id) => {
+                self.handle_commit_stash(endpoints, client_id)?;
+            }
+
+            CtlMsg::CreateCheckpoint(CreateCheckpointReq {
+                client_id,
+                contract_id,
+                height,
+            }) => {
+                self.handle_create_checkpoint(endpoints, client_id, contract_id, height)?;
+            }
+
+            CtlMsg::ConsolidateAllocations(ConsolidateAllocationsReq {
+                client_id,
+                contract_id,
+                outpoints,
+                seal,
+            }) => {
+                self.handle_consolidate(endpoints, client_id, contract_id, outpoints, seal)?;
+            }
+
+            CtlMsg::ProposeSwap(ProposeSwapReq {
+                client_id,
+                terms,
+                timeout,
+                psbt,
+                consignment,
+                owner,
+            }) => {
+                self.handle_propose_swap(
+                    endpoints,
+                    client_id,
+                    terms,
+                    timeout,
+                    psbt,
+                    consignment,
+                    owner,
+                )?;
+            }
+
+            CtlMsg::AcceptSwap(AcceptSwapReq {
+                client_id,
+                offer,
+                psbt,
+                consignment,
+                owner,
+            }) => {
+                self.handle_accept_swap(endpoints, client_id, offer, psbt, consignment, owner)?;
+            }
+
+            CtlMsg::CompleteSwap(CompleteSwapReq {
+                client_id,
+                acceptance,
+            }) => {
+                self.handle_complete_swap(endpoints, client_id, acceptance)?;
+            }
+
+            CtlMsg::ProposePayjoin(ProposePayjoinReq {
+                client_id,
+                psbt,
+                consignment,
+                owner,
+            }) => {
+                self.handle_propose_payjoin(endpoints, client_id, psbt, consignment, owner)?;
+            }
+
+            CtlMsg::ContributePayjoin(ContributePayjoinReq {
+                client_id,
+                proposal,
+                descriptor,
+                outpoints,
+                endseals,
+                fee,
+                owner,
+            }) => {
+                self.handle_contribute_payjoin(
+                    endpoints, client_id, proposal, descriptor, outpoints, endseals, fee, owner,
+                )?;
+            }
+
+            CtlMsg::CompletePayjoin(CompletePayjoinReq {
+                client_id,
+                contribution,
+            }) => {
+                self.handle_complete_payjoin(endpoints, client_id, contribution)?;
+            }
+
+            CtlMsg::ComposePayment(ComposePaymentReq {
+                client_id,
+                descriptor,
+                contract_id,
+                beneficiaries,
+                fee,
+            }) => {
+                self.handle_compose_payment(
+                    endpoints,
+                    client_id,
+                    descriptor,
+                    contract_id,
+                    beneficiaries,
+                    fee,
+                )?;
+            }
+
+            CtlMsg::PublishTx(PublishTxReq { client_id, psbt }) => {
+                self.handle_publish_tx(endpoints, client_id, psbt)?;
+            }
+
+            CtlMsg::AccelerateTransfer(AccelerateTransferReq {
+                client_id,
+                consignment_id,
+                descriptor,
+                fee_rate,
+            }) => {
+                self.handle_accelerate_transfer(
+                    endpoints,
+                    client_id,
+                    consignment_id,
+                    descriptor,
+                    fee_rate,
+                )?;
+            }
+
+            CtlMsg::ForgetSpent(ForgetSpentOutpointsReq {
+                client_id,
+                descriptor,
+                outpoints,
+            }) => {
+                self.handle_forget_spent(endpoints, client_id, descriptor, outpoints)?;
+            }
+
+            CtlMsg::SignPsbt(SignPsbtReq { client_id, psbt }) => {
+                self.handle_sign_psbt(endpoints, client_id, psbt)?;
+            }
+
+            CtlMsg::SignCheckpoint(SignCheckpointReq {
+                client_id,
+                checkpoint,
+            }) => {
+                self.handle_sign_checkpoint(endpoints, client_id, checkpoint)?;
+            }
+
+            CtlMsg::Timestamp(TimestampReq { client_id, digest }) => {
+                self.handle_timestamp(endpoints, client_id, digest)?;
+            }
+
+            CtlMsg::ApproveQuarantined(QuarantineReq {
+                client_id,
+                consignment_id,
+            }) => {
+                self.handle_quarantined(endpoints, client_id, consignment_id, true)?;
+            }
+            CtlMsg::RevalidateQuarantined(QuarantineReq {
+                client_id,
+                consignment_id,
+            }) => {
+                self.handle_quarantined(endpoints, client_id, consignment_id, false)?;
+            }
+
+            CtlMsg::CollectGarbage(client_id) => {
+                self.handle_collect_garbage(endpoints, client_id)?;
+            }
+
+            CtlMsg::PruneData(PruneDataReq { client_id, dry_run }) => {
+                self.handle_prune(endpoints, client_id, dry_run)?;
+            }
+
+            CtlMsg::ForgetContract(ForgetContractReq {
+                client_id,
+                contract_id,
+                tombstone,
+            }) => {
+                self.handle_forget_contract(endpoints, client_id, contract_id, tombstone)?;
+            }
+
+            CtlMsg::RewindContract(RewindContractReq {
+                client_id,
+                contract_id,
+                consignment_id,
+            }) => {
+                self.handle_rewind_contract(endpoints, client_id, contract_id, consignment_id)?;
+            }
+
+            CtlMsg::ReplayConsignments(ReplayConsignmentsReq {
+                client_id,
+                contract_id,
+                consignments,
+                progress,
+            }) => {
+                self.progress.start(client_id, progress);
+                self.handle_replay_consignments(endpoints, client_id, contract_id, consignments)?;
+            }
+
+            CtlMsg::RebuildState(RebuildStateReq {
+                client_id,
+                contract_id,
+                progress,
+            }) => {
+                self.progress.start(client_id, progress);
+                self.handle_rebuild_state(endpoints, client_id, contract_id)?;
+            }
+
+            CtlMsg::RevalidateContracts(RevalidateContractsReq {
+                client_id,
+                contract_id,
+                progress,
+            }) => {
+                self.progress.start(client_id, progress);
+                self.handle_revalidate_contracts(endpoints, client_id, contract_id)?;
+            }
+
+            CtlMsg::RecoverStore => {
+                self.handle_recover_store(endpoints)?;
+            }
+
+            CtlMsg::CheckValidations => {
+                self.handle_check_validations(endpoints)?;
+            }
+
+            CtlMsg::InvalidateStates(updates) => {
+                self.state_cache.invalidate(&updates.contract_ids);
+                self.outpoint_index.extend(updates.outpoints);
+            }
+
+            CtlMsg::CompressedClients(client_ids) => {
+                self.compressed_clients = client_ids;
+            }
+
+            wrong_msg => {
+                error!("Request is not supported by the CTL interface");
+                return Err(DaemonError::wrong_esb_msg(ServiceBus::Ctl, &wrong_msg));
+            }
+        }
+
+        self.report_cache_stats(endpoints)
+    }
+}
+
+impl Runtime {
+    /// Reports cache hits and misses to RGBd for the node health, if they
+    /// have changed since the last report.
+    fn report_cache_stats(&mut self, endpoints: &mut Endpoints) -> Result<(), DaemonError> {
+        let (state_hits, state_misses) = self.state_cache.stats();
+        let (chain_hits, chain_misses) = self.chain_cache.stats();
+        let stats = CacheStats {
+            state_hits,
+            state_misses,
+            chain_hits,
+            chain_misses,
+        };
+        if stats != self.reported_caches {
+            self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::CacheStats(stats))?;
+            self.reported_caches = stats;
+        }
+        Ok(())
+    }
+
+    /// Notifies other bucket daemons about updated contract states and indexed
+    /// outpoints. Must be called before reporting task completion, such that
+    /// the next tasks are not served from outdated caches.
+    fn report_state_updates(&mut self, endpoints: &mut Endpoints) -> Result<(), DaemonError> {
+        let updates = StateUpdates {
+            contract_ids: self.state_cache.take_updated(),
+            outpoints: self.outpoint_index.take_added(),
+        };
+        if let Err(err) = self.update_storage_usage(&updates.contract_ids) {
+            warn!("Unable to update storage usage of the modified contracts: {}", err);
+        }
+        if !updates.is_empty() {
+            self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::InvalidateStates(updates))?;
+        }
+        Ok(())
+    }
+
+    fn handle_container(
+        &mut self,
+        endpoints: &mut Endpoints,
+        container_id: ContainerId,
+    ) -> Result<(), DaemonError> {
+        let res = self.process_container(container_id);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                error!("Invalid consignment in the container {}: {}", container_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(None) => {
+                info!("Consignment from container {} is quarantined", container_id);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+            Ok(Some(status)) => {
+                info!(
+                    "Consignment from container {} is processed with status {}",
+                    container_id,
+                    status.validity()
+                );
+                debug!("Validation report: {}", status);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_consignment<C: ConsignmentType>(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment: InmemConsignment<C>,
+        force: bool,
+        full: bool,
+        reveal: Option<Reveal>,
+        proofs: Option<ProofBundle>,
+    ) -> Result<(), DaemonError> {
+        let id = consignment.consensus_commit();
+        let res = self.process_consignment(consignment, force, full, reveal, proofs);
+        self.report_validity(endpoints, client_id, id, force, res)
+    }
+
+    fn report_validity(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        id: ConsignmentId,
+        force: bool,
+        res: Result<validation::Status, DaemonError>,
+    ) -> Result<(), DaemonError> {
+        let cost = self.validation_cost.take();
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(status) => {
+                // We ignore client reporting if it fails
+                let report = ValidationReport::from(&status).with_cost(cost);
+                let msg = match status.validity() {
+                    Validity::UnresolvedTransactions => RpcMsg::UnresolvedTxids(report),
+                    Validity::Invalid => RpcMsg::Invalid(report),
+                    // Consumed notwithstanding non-mined endpoint transactions
+                    Validity::ValidExceptEndpoints if force => RpcMsg::Valid(report),
+                    Validity::ValidExceptEndpoints => RpcMsg::UnresolvedTxids(report),
+                    Validity::Valid => RpcMsg::Valid(report),
+                };
+                let _ = self.send_rpc(endpoints, client_id, msg);
+                self.send_ctl(endpoints, ServiceId::rgbd(), ValidityResp {
+                    client_id,
+                    consignment_id: id,
+                    status,
+                })?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_quarantined(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment_id: ConsignmentId,
+        approved: bool,
+    ) -> Result<(), DaemonError> {
+        let res = self
+            .release_quarantined(consignment_id, approved)
+            .and_then(|consignment| self.process_received(consignment));
+        self.report_validity(endpoints, client_id, consignment_id, true, res)
+    }
+
+    fn handle_collect_garbage(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        match self.collect_garbage(None) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(report) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::GarbageCollected(report));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_prune(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: Option<ClientId>,
+        dry_run: bool,
+    ) -> Result<(), DaemonError> {
+        match self.prune_expired(dry_run) {
+            Err(err) => {
+                match client_id {
+                    Some(client_id) => {
+                        let _ = self.send_rpc(endpoints, client_id, err);
+                    }
+                    None => error!("Unable to prune expired data: {}", err),
+                }
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(report) => {
+                if let Some(client_id) = client_id {
+                    let _ = self.send_rpc(endpoints, client_id, RpcMsg::Pruned(report));
+                }
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_recover_store(&mut self, endpoints: &mut Endpoints) -> Result<(), DaemonError> {
+        let res = self.recover_store();
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                error!("Unable to recover the store: {}", err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(recovery) => {
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::StoreRecovered(recovery))?;
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_check_validations(&mut self, endpoints: &mut Endpoints) -> Result<(), DaemonError> {
+        match self.outdated_validations() {
+            Err(err) => {
+                error!("Unable to check contract validations: {}", err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(outdated) => {
+                let count = outdated.len() as u32;
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ValidationsChecked(count))?;
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_revalidate_contracts(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: Option<ContractId>,
+    ) -> Result<(), DaemonError> {
+        match self.revalidate_contracts(contract_id) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(report) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractsRevalidated(report));
+                let count = self.outdated_validations()?.len() as u32;
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ValidationsChecked(count))?;
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_forget_contract(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        tombstone: bool,
+    ) -> Result<(), DaemonError> {
+        let res = self.forget_contract(contract_id, tombstone);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(report) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractForgotten(report));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_rewind_contract(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        consignment_id: ConsignmentId,
+    ) -> Result<(), DaemonError> {
+        let res = self.rewind_contract(contract_id, consignment_id);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(change) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StateChanged(change));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_replay_consignments(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        consignments: Vec<ConsignmentId>,
+    ) -> Result<(), DaemonError> {
+        let res = self.replay_consignments(contract_id, consignments);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(change) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StateChanged(change));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_rebuild_state(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: Option<ContractId>,
+    ) -> Result<(), DaemonError> {
+        let res = self.rebuild_state(contract_id);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(report) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StateRebuilt(report));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_disclosure(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        txid: Txid,
+    ) -> Result<(), DaemonError> {
+        let res = self.process_disclosure(txid);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(_) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_issue(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+    ) -> Result<(), DaemonError> {
+        let res = self.issue_contract(spec, schema);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(contract) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Contract(contract));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_import(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        genesis: String,
+    ) -> Result<(), DaemonError> {
+        let res = self.import_contract(&genesis);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(state) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractState(state));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_preview_issue(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        spec: IssueSpec,
+        schema: Option<Schema>,
+    ) -> Result<(), DaemonError> {
+        match self.preview_issue(spec, schema) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(preview) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Preview(preview));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_reveal_seal(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        reveal: Reveal,
+    ) -> Result<(), DaemonError> {
+        let res = self.reveal_seal(contract_id, reveal);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(node_ids) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::SealRevealed(node_ids));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_create_invoice(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        amount: AtomicValue,
+        outpoint: OutPoint,
+        close_method: CloseMethod,
+        transport: Option<NodeAddr>,
+        signet: Option<NetworkMagic>,
+        tolerance: InvoiceTolerance,
+    ) -> Result<(), DaemonError> {
+        let res = self.create_invoice(
+            contract_id,
+            amount,
+            outpoint,
+            close_method,
+            transport,
+            signet,
+            tolerance,
+        );
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(invoice) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Invoice(invoice));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_export_reveals(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<(), DaemonError> {
+        match self.export_reveals(contract_id, outpoints) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(data) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::RevealData(data));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_consign_contract(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        include: BTreeSet<TransitionType>,
+        outpoints: OutpointFilter,
+        known: BTreeSet<NodeId>,
+        armored: bool,
+    ) -> Result<(), DaemonError> {
+        match self.compose_consignment(contract_id, include, outpoints, known, ContractConsignment)
+        {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(consignment) => {
+                let reply = match armored {
+                    true => RpcMsg::Armored(consignment.to_armored_string()),
+                    false => RpcMsg::Contract(consignment),
+                };
+                let _ = self.send_rpc(endpoints, client_id, reply);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_consign_transfer(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        include: BTreeSet<TransitionType>,
+        outpoints: OutpointFilter,
+        known: BTreeSet<NodeId>,
+        armored: bool,
+    ) -> Result<(), DaemonError> {
+        match self.compose_consignment(contract_id, include, outpoints, known, TransferConsignment)
+        {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(consignment) => {
+                let reply = match armored {
+                    true => RpcMsg::Armored(consignment.to_armored_string()),
+                    false => RpcMsg::StateTransfer(consignment),
+                };
+                let _ = self.send_rpc(endpoints, client_id, reply);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_outpoint_state(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        outpoints: BTreeSet<OutPoint>,
+        scripts: BTreeSet<Script>,
+        spent_status: bool,
+        decimal_amounts: bool,
+        contracts: Option<BTreeSet<ContractId>>,
+    ) -> Result<(), DaemonError> {
+        let res = self.query_outpoint_state(outpoints, scripts).and_then(|mut state| {
+            if let Some(contracts) = contracts {
+                state.retain(|contract_id, _| contracts.contains(contract_id));
+            }
+            match spent_status || decimal_amounts {
+                true => self
+                    .outpoint_status(state, spent_status, decimal_amounts)
+                    .map(RpcMsg::OutpointStatus),
+                false => Ok(RpcMsg::OutpointState(state)),
+            }
+        });
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(msg) => {
+                let _ = self.send_rpc(endpoints, client_id, msg);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_finalize_transfer(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+        beneficiary: Option<NodeAddr>,
+        recipient_key: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        match self.finalize_transfer(consignment, endseals, psbt) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(transfer) => {
+                if let Some(beneficiary) = beneficiary {
+                    let consignment = &transfer.consignment;
+                    self.send_consignment(endpoints, consignment, beneficiary, recipient_key)?;
+                }
+                let _ =
+                    self.send_rpc(endpoints, client_id, RpcMsg::StateTransferFinalize(transfer));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_send_transfer(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment: StateTransfer,
+        beneficiary: NodeAddr,
+        recipient_key: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        match self.send_consignment(endpoints, &consignment, beneficiary, recipient_key) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(()) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Containerizes the consignment and announces the container to the
+    /// beneficiary node via storm.
+    fn send_consignment(
+        &mut self,
+        endpoints: &mut Endpoints,
+        consignment: &StateTransfer,
+        beneficiary: NodeAddr,
+        recipient_key: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        // 1. Containerize consignment
+        // TODO: Make consignment containerization part of the RGB stdlib; use logical,
+        //       not a size-chunking
+        // Unlike RPC clients, beneficiaries do not announce their capabilities
+        // to us, so the relay compression is set by the node configuration
+        let mut encoder = self.relay_compression.encoder(vec![]);
+        consignment.strict_encode(&mut encoder)?;
+        let mut data = encoder.finish().expect("memory writers do not error");
+        let mut mime = s!(match self.relay_compression {
+            Compression::None => CONSIGNMENT_MIME,
+            Compression::Zstd => CONSIGNMENT_ZSTD_MIME,
+        });
+        if let Some(recipient_key) = recipient_key {
+            // Storm nodes delivering the container learn just its size
+            let one_time_key = SecretKey::new(&mut thread_rng());
+            data = seal_payload(SECP256K1, recipient_key, one_time_key, &data);
+            mime.push_str(SEALED_MIME_SUFFIX);
+        }
+        let mut chunk_ids = MediumVec::new();
+        let size = data.len() as u64;
+        for piece in data.chunks(u24::MAX.into_usize()) {
+            let chunk = Chunk::try_from(piece)?;
+            let chunk_id = chunk.chunk_id();
+            self.store.store(storm_rpc::DB_TABLE_CHUNKS, chunk_id.into_slice32(), &chunk)?;
+            chunk_ids.push(chunk_id)?;
+        }
+
+        let header = ContainerHeader {
+            version: 0,
+            mime: AsciiString::from_str(&mime).expect("hardcoded MIME type"),
+            info: empty!(),
+            size,
+        };
+        let header_chunk = Chunk::try_from(header.strict_serialize()?)?;
+        let container = Container {
+            header: header.clone(),
+            chunks: chunk_ids,
+        };
+        let container_chunk = Chunk::try_from(container.strict_serialize()?)?;
+
+        // 2. Upload container to stored database
+        let container_id = container.container_id();
+        self.store.store(
+            storm_rpc::DB_TABLE_CONTAINER_HEADERS,
+            container_id.into_slice32(),
+            &header_chunk,
+        )?;
+        self.store.store(
+            storm_rpc::DB_TABLE_CONTAINERS,
+            container_id.into_slice32(),
+            &container_chunk,
+        )?;
+        // Beneficiary may retrieve the container until the retention period expires
+        self.track_container(container_id, &container, Some(consignment.id()))?;
+
+        // 3. Instruct storm to send the consignment to the remote peer
+        // TODO: Ensure we are connected to the beneficiary
+        let container_full_id = ContainerFullId {
+            // TODO: Change to use message-wrapped container announcements
+            message_id: MesgId::default(),
+            container_id,
+        };
+        let addressed_msg = AddressedMsg {
+            remote_id: beneficiary.id,
+            data: ContainerInfo {
+                id: container_full_id,
+                header,
+            },
+        };
+
+        self.send_storm(endpoints, StormMsg::ContainerAnnouncement(addressed_msg))?;
+        self.transfer_sent(consignment.id(), beneficiary)?;
+        Ok(())
+    }
+
+    fn handle_finalize_transfers(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
+        psbt: Psbt,
+    ) -> Result<(), DaemonError> {
+        match self.finalize_transfers(transfers, psbt) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(transfers) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::FinalizedTransfers(transfers));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_preview_transfer(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment: StateTransfer,
+        endseals: Vec<SealEndpoint>,
+        psbt: Psbt,
+    ) -> Result<(), DaemonError> {
+        match self.preview_transfer(consignment, endseals, psbt) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(preview) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Preview(preview));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_sync_wallet(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+        gap_limit: u16,
+        rescan: bool,
+    ) -> Result<(), DaemonError> {
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::Progress(s!("Scanning the chain")));
+        match self.sync_wallet(&descriptor, gap_limit, rescan) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(balance) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Balance(balance));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_wallet_balance(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+    ) -> Result<(), DaemonError> {
+        match self.wallet_balance(&descriptor) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(balance) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Balance(balance));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_create_wallet(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+    ) -> Result<(), DaemonError> {
+        match self.create_wallet(&descriptor) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(()) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_list_wallets(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        visible: Option<BTreeSet<String>>,
+    ) -> Result<(), DaemonError> {
+        let res = self.list_wallets().map(|mut wallets| {
+            if let Some(visible) = visible {
+                wallets.retain(|info| visible.contains(&info.descriptor));
+            }
+            wallets
+        });
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(wallets) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Wallets(wallets));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_list_schemata(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        match self.list_schemata() {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(schemata) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Schemata(schemata));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_contract_deps(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: Option<ContractId>,
+    ) -> Result<(), DaemonError> {
+        match self.contract_deps(contract_id) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(deps) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractDeps(deps));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_wallet_addresses(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+        unused: u16,
+    ) -> Result<(), DaemonError> {
+        match self.wallet_addresses(&descriptor, unused) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(addresses) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Addresses(addresses));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_replay_balance(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        height: u32,
+        descriptor: Option<String>,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<(), DaemonError> {
+        match self.historical_balance(contract_id, height, descriptor, outpoints) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(balance) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::HistoricalBalance(balance));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_contract_stats(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        match self.contract_stats(contract_id) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(stats) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractStats(stats));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_contract_allocations(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        spent_status: bool,
+    ) -> Result<(), DaemonError> {
+        let res =
+            self.contract_allocations(contract_id).and_then(|allocations| match spent_status {
+                true => self.allocation_status(allocations).map(RpcMsg::AllocationStatus),
+                false => Ok(RpcMsg::Allocations(allocations)),
+            });
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(msg) => {
+                let _ = self.send_rpc(endpoints, client_id, msg);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_storage_usage(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        limit: u16,
+    ) -> Result<(), DaemonError> {
+        match self.storage_usage(limit) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(usage) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StorageUsage(usage));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_state_digest(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        match self.state_digest(contract_id) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(digest) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StateDigest(digest));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_diff_history(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        bundles: BTreeSet<BundleId>,
+    ) -> Result<(), DaemonError> {
+        match self.diff_history(contract_id, bundles) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(diff) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::HistoryDiff(diff));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_commit_stash(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        match self.commit_stash() {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(commitment) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::StashCommitment(commitment));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_prove_transactions(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        txids: BTreeSet<Txid>,
+        from_height: u32,
+    ) -> Result<(), DaemonError> {
+        let _ = self.send_rpc(
+            endpoints,
+            client_id,
+            RpcMsg::Progress(format!("Proving {} transaction(s)", txids.len())),
+        );
+        match self.prove_transactions(txids, from_height) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(proofs) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Proofs(proofs));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_create_checkpoint(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        height: u32,
+    ) -> Result<(), DaemonError> {
+        match self.create_checkpoint(contract_id, height) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(checkpoint) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Checkpoint(checkpoint));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_consolidate(
+        &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
+        contract_id: ContractId,
+        outpoints: BTreeSet<OutPoint>,
+        seal: seal::Revealed,
+    ) -> Result<(), DaemonError> {
+        // The node generates the seal blinding from its entropy source instead
+        // of relying on the client-provided one.
+        let result = self.entropy.rng().map_err(DaemonError::from).and_then(|mut rng| {
+            let seal = seal::Revealed {
+                blinding: rng.gen(),
+                ..seal
+            };
+            self.consolidate_allocations(contract_id, outpoints, seal)
+        });
+        match result {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(transition) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Transition(transition));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_propose_swap(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        terms: SwapTerms,
+        timeout: u32,
+        psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        match self.propose_swap(terms, timeout, psbt, consignment, owner) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(offer) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::SwapOffer(offer));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_accept_swap(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        offer: SwapOffer,
+        psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        match self.accept_swap(offer, psbt, consignment, owner) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(acceptance) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Swap(acceptance));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_complete_swap(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        acceptance: SwapAcceptance,
+    ) -> Result<(), DaemonError> {
+        match self.complete_swap(acceptance) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(completion) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Swap(completion));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_propose_payjoin(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        psbt: Psbt,
         consignment: StateTransfer,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<(), DaemonError> {
+        match self.propose_payjoin(psbt, consignment, owner) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(proposal) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::PayjoinProposal(proposal));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_contribute_payjoin(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        proposal: PayjoinProposal,
+        descriptor: String,
+        outpoints: BTreeSet<OutPoint>,
         endseals: Vec<SealEndpoint>,
-        psbt: Psbt,
-        beneficiary: Option<NodeAddr>,
+        fee: u64,
+        owner: Option<XOnlyPublicKey>,
     ) -> Result<(), DaemonError> {
-        match self.finalize_transfer(consignment, endseals, psbt) {
+        let res = self.contribute_payjoin(proposal, &descriptor, outpoints, endseals, fee, owner);
+        match res {
             Err(err) => {
                 let _ = self.send_rpc(endpoints, client_id, err);
                 self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
             }
-            Ok(transfer) => {
-                if let Some(beneficiary) = beneficiary {
-                    // 1. Containerize consignment
-                    // TODO: Make consignment containerization part of the RGB stdlib; use logical,
-                    //       not a size-chunking
-                    let data = transfer.consignment.strict_serialize()?;
-                    let mut chunk_ids = MediumVec::new();
-                    let size = data.len() as u64;
-                    for piece in data.chunks(u24::MAX.into_usize()) {
-                        let chunk = Chunk::try_from(piece)?;
-                        let chunk_id = chunk.chunk_id();
-                        self.store.store(storm_rpc::DB_TABLE_CHUNKS, chunk_id, &chunk)?;
-                        chunk_ids.push(chunk_id)?;
-                    }
+            Ok(contribution) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Payjoin(contribution));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
 
-                    let header = ContainerHeader {
-                        version: 0,
-                        mime: AsciiString::from_str("application/vnd.lnpbp.rgb.consignment")
-                            .expect("hardcoded MIME type"),
-                        info: empty!(),
-                        size,
-                    };
-                    let header_chunk = Chunk::try_from(header.strict_serialize()?)?;
-                    let container = Container {
-                        header: header.clone(),
-                        chunks: chunk_ids,
-                    };
-                    let container_chunk = Chunk::try_from(container.strict_serialize()?)?;
-
-                    // 2. Upload container to stored database
-                    let container_id = container.container_id();
-                    self.store.store(
-                        storm_rpc::DB_TABLE_CONTAINER_HEADERS,
-                        container_id,
-                        &header_chunk,
-                    )?;
-                    self.store.store(
-                        storm_rpc::DB_TABLE_CONTAINERS,
-                        container_id,
-                        &container_chunk,
-                    )?;
-
-                    // 3. Instruct storm to send the consignment to the remote peer
-                    // TODO: Ensure we are connected to the beneficiary
-                    let container_full_id = ContainerFullId {
-                        // TODO: Change to use message-wrapped container announcements
-                        message_id: MesgId::default(),
-                        container_id,
-                    };
-                    let addressed_msg = AddressedMsg {
-                        remote_id: beneficiary.id,
-                        data: ContainerInfo {
-                            id: container_full_id,
-                            header,
-                        },
-                    };
-
-                    self.send_storm(endpoints, StormMsg::ContainerAnnouncement(addressed_msg))?;
-                }
-                let _ =
-                    self.send_rpc(endpoints, client_id, RpcMsg::StateTransferFinalize(transfer));
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?;
+    fn handle_complete_payjoin(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contribution: PayjoinContribution,
+    ) -> Result<(), DaemonError> {
+        match self.complete_payjoin(contribution) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(contribution) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Payjoin(contribution));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
             }
         }
         Ok(())
     }
 
-    fn handle_finalize_transfers(
+    fn handle_compose_payment(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+        contract_id: ContractId,
+        beneficiaries: BTreeMap<SealEndpoint, AtomicValue>,
+        fee: u64,
+    ) -> Result<(), DaemonError> {
+        match self.compose_payment(&descriptor, contract_id, beneficiaries, fee) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(draft) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::PaymentDraft(draft));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_publish_tx(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
-        transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
         psbt: Psbt,
     ) -> Result<(), DaemonError> {
-        match self.finalize_transfers(transfers, psbt) {
+        match self.publish_tx(psbt) {
             Err(err) => {
                 let _ = self.send_rpc(endpoints, client_id, err);
                 self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
             }
-            Ok(transfers) => {
-                let _ = self.send_rpc(endpoints, client_id, RpcMsg::FinalizedTransfers(transfers));
-                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?;
+            Ok(txid) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::TxPublished(txid));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_accelerate_transfer(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment_id: ConsignmentId,
+        descriptor: String,
+        fee_rate: u64,
+    ) -> Result<(), DaemonError> {
+        match self.accelerate_transfer(consignment_id, &descriptor, fee_rate) {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(draft) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::CpfpDraft(draft));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_forget_spent(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+        outpoints: BTreeSet<OutPoint>,
+    ) -> Result<(), DaemonError> {
+        let res = self.forget_spent(&descriptor, outpoints);
+        self.report_state_updates(endpoints)?;
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, err);
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(state) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::OutpointState(state));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_sign_psbt(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        psbt: Psbt,
+    ) -> Result<(), DaemonError> {
+        // We connect each time since ZMQ REQ socket can't be re-used after the timeout
+        let res = match self.signer_endpoint {
+            None => Err(SignerError::NotConfigured),
+            Some(ref endpoint) => {
+                SignerClient::with(endpoint).and_then(|mut signer| signer.sign(psbt))
+            }
+        };
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(psbt) => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::SignedPsbt(psbt));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_sign_checkpoint(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        checkpoint: Checkpoint,
+    ) -> Result<(), DaemonError> {
+        let res = match self.issuer_signer {
+            None => Err(SignerError::IssuerNotConfigured),
+            Some(ref signer) => signer.sign(checkpoint),
+        };
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(signed) => {
+                info!("Checkpoint is signed by issuer {}", signed.issuer);
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::SignedCheckpoint(signed));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_timestamp(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        digest: sha256::Hash,
+    ) -> Result<(), DaemonError> {
+        let res = match self.timestamp_calendar {
+            None => Err(TimestampError::NotConfigured),
+            Some(ref calendar) => timestamp::submit(calendar, digest),
+        };
+        match res {
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingFailed)?
+            }
+            Ok(proof) => {
+                info!("Digest {} is submitted for timestamping to {}", digest, proof.calendar);
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::TimestampProof(proof));
+                self.send_ctl(endpoints, ServiceId::rgbd(), CtlMsg::ProcessingComplete)?
             }
         }
         Ok(())
@@ -0,0 +1,243 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Contract time travel for the nodes working with regtest: journal of the
+//! accepted consignments, rewinding the contract state to the moment before
+//! some consignment and re-applying the recorded consignments.
+
+use std::collections::BTreeSet;
+
+use bitcoin::OutPoint;
+use lnpbp::chain::Chain;
+use rgb::{
+    ConsignmentId, ConsignmentType, Contract, ContractId, ContractState, InmemConsignment,
+    StateTransfer, Validity,
+};
+use rgb_rpc::{now, FailureCode, JournalEntry, StateChange, StateDigest};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum SimulationError {
+    /// contract time travel is available on regtest only, while the node works
+    /// with {0}.
+    Unavailable(Chain),
+
+    /// contract {0} has no consignments recorded into the journal.
+    NoJournal(ContractId),
+
+    /// consignment {1} is not recorded into the journal of contract {0}.
+    UnknownConsignment(ContractId, ConsignmentId),
+
+    /// consignment {0} recorded into the journal is absent in the store.
+    ConsignmentAbsent(ConsignmentId),
+
+    /// consignment {0} recorded into the journal has failed validation with
+    /// status {1}.
+    Invalid(ConsignmentId, String),
+}
+
+impl From<&SimulationError> for FailureCode {
+    fn from(_: &SimulationError) -> Self { FailureCode::Simulation }
+}
+
+/// Contract state together with its digest
+type Snapshot = (StateDigest, ContractState);
+
+impl Runtime {
+    /// Records consignment accepted for the contract into the contract
+    /// journal, unless it is already recorded.
+    pub(super) fn record_journal<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        let consignment_id = consignment.id();
+        let mut journal: Vec<JournalEntry> =
+            self.store.retrieve_sten(db::JOURNAL, contract_id)?.unwrap_or_default();
+        if journal.iter().any(|entry| entry.consignment_id == consignment_id) {
+            return Ok(());
+        }
+        self.store.store_sten(db::JOURNAL_CONSIGNMENTS, consignment_id, consignment)?;
+        journal.push(JournalEntry {
+            consignment_id,
+            // Contract consignments have no endpoints and are not transfers
+            contract: consignment.endpoints().next().is_none(),
+            timestamp: now(),
+        });
+        self.store.store_sten(db::JOURNAL, contract_id, &journal)?;
+        debug!("Consignment {} is recorded into the journal of {}", consignment_id, contract_id);
+        Ok(())
+    }
+
+    /// Rewinds contract state to the moment before the consignment was
+    /// accepted. The contract is forgotten and the consignments recorded into
+    /// the journal before the given one are re-applied, so the transfer
+    /// records, policies and annotations of the contract are lost, while the
+    /// journal is kept.
+    pub(super) fn rewind_contract(
+        &mut self,
+        contract_id: ContractId,
+        consignment_id: ConsignmentId,
+    ) -> Result<StateChange, DaemonError> {
+        let journal = self.journal(contract_id)?;
+        let pos = journal
+            .iter()
+            .position(|entry| entry.consignment_id == consignment_id)
+            .ok_or(SimulationError::UnknownConsignment(contract_id, consignment_id))?;
+
+        let before = self.snapshot(contract_id)?;
+        if before.is_some() {
+            self.forget_contract(contract_id, false)?;
+        }
+        self.reapply(&journal[..pos])?;
+        let after = self.snapshot(contract_id)?;
+
+        info!(
+            "Contract {} is rewound to the state before consignment {}",
+            contract_id, consignment_id
+        );
+        Ok(state_change(contract_id, before, after))
+    }
+
+    /// Re-applies consignments recorded into the contract journal in the given
+    /// order.
+    pub(super) fn replay_consignments(
+        &mut self,
+        contract_id: ContractId,
+        consignments: Vec<ConsignmentId>,
+    ) -> Result<StateChange, DaemonError> {
+        let journal = self.journal(contract_id)?;
+        let entries = consignments
+            .into_iter()
+            .map(|consignment_id| {
+                journal
+                    .iter()
+                    .find(|entry| entry.consignment_id == consignment_id)
+                    .copied()
+                    .ok_or(SimulationError::UnknownConsignment(contract_id, consignment_id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let before = self.snapshot(contract_id)?;
+        self.reapply(&entries)?;
+        let after = self.snapshot(contract_id)?;
+
+        info!("{} consignment(s) of contract {} are replayed", entries.len(), contract_id);
+        Ok(state_change(contract_id, before, after))
+    }
+
+    fn journal(&mut self, contract_id: ContractId) -> Result<Vec<JournalEntry>, DaemonError> {
+        self.store
+            .retrieve_sten(db::JOURNAL, contract_id)?
+            .filter(|journal: &Vec<JournalEntry>| !journal.is_empty())
+            .ok_or_else(|| SimulationError::NoJournal(contract_id).into())
+    }
+
+    fn snapshot(&mut self, contract_id: ContractId) -> Result<Option<Snapshot>, DaemonError> {
+        match self.state_cache.get(&mut self.store, contract_id)? {
+            Some(state) => Ok(Some((self.state_digest(contract_id)?, state))),
+            None => Ok(None),
+        }
+    }
+
+    /// Processes recorded consignments one after another with full
+    /// validation, failing on the first consignment which is not valid.
+    /// Consignments with non-mined transactions are accepted, like they were
+    /// forced at the moment of their recording.
+    fn reapply(&mut self, entries: &[JournalEntry]) -> Result<(), DaemonError> {
+        for entry in entries {
+            self.interrupt.check()?;
+            let id = entry.consignment_id;
+            debug!("Re-applying consignment {} from the journal", id);
+            let status = if entry.contract {
+                let consignment: Contract = self
+                    .store
+                    .retrieve_sten(db::JOURNAL_CONSIGNMENTS, id)?
+                    .ok_or(SimulationError::ConsignmentAbsent(id))?;
+                self.process_consignment(consignment, true, true, None, None)?
+            } else {
+                let consignment: StateTransfer = self
+                    .store
+                    .retrieve_sten(db::JOURNAL_CONSIGNMENTS, id)?
+                    .ok_or(SimulationError::ConsignmentAbsent(id))?;
+                self.process_consignment(consignment, true, true, None, None)?
+            };
+            match status.validity() {
+                Validity::Valid | Validity::ValidExceptEndpoints => {}
+                validity => return Err(SimulationError::Invalid(id, validity.to_string()).into()),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn state_change(
+    contract_id: ContractId,
+    before: Option<Snapshot>,
+    after: Option<Snapshot>,
+) -> StateChange {
+    let before_state = before.as_ref().map(|(_, state)| state);
+    let after_state = after.as_ref().map(|(_, state)| state);
+    StateChange {
+        contract_id,
+        before: before.as_ref().map(|(digest, _)| *digest),
+        after: after.as_ref().map(|(digest, _)| *digest),
+        assigned: assignment_diff(after_state, before_state),
+        unassigned: assignment_diff(before_state, after_state),
+    }
+}
+
+/// Collects outpoints of the assignments present in the state and absent in
+/// the other state
+fn assignment_diff(
+    state: Option<&ContractState>,
+    other: Option<&ContractState>,
+) -> BTreeSet<OutPoint> {
+    let state = match state {
+        Some(state) => state,
+        None => return empty!(),
+    };
+    let mut outpoints = bset! {};
+    outpoints.extend(
+        state
+            .owned_rights
+            .iter()
+            .filter(|assigned| other.map_or(true, |other| !other.owned_rights.contains(assigned)))
+            .map(|assigned| assigned.outpoint),
+    );
+    outpoints.extend(
+        state
+            .owned_values
+            .iter()
+            .filter(|assigned| other.map_or(true, |other| !other.owned_values.contains(assigned)))
+            .map(|assigned| assigned.outpoint),
+    );
+    outpoints.extend(
+        state
+            .owned_data
+            .iter()
+            .filter(|assigned| other.map_or(true, |other| !other.owned_data.contains(assigned)))
+            .map(|assigned| assigned.outpoint),
+    );
+    outpoints.extend(
+        state
+            .owned_attachments
+            .iter()
+            .filter(|assigned| {
+                other.map_or(true, |other| !other.owned_attachments.contains(assigned))
+            })
+            .map(|assigned| assigned.outpoint),
+    );
+    outpoints
+}
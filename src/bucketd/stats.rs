@@ -0,0 +1,71 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeSet;
+
+use bitcoin::Txid;
+use rgb::ContractId;
+use rgb_rpc::ContractStats;
+use store_rpc::PrimaryKey;
+
+use super::{ContractAncestry, Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Computes aggregate statistics of the contract from its known state,
+    /// history index and the data kept in the stash.
+    pub(super) fn contract_stats(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<ContractStats, DaemonError> {
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        let outpoint_state = state.all_outpoint_state();
+        let allocations = outpoint_state.values().map(BTreeSet::len).sum::<usize>();
+
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        let ancestry = ContractAncestry::load(self.store.as_mut(), node_ids.iter().copied())?;
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids.clone())?;
+
+        // Anchors may be shared with other contracts; they are accounted for
+        // each of them
+        let mut stored_bytes = self.chunk_size(db::GENESIS, contract_id)?;
+        for node_id in &node_ids {
+            stored_bytes += self.chunk_size(db::TRANSITIONS, *node_id)?;
+        }
+        for txid in witnesses.into_iter().flatten().collect::<BTreeSet<_>>() {
+            stored_bytes += self.chunk_size(db::ANCHORS, txid)?;
+        }
+
+        Ok(ContractStats {
+            contract_id,
+            allocations: allocations as u32,
+            outpoints: outpoint_state.len() as u32,
+            transitions: node_ids.len() as u32,
+            depth: ancestry.depth(contract_id),
+            stored_bytes,
+        })
+    }
+
+    /// Returns size of the data kept in the table under the key, or zero if
+    /// there are no such data.
+    pub(super) fn chunk_size(
+        &mut self,
+        table: &str,
+        key: impl PrimaryKey,
+    ) -> Result<u64, DaemonError> {
+        let chunk = self.store.retrieve_chunk(table, key.into_slice32())?;
+        Ok(chunk.map(|chunk| chunk.as_slice().len() as u64).unwrap_or_default())
+    }
+}
@@ -0,0 +1,440 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::rand::random;
+use bitcoin::{OutPoint, Txid, XOnlyPublicKey};
+use commit_verify::{lnpbp4, CommitConceal};
+use psbt::Psbt;
+use rgb::psbt::RgbExt;
+use rgb::{
+    Anchor, AtomicValue, BundleId, ContractId, Disclosure, Node, SealEndpoint, StateTransfer,
+    TransitionBundle, TypedAssignments, Validator, Validity,
+};
+use rgb_rpc::{now, FailureCode, SwapAcceptance, SwapId, SwapLeg, SwapOffer, SwapTerms};
+use strict_encoding::StrictEncode;
+
+use super::{FinalizeError, Runtime};
+use crate::amplify::Wrapper;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum SwapError {
+    /// swap {0} is not known to the node.
+    Unknown(SwapId),
+
+    /// swap {0} was aborted.
+    Aborted(SwapId),
+
+    /// swap {0} has expired and was aborted.
+    Expired(SwapId),
+
+    /// swap {0} was already accepted or completed.
+    AlreadyProcessed(SwapId),
+
+    /// consignment for the contract {0} is required for the swap but was not
+    /// provided.
+    ConsignmentMissed(ContractId),
+
+    /// consignment is provided for the contract {found}, while the swap
+    /// requires contract {expected}.
+    ContractMismatch {
+        expected: ContractId,
+        found: ContractId,
+    },
+
+    /// swap PSBT moves only {found} of {expected} required by the swap
+    /// terms.
+    InsufficientAmount { expected: u64, found: u64 },
+
+    /// swap PSBT does not spend the outputs of the swap offer.
+    InputsMismatch,
+
+    /// swap PSBT or consignment does not match the transaction accepted by
+    /// the counterparty.
+    TxidMismatch,
+
+    /// swap PSBT changes the state transition bundle of the contract {0}
+    /// proposed by the maker.
+    BundleMismatch(ContractId),
+
+    /// consignment for the contract {0} provided by the counterparty is
+    /// invalid: {1}.
+    InvalidConsignment(ContractId, String),
+}
+
+impl From<&SwapError> for FailureCode {
+    fn from(err: &SwapError) -> Self {
+        match err {
+            SwapError::Unknown(_)
+            | SwapError::Aborted(_)
+            | SwapError::Expired(_)
+            | SwapError::AlreadyProcessed(_) => FailureCode::Swap,
+            SwapError::ConsignmentMissed(_)
+            | SwapError::ContractMismatch { .. }
+            | SwapError::InsufficientAmount { .. }
+            | SwapError::InputsMismatch
+            | SwapError::TxidMismatch
+            | SwapError::BundleMismatch(_)
+            | SwapError::InvalidConsignment(..) => FailureCode::SwapMismatch,
+        }
+    }
+}
+
+/// Party of the swap run by this node
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display(lowercase)]
+pub(crate) enum SwapRole {
+    Maker,
+    Taker,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display(lowercase)]
+pub(crate) enum SwapStatus {
+    Proposed,
+    Accepted,
+    Completed,
+    Aborted,
+}
+
+/// Swap data kept in the store between the swap protocol steps.
+#[derive(Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct SwapRecord {
+    pub role: SwapRole,
+    pub status: SwapStatus,
+    pub terms: SwapTerms,
+    pub expiry: u64,
+    /// Maker inputs, which must be spent by the swap transaction
+    pub inputs: BTreeSet<OutPoint>,
+    /// Bundle of the maker state transitions, which must be kept unchanged
+    /// by the swap transaction
+    pub bundle_id: Option<BundleId>,
+    /// Witness transaction id, known once the state transitions are committed
+    pub txid: Option<Txid>,
+    /// Consignment for the asset received by this party
+    pub consignment: Option<StateTransfer>,
+    /// Identity of the client which has created the swap, if the client was
+    /// authenticated. Only this client may complete or abort the swap.
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+pub(super) fn psbt_inputs(psbt: &Psbt) -> BTreeSet<OutPoint> {
+    psbt.inputs.iter().map(|input| input.previous_outpoint).collect()
+}
+
+/// Computes amount moved by the swap leg in the given transaction and
+/// transition bundles, checking it against the swap terms.
+fn check_leg<'bundle>(
+    leg: &SwapLeg,
+    psbt: &Psbt,
+    bundles: impl IntoIterator<Item = &'bundle TransitionBundle>,
+) -> Result<(), DaemonError> {
+    let (expected, found) = match leg {
+        SwapLeg::Rgb {
+            amount,
+            beneficiaries,
+            ..
+        } => {
+            let seals = beneficiaries.iter().map(SealEndpoint::commit_conceal).collect::<Vec<_>>();
+            let found: AtomicValue = bundles
+                .into_iter()
+                .flat_map(TransitionBundle::known_transitions)
+                .flat_map(|transition| transition.owned_rights().values())
+                .flat_map(TypedAssignments::to_value_assignments)
+                .filter(|assignment| seals.contains(&assignment.to_confidential_seal()))
+                .filter_map(|assignment| assignment.as_revealed_state().map(|state| state.value))
+                .sum();
+            (*amount, found)
+        }
+        SwapLeg::Bitcoin { amount, script } => {
+            let found = psbt
+                .outputs
+                .iter()
+                .filter(|output| output.script.as_inner() == script)
+                .map(|output| output.amount)
+                .sum();
+            (*amount, found)
+        }
+    };
+    if found < expected {
+        return Err(SwapError::InsufficientAmount { expected, found }.into());
+    }
+    Ok(())
+}
+
+/// Checks that the consignment is provided for the RGB swap leg.
+fn leg_consignment(
+    leg: &SwapLeg,
+    consignment: Option<StateTransfer>,
+) -> Result<Option<StateTransfer>, SwapError> {
+    match (leg.contract_id(), consignment) {
+        (None, _) => Ok(None),
+        (Some(expected), None) => Err(SwapError::ConsignmentMissed(expected)),
+        (Some(expected), Some(consignment)) if consignment.contract_id() != expected => {
+            Err(SwapError::ContractMismatch {
+                expected,
+                found: consignment.contract_id(),
+            })
+        }
+        (Some(_), consignment) => Ok(consignment),
+    }
+}
+
+impl Runtime {
+    /// Validates consignment received from the counterparty. The witness
+    /// transaction of the swap is not yet published, so the consignment
+    /// endpoints are allowed to be unmined.
+    fn validate_leg(&mut self, consignment: StateTransfer) -> Result<StateTransfer, DaemonError> {
+        let contract_id = consignment.contract_id();
+        let consignment = self.with_root_schema(consignment)?;
+        self.chain_cache.sync_tip(&self.electrum, &mut self.store)?;
+        let resolver = self.chain_cache.resolver(&self.electrum, &mut self.store);
+        let status = Validator::validate(&consignment, &resolver);
+        match status.validity() {
+            Validity::Valid | Validity::ValidExceptEndpoints => Ok(consignment),
+            _ => {
+                let failures = status.failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+                Err(SwapError::InvalidConsignment(contract_id, failures.join("; ")).into())
+            }
+        }
+    }
+
+    fn swap_record(&mut self, swap_id: SwapId) -> Result<SwapRecord, DaemonError> {
+        let mut record: SwapRecord =
+            self.store.retrieve_sten(db::SWAPS, swap_id)?.ok_or(SwapError::Unknown(swap_id))?;
+        match record.status {
+            SwapStatus::Aborted => Err(SwapError::Aborted(swap_id).into()),
+            SwapStatus::Completed => Err(SwapError::AlreadyProcessed(swap_id).into()),
+            _ if record.expiry <= now() => {
+                record.status = SwapStatus::Aborted;
+                self.store.store_sten(db::SWAPS, swap_id, &record)?;
+                Err(SwapError::Expired(swap_id).into())
+            }
+            _ => Ok(record),
+        }
+    }
+
+    /// Creates swap offer, checking that the maker side of the swap is
+    /// present in the PSBT.
+    pub(super) fn propose_swap(
+        &mut self,
+        terms: SwapTerms,
+        timeout: u32,
+        psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<SwapOffer, DaemonError> {
+        let consignment = leg_consignment(&terms.give, consignment)?;
+        let bundles = psbt.rgb_bundles()?;
+        let bundle = terms.give.contract_id().and_then(|id| bundles.get(&id));
+        check_leg(&terms.give, &psbt, bundle)?;
+
+        let expiry = now() + timeout as u64;
+        let mut engine = sha256::Hash::engine();
+        strict_encode_list!(engine; terms, expiry, psbt.to_txid(), random::<u64>());
+        let swap_id = SwapId::from(sha256::Hash::from_engine(engine));
+        info!("Proposing swap {} to {}", swap_id, terms);
+
+        let record = SwapRecord {
+            role: SwapRole::Maker,
+            status: SwapStatus::Proposed,
+            terms: terms.clone(),
+            expiry,
+            inputs: psbt_inputs(&psbt),
+            bundle_id: bundle.map(TransitionBundle::bundle_id),
+            txid: None,
+            consignment: None,
+            owner,
+        };
+        self.store.store_sten(db::SWAPS, swap_id, &record)?;
+
+        Ok(SwapOffer {
+            swap_id,
+            terms,
+            expiry,
+            psbt,
+            consignment,
+        })
+    }
+
+    /// Accepts swap offer, committing both state transitions into the PSBT
+    /// and finalizing consignments for both parties. Returns the data for
+    /// the maker; the consignment for the taker is kept until the swap is
+    /// completed.
+    pub(super) fn accept_swap(
+        &mut self,
+        offer: SwapOffer,
+        mut psbt: Psbt,
+        consignment: Option<StateTransfer>,
+        owner: Option<XOnlyPublicKey>,
+    ) -> Result<SwapAcceptance, DaemonError> {
+        let swap_id = offer.swap_id;
+        if self.store.retrieve_sten::<SwapRecord>(db::SWAPS, swap_id)?.is_some() {
+            return Err(SwapError::AlreadyProcessed(swap_id).into());
+        }
+        if offer.expiry <= now() {
+            return Err(SwapError::Expired(swap_id).into());
+        }
+        info!("Accepting swap {}: {}", swap_id, offer.terms);
+
+        let terms = offer.terms;
+        if !psbt_inputs(&psbt).is_superset(&psbt_inputs(&offer.psbt)) {
+            return Err(SwapError::InputsMismatch.into());
+        }
+        let give = match leg_consignment(&terms.give, offer.consignment)? {
+            Some(consignment) => Some(self.validate_leg(consignment)?),
+            None => None,
+        };
+        let take = leg_consignment(&terms.take, consignment)?;
+
+        // 1. Check both sides of the swap before committing to them.
+        let mut bundles = psbt.rgb_bundles()?;
+        debug!("Found {} bundles", bundles.len());
+        check_leg(&terms.give, &psbt, terms.give.contract_id().and_then(|id| bundles.get(&id)))?;
+        check_leg(&terms.take, &psbt, terms.take.contract_id().and_then(|id| bundles.get(&id)))?;
+        if let Some(contract_id) = terms.take.contract_id() {
+            let bundle = bundles.get(&contract_id).ok_or(FinalizeError::ContractBundleMissed)?;
+            self.check_policy(contract_id, bundle)?;
+        }
+
+        // 2. Commit to both bundles with a single anchor.
+        let anchor = Anchor::commit(&mut psbt)?;
+        trace!("Anchor: {:?}", anchor);
+
+        // 3. Finalize consignments for both parties.
+        let received = finalize_leg(&terms.give, give, &anchor, &mut bundles)?;
+        let sent = finalize_leg(&terms.take, take, &anchor, &mut bundles)?;
+
+        // 4. Construct and store disclosure for the blank transfers.
+        let txid = anchor.txid;
+        let disclosure = Disclosure::with(anchor, bundles, None);
+        self.store.store_sten(db::DISCLOSURES, txid, &disclosure)?;
+
+        let record = SwapRecord {
+            role: SwapRole::Taker,
+            status: SwapStatus::Accepted,
+            terms,
+            expiry: offer.expiry,
+            inputs: psbt_inputs(&offer.psbt),
+            bundle_id: None,
+            txid: Some(txid),
+            consignment: received,
+            owner,
+        };
+        self.store.store_sten(db::SWAPS, swap_id, &record)?;
+
+        Ok(SwapAcceptance {
+            swap_id,
+            psbt,
+            consignment: sent,
+        })
+    }
+
+    /// Checks data received from the counterparty against the swap terms and
+    /// marks the swap as completed. Returns PSBT which can be signed by this
+    /// party and the consignment for the asset it receives.
+    pub(super) fn complete_swap(
+        &mut self,
+        acceptance: SwapAcceptance,
+    ) -> Result<SwapAcceptance, DaemonError> {
+        let swap_id = acceptance.swap_id;
+        let mut record = self.swap_record(swap_id)?;
+        let txid = acceptance.psbt.to_txid();
+        info!("Completing swap {} as {}", swap_id, record.role);
+
+        match (record.role, record.status) {
+            (SwapRole::Maker, SwapStatus::Proposed) => {
+                if !psbt_inputs(&acceptance.psbt).is_superset(&record.inputs) {
+                    return Err(SwapError::InputsMismatch.into());
+                }
+                // The taker commits to the bundles, so it must not have replaced the maker
+                // state transitions with its own
+                if let Some(contract_id) = record.terms.give.contract_id() {
+                    let bundles = acceptance.psbt.rgb_bundles()?;
+                    if bundles.get(&contract_id).map(TransitionBundle::bundle_id)
+                        != record.bundle_id
+                    {
+                        return Err(SwapError::BundleMismatch(contract_id).into());
+                    }
+                }
+                let consignment = leg_consignment(&record.terms.take, acceptance.consignment)?;
+                let consignment = match consignment {
+                    Some(consignment) => {
+                        if !consignment.txids().contains(&txid) {
+                            return Err(SwapError::TxidMismatch.into());
+                        }
+                        let consignment = self.validate_leg(consignment)?;
+                        check_leg(
+                            &record.terms.take,
+                            &acceptance.psbt,
+                            consignment.endpoint_bundles(),
+                        )?;
+                        Some(consignment)
+                    }
+                    None => {
+                        check_leg(&record.terms.take, &acceptance.psbt, vec![])?;
+                        None
+                    }
+                };
+                record.consignment = consignment;
+            }
+            (SwapRole::Taker, SwapStatus::Accepted) => {
+                if record.txid != Some(txid) {
+                    return Err(SwapError::TxidMismatch.into());
+                }
+            }
+            _ => return Err(SwapError::AlreadyProcessed(swap_id).into()),
+        }
+
+        record.status = SwapStatus::Completed;
+        record.txid = Some(txid);
+        self.store.store_sten(db::SWAPS, swap_id, &record)?;
+
+        Ok(SwapAcceptance {
+            swap_id,
+            psbt: acceptance.psbt,
+            consignment: record.consignment,
+        })
+    }
+}
+
+/// Adds the anchored bundle and seal endpoints to the consignment for the
+/// RGB swap leg.
+fn finalize_leg(
+    leg: &SwapLeg,
+    consignment: Option<StateTransfer>,
+    anchor: &Anchor<lnpbp4::MerkleBlock>,
+    bundles: &mut BTreeMap<ContractId, TransitionBundle>,
+) -> Result<Option<StateTransfer>, DaemonError> {
+    let (contract_id, beneficiaries) = match leg {
+        SwapLeg::Rgb {
+            contract_id,
+            beneficiaries,
+            ..
+        } => (*contract_id, beneficiaries),
+        SwapLeg::Bitcoin { .. } => return Ok(None),
+    };
+    let mut consignment = match consignment {
+        Some(consignment) => consignment,
+        None => return Err(SwapError::ConsignmentMissed(contract_id).into()),
+    };
+    let bundle = bundles.remove(&contract_id).ok_or(FinalizeError::ContractBundleMissed)?;
+    let bundle_id = bundle.bundle_id();
+    consignment.push_anchored_bundle(anchor.to_merkle_proof(contract_id)?, bundle)?;
+    for endseal in beneficiaries {
+        consignment.push_seal_endpoint(bundle_id, *endseal);
+    }
+    Ok(Some(consignment))
+}
@@ -0,0 +1,473 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Generator of synthetic contract histories for benchmarks and tests.
+//!
+//! The generated contract has a history of the given depth, each level of
+//! which is a witness transaction closing the seals of the previous level by
+//! as many transfers as the history is wide. Witness transactions are mined
+//! into a chain of regtest block headers following the regtest genesis block,
+//! so the history is validated by an offline regtest node having the
+//! [`SyntheticHistory::checkpoint`] as its header checkpoint.
+//!
+//! All the blinding factors are taken from the provided random number
+//! generator, so a seeded generator produces the same history each time.
+
+use std::collections::BTreeMap;
+
+use bitcoin::blockdata::constants::genesis_block;
+use bitcoin::hashes::Hash;
+use bitcoin::psbt::PartiallySignedTransaction;
+use bitcoin::{
+    BlockHeader, Network, OutPoint, PackedLockTime, Script, Sequence, Transaction, TxIn,
+    TxMerkleNode, TxOut, Txid, Witness,
+};
+use bp::seals::txout::CloseMethod;
+use commit_verify::lnpbp4;
+use lnpbp::chain::Chain;
+use psbt::Psbt;
+use rgb::psbt::RgbExt;
+use rgb::schema::{OwnedRightType, TransitionType};
+use rgb::secp256k1zkp::rand::{thread_rng, CryptoRng, Rng};
+use rgb::{
+    seal, value, Anchor, Assignment, Consignment, Contract, ContractId, Genesis, Node, NodeId,
+    OwnedRights, ParentOwnedRights, Schema, SchemaId, SealEndpoint, StateTransfer, Transition,
+    TransitionBundle, TypedAssignments,
+};
+use rgb_rpc::{AssignmentSpec, HeaderCheckpoint, IssueSpec, ProofBundle, ProofHeader, TxProof};
+
+use super::issue::{genesis_metadata, genesis_owned_rights};
+use super::payment::transfer_type;
+use super::IssueError;
+
+/// Amount allocated to each seal of the synthetic history
+pub const SYNTHETIC_AMOUNT: u64 = 1_000;
+
+/// Value of the bitcoin outputs hosting the seals
+const SEAL_OUTPUT_VALUE: u64 = 1_000;
+
+/// Compact target of the regtest headers, which are mined with a few attempts
+const REGTEST_BITS: u32 = 0x207fffff;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum SyntheticError {
+    /// schema {0} does not define state transition which can be used for the transfers.
+    NoTransferType(SchemaId),
+
+    /// schema {0} inherits from a root schema, which is not supported by the generator.
+    RootSchema(SchemaId),
+
+    /// synthetic history must be at least one transfer wide.
+    ZeroWidth,
+
+    /// invalid issuance specification. Details: {0}
+    #[from]
+    Issue(IssueError),
+
+    /// unable to construct synthetic history. Details: {0}
+    Construct(String),
+}
+
+/// Shape of the synthetic contract history
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+#[display("{depth}x{width}")]
+pub struct HistoryShape {
+    /// Number of witness transactions between the genesis and the endpoints
+    pub depth: u16,
+    /// Number of transfers closed by each witness transaction
+    pub width: u16,
+}
+
+/// Synthetic contract together with the transfer of its whole history
+#[derive(Clone, Debug)]
+pub struct SyntheticHistory {
+    pub contract: Contract,
+    pub transfer: StateTransfer,
+    /// Proofs of the witness transactions of the transfer
+    pub proofs: ProofBundle,
+    /// Header checkpoint from which the header chain of the proofs starts
+    pub checkpoint: HeaderCheckpoint,
+}
+
+impl SyntheticHistory {
+    /// Generates contract with the history of the given shape. Genesis
+    /// metadata and the assignments of the rights other than the transferred
+    /// one are taken from the issuance specification.
+    pub fn generate(
+        schema: Schema,
+        spec: IssueSpec,
+        shape: HistoryShape,
+    ) -> Result<SyntheticHistory, SyntheticError> {
+        SyntheticHistory::generate_with(schema, spec, shape, &mut thread_rng())
+    }
+
+    /// Generates contract with the history of the given shape like
+    /// [`SyntheticHistory::generate`], taking the blinding factors from the
+    /// provided random number generator.
+    pub fn generate_with(
+        schema: Schema,
+        spec: IssueSpec,
+        shape: HistoryShape,
+        rng: &mut (impl Rng + CryptoRng),
+    ) -> Result<SyntheticHistory, SyntheticError> {
+        let mut issue = SyntheticIssue::with(schema, spec, shape.width, 0, rng)?;
+        let mut headers = HeaderChain::new();
+        let mut proofs = ProofBundle::default();
+        for level in 0..shape.depth {
+            let witness = SyntheticWitness::close(&[&issue], rng)?;
+            let (header, proof) = headers.mine(witness.tx.clone());
+            proofs.headers.push(header);
+            proofs.txes.push(proof);
+            witness.push_to(&mut issue, level + 1 == shape.depth)?;
+        }
+        Ok(SyntheticHistory {
+            contract: issue.contract,
+            transfer: issue.transfer,
+            proofs,
+            checkpoint: HeaderChain::checkpoint(),
+        })
+    }
+}
+
+/// Synthetic contract with the transfer of its history and the allocations
+/// which are not closed yet
+pub(super) struct SyntheticIssue {
+    pub contract: Contract,
+    pub transfer: StateTransfer,
+    transition_type: TransitionType,
+    right_type: OwnedRightType,
+    allocations: Vec<Allocation>,
+}
+
+impl SyntheticIssue {
+    /// Issues the contract allocating the transferred right to the outputs of
+    /// a funding transaction, which is never resolved by the validation.
+    /// Contracts of the same schema have distinct funding transactions for
+    /// different funding indexes.
+    pub fn with(
+        schema: Schema,
+        mut spec: IssueSpec,
+        width: u16,
+        funding_index: u32,
+        rng: &mut (impl Rng + CryptoRng),
+    ) -> Result<SyntheticIssue, SyntheticError> {
+        let schema_id = schema.schema_id();
+        if schema.root_id != zero!() {
+            return Err(SyntheticError::RootSchema(schema_id));
+        }
+        if width == 0 {
+            return Err(SyntheticError::ZeroWidth);
+        }
+        let (transition_type, _, right_type) =
+            transfer_type(&schema).ok_or(SyntheticError::NoTransferType(schema_id))?;
+
+        let funding = Transaction {
+            version: 2,
+            lock_time: PackedLockTime(funding_index),
+            input: vec![],
+            output: seal_outputs(width, false),
+        };
+        let funding_txid = funding.txid();
+        spec.schema = schema_id;
+        spec.assignments.retain(|assignment| assignment.right_type != right_type);
+        spec.assignments.extend((0..width).map(|vout| AssignmentSpec {
+            right_type,
+            outpoint: OutPoint::new(funding_txid, vout as u32),
+            method: CloseMethod::OpretFirst,
+            amount: Some(SYNTHETIC_AMOUNT),
+        }));
+        let metadata = genesis_metadata(&schema, &spec)?;
+        let owned_rights = genesis_owned_rights(&schema, &spec, rng)?;
+        let genesis = Genesis::with(
+            schema_id,
+            Chain::Regtest(genesis_block(Network::Regtest).block_hash()),
+            metadata,
+            owned_rights,
+            empty!(),
+        );
+        let allocations = node_allocations(&genesis, right_type, funding_txid)?;
+        let contract =
+            Contract::with(schema.clone(), None, genesis.clone(), empty!(), empty!(), empty!());
+        let transfer = StateTransfer::with(schema, None, genesis, empty!(), empty!(), empty!());
+        Ok(SyntheticIssue {
+            contract,
+            transfer,
+            transition_type,
+            right_type,
+            allocations,
+        })
+    }
+
+    pub fn contract_id(&self) -> ContractId { self.contract.contract_id() }
+}
+
+/// Witness transaction closing all the allocations of one or more synthetic
+/// contracts
+pub(super) struct SyntheticWitness {
+    /// PSBT before the anchor is committed into it
+    pub psbt: Psbt,
+    pub anchor: Anchor<lnpbp4::MerkleBlock>,
+    pub bundles: BTreeMap<ContractId, TransitionBundle>,
+    pub tx: Transaction,
+    transitions: BTreeMap<ContractId, Vec<Transition>>,
+}
+
+impl SyntheticWitness {
+    /// Constructs witness transaction spending the allocations of the
+    /// contracts in their order, with a transfer to a new output for each of
+    /// the allocations.
+    pub fn close(
+        issues: &[&SyntheticIssue],
+        rng: &mut (impl Rng + CryptoRng),
+    ) -> Result<SyntheticWitness, SyntheticError> {
+        let mut spends = vec![];
+        for issue in issues {
+            for allocation in &issue.allocations {
+                let seal = seal::Revealed {
+                    method: CloseMethod::OpretFirst,
+                    blinding: rng.gen(),
+                    txid: None,
+                    vout: spends.len() as u32 + 1,
+                };
+                // Single input is transferred to a single output, so the output keeps the
+                // blinding factor of the input and the amounts stay balanced
+                let assignments = TypedAssignments::Value(vec![Assignment::Revealed {
+                    seal,
+                    state: allocation.state.clone(),
+                }]);
+                let transition = Transition::with(
+                    issue.transition_type,
+                    empty!(),
+                    empty!(),
+                    OwnedRights::from(bmap! { issue.right_type => assignments }),
+                    empty!(),
+                    ParentOwnedRights::from(bmap! {
+                        allocation.node_id => bmap! { issue.right_type => vec![allocation.no] }
+                    }),
+                );
+                spends.push((&issue.contract, allocation.outpoint, transition));
+            }
+        }
+        let seals = spends.len() as u16;
+        SyntheticWitness::spend(spends, seals)
+    }
+
+    /// Constructs witness transaction with an input for each of the spent
+    /// outpoints, consumed by the transition of the contract, and the given
+    /// number of outputs hosting the seals. Seals of the transitions must
+    /// reference the outputs starting from the first one after the commitment
+    /// host.
+    pub fn spend(
+        spends: Vec<(&Contract, OutPoint, Transition)>,
+        seals: u16,
+    ) -> Result<SyntheticWitness, SyntheticError> {
+        let tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: spends
+                .iter()
+                .map(|(_, outpoint, _)| TxIn {
+                    previous_output: *outpoint,
+                    script_sig: Script::new(),
+                    sequence: Sequence::MAX,
+                    witness: Witness::new(),
+                })
+                .collect(),
+            output: seal_outputs(seals, true),
+        };
+        let psbt = PartiallySignedTransaction::from_unsigned_tx(tx)
+            .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+        let mut psbt = Psbt::from(psbt);
+        psbt.outputs[0]
+            .set_opret_host()
+            .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+
+        let mut transitions = BTreeMap::<ContractId, Vec<Transition>>::new();
+        for (input, (contract, _, transition)) in spends.into_iter().enumerate() {
+            let contract_id = contract.contract_id();
+            if !transitions.contains_key(&contract_id) {
+                psbt.set_rgb_contract(contract.clone())
+                    .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+            }
+            psbt.push_rgb_transition(transition.clone())
+                .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+            psbt.inputs[input]
+                .set_rgb_consumer(contract_id, transition.node_id())
+                .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+            transitions.entry(contract_id).or_default().push(transition);
+        }
+
+        let bundles =
+            psbt.rgb_bundles().map_err(|err| SyntheticError::Construct(err.to_string()))?;
+        let draft = psbt.clone();
+        let anchor =
+            Anchor::commit(&mut psbt).map_err(|err| SyntheticError::Construct(err.to_string()))?;
+        let tx = PartiallySignedTransaction::from(psbt).unsigned_tx;
+        Ok(SyntheticWitness {
+            psbt: draft,
+            anchor,
+            bundles,
+            tx,
+            transitions,
+        })
+    }
+
+    /// Adds the anchored bundle of the contract to the transfer. Revealed
+    /// seals of the bundle transitions become the transfer endpoints, if
+    /// requested.
+    pub fn push_bundle(
+        &self,
+        transfer: &mut StateTransfer,
+        contract_id: ContractId,
+        endpoints: bool,
+    ) -> Result<(), SyntheticError> {
+        let bundle = self.bundles.get(&contract_id).expect("bundle of the synthetic contract");
+        let bundle_id = bundle.bundle_id();
+        let anchor = self
+            .anchor
+            .to_merkle_proof(contract_id)
+            .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+        transfer
+            .push_anchored_bundle(anchor, bundle.clone())
+            .map_err(|err| SyntheticError::Construct(err.to_string()))?;
+        if endpoints {
+            for transition in self.transitions.get(&contract_id).into_iter().flatten() {
+                for seal in transition.revealed_seals().unwrap_or_default() {
+                    transfer.push_seal_endpoint(bundle_id, SealEndpoint::from(seal));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the bundle of the contract to its transfer, replacing the closed
+    /// allocations with the new ones. The new allocations become the
+    /// transfer endpoints, if requested.
+    pub fn push_to(
+        &self,
+        issue: &mut SyntheticIssue,
+        endpoints: bool,
+    ) -> Result<(), SyntheticError> {
+        let contract_id = issue.contract_id();
+        self.push_bundle(&mut issue.transfer, contract_id, endpoints)?;
+
+        let witness_txid = self.tx.txid();
+        issue.allocations = vec![];
+        for transition in self.transitions.get(&contract_id).into_iter().flatten() {
+            issue.allocations.extend(node_allocations(transition, issue.right_type, witness_txid)?);
+        }
+        Ok(())
+    }
+}
+
+/// State allocated to a bitcoin output by the synthetic history
+struct Allocation {
+    node_id: NodeId,
+    no: u16,
+    outpoint: OutPoint,
+    state: value::Revealed,
+}
+
+/// Lists allocations of the transferred right by the node; seals without txid
+/// belong to the witness transaction.
+fn node_allocations(
+    node: &impl Node,
+    right_type: OwnedRightType,
+    witness_txid: Txid,
+) -> Result<Vec<Allocation>, SyntheticError> {
+    let assignments = match node.owned_rights_by_type(right_type) {
+        Some(assignments) => assignments,
+        None => return Ok(vec![]),
+    };
+    let values = assignments.to_value_assignments();
+    let mut allocations = vec![];
+    for no in 0..assignments.len() as u16 {
+        let seal = assignments
+            .revealed_seal_at(no)
+            .map_err(|err| SyntheticError::Construct(err.to_string()))?
+            .ok_or_else(|| SyntheticError::Construct(s!("concealed synthetic seal")))?;
+        let state = values
+            .get(no as usize)
+            .and_then(|assignment| assignment.as_revealed_state())
+            .ok_or_else(|| SyntheticError::Construct(s!("concealed synthetic state")))?;
+        allocations.push(Allocation {
+            node_id: node.node_id(),
+            no,
+            outpoint: seal.outpoint_or(witness_txid),
+            state: state.clone(),
+        });
+    }
+    Ok(allocations)
+}
+
+/// Outputs hosting the seals, preceded by the output hosting the commitment
+/// for the witness transactions
+pub(super) fn seal_outputs(width: u16, commitment: bool) -> Vec<TxOut> {
+    let host = TxOut {
+        value: 0,
+        script_pubkey: Script::new_op_return(&[]),
+    };
+    let seals = (0..width).map(|_| TxOut {
+        value: SEAL_OUTPUT_VALUE,
+        script_pubkey: Script::new(),
+    });
+    match commitment {
+        true => Some(host).into_iter().chain(seals).collect(),
+        false => seals.collect(),
+    }
+}
+
+/// Chain of regtest block headers, each mining a single transaction
+pub(super) struct HeaderChain {
+    tip: BlockHeader,
+    height: u32,
+}
+
+impl HeaderChain {
+    pub fn new() -> HeaderChain {
+        HeaderChain {
+            tip: genesis_block(Network::Regtest).header,
+            height: 0,
+        }
+    }
+
+    pub fn checkpoint() -> HeaderCheckpoint {
+        HeaderCheckpoint {
+            height: 0,
+            block_hash: genesis_block(Network::Regtest).block_hash(),
+        }
+    }
+
+    /// Mines the transaction into a new block, returning the block header and
+    /// the proof of the transaction inclusion.
+    pub fn mine(&mut self, tx: Transaction) -> (ProofHeader, TxProof) {
+        let mut header = BlockHeader {
+            version: 0x20000000,
+            prev_blockhash: self.tip.block_hash(),
+            merkle_root: TxMerkleNode::from_inner(tx.txid().into_inner()),
+            time: self.tip.time + 600,
+            bits: REGTEST_BITS,
+            nonce: 0,
+        };
+        while header.validate_pow(&header.target()).is_err() {
+            header.nonce += 1;
+        }
+        self.tip = header;
+        self.height += 1;
+        let proof = TxProof {
+            tx,
+            height: self.height,
+            pos: 0,
+            merkle_branch: vec![],
+        };
+        (ProofHeader(header), proof)
+    }
+}
@@ -0,0 +1,177 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use bitcoin::Txid;
+use commit_verify::CommitConceal;
+use internet2::addr::NodeAddr;
+use rgb::{
+    Consignment, ConsignmentId, ConsignmentType, InmemConsignment, Node, SealEndpoint,
+    TransitionBundle, TypedAssignments,
+};
+use rgb_rpc::{now, NodeEvent, TransferDirection, TransferRecord, TransferStatus};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Constructs transfer record for a consignment having seal endpoints.
+pub(super) fn transfer_record<C: ConsignmentType>(
+    consignment: &InmemConsignment<C>,
+    direction: TransferDirection,
+    status: TransferStatus,
+) -> TransferRecord {
+    let beneficiaries = consignment.endpoints().map(|(_, seal)| *seal).collect::<Vec<_>>();
+    let seals = beneficiaries.iter().map(SealEndpoint::commit_conceal).collect::<Vec<_>>();
+    let bundle_ids = consignment.endpoint_bundle_ids();
+    let txid = consignment
+        .anchored_bundles()
+        .find(|(_, bundle)| bundle_ids.contains(&bundle.bundle_id()))
+        .map(|(anchor, _)| anchor.txid);
+    let amounts = consignment
+        .endpoint_bundles()
+        .into_iter()
+        .flat_map(TransitionBundle::known_transitions)
+        .flat_map(|transition| transition.owned_rights().values())
+        .flat_map(TypedAssignments::to_value_assignments)
+        .filter(|assignment| seals.contains(&assignment.to_confidential_seal()))
+        .filter_map(|assignment| assignment.as_revealed_state().map(|state| state.value))
+        .collect::<Vec<_>>();
+    let timestamp = now();
+    TransferRecord {
+        id: consignment.id(),
+        direction,
+        status,
+        contract_id: consignment.contract_id(),
+        txid,
+        amount: if amounts.is_empty() { None } else { Some(amounts.into_iter().sum()) },
+        beneficiaries,
+        peer: None,
+        created: timestamp,
+        updated: timestamp,
+    }
+}
+
+impl Runtime {
+    /// Stores transfer record. If the transfer is already known, keeps its
+    /// direction and the time it became known.
+    pub(super) fn record_transfer(
+        &mut self,
+        mut record: TransferRecord,
+    ) -> Result<(), DaemonError> {
+        let known: Option<TransferRecord> = self.store.retrieve_sten(db::TRANSFERS, record.id)?;
+        if let Some(known) = known {
+            record.direction = known.direction;
+            record.created = known.created;
+            record.peer = record.peer.or(known.peer);
+        }
+        debug!("Recording {}", record);
+        self.store.store_sten(db::TRANSFERS, record.id, &record)?;
+        self.events.push(NodeEvent::TransferUpdated(record));
+        Ok(())
+    }
+
+    /// Marks outgoing transfer as sent to the remote node.
+    pub(super) fn transfer_sent(
+        &mut self,
+        id: ConsignmentId,
+        peer: NodeAddr,
+    ) -> Result<(), DaemonError> {
+        if let Some(mut record) = self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, id)? {
+            record.status = TransferStatus::Sent;
+            record.peer = Some(peer);
+            record.updated = now();
+            self.store.store_sten(db::TRANSFERS, id, &record)?;
+            self.events.push(NodeEvent::TransferUpdated(record));
+        }
+        Ok(())
+    }
+
+    /// Marks pending incoming transfer as accelerated with a published child
+    /// transaction.
+    pub(super) fn transfer_accelerated(&mut self, id: ConsignmentId) -> Result<(), DaemonError> {
+        if let Some(mut record) = self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, id)? {
+            debug!("Transfer {} is accelerated", record.id);
+            record.status = TransferStatus::Accelerated;
+            record.updated = now();
+            self.store.store_sten(db::TRANSFERS, id, &record)?;
+            self.events.push(NodeEvent::TransferUpdated(record));
+        }
+        Ok(())
+    }
+
+    /// Marks all outgoing transfers with the witness transaction as
+    /// published.
+    pub(super) fn transfers_published(&mut self, txid: Txid) -> Result<(), DaemonError> {
+        for key in self.store.ids(db::TRANSFERS)? {
+            let mut record = match self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, key)? {
+                Some(record) => record,
+                None => continue,
+            };
+            if record.direction != TransferDirection::Outgoing || record.txid != Some(txid) {
+                continue;
+            }
+            debug!("Transfer {} is published", record.id);
+            record.status = TransferStatus::Published;
+            record.updated = now();
+            self.store.store_sten(db::TRANSFERS, key, &record)?;
+            self.events.push(NodeEvent::TransferUpdated(record));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "testkit", feature = "test-utils"))]
+mod test {
+    use std::sync::Arc;
+
+    use rgb::StateTransfer;
+
+    use super::*;
+    use crate::bucketd::HistoryShape;
+    use crate::testkit::{fungible_history, regtest_runtime, MemStore, MockChain};
+
+    fn transfer() -> StateTransfer {
+        fungible_history(HistoryShape { depth: 1, width: 1 }).transfer
+    }
+
+    fn runtime(name: &str, store: &MemStore) -> Runtime {
+        regtest_runtime(&format!("transfers-{}", name), Arc::new(MockChain::new()), store.clone())
+    }
+
+    #[test]
+    fn record_in_shared_store() {
+        let transfer = transfer();
+        let id = transfer.id();
+        let store = MemStore::new();
+        let mut first = runtime("first", &store);
+        let mut second = runtime("second", &store);
+
+        let incoming =
+            transfer_record(&transfer, TransferDirection::Incoming, TransferStatus::Pending);
+        first.record_transfer(incoming.clone()).unwrap();
+
+        // Update made through another runtime keeps the direction and the
+        // creation time of the known record
+        let mut update =
+            transfer_record(&transfer, TransferDirection::Outgoing, TransferStatus::Accepted);
+        update.created = incoming.created + 1;
+        second.record_transfer(update).unwrap();
+        let record: TransferRecord =
+            first.store.retrieve_sten(db::TRANSFERS, id).unwrap().expect("transfer is recorded");
+        assert_eq!(record.status, TransferStatus::Accepted);
+        assert_eq!(record.direction, TransferDirection::Incoming);
+        assert_eq!(record.created, incoming.created);
+
+        // Removed record is overwritten with an empty chunk
+        assert!(second.store.wipe_chunk(db::TRANSFERS, id).unwrap().is_some());
+        assert!(first.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, id).unwrap().is_none());
+        assert_eq!(store.count(db::TRANSFERS), 1);
+    }
+}
@@ -0,0 +1,159 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Accounting of the store space taken by each of the contracts, which guides
+//! the choice of contracts to forget on the nodes with limited storage.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
+
+use amplify::Wrapper;
+use bitcoin::hashes::Hash;
+use bitcoin::Txid;
+use rgb::{ConsignmentId, ContractId};
+use rgb_rpc::{now, StorageUsage, TransferRecord};
+use storm::ChunkId;
+
+use super::{ContainerRef, Runtime};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+impl Runtime {
+    /// Recomputes and stores the storage usage of the given contracts. Records
+    /// of the forgotten contracts are removed together with the contracts.
+    pub(super) fn update_storage_usage(
+        &mut self,
+        contract_ids: &BTreeSet<ContractId>,
+    ) -> Result<(), DaemonError> {
+        if contract_ids.is_empty() {
+            return Ok(());
+        }
+        let mut attachments = self.attachments_usage(contract_ids)?;
+        for contract_id in contract_ids {
+            let attachments = attachments.remove(contract_id).unwrap_or_default();
+            if let Some(usage) = self.contract_usage(*contract_id, attachments)? {
+                trace!("Storage usage of {}: {:?}", contract_id, usage);
+                self.store.store_sten(db::STORAGE_USAGE, *contract_id, &usage)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists contracts sorted by the size of their data, up to `limit` of them
+    /// unless it is zero. Usage of the contracts registered before the node
+    /// started to account it is computed and stored on the first request.
+    pub(super) fn storage_usage(&mut self, limit: u16) -> Result<Vec<StorageUsage>, DaemonError> {
+        let mut report = vec![];
+        let mut missed = bset! {};
+        for key in self.store.ids(db::CONTRACTS)? {
+            let contract_id = ContractId::from_inner(Hash::from_inner(key.into_inner()));
+            match self.store.retrieve_sten::<StorageUsage>(db::STORAGE_USAGE, contract_id)? {
+                Some(usage) => report.push(usage),
+                // Forgotten contracts keep their keys in the store
+                None if self.chunk_size(db::GENESIS, contract_id)? > 0 => {
+                    missed.insert(contract_id);
+                }
+                None => {}
+            }
+        }
+        if !missed.is_empty() {
+            debug!("Computing storage usage of {} contracts", missed.len());
+            self.update_storage_usage(&missed)?;
+            for contract_id in missed {
+                report.extend(self.store.retrieve_sten(db::STORAGE_USAGE, contract_id)?);
+            }
+        }
+
+        report.sort_by_key(|usage| Reverse(usage.total()));
+        if limit > 0 {
+            report.truncate(limit as usize);
+        }
+        Ok(report)
+    }
+
+    /// Computes size of the contract history and anchors, or returns `None`
+    /// if the contract is not known or was forgotten.
+    fn contract_usage(
+        &mut self,
+        contract_id: ContractId,
+        attachments: u64,
+    ) -> Result<Option<StorageUsage>, DaemonError> {
+        let mut transitions = self.chunk_size(db::GENESIS, contract_id)?;
+        if transitions == 0 {
+            return Ok(None);
+        }
+
+        let node_ids = self.contract_transitions(contract_id)?.into_iter().collect::<Vec<_>>();
+        for node_id in &node_ids {
+            transitions += self.chunk_size(db::TRANSITIONS, *node_id)?;
+        }
+        let witnesses =
+            self.store_pool.retrieve_all::<_, Txid>(db::TRANSITION_WITNESS, node_ids)?;
+        let mut anchors = 0;
+        for txid in witnesses.into_iter().flatten().collect::<BTreeSet<_>>() {
+            anchors += self.chunk_size(db::ANCHORS, txid)?;
+            anchors +=
+                self.chunk_size(db::BUNDLES, ChunkId::with_fixed_fragments(contract_id, txid))?;
+        }
+
+        Ok(Some(StorageUsage {
+            contract_id,
+            transitions,
+            anchors,
+            attachments,
+            updated: now(),
+        }))
+    }
+
+    /// Computes size of the Storm containers kept for the consignments of the
+    /// given contracts.
+    fn attachments_usage(
+        &mut self,
+        contract_ids: &BTreeSet<ContractId>,
+    ) -> Result<BTreeMap<ContractId, u64>, DaemonError> {
+        let mut consignments = BTreeMap::<ConsignmentId, ContractId>::new();
+        for key in self.store.ids(db::TRANSFERS)? {
+            match self.store.retrieve_sten::<TransferRecord>(db::TRANSFERS, key)? {
+                Some(record) if contract_ids.contains(&record.contract_id) => {
+                    consignments.insert(record.id, record.contract_id);
+                }
+                _ => {}
+            }
+        }
+
+        let mut usage = bmap! {};
+        if consignments.is_empty() {
+            return Ok(usage);
+        }
+        for key in self.store.ids(db::CONTAINER_REFS)? {
+            let container_ref =
+                match self.store.retrieve_sten::<ContainerRef>(db::CONTAINER_REFS, key)? {
+                    Some(container_ref) if !container_ref.collected => container_ref,
+                    _ => continue,
+                };
+            let contract_id = match container_ref
+                .consignment_id
+                .and_then(|consignment_id| consignments.get(&consignment_id))
+            {
+                Some(contract_id) => *contract_id,
+                None => continue,
+            };
+            let mut size = 0;
+            for chunk_id in container_ref.chunks {
+                size += self.chunk_size(storm_rpc::DB_TABLE_CHUNKS, chunk_id)?;
+            }
+            for table in [storm_rpc::DB_TABLE_CONTAINERS, storm_rpc::DB_TABLE_CONTAINER_HEADERS] {
+                size += self.chunk_size(table, container_ref.container_id)?;
+            }
+            *usage.entry(contract_id).or_default() += size;
+        }
+        Ok(usage)
+    }
+}
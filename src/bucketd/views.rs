@@ -0,0 +1,207 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Materialized views of the contract state, keeping the state assigned to
+//! each outpoint of a contract in a separate table per assignment type:
+//! fungible allocations, declarative rights (like inflation or renomination),
+//! structured data and attachments. Queries targeting few outpoints read only
+//! the relevant view entries instead of deserializing the whole
+//! [`ContractState`].
+//!
+//! Views are updated incrementally each time the contract state is stored,
+//! touching only the outpoints which state has changed. Contracts which state
+//! was stored before the views were introduced get their views built on the
+//! first outpoint state query.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::OutPoint;
+use rgb::{AssignedState, ContractId, ContractState, OutpointState};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use crate::db::{self, Store, StoreRpcExt, StrictEncodedChunk};
+use crate::DaemonError;
+
+fn view_key(contract_id: ContractId, outpoint: OutPoint) -> ChunkId {
+    ChunkId::with_fixed_fragments(contract_id, outpoint)
+}
+
+fn group<State>(
+    assignments: &BTreeSet<AssignedState<State>>,
+) -> BTreeMap<OutPoint, BTreeSet<AssignedState<State>>>
+where AssignedState<State>: Clone + Ord {
+    let mut views: BTreeMap<OutPoint, BTreeSet<_>> = bmap! {};
+    for assigned in assignments {
+        views.entry(assigned.outpoint).or_default().insert(assigned.clone());
+    }
+    views
+}
+
+fn outpoints(state: &ContractState) -> BTreeSet<OutPoint> {
+    state
+        .owned_rights
+        .iter()
+        .map(|assigned| assigned.outpoint)
+        .chain(state.owned_values.iter().map(|assigned| assigned.outpoint))
+        .chain(state.owned_data.iter().map(|assigned| assigned.outpoint))
+        .chain(state.owned_attachments.iter().map(|assigned| assigned.outpoint))
+        .collect()
+}
+
+/// Writes view entries of a single assignment type for the outpoints which
+/// state differs from the previous one. Entries of the outpoints left without
+/// the state are removed.
+fn update_table<State>(
+    store: &mut dyn Store,
+    table: &str,
+    contract_id: ContractId,
+    outpoints: &BTreeSet<OutPoint>,
+    prev: Option<&BTreeSet<AssignedState<State>>>,
+    next: &BTreeSet<AssignedState<State>>,
+) -> Result<usize, DaemonError>
+where
+    AssignedState<State>: Clone + Ord,
+    BTreeSet<AssignedState<State>>: StrictEncodedChunk,
+{
+    let before = prev.map(group).unwrap_or_default();
+    let after = group(next);
+    let mut updated = 0usize;
+    for outpoint in outpoints {
+        let view = after.get(outpoint);
+        if prev.is_some() && before.get(outpoint) == view {
+            continue;
+        }
+        let key = view_key(contract_id, *outpoint);
+        match view {
+            Some(view) => {
+                store.store_sten(table, key, view)?;
+            }
+            None => {
+                store.wipe_chunk(table, key)?;
+            }
+        }
+        updated += 1;
+    }
+    Ok(updated)
+}
+
+/// Updates state views of the contract after its state has changed from the
+/// previous one (if any). If the views of the contract were never built, they
+/// are built from scratch.
+pub(crate) fn update(
+    store: &mut dyn Store,
+    contract_id: ContractId,
+    prev: Option<&ContractState>,
+    state: &ContractState,
+) -> Result<(), DaemonError> {
+    let known: Option<BTreeSet<OutPoint>> = store.retrieve_sten(db::STATE_VIEWS, contract_id)?;
+    // Previous state is useless for diffing if it was never reflected in the views
+    let prev = if known.is_some() { prev } else { None };
+    let next = outpoints(state);
+    let mut candidates = known.unwrap_or_default();
+    candidates.extend(&next);
+
+    let mut updated = 0usize;
+    updated += update_table(
+        store,
+        db::STATE_ALLOCATIONS,
+        contract_id,
+        &candidates,
+        prev.map(|prev| &prev.owned_values),
+        &state.owned_values,
+    )?;
+    updated += update_table(
+        store,
+        db::STATE_RIGHTS,
+        contract_id,
+        &candidates,
+        prev.map(|prev| &prev.owned_rights),
+        &state.owned_rights,
+    )?;
+    updated += update_table(
+        store,
+        db::STATE_DATA,
+        contract_id,
+        &candidates,
+        prev.map(|prev| &prev.owned_data),
+        &state.owned_data,
+    )?;
+    updated += update_table(
+        store,
+        db::STATE_ATTACHMENTS,
+        contract_id,
+        &candidates,
+        prev.map(|prev| &prev.owned_attachments),
+        &state.owned_attachments,
+    )?;
+    store.store_sten(db::STATE_VIEWS, contract_id, &next)?;
+    trace!("Updated {} state view entries of {}", updated, contract_id);
+    Ok(())
+}
+
+/// Removes all state views of the contract.
+pub(crate) fn forget(store: &mut dyn Store, contract_id: ContractId) -> Result<(), DaemonError> {
+    let known: Option<BTreeSet<OutPoint>> = store.retrieve_sten(db::STATE_VIEWS, contract_id)?;
+    for outpoint in known.iter().flatten() {
+        let key = view_key(contract_id, *outpoint);
+        for table in
+            [db::STATE_ALLOCATIONS, db::STATE_RIGHTS, db::STATE_DATA, db::STATE_ATTACHMENTS]
+        {
+            store.wipe_chunk(table, key)?;
+        }
+    }
+    if known.is_some() {
+        store.wipe_chunk(db::STATE_VIEWS, contract_id)?;
+    }
+    Ok(())
+}
+
+fn read_table<State>(
+    store: &mut dyn Store,
+    table: &str,
+    key: ChunkId,
+    set: &mut BTreeSet<OutpointState>,
+) -> Result<(), DaemonError>
+where
+    BTreeSet<AssignedState<State>>: StrictEncodedChunk,
+    OutpointState: From<AssignedState<State>>,
+{
+    let view: Option<BTreeSet<AssignedState<State>>> = store.retrieve_sten(table, key)?;
+    set.extend(view.into_iter().flatten().map(OutpointState::from));
+    Ok(())
+}
+
+/// Returns the state of the contract assigned to the given outpoints using the
+/// state views, or `None` if the views of the contract are not built yet.
+/// Outpoints without any state are not included into the returned map.
+pub(crate) fn outpoint_state(
+    store: &mut dyn Store,
+    contract_id: ContractId,
+    outpoints: &BTreeSet<OutPoint>,
+) -> Result<Option<BTreeMap<OutPoint, BTreeSet<OutpointState>>>, DaemonError> {
+    let known: BTreeSet<OutPoint> = match store.retrieve_sten(db::STATE_VIEWS, contract_id)? {
+        Some(known) => known,
+        None => return Ok(None),
+    };
+    let mut map = bmap! {};
+    for outpoint in outpoints.intersection(&known) {
+        let key = view_key(contract_id, *outpoint);
+        let mut set = bset! {};
+        read_table(store, db::STATE_ALLOCATIONS, key, &mut set)?;
+        read_table(store, db::STATE_RIGHTS, key, &mut set)?;
+        read_table(store, db::STATE_DATA, key, &mut set)?;
+        read_table(store, db::STATE_ATTACHMENTS, key, &mut set)?;
+        if !set.is_empty() {
+            map.insert(*outpoint, set);
+        }
+    }
+    Ok(Some(map))
+}
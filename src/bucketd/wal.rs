@@ -0,0 +1,322 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Write-ahead log of the consignments being stored.
+//!
+//! Storing a consignment writes many chunks into different tables, which may
+//! be interrupted by a crash of the node, leaving the contract with partially
+//! stored history. Before the first write the consignment and the contract
+//! state it is applied to are put into the log; the log entry is removed once
+//! the consignment is stored completely. At the node start the remaining
+//! entries are completed by re-applying the logged consignment, which is
+//! possible since all the writes are idempotent, or rolled back to the logged
+//! state if the consignment data were lost.
+
+use rgb::{
+    ConsignmentId, ConsignmentType, Contract, ContractId, ContractState, InmemConsignment,
+    StateTransfer,
+};
+use rgb_rpc::{now, Reveal, StoreRecovery};
+
+use super::Runtime;
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Name of the environment variable selecting the fault point at which the
+/// bucket daemon aborts.
+#[cfg(all(feature = "fault-injection", not(test)))]
+const FAULT_POINT_ENV: &str = "RGB_NODE_FAULT_POINT";
+
+/// Consignment which storing was started but not completed. Since the
+/// contract is locked while the consignment is stored, there is at most one
+/// entry per contract.
+#[derive(Clone, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct WalEntry {
+    pub consignment_id: ConsignmentId,
+    pub contract_id: ContractId,
+    /// Whether the consignment is a state transfer or a contract consignment
+    pub transfer: bool,
+    pub reveal: Option<Reveal>,
+    /// Contract state before the consignment is applied
+    pub state: ContractState,
+    pub new_contract: bool,
+    /// Whether the contract was forgotten before
+    pub forgotten: bool,
+    /// UNIX timestamp of the start of storing
+    pub started: u64,
+}
+
+impl Runtime {
+    /// Logs the consignment before any of its data are written into the store.
+    pub(super) fn wal_begin<C: ConsignmentType>(
+        &mut self,
+        consignment: &InmemConsignment<C>,
+        reveal: Option<&Reveal>,
+        state: &ContractState,
+        new_contract: bool,
+        forgotten: bool,
+    ) -> Result<(), DaemonError> {
+        let contract_id = consignment.contract_id();
+        // The consignment goes first, so the entry is never present without it
+        self.store.store_sten(db::WAL_CONSIGNMENTS, contract_id, consignment)?;
+        let entry = WalEntry {
+            consignment_id: consignment.id(),
+            contract_id,
+            // Contract consignments have no endpoints and are not transfers
+            transfer: consignment.endpoints().next().is_some(),
+            reveal: reveal.cloned(),
+            state: state.clone(),
+            new_contract,
+            forgotten,
+            started: now(),
+        };
+        self.store.store_sten(db::WAL, contract_id, &entry)?;
+        Ok(())
+    }
+
+    /// Removes the log entry once the consignment is completely stored.
+    pub(super) fn wal_commit(&mut self, contract_id: ContractId) -> Result<(), DaemonError> {
+        self.store.wipe_chunk(db::WAL, contract_id)?;
+        self.store.wipe_chunk(db::WAL_CONSIGNMENTS, contract_id)?;
+        Ok(())
+    }
+
+    /// Completes or rolls back the consignments which were not completely
+    /// stored when the node has stopped.
+    pub(super) fn recover_store(&mut self) -> Result<StoreRecovery, DaemonError> {
+        let mut recovery = StoreRecovery::default();
+        for key in self.store.ids(db::WAL)? {
+            let entry: WalEntry = match self.store.retrieve_sten(db::WAL, key)? {
+                Some(entry) => entry,
+                None => continue,
+            };
+            let id = entry.consignment_id;
+            warn!(
+                "Consignment {} for contract {} was not completely stored, recovering",
+                id, entry.contract_id
+            );
+            if self.redo_consignment(&entry)? {
+                info!("Storing of consignment {} is completed", id);
+                recovery.completed.push(id);
+            } else {
+                self.rollback_consignment(&entry)?;
+                warn!("Consignment {} data are lost, contract state is rolled back", id);
+                recovery.rolled_back.push(id);
+            }
+        }
+        Ok(recovery)
+    }
+
+    /// Re-applies the logged consignment, returning `false` if it can't be
+    /// read from the log.
+    fn redo_consignment(&mut self, entry: &WalEntry) -> Result<bool, DaemonError> {
+        if entry.transfer {
+            match self.wal_consignment::<StateTransfer>(entry.contract_id)? {
+                Some(consignment) => self.redo_with(entry, consignment)?,
+                None => return Ok(false),
+            }
+        } else {
+            match self.wal_consignment::<Contract>(entry.contract_id)? {
+                Some(consignment) => self.redo_with(entry, consignment)?,
+                None => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    fn redo_with<C: ConsignmentType>(
+        &mut self,
+        entry: &WalEntry,
+        consignment: InmemConsignment<C>,
+    ) -> Result<(), DaemonError> {
+        let mut state = entry.state.clone();
+        self.store_consignment(&consignment, entry.reveal.as_ref(), &mut state)?;
+        self.commit_consignment(&consignment, &state, entry.new_contract, entry.forgotten)
+    }
+
+    /// Reads the logged consignment, treating the data which can't be decoded
+    /// as lost.
+    fn wal_consignment<C: ConsignmentType>(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<Option<InmemConsignment<C>>, DaemonError> {
+        match self.store.retrieve_sten(db::WAL_CONSIGNMENTS, contract_id) {
+            Err(DaemonError::Encoding(err)) => {
+                error!("Logged consignment for contract {} is corrupted: {}", contract_id, err);
+                Ok(None)
+            }
+            res => res,
+        }
+    }
+
+    /// Restores the contract state logged before the consignment was applied.
+    /// Indexes written for the consignment are kept, since they do not affect
+    /// the contract state.
+    fn rollback_consignment(&mut self, entry: &WalEntry) -> Result<(), DaemonError> {
+        let contract_id = entry.contract_id;
+        if !entry.new_contract {
+            self.state_cache.store(&mut self.store, contract_id, &entry.state)?;
+        } else if self.state_cache.get(&mut self.store, contract_id)?.is_some() {
+            self.forget_contract(contract_id, false)?;
+        }
+        self.wal_commit(contract_id)
+    }
+}
+
+/// Aborts the process if the fault point is selected with the
+/// `RGB_NODE_FAULT_POINT` environment variable, simulating a crash of the node
+/// while the consignment is stored.
+#[cfg(all(feature = "fault-injection", not(test)))]
+pub(super) fn fault_point(name: &str) {
+    if std::env::var(FAULT_POINT_ENV).as_deref() == Ok(name) {
+        error!("Aborting at fault point {}", name);
+        std::process::abort();
+    }
+}
+
+#[cfg(all(feature = "fault-injection", test))]
+thread_local! {
+    /// Fault point selected by the test running in the current thread
+    static FAULT_POINT: std::cell::Cell<Option<&'static str>> = std::cell::Cell::new(None);
+}
+
+/// Unwinds if the fault point is selected by the test running in the current
+/// thread, so the test may recover the store left by the interrupted runtime.
+#[cfg(all(feature = "fault-injection", test))]
+pub(super) fn fault_point(name: &str) {
+    if FAULT_POINT.with(|point| point.get()) == Some(name) {
+        panic!("interrupted at fault point {}", name);
+    }
+}
+
+#[cfg(not(feature = "fault-injection"))]
+#[inline]
+pub(super) fn fault_point(_name: &str) {}
+
+#[cfg(all(test, feature = "testkit", feature = "test-utils", feature = "fault-injection"))]
+mod test {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Arc;
+
+    use rgb::{validation, Validity};
+    use store_rpc::PrimaryKey;
+    use storm::Chunk;
+    use strict_encoding::StrictEncode;
+
+    use super::*;
+    use crate::bucketd::{HistoryShape, SyntheticHistory};
+    use crate::testkit::{fungible_history, regtest_runtime, MemStore, MockChain};
+    use crate::Store;
+
+    fn history() -> SyntheticHistory { fungible_history(HistoryShape { depth: 2, width: 1 }) }
+
+    fn runtime(name: &str, store: &MemStore) -> Runtime {
+        regtest_runtime(&format!("wal-{}", name), Arc::new(MockChain::new()), store.clone())
+    }
+
+    fn register(runtime: &mut Runtime, history: &SyntheticHistory) {
+        let proofs = Some(history.proofs.clone());
+        let status = runtime
+            .process_consignment(history.contract.clone(), false, true, None, proofs)
+            .expect("contract is registered");
+        assert!(matches!(status.validity(), Validity::Valid));
+    }
+
+    fn accept(
+        runtime: &mut Runtime,
+        history: &SyntheticHistory,
+    ) -> Result<validation::Status, DaemonError> {
+        let proofs = Some(history.proofs.clone());
+        runtime.process_consignment(history.transfer.clone(), false, true, None, proofs)
+    }
+
+    fn contract_state(runtime: &mut Runtime, contract_id: ContractId) -> Option<Vec<u8>> {
+        runtime
+            .state_cache
+            .get(runtime.store.as_mut(), contract_id)
+            .expect("contract state is readable")
+            .map(|state| state.strict_serialize().expect("in-memory encoding"))
+    }
+
+    /// State of the contract once the transfer is stored without
+    /// interruptions.
+    fn accepted_state(history: &SyntheticHistory) -> Option<Vec<u8>> {
+        let mut runtime = runtime("reference", &MemStore::new());
+        register(&mut runtime, history);
+        let status = accept(&mut runtime, history).expect("transfer is accepted");
+        assert!(matches!(status.validity(), Validity::Valid));
+        contract_state(&mut runtime, history.contract.contract_id())
+    }
+
+    /// Registers the contract and stores the transfer until the fault point,
+    /// returning the contract state preceding the transfer and the runtime
+    /// restarted over the store left by the interrupted one.
+    fn crash_at(
+        point: &'static str,
+        history: &SyntheticHistory,
+        store: &MemStore,
+    ) -> (Option<Vec<u8>>, Runtime) {
+        let mut crashed = runtime(point, store);
+        register(&mut crashed, history);
+        let registered = contract_state(&mut crashed, history.contract.contract_id());
+
+        FAULT_POINT.with(|fault| fault.set(Some(point)));
+        let res = panic::catch_unwind(AssertUnwindSafe(|| accept(&mut crashed, history)));
+        FAULT_POINT.with(|fault| fault.set(None));
+        assert!(res.is_err(), "storing is not interrupted at {}", point);
+        drop(crashed);
+
+        let mut restarted = runtime(point, store);
+        let contract_id = history.contract.contract_id();
+        assert!(restarted.store.retrieve_sten::<WalEntry>(db::WAL, contract_id).unwrap().is_some());
+        (registered, restarted)
+    }
+
+    fn assert_completed(point: &'static str) {
+        let history = history();
+        let contract_id = history.contract.contract_id();
+        let (_, mut runtime) = crash_at(point, &history, &MemStore::new());
+
+        let recovery = runtime.recover_store().expect("store is recovered");
+        assert_eq!(recovery.completed, vec![history.transfer.id()]);
+        assert!(recovery.rolled_back.is_empty());
+        assert!(runtime.store.retrieve_sten::<WalEntry>(db::WAL, contract_id).unwrap().is_none());
+        assert_eq!(contract_state(&mut runtime, contract_id), accepted_state(&history));
+
+        let recovery = runtime.recover_store().expect("store is recovered");
+        assert!(recovery.completed.is_empty() && recovery.rolled_back.is_empty());
+    }
+
+    #[test]
+    fn wal_written_recovery() { assert_completed("wal-written"); }
+
+    #[test]
+    fn bundles_stored_recovery() { assert_completed("bundles-stored"); }
+
+    #[test]
+    fn state_stored_recovery() { assert_completed("state-stored"); }
+
+    #[test]
+    fn lost_consignment_rollback() {
+        let history = history();
+        let contract_id = history.contract.contract_id();
+        let mut store = MemStore::new();
+        let (registered, mut runtime) = crash_at("bundles-stored", &history, &store);
+
+        let garbage = Chunk::try_from(&[0xFFu8; 4][..]).unwrap();
+        store.store(db::WAL_CONSIGNMENTS, contract_id.into_slice32(), &garbage).unwrap();
+
+        let recovery = runtime.recover_store().expect("store is recovered");
+        assert!(recovery.completed.is_empty());
+        assert_eq!(recovery.rolled_back, vec![history.transfer.id()]);
+        assert!(runtime.store.retrieve_sten::<WalEntry>(db::WAL, contract_id).unwrap().is_none());
+        assert_eq!(contract_state(&mut runtime, contract_id), registered);
+    }
+}
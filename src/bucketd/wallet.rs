@@ -0,0 +1,715 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+
+use bitcoin::{Network, OutPoint, Script, Txid};
+use lnpbp::chain::Chain;
+use miniscript::descriptor::DescriptorPublicKey;
+use miniscript::Descriptor;
+use rgb::{ContractId, ContractStateMap, StateAtom};
+use rgb_rpc::{
+    AllocationStatus, ContractAllocation, DecimalAmount, FailureCode, OutpointFilter,
+    OutpointStatus, SpentStatus, WalletAddress, WalletBalance, WalletInfo, WalletUtxo,
+};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::{Runtime, StashError};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+/// Maximal number of scripts requested from electrum server at once
+const ELECTRUM_BATCH: usize = 100;
+/// Derivation indexes starting from this one are hardened and can't be used
+/// with wildcard descriptors
+const HARDENED_INDEX: u32 = 1 << 31;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum WalletError {
+    /// invalid wallet descriptor. Details: {0}
+    InvalidDescriptor(String),
+
+    /// wallet with the provided descriptor was never created or synchronized.
+    UnknownWallet,
+
+    /// wallet addresses can't be derived for chain {0}.
+    UnsupportedChain(String),
+
+    /// electrum server error during wallet synchronization. Details: {0}
+    Electrum(String),
+
+    /// unable to retrieve transaction {0} to check whether its outputs belong
+    /// to the wallet.
+    UnresolvedTx(Txid),
+
+    /// outpoint {0} does not belong to the wallet.
+    ForeignOutpoint(OutPoint),
+
+    /// outpoint {0} is not spent.
+    Unspent(OutPoint),
+
+    /// outpoint {0} is spent by transaction {1}, which is not mined yet.
+    SpendUnconfirmed(OutPoint, Txid),
+
+    /// outpoint {0} is spent by transaction {1} having a pending disclosure;
+    /// the state assigned to the outpoint is transferred and will be updated
+    /// once the disclosure is enclosed.
+    PendingDisclosure(OutPoint, Txid),
+
+    /// embedded BDK wallet failure. Details: {0}
+    Bdk(String),
+}
+
+impl From<electrum_client::Error> for WalletError {
+    fn from(err: electrum_client::Error) -> Self { WalletError::Electrum(err.to_string()) }
+}
+
+impl From<&WalletError> for FailureCode {
+    fn from(err: &WalletError) -> Self {
+        match err {
+            WalletError::InvalidDescriptor(_) => FailureCode::InvalidDescriptor,
+            WalletError::UnknownWallet => FailureCode::UnknownWallet,
+            WalletError::Electrum(_) | WalletError::UnresolvedTx(_) => FailureCode::ChainBackend,
+            WalletError::ForeignOutpoint(_) => FailureCode::ForeignOutpoint,
+            WalletError::Unspent(_) => FailureCode::Unspent,
+            WalletError::SpendUnconfirmed(..) => FailureCode::TxUnmined,
+            WalletError::PendingDisclosure(..) => FailureCode::PendingDisclosure,
+            WalletError::UnsupportedChain(_) | WalletError::Bdk(_) => FailureCode::Wallet,
+        }
+    }
+}
+
+/// Wallet data kept in the store between synchronizations.
+#[derive(Clone, PartialEq, Eq, Debug, Default, StrictEncode, StrictDecode)]
+pub(crate) struct WalletState {
+    pub gap_limit: u16,
+    /// Last derivation index having transaction history, per keychain
+    pub last_used: BTreeMap<u8, u32>,
+    /// Wallet UTXOs; confirmations are computed each time the balance is
+    /// requested and are not stored
+    pub utxos: BTreeSet<WalletUtxo>,
+}
+
+type Keychain = Descriptor<DescriptorPublicKey>;
+
+/// Expands `<0;1>` multipath step of the wallet descriptor into the receive
+/// and change descriptors.
+pub(super) fn multipath(descriptor: &str) -> Vec<String> {
+    let descriptor = descriptor.trim();
+    if descriptor.contains("<0;1>") {
+        // Descriptor checksum does not match the expanded descriptors, so we drop it
+        let descriptor = descriptor.split('#').next().unwrap_or_default();
+        vec![descriptor.replace("<0;1>", "0"), descriptor.replace("<0;1>", "1")]
+    } else {
+        vec![descriptor.to_owned()]
+    }
+}
+
+/// Parses wallet descriptor into the receive and change keychains.
+pub(super) fn keychains(descriptor: &str) -> Result<Vec<Keychain>, WalletError> {
+    multipath(descriptor)
+        .iter()
+        .map(|s| Keychain::from_str(s))
+        .collect::<Result<_, _>>()
+        .map_err(|err| WalletError::InvalidDescriptor(err.to_string()))
+}
+
+pub(super) fn wallet_key(keychains: &[Keychain]) -> ChunkId {
+    let descriptors = keychains.iter().map(Keychain::to_string).collect::<Vec<_>>();
+    ChunkId::with_fixed_fragments(db::WALLETS, descriptors)
+}
+
+/// Identifies the wallet by its descriptor, such that different spellings of
+/// the same descriptor give the same id. Descriptors which can't be parsed are
+/// identified by their text.
+pub(crate) fn wallet_id(descriptor: &str) -> ChunkId {
+    match keychains(descriptor) {
+        Ok(keychains) => wallet_key(&keychains),
+        Err(_) => ChunkId::with_fixed_fragments(db::WALLETS, descriptor.trim().to_owned()),
+    }
+}
+
+/// Bitcoin network of the addresses derived for the chain used by the node
+pub(super) fn network(chain: &Chain) -> Result<Network, WalletError> {
+    match chain {
+        Chain::Mainnet => Ok(Network::Bitcoin),
+        Chain::Testnet3 => Ok(Network::Testnet),
+        Chain::Regtest(_) => Ok(Network::Regtest),
+        Chain::Signet | Chain::SignetCustom(_) => Ok(Network::Signet),
+        chain => Err(WalletError::UnsupportedChain(chain.to_string())),
+    }
+}
+
+pub(super) fn script(keychain: &Keychain, index: u32) -> Script {
+    keychain.at_derivation_index(index).script_pubkey()
+}
+
+fn address(keychain: &Keychain, index: u32, network: Network) -> Result<String, WalletError> {
+    keychain
+        .at_derivation_index(index)
+        .address(network)
+        .map(|address| address.to_string())
+        .map_err(|err| WalletError::InvalidDescriptor(err.to_string()))
+}
+
+/// Indexes contracts by the outpoints having their state assigned
+fn outpoint_contracts(allocations: &ContractStateMap) -> BTreeMap<OutPoint, BTreeSet<ContractId>> {
+    let mut index = BTreeMap::<OutPoint, BTreeSet<ContractId>>::new();
+    for (contract_id, state) in allocations {
+        let outpoints = state
+            .owned_rights
+            .iter()
+            .map(|assigned| assigned.outpoint)
+            .chain(state.owned_values.iter().map(|assigned| assigned.outpoint))
+            .chain(state.owned_data.iter().map(|assigned| assigned.outpoint))
+            .chain(state.owned_attachments.iter().map(|assigned| assigned.outpoint));
+        for outpoint in outpoints {
+            index.entry(outpoint).or_default().insert(*contract_id);
+        }
+    }
+    index
+}
+
+fn script_key(script: &Script) -> ChunkId {
+    ChunkId::with_fixed_fragments(db::SCRIPT_UTXOS, script)
+}
+
+fn spent_key(outpoint: OutPoint) -> ChunkId {
+    ChunkId::with_fixed_fragments(outpoint.txid, outpoint.vout)
+}
+
+impl Runtime {
+    /// Scans the chain for the wallet UTXOs, continuing the discovery of the
+    /// used addresses from the last known used index unless `rescan` is set.
+    pub(super) fn sync_wallet(
+        &mut self,
+        descriptor: &str,
+        gap_limit: u16,
+        rescan: bool,
+    ) -> Result<WalletBalance, DaemonError> {
+        let keychains = keychains(descriptor)?;
+        let key = wallet_key(&keychains);
+        let known: Option<WalletState> = self.store.retrieve_sten(db::WALLETS, key)?;
+        let known_utxos = known.as_ref().map(|wallet| wallet.utxos.clone()).unwrap_or_default();
+        #[cfg(feature = "bdk-wallet")]
+        if self.bdk_wallets.contains(key) {
+            debug!("Synchronizing wallet {} with BDK", keychains[0]);
+            let wallet = self.bdk_wallets.sync(key, gap_limit)?;
+            self.index_scripts(&keychains, &wallet)?;
+            self.track_spends(key, &keychains, known_utxos, &wallet)?;
+            self.store.store_sten(db::WALLETS, key, &wallet)?;
+            return self.balance(&wallet);
+        }
+        let mut wallet: WalletState = match rescan {
+            true => default!(),
+            false => known.unwrap_or_default(),
+        };
+        wallet.gap_limit = gap_limit.max(1);
+        wallet.utxos = bset! {};
+
+        for (no, keychain) in keychains.iter().enumerate() {
+            let keychain_no = no as u8;
+            let last_used = wallet.last_used.get(&keychain_no).copied();
+            let last_used = match self.discover(keychain, last_used, wallet.gap_limit)? {
+                Some(last_used) => last_used,
+                None => continue,
+            };
+            debug!("Keychain {} has last used index {}", keychain, last_used);
+            wallet.last_used.insert(keychain_no, last_used);
+
+            let scripts = (0..=last_used).map(|index| script(keychain, index)).collect::<Vec<_>>();
+            for (batch_no, batch) in scripts.chunks(ELECTRUM_BATCH).enumerate() {
+                let unspent = self
+                    .electrum
+                    .call(|client| client.batch_script_list_unspent(batch))
+                    .map_err(WalletError::from)?;
+                for (offset, list) in unspent.into_iter().enumerate() {
+                    let index = (batch_no * ELECTRUM_BATCH + offset) as u32;
+                    let utxos = list
+                        .into_iter()
+                        .map(|item| WalletUtxo {
+                            outpoint: OutPoint::new(item.tx_hash, item.tx_pos as u32),
+                            value: item.value,
+                            keychain: keychain_no,
+                            index,
+                            height: item.height as u32,
+                            confirmations: 0,
+                        })
+                        .collect::<BTreeSet<_>>();
+                    // Script index is overwritten even with no UTXOs left, such that spent
+                    // outpoints are not reported for the script anymore
+                    let outpoints = utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+                    self.store.store_sten(
+                        db::SCRIPT_UTXOS,
+                        script_key(&batch[offset]),
+                        &outpoints,
+                    )?;
+                    wallet.utxos.extend(utxos);
+                }
+            }
+        }
+
+        self.track_spends(key, &keychains, known_utxos, &wallet)?;
+        self.store.store_sten(db::WALLETS, key, &wallet)?;
+        self.store.store_sten(db::WALLET_DESCRIPTORS, key, &descriptor.trim().to_owned())?;
+        self.balance(&wallet)
+    }
+
+    /// Records spent status of the wallet UTXOs which have left the UTXO set
+    /// since the previous synchronization. Outpoints spent by transactions in
+    /// mempool are kept pending and are checked again on the next
+    /// synchronization, until the spending transaction gets mined.
+    fn track_spends(
+        &mut self,
+        key: ChunkId,
+        keychains: &[Keychain],
+        known_utxos: BTreeSet<WalletUtxo>,
+        wallet: &WalletState,
+    ) -> Result<(), DaemonError> {
+        let unspent = wallet.utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+        let pending: BTreeSet<WalletUtxo> =
+            self.store.retrieve_sten(db::PENDING_SPENDS, key)?.unwrap_or_default();
+        let mut still_pending = bset! {};
+        for utxo in known_utxos.into_iter().chain(pending) {
+            if unspent.contains(&utxo.outpoint) {
+                continue;
+            }
+            let keychain = match keychains.get(utxo.keychain as usize) {
+                Some(keychain) => keychain,
+                None => continue,
+            };
+            let status = match self.spender(&script(keychain, utxo.index), utxo.outpoint)? {
+                Some((txid, 0)) => {
+                    still_pending.insert(utxo);
+                    SpentStatus::Mempool(txid)
+                }
+                Some((txid, height)) => SpentStatus::Spent { txid, height },
+                // The transaction creating the output was dropped from mempool or reorged out
+                None => {
+                    self.store.wipe_chunk(db::SPENT_OUTPOINTS, spent_key(utxo.outpoint))?;
+                    continue;
+                }
+            };
+            trace!("Wallet outpoint {} is {}", utxo.outpoint, status);
+            self.store.store_sten(db::SPENT_OUTPOINTS, spent_key(utxo.outpoint), &status)?;
+        }
+        self.store.store_sten(db::PENDING_SPENDS, key, &still_pending)?;
+        Ok(())
+    }
+
+    /// Finds transaction spending the outpoint in the history of the script,
+    /// returning its txid and the height of the block mining it, which is zero
+    /// for the transactions in mempool.
+    pub(super) fn spender(
+        &self,
+        script: &Script,
+        outpoint: OutPoint,
+    ) -> Result<Option<(Txid, u32)>, WalletError> {
+        let history = self.electrum.call(|client| client.script_get_history(script))?;
+        for item in history.into_iter().filter(|item| item.tx_hash != outpoint.txid) {
+            let tx = self
+                .electrum
+                .call(|client| client.transaction_get(&item.tx_hash))
+                .map_err(|_| WalletError::UnresolvedTx(item.tx_hash))?;
+            if tx.input.iter().any(|input| input.previous_output == outpoint) {
+                return Ok(Some((item.tx_hash, item.height.max(0) as u32)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Reports spent status of the outpoints as found during the last
+    /// synchronization of the wallets registered with the node. Outpoints
+    /// which never belonged to these wallets are not reported.
+    pub(super) fn spent_status(
+        &mut self,
+        outpoints: impl IntoIterator<Item = OutPoint>,
+    ) -> Result<BTreeMap<OutPoint, SpentStatus>, DaemonError> {
+        let utxos = self.wallet_utxos()?;
+        let mut spent = bmap! {};
+        for outpoint in outpoints {
+            if utxos.contains(&outpoint) {
+                spent.insert(outpoint, SpentStatus::Unspent);
+            } else if let Some(status) =
+                self.store.retrieve_sten(db::SPENT_OUTPOINTS, spent_key(outpoint))?
+            {
+                spent.insert(outpoint, status);
+            }
+        }
+        Ok(spent)
+    }
+
+    /// Adds spent status of the outpoints and the fungible amounts assigned
+    /// to them to their state, as negotiated with the client
+    pub(super) fn outpoint_status(
+        &mut self,
+        state: ContractStateMap,
+        spent_status: bool,
+        decimal_amounts: bool,
+    ) -> Result<OutpointStatus, DaemonError> {
+        let spent = match spent_status {
+            true => {
+                let outpoints =
+                    state.values().flat_map(BTreeMap::keys).copied().collect::<BTreeSet<_>>();
+                self.spent_status(outpoints)?
+            }
+            false => empty!(),
+        };
+        let amounts = match decimal_amounts {
+            true => self.decimal_amounts(&state)?,
+            false => empty!(),
+        };
+        Ok(OutpointStatus {
+            state,
+            spent,
+            amounts,
+        })
+    }
+
+    /// Adds spent status of the outpoints to the allocations
+    pub(super) fn allocation_status(
+        &mut self,
+        allocations: Vec<ContractAllocation>,
+    ) -> Result<AllocationStatus, DaemonError> {
+        let outpoints =
+            allocations.iter().map(|allocation| allocation.outpoint).collect::<BTreeSet<_>>();
+        let spent = self.spent_status(outpoints)?;
+        Ok(AllocationStatus { allocations, spent })
+    }
+
+    /// Registers wallet with the node without scanning the chain. Wallets
+    /// which were created or synchronized before are left intact.
+    pub(super) fn create_wallet(&mut self, descriptor: &str) -> Result<(), DaemonError> {
+        let keychains = keychains(descriptor)?;
+        // Descriptors which can't produce addresses are rejected early
+        address(&keychains[0], 0, network(&self.chain)?)?;
+        let key = wallet_key(&keychains);
+        self.store.store_sten(db::WALLET_DESCRIPTORS, key, &descriptor.trim().to_owned())?;
+        info!("Wallet {} is created", keychains[0]);
+        Ok(())
+    }
+
+    /// Lists wallets created or synchronized with the node
+    pub(super) fn list_wallets(&mut self) -> Result<Vec<WalletInfo>, DaemonError> {
+        let mut wallets = vec![];
+        for key in self.store.ids(db::WALLET_DESCRIPTORS)? {
+            let descriptor: String = match self.store.retrieve_sten(db::WALLET_DESCRIPTORS, key)? {
+                Some(descriptor) => descriptor,
+                None => continue,
+            };
+            let wallet: WalletState =
+                self.store.retrieve_sten(db::WALLETS, key)?.unwrap_or_default();
+            wallets.push(WalletInfo {
+                descriptor,
+                last_used: wallet.last_used,
+                utxo_count: wallet.utxos.len() as u32,
+                btc_amount: wallet.utxos.iter().map(|utxo| utxo.value).sum(),
+            });
+        }
+        wallets.sort_by(|a, b| a.descriptor.cmp(&b.descriptor));
+        Ok(wallets)
+    }
+
+    /// Lists allocations of the contract state, flagging the allocations on
+    /// the wallet UTXOs found during the last synchronization of the wallets
+    /// registered with the node as owned.
+    pub(super) fn contract_allocations(
+        &mut self,
+        contract_id: ContractId,
+    ) -> Result<Vec<ContractAllocation>, DaemonError> {
+        let state = self
+            .state_cache
+            .get(&mut self.store, contract_id)?
+            .ok_or(StashError::StateAbsent(contract_id))?;
+        let precision = self.contract_precision(contract_id)?;
+        let utxos = self.wallet_utxos()?;
+        let allocations = state
+            .all_outpoint_state()
+            .into_iter()
+            .flat_map(|(outpoint, set)| set.into_iter().map(move |assigned| (outpoint, assigned)))
+            .map(|(outpoint, assigned)| ContractAllocation {
+                outpoint,
+                node_id: assigned.node_outpoint.node_id,
+                right_type: assigned.node_outpoint.ty,
+                no: assigned.node_outpoint.no,
+                amount: match assigned.state {
+                    StateAtom::Value(revealed) => {
+                        Some(DecimalAmount::with(revealed.value, precision))
+                    }
+                    _ => None,
+                },
+                owned: utxos.contains(&outpoint),
+            })
+            .collect();
+        Ok(allocations)
+    }
+
+    /// Collects UTXOs of all wallets registered with the node, as found
+    /// during their last synchronization
+    fn wallet_utxos(&mut self) -> Result<BTreeSet<OutPoint>, DaemonError> {
+        let mut utxos = bset! {};
+        for key in self.store.ids(db::WALLET_DESCRIPTORS)? {
+            if let Some(wallet) = self.store.retrieve_sten::<WalletState>(db::WALLETS, key)? {
+                utxos.extend(wallet.utxos.into_iter().map(|utxo| utxo.outpoint));
+            }
+        }
+        Ok(utxos)
+    }
+
+    /// Lists wallet addresses up to the last used derivation index of each
+    /// keychain, followed by `unused` addresses, together with the UTXOs found
+    /// during the last synchronization and the contracts having state assigned
+    /// to them.
+    pub(super) fn wallet_addresses(
+        &mut self,
+        descriptor: &str,
+        unused: u16,
+    ) -> Result<Vec<WalletAddress>, DaemonError> {
+        let keychains = keychains(descriptor)?;
+        let network = network(&self.chain)?;
+        let key = wallet_key(&keychains);
+        let wallet: Option<WalletState> = self.store.retrieve_sten(db::WALLETS, key)?;
+        let created: Option<String> = self.store.retrieve_sten(db::WALLET_DESCRIPTORS, key)?;
+        let wallet = match (wallet, created) {
+            (Some(wallet), _) => wallet,
+            (None, Some(_)) => default!(),
+            (None, None) => return Err(WalletError::UnknownWallet.into()),
+        };
+
+        let outpoints = wallet.utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+        // Empty outpoint set means "all outpoints" for the outpoint state request
+        let allocations = match outpoints.is_empty() {
+            true => bmap! {},
+            false => self.outpoint_state(outpoints)?,
+        };
+        let contracts = outpoint_contracts(&allocations);
+
+        let mut addresses = vec![];
+        for (no, keychain) in keychains.iter().enumerate() {
+            let keychain_no = no as u8;
+            let count = match wallet.last_used.get(&keychain_no) {
+                Some(last_used) => last_used.saturating_add(1).saturating_add(unused as u32),
+                None => unused as u32,
+            };
+            let count = match keychain.has_wildcard() {
+                true => count.min(HARDENED_INDEX),
+                false => count.min(1),
+            };
+            for index in 0..count {
+                let utxos = wallet
+                    .utxos
+                    .iter()
+                    .filter(|utxo| utxo.keychain == keychain_no && utxo.index == index)
+                    .map(|utxo| utxo.outpoint)
+                    .collect::<BTreeSet<_>>();
+                let contracts = utxos
+                    .iter()
+                    .filter_map(|outpoint| contracts.get(outpoint))
+                    .flatten()
+                    .copied()
+                    .collect();
+                addresses.push(WalletAddress {
+                    keychain: keychain_no,
+                    index,
+                    address: address(keychain, index, network)?,
+                    utxos,
+                    contracts,
+                });
+            }
+        }
+        Ok(addresses)
+    }
+
+    /// Reports balance of a previously synchronized wallet without querying
+    /// the chain for the new UTXOs.
+    pub(super) fn wallet_balance(
+        &mut self,
+        descriptor: &str,
+    ) -> Result<WalletBalance, DaemonError> {
+        let key = wallet_key(&keychains(descriptor)?);
+        let wallet: WalletState =
+            self.store.retrieve_sten(db::WALLETS, key)?.ok_or(WalletError::UnknownWallet)?;
+        self.balance(&wallet)
+    }
+
+    /// Reports state of the outpoints given directly or by the scripts
+    /// controlling them. Scripts are resolved into the UTXOs found during the
+    /// last synchronization of the wallets having these scripts.
+    pub(super) fn query_outpoint_state(
+        &mut self,
+        mut outpoints: BTreeSet<OutPoint>,
+        scripts: BTreeSet<Script>,
+    ) -> Result<ContractStateMap, DaemonError> {
+        if scripts.is_empty() {
+            return self.outpoint_state(outpoints);
+        }
+        for script in &scripts {
+            let utxos: Option<BTreeSet<OutPoint>> =
+                self.store.retrieve_sten(db::SCRIPT_UTXOS, script_key(script))?;
+            outpoints.extend(utxos.unwrap_or_default());
+        }
+        // Empty outpoint set means "all outpoints" for the outpoint state request
+        match outpoints.is_empty() {
+            true => Ok(bmap! {}),
+            false => self.outpoint_state(outpoints),
+        }
+    }
+
+    /// Expands wallet outpoint filter into the wallet UTXOs found during the
+    /// last wallet synchronization within the filter derivation index range.
+    /// Other filters are returned unchanged.
+    pub(super) fn expand_outpoint_filter(
+        &mut self,
+        filter: OutpointFilter,
+    ) -> Result<OutpointFilter, DaemonError> {
+        let descriptor = match &filter {
+            OutpointFilter::Wallet { descriptor, .. } => descriptor.clone(),
+            _ => return Ok(filter),
+        };
+        let key = wallet_key(&keychains(&descriptor)?);
+        let wallet: WalletState =
+            self.store.retrieve_sten(db::WALLETS, key)?.ok_or(WalletError::UnknownWallet)?;
+        let outpoints = wallet
+            .utxos
+            .iter()
+            .filter(|utxo| filter.includes_index(utxo.index))
+            .map(|utxo| utxo.outpoint)
+            .collect::<BTreeSet<_>>();
+        debug!("Wallet outpoint filter is expanded into {} outpoint(s)", outpoints.len());
+        Ok(OutpointFilter::Only(outpoints))
+    }
+
+    /// Returns scripts of the previously synchronized wallet up to the last
+    /// used derivation index of each keychain.
+    pub(super) fn wallet_scripts(
+        &mut self,
+        descriptor: &str,
+    ) -> Result<BTreeSet<Script>, DaemonError> {
+        let keychains = keychains(descriptor)?;
+        let wallet: WalletState = self
+            .store
+            .retrieve_sten(db::WALLETS, wallet_key(&keychains))?
+            .ok_or(WalletError::UnknownWallet)?;
+        let scripts = keychains
+            .iter()
+            .enumerate()
+            .filter_map(|(no, keychain)| {
+                let last_used = wallet.last_used.get(&(no as u8))?;
+                Some((0..=*last_used).map(|index| script(keychain, index)))
+            })
+            .flatten()
+            .collect();
+        Ok(scripts)
+    }
+
+    /// Indexes UTXOs of the wallet synchronized by BDK by the scripts up to
+    /// the last used derivation index of each keychain.
+    #[cfg(feature = "bdk-wallet")]
+    fn index_scripts(
+        &mut self,
+        keychains: &[Keychain],
+        wallet: &WalletState,
+    ) -> Result<(), DaemonError> {
+        for (no, keychain) in keychains.iter().enumerate() {
+            let keychain_no = no as u8;
+            let last_used = match wallet.last_used.get(&keychain_no) {
+                Some(last_used) => *last_used,
+                None => continue,
+            };
+            for index in 0..=last_used {
+                let outpoints = wallet
+                    .utxos
+                    .iter()
+                    .filter(|utxo| utxo.keychain == keychain_no && utxo.index == index)
+                    .map(|utxo| utxo.outpoint)
+                    .collect::<BTreeSet<_>>();
+                self.store.store_sten(
+                    db::SCRIPT_UTXOS,
+                    script_key(&script(keychain, index)),
+                    &outpoints,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds last derivation index having transaction history, starting from
+    /// the index after the known last used one and stopping after `gap_limit`
+    /// unused indexes in a row.
+    fn discover(
+        &self,
+        keychain: &Keychain,
+        mut last_used: Option<u32>,
+        gap_limit: u16,
+    ) -> Result<Option<u32>, WalletError> {
+        if !keychain.has_wildcard() {
+            let script = script(keychain, 0);
+            let history = self.electrum.call(|client| client.script_get_history(&script))?;
+            return Ok(if history.is_empty() { last_used } else { Some(0) });
+        }
+
+        loop {
+            let from = last_used.map(|index| index + 1).unwrap_or_default();
+            let till = from.saturating_add(gap_limit as u32).min(HARDENED_INDEX);
+            if from >= till {
+                return Ok(last_used);
+            }
+            let scripts = (from..till).map(|index| script(keychain, index)).collect::<Vec<_>>();
+            let mut found = None;
+            for (batch_no, batch) in scripts.chunks(ELECTRUM_BATCH).enumerate() {
+                let histories =
+                    self.electrum.call(|client| client.batch_script_get_history(batch))?;
+                if let Some(pos) = histories.iter().rposition(|history| !history.is_empty()) {
+                    found = Some(from + (batch_no * ELECTRUM_BATCH + pos) as u32);
+                }
+            }
+            match found {
+                Some(index) => last_used = Some(index),
+                None => return Ok(last_used),
+            }
+        }
+    }
+
+    fn balance(&mut self, wallet: &WalletState) -> Result<WalletBalance, DaemonError> {
+        let tip = self
+            .electrum
+            .call(|client| client.block_headers_subscribe())
+            .map_err(WalletError::from)?;
+        let height = tip.height as u32;
+        let utxos = wallet
+            .utxos
+            .iter()
+            .cloned()
+            .map(|mut utxo| {
+                utxo.confirmations = match utxo.height {
+                    0 => 0,
+                    mined => height.saturating_sub(mined) + 1,
+                };
+                utxo
+            })
+            .collect::<BTreeSet<_>>();
+        let outpoints = utxos.iter().map(|utxo| utxo.outpoint).collect::<BTreeSet<_>>();
+        // Empty outpoint set means "all outpoints" for the outpoint state request
+        let allocations = match outpoints.is_empty() {
+            true => bmap! {},
+            false => self.outpoint_state(outpoints)?,
+        };
+        let amounts = self.decimal_amounts(&allocations)?;
+        Ok(WalletBalance {
+            height,
+            utxos,
+            allocations,
+            amounts,
+        })
+    }
+}
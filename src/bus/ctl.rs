@@ -8,24 +8,33 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
-use bitcoin::{OutPoint, Txid};
+use bitcoin::hashes::sha256;
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
+use bp::seals::txout::CloseMethod;
 use internet2::addr::NodeAddr;
 use microservices::esb::ClientId;
 use psbt::Psbt;
 use rgb::schema::TransitionType;
 use rgb::{
-    validation, ConsignmentId, ConsignmentType, ContractConsignment, ContractId, InmemConsignment,
-    SealEndpoint, StateTransfer, TransferConsignment,
+    seal, validation, AtomicValue, BundleId, ConsignmentId, ConsignmentType, ContractConsignment,
+    ContractId, InmemConsignment, NodeId, Schema, SealEndpoint, StateTransfer, TransferConsignment,
 };
-use rgb_rpc::{OutpointFilter, Reveal};
-use storm::ContainerId;
+use rgb_rpc::{
+    CacheStats, Checkpoint, InvoiceTolerance, IssueSpec, NetworkMagic, NodeEvent, OutpointFilter,
+    PayjoinContribution, PayjoinProposal, ProofBundle, Reveal, StoreRecovery, SwapAcceptance,
+    SwapOffer, SwapTerms,
+};
+use storm::{ChunkId, ContainerId};
+
+use crate::bus::DaemonId;
 
 /// RPC API requests over CTL message bus between RGB Node daemons.
 #[derive(Clone, Debug, Display, From)]
 #[derive(NetworkEncode, NetworkDecode)]
 #[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum CtlMsg {
     #[display("hello()")]
     Hello,
@@ -39,9 +48,30 @@ pub enum CtlMsg {
     #[display("process_disclosure({0})")]
     ProcessDisclosure(ProcessDisclosureReq),
 
+    #[display(inner)]
+    IssueContract(IssueContractReq),
+
+    #[display("preview_{0}")]
+    PreviewIssue(IssueContractReq),
+
+    #[display(inner)]
+    ImportContract(ImportContractReq),
+
+    #[display(inner)]
+    RevealSeal(RevealSealReq),
+
+    #[display(inner)]
+    CreateInvoice(CreateInvoiceReq),
+
+    #[display(inner)]
+    ExportReveals(ExportRevealsReq),
+
     #[display("process_transfer_container({0})")]
     ProcessTransferContainer(ContainerId),
 
+    #[display(inner)]
+    ProveTransactions(ProveTransactionsReq),
+
     #[display("consign_contract({0})")]
     ConsignContract(ConsignReq<ContractConsignment>),
 
@@ -57,6 +87,142 @@ pub enum CtlMsg {
     #[display(inner)]
     FinalizeTransfers(FinalizeTransfersReq),
 
+    #[display(inner)]
+    PreviewTransfer(PreviewTransferReq),
+
+    #[display(inner)]
+    SendTransfer(SendTransferReq),
+
+    #[display(inner)]
+    SyncWallet(SyncWalletReq),
+
+    #[display(inner)]
+    WalletBalance(WalletBalanceReq),
+
+    #[display(inner)]
+    CreateWallet(CreateWalletReq),
+
+    #[display(inner)]
+    ListWallets(ListWalletsReq),
+
+    #[display("list_schemata({0})")]
+    ListSchemata(ClientId),
+
+    #[display(inner)]
+    WalletAddresses(WalletAddressesReq),
+
+    #[display(inner)]
+    ReplayBalance(ReplayBalanceReq),
+
+    #[display(inner)]
+    ContractStats(ContractStatsReq),
+
+    #[display(inner)]
+    ContractDeps(ContractDepsReq),
+
+    #[display(inner)]
+    ContractAllocations(ContractAllocationsReq),
+
+    #[display(inner)]
+    StorageUsage(StorageUsageReq),
+
+    #[display(inner)]
+    StateDigest(StateDigestReq),
+
+    #[display(inner)]
+    DiffHistory(DiffHistoryReq),
+
+    #[display("commit_stash({0})")]
+    CommitStash(ClientId),
+
+    #[display(inner)]
+    CreateCheckpoint(CreateCheckpointReq),
+
+    #[display(inner)]
+    ConsolidateAllocations(ConsolidateAllocationsReq),
+
+    #[display(inner)]
+    ProposeSwap(ProposeSwapReq),
+
+    #[display(inner)]
+    AcceptSwap(AcceptSwapReq),
+
+    #[display(inner)]
+    CompleteSwap(CompleteSwapReq),
+
+    #[display(inner)]
+    ProposePayjoin(ProposePayjoinReq),
+
+    #[display(inner)]
+    ContributePayjoin(ContributePayjoinReq),
+
+    #[display(inner)]
+    CompletePayjoin(CompletePayjoinReq),
+
+    #[display(inner)]
+    ComposePayment(ComposePaymentReq),
+
+    #[display(inner)]
+    PublishTx(PublishTxReq),
+
+    #[display(inner)]
+    AccelerateTransfer(AccelerateTransferReq),
+
+    #[display(inner)]
+    ForgetSpent(ForgetSpentOutpointsReq),
+
+    /// Signing of the PSBT by the external signer, which may wait for the
+    /// signer reply for a long time and thus is never done by RGBd itself
+    #[display(inner)]
+    SignPsbt(SignPsbtReq),
+
+    /// Signing of the contract checkpoint by the issuer signer, served by a
+    /// bucket daemon for the same reason as [`CtlMsg::SignPsbt`]
+    #[display(inner)]
+    SignCheckpoint(SignCheckpointReq),
+
+    /// Submission of the stash commitment to the timestamping calendar, served
+    /// by a bucket daemon to keep RGBd responsive while the calendar replies
+    #[display(inner)]
+    Timestamp(TimestampReq),
+
+    #[display("approve_quarantined({0})")]
+    ApproveQuarantined(QuarantineReq),
+
+    #[display("revalidate_quarantined({0})")]
+    RevalidateQuarantined(QuarantineReq),
+
+    #[display("collect_garbage({0})")]
+    CollectGarbage(ClientId),
+
+    #[display(inner)]
+    PruneData(PruneDataReq),
+
+    #[display(inner)]
+    ForgetContract(ForgetContractReq),
+
+    #[display(inner)]
+    RewindContract(RewindContractReq),
+
+    #[display(inner)]
+    ReplayConsignments(ReplayConsignmentsReq),
+
+    #[display(inner)]
+    RebuildState(RebuildStateReq),
+
+    #[display(inner)]
+    RevalidateContracts(RevalidateContractsReq),
+
+    /// Completes or rolls back the consignments which were not completely
+    /// stored when the node has stopped. Queued by RGBd at the start.
+    #[display("recover_store()")]
+    RecoverStore,
+
+    /// Counts contracts validated by an older version of the validation
+    /// rules. Queued by RGBd at the start.
+    #[display("check_validations()")]
+    CheckValidations,
+
     #[display(inner)]
     #[from]
     Validity(ValidityResp),
@@ -64,17 +230,139 @@ pub enum CtlMsg {
     #[display("processing_complete()")]
     ProcessingComplete,
 
+    /// Contract states updated by a bucket daemon, which must be dropped from
+    /// the state caches of other bucket daemons, and the outpoints it has
+    /// indexed, which must be added to their outpoint filters.
+    #[display("invalidate_states(...)")]
+    InvalidateStates(StateUpdates),
+
+    /// Events produced by a bucket daemon, which are published by RGBd to the
+    /// event subscribers.
+    #[display("events(...)")]
+    Events(Vec<NodeEvent>),
+
+    /// Repairs of the store made by a bucket daemon, which are reported by
+    /// RGBd in the node health.
+    #[display("store_recovered({0})")]
+    StoreRecovered(StoreRecovery),
+
+    /// Number of contracts validated by an older version of the validation
+    /// rules, which is reported by RGBd in the node health.
+    #[display("validations_checked({0})")]
+    ValidationsChecked(u32),
+
+    /// Cache hits and misses of a bucket daemon since its start, which are
+    /// reported by RGBd in the node health.
+    #[display("cache_stats({0})")]
+    CacheStats(CacheStats),
+
     #[display("processing_failed()")]
     ProcessingFailed,
+
+    /// Clients which have negotiated compression of RPC messages. Sent by
+    /// RGBd to bucket daemons, replacing the previously known set.
+    #[display("compressed_clients(...)")]
+    CompressedClients(BTreeSet<ClientId>),
+
+    /// Worker daemon which has terminated. Sent by the supervisor to RGBd,
+    /// which fails the task processed by the daemon.
+    #[display("daemon_terminated({0})")]
+    DaemonTerminated(DaemonId),
+
+    /// Request of the client which must be interrupted. Sent by RGBd to the
+    /// interrupt listener of the bucket daemon processing the request.
+    #[display("cancel({0})")]
+    Cancel(ClientId),
+}
+
+impl CtlMsg {
+    /// Client which has requested the task, if any
+    pub fn client_id(&self) -> Option<ClientId> {
+        Some(match self {
+            CtlMsg::ProcessContract(ProcessReq { client_id, .. })
+            | CtlMsg::ProcessTransfer(ProcessReq { client_id, .. }) => *client_id,
+            CtlMsg::ProcessDisclosure(req) => req.client_id,
+            CtlMsg::IssueContract(req) | CtlMsg::PreviewIssue(req) => req.client_id,
+            CtlMsg::ImportContract(req) => req.client_id,
+            CtlMsg::RevealSeal(req) => req.client_id,
+            CtlMsg::CreateInvoice(req) => req.client_id,
+            CtlMsg::ExportReveals(req) => req.client_id,
+            CtlMsg::ProveTransactions(req) => req.client_id,
+            CtlMsg::ConsignContract(ConsignReq { client_id, .. })
+            | CtlMsg::ConsignTranfer(ConsignReq { client_id, .. }) => *client_id,
+            CtlMsg::OutpointState(req) => req.client_id,
+            CtlMsg::FinalizeTransfer(req) => req.client_id,
+            CtlMsg::FinalizeTransfers(req) => req.client_id,
+            CtlMsg::PreviewTransfer(req) => req.client_id,
+            CtlMsg::SendTransfer(req) => req.client_id,
+            CtlMsg::SyncWallet(req) => req.client_id,
+            CtlMsg::WalletBalance(req) => req.client_id,
+            CtlMsg::CreateWallet(req) => req.client_id,
+            CtlMsg::ListWallets(req) => req.client_id,
+            CtlMsg::ListSchemata(client_id) => *client_id,
+            CtlMsg::WalletAddresses(req) => req.client_id,
+            CtlMsg::ReplayBalance(req) => req.client_id,
+            CtlMsg::ContractStats(req) => req.client_id,
+            CtlMsg::ContractDeps(req) => req.client_id,
+            CtlMsg::ContractAllocations(req) => req.client_id,
+            CtlMsg::StorageUsage(req) => req.client_id,
+            CtlMsg::StateDigest(req) => req.client_id,
+            CtlMsg::DiffHistory(req) => req.client_id,
+            CtlMsg::CommitStash(client_id) => *client_id,
+            CtlMsg::CreateCheckpoint(req) => req.client_id,
+            CtlMsg::ConsolidateAllocations(req) => req.client_id,
+            CtlMsg::ProposeSwap(req) => req.client_id,
+            CtlMsg::AcceptSwap(req) => req.client_id,
+            CtlMsg::CompleteSwap(req) => req.client_id,
+            CtlMsg::ProposePayjoin(req) => req.client_id,
+            CtlMsg::ContributePayjoin(req) => req.client_id,
+            CtlMsg::CompletePayjoin(req) => req.client_id,
+            CtlMsg::ComposePayment(req) => req.client_id,
+            CtlMsg::PublishTx(req) => req.client_id,
+            CtlMsg::AccelerateTransfer(req) => req.client_id,
+            CtlMsg::ForgetSpent(req) => req.client_id,
+            CtlMsg::SignPsbt(req) => req.client_id,
+            CtlMsg::SignCheckpoint(req) => req.client_id,
+            CtlMsg::Timestamp(req) => req.client_id,
+            CtlMsg::ApproveQuarantined(req) | CtlMsg::RevalidateQuarantined(req) => req.client_id,
+            CtlMsg::CollectGarbage(client_id) => *client_id,
+            CtlMsg::PruneData(req) => return req.client_id,
+            CtlMsg::ForgetContract(req) => req.client_id,
+            CtlMsg::RewindContract(req) => req.client_id,
+            CtlMsg::ReplayConsignments(req) => req.client_id,
+            CtlMsg::RebuildState(req) => req.client_id,
+            CtlMsg::RevalidateContracts(req) => req.client_id,
+            CtlMsg::Validity(resp) => resp.client_id,
+            _ => return None,
+        })
+    }
+
+    /// UNIX timestamp after which the task must be abandoned, if any
+    pub fn deadline(&self) -> Option<u64> {
+        match self {
+            CtlMsg::ProcessContract(ProcessReq { deadline, .. })
+            | CtlMsg::ProcessTransfer(ProcessReq { deadline, .. }) => *deadline,
+            CtlMsg::ConsignContract(ConsignReq { deadline, .. })
+            | CtlMsg::ConsignTranfer(ConsignReq { deadline, .. }) => *deadline,
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Display, StrictEncode, StrictDecode)]
-#[display("{client_id}, force = {force}, ...")]
+#[display("{client_id}, force = {force}, full = {full}, ...")]
 pub struct ProcessReq<T: ConsignmentType> {
     pub client_id: ClientId,
     pub consignment: InmemConsignment<T>,
     pub force: bool,
+    pub full: bool,
     pub reveal: Option<Reveal>,
+    /// UNIX timestamp after which the request must be abandoned
+    pub deadline: Option<u64>,
+    /// Proofs of the witness transactions replacing the chain backend
+    pub proofs: Option<ProofBundle>,
+    /// Whether the client receives incremental progress of the processing
+    pub progress: bool,
 }
 
 #[derive(Clone, Debug, Display, StrictEncode, StrictDecode)]
@@ -84,6 +372,58 @@ pub struct ProcessDisclosureReq {
     pub txid: Txid,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("issue_contract({client_id}, {spec})")]
+pub struct IssueContractReq {
+    pub client_id: ClientId,
+    pub spec: IssueSpec,
+    pub schema: Option<Schema>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("import_contract({client_id}, ...)")]
+pub struct ImportContractReq {
+    pub client_id: ClientId,
+    pub genesis: String,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("reveal_seal({client_id}, {contract_id}, ...)")]
+pub struct RevealSealReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub reveal: Reveal,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("create_invoice({client_id}, {contract_id}, {amount}, {outpoint}, ...)")]
+pub struct CreateInvoiceReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub amount: AtomicValue,
+    pub outpoint: OutPoint,
+    pub close_method: CloseMethod,
+    pub transport: Option<NodeAddr>,
+    pub signet: Option<NetworkMagic>,
+    pub tolerance: InvoiceTolerance,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("export_reveals({client_id}, {contract_id}, ...)")]
+pub struct ExportRevealsReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, StrictEncode, StrictDecode)]
+#[display("prove_transactions({client_id}, ..., {from_height})")]
+pub struct ProveTransactionsReq {
+    pub client_id: ClientId,
+    pub txids: BTreeSet<Txid>,
+    pub from_height: u32,
+}
+
 #[derive(Clone, Debug, Display, StrictEncode, StrictDecode)]
 #[display("{client_id}, {contract_id}, ...")]
 pub struct ConsignReq<T: ConsignmentType> {
@@ -91,6 +431,13 @@ pub struct ConsignReq<T: ConsignmentType> {
     pub contract_id: ContractId,
     pub include: BTreeSet<TransitionType>,
     pub outpoints: OutpointFilter,
+    pub known: BTreeSet<NodeId>,
+    /// UNIX timestamp after which the request must be abandoned
+    pub deadline: Option<u64>,
+    /// Whether the client receives incremental progress of the composition
+    pub progress: bool,
+    /// Whether the consignment is sent to the client in ASCII-armored form
+    pub armored: bool,
     #[strict_encoding(skip)]
     pub _phantom: T,
 }
@@ -103,11 +450,342 @@ pub struct ValidityResp {
     pub status: validation::Status,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Default, StrictEncode, StrictDecode)]
+pub struct StateUpdates {
+    pub contract_ids: BTreeSet<ContractId>,
+    /// Ids of the `OUTPOINTS` table entries added by the bucket daemon
+    pub outpoints: BTreeSet<ChunkId>,
+}
+
+impl StateUpdates {
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.contract_ids.is_empty() && self.outpoints.is_empty() }
+}
+
 #[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
 #[display("outpoint_state({client_id}, ...)")]
 pub struct OutpointStateReq {
     pub client_id: ClientId,
     pub outpoints: BTreeSet<OutPoint>,
+    /// Scripts resolved into the outpoints using the wallet synchronization
+    /// index
+    pub scripts: BTreeSet<Script>,
+    /// Whether the client receives spent status of the outpoints
+    pub spent_status: bool,
+    /// Whether the client receives fungible amounts assigned to the outpoints
+    pub decimal_amounts: bool,
+    /// Contracts which state is reported to the client; all contracts if
+    /// none
+    pub contracts: Option<BTreeSet<ContractId>>,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("sync_wallet({client_id}, ...)")]
+pub struct SyncWalletReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+    pub gap_limit: u16,
+    pub rescan: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("wallet_balance({client_id}, ...)")]
+pub struct WalletBalanceReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("create_wallet({client_id}, ...)")]
+pub struct CreateWalletReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("list_wallets({client_id})")]
+pub struct ListWalletsReq {
+    pub client_id: ClientId,
+    /// Wallets which are reported to the client; all wallets if none
+    pub wallets: Option<BTreeSet<String>>,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("wallet_addresses({client_id}, unused: {unused}, ...)")]
+pub struct WalletAddressesReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+    pub unused: u16,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("replay_balance({client_id}, {contract_id}, {height}, ...)")]
+pub struct ReplayBalanceReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub height: u32,
+    pub descriptor: Option<String>,
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("contract_stats({client_id}, {contract_id})")]
+pub struct ContractStatsReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("contract_deps({client_id}, {contract_id:?})")]
+pub struct ContractDepsReq {
+    pub client_id: ClientId,
+    /// Contract which dependency graph is requested; all known contracts if
+    /// absent
+    pub contract_id: Option<ContractId>,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("contract_allocations({client_id}, {contract_id})")]
+pub struct ContractAllocationsReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    /// Whether the client receives spent status of the allocation outpoints
+    pub spent_status: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("storage_usage({client_id}, {limit})")]
+pub struct StorageUsageReq {
+    pub client_id: ClientId,
+    pub limit: u16,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("state_digest({client_id}, {contract_id})")]
+pub struct StateDigestReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("diff_history({client_id}, {contract_id}, ...)")]
+pub struct DiffHistoryReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub bundles: BTreeSet<BundleId>,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("forget_contract({client_id}, {contract_id}, {tombstone})")]
+pub struct ForgetContractReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub tombstone: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("rewind_contract({client_id}, {contract_id}, {consignment_id})")]
+pub struct RewindContractReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub consignment_id: ConsignmentId,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("replay_consignments({client_id}, {contract_id}, ...)")]
+pub struct ReplayConsignmentsReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub consignments: Vec<ConsignmentId>,
+    /// Whether the client receives incremental progress of the replay
+    pub progress: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("rebuild_state({client_id}, ...)")]
+pub struct RebuildStateReq {
+    pub client_id: ClientId,
+    /// Contract which state is rebuilt; all contracts are rebuilt if absent
+    pub contract_id: Option<ContractId>,
+    /// Whether the client receives incremental progress of the rebuild
+    pub progress: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("revalidate_contracts({client_id}, ...)")]
+pub struct RevalidateContractsReq {
+    pub client_id: ClientId,
+    /// Contract to re-validate; all contracts validated by an older version
+    /// of the validation rules are re-validated if absent
+    pub contract_id: Option<ContractId>,
+    /// Whether the client receives incremental progress of the validation
+    pub progress: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("prune_data(dry_run: {dry_run})")]
+pub struct PruneDataReq {
+    /// Client which has requested pruning; absent when the pruning is
+    /// scheduled by the node itself
+    pub client_id: Option<ClientId>,
+    pub dry_run: bool,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("create_checkpoint({client_id}, {contract_id}, {height})")]
+pub struct CreateCheckpointReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub height: u32,
+}
+
+#[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug, Display, StrictEncode, StrictDecode)]
+#[display("consolidate_allocations({client_id}, {contract_id}, ...)")]
+pub struct ConsolidateAllocationsReq {
+    pub client_id: ClientId,
+    pub contract_id: ContractId,
+    pub outpoints: BTreeSet<OutPoint>,
+    pub seal: seal::Revealed,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("propose_swap({client_id}, {terms}, ...)")]
+pub struct ProposeSwapReq {
+    pub client_id: ClientId,
+    pub terms: SwapTerms,
+    pub timeout: u32,
+    pub psbt: Psbt,
+    pub consignment: Option<StateTransfer>,
+    /// Identity of the authenticated client, which becomes the owner of the
+    /// created record
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("accept_swap({client_id}, {offer}, ...)")]
+pub struct AcceptSwapReq {
+    pub client_id: ClientId,
+    pub offer: SwapOffer,
+    pub psbt: Psbt,
+    pub consignment: Option<StateTransfer>,
+    /// Identity of the authenticated client, which becomes the owner of the
+    /// created record
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("complete_swap({client_id}, {acceptance})")]
+pub struct CompleteSwapReq {
+    pub client_id: ClientId,
+    pub acceptance: SwapAcceptance,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("propose_payjoin({client_id}, ...)")]
+pub struct ProposePayjoinReq {
+    pub client_id: ClientId,
+    pub psbt: Psbt,
+    pub consignment: StateTransfer,
+    /// Identity of the authenticated client, which becomes the owner of the
+    /// created record
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("contribute_payjoin({client_id}, {proposal}, ...)")]
+pub struct ContributePayjoinReq {
+    pub client_id: ClientId,
+    pub proposal: PayjoinProposal,
+    pub descriptor: String,
+    pub outpoints: BTreeSet<OutPoint>,
+    pub endseals: Vec<SealEndpoint>,
+    pub fee: u64,
+    /// Identity of the authenticated client, which becomes the owner of the
+    /// created record
+    pub owner: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("complete_payjoin({client_id}, {contribution})")]
+pub struct CompletePayjoinReq {
+    pub client_id: ClientId,
+    pub contribution: PayjoinContribution,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("compose_payment({client_id}, {contract_id}, ...)")]
+pub struct ComposePaymentReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+    pub contract_id: ContractId,
+    pub beneficiaries: BTreeMap<SealEndpoint, AtomicValue>,
+    pub fee: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("publish_tx({client_id}, ...)")]
+pub struct PublishTxReq {
+    pub client_id: ClientId,
+    pub psbt: Psbt,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("accelerate_transfer({client_id}, {consignment_id}, ...)")]
+pub struct AccelerateTransferReq {
+    pub client_id: ClientId,
+    pub consignment_id: ConsignmentId,
+    pub descriptor: String,
+    pub fee_rate: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("sign_psbt({client_id}, ...)")]
+pub struct SignPsbtReq {
+    pub client_id: ClientId,
+    pub psbt: Psbt,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("sign_checkpoint({client_id}, {checkpoint})")]
+pub struct SignCheckpointReq {
+    pub client_id: ClientId,
+    pub checkpoint: Checkpoint,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("timestamp({client_id}, {digest})")]
+pub struct TimestampReq {
+    pub client_id: ClientId,
+    pub digest: sha256::Hash,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("forget_spent({client_id}, ...)")]
+pub struct ForgetSpentOutpointsReq {
+    pub client_id: ClientId,
+    pub descriptor: String,
+    pub outpoints: BTreeSet<OutPoint>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("{client_id}, {consignment_id}")]
+pub struct QuarantineReq {
+    pub client_id: ClientId,
+    pub consignment_id: ConsignmentId,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
@@ -119,6 +797,7 @@ pub struct FinalizeTransferReq {
     pub endseals: Vec<SealEndpoint>,
     pub psbt: Psbt,
     pub beneficiary: Option<NodeAddr>,
+    pub recipient_key: Option<XOnlyPublicKey>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Display)]
@@ -129,3 +808,23 @@ pub struct FinalizeTransfersReq {
     pub transfers: Vec<(StateTransfer, Vec<SealEndpoint>)>,
     pub psbt: Psbt,
 }
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("send_transfer({client_id}, ..., {beneficiary})")]
+pub struct SendTransferReq {
+    pub client_id: ClientId,
+    pub consignment: StateTransfer,
+    pub beneficiary: NodeAddr,
+    pub recipient_key: Option<XOnlyPublicKey>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+#[derive(NetworkEncode, NetworkDecode)]
+#[display("preview_transfer({client_id}, ...)")]
+pub struct PreviewTransferReq {
+    pub client_id: ClientId,
+    pub consignment: StateTransfer,
+    pub endseals: Vec<SealEndpoint>,
+    pub psbt: Psbt,
+}
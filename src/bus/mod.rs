@@ -12,12 +12,21 @@ mod services;
 mod ctl;
 
 use microservices::rpc;
-use rgb_rpc::RpcMsg;
+use rgb_rpc::RpcEnvelope;
 use storm_ext::ExtMsg as StormMsg;
 
 pub use self::ctl::{
-    ConsignReq, CtlMsg, FinalizeTransferReq, FinalizeTransfersReq, OutpointStateReq,
-    ProcessDisclosureReq, ProcessReq, ValidityResp,
+    AccelerateTransferReq, AcceptSwapReq, CompletePayjoinReq, CompleteSwapReq, ComposePaymentReq,
+    ConsignReq, ConsolidateAllocationsReq, ContractAllocationsReq, ContractDepsReq,
+    ContractStatsReq, ContributePayjoinReq, CreateCheckpointReq, CreateInvoiceReq, CreateWalletReq,
+    CtlMsg, DiffHistoryReq, ExportRevealsReq, FinalizeTransferReq, FinalizeTransfersReq,
+    ForgetContractReq, ForgetSpentOutpointsReq, ImportContractReq, IssueContractReq,
+    ListWalletsReq, OutpointStateReq, PreviewTransferReq, ProcessDisclosureReq, ProcessReq,
+    ProposePayjoinReq, ProposeSwapReq, ProveTransactionsReq, PruneDataReq, PublishTxReq,
+    QuarantineReq, RebuildStateReq, ReplayBalanceReq, ReplayConsignmentsReq,
+    RevalidateContractsReq, RevealSealReq, RewindContractReq, SendTransferReq, SignCheckpointReq,
+    SignPsbtReq, StateDigestReq, StateUpdates, StorageUsageReq, SyncWalletReq, TimestampReq,
+    ValidityResp, WalletAddressesReq, WalletBalanceReq,
 };
 pub use self::services::{DaemonId, ServiceId};
 pub(crate) use self::services::{Endpoints, Responder, ServiceBus};
@@ -26,12 +35,13 @@ pub(crate) use self::services::{Endpoints, Responder, ServiceBus};
 #[derive(Clone, Debug, Display, From, Api)]
 #[api(encoding = "strict")]
 #[display(inner)]
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum BusMsg {
-    /// RPC requests
-    #[api(type = 4)]
+    /// RPC requests in versioned envelopes
+    #[api(type = 7)]
     #[display(inner)]
     #[from]
-    Rpc(RpcMsg),
+    Rpc(RpcEnvelope),
 
     /// CTL requests
     #[api(type = 6)]
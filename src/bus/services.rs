@@ -14,7 +14,7 @@ use internet2::addr::NodeAddr;
 use lnp::p2p::bifrost::{BifrostApp, ChannelId};
 use microservices::esb;
 use microservices::esb::{ClientId, ServiceName};
-use rgb_rpc::RpcMsg;
+use rgb_rpc::{Compression, FailureCode, RpcEnvelope, RpcMsg, VersionError};
 use storm::StormApp;
 use storm_ext::ExtMsg as StormMsg;
 use strict_encoding::{strict_deserialize, strict_serialize};
@@ -44,6 +44,18 @@ pub enum ServiceId {
     #[strict_encoding(value = 0x31)]
     Bucket(DaemonId),
 
+    #[display("supervisor")]
+    #[strict_encoding(value = 0x32)]
+    Supervisor,
+
+    #[display("bucketd<{0}>/interrupts")]
+    #[strict_encoding(value = 0x33)]
+    BucketInterrupts(DaemonId),
+
+    #[display("bucketd<{0}>/progress")]
+    #[strict_encoding(value = 0x34)]
+    BucketProgress(DaemonId),
+
     #[display("peerd<{0}>")]
     #[from]
     #[strict_encoding(value = 0x22)]
@@ -115,6 +127,9 @@ where
     Self: esb::Handler<ServiceBus>,
     esb::Error<ServiceId>: From<Self::Error>,
 {
+    /// Compression which can be applied to the messages sent to the client.
+    fn rpc_compression(&self, _client_id: ClientId) -> Compression { Compression::None }
+
     #[inline]
     fn send_rpc(
         &self,
@@ -122,14 +137,38 @@ where
         client_id: ClientId,
         message: impl Into<RpcMsg>,
     ) -> Result<(), esb::Error<ServiceId>> {
+        let compression = self.rpc_compression(client_id);
         endpoints.send_to(
             ServiceBus::Rpc,
             self.identity(),
             ServiceId::Client(client_id),
-            BusMsg::Rpc(message.into()),
+            BusMsg::Rpc(RpcEnvelope::with_compression(&message.into(), compression)),
         )
     }
 
+    /// Opens versioned RPC envelope received from a client. If the protocol
+    /// version is not supported or the message can't be decoded, replies to
+    /// the client with the failure and returns `None`.
+    fn open_rpc(
+        &self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        envelope: RpcEnvelope,
+    ) -> Option<RpcMsg> {
+        match envelope.open() {
+            Ok(msg) => Some(msg),
+            Err(err) => {
+                warn!("Rejecting request from client {}: {}", client_id, err);
+                let code = match err {
+                    VersionError::Unsupported(_) => FailureCode::UnsupportedVersion,
+                    VersionError::Payload(..) => FailureCode::Encoding,
+                };
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::failure(code, err));
+                None
+            }
+        }
+    }
+
     #[inline]
     fn send_ctl(
         &self,
@@ -0,0 +1,345 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Chain data access used by validation, with a cache of the transactions
+//! fetched from the electrum servers shared between bucket daemons through the
+//! store.
+
+use std::cell::RefCell;
+
+use bitcoin::hashes::{sha256d, Hash};
+use bitcoin::{BlockHash, BlockHeader, Transaction, Txid};
+use bitcoin_onchain::{ResolveTx, TxResolverError};
+use rgb_rpc::TxProof;
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use crate::db::{self, Store, StoreRpcExt};
+use crate::electrum::ElectrumPool;
+use crate::headers::{self, HeaderChainState, HeaderError};
+use crate::lru::LruCache;
+use crate::DaemonError;
+
+/// Transaction which was found mined, as kept in the cache together with its
+/// merkle proof verified against the tracked header chain.
+#[derive(Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct CachedTx {
+    /// Epoch of the cache in which the transaction was found mined
+    pub epoch: u32,
+    pub proof: TxProof,
+}
+
+/// Chain tip which was known at the moment of the last cache use. Epoch is
+/// increased each time the cache is invalidated, making all previously stored
+/// transactions unreachable.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct ChainTip {
+    pub epoch: u32,
+    pub height: u32,
+    pub block_hash: BlockHash,
+}
+
+/// Cache of the chain data fetched from the electrum servers.
+///
+/// Transactions are cached only once they are mined, and are kept both in
+/// memory (up to a configured number of entries) and in the store, such that
+/// they are reused by all bucket daemons. Headers of the tracked header chain
+/// used to verify the merkle proofs are kept in memory. The whole cache is
+/// invalidated once a reorg affecting the previously seen chain tip is
+/// detected, and the transactions stored in the previous epochs are removed.
+pub(crate) struct ChainCache {
+    tip: Option<ChainTip>,
+    txes: LruCache<Txid, CachedTx>,
+    headers: LruCache<u32, BlockHeader>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ChainCache {
+    pub fn with_limit(limit: usize) -> Self {
+        ChainCache {
+            tip: None,
+            txes: LruCache::with_limit(limit),
+            headers: LruCache::with_limit(limit),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn tip_key() -> ChunkId { ChunkId::with_fixed_fragments(db::CHAIN_TIP, 0u8) }
+
+    fn tx_key(&self, txid: Txid) -> ChunkId {
+        ChunkId::with_fixed_fragments(txid, self.tip.map(|tip| tip.epoch).unwrap_or_default())
+    }
+
+    /// Checks the current chain tip against the one seen last time and
+    /// invalidates the cache if the chain got reorganized. Must be called
+    /// before each validation.
+    pub fn sync_tip(
+        &mut self,
+        electrum: &ElectrumPool,
+        store: &mut dyn Store,
+    ) -> Result<(), DaemonError> {
+        let known: Option<ChainTip> = store.retrieve_sten(db::CHAIN_TIP, Self::tip_key())?;
+        // Both requests must be answered by the same server, otherwise a server
+        // lagging behind may be taken as a reorg
+        let res = electrum.call(|client| {
+            let notification = client.block_headers_subscribe()?;
+            let header = known.map(|known| client.block_header(known.height as usize));
+            Ok((notification, header))
+        });
+        let (notification, header) = match res {
+            Ok(res) => res,
+            Err(err) => {
+                warn!("Unable to check chain tip, disabling transaction cache: {}", err);
+                self.tip = None;
+                return Ok(());
+            }
+        };
+
+        let mut epoch = known.map(|tip| tip.epoch).unwrap_or_default();
+        let mut reorg = false;
+        if let (Some(known), Some(header)) = (known, header) {
+            reorg = match header {
+                Ok(header) => header.block_hash() != known.block_hash,
+                Err(_) => true,
+            };
+            if reorg {
+                warn!(
+                    "Chain reorganization detected below height {}; invalidating transaction cache",
+                    known.height
+                );
+                epoch += 1;
+            }
+        }
+        if self.tip.map(|tip| tip.epoch) != Some(epoch) {
+            self.txes.clear();
+            self.headers.clear();
+        }
+
+        let tip = ChainTip {
+            epoch,
+            height: notification.height as u32,
+            block_hash: notification.header.block_hash(),
+        };
+        store.store_sten(db::CHAIN_TIP, Self::tip_key(), &tip)?;
+        self.tip = Some(tip);
+        if reorg {
+            purge_epochs(store, epoch)?;
+        }
+        Ok(())
+    }
+
+    /// Returns resolver for the validation which uses the cache and falls back
+    /// to the electrum server for the missed transactions.
+    pub fn resolver<'runtime>(
+        &'runtime mut self,
+        electrum: &'runtime ElectrumPool,
+        store: &'runtime mut dyn Store,
+    ) -> CachedResolver<'runtime> {
+        CachedResolver {
+            electrum,
+            cache: RefCell::new(self),
+            store: RefCell::new(store),
+        }
+    }
+
+    pub fn stats(&self) -> (u64, u64) { (self.hits, self.misses) }
+
+    /// Height of the chain tip seen by the last [`ChainCache::sync_tip`] call.
+    pub fn tip_height(&self) -> Option<u32> { self.tip.map(|tip| tip.height) }
+
+    /// Returns height at which the transaction was mined, or `None` if the
+    /// transaction is not mined yet.
+    pub fn tx_height(
+        &mut self,
+        electrum: &ElectrumPool,
+        store: &mut dyn Store,
+        txid: Txid,
+    ) -> Result<Option<u32>, TxResolverError> {
+        Ok(self.tx_proof(electrum, store, txid)?.map(|proof| proof.height))
+    }
+
+    /// Returns proof of the transaction inclusion into the block, verified
+    /// against the tracked header chain, or `None` if the transaction is not
+    /// mined yet.
+    pub fn tx_proof(
+        &mut self,
+        electrum: &ElectrumPool,
+        store: &mut dyn Store,
+        txid: Txid,
+    ) -> Result<Option<TxProof>, TxResolverError> {
+        if let Some(cached) = self.cached(store, txid) {
+            self.hits += 1;
+            return Ok(Some(cached.proof));
+        }
+        self.misses += 1;
+        let tx = electrum.call(|client| client.transaction_get(&txid)).map_err(|err| {
+            TxResolverError {
+                txid,
+                err: Some(Box::new(err)),
+            }
+        })?;
+        Ok(self.memorize(electrum, store, &tx))
+    }
+
+    fn cached(&mut self, store: &mut dyn Store, txid: Txid) -> Option<CachedTx> {
+        self.tip?;
+        if let Some(cached) = self.txes.get(&txid) {
+            return Some(cached.clone());
+        }
+        let key = self.tx_key(txid);
+        match store.retrieve_sten::<CachedTx>(db::CHAIN_TXES, key) {
+            Ok(Some(cached)) => {
+                self.txes.insert(txid, cached.clone());
+                Some(cached)
+            }
+            Ok(None) => None,
+            Err(err) => {
+                warn!("Unable to read transaction cache: {}", err);
+                None
+            }
+        }
+    }
+
+    /// Proves that the transaction is mined and caches the proof. The proof
+    /// is returned even if the cache is disabled.
+    fn memorize(
+        &mut self,
+        electrum: &ElectrumPool,
+        store: &mut dyn Store,
+        tx: &Transaction,
+    ) -> Option<TxProof> {
+        // We cache only mined transactions, since the ones in mempool may be replaced
+        let proof = mined_proof(electrum, tx)?;
+        if let Err(err) = self.verify(store, &proof) {
+            warn!("Transaction {} is not considered mined: {}", proof.tx.txid(), err);
+            return None;
+        }
+        let tip = match self.tip {
+            Some(tip) => tip,
+            None => return Some(proof),
+        };
+        let txid = tx.txid();
+        let cached = CachedTx {
+            epoch: tip.epoch,
+            proof,
+        };
+        if let Err(err) = store.store_sten(db::CHAIN_TXES, self.tx_key(txid), &cached) {
+            warn!("Unable to store transaction {} in the cache: {}", txid, err);
+        }
+        self.txes.insert(txid, cached.clone());
+        Some(cached.proof)
+    }
+
+    /// Checks the merkle path of the proof against the block header from the
+    /// tracked header chain. Transactions mined at or below the chain anchor
+    /// are trusted.
+    fn verify(&mut self, store: &mut dyn Store, proof: &TxProof) -> Result<(), HeaderError> {
+        let txid = proof.tx.txid();
+        let header = match self.headers.get(&proof.height) {
+            Some(header) => *header,
+            None => {
+                let state =
+                    HeaderChainState::load(store)?.ok_or(HeaderError::NotSynced(proof.height))?;
+                if proof.height <= state.anchor.height {
+                    return Ok(());
+                }
+                let header = headers::header_at(store, proof.height)?
+                    .ok_or(HeaderError::NotSynced(proof.height))?;
+                self.headers.insert(proof.height, header);
+                header
+            }
+        };
+        if proof.merkle_root()[..] != header.merkle_root[..] {
+            return Err(HeaderError::NotIncluded(txid, proof.height));
+        }
+        Ok(())
+    }
+}
+
+/// Requests merkle proof of the transaction, looking up the height of its
+/// block with the history of its first spendable output script. Height of
+/// the proof is the one reported in the merkle response, which is verified
+/// against the tracked header chain before the proof is used.
+fn mined_proof(electrum: &ElectrumPool, tx: &Transaction) -> Option<TxProof> {
+    let txid = tx.txid();
+    let output = tx.output.iter().find(|out| !out.script_pubkey.is_provably_unspendable())?;
+    let script = &output.script_pubkey;
+    // Both requests are answered by the same server, so the history height
+    // matches the block of the merkle proof unless the chain was reorganized
+    let merkle = electrum
+        .call(|client| {
+            client
+                .script_get_history(script)?
+                .into_iter()
+                .find(|item| item.tx_hash == txid && item.height > 0)
+                .map(|item| client.transaction_get_merkle(&txid, item.height as usize))
+                .transpose()
+        })
+        .ok()??;
+    Some(TxProof {
+        tx: tx.clone(),
+        height: merkle.block_height as u32,
+        pos: merkle.pos as u32,
+        merkle_branch: merkle.merkle.into_iter().map(sha256d::Hash::from_inner).collect(),
+    })
+}
+
+/// Removes transactions cached in the epochs preceding the given one, which
+/// are not reachable anymore.
+fn purge_epochs(store: &mut dyn Store, epoch: u32) -> Result<(), DaemonError> {
+    let mut purged = 0usize;
+    for id in store.ids(db::CHAIN_TXES)? {
+        let stale = match store.retrieve_sten::<CachedTx>(db::CHAIN_TXES, id) {
+            Ok(Some(cached)) => cached.epoch < epoch,
+            Ok(None) => false,
+            // Entries of the previous versions of the cache are not decodable
+            Err(_) => true,
+        };
+        if stale {
+            store.wipe_chunk(db::CHAIN_TXES, id)?;
+            purged += 1;
+        }
+    }
+    debug!("Removed {} transactions cached before epoch {}", purged, epoch);
+    Ok(())
+}
+
+/// Transaction resolver used by the validator which consults [`ChainCache`]
+/// before querying the electrum server.
+pub(crate) struct CachedResolver<'runtime> {
+    electrum: &'runtime ElectrumPool,
+    cache: RefCell<&'runtime mut ChainCache>,
+    store: RefCell<&'runtime mut dyn Store>,
+}
+
+impl<'runtime> ResolveTx for CachedResolver<'runtime> {
+    fn resolve_tx(&self, txid: Txid) -> Result<Transaction, TxResolverError> {
+        let mut cache = self.cache.borrow_mut();
+        let mut store = self.store.borrow_mut();
+        if let Some(cached) = cache.cached(&mut store, txid) {
+            trace!("Transaction {} is found in the cache", txid);
+            cache.hits += 1;
+            return Ok(cached.proof.tx);
+        }
+        cache.misses += 1;
+        let tx = self.electrum.call(|client| client.transaction_get(&txid)).map_err(|err| {
+            TxResolverError {
+                txid,
+                err: Some(Box::new(err)),
+            }
+        })?;
+        if cache.tip.is_some() {
+            cache.memorize(self.electrum, &mut store, &tx);
+        }
+        Ok(tx)
+    }
+}
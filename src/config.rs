@@ -8,19 +8,34 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::str::FromStr;
 
+use bitcoin::secp256k1::rand::thread_rng;
+use bitcoin::secp256k1::{KeyPair, SecretKey, SECP256K1};
+use bitcoin::XOnlyPublicKey;
 use internet2::addr::ServiceAddr;
 use lnpbp::chain::Chain;
+use rgb::{ContractId, SchemaId};
 #[cfg(feature = "server")]
 use rgb_rpc::RGB_NODE_RPC_ENDPOINT;
+use rgb_rpc::{HeaderCheckpoint, RetentionCategory, RetentionPeriod, Setting, SignetChallenge};
 #[cfg(feature = "server")]
 use storm_ext::STORM_NODE_EXT_ENDPOINT;
 
+use crate::bus::DaemonId;
+use crate::hooks::Hook;
 #[cfg(feature = "server")]
 use crate::opts::Opts;
 #[cfg(feature = "server")]
 use crate::{bucketd, rgbd};
+use crate::{IssuerSigner, LaunchError};
+
+/// Name of the file in the data directory keeping the node identity key
+pub const NODE_IDENTITY_FILE: &str = "node_identity.key";
 
 /// Final configuration resulting from data contained in config file environment
 /// variables and command-line options. For security reasons node key is kept
@@ -31,6 +46,12 @@ pub struct Config {
     /// ZMQ socket for RPC API.
     pub rpc_endpoint: ServiceAddr,
 
+    /// Additional ZMQ sockets for RPC API
+    pub rpc_listen: Vec<ServiceAddr>,
+
+    /// Permissions of the RPC IPC socket files
+    pub rpc_socket_mode: Option<u32>,
+
     /// ZMQ socket for RPC API.
     pub ctl_endpoint: ServiceAddr,
 
@@ -43,14 +64,176 @@ pub struct Config {
     /// Data location
     pub data_dir: PathBuf,
 
-    /// URL for the electrum server connection
-    pub electrum_url: String,
+    /// URLs for the electrum server connections
+    pub electrum_urls: Vec<String>,
+
+    /// Indicates whether the node works without connecting to electrum
+    /// servers
+    pub offline: bool,
+
+    /// Trusted block header used for the verification of transaction proofs
+    pub header_checkpoint: Option<HeaderCheckpoint>,
+
+    /// Maximum number of transactions kept in memory by the chain data cache
+    pub chain_cache_size: usize,
+
+    /// Maximum number of contract states kept in memory by each bucket daemon
+    pub state_cache_size: usize,
+
+    /// Number of parallel store connections used by each bucket daemon
+    pub store_concurrency: usize,
+
+    /// Compress consignments relayed to the beneficiaries with zstd
+    pub compress_relay: bool,
+
+    /// File with the secret key opening consignments encrypted to the node
+    pub consignment_key: Option<PathBuf>,
 
     /// Chain used by the node.
     pub chain: Chain,
 
+    /// Challenge script of the custom signet used by the node, if any
+    pub signet_challenge: Option<SignetChallenge>,
+
+    /// ZMQ socket of the external signer daemon, if PSBT signing is delegated
+    pub signer_endpoint: Option<ServiceAddr>,
+
+    /// Backend signing contract checkpoints with the issuer keys
+    pub issuer_signer: Option<IssuerSigner>,
+
+    /// URL of the OpenTimestamps calendar anchoring stash commitments
+    pub timestamp_calendar: Option<String>,
+
+    /// ZMQ socket publishing node events
+    pub events_endpoint: Option<ServiceAddr>,
+
+    /// Publish events as JSON instead of strict encoding
+    pub events_json: bool,
+
+    /// RPC socket of the primary node, if the node runs as its read replica
+    pub replica_of: Option<ServiceAddr>,
+
+    /// ZMQ socket publishing events of the primary node
+    pub replica_events: Option<ServiceAddr>,
+
+    /// Contract paid out by the faucet, if the node runs as a faucet
+    pub faucet_contract: Option<ContractId>,
+
+    /// Socket address of the faucet HTTP endpoint
+    pub faucet_listen: Option<SocketAddr>,
+
+    /// Descriptor of the wallet paying from the faucet
+    pub faucet_descriptor: Option<String>,
+
+    /// Maximum amount paid by the faucet to a single invoice
+    pub faucet_amount: u64,
+
+    /// Fee of the faucet payment transactions, in satoshis
+    pub faucet_fee: u64,
+
+    /// Minimal interval between faucet payments to the same IP address, in
+    /// seconds
+    pub faucet_interval: u64,
+
+    /// Whether the node holds consignments for offline recipients
+    pub relay: bool,
+
+    /// Maximum time the relay holds an envelope, in seconds
+    pub relay_ttl: u32,
+
+    /// Maximum size of a sealed consignment accepted by the relay
+    pub relay_max_size: u32,
+
+    /// Maximum total size of the sealed consignments held by the relay
+    pub relay_quota: u64,
+
+    /// Peer relays receiving copies of the deposited envelopes
+    pub relay_peers: Vec<ServiceAddr>,
+
+    /// Issuers which contract checkpoints are accepted by the node, per
+    /// contract
+    pub trusted_issuers: BTreeMap<ContractId, BTreeSet<XOnlyPublicKey>>,
+
+    /// Administrators of the node; if not empty, clients have to authenticate
+    /// and access only their own contracts and wallets
+    pub admin_keys: BTreeSet<XOnlyPublicKey>,
+
+    /// Keys which may sign contract issue and transfer orders; if not empty,
+    /// unsigned orders are rejected
+    pub order_keys: BTreeSet<XOnlyPublicKey>,
+
+    /// Contracts which consignments are accepted automatically
+    pub accept_contracts: BTreeSet<ContractId>,
+
+    /// Schemata which contracts are accepted automatically
+    pub accept_schemata: BTreeSet<SchemaId>,
+
+    /// Contracts which consignments are always rejected
+    pub reject_contracts: BTreeSet<ContractId>,
+
+    /// Hooks notified about node events
+    pub hooks: Vec<Hook>,
+
+    /// Secret key for signing hook payloads
+    pub hook_secret: Option<String>,
+
+    /// Number of repeated attempts to deliver event to a failing hook
+    pub hook_retries: u8,
+
+    /// Number of days the consignment containers are kept in the store unless
+    /// the consignment is accepted
+    pub container_retention: u16,
+
+    /// Period after which the auxiliary data are pruned from the store
+    pub retention: RetentionPeriod,
+
+    /// Retention periods overriding the default one for specific categories
+    /// of the data
+    pub retention_overrides: BTreeMap<RetentionCategory, RetentionPeriod>,
+
+    /// PostgreSQL connection string of the database mirroring accepted
+    /// contract data
+    pub sql_export: Option<String>,
+
+    /// WebAssembly modules validating consignments of the contracts with
+    /// specific schemata
+    pub validation_plugins: Vec<(SchemaId, PathBuf)>,
+
+    /// Maximum amount of fuel consumed by a validation plugin per consignment
+    pub plugin_fuel: u64,
+
+    /// Maximum number of contract nodes which scripts are executed during a
+    /// consignment validation
+    pub max_script_runs: u32,
+
+    /// Maximum size of the contract schema validation script, in bytes
+    pub max_script_size: u32,
+
+    /// Number of decoy change allocations added to the composed payments
+    pub decoy_change: u8,
+
+    /// Source of the entropy for the blinding factors; the operating system
+    /// RNG is used if absent
+    pub entropy_source: Option<PathBuf>,
+
+    /// Output descriptors of the wallets delegated to embedded BDK wallets
+    pub bdk_wallets: Vec<String>,
+
+    /// Maximum number of bucket daemons serving interactive requests
+    pub interactive_workers: usize,
+
+    /// Maximum number of bucket daemons performing batch tasks at the same time
+    pub batch_workers: usize,
+
     /// Indicates whether deamons should be spawned as threads (true) or as child processes (false)
     pub threaded: bool,
+
+    /// Identifier of the bucket daemon assigned by RGBd
+    pub daemon_id: Option<DaemonId>,
+
+    /// Settings of the node together with the configuration layers providing
+    /// them
+    pub settings: Vec<Setting>,
 }
 
 // TODO: Move to descriptor wallet
@@ -69,21 +252,78 @@ fn default_electrum_port(chain: &Chain) -> u16 {
 #[cfg(feature = "server")]
 impl From<Opts> for Config {
     fn from(opts: Opts) -> Self {
-        let electrum_url = format!(
-            "{}:{}",
-            opts.electrum_server,
-            opts.electrum_port.unwrap_or_else(|| default_electrum_port(&opts.chain))
-        );
+        let electrum_port =
+            opts.electrum_port.unwrap_or_else(|| default_electrum_port(&opts.chain));
+        let electrum_urls = opts
+            .electrum_servers
+            .iter()
+            .map(|server| match server.rsplit_once(':') {
+                Some((_, port)) if port.parse::<u16>().is_ok() => server.clone(),
+                _ => format!("{}:{}", server, electrum_port),
+            })
+            .collect();
 
         Config {
             data_dir: opts.data_dir,
             rpc_endpoint: RGB_NODE_RPC_ENDPOINT.parse().expect("error in constant value"),
+            rpc_listen: empty!(),
+            rpc_socket_mode: None,
             ctl_endpoint: opts.ctl_endpoint,
             storm_endpoint: STORM_NODE_EXT_ENDPOINT.parse().expect("error in constant value"),
             store_endpoint: opts.store_endpoint,
-            electrum_url,
+            electrum_urls,
+            offline: opts.offline,
+            header_checkpoint: opts.header_checkpoint,
+            chain_cache_size: opts.chain_cache_size,
+            state_cache_size: opts.state_cache_size,
+            store_concurrency: opts.store_concurrency,
+            compress_relay: opts.compress_relay,
+            consignment_key: opts.consignment_key,
             chain: opts.chain,
+            signet_challenge: opts.signet_challenge,
+            signer_endpoint: None,
+            issuer_signer: None,
+            timestamp_calendar: None,
+            events_endpoint: None,
+            events_json: false,
+            replica_of: None,
+            replica_events: None,
+            faucet_contract: None,
+            faucet_listen: None,
+            faucet_descriptor: None,
+            faucet_amount: 0,
+            faucet_fee: 0,
+            faucet_interval: 0,
+            relay: false,
+            relay_ttl: 0,
+            relay_max_size: 0,
+            relay_quota: 0,
+            relay_peers: empty!(),
+            trusted_issuers: empty!(),
+            admin_keys: empty!(),
+            order_keys: empty!(),
+            accept_contracts: opts.accept_contracts.into_iter().collect(),
+            accept_schemata: opts.accept_schemata.into_iter().collect(),
+            reject_contracts: opts.reject_contracts.into_iter().collect(),
+            hooks: opts.hooks.iter().map(String::as_str).map(Hook::from).collect(),
+            hook_secret: opts.hook_secret,
+            hook_retries: opts.hook_retries,
+            container_retention: opts.container_retention,
+            retention: opts.retention,
+            retention_overrides: opts.retention_overrides.into_iter().collect(),
+            sql_export: opts.sql_export,
+            validation_plugins: opts.validation_plugins,
+            plugin_fuel: opts.plugin_fuel,
+            max_script_runs: opts.max_script_runs,
+            max_script_size: opts.max_script_size,
+            decoy_change: opts.decoy_change,
+            entropy_source: opts.entropy_source,
+            bdk_wallets: opts.bdk_wallets,
+            interactive_workers: 2,
+            batch_workers: 2,
             threaded: true,
+            daemon_id: None,
+            settings: empty!(),
         }
     }
 }
@@ -94,17 +334,113 @@ impl From<rgbd::Opts> for Config {
         let mut config = Config::from(opts.shared);
         config.set_storm_endpoint(opts.storm_endpoint);
         config.set_rpc_endpoint(opts.rpc_endpoint);
+        config.rpc_listen = opts.rpc_listen;
+        config.rpc_socket_mode = opts.rpc_socket_mode;
         config.threaded = opts.threaded_daemons;
+        config.interactive_workers = opts.interactive_workers.max(1);
+        config.batch_workers = opts.batch_workers.max(1);
+        config.signer_endpoint = opts.signer_endpoint;
+        config.issuer_signer = opts
+            .issuer_signer_endpoint
+            .map(IssuerSigner::Zmq)
+            .or_else(|| opts.issuer_signer_url.map(IssuerSigner::Http));
+        config.timestamp_calendar = opts.timestamp_calendar;
+        config.events_endpoint = opts.events_endpoint;
+        config.events_json = opts.events_json;
+        config.replica_of = opts.replica_of;
+        config.replica_events = opts.replica_events;
+        config.faucet_contract = opts.faucet;
+        config.faucet_listen = Some(opts.faucet_listen);
+        config.faucet_descriptor = opts.faucet_descriptor;
+        config.faucet_amount = opts.faucet_amount;
+        config.faucet_fee = opts.faucet_fee;
+        config.faucet_interval = opts.faucet_interval;
+        config.relay = opts.relay;
+        config.relay_ttl = opts.relay_ttl;
+        config.relay_max_size = opts.relay_max_size;
+        config.relay_quota = opts.relay_quota;
+        config.relay_peers = opts.relay_peers;
+        for (contract_id, issuer) in opts.trusted_issuers {
+            config.trusted_issuers.entry(contract_id).or_default().insert(issuer);
+        }
+        config.admin_keys = opts.admin_keys.into_iter().collect();
+        config.order_keys = opts.order_keys.into_iter().collect();
         config
     }
 }
 
 #[cfg(feature = "server")]
 impl From<bucketd::Opts> for Config {
-    fn from(opts: bucketd::Opts) -> Config { Config::from(opts.shared) }
+    fn from(opts: bucketd::Opts) -> Config {
+        let mut config = Config::from(opts.shared);
+        config.daemon_id = opts.daemon_id;
+        config
+    }
 }
 
 impl Config {
     pub fn set_rpc_endpoint(&mut self, endpoint: ServiceAddr) { self.rpc_endpoint = endpoint; }
     pub fn set_storm_endpoint(&mut self, endpoint: ServiceAddr) { self.storm_endpoint = endpoint; }
+
+    /// Returns retention period for the category of the data, if the data are
+    /// subject to pruning.
+    pub fn retention_period(&self, category: RetentionCategory) -> Option<RetentionPeriod> {
+        let period = self.retention_overrides.get(&category).copied().unwrap_or(self.retention);
+        Some(period).filter(|period| *period != RetentionPeriod::Never)
+    }
+
+    /// Detects whether any of the data are subject to pruning.
+    pub fn prunes_data(&self) -> bool {
+        RetentionCategory::ALL.into_iter().any(|category| self.retention_period(category).is_some())
+    }
+
+    /// Reads the key opening consignments encrypted to the node, if the key is
+    /// configured.
+    pub fn consignment_keypair(&self) -> Result<Option<KeyPair>, LaunchError> {
+        let path = match self.consignment_key {
+            Some(ref path) => path,
+            None => return Ok(None),
+        };
+        let secret_key = fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|hex| SecretKey::from_str(hex.trim()).map_err(|err| err.to_string()))
+            .map_err(|err| {
+                LaunchError::Config(format!(
+                    "unable to read consignment key from {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+        Ok(Some(KeyPair::from_secret_key(SECP256K1, &secret_key)))
+    }
+
+    /// Reads persistent identity key of the node from the data directory,
+    /// generating the key on the first start.
+    pub fn identity_keypair(&self) -> Result<KeyPair, LaunchError> {
+        let path = self.data_dir.join(NODE_IDENTITY_FILE);
+        let err = |err: String| {
+            LaunchError::Config(format!(
+                "unable to read node identity key from {}: {}",
+                path.display(),
+                err
+            ))
+        };
+        if !path.exists() {
+            let secret_key = SecretKey::new(&mut thread_rng());
+            info!("Generating node identity key in {}", path.display());
+            fs::write(&path, secret_key.display_secret().to_string())
+                .map_err(|e| err(e.to_string()))?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+                    .map_err(|e| err(e.to_string()))?;
+            }
+        }
+        let secret_key = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|hex| SecretKey::from_str(hex.trim()).map_err(|err| err.to_string()))
+            .map_err(err)?;
+        Ok(KeyPair::from_secret_key(SECP256K1, &secret_key))
+    }
 }
@@ -8,6 +8,20 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::collections::BTreeSet;
+
+use amplify::Slice32;
+pub use encoding::{ChunkHolder, StrictEncodedChunk};
+use microservices::rpc::ServerError;
+use rgb::MergeReveal;
+use store_rpc::{FailureCode, PrimaryKey};
+use storm::{Chunk, ChunkId};
+use strict_encoding::{StrictDecode, StrictEncode};
+
+use crate::DaemonError;
+
+pub const META: &str = "meta";
+
 pub const SCHEMATA: &str = "schemata";
 pub const CONTRACTS: &str = "contracts";
 pub const BUNDLES: &str = "bundles";
@@ -21,15 +35,162 @@ pub const OUTPOINTS: &str = "outpoints";
 pub const NODE_CONTRACTS: &str = "node_contracts";
 pub const TRANSITION_WITNESS: &str = "transition_txid";
 pub const CONTRACT_TRANSITIONS: &str = "contract_transitions";
+pub const ANCESTRY: &str = "ancestry";
+pub const CHECKPOINTS: &str = "checkpoints";
+pub const POLICIES: &str = "policies";
+pub const SWAPS: &str = "swaps";
+pub const PAYJOINS: &str = "payjoins";
+pub const TRANSFERS: &str = "transfers";
+pub const QUARANTINE: &str = "quarantine";
+pub const CONTAINER_REFS: &str = "container_refs";
+pub const FORGOTTEN: &str = "forgotten";
+pub const STORAGE_USAGE: &str = "storage_usage";
+pub const PENDING_REVEALS: &str = "pending_reveals";
+pub const INVOICES: &str = "invoices";
+pub const JOURNAL: &str = "journal";
+pub const JOURNAL_CONSIGNMENTS: &str = "journal_consignments";
+
+// Write-ahead log of the consignments being stored
+pub const WAL: &str = "wal";
+pub const WAL_CONSIGNMENTS: &str = "wal_consignments";
 
 pub const DISCLOSURES: &str = "disclosures";
 
+// Contracts referenced from the metadata of each contract
+pub const CONTRACT_REFS: &str = "contract_refs";
+
+// Version of the validation rules used for the last validation of each contract
+pub const VALIDATIONS: &str = "validations";
+
+// Materialized views of the contract state per assignment type
+pub const STATE_VIEWS: &str = "state_views";
+pub const STATE_ALLOCATIONS: &str = "state_allocations";
+pub const STATE_RIGHTS: &str = "state_rights";
+pub const STATE_DATA: &str = "state_data";
+pub const STATE_ATTACHMENTS: &str = "state_attachments";
+
+// Chain data cache
+pub const CHAIN_TIP: &str = "chain_tip";
+pub const CHAIN_TXES: &str = "chain_txes";
+
+// Tracked block header chain
+pub const HEADER_TIP: &str = "header_tip";
+pub const HEADERS: &str = "headers";
+
+pub const WALLETS: &str = "wallets";
+pub const WALLET_DESCRIPTORS: &str = "wallet_descriptors";
+pub const SCRIPT_UTXOS: &str = "script_utxos";
+pub const SPENT_OUTPOINTS: &str = "spent_outpoints";
+pub const PENDING_SPENDS: &str = "pending_spends";
+pub const PACKAGES: &str = "packages";
+
+pub const ANNOTATIONS: &str = "annotations";
+
+// Consignment relay
+pub const RELAY_ENVELOPES: &str = "relay_envelopes";
+pub const RELAY_PAYLOADS: &str = "relay_payloads";
+pub const RELAY_INBOX: &str = "relay_inbox";
+
+// Access control of the clients
+pub const ACCESS_GRANTS: &str = "access_grants";
+pub const ORDERS: &str = "orders";
+
 // Storm intgration
 pub const ATTACHMENT_CHUNKS: &str = "chunks";
 pub const ATTACHMENT_INDEX: &str = "attachments";
 pub const ATTACHMENT_CONTAINER_HEADERS: &str = "container_headers";
 pub const ATTACHMENT_CONTAINERS: &str = "containers";
 
+/// Store operations used by the bucket daemons. Implemented by the store RPC
+/// client; other transports, like the in-process store provided under
+/// `testkit` feature, may be used by implementing this trait.
+pub trait Store: Send {
+    fn store(&mut self, table: &str, key: Slice32, chunk: &Chunk) -> Result<ChunkId, StoreError>;
+
+    fn retrieve_chunk(&mut self, table: &str, key: Slice32) -> Result<Option<Chunk>, StoreError>;
+
+    /// Returns keys of all the objects kept in the table
+    fn ids(&mut self, table: &str) -> Result<BTreeSet<ChunkId>, StoreError>;
+
+    /// Returns those of the given ids which are absent in the table
+    fn filter_unknown(
+        &mut self,
+        table: &str,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, StoreError>;
+
+    /// Adds item to the set of 32-byte items kept in the table under the key
+    fn insert_into_set(
+        &mut self,
+        table: &str,
+        key: Slice32,
+        item: Slice32,
+    ) -> Result<(), StoreError>;
+}
+
+/// Error returned by the store
+pub type StoreError = ServerError<FailureCode>;
+
+impl Store for store_rpc::Client {
+    fn store(&mut self, table: &str, key: Slice32, chunk: &Chunk) -> Result<ChunkId, StoreError> {
+        store_rpc::Client::store(self, table, key, chunk)
+    }
+
+    fn retrieve_chunk(&mut self, table: &str, key: Slice32) -> Result<Option<Chunk>, StoreError> {
+        store_rpc::Client::retrieve_chunk(self, table, key)
+    }
+
+    fn ids(&mut self, table: &str) -> Result<BTreeSet<ChunkId>, StoreError> {
+        store_rpc::Client::ids(self, table).map(|ids| ids.into_iter().collect())
+    }
+
+    fn filter_unknown(
+        &mut self,
+        table: &str,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, StoreError> {
+        store_rpc::Client::filter_unknown(self, table, ids)
+    }
+
+    fn insert_into_set(
+        &mut self,
+        table: &str,
+        key: Slice32,
+        item: Slice32,
+    ) -> Result<(), StoreError> {
+        store_rpc::Client::insert_into_set(self, table, key, item).map(|_| ())
+    }
+}
+
+impl<S: Store + ?Sized> Store for Box<S> {
+    fn store(&mut self, table: &str, key: Slice32, chunk: &Chunk) -> Result<ChunkId, StoreError> {
+        (**self).store(table, key, chunk)
+    }
+
+    fn retrieve_chunk(&mut self, table: &str, key: Slice32) -> Result<Option<Chunk>, StoreError> {
+        (**self).retrieve_chunk(table, key)
+    }
+
+    fn ids(&mut self, table: &str) -> Result<BTreeSet<ChunkId>, StoreError> { (**self).ids(table) }
+
+    fn filter_unknown(
+        &mut self,
+        table: &str,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, StoreError> {
+        (**self).filter_unknown(table, ids)
+    }
+
+    fn insert_into_set(
+        &mut self,
+        table: &str,
+        key: Slice32,
+        item: Slice32,
+    ) -> Result<(), StoreError> {
+        (**self).insert_into_set(table, key, item)
+    }
+}
+
 pub(crate) trait StoreRpcExt {
     fn retrieve_sten<T>(
         &mut self,
@@ -50,11 +211,28 @@ pub(crate) trait StoreRpcExt {
         &mut self,
         table: impl ToString,
         key: impl PrimaryKey,
-        new_obj: impl StrictEncodedChunk + MergeReveal + Clone,
+        new_obj: impl StrictEncodedChunk + MergeReveal,
     ) -> Result<(), DaemonError>;
+
+    /// Returns those of the given ids which are present in the table, using a
+    /// single store request.
+    fn filter_known(
+        &mut self,
+        table: impl ToString,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, DaemonError>;
+
+    /// Removes object from the table, returning the size of the removed data,
+    /// if any. The store has no removal operation, so removed objects are
+    /// overwritten with empty chunks, which are read back as missing.
+    fn wipe_chunk(
+        &mut self,
+        table: impl ToString,
+        key: impl PrimaryKey,
+    ) -> Result<Option<usize>, DaemonError>;
 }
 
-impl StoreRpcExt for store_rpc::Client {
+impl<S: Store + ?Sized> StoreRpcExt for S {
     fn retrieve_sten<T>(
         &mut self,
         table: impl ToString,
@@ -63,8 +241,13 @@ impl StoreRpcExt for store_rpc::Client {
     where
         T: StrictEncodedChunk,
     {
-        let maybe_holder = self.retrieve(table, key)?;
-        Ok(maybe_holder.map(ChunkHolder::unbox))
+        // Empty chunks are left by `wipe_chunk`
+        match self.retrieve_chunk(&table.to_string(), key.into_slice32())? {
+            Some(chunk) if !chunk.as_slice().is_empty() => {
+                Ok(Some(ChunkHolder::<T>::strict_deserialize(chunk)?.unbox()))
+            }
+            _ => Ok(None),
+        }
     }
 
     fn store_sten(
@@ -73,14 +256,15 @@ impl StoreRpcExt for store_rpc::Client {
         key: impl PrimaryKey,
         data: &impl StrictEncodedChunk,
     ) -> Result<ChunkId, DaemonError> {
-        self.store(table, key, &data.chunk()).map_err(DaemonError::from)
+        let chunk = Chunk::try_from(data.chunk().strict_serialize()?)?;
+        self.store(&table.to_string(), key.into_slice32(), &chunk).map_err(DaemonError::from)
     }
 
     fn store_merge(
         &mut self,
         table: impl ToString,
         key: impl PrimaryKey,
-        new_obj: impl StrictEncodedChunk + MergeReveal + Clone,
+        new_obj: impl StrictEncodedChunk + MergeReveal,
     ) -> Result<(), DaemonError> {
         let key = key.into_slice32();
         let table = table.to_string();
@@ -96,13 +280,40 @@ impl StoreRpcExt for store_rpc::Client {
         self.store_sten(table, key, &obj)?;
         Ok(())
     }
+
+    fn filter_known(
+        &mut self,
+        table: impl ToString,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, DaemonError> {
+        if ids.is_empty() {
+            return Ok(ids);
+        }
+        let unknown = self.filter_unknown(&table.to_string(), ids.clone())?;
+        Ok(ids.difference(&unknown).copied().collect())
+    }
+
+    fn wipe_chunk(
+        &mut self,
+        table: impl ToString,
+        key: impl PrimaryKey,
+    ) -> Result<Option<usize>, DaemonError> {
+        let table = table.to_string();
+        let key = key.into_slice32();
+        let size = match self.retrieve_chunk(&table, key)? {
+            Some(chunk) if !chunk.as_slice().is_empty() => chunk.as_slice().len(),
+            _ => return Ok(None),
+        };
+        self.store(&table, key, &Chunk::try_from(&[][..])?)?;
+        Ok(Some(size))
+    }
 }
 
 mod encoding {
     use std::collections::BTreeSet;
     use std::io::{Read, Write};
 
-    use bitcoin::Txid;
+    use bitcoin::{OutPoint, Txid};
     use commit_verify::lnpbp4;
     use storm::chunk;
     use strict_encoding::{StrictDecode, StrictEncode};
@@ -142,12 +353,13 @@ mod encoding {
     }
 
     pub trait StrictEncodedChunk: StrictEncode + StrictDecode + Clone {
-        fn chunk(&self) -> ChunkRefHolder<Self>
+        fn chunk(&self) -> ChunkRefHolder<'_, Self>
         where Self: Sized {
             ChunkRefHolder(self)
         }
     }
 
+    impl StrictEncodedChunk for u16 {}
     impl StrictEncodedChunk for Txid {}
 
     // TODO: Probably we need to split disclosures into a multiple chunks
@@ -163,14 +375,42 @@ mod encoding {
     impl StrictEncodedChunk for rgb::TransitionBundle {}
     impl StrictEncodedChunk for rgb::Anchor<lnpbp4::MerkleBlock> {}
     impl StrictEncodedChunk for rgb::ContractState {}
+    impl<T: rgb::ConsignmentType> StrictEncodedChunk for rgb::InmemConsignment<T> {}
 
     impl StrictEncodedChunk for BTreeSet<rgb::NodeId> {}
+    impl StrictEncodedChunk for BTreeSet<rgb::ContractId> {}
+    impl StrictEncodedChunk for BTreeSet<OutPoint> {}
+    impl StrictEncodedChunk for BTreeSet<rgb::OwnedRight> {}
+    impl StrictEncodedChunk for BTreeSet<rgb::OwnedValue> {}
+    impl StrictEncodedChunk for BTreeSet<rgb::OwnedData> {}
+    impl StrictEncodedChunk for BTreeSet<rgb::OwnedAttachment> {}
     impl StrictEncodedChunk for Vec<(rgb::NodeId, BTreeSet<u16>)> {}
-}
 
-pub use encoding::{ChunkHolder, StrictEncodedChunk};
-use rgb::MergeReveal;
-use store_rpc::PrimaryKey;
-use storm::ChunkId;
+    impl StrictEncodedChunk for crate::chain::CachedTx {}
+    impl StrictEncodedChunk for crate::chain::ChainTip {}
+    impl StrictEncodedChunk for crate::headers::HeaderSegment {}
+    impl StrictEncodedChunk for crate::headers::HeaderChainState {}
 
-use crate::DaemonError;
+    impl StrictEncodedChunk for crate::bucketd::WalletState {}
+    impl StrictEncodedChunk for crate::bucketd::SwapRecord {}
+    impl StrictEncodedChunk for crate::bucketd::PayjoinRecord {}
+    impl StrictEncodedChunk for crate::bucketd::QuarantineEntry {}
+    impl StrictEncodedChunk for crate::bucketd::ContainerRef {}
+    impl StrictEncodedChunk for crate::bucketd::CpfpPackage {}
+    impl StrictEncodedChunk for crate::bucketd::ForgottenContract {}
+    impl StrictEncodedChunk for crate::bucketd::ValidationStamp {}
+    impl StrictEncodedChunk for crate::bucketd::WalEntry {}
+
+    impl StrictEncodedChunk for rgb_rpc::Annotation {}
+    impl StrictEncodedChunk for rgb_rpc::SignedCheckpoint {}
+    impl StrictEncodedChunk for rgb_rpc::ContractPolicy {}
+    impl StrictEncodedChunk for rgb_rpc::TransferRecord {}
+    impl StrictEncodedChunk for rgb_rpc::InvoiceRecord {}
+    impl StrictEncodedChunk for rgb_rpc::AccessGrants {}
+    impl StrictEncodedChunk for rgb_rpc::OrderRecord {}
+    impl StrictEncodedChunk for rgb_rpc::SpentStatus {}
+    impl StrictEncodedChunk for BTreeSet<rgb_rpc::WalletUtxo> {}
+
+    impl StrictEncodedChunk for BTreeSet<rgb_rpc::EnvelopeId> {}
+    impl StrictEncodedChunk for crate::rgbd::EnvelopeRecord {}
+}
@@ -0,0 +1,312 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Pool of connections to electrum servers, balancing requests between the
+//! servers and failing over to the healthy ones once a server stops
+//! responding.
+//!
+//! The daemons access the chain only through the [`ChainBackend`] trait, such
+//! that the servers can be replaced with a different backend, like the mock
+//! chain provided under `testkit` feature.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use bitcoin::{BlockHeader, Script, Transaction, Txid};
+use electrum_client::{
+    Client as ElectrumClient, ConfigBuilder, ElectrumApi, Error, GetHeadersRes, GetHistoryRes,
+    GetMerkleRes, HeaderNotification, ListUnspentRes,
+};
+
+/// Timeout for electrum server connection and requests, in seconds
+const ELECTRUM_TIMEOUT: u8 = 4;
+
+/// Time for which a failed server is excluded from the rotation, doubling
+/// with each subsequent failure
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Maximal time for which a failed server is excluded from the rotation
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Chain data requests made by the daemons, which are served by electrum
+/// servers. Errors other than [`Error::Protocol`] are treated as failures of
+/// the backend, making the pool retry the request with another one.
+pub trait ChainBackend: Send + Sync {
+    fn ping(&self) -> Result<(), Error>;
+
+    fn block_header(&self, height: usize) -> Result<BlockHeader, Error>;
+
+    fn block_headers(&self, start_height: usize, count: usize) -> Result<GetHeadersRes, Error>;
+
+    fn block_headers_subscribe(&self) -> Result<HeaderNotification, Error>;
+
+    fn transaction_get(&self, txid: &Txid) -> Result<Transaction, Error>;
+
+    fn transaction_get_merkle(&self, txid: &Txid, height: usize) -> Result<GetMerkleRes, Error>;
+
+    fn transaction_broadcast(&self, tx: &Transaction) -> Result<Txid, Error>;
+
+    fn script_get_history(&self, script: &Script) -> Result<Vec<GetHistoryRes>, Error>;
+
+    fn batch_script_get_history(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<GetHistoryRes>>, Error>;
+
+    fn batch_script_list_unspent(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<ListUnspentRes>>, Error>;
+}
+
+impl ChainBackend for ElectrumClient {
+    fn ping(&self) -> Result<(), Error> { ElectrumApi::ping(self) }
+
+    fn block_header(&self, height: usize) -> Result<BlockHeader, Error> {
+        ElectrumApi::block_header(self, height)
+    }
+
+    fn block_headers(&self, start_height: usize, count: usize) -> Result<GetHeadersRes, Error> {
+        ElectrumApi::block_headers(self, start_height, count)
+    }
+
+    fn block_headers_subscribe(&self) -> Result<HeaderNotification, Error> {
+        ElectrumApi::block_headers_subscribe(self)
+    }
+
+    fn transaction_get(&self, txid: &Txid) -> Result<Transaction, Error> {
+        ElectrumApi::transaction_get(self, txid)
+    }
+
+    fn transaction_get_merkle(&self, txid: &Txid, height: usize) -> Result<GetMerkleRes, Error> {
+        ElectrumApi::transaction_get_merkle(self, txid, height)
+    }
+
+    fn transaction_broadcast(&self, tx: &Transaction) -> Result<Txid, Error> {
+        ElectrumApi::transaction_broadcast(self, tx)
+    }
+
+    fn script_get_history(&self, script: &Script) -> Result<Vec<GetHistoryRes>, Error> {
+        ElectrumApi::script_get_history(self, script)
+    }
+
+    fn batch_script_get_history(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<GetHistoryRes>>, Error> {
+        ElectrumApi::batch_script_get_history(self, scripts)
+    }
+
+    fn batch_script_list_unspent(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<ListUnspentRes>>, Error> {
+        ElectrumApi::batch_script_list_unspent(self, scripts)
+    }
+}
+
+/// Electrum server and the state of its connection
+struct Server {
+    url: String,
+    /// Connection, which is absent until the server is used for the first
+    /// time or after a failure
+    client: Option<Arc<dyn ChainBackend>>,
+    /// Whether the connection is provided by the caller and is kept after
+    /// failures instead of reconnecting
+    persistent: bool,
+    /// Number of failures in a row
+    failures: u32,
+    /// Moment when the failed server may be used again
+    retry_at: Option<Instant>,
+}
+
+impl Server {
+    fn with(url: &str) -> Server {
+        Server {
+            url: url.to_owned(),
+            client: None,
+            persistent: false,
+            failures: 0,
+            retry_at: None,
+        }
+    }
+
+    fn is_available(&self, now: Instant) -> bool {
+        self.retry_at.map(|at| at <= now).unwrap_or(true)
+    }
+}
+
+struct PoolState {
+    servers: Vec<Server>,
+    /// Index of the server receiving the next request
+    next: usize,
+}
+
+/// Connections to the electrum servers used by a bucket daemon.
+///
+/// Requests are distributed between the servers in round-robin order. A server
+/// failing to respond is disconnected and excluded from the rotation for
+/// a time growing exponentially with the number of its failures, while the
+/// request is retried with the next server. Before returning to the rotation
+/// the server is reconnected and checked with a ping.
+pub(crate) struct ElectrumPool {
+    state: Mutex<PoolState>,
+}
+
+impl ElectrumPool {
+    /// Constructs the pool, ensuring that at least one of the servers is
+    /// reachable. Other servers are connected once they receive a request.
+    pub fn with(urls: &[String]) -> Result<ElectrumPool, Error> {
+        let pool = ElectrumPool {
+            state: Mutex::new(PoolState {
+                servers: urls.iter().map(String::as_str).map(Server::with).collect(),
+                next: 0,
+            }),
+        };
+        pool.call(|client| client.ping())?;
+        Ok(pool)
+    }
+
+    /// Constructs pool without servers, failing all requests. Used when the
+    /// node runs in offline mode.
+    pub fn offline() -> ElectrumPool {
+        ElectrumPool {
+            state: Mutex::new(PoolState {
+                servers: vec![],
+                next: 0,
+            }),
+        }
+    }
+
+    /// Constructs pool serving all requests with the given backend instead of
+    /// electrum servers.
+    pub fn with_backend(backend: Arc<dyn ChainBackend>) -> ElectrumPool {
+        ElectrumPool {
+            state: Mutex::new(PoolState {
+                servers: vec![Server {
+                    url: s!("custom backend"),
+                    client: Some(backend),
+                    persistent: true,
+                    failures: 0,
+                    retry_at: None,
+                }],
+                next: 0,
+            }),
+        }
+    }
+
+    /// Performs request with one of the servers, failing over to other
+    /// servers if it can't be completed. All requests made by the closure are
+    /// sent to the same server, so they observe the same chain state.
+    ///
+    /// Errors returned by the server itself, like absence of the requested
+    /// transaction, are not considered as server failures and are returned
+    /// immediately.
+    pub fn call<T>(
+        &self,
+        mut request: impl FnMut(&dyn ChainBackend) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut tried = BTreeSet::new();
+        let mut errors = vec![];
+        while let Some(index) = self.pick(&tried) {
+            tried.insert(index);
+            match self.client(index).and_then(|client| request(client.as_ref())) {
+                Ok(res) => {
+                    self.succeeded(index);
+                    return Ok(res);
+                }
+                Err(err @ Error::Protocol(_)) => return Err(err),
+                Err(err) => {
+                    self.failed(index, &err);
+                    errors.push(err);
+                }
+            }
+        }
+        if errors.is_empty() {
+            return Err(Error::Message(s!("node runs in offline mode")));
+        }
+        Err(Error::AllAttemptsErrored(errors))
+    }
+
+    fn state(&self) -> MutexGuard<PoolState> {
+        self.state.lock().expect("electrum pool state is poisoned")
+    }
+
+    /// Selects next server which was not tried for the current request. If all
+    /// of them are excluded after failures, the one which is excluded for the
+    /// shortest time is used, such that requests never fail without trying.
+    fn pick(&self, tried: &BTreeSet<usize>) -> Option<usize> {
+        let mut state = self.state();
+        let count = state.servers.len();
+        let now = Instant::now();
+        let untried =
+            (0..count).map(|offset| (state.next + offset) % count).filter(|i| !tried.contains(i));
+        let index = untried
+            .clone()
+            .find(|i| state.servers[*i].is_available(now))
+            .or_else(|| untried.min_by_key(|i| state.servers[*i].retry_at))?;
+        state.next = (index + 1) % count;
+        Some(index)
+    }
+
+    /// Returns connection to the server, (re)connecting to it if necessary
+    fn client(&self, index: usize) -> Result<Arc<dyn ChainBackend>, Error> {
+        let url = {
+            let state = self.state();
+            let server = &state.servers[index];
+            if let Some(client) = &server.client {
+                return Ok(client.clone());
+            }
+            server.url.clone()
+        };
+
+        debug!("Connecting to electrum server {}", url);
+        let config = ConfigBuilder::new()
+            .timeout(Some(ELECTRUM_TIMEOUT))
+            .expect("cannot fail since socks5 is unset")
+            .build();
+        let client = ElectrumClient::from_config(&url, config)?;
+        ElectrumApi::ping(&client)?;
+        let client = Arc::new(client) as Arc<dyn ChainBackend>;
+        self.state().servers[index].client = Some(client.clone());
+        Ok(client)
+    }
+
+    fn succeeded(&self, index: usize) {
+        let mut state = self.state();
+        let server = &mut state.servers[index];
+        if server.failures > 0 {
+            info!("Electrum server {} is back online", server.url);
+        }
+        server.failures = 0;
+        server.retry_at = None;
+    }
+
+    fn failed(&self, index: usize, err: &Error) {
+        let mut state = self.state();
+        let server = &mut state.servers[index];
+        let delay = RETRY_DELAY
+            .checked_mul(1 << server.failures.min(16))
+            .unwrap_or(MAX_RETRY_DELAY)
+            .min(MAX_RETRY_DELAY);
+        warn!(
+            "Electrum server {} has failed: {}; excluding it for {} s",
+            server.url,
+            err,
+            delay.as_secs()
+        );
+        if !server.persistent {
+            server.client = None;
+        }
+        server.failures += 1;
+        server.retry_at = Some(Instant::now() + delay);
+    }
+}
@@ -0,0 +1,130 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Entropy source of the seal and amount blinding factors generated by the
+//! node. By default the operating system RNG is used; the node may instead
+//! read the entropy from a hardware RNG device, a named pipe fed by an HSM or
+//! an inherited file descriptor (as `/dev/fd/<no>`).
+//!
+//! Each operation generating blinding factors checks the source and seeds its
+//! own RNG from it, so a failing source stops the operation instead of
+//! producing predictable blinding.
+//!
+//! Regular files are not accepted as a source: the node re-opens the source
+//! for each operation and would read the same leading bytes every time, and
+//! it can't keep track of the consumed part of a file across restarts and
+//! parallel bucket daemons. Reusing the entropy would make blinding factors
+//! of different operations equal, disclosing the amounts and linking the
+//! seals. A file with pre-generated entropy can still be used by feeding it
+//! into a named pipe, which consumes each byte once.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+
+use rgb::secp256k1zkp::rand::rngs::StdRng;
+use rgb::secp256k1zkp::rand::{thread_rng, RngCore, SeedableRng};
+use rgb_rpc::FailureCode;
+
+/// Size of the samples compared by the health check
+const SAMPLE_SIZE: usize = 64;
+
+/// Length of a run of the same byte which the health check considers a
+/// failure of the source; probability of such a run in random data is
+/// negligible
+const MAX_RUN: usize = 8;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum EntropyError {
+    /// unable to read entropy source `{0}`. Details: {1}
+    Read(String, String),
+
+    /// entropy source `{0}` is a regular file which content would be reused for the blinding;
+    /// only devices, pipes and sockets can be used.
+    RegularFile(String),
+
+    /// entropy source `{0}` has failed the health check: {1}.
+    Unhealthy(String, &'static str),
+}
+
+impl From<&EntropyError> for FailureCode {
+    fn from(_: &EntropyError) -> Self { FailureCode::Entropy }
+}
+
+/// Entropy source of the blinding factors
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub(crate) struct Entropy {
+    /// Device, pipe or socket providing the entropy; the operating system RNG
+    /// is used if absent
+    source: Option<PathBuf>,
+}
+
+impl Entropy {
+    pub fn with(source: Option<PathBuf>) -> Entropy { Entropy { source } }
+
+    fn name(&self) -> String {
+        match &self.source {
+            Some(path) => path.display().to_string(),
+            None => s!("system"),
+        }
+    }
+
+    fn fill(&self, buf: &mut [u8]) -> Result<(), EntropyError> {
+        let path = match &self.source {
+            Some(path) => path,
+            None => {
+                return thread_rng()
+                    .try_fill_bytes(buf)
+                    .map_err(|err| EntropyError::Read(self.name(), err.to_string()))
+            }
+        };
+        let read = |err: std::io::Error| EntropyError::Read(self.name(), err.to_string());
+        let mut file = File::open(path).map_err(read)?;
+        if file.metadata().map_err(read)?.is_file() {
+            return Err(EntropyError::RegularFile(self.name()));
+        }
+        file.read_exact(buf).map_err(read)
+    }
+
+    /// Reads two samples from the source, checking that they differ and have
+    /// no long runs of the same byte.
+    pub fn check(&self) -> Result<(), EntropyError> {
+        let mut first = [0u8; SAMPLE_SIZE];
+        let mut second = [0u8; SAMPLE_SIZE];
+        self.fill(&mut first)?;
+        self.fill(&mut second)?;
+        if first == second {
+            return Err(EntropyError::Unhealthy(self.name(), "source repeats its output"));
+        }
+        if first
+            .iter()
+            .chain(&second)
+            .collect::<Vec<_>>()
+            .windows(MAX_RUN)
+            .any(|run| run.iter().all(|byte| byte == &run[0]))
+        {
+            return Err(EntropyError::Unhealthy(
+                self.name(),
+                "source outputs runs of the same byte",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the source and constructs RNG seeded from it, which generates
+    /// the blinding factors of a single operation.
+    pub fn rng(&self) -> Result<StdRng, EntropyError> {
+        self.check()?;
+        let mut seed = <StdRng as SeedableRng>::Seed::default();
+        self.fill(&mut seed)?;
+        Ok(StdRng::from_seed(seed))
+    }
+}
@@ -10,14 +10,24 @@
 
 use commit_verify::lnpbp4;
 use internet2::presentation;
+use microservices::esb::ClientId;
 use microservices::rpc::ServerError;
 use microservices::{esb, rpc, LauncherError};
-use rgb_rpc::{FailureCode, RpcMsg};
+use rgb_rpc::{FailureCode, ProofError, RpcMsg};
 use storm::ContainerId;
 
-use crate::bucketd::{FinalizeError, StashError};
-use crate::bus::{ServiceBus, ServiceId};
-use crate::rgbd::Daemon;
+use crate::bucketd::{
+    CheckpointError, FinalizeError, ImportError, IssueError, PayjoinError, PaymentError,
+    PolicyError, QuarantineError, ScriptLimitError, SimulationError, StashError, SwapError,
+    WalletError,
+};
+use crate::bus::{DaemonId, ServiceBus, ServiceId};
+use crate::plugins::PluginError;
+use crate::rgbd::{
+    AccessError, Daemon, FaucetError, OrderError, ReceiptError, RelayError, ReplicaError,
+    UploadError,
+};
+use crate::{EntropyError, SignerError, TimestampError};
 
 #[derive(Clone, Debug, Display, Error, From)]
 #[display(doc_comments)]
@@ -31,6 +41,34 @@ pub enum LaunchError {
 
     /// electrum connectivity error. Details: {0}
     ElectrumConnectivity(String),
+
+    /// unable to bind event publisher socket. Details: {0}
+    EventPublisher(String),
+
+    /// unable to set up RPC listener. Details: {0}
+    RpcListener(String),
+
+    #[display(inner)]
+    #[from]
+    Plugin(PluginError),
+
+    #[display(inner)]
+    #[from]
+    Wallet(WalletError),
+
+    #[display(inner)]
+    #[from]
+    Faucet(FaucetError),
+
+    /// invalid node configuration. Details: {0}
+    Config(String),
+
+    /// store data have format version {found}, while this node supports
+    /// versions up to {supported}; please upgrade the node.
+    StoreVersion { found: u16, supported: u16 },
+
+    /// unable to migrate store data to the current format. Details: {0}
+    StoreMigration(String),
 }
 
 impl microservices::error::Error for LaunchError {}
@@ -71,9 +109,113 @@ pub(crate) enum DaemonError {
     #[from(bp::dbc::anchor::Error)]
     Finalize(FinalizeError),
 
-    /// the container which was requested to be processed is absent in sthe store
+    #[display(inner)]
+    #[from]
+    Signer(SignerError),
+
+    #[display(inner)]
+    #[from]
+    Wallet(WalletError),
+
+    #[display(inner)]
+    #[from]
+    Checkpoint(CheckpointError),
+
+    #[display(inner)]
+    #[from]
+    Policy(PolicyError),
+
+    #[display(inner)]
+    #[from]
+    Swap(SwapError),
+
+    #[display(inner)]
+    #[from]
+    Payjoin(PayjoinError),
+
+    #[display(inner)]
+    #[from]
+    Issue(IssueError),
+
+    #[display(inner)]
+    #[from]
+    Import(ImportError),
+
+    #[display(inner)]
+    #[from]
+    Payment(PaymentError),
+
+    #[display(inner)]
+    #[from]
+    Quarantine(QuarantineError),
+
+    #[display(inner)]
+    #[from]
+    Upload(UploadError),
+
+    #[display(inner)]
+    #[from]
+    Proof(ProofError),
+
+    #[display(inner)]
+    #[from]
+    Plugin(PluginError),
+
+    #[display(inner)]
+    #[from]
+    ScriptLimit(ScriptLimitError),
+
+    #[display(inner)]
+    #[from]
+    Replica(ReplicaError),
+
+    #[display(inner)]
+    #[from]
+    Relay(RelayError),
+
+    #[display(inner)]
+    #[from]
+    Access(AccessError),
+
+    #[display(inner)]
+    #[from]
+    Order(OrderError),
+
+    #[display(inner)]
+    #[from]
+    Receipt(ReceiptError),
+
+    #[display(inner)]
+    #[from]
+    Timestamp(TimestampError),
+
+    #[display(inner)]
+    #[from]
+    Simulation(SimulationError),
+
+    #[display(inner)]
+    #[from]
+    Entropy(EntropyError),
+
+    /// the container {0} which was requested to be processed is absent in the store
     NoContainer(ContainerId),
 
+    /// consignment in the container {0} is encrypted to a key which is not
+    /// configured for the node with `--consignment-key` option, or is corrupted
+    SealedContainer(ContainerId),
+
+    /// bucket daemon {0} has terminated while processing the request
+    WorkerFailed(DaemonId),
+
+    /// request of client {0} was cancelled
+    Cancelled(ClientId),
+
+    /// request deadline has expired before the processing was completed
+    DeadlineExpired,
+
+    /// there is no queued or processed request of client {0}
+    UnknownRequest(ClientId),
+
     /// request `{1}` is not supported on {0} message bus
     RequestNotSupported(ServiceBus, String),
     // /// request `{1}` is not supported on {0} message bus for service {2}
@@ -88,7 +230,7 @@ impl From<DaemonError> for esb::Error<ServiceId> {
 
 impl From<DaemonError> for RpcMsg {
     fn from(err: DaemonError) -> Self {
-        let code = match err {
+        let code = match &err {
             DaemonError::StormEncoding(_) | DaemonError::Encoding(_) => FailureCode::Encoding,
             DaemonError::Esb(_) => FailureCode::Esb,
             DaemonError::RequestNotSupported(_, _) /* | DaemonError::SourceNotSupported(_, _, _) */ => {
@@ -96,9 +238,35 @@ impl From<DaemonError> for RpcMsg {
             }
             DaemonError::Store(_) => FailureCode::Store,
             DaemonError::BucketLauncher(_) => FailureCode::Launcher,
-            DaemonError::Stash(_) => FailureCode::Stash,
-            DaemonError::Finalize(_) => FailureCode::Finalize,
-            DaemonError::NoContainer(_) => FailureCode::Store,
+            DaemonError::Stash(err) => err.into(),
+            DaemonError::Finalize(err) => err.into(),
+            DaemonError::NoContainer(_) => FailureCode::ContainerAbsent,
+            DaemonError::SealedContainer(_) => FailureCode::InvalidConsignment,
+            DaemonError::WorkerFailed(_) => FailureCode::WorkerFailed,
+            DaemonError::Cancelled(_) | DaemonError::DeadlineExpired => FailureCode::Cancelled,
+            DaemonError::UnknownRequest(_) => FailureCode::Absent,
+            DaemonError::Signer(err) => err.into(),
+            DaemonError::Wallet(err) => err.into(),
+            DaemonError::Checkpoint(err) => err.into(),
+            DaemonError::Policy(err) => err.into(),
+            DaemonError::Swap(err) => err.into(),
+            DaemonError::Payjoin(err) => err.into(),
+            DaemonError::Issue(err) => err.into(),
+            DaemonError::Import(err) => err.into(),
+            DaemonError::Payment(err) => err.into(),
+            DaemonError::Quarantine(err) => err.into(),
+            DaemonError::Upload(err) => err.into(),
+            DaemonError::Proof(err) => err.into(),
+            DaemonError::Plugin(err) => err.into(),
+            DaemonError::ScriptLimit(err) => err.into(),
+            DaemonError::Replica(err) => err.into(),
+            DaemonError::Relay(err) => err.into(),
+            DaemonError::Access(err) => err.into(),
+            DaemonError::Order(err) => err.into(),
+            DaemonError::Receipt(err) => err.into(),
+            DaemonError::Timestamp(err) => err.into(),
+            DaemonError::Simulation(err) => err.into(),
+            DaemonError::Entropy(err) => err.into(),
         };
         RpcMsg::Failure(rpc::Failure {
             code: code.into(),
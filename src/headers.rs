@@ -0,0 +1,124 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Best block header chain, which is synchronized by RGBd and kept in the
+//! store. Bucket daemons use it to verify merkle proofs of the witness
+//! transactions independently from the electrum servers.
+
+use bitcoin::{BlockHeader, Txid};
+use rgb_rpc::{HeaderCheckpoint, ProofHeader};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use crate::db::{self, Store, StoreRpcExt};
+use crate::DaemonError;
+
+/// Number of headers kept in a single store entry
+pub(crate) const SEGMENT_LEN: u32 = 2016;
+
+/// Part of the header chain kept in a single store entry. Segments cover
+/// heights aligned to [`SEGMENT_LEN`], except the first one, which starts right
+/// after the chain anchor.
+#[derive(Clone, PartialEq, Eq, Debug, Default, StrictEncode, StrictDecode)]
+pub(crate) struct HeaderSegment {
+    /// Height of the first header in the segment
+    pub start: u32,
+    pub headers: Vec<ProofHeader>,
+}
+
+impl HeaderSegment {
+    pub fn key(height: u32) -> ChunkId {
+        ChunkId::with_fixed_fragments(db::HEADERS, height / SEGMENT_LEN)
+    }
+
+    pub fn get(&self, height: u32) -> Option<BlockHeader> {
+        let index = height.checked_sub(self.start)?;
+        self.headers.get(index as usize).map(|header| header.0)
+    }
+}
+
+/// Range of the header chain kept in the store. Headers are tracked starting
+/// after the anchor, which is either the header checkpoint of the node or the
+/// genesis block.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct HeaderChainState {
+    pub anchor: HeaderCheckpoint,
+    /// Header of the anchor block, which difficulty is the base for the
+    /// difficulty checks of the tracked headers
+    pub anchor_header: ProofHeader,
+    pub tip: HeaderCheckpoint,
+}
+
+impl HeaderChainState {
+    pub fn key() -> ChunkId { ChunkId::with_fixed_fragments(db::HEADER_TIP, 0u8) }
+
+    pub fn load(store: &mut dyn Store) -> Result<Option<Self>, DaemonError> {
+        store.retrieve_sten(db::HEADER_TIP, Self::key())
+    }
+}
+
+#[derive(Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub(crate) enum HeaderError {
+    /// unable to retrieve data from electrum server. Details: {0}
+    #[from]
+    Electrum(electrum_client::Error),
+
+    #[display(inner)]
+    #[from]
+    Store(DaemonError),
+
+    /// header chain is not synchronized up to height {0} yet.
+    NotSynced(u32),
+
+    /// block header at height {0} is not connected to the previous header.
+    Disconnected(u32),
+
+    /// block header at height {0} has insufficient proof of work.
+    Work(u32),
+
+    /// block header at height {0} has difficulty which doesn't follow the
+    /// network retarget rules.
+    Difficulty(u32),
+
+    /// block header at the anchor height {0} doesn't match the header
+    /// checkpoint.
+    AnchorMismatch(u32),
+
+    /// chain provided by the electrum server forks from the tracked chain at
+    /// height {0} and has less work, so it is ignored.
+    LessWork(u32),
+
+    /// header chain tracking is not supported for {0}.
+    UnsupportedChain(String),
+
+    /// chain is reorganized below the header chain anchor at height {0}.
+    ReorgBelowAnchor(u32),
+
+    /// merkle proof provided by electrum server does not include transaction
+    /// {0} into the block at height {1}.
+    NotIncluded(Txid, u32),
+}
+
+/// Returns header at the given height from the tracked header chain. Heights
+/// below the chain anchor or above its tip are not known.
+pub(crate) fn header_at(
+    store: &mut dyn Store,
+    height: u32,
+) -> Result<Option<BlockHeader>, DaemonError> {
+    let state = match HeaderChainState::load(store)? {
+        Some(state) if height > state.anchor.height && height <= state.tip.height => state,
+        _ => return Ok(None),
+    };
+    let segment: Option<HeaderSegment> =
+        store.retrieve_sten(db::HEADERS, HeaderSegment::key(height))?;
+    trace!("Reading header at height {} tracked up to {}", height, state.tip);
+    Ok(segment.and_then(|segment| segment.get(height)))
+}
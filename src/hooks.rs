@@ -0,0 +1,315 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Notifications of the external services about the node events by calling
+//! webhooks or executing hook commands.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use bitcoin::hashes::hex::ToHex;
+use bitcoin::hashes::hmac::{Hmac, HmacEngine};
+use bitcoin::hashes::{sha256, Hash, HashEngine};
+use bitcoin::secp256k1::rand::random;
+use rgb::{AtomicValue, ConsignmentId, ContractId, NodeId};
+use rgb_rpc::{now, parse_contract_id, TransferRecord};
+use serde_json::{json, Value};
+
+use crate::http::{self, HttpError};
+
+/// Timeout for talking to a webhook endpoint
+const HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum size of the webhook reply, which is ignored
+const MAX_REPLY_SIZE: u64 = 64 * 1024;
+
+/// Delay before the first retry of a failed delivery; doubled on each next
+/// attempt
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Target of the hook notification
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum HookTarget {
+    /// URL receiving JSON payload with HTTP POST request
+    #[display("{0}")]
+    Webhook(String),
+
+    /// Shell command receiving JSON payload from the standard input
+    #[display("exec:{0}")]
+    Exec(String),
+}
+
+impl From<&str> for HookTarget {
+    fn from(s: &str) -> Self {
+        match s.strip_prefix("exec:") {
+            Some(cmd) => HookTarget::Exec(cmd.to_owned()),
+            None => HookTarget::Webhook(s.to_owned()),
+        }
+    }
+}
+
+/// Hook configured in form of `[<contract_id>:]<url>` or
+/// `[<contract_id>:]exec:<command>`. Hooks without contract id are triggered
+/// by the events of all contracts.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Hook {
+    pub contract_id: Option<ContractId>,
+    pub target: HookTarget,
+}
+
+impl From<&str> for Hook {
+    fn from(s: &str) -> Self {
+        let contract_id = s
+            .split_once(':')
+            .and_then(|(prefix, target)| parse_contract_id(prefix).ok().map(|id| (id, target)));
+        match contract_id {
+            Some((contract_id, target)) => Hook {
+                contract_id: Some(contract_id),
+                target: HookTarget::from(target),
+            },
+            None => Hook {
+                contract_id: None,
+                target: HookTarget::from(s),
+            },
+        }
+    }
+}
+
+/// Node events which are reported to the hooks
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+pub enum HookEvent {
+    /// Incoming transfer assigned state to the seals controlled by the node
+    #[display("allocation")]
+    Allocation(TransferRecord),
+
+    /// Witness transactions of the incoming transfer are mined
+    #[display("confirmed")]
+    Confirmed(TransferRecord),
+
+    /// Consignment has not passed validation
+    #[display("invalid")]
+    ValidationFailure {
+        consignment_id: ConsignmentId,
+        contract_id: ContractId,
+        failures: Vec<String>,
+    },
+
+    /// State transition or extension issuing or burning the contract assets
+    #[display("supply")]
+    SupplyChange {
+        contract_id: ContractId,
+        node_id: NodeId,
+        amount: AtomicValue,
+    },
+}
+
+impl HookEvent {
+    pub fn contract_id(&self) -> ContractId {
+        match self {
+            HookEvent::Allocation(record) | HookEvent::Confirmed(record) => record.contract_id,
+            HookEvent::ValidationFailure { contract_id, .. }
+            | HookEvent::SupplyChange { contract_id, .. } => *contract_id,
+        }
+    }
+
+    fn data(&self) -> Value {
+        match self {
+            HookEvent::Allocation(record) | HookEvent::Confirmed(record) => {
+                serde_json::to_value(record).unwrap_or_default()
+            }
+            HookEvent::ValidationFailure {
+                consignment_id,
+                failures,
+                ..
+            } => json!({
+                "consignmentId": consignment_id.to_string(),
+                "failures": failures,
+            }),
+            HookEvent::SupplyChange {
+                node_id, amount, ..
+            } => json!({
+                "nodeId": node_id.to_string(),
+                "amount": amount,
+            }),
+        }
+    }
+
+    /// Serializes event into the JSON payload sent to the hooks
+    pub fn payload(&self) -> String {
+        let timestamp = now();
+        json!({
+            "event": self.to_string(),
+            "contractId": self.contract_id().to_string(),
+            "timestamp": timestamp,
+            "data": self.data(),
+        })
+        .to_string()
+    }
+}
+
+#[derive(Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum HookError {
+    /// I/O error during hook invocation. Details: {0}
+    #[from]
+    Io(io::Error),
+
+    /// webhook request has failed. Details: {0}
+    #[from]
+    Http(HttpError),
+
+    /// hook has responded with `{0}`.
+    Rejected(String),
+}
+
+/// Delivers node events to the configured hooks
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Notifier {
+    hooks: Vec<Hook>,
+    secret: Option<String>,
+    retries: u8,
+}
+
+impl Notifier {
+    pub fn with(hooks: Vec<Hook>, secret: Option<String>, retries: u8) -> Notifier {
+        Notifier {
+            hooks,
+            secret,
+            retries,
+        }
+    }
+
+    /// Checks whether any hooks are configured
+    pub fn is_active(&self) -> bool { !self.hooks.is_empty() }
+
+    /// Sends event to all hooks matching the event contract. Each hook is
+    /// called from a separate thread retrying failed deliveries with an
+    /// exponential backoff, so the caller is never blocked.
+    pub fn notify(&self, event: HookEvent) {
+        let contract_id = event.contract_id();
+        let targets = self
+            .hooks
+            .iter()
+            .filter(|hook| hook.contract_id.map(|id| id == contract_id).unwrap_or(true))
+            .map(|hook| hook.target.clone())
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return;
+        }
+
+        let name = event.to_string();
+        let payload = event.payload();
+        debug!("Notifying {} hook(s) about {} event for {}", targets.len(), name, contract_id);
+
+        for target in targets {
+            let delivery = Delivery {
+                id: random::<[u8; 16]>().to_hex(),
+                event: name.clone(),
+                payload: payload.clone(),
+            };
+            let secret = self.secret.clone();
+            let retries = self.retries;
+            thread::spawn(move || {
+                let name = &delivery.event;
+                let mut delay = RETRY_DELAY;
+                for attempt in 0..=retries {
+                    match delivery.deliver(&target, secret.as_deref()) {
+                        Ok(()) => {
+                            debug!("Hook {} is notified about {} event", target, name);
+                            return;
+                        }
+                        Err(err) if attempt < retries => {
+                            warn!("Hook {} failed: {}; retrying in {:?}", target, err, delay);
+                            thread::sleep(delay);
+                            delay *= 2;
+                        }
+                        Err(err) => {
+                            error!("Hook {} failed: {}; giving up on {} event", target, err, name)
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Event delivered to a single hook. Retried deliveries keep the id, allowing
+/// the hook to detect duplicates.
+struct Delivery {
+    id: String,
+    event: String,
+    payload: String,
+}
+
+impl Delivery {
+    /// Makes a single delivery attempt. Payload signature covers the time of
+    /// the attempt and the delivery id, such that a captured request can't be
+    /// replayed to the hook later or as a different delivery.
+    fn deliver(&self, target: &HookTarget, secret: Option<&str>) -> Result<(), HookError> {
+        let timestamp = now().to_string();
+        let signature = secret.map(|secret| self.sign(secret, &timestamp));
+        let mut headers = vec![
+            ("X-RGB-Event", self.event.as_str()),
+            ("X-RGB-Delivery", self.id.as_str()),
+            ("X-RGB-Timestamp", timestamp.as_str()),
+        ];
+        if let Some(ref signature) = signature {
+            headers.push(("X-RGB-Signature", signature.as_str()));
+        }
+        match target {
+            HookTarget::Webhook(url) => {
+                http::post(
+                    url,
+                    "application/json",
+                    &headers,
+                    self.payload.as_bytes(),
+                    HOOK_TIMEOUT,
+                    MAX_REPLY_SIZE,
+                )?;
+                Ok(())
+            }
+            HookTarget::Exec(cmd) => exec(cmd, &headers, &self.payload),
+        }
+    }
+
+    /// Computes `sha256=<hex>` HMAC of the `<timestamp>.<delivery id>.<payload>`
+    /// string
+    fn sign(&self, secret: &str, timestamp: &str) -> String {
+        let mut engine = HmacEngine::<sha256::Hash>::new(secret.as_bytes());
+        engine.input(timestamp.as_bytes());
+        engine.input(b".");
+        engine.input(self.id.as_bytes());
+        engine.input(b".");
+        engine.input(self.payload.as_bytes());
+        format!("sha256={}", Hmac::<sha256::Hash>::from_engine(engine))
+    }
+}
+
+/// Executes hook command, providing the headers of the webhook request in
+/// `RGB_HOOK_<NAME>` environment variables
+fn exec(cmd: &str, headers: &[(&str, &str)], payload: &str) -> Result<(), HookError> {
+    let mut command = Command::new("sh");
+    command.arg("-c").arg(cmd).stdin(Stdio::piped());
+    for (name, value) in headers {
+        let var = name.trim_start_matches("X-RGB-").to_uppercase();
+        command.env(format!("RGB_HOOK_{}", var), value);
+    }
+    let mut child = command.spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(HookError::Rejected(status.to_string()));
+    }
+    Ok(())
+}
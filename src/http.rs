@@ -0,0 +1,78 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! HTTP client calling the external services used by the node. Both `http://`
+//! and `https://` URLs are supported; server certificates are verified against
+//! the Mozilla root certificates.
+
+use std::io::Read;
+use std::time::Duration;
+
+/// Timeout for establishing connection to the service
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum HttpError {
+    /// URL `{0}` is not supported; only `http://` and `https://` URLs can be
+    /// used.
+    Url(String),
+
+    /// unable to connect to {0}. Details: {1}
+    Connection(String, String),
+
+    /// {0} has responded with status {1}.
+    Status(String, u16),
+
+    /// reply of {0} exceeds {1} bytes.
+    ReplyTooLarge(String, u64),
+}
+
+/// Sends POST request with the given body, returning the body of the reply
+/// with a success status. Timeout applies to each read and write of the
+/// request after the connection is established.
+pub(crate) fn post(
+    url: &str,
+    content_type: &str,
+    headers: &[(&str, &str)],
+    body: &[u8],
+    timeout: Duration,
+    max_reply: u64,
+) -> Result<Vec<u8>, HttpError> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(HttpError::Url(url.to_owned()));
+    }
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(CONNECT_TIMEOUT)
+        .timeout_read(timeout)
+        .timeout_write(timeout)
+        .redirects(0)
+        .build();
+    let mut request = agent.post(url).set("Content-Type", content_type);
+    for (name, value) in headers {
+        request = request.set(name, value);
+    }
+    let response = match request.send_bytes(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code, _)) => return Err(HttpError::Status(url.to_owned(), code)),
+        Err(err) => return Err(HttpError::Connection(url.to_owned(), err.to_string())),
+    };
+
+    let mut reply = vec![];
+    response
+        .into_reader()
+        .take(max_reply + 1)
+        .read_to_end(&mut reply)
+        .map_err(|err| HttpError::Connection(url.to_owned(), err.to_string()))?;
+    if reply.len() as u64 > max_reply {
+        return Err(HttpError::ReplyTooLarge(url.to_owned(), max_reply));
+    }
+    Ok(reply)
+}
@@ -32,13 +32,33 @@ extern crate log;
 
 mod config;
 mod error;
+pub(crate) mod chain;
+pub(crate) mod electrum;
+pub(crate) mod entropy;
+pub(crate) mod headers;
+mod http;
+pub(crate) mod lru;
+mod signer;
+mod timestamp;
+pub mod hooks;
+pub mod plugins;
 pub mod rgbd;
 pub mod bus;
 pub mod bucketd;
 #[cfg(feature = "server")]
 pub mod opts;
+#[cfg(feature = "server")]
+pub mod settings;
 pub(crate) mod db;
+#[cfg(feature = "testkit")]
+pub mod testkit;
 
 pub use config::Config;
+pub use db::{Store, StoreError};
+pub use electrum::ChainBackend;
+pub use entropy::EntropyError;
 pub(crate) use error::DaemonError;
 pub use error::LaunchError;
+pub use http::HttpError;
+pub use signer::{IssuerSigner, SignerError};
+pub use timestamp::TimestampError;
@@ -0,0 +1,74 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeMap;
+
+/// Bounded in-memory cache evicting least recently used entries once the
+/// number of stored items exceeds the configured limit.
+#[derive(Clone, Debug)]
+pub(crate) struct LruCache<K: Ord + Clone, V> {
+    limit: usize,
+    tick: u64,
+    entries: BTreeMap<K, (V, u64)>,
+    usage: BTreeMap<u64, K>,
+}
+
+impl<K: Ord + Clone, V> LruCache<K, V> {
+    pub fn with_limit(limit: usize) -> Self {
+        LruCache {
+            limit,
+            tick: 0,
+            entries: empty!(),
+            usage: empty!(),
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let tick = self.next_tick();
+        let (value, used) = self.entries.get_mut(key)?;
+        self.usage.remove(used);
+        self.usage.insert(tick, key.clone());
+        *used = tick;
+        Some(value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.limit == 0 {
+            return;
+        }
+        let tick = self.next_tick();
+        if let Some((_, used)) = self.entries.insert(key.clone(), (value, tick)) {
+            self.usage.remove(&used);
+        }
+        self.usage.insert(tick, key);
+        while self.entries.len() > self.limit {
+            let oldest = *self.usage.keys().next().expect("usage index is out of sync");
+            if let Some(key) = self.usage.remove(&oldest) {
+                self.entries.remove(&key);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, used) = self.entries.remove(key)?;
+        self.usage.remove(&used);
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.usage.clear();
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
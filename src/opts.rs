@@ -9,13 +9,16 @@
 // If not, see <https://opensource.org/licenses/MIT>.
 
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use clap::{Parser, ValueHint};
 use internet2::addr::ServiceAddr;
 use lnpbp::chain::Chain;
+use rgb::{ContractId, SchemaId};
+use rgb_rpc::{HeaderCheckpoint, RetentionCategory, RetentionPeriod, SignetChallenge};
 use store_rpc::STORED_RPC_ENDPOINT;
 
-#[cfg(any(target_os = "linux"))]
+#[cfg(target_os = "linux")]
 pub const RGB_NODE_DATA_DIR: &str = "~/.rgb_node";
 #[cfg(any(target_os = "freebsd", target_os = "openbsd", target_os = "netbsd"))]
 pub const RGB_NODE_DATA_DIR: &str = "~/.rgb_node";
@@ -56,6 +59,22 @@ pub struct Opts {
     )]
     pub data_dir: PathBuf,
 
+    /// Path to the node configuration file.
+    ///
+    /// Keys of the configuration file are the long names of the command-line
+    /// options, like `electrum-server` or `max-script-runs`. Values given in
+    /// the command line or with the environment variables take precedence
+    /// over the values from the file. Defaults to `rgb_node.toml` file inside
+    /// `--data-dir` directory, which is used only if it exists.
+    #[clap(
+        short,
+        long,
+        global = true,
+        env = "RGB_NODE_CONFIG",
+        value_hint = ValueHint::FilePath
+    )]
+    pub config: Option<PathBuf>,
+
     /// ZMQ socket for connecting storage daemon.
     #[clap(
         short = 'S',
@@ -99,20 +118,242 @@ pub struct Opts {
     )]
     pub chain: Chain,
 
-    /// Electrum server to use.
+    /// Challenge script of a custom signet, in hex. Requires `--network
+    /// signet`; clients and invoices must then refer to the same signet.
+    #[clap(long, global = true, env = "RGB_NODE_SIGNET_CHALLENGE")]
+    pub signet_challenge: Option<SignetChallenge>,
+
+    /// Electrum server to use, optionally followed by `:<port>`. Can be used
+    /// multiple times; requests are then balanced between the servers, and a
+    /// failing server is excluded from the use until it recovers.
     #[clap(
-        long,
+        long = "electrum-server",
         global = true,
         default_value("blockstream.info"),
         env = "RGB_NODE_ELECTRUM_SERVER",
         value_hint = ValueHint::Hostname
     )]
-    pub electrum_server: String,
+    pub electrum_servers: Vec<String>,
 
-    /// Customize Electrum server port number. By default the wallet will use port
-    /// matching the selected network.
+    /// Customize Electrum server port number for the servers given without
+    /// port. By default the wallet will use port matching the selected
+    /// network.
     #[clap(long, global = true, env = "RGB_NODE_ELECTRUM_PORT")]
     pub electrum_port: Option<u16>,
+
+    /// Do not connect to electrum servers. Consignments are then validated
+    /// only with the transaction proofs provided together with them.
+    #[clap(long, global = true, env = "RGB_NODE_OFFLINE")]
+    pub offline: bool,
+
+    /// Trusted block header in form of `<height>:<block_hash>`. Header chains
+    /// of the transaction proofs provided with consignments must start right
+    /// after this header.
+    #[clap(long, global = true, env = "RGB_NODE_HEADER_CHECKPOINT")]
+    pub header_checkpoint: Option<HeaderCheckpoint>,
+
+    /// Maximum number of transactions kept in memory by the chain data cache
+    /// used during validation. Setting to zero disables in-memory caching.
+    #[clap(long, global = true, default_value = "10000", env = "RGB_NODE_CHAIN_CACHE_SIZE")]
+    pub chain_cache_size: usize,
+
+    /// Maximum number of contract states kept in memory by each bucket daemon.
+    /// Setting to zero disables the cache.
+    #[clap(long, global = true, default_value = "1000", env = "RGB_NODE_STATE_CACHE_SIZE")]
+    pub state_cache_size: usize,
+
+    /// Number of parallel store connections used by each bucket daemon to
+    /// retrieve contract history when composing consignments.
+    #[clap(long, global = true, default_value = "4", env = "RGB_NODE_STORE_CONCURRENCY")]
+    pub store_concurrency: usize,
+
+    /// Compress consignments relayed to the beneficiaries with zstd.
+    ///
+    /// Relayed containers are announced over Storm without a capability
+    /// exchange with the beneficiary node, so the compression can't be
+    /// negotiated: enable it only if all beneficiaries run a node version
+    /// accepting compressed consignment containers. Uncompressed containers are
+    /// always accepted.
+    #[clap(long, global = true, env = "RGB_NODE_COMPRESS_RELAY")]
+    pub compress_relay: bool,
+
+    /// File containing hex-encoded secret key opening consignments encrypted
+    /// to the node.
+    ///
+    /// Payers encrypt consignments sent over Storm to the key given in the
+    /// invoice with `key=<pubkey>` parameter, where the public key is printed
+    /// by `rgb-cli relay id`.
+    #[clap(
+        long,
+        global = true,
+        env = "RGB_NODE_CONSIGNMENT_KEY",
+        value_hint = ValueHint::FilePath
+    )]
+    pub consignment_key: Option<PathBuf>,
+
+    /// Contract which consignments received from the remote nodes are accepted
+    /// automatically.
+    ///
+    /// If any contracts or schemata are whitelisted, consignments of the other
+    /// contracts are kept in the quarantine until they are approved with
+    /// `quarantine approve` command. Can be used multiple times.
+    #[clap(
+        long = "accept-contract",
+        global = true,
+        env = "RGB_NODE_ACCEPT_CONTRACTS",
+        value_delimiter = ','
+    )]
+    pub accept_contracts: Vec<ContractId>,
+
+    /// Schema which contracts are accepted automatically, in addition to the
+    /// contracts whitelisted with `--accept-contract`. Can be used multiple
+    /// times.
+    #[clap(
+        long = "accept-schema",
+        global = true,
+        env = "RGB_NODE_ACCEPT_SCHEMATA",
+        value_delimiter = ','
+    )]
+    pub accept_schemata: Vec<SchemaId>,
+
+    /// Contract which consignments received from the remote nodes are always
+    /// rejected. Can be used multiple times.
+    #[clap(
+        long = "reject-contract",
+        global = true,
+        env = "RGB_NODE_REJECT_CONTRACTS",
+        value_delimiter = ','
+    )]
+    pub reject_contracts: Vec<ContractId>,
+
+    /// Hook notified about node events: incoming allocations, confirmed
+    /// transfers, validation failures and changes of the asset supply.
+    ///
+    /// Hook is either an `http://` or `https://` URL receiving JSON payload
+    /// with POST request, or `exec:<command>` receiving the payload from the standard
+    /// input. Prefix it with `<contract_id>:` to receive only the events of a
+    /// specific contract. Can be used multiple times.
+    #[clap(long = "hook", global = true, env = "RGB_NODE_HOOKS")]
+    pub hooks: Vec<String>,
+
+    /// Secret key for HMAC-SHA256 signing of the hook payloads.
+    ///
+    /// The signature covers `<timestamp>.<delivery>.<payload>` string, where
+    /// the timestamp and the delivery id are given in `X-RGB-Timestamp` and
+    /// `X-RGB-Delivery` HTTP headers. Hooks should reject stale timestamps and
+    /// repeated delivery ids. The signature is provided in `X-RGB-Signature`
+    /// HTTP header. Hook commands receive the same values in
+    /// `RGB_HOOK_TIMESTAMP`, `RGB_HOOK_DELIVERY` and `RGB_HOOK_SIGNATURE`
+    /// environment variables.
+    #[clap(long, global = true, env = "RGB_NODE_HOOK_SECRET")]
+    pub hook_secret: Option<String>,
+
+    /// Number of repeated attempts to deliver event to a failing hook, with the
+    /// delay doubling after each attempt.
+    #[clap(long, global = true, default_value = "5", env = "RGB_NODE_HOOK_RETRIES")]
+    pub hook_retries: u8,
+
+    /// Number of days consignment containers are kept in the store, unless
+    /// the consignment is accepted, in which case they are removed
+    /// immediately. Expired containers are removed with `gc` command.
+    #[clap(long, global = true, default_value = "14", env = "RGB_NODE_CONTAINER_RETENTION")]
+    pub container_retention: u16,
+
+    /// Period after which quarantined consignments, records of completed
+    /// transfers, contract journals and enclosed disclosures are pruned from
+    /// the store, given as a number of days (`30d`), a number of blocks
+    /// (`4320b`) or `never`. Pruning is performed automatically once an hour
+    /// and with `prune` command.
+    #[clap(long, global = true, default_value = "never", env = "RGB_NODE_RETENTION")]
+    pub retention: RetentionPeriod,
+
+    /// Retention period for a specific category of data, overriding the one
+    /// given with `--retention`, in form of `<category>=<period>`. Category is
+    /// one of `quarantine`, `transfers`, `journal` or `disclosures`.
+    ///
+    /// Can be used multiple times.
+    #[clap(
+        long = "retention-override",
+        global = true,
+        env = "RGB_NODE_RETENTION_OVERRIDES",
+        value_parser = RetentionPeriod::parse_override
+    )]
+    pub retention_overrides: Vec<(RetentionCategory, RetentionPeriod)>,
+
+    /// PostgreSQL connection string of a database receiving a mirror of the
+    /// accepted contract data: contracts, state transitions and allocations.
+    /// Requires node compiled with `sql` feature.
+    ///
+    /// The database may be shared by several nodes, including replicas, which
+    /// keep its schema migrated to the most recent version known to them.
+    #[clap(long, global = true, env = "RGB_NODE_SQL_EXPORT")]
+    pub sql_export: Option<String>,
+
+    /// WebAssembly module validating consignments of the contracts with a
+    /// given schema after the core validation, in form of
+    /// `<schema_id>:<path>`.
+    ///
+    /// Consignments rejected by any of the schema plugins are not accepted.
+    /// Requires node compiled with `plugins` feature. Can be used multiple
+    /// times.
+    #[clap(
+        long = "validation-plugin",
+        global = true,
+        env = "RGB_NODE_VALIDATION_PLUGINS",
+        value_parser = parse_plugin,
+        value_hint = ValueHint::FilePath
+    )]
+    pub validation_plugins: Vec<(SchemaId, PathBuf)>,
+
+    /// Maximum amount of fuel, roughly corresponding to the number of executed
+    /// instructions, which a validation plugin may consume per consignment.
+    #[clap(long, global = true, default_value = "1000000000", env = "RGB_NODE_PLUGIN_FUEL")]
+    pub plugin_fuel: u64,
+
+    /// Maximum number of contract nodes which scripts are executed during a
+    /// consignment validation.
+    ///
+    /// Consignments exceeding the limit are refused before the validation
+    /// starts.
+    #[clap(long, global = true, default_value = "100000", env = "RGB_NODE_MAX_SCRIPT_RUNS")]
+    pub max_script_runs: u32,
+
+    /// Maximum size of the contract schema validation script, in bytes.
+    #[clap(long, global = true, default_value = "1048576", env = "RGB_NODE_MAX_SCRIPT_SIZE")]
+    pub max_script_size: u32,
+
+    /// Number of decoy change allocations added to the payments composed by
+    /// the node.
+    ///
+    /// Change of the paid contract is split into the additional allocations
+    /// with random amounts, each assigned to a separate output of the wallet
+    /// change keychain, so the counterparties can't tell the change from the
+    /// payment by the amounts. Each decoy output receives 1000 sats. Zero
+    /// disables the decoys.
+    #[clap(long, global = true, default_value = "0", env = "RGB_NODE_DECOY_CHANGE")]
+    pub decoy_change: u8,
+
+    /// Device, named pipe or file descriptor (as `/dev/fd/<no>`) providing
+    /// the entropy for the seal and amount blinding factors, like a hardware
+    /// RNG or a pipe fed by an HSM.
+    ///
+    /// The source is checked before each use; invoices, issues and payments
+    /// are refused while the check fails. Defaults to the operating system
+    /// RNG. Regular files are rejected, since their content would be reused
+    /// for the blinding of each operation; pre-generated entropy has to be
+    /// fed through a named pipe.
+    #[clap(long, global = true, env = "RGB_NODE_ENTROPY_SOURCE")]
+    pub entropy_source: Option<PathBuf>,
+
+    /// Output descriptor of a wallet delegated to an embedded BDK wallet.
+    ///
+    /// Registered wallets are synchronized by BDK, which also selects UTXOs
+    /// paying the payment fees. If the descriptor contains private keys, BDK
+    /// signs PSBTs spending the wallet outputs when they are published through
+    /// the node. Requests refer the wallet with its public descriptor. Requires
+    /// node compiled with `bdk-wallet` feature. Can be used multiple times.
+    #[clap(long = "bdk-wallet", global = true, env = "RGB_NODE_BDK_WALLETS")]
+    pub bdk_wallets: Vec<String>,
 }
 
 #[cfg(feature = "server")]
@@ -126,3 +367,12 @@ impl Opts {
         )]);
     }
 }
+
+/// Parses validation plugin given in form of `<schema_id>:<path>`
+fn parse_plugin(s: &str) -> Result<(SchemaId, PathBuf), String> {
+    let (schema_id, path) = s
+        .split_once(':')
+        .ok_or_else(|| format!("validation plugin `{}` must have `<schema_id>:<path>` form", s))?;
+    let schema_id = SchemaId::from_str(schema_id).map_err(|err| err.to_string())?;
+    Ok((schema_id, PathBuf::from(path)))
+}
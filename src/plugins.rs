@@ -0,0 +1,217 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Validation plugins: WebAssembly modules provided by the node operator,
+//! which run additional validation logic for the consignments of contracts
+//! with specific schemata once the consignment passes the core validation.
+//!
+//! # Plugin ABI
+//!
+//! Plugin module may not import any host functions and must export:
+//! - `memory`: linear memory of the module;
+//! - `rgb_plugin_abi() -> i32`: version of the plugin ABI implemented by the module, which must be
+//!   equal to [`PLUGIN_ABI_VERSION`];
+//! - `rgb_alloc(len: i32) -> i32`: allocates buffer of `len` bytes in the module memory, returning
+//!   its offset;
+//! - `rgb_validate(ptr: i32, len: i32) -> i64`: validates strict-encoded consignment, which is
+//!   written by the node into the buffer allocated with `rgb_alloc`. Returns zero if the
+//!   consignment is accepted. Otherwise the higher 32 bits of the returned value are the offset and
+//!   the lower 32 bits are the length of UTF-8 string in the module memory explaining the reason of
+//!   the rejection.
+//!
+//! Each consignment is validated by a fresh module instance, which memory is
+//! limited to [`PLUGIN_MEMORY_LIMIT`] bytes and which may consume no more fuel
+//! than configured with `--plugin-fuel` node option.
+
+#[cfg(feature = "plugins")]
+use std::collections::BTreeMap;
+#[cfg(feature = "plugins")]
+use std::fs;
+
+use rgb::SchemaId;
+use rgb_rpc::FailureCode;
+#[cfg(feature = "plugins")]
+use strict_encoding::StrictEncode;
+#[cfg(feature = "plugins")]
+use wasmi::{Engine, Instance, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+use crate::Config;
+
+/// Version of the plugin ABI supported by the node
+pub const PLUGIN_ABI_VERSION: i32 = 1;
+
+/// Maximum size of the validation plugin memory, in bytes
+pub const PLUGIN_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Maximum length of the rejection reason reported by a plugin
+#[cfg(feature = "plugins")]
+const MAX_REASON_LEN: usize = 1024;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum PluginError {
+    /// validation plugins are configured, but the node is compiled without
+    /// `plugins` feature.
+    Unsupported,
+
+    /// unable to load validation plugin {0}. Details: {1}
+    Load(String, String),
+
+    /// validation plugin {0} implements ABI version {1}, while the node
+    /// supports only version 1.
+    Abi(String, i32),
+
+    /// validation plugin {0} has failed. Details: {1}
+    Execution(String, String),
+
+    /// consignment is rejected by validation plugin {plugin} for schema
+    /// {schema_id}: {reason}
+    Rejected {
+        schema_id: SchemaId,
+        plugin: String,
+        reason: String,
+    },
+}
+
+impl From<&PluginError> for FailureCode {
+    fn from(err: &PluginError) -> Self {
+        match err {
+            PluginError::Rejected { .. } => FailureCode::PluginRejected,
+            _ => FailureCode::Plugin,
+        }
+    }
+}
+
+/// Checks that the configured validation plugins can be loaded
+pub(crate) fn check_plugins(config: &Config) -> Result<(), PluginError> {
+    #[cfg(feature = "plugins")]
+    PluginHost::load(config)?;
+    #[cfg(not(feature = "plugins"))]
+    if !config.validation_plugins.is_empty() {
+        return Err(PluginError::Unsupported);
+    }
+    Ok(())
+}
+
+/// Executor of the validation plugins
+#[cfg(feature = "plugins")]
+pub(crate) struct PluginHost {
+    engine: Engine,
+    /// Compiled plugin modules with the paths they are loaded from
+    modules: BTreeMap<SchemaId, Vec<(String, Module)>>,
+    fuel: u64,
+}
+
+#[cfg(feature = "plugins")]
+impl PluginHost {
+    /// Compiles configured plugins, checking the ABI version they implement
+    pub fn load(config: &Config) -> Result<PluginHost, PluginError> {
+        let mut wasm_config = wasmi::Config::default();
+        wasm_config.consume_fuel(true);
+        let mut host = PluginHost {
+            engine: Engine::new(&wasm_config),
+            modules: empty!(),
+            fuel: config.plugin_fuel,
+        };
+        for (schema_id, file) in &config.validation_plugins {
+            let path = file.display().to_string();
+            let wasm =
+                fs::read(file).map_err(|err| PluginError::Load(path.clone(), err.to_string()))?;
+            let module = Module::new(&host.engine, &wasm[..])
+                .map_err(|err| PluginError::Load(path.clone(), err.to_string()))?;
+            let (mut store, instance) = host.instantiate(&path, &module)?;
+            let abi = instance
+                .get_typed_func::<(), i32>(&store, "rgb_plugin_abi")
+                .and_then(|func| func.call(&mut store, ()))
+                .map_err(|err| PluginError::Load(path.clone(), err.to_string()))?;
+            if abi != PLUGIN_ABI_VERSION {
+                return Err(PluginError::Abi(path, abi));
+            }
+            debug!("Validation plugin {} is loaded for schema {}", path, schema_id);
+            host.modules.entry(*schema_id).or_default().push((path, module));
+        }
+        Ok(host)
+    }
+
+    /// Runs all plugins registered for the schema, failing on the first
+    /// plugin rejecting the consignment
+    pub fn validate(
+        &self,
+        schema_id: SchemaId,
+        consignment: &impl StrictEncode,
+    ) -> Result<(), PluginError> {
+        let modules = match self.modules.get(&schema_id) {
+            Some(modules) => modules,
+            None => return Ok(()),
+        };
+        let data = consignment.strict_serialize().expect("in-memory encoding failure");
+        for (path, module) in modules {
+            debug!("Running validation plugin {} for schema {}", path, schema_id);
+            if let Some(reason) = self.run(path, module, &data)? {
+                return Err(PluginError::Rejected {
+                    schema_id,
+                    plugin: path.clone(),
+                    reason,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes plugin, returning the rejection reason, if any
+    fn run(&self, path: &str, module: &Module, data: &[u8]) -> Result<Option<String>, PluginError> {
+        let failure = |err: wasmi::Error| PluginError::Execution(path.to_owned(), err.to_string());
+        let (mut store, instance) = self.instantiate(path, module)?;
+        let memory = instance.get_memory(&store, "memory").ok_or_else(|| {
+            PluginError::Execution(path.to_owned(), s!("module does not export its memory"))
+        })?;
+        let alloc = instance.get_typed_func::<i32, i32>(&store, "rgb_alloc").map_err(failure)?;
+        let validate =
+            instance.get_typed_func::<(i32, i32), i64>(&store, "rgb_validate").map_err(failure)?;
+
+        let len = i32::try_from(data.len()).map_err(|_| {
+            PluginError::Execution(path.to_owned(), s!("consignment exceeds plugin memory"))
+        })?;
+        let ptr = alloc.call(&mut store, len).map_err(failure)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, data)
+            .map_err(|err| PluginError::Execution(path.to_owned(), err.to_string()))?;
+        let res = validate.call(&mut store, (ptr, len)).map_err(failure)? as u64;
+        if res == 0 {
+            return Ok(None);
+        }
+
+        let offset = (res >> 32) as usize;
+        let len = ((res & 0xFFFF_FFFF) as usize).min(MAX_REASON_LEN);
+        let mut reason = vec![0u8; len];
+        memory
+            .read(&store, offset, &mut reason)
+            .map_err(|err| PluginError::Execution(path.to_owned(), err.to_string()))?;
+        Ok(Some(String::from_utf8_lossy(&reason).into_owned()))
+    }
+
+    /// Creates new module instance with the memory and fuel limits applied
+    fn instantiate(
+        &self,
+        path: &str,
+        module: &Module,
+    ) -> Result<(Store<StoreLimits>, Instance), PluginError> {
+        let limits =
+            StoreLimitsBuilder::new().memory_size(PLUGIN_MEMORY_LIMIT).instances(1).build();
+        let mut store = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.add_fuel(self.fuel).expect("fuel metering is enabled");
+        let instance = Linker::<StoreLimits>::new(&self.engine)
+            .instantiate(&mut store, module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|err| PluginError::Execution(path.to_owned(), err.to_string()))?;
+        Ok((store, instance))
+    }
+}
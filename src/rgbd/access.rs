@@ -0,0 +1,527 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Access control of a node shared by multiple users, enabled by configuring
+//! the administrator keys with `--admin-key`.
+//!
+//! Clients authenticate with their identity keys right after the hello
+//! request, signing the node identity and the nonce issued in response to the
+//! request; each nonce is accepted once. Contracts become accessible to the
+//! client which has issued, imported or received them, and wallets to the
+//! clients which an administrator has granted them to. Swaps and payjoins
+//! may be completed or aborted only by the client which has created them.
+//! Administrators and the node itself, authenticated with the node key, have
+//! access to the whole stash.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::secp256k1::rand::random;
+use bitcoin::secp256k1::SECP256K1;
+use bitcoin::XOnlyPublicKey;
+use microservices::esb::ClientId;
+use rgb::ContractId;
+use rgb_rpc::{
+    AccessGrants, AccessReq, AccessTarget, Annotation, AnnotationTarget, Client, ClientAuth,
+    FailureCode, NodeEvent, PayjoinId, RpcMsg, SwapId, TransferFilter,
+};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::Runtime;
+use crate::bucketd::{PayjoinRecord, SwapRecord};
+use crate::bus::{DaemonId, Endpoints, Responder};
+use crate::db::{self, StoreRpcExt};
+use crate::{Config, DaemonError};
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum AccessError {
+    /// node requires clients to authenticate with their identity key before
+    /// `{0}` request.
+    Unauthenticated(String),
+
+    /// client authentication has invalid signature or does not sign the
+    /// node identity and the nonce issued by the node for the connection.
+    Unauthorized,
+
+    /// `{0}` request is allowed to the node administrators only.
+    AdminOnly(String),
+
+    /// client {0} has no access to {1}.
+    Denied(XOnlyPublicKey, AccessTarget),
+
+    /// client {0} was not granted access to {1}.
+    NotGranted(XOnlyPublicKey, AccessTarget),
+
+    /// client {0} has not created swap {1}.
+    SwapDenied(XOnlyPublicKey, SwapId),
+
+    /// client {0} has not created payjoin {1}.
+    PayjoinDenied(XOnlyPublicKey, PayjoinId),
+
+    /// node does not control access of the clients; it must be started with
+    /// `--admin-key`.
+    Disabled,
+}
+
+impl From<&AccessError> for FailureCode {
+    fn from(err: &AccessError) -> Self {
+        match err {
+            AccessError::Unauthorized => FailureCode::InvalidSignature,
+            _ => FailureCode::Access,
+        }
+    }
+}
+
+/// Part of the stash a request operates on
+enum Scope {
+    /// Requests which do not require authentication
+    Open,
+
+    /// Requests available to any authenticated client
+    Public,
+
+    /// Requests available to the clients having access to all of the given
+    /// contracts and wallets
+    Owned(Vec<AccessTarget>),
+
+    /// Requests available to the client which has created the swap
+    Swap(SwapId),
+
+    /// Requests available to the client which has created the payjoin
+    Payjoin(PayjoinId),
+
+    /// Requests affecting the node as a whole
+    Admin,
+}
+
+impl Scope {
+    fn of(msg: &RpcMsg) -> Scope {
+        let contract =
+            |contract_id: ContractId| Scope::Owned(vec![AccessTarget::Contract(contract_id)]);
+        let wallet =
+            |descriptor: &String| Scope::Owned(vec![AccessTarget::Wallet(descriptor.clone())]);
+        match msg {
+            RpcMsg::Hello(_)
+            | RpcMsg::Ping(_)
+            | RpcMsg::Authenticate(_)
+            | RpcMsg::RelayDeposit(_)
+            | RpcMsg::RelayPickup(_)
+            | RpcMsg::RelayFetch(_) => Scope::Open,
+
+            // Contract list is filtered by the accessible contracts
+            RpcMsg::ListContracts
+            | RpcMsg::ListSchemata
+            | RpcMsg::IssueContract(_)
+            | RpcMsg::PreviewIssue(_)
+            | RpcMsg::ImportContract(_)
+            | RpcMsg::ConsumeContract(_)
+            | RpcMsg::ConsumeTransfer(_)
+            | RpcMsg::UploadConsignment(_)
+            | RpcMsg::ConsumeUpload(_)
+            | RpcMsg::ProcessDisclosure(_)
+            | RpcMsg::ProveTransactions(_)
+            | RpcMsg::MemorizeSeal(_)
+            | RpcMsg::PublishTx(_)
+            | RpcMsg::GetHealth => Scope::Public,
+
+            // Responses are filtered by the contracts and wallets granted to
+            // the client
+            RpcMsg::GetOutpointState(_)
+            | RpcMsg::QueryOutpointState(_)
+            | RpcMsg::ListWallets
+            | RpcMsg::GetReceipt(_) => Scope::Public,
+
+            RpcMsg::GetContractState(contract_id)
+            | RpcMsg::GetContractStats(contract_id)
+            | RpcMsg::ListAllocations(contract_id)
+            | RpcMsg::GetStateDigest(contract_id)
+            | RpcMsg::GetKnownHistory(contract_id)
+            | RpcMsg::GetPolicy(contract_id)
+            | RpcMsg::GetJournal(contract_id) => contract(*contract_id),
+            RpcMsg::GetContractDeps(Some(contract_id)) => contract(*contract_id),
+            RpcMsg::DiffHistory(req) => contract(req.contract_id),
+            RpcMsg::ConsignContract(req) | RpcMsg::ConsignTransfer(req) => {
+                contract(req.contract_id)
+            }
+            RpcMsg::CreateCheckpoint(req) => contract(req.contract_id),
+            RpcMsg::SignCheckpoint(checkpoint) => contract(checkpoint.contract_id),
+            RpcMsg::SetPolicy(req) => contract(req.contract_id),
+            RpcMsg::ConsolidateAllocations(req) => contract(req.contract_id),
+            RpcMsg::RewindContract(req) => contract(req.contract_id),
+            RpcMsg::ReplayConsignments(req) => contract(req.contract_id),
+            RpcMsg::RevealSeal(req) => contract(req.contract_id),
+            RpcMsg::CreateInvoice(req) => contract(req.contract_id),
+            RpcMsg::ListInvoices(contract_id) => contract(*contract_id),
+            RpcMsg::ExportReveals(req) => contract(req.contract_id),
+            RpcMsg::Transfer(req) => contract(req.consignment.contract_id()),
+            RpcMsg::PreviewTransfer(req) => contract(req.consignment.contract_id()),
+            RpcMsg::SendTransfer(req) => contract(req.consignment.contract_id()),
+            RpcMsg::FinalizeTransfers(req) => Scope::Owned(
+                req.transfers
+                    .iter()
+                    .map(|(consignment, _)| AccessTarget::Contract(consignment.contract_id()))
+                    .collect(),
+            ),
+            RpcMsg::ProposeSwap(req) => Scope::Owned(
+                req.consignment
+                    .iter()
+                    .map(|consignment| AccessTarget::Contract(consignment.contract_id()))
+                    .collect(),
+            ),
+            RpcMsg::AcceptSwap(req) => Scope::Owned(
+                req.consignment
+                    .iter()
+                    .map(|consignment| AccessTarget::Contract(consignment.contract_id()))
+                    .collect(),
+            ),
+            RpcMsg::ProposePayjoin(req) => contract(req.consignment.contract_id()),
+            RpcMsg::CompleteSwap(req) => Scope::Swap(req.acceptance.swap_id),
+            RpcMsg::AbortSwap(swap_id) => Scope::Swap(*swap_id),
+            RpcMsg::CompletePayjoin(req) => Scope::Payjoin(req.contribution.payjoin_id),
+            RpcMsg::ListTransfers(TransferFilter {
+                contract_id: Some(contract_id),
+                ..
+            }) => contract(*contract_id),
+            RpcMsg::Annotate(Annotation {
+                target: AnnotationTarget::Contract(contract_id),
+                ..
+            }) => contract(*contract_id),
+            RpcMsg::Annotate(_) => Scope::Public,
+            RpcMsg::GetAnnotations(targets) if !targets.is_empty() => Scope::Owned(
+                targets
+                    .iter()
+                    .filter_map(|target| match target {
+                        AnnotationTarget::Contract(contract_id) => {
+                            Some(AccessTarget::Contract(*contract_id))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+
+            RpcMsg::ContributePayjoin(req) => wallet(&req.descriptor),
+            RpcMsg::SyncWallet(req) => wallet(&req.descriptor),
+            RpcMsg::GetBalance(descriptor) | RpcMsg::CreateWallet(descriptor) => wallet(descriptor),
+            RpcMsg::ListAddresses(req) => wallet(&req.descriptor),
+            RpcMsg::AccelerateTransfer(req) => wallet(&req.descriptor),
+            RpcMsg::ForgetSpent(req) => wallet(&req.descriptor),
+            RpcMsg::ComposePayment(req) => Scope::Owned(vec![
+                AccessTarget::Contract(req.contract_id),
+                AccessTarget::Wallet(req.descriptor.clone()),
+            ]),
+            RpcMsg::GetHistoricalBalance(req) => Scope::Owned(
+                req.descriptor
+                    .iter()
+                    .cloned()
+                    .map(AccessTarget::Wallet)
+                    .chain(Some(AccessTarget::Contract(req.contract_id)))
+                    .collect(),
+            ),
+
+            _ => Scope::Admin,
+        }
+    }
+}
+
+/// Identities of the authenticated clients and the contracts and wallets
+/// accessible by them
+#[derive(Default)]
+pub(crate) struct AccessControl {
+    /// Administrator identities, including the node key; access control is
+    /// disabled if there are none
+    admins: BTreeSet<XOnlyPublicKey>,
+
+    identities: BTreeMap<ClientId, XOnlyPublicKey>,
+
+    /// Authentication nonces issued to the clients in response to their
+    /// hello requests. Nonce is removed once the client uses it.
+    nonces: BTreeMap<ClientId, u64>,
+
+    grants: BTreeMap<XOnlyPublicKey, AccessGrants>,
+
+    /// Client which request was the last one completed by each of the bucket
+    /// daemons. Events reported by a daemon after completing a request are
+    /// attributed to the client.
+    completed: BTreeMap<DaemonId, ClientId>,
+}
+
+impl AccessControl {
+    /// Loads grants from the store, if access control is enabled
+    pub fn load(
+        config: &Config,
+        node_key: Option<XOnlyPublicKey>,
+        store: &mut store_rpc::Client,
+    ) -> Result<AccessControl, DaemonError> {
+        if config.admin_keys.is_empty() {
+            return Ok(default!());
+        }
+        let mut grants = bmap! {};
+        for key in store.ids(db::ACCESS_GRANTS)? {
+            if let Some(record) = store.retrieve_sten::<AccessGrants>(db::ACCESS_GRANTS, key)? {
+                grants.insert(record.identity, record);
+            }
+        }
+        Ok(AccessControl {
+            admins: config.admin_keys.iter().copied().chain(node_key).collect(),
+            identities: empty!(),
+            nonces: empty!(),
+            grants,
+            completed: empty!(),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool { !self.admins.is_empty() }
+
+    /// Registers the client which request was completed by the bucket daemon
+    pub fn complete(&mut self, daemon_id: DaemonId, client_id: Option<ClientId>) {
+        match client_id {
+            Some(client_id) => self.completed.insert(daemon_id, client_id),
+            None => self.completed.remove(&daemon_id),
+        };
+    }
+
+    /// Issues new authentication nonce for the client, replacing the one
+    /// issued before.
+    pub fn issue_nonce(&mut self, client_id: ClientId) -> u64 {
+        let nonce = random();
+        self.nonces.insert(client_id, nonce);
+        nonce
+    }
+
+    /// Returns identity the client has authenticated with
+    pub fn identity(&self, client_id: ClientId) -> Option<XOnlyPublicKey> {
+        self.identities.get(&client_id).copied()
+    }
+
+    /// Checks whether the client may see the contract
+    pub fn is_visible(&self, client_id: ClientId, contract_id: ContractId) -> bool {
+        self.restriction(client_id)
+            .map(|grants| grants.map_or(false, |grants| grants.contracts.contains(&contract_id)))
+            .unwrap_or(true)
+    }
+
+    /// Returns contracts visible to the client, or `None` if the client sees
+    /// the whole stash.
+    pub fn visible_contracts(&self, client_id: ClientId) -> Option<BTreeSet<ContractId>> {
+        self.restriction(client_id)
+            .map(|grants| grants.map(|grants| grants.contracts.clone()).unwrap_or_default())
+    }
+
+    /// Returns wallets visible to the client, or `None` if the client sees all
+    /// the wallets.
+    pub fn visible_wallets(&self, client_id: ClientId) -> Option<BTreeSet<String>> {
+        self.restriction(client_id)
+            .map(|grants| grants.map(|grants| grants.wallets.clone()).unwrap_or_default())
+    }
+
+    /// Returns grants the client is restricted to, or `None` if access
+    /// control is disabled or the client is an administrator.
+    fn restriction(&self, client_id: ClientId) -> Option<Option<&AccessGrants>> {
+        if !self.is_enabled() {
+            return None;
+        }
+        match self.identities.get(&client_id) {
+            Some(identity) if self.admins.contains(identity) => None,
+            Some(identity) => Some(self.grants.get(identity)),
+            None => Some(None),
+        }
+    }
+}
+
+fn grants_key(identity: XOnlyPublicKey) -> ChunkId {
+    ChunkId::with_fixed_fragments(db::ACCESS_GRANTS, identity)
+}
+
+/// Authenticates RPC client used by the node itself with the node key, if the
+/// key is configured. The key also signs orders of the client.
+pub(crate) fn authenticate_node(client: &mut Client, config: &Config) -> Result<(), String> {
+    match config.consignment_keypair().map_err(|err| err.to_string())? {
+        Some(keypair) => {
+            client.set_order_key(Some(keypair));
+            client.authenticate(&keypair).map_err(|err| err.to_string())
+        }
+        None => Ok(()),
+    }
+}
+
+impl Runtime {
+    /// Checks whether the client has access to the contracts and wallets the
+    /// request operates on.
+    pub(super) fn check_access(
+        &mut self,
+        client_id: ClientId,
+        msg: &RpcMsg,
+    ) -> Result<(), DaemonError> {
+        if !self.access.is_enabled() {
+            return Ok(());
+        }
+        let scope = Scope::of(msg);
+        if matches!(scope, Scope::Open) {
+            return Ok(());
+        }
+        let identity = *self
+            .access
+            .identities
+            .get(&client_id)
+            .ok_or_else(|| AccessError::Unauthenticated(msg.to_string()))?;
+        if self.access.admins.contains(&identity) {
+            return Ok(());
+        }
+        let targets = match scope {
+            Scope::Open | Scope::Public => return Ok(()),
+            Scope::Admin => return Err(AccessError::AdminOnly(msg.to_string()).into()),
+            // Unknown records are reported by the request handlers
+            Scope::Swap(swap_id) => {
+                return match self.store.retrieve_sten::<SwapRecord>(db::SWAPS, swap_id)? {
+                    Some(record) if record.owner != Some(identity) => {
+                        Err(AccessError::SwapDenied(identity, swap_id).into())
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Scope::Payjoin(payjoin_id) => {
+                return match self.store.retrieve_sten::<PayjoinRecord>(db::PAYJOINS, payjoin_id)? {
+                    Some(record) if record.owner != Some(identity) => {
+                        Err(AccessError::PayjoinDenied(identity, payjoin_id).into())
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Scope::Owned(targets) => targets,
+        };
+        let grants = self.access.grants.get(&identity);
+        match targets.into_iter().find(|target| !grants.map_or(false, |g| g.contains(target))) {
+            Some(target) => Err(AccessError::Denied(identity, target).into()),
+            None => Ok(()),
+        }
+    }
+
+    pub(super) fn authenticate(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        auth: ClientAuth,
+    ) -> Result<(), DaemonError> {
+        // Nonce is consumed by the first authentication attempt, so the
+        // signed authentication can't be replayed
+        let nonce = self.access.nonces.remove(&client_id);
+        let node = self.identity.x_only_public_key().0;
+        let msg = match nonce == Some(auth.nonce) && auth.node == node && auth.verify(SECP256K1) {
+            true => {
+                info!("Client {} is authenticated as {}", client_id, auth.identity);
+                self.access.identities.insert(client_id, auth.identity);
+                RpcMsg::success()
+            }
+            false => DaemonError::from(AccessError::Unauthorized).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    pub(super) fn grant_access(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        req: AccessReq,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.access.is_enabled() {
+            true => {
+                info!("Granting access to {} to client {}", req.target, req.identity);
+                self.grant(req.identity, req.target)?;
+                RpcMsg::success()
+            }
+            false => DaemonError::from(AccessError::Disabled).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    pub(super) fn revoke_access(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        req: AccessReq,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.revoke(req) {
+            Ok(()) => RpcMsg::success(),
+            Err(err) => err.into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    pub(super) fn list_access(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.access.is_enabled() {
+            true => RpcMsg::AccessGrants(
+                self.access.grants.values().filter(|grants| !grants.is_empty()).cloned().collect(),
+            ),
+            false => DaemonError::from(AccessError::Disabled).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    /// Grants contracts registered or updated while processing a client
+    /// request to the client.
+    pub(super) fn grant_processed(
+        &mut self,
+        daemon_id: DaemonId,
+        events: &[NodeEvent],
+    ) -> Result<(), DaemonError> {
+        let identity = match self
+            .access
+            .completed
+            .get(&daemon_id)
+            .and_then(|client_id| self.access.identities.get(client_id))
+        {
+            Some(identity) if !self.access.admins.contains(identity) => *identity,
+            _ => return Ok(()),
+        };
+        for event in events {
+            match event {
+                NodeEvent::ContractRegistered(contract_id)
+                | NodeEvent::ConsignmentAccepted { contract_id, .. } => {
+                    self.grant(identity, AccessTarget::Contract(*contract_id))?;
+                }
+                NodeEvent::TransferUpdated(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn grant(&mut self, identity: XOnlyPublicKey, target: AccessTarget) -> Result<(), DaemonError> {
+        let grants =
+            self.access.grants.entry(identity).or_insert_with(|| AccessGrants::with(identity));
+        if grants.insert(target) {
+            self.store.store_sten(db::ACCESS_GRANTS, grants_key(identity), grants)?;
+        }
+        Ok(())
+    }
+
+    fn revoke(&mut self, req: AccessReq) -> Result<(), DaemonError> {
+        if !self.access.is_enabled() {
+            return Err(AccessError::Disabled.into());
+        }
+        let AccessReq { identity, target } = req;
+        match self.access.grants.get_mut(&identity) {
+            Some(grants) if grants.remove(&target) => {
+                info!("Revoking access to {} from client {}", target, identity);
+                self.store.store_sten(db::ACCESS_GRANTS, grants_key(identity), grants)?;
+                Ok(())
+            }
+            _ => Err(AccessError::NotGranted(identity, target).into()),
+        }
+    }
+}
@@ -13,14 +13,14 @@ use std::process::Command;
 use microservices::error::BootstrapError;
 use microservices::{DaemonHandle, Launcher, LauncherError};
 
-use crate::rgbd::Runtime;
+use crate::bus::DaemonId;
 use crate::{bucketd, Config, LaunchError};
 
 /// Daemons that can be launched by rgbd
 #[derive(Clone, Eq, PartialEq, Debug, Display)]
 pub enum Daemon {
-    #[display("bucketd")]
-    Bucketd,
+    #[display("bucketd<{0}>")]
+    Bucketd(DaemonId),
 }
 
 impl Launcher for Daemon {
@@ -29,7 +29,7 @@ impl Launcher for Daemon {
 
     fn bin_name(&self) -> &'static str {
         match self {
-            Daemon::Bucketd => "bucketd",
+            Daemon::Bucketd(_) => "bucketd",
         }
     }
 
@@ -39,27 +39,34 @@ impl Launcher for Daemon {
                 .skip(1)
                 .filter(|arg| !["--threaded"].iter().any(|pat| arg.starts_with(pat))),
         );
+        match self {
+            Daemon::Bucketd(daemon_id) => cmd.args(["--daemon-id", &daemon_id.to_string()]),
+        };
 
         Ok(())
     }
 
     fn run_impl(self, config: Config) -> Result<(), BootstrapError<LaunchError>> {
         match self {
-            Daemon::Bucketd => bucketd::run(config),
+            Daemon::Bucketd(daemon_id) => bucketd::run(Config {
+                daemon_id: Some(daemon_id),
+                ..config
+            }),
         }
     }
 }
 
-impl Runtime {
-    pub(crate) fn launch_daemon(
-        &self,
-        daemon: Daemon,
-        config: Config,
+impl Daemon {
+    /// Launches daemon either as a thread or as a child process, depending on
+    /// the configuration.
+    pub(crate) fn launch(
+        self,
+        config: &Config,
     ) -> Result<DaemonHandle<Daemon>, LauncherError<Daemon>> {
-        if self.config.threaded {
-            daemon.thread_daemon(config)
+        if config.threaded {
+            self.thread_daemon(config.clone())
         } else {
-            daemon.exec_daemon()
+            self.exec_daemon()
         }
     }
 }
@@ -0,0 +1,53 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use internet2::addr::ServiceAddr;
+use microservices::ZMQ_CONTEXT;
+use rgb_rpc::NodeEvent;
+use strict_encoding::StrictEncode;
+
+use crate::LaunchError;
+
+/// ZMQ PUB socket broadcasting node events to any number of subscribers
+pub(crate) struct EventPublisher {
+    socket: zmq::Socket,
+    json: bool,
+}
+
+impl EventPublisher {
+    pub fn bind(endpoint: &ServiceAddr, json: bool) -> Result<Self, LaunchError> {
+        debug!("Binding event publisher socket at {}", endpoint);
+        let socket = ZMQ_CONTEXT
+            .socket(zmq::PUB)
+            .and_then(|socket| socket.bind(&endpoint.zmq_connect_string()).map(|_| socket))
+            .map_err(|err| LaunchError::EventPublisher(err.to_string()))?;
+        Ok(EventPublisher { socket, json })
+    }
+
+    /// Sends event as a two-frame message consisting of the event topic and
+    /// either strict-encoded or JSON-serialized event data. Failures are only
+    /// logged, since subscribers must not affect the node operations.
+    pub fn publish(&self, event: &NodeEvent) {
+        debug!("Publishing {}", event);
+        let data = if self.json {
+            serde_json::to_vec(event).map_err(|err| err.to_string())
+        } else {
+            event.strict_serialize().map_err(|err| err.to_string())
+        };
+        let res = data.and_then(|data| {
+            self.socket
+                .send_multipart([event.topic().as_bytes(), &data], zmq::DONTWAIT)
+                .map_err(|err| err.to_string())
+        });
+        if let Err(err) = res {
+            warn!("Unable to publish {}: {}", event, err);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Faucet mode for public test deployments: the node pays small amounts of a
+//! contract it controls to any invoice submitted over HTTP.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bitcoin::Txid;
+use lnpbp::chain::Chain;
+use psbt::Psbt;
+use rgb::blank::BlankBundle;
+use rgb::psbt::{RgbExt, RgbInExt};
+use rgb::{AtomicValue, ContractId, Node, TransitionBundle};
+use rgb_rpc::{Client, Invoice, SignetChallenge};
+use strict_encoding::StrictEncode;
+
+use crate::rgbd::access::authenticate_node;
+use crate::Config;
+
+/// Timeout for reading the request from the faucet client and sending it the
+/// reply
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum size of the request head and body; invoices are much smaller
+const MAX_REQUEST_SIZE: u64 = 16 * 1024;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum FaucetError {
+    /// faucet can't be run on {0}; it may be used on test networks only.
+    Mainnet(Chain),
+
+    /// faucet requires descriptor of the wallet holding the contract
+    /// allocations, which must be provided with `--faucet-descriptor`.
+    NoDescriptor,
+
+    /// faucet requires external signer, which must be provided with
+    /// `--signer`.
+    NoSigner,
+
+    /// faucet of a node with access control or registered order keys requires
+    /// node key to authenticate and sign its requests, which must be provided
+    /// with `--consignment-key`.
+    NoNodeKey,
+
+    /// unable to bind faucet HTTP socket {0}. Details: {1}
+    Bind(SocketAddr, String),
+
+    /// malformed HTTP request. Details: {0}
+    Request(String),
+
+    /// unknown faucet request `{0}`; invoices must be submitted with `POST
+    /// /invoice`.
+    NotFound(String),
+
+    /// invalid invoice. Details: {0}
+    Invoice(String),
+
+    /// faucet pays with contract {0} only.
+    Contract(ContractId),
+
+    /// invoice is issued for a different network than the faucet works with.
+    Network,
+
+    /// invoice amount exceeds faucet limit of {0}.
+    Amount(AtomicValue),
+
+    /// too many requests; retry in {0} seconds.
+    RateLimited(u64),
+
+    /// payment has failed. Details: {0}
+    Payment(String),
+}
+
+impl FaucetError {
+    fn status(&self) -> &'static str {
+        match self {
+            FaucetError::NotFound(_) => "404 Not Found",
+            FaucetError::RateLimited(_) => "429 Too Many Requests",
+            FaucetError::Payment(_) => "500 Internal Server Error",
+            _ => "400 Bad Request",
+        }
+    }
+}
+
+/// Faucet paying from the contract allocations controlled by the node.
+///
+/// Faucet runs in a separate thread serving plain HTTP. Invoice submitted as
+/// the body of `POST /invoice` request is paid by the node over its own RPC
+/// interface in the same way as with `rgb-cli pay`, and the transfer
+/// consignment is returned in the reply (and sent to the invoice transport
+/// node, if any). Each IP address is paid at most once per the configured
+/// interval. Requests are served one after another, so the payments do not
+/// compete for the same allocations.
+pub(crate) struct Faucet {
+    contract_id: ContractId,
+    descriptor: String,
+    amount: AtomicValue,
+    fee: u64,
+    interval: Duration,
+    /// Time of the last payment to each IP address within the interval
+    served: HashMap<IpAddr, Instant>,
+}
+
+impl Faucet {
+    /// Starts the faucet, if it is configured for the node
+    pub fn start(config: &Config) -> Result<(), FaucetError> {
+        let contract_id = match config.faucet_contract {
+            Some(contract_id) => contract_id,
+            None => return Ok(()),
+        };
+        if matches!(config.chain, Chain::Mainnet | Chain::LiquidV1) {
+            return Err(FaucetError::Mainnet(config.chain.clone()));
+        }
+        let descriptor = config.faucet_descriptor.clone().ok_or(FaucetError::NoDescriptor)?;
+        if config.signer_endpoint.is_none() {
+            return Err(FaucetError::NoSigner);
+        }
+        if (!config.admin_keys.is_empty() || !config.order_keys.is_empty())
+            && config.consignment_key.is_none()
+        {
+            return Err(FaucetError::NoNodeKey);
+        }
+        let addr = config.faucet_listen.unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 8080)));
+        let listener =
+            TcpListener::bind(addr).map_err(|err| FaucetError::Bind(addr, err.to_string()))?;
+
+        let faucet = Faucet {
+            contract_id,
+            descriptor,
+            amount: config.faucet_amount,
+            fee: config.faucet_fee,
+            interval: Duration::from_secs(config.faucet_interval),
+            served: empty!(),
+        };
+        let config = config.clone();
+        thread::Builder::new()
+            .name(s!("faucet"))
+            .spawn(move || faucet.run(listener, config))
+            .expect("unable to start faucet thread");
+        info!("Faucet paying with contract {} listens on http://{}", contract_id, addr);
+        Ok(())
+    }
+
+    fn run(mut self, listener: TcpListener, config: Config) {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    warn!("Unable to accept faucet connection: {}", err);
+                    continue;
+                }
+            };
+            let result = match self.serve(&stream, &config) {
+                Ok((txid, consignment)) => {
+                    let headers = format!("X-Txid: {}\r\n", txid);
+                    reply(&mut stream, "200 OK", &headers, "application/octet-stream", &consignment)
+                }
+                Err(err) => {
+                    debug!("Faucet request is declined: {}", err);
+                    let message = format!("{}\n", err);
+                    reply(&mut stream, err.status(), "", "text/plain", message.as_bytes())
+                }
+            };
+            if let Err(err) = result {
+                warn!("Unable to reply to faucet client: {}", err);
+            }
+        }
+    }
+
+    /// Serves a single request, returning id of the payment transaction and
+    /// strict-encoded consignment
+    fn serve(
+        &mut self,
+        stream: &TcpStream,
+        config: &Config,
+    ) -> Result<(Txid, Vec<u8>), FaucetError> {
+        let ip = stream.peer_addr().map_err(|err| FaucetError::Request(err.to_string()))?.ip();
+        let (method, path, body) = read_request(stream)?;
+        if method != "POST" || path != "/invoice" {
+            return Err(FaucetError::NotFound(format!("{} {}", method, path)));
+        }
+        let invoice = String::from_utf8(body)
+            .map_err(|err| FaucetError::Invoice(err.to_string()))?
+            .trim()
+            .parse::<Invoice>()
+            .map_err(|err| FaucetError::Invoice(err.to_string()))?;
+        if invoice.contract_id != self.contract_id {
+            return Err(FaucetError::Contract(self.contract_id));
+        }
+        let signet = config.signet_challenge.as_ref().map(SignetChallenge::magic);
+        if !invoice.matches_signet(signet) {
+            return Err(FaucetError::Network);
+        }
+        if invoice.amount > self.amount {
+            return Err(FaucetError::Amount(self.amount));
+        }
+
+        let interval = self.interval;
+        self.served.retain(|_, served| served.elapsed() < interval);
+        if let Some(served) = self.served.get(&ip) {
+            return Err(FaucetError::RateLimited((interval - served.elapsed()).as_secs() + 1));
+        }
+
+        let user_agent = format!("rgbd-faucet/{}", env!("CARGO_PKG_VERSION"));
+        let mut client =
+            Client::with(config.rpc_endpoint.clone(), user_agent, config.chain.clone())
+                .map_err(payment)?;
+        client.set_signet(signet);
+        client.hello().map_err(payment)?;
+        authenticate_node(&mut client, config).map_err(FaucetError::Payment)?;
+        let reply = self.pay(&mut client, invoice)?;
+        self.served.insert(ip, Instant::now());
+        info!("Faucet has paid to {} with transaction {}", ip, reply.0);
+        Ok(reply)
+    }
+
+    /// Pays the invoice, signing and publishing the witness transaction
+    fn pay(&self, client: &mut Client, invoice: Invoice) -> Result<(Txid, Vec<u8>), FaucetError> {
+        let contract_id = self.contract_id;
+        // Open invoices receive the whole faucet amount
+        let amount = match invoice.is_open() {
+            true => self.amount,
+            false => invoice.amount,
+        };
+        let draft = client
+            .compose_payment(
+                self.descriptor.clone(),
+                contract_id,
+                bmap! { invoice.beneficiary => amount },
+                self.fee,
+                |_| (),
+            )
+            .map_err(payment)?;
+
+        let mut psbt = draft.psbt;
+        let node_id = draft.transition.node_id();
+        if !psbt.has_rgb_contract(contract_id) {
+            let contract = client.contract(contract_id, vec![], |_| ()).map_err(payment)?;
+            psbt.set_rgb_contract(contract).map_err(payment)?;
+        }
+        psbt.push_rgb_transition(draft.transition).map_err(payment)?;
+        for input in &mut psbt.inputs {
+            if draft.outpoints.contains(&input.previous_outpoint) {
+                input.set_rgb_consumer(contract_id, node_id).map_err(payment)?;
+            }
+        }
+        push_blank_transitions(client, &mut psbt, contract_id)?;
+
+        let transfer = client
+            .consign(contract_id, vec![], draft.outpoints, empty!(), |_| ())
+            .map_err(payment)?;
+        let transfer = client
+            .transfer(
+                transfer,
+                vec![invoice.beneficiary],
+                psbt,
+                invoice.transport,
+                invoice.key,
+                |_| (),
+            )
+            .map_err(payment)?;
+        let consignment = transfer.consignment.strict_serialize().map_err(payment)?;
+        let psbt = client.sign_psbt(transfer.psbt).map_err(payment)?;
+        let txid = client.publish_tx(psbt, |_| ()).map_err(payment)?;
+        Ok((txid, consignment))
+    }
+}
+
+fn payment(err: impl Display) -> FaucetError { FaucetError::Payment(err.to_string()) }
+
+/// Adds blank transitions moving allocations of other contracts assigned to
+/// the spent outputs
+fn push_blank_transitions(
+    client: &mut Client,
+    psbt: &mut Psbt,
+    contract_id: ContractId,
+) -> Result<(), FaucetError> {
+    let outpoints: BTreeSet<_> = psbt.inputs.iter().map(|input| input.previous_outpoint).collect();
+    let state_map = client.outpoint_state(outpoints, |_| ()).map_err(payment)?;
+    for (cid, outpoint_map) in state_map {
+        if cid == contract_id {
+            continue;
+        }
+        let contract = client.contract(cid, vec![], |_| ()).map_err(payment)?;
+        psbt.set_rgb_contract(contract).map_err(payment)?;
+        let blank_bundle = TransitionBundle::blank(&outpoint_map, &bmap! {}).map_err(payment)?;
+        for (transition, indexes) in blank_bundle.revealed_iter() {
+            psbt.push_rgb_transition(transition.clone()).map_err(payment)?;
+            for no in indexes {
+                psbt.inputs[*no as usize]
+                    .set_rgb_consumer(cid, transition.node_id())
+                    .map_err(payment)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads HTTP request, returning its method, path and body
+fn read_request(stream: &TcpStream) -> Result<(String, String, Vec<u8>), FaucetError> {
+    let malformed = |err: io::Error| FaucetError::Request(err.to_string());
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT)).map_err(malformed)?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT)).map_err(malformed)?;
+    let mut reader = BufReader::new(stream.take(MAX_REQUEST_SIZE));
+
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(malformed)?;
+    let mut request_line = line.split_whitespace();
+    let (method, path) = match (request_line.next(), request_line.next()) {
+        (Some(method), Some(path)) => (method.to_owned(), path.to_owned()),
+        _ => return Err(FaucetError::Request(s!("no request line"))),
+    };
+
+    let mut content_len = 0usize;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(malformed)? == 0 {
+            return Err(FaucetError::Request(s!("incomplete request head")));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_len = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| FaucetError::Request(s!("invalid content length")))?;
+            }
+        }
+    }
+
+    if content_len as u64 > MAX_REQUEST_SIZE {
+        return Err(FaucetError::Request(s!("request body is too large")));
+    }
+    let mut body = vec![0u8; content_len];
+    reader.read_exact(&mut body).map_err(malformed)?;
+    Ok((method, path, body))
+}
+
+fn reply(
+    stream: &mut TcpStream,
+    status: &str,
+    headers: &str,
+    content_type: &str,
+    body: &[u8],
+) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.0 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len(),
+        headers
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body)?;
+    stream.flush()
+}
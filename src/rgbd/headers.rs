@@ -0,0 +1,408 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bitcoin::util::uint::Uint256;
+use bitcoin::{BlockHash, BlockHeader};
+use lnpbp::chain::Chain;
+use rgb_rpc::{HeaderCheckpoint, HeaderTip, ProofHeader};
+
+use crate::db::{self, StoreRpcExt};
+use crate::electrum::ElectrumPool;
+use crate::headers::{HeaderChainState, HeaderError, HeaderSegment, SEGMENT_LEN};
+use crate::Config;
+
+/// Interval between header chain synchronizations
+const SYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Number of blocks between difficulty adjustments
+const RETARGET_INTERVAL: u32 = 2016;
+
+/// Expected time between difficulty adjustments, in seconds
+const TARGET_TIMESPAN: u32 = 14 * 24 * 60 * 60;
+
+/// Delay after which testnet allows a block with the minimal difficulty, in
+/// seconds
+const MIN_DIFFICULTY_DELAY: u32 = 20 * 60;
+
+/// Synchronizes the best block header chain from the electrum servers into the
+/// store.
+///
+/// Synchronization runs in a separate thread with its own electrum and store
+/// connections. Each header is checked to be connected to the previous one, to
+/// have the difficulty required by the network retarget rules and a valid
+/// proof of work for it. Retargets which can't be recomputed since the start
+/// of their period precedes the chain anchor are bound to the fourfold
+/// adjustment from the previous difficulty. Once the electrum servers switch to
+/// another branch, the tracked chain follows it only if the branch has more
+/// work than the tracked headers it replaces.
+#[derive(Clone)]
+pub(crate) struct HeaderTracker {
+    tip: Arc<Mutex<Option<(HeaderCheckpoint, Instant)>>>,
+}
+
+impl HeaderTracker {
+    /// Starts tracking, unless the node runs offline
+    pub fn start(config: Config) -> HeaderTracker {
+        let tracker = HeaderTracker { tip: default!() };
+        if config.offline {
+            return tracker;
+        }
+        let clone = tracker.clone();
+        thread::Builder::new()
+            .name(s!("headers"))
+            .spawn(move || clone.run(config))
+            .expect("unable to start header tracker thread");
+        tracker
+    }
+
+    /// Tip of the header chain after the last successful synchronization
+    pub fn tip(&self) -> Option<HeaderTip> {
+        let state = *self.state();
+        state.map(|(tip, synced)| HeaderTip {
+            height: tip.height,
+            block_hash: tip.block_hash,
+            synced: synced.elapsed().as_secs(),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<Option<(HeaderCheckpoint, Instant)>> {
+        self.tip.lock().expect("header tracker state is poisoned")
+    }
+
+    fn run(self, config: Config) {
+        let params = match PowParams::with(&config.chain) {
+            Ok(params) => params,
+            Err(err) => {
+                warn!("{}", err);
+                return;
+            }
+        };
+        let anchor = config.header_checkpoint.unwrap_or_else(|| HeaderCheckpoint {
+            height: 0,
+            block_hash: *config.chain.as_genesis_hash(),
+        });
+        let mut electrum = None;
+        let mut store = None;
+        loop {
+            if electrum.is_none() {
+                electrum = ElectrumPool::with(&config.electrum_urls)
+                    .map_err(|err| warn!("Header tracker can't connect to electrum: {}", err))
+                    .ok();
+            }
+            if store.is_none() {
+                store = store_rpc::Client::with(&config.store_endpoint)
+                    .map_err(|err| warn!("Header tracker can't connect to store: {}", err))
+                    .ok();
+            }
+            if let (Some(electrum), Some(store)) = (&electrum, &mut store) {
+                match sync(electrum, store, params, anchor) {
+                    Ok(tip) => *self.state() = Some((tip, Instant::now())),
+                    Err(err) => warn!("Unable to synchronize header chain: {}", err),
+                }
+            }
+            thread::sleep(SYNC_INTERVAL);
+        }
+    }
+}
+
+/// Proof of work rules of the tracked network
+#[derive(Copy, Clone, Debug)]
+struct PowParams {
+    /// Maximal target of the network in the compact form
+    limit_bits: u32,
+    /// Whether the difficulty is adjusted each [`RETARGET_INTERVAL`] blocks
+    retarget: bool,
+    /// Whether a block with the minimal difficulty is allowed once it is
+    /// mined [`MIN_DIFFICULTY_DELAY`] after the previous one
+    min_difficulty_blocks: bool,
+}
+
+impl PowParams {
+    fn with(chain: &Chain) -> Result<PowParams, HeaderError> {
+        let (limit_bits, retarget, min_difficulty_blocks) = match chain {
+            Chain::Mainnet => (0x1d00ffff, true, false),
+            Chain::Testnet3 => (0x1d00ffff, true, true),
+            Chain::Signet | Chain::SignetCustom(_) => (0x1e0377ae, true, false),
+            Chain::Regtest(_) => (0x207fffff, false, false),
+            chain => return Err(HeaderError::UnsupportedChain(chain.to_string())),
+        };
+        Ok(PowParams {
+            limit_bits,
+            retarget,
+            min_difficulty_blocks,
+        })
+    }
+
+    fn limit(self) -> Uint256 { BlockHeader::u256_from_compact_target(self.limit_bits) }
+}
+
+/// Headers of the chain being checked: the tracked headers up to the `start`
+/// height, followed by the pending headers which are not stored yet
+struct ChainView<'store> {
+    store: &'store mut store_rpc::Client,
+    state: HeaderChainState,
+    start: u32,
+    pending: Vec<BlockHeader>,
+    segment: Option<HeaderSegment>,
+}
+
+impl<'store> ChainView<'store> {
+    fn with(store: &'store mut store_rpc::Client, state: HeaderChainState, start: u32) -> Self {
+        ChainView {
+            store,
+            state,
+            start,
+            pending: vec![],
+            segment: None,
+        }
+    }
+
+    /// Returns header at the given height, if it is not below the chain anchor
+    fn header(&mut self, height: u32) -> Result<Option<BlockHeader>, HeaderError> {
+        if height >= self.start {
+            return Ok(self.pending.get((height - self.start) as usize).copied());
+        }
+        if height < self.state.anchor.height {
+            return Ok(None);
+        }
+        if height == self.state.anchor.height {
+            return Ok(Some(self.state.anchor_header.0));
+        }
+        if self.segment.as_ref().and_then(|segment| segment.get(height)).is_none() {
+            self.segment = self.store.retrieve_sten(db::HEADERS, HeaderSegment::key(height))?;
+        }
+        Ok(self.segment.as_ref().and_then(|segment| segment.get(height)))
+    }
+
+    fn known_header(&mut self, height: u32) -> Result<BlockHeader, HeaderError> {
+        self.header(height)?.ok_or(HeaderError::NotSynced(height))
+    }
+}
+
+/// Extends the tracked header chain up to the tip known to the electrum
+/// servers, returning the new tip
+fn sync(
+    electrum: &ElectrumPool,
+    store: &mut store_rpc::Client,
+    params: PowParams,
+    anchor: HeaderCheckpoint,
+) -> Result<HeaderCheckpoint, HeaderError> {
+    let mut state = match HeaderChainState::load(store)? {
+        Some(state) if state.anchor == anchor => state,
+        stored => {
+            if let Some(state) = stored {
+                info!(
+                    "Header chain anchor has changed from {} to {}; resyncing",
+                    state.anchor, anchor
+                );
+            }
+            let header = electrum.call(|client| client.block_header(anchor.height as usize))?;
+            if header.block_hash() != anchor.block_hash {
+                return Err(HeaderError::AnchorMismatch(anchor.height));
+            }
+            HeaderChainState {
+                anchor,
+                anchor_header: ProofHeader(header),
+                tip: anchor,
+            }
+        }
+    };
+    let initial = state.tip;
+
+    loop {
+        let from = state.tip.height + 1;
+        let count = SEGMENT_LEN - from % SEGMENT_LEN;
+        let res = electrum.call(|client| client.block_headers(from as usize, count as usize))?;
+        let first = match res.headers.first() {
+            Some(header) => header,
+            None => break,
+        };
+        if first.prev_blockhash != state.tip.block_hash {
+            state.tip = reorg(electrum, store, params, state)?;
+            store.store_sten(db::HEADER_TIP, HeaderChainState::key(), &state)?;
+            continue;
+        }
+
+        let fetched = res.headers.len() as u32;
+        state.tip = extend(store, params, state, from, res.headers)?;
+        store.store_sten(db::HEADER_TIP, HeaderChainState::key(), &state)?;
+
+        if fetched < count {
+            break;
+        }
+    }
+
+    if state.tip != initial {
+        debug!("Header chain is synchronized up to {}", state.tip);
+    }
+    Ok(state.tip)
+}
+
+/// Checks headers following the tracked chain at `from - 1` height and puts
+/// them into the store, returning the new tip. Stored headers at the same
+/// heights are replaced.
+fn extend(
+    store: &mut store_rpc::Client,
+    params: PowParams,
+    state: HeaderChainState,
+    from: u32,
+    headers: Vec<BlockHeader>,
+) -> Result<HeaderCheckpoint, HeaderError> {
+    let mut tip = None;
+    let mut view = ChainView::with(store, state, from);
+    for (height, header) in (from..).zip(headers) {
+        tip = Some(HeaderCheckpoint {
+            height,
+            block_hash: check_header(&mut view, params, &header, height)?,
+        });
+        view.pending.push(header);
+    }
+    let headers = view.pending;
+    let tip = tip.ok_or(HeaderError::NotSynced(from))?;
+
+    let mut rest = &headers[..];
+    let mut height = from;
+    while !rest.is_empty() {
+        let len = rest.len().min((SEGMENT_LEN - height % SEGMENT_LEN) as usize);
+        let stored: Option<HeaderSegment> =
+            store.retrieve_sten(db::HEADERS, HeaderSegment::key(height))?;
+        let mut segment = match stored {
+            Some(mut segment) if segment.start <= height && segment.get(height - 1).is_some() => {
+                segment.headers.truncate((height - segment.start) as usize);
+                segment
+            }
+            _ => HeaderSegment {
+                start: height,
+                headers: vec![],
+            },
+        };
+        segment.headers.extend(rest[..len].iter().copied().map(ProofHeader));
+        store.store_sten(db::HEADERS, HeaderSegment::key(height), &segment)?;
+        rest = &rest[len..];
+        height += len as u32;
+    }
+    Ok(tip)
+}
+
+/// Verifies that the header is connected to the previous one, follows the
+/// difficulty rules of the network and has a valid proof of work, returning
+/// its hash
+fn check_header(
+    view: &mut ChainView,
+    params: PowParams,
+    header: &BlockHeader,
+    height: u32,
+) -> Result<BlockHash, HeaderError> {
+    let prev = view.known_header(height - 1)?;
+    if header.prev_blockhash != prev.block_hash() {
+        return Err(HeaderError::Disconnected(height));
+    }
+    let target = header.target();
+    if target > params.limit() {
+        return Err(HeaderError::Work(height));
+    }
+
+    let valid = if !params.retarget {
+        header.bits == prev.bits
+    } else if height % RETARGET_INTERVAL == 0 {
+        let prev_target = prev.target();
+        match view.header(height - RETARGET_INTERVAL)? {
+            Some(first) => {
+                let timespan = prev
+                    .time
+                    .saturating_sub(first.time)
+                    .max(TARGET_TIMESPAN / 4)
+                    .min(TARGET_TIMESPAN * 4);
+                let expected = (prev_target.mul_u32(timespan)
+                    / Uint256::from_u64(TARGET_TIMESPAN as u64).expect("u64 fits into u256"))
+                .min(params.limit());
+                header.bits == BlockHeader::compact_target_from_u256(&expected)
+            }
+            None => {
+                let min = prev_target / Uint256::from_u64(4).expect("u64 fits into u256");
+                let min = BlockHeader::u256_from_compact_target(
+                    BlockHeader::compact_target_from_u256(&min),
+                );
+                let max = prev_target.mul_u32(4).min(params.limit());
+                target >= min && target <= max
+            }
+        }
+    } else if params.min_difficulty_blocks {
+        if header.time > prev.time.saturating_add(MIN_DIFFICULTY_DELAY) {
+            header.bits == params.limit_bits
+        } else {
+            let mut last = prev;
+            let mut last_height = height - 1;
+            while last_height % RETARGET_INTERVAL != 0
+                && last.bits == params.limit_bits
+                && last_height > view.state.anchor.height
+            {
+                last_height -= 1;
+                last = view.known_header(last_height)?;
+            }
+            header.bits == last.bits
+        }
+    } else {
+        header.bits == prev.bits
+    };
+    if !valid {
+        return Err(HeaderError::Difficulty(height));
+    }
+
+    header.validate_pow(&target).map_err(|_| HeaderError::Work(height))
+}
+
+/// Switches the tracked chain to the branch provided by the electrum servers
+/// once it has more work than the tracked headers it replaces, returning the
+/// new tip
+fn reorg(
+    electrum: &ElectrumPool,
+    store: &mut store_rpc::Client,
+    params: PowParams,
+    state: HeaderChainState,
+) -> Result<HeaderCheckpoint, HeaderError> {
+    let mut view = ChainView::with(store, state, state.tip.height + 1);
+    let mut fork = state.tip.height;
+    let mut tracked_work = Uint256::default();
+    loop {
+        let tracked = view.known_header(fork)?;
+        let header = electrum.call(|client| client.block_header(fork as usize))?;
+        if header.block_hash() == tracked.block_hash() {
+            break;
+        }
+        if fork <= state.anchor.height {
+            return Err(HeaderError::ReorgBelowAnchor(state.anchor.height));
+        }
+        tracked_work = tracked_work + tracked.work();
+        fork -= 1;
+    }
+    warn!("Chain reorganization detected at height {}; checking the new branch", fork + 1);
+
+    let mut branch = vec![];
+    loop {
+        let from = fork + 1 + branch.len() as u32;
+        let res =
+            electrum.call(|client| client.block_headers(from as usize, SEGMENT_LEN as usize))?;
+        let fetched = res.headers.len() as u32;
+        branch.extend(res.headers);
+        if fetched < SEGMENT_LEN {
+            break;
+        }
+    }
+    let branch_work = branch.iter().fold(Uint256::default(), |work, header| work + header.work());
+    if branch_work <= tracked_work {
+        return Err(HeaderError::LessWork(fork));
+    }
+    extend(store, params, state, fork + 1, branch)
+}
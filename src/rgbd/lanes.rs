@@ -0,0 +1,256 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::VecDeque;
+
+use microservices::esb::ClientId;
+use rgb::ContractId;
+use storm::ChunkId;
+
+use crate::bucketd::{decode_genesis, wallet_id};
+use crate::bus::{CtlMsg, FinalizeTransferReq, FinalizeTransfersReq, ProcessReq};
+
+/// Identifier of a queued task, unique within the node run
+pub(crate) type TaskId = u64;
+
+/// Processing lane of the tasks performed by bucket daemons
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub(crate) enum Lane {
+    /// Queries which are expected to complete quickly, like state and balance
+    /// requests
+    #[display("interactive")]
+    Interactive,
+
+    /// Heavy operations: consignment processing and composition, wallet
+    /// synchronization and store maintenance
+    #[display("batch")]
+    Batch,
+}
+
+impl Lane {
+    pub fn of(msg: &CtlMsg) -> Lane {
+        match msg {
+            CtlMsg::ProcessContract(_)
+            | CtlMsg::ProcessTransfer(_)
+            | CtlMsg::ProcessDisclosure(_)
+            | CtlMsg::RevealSeal(_)
+            | CtlMsg::CreateInvoice(_)
+            | CtlMsg::ProcessTransferContainer(_)
+            | CtlMsg::ProveTransactions(_)
+            | CtlMsg::ConsignContract(_)
+            | CtlMsg::ConsignTranfer(_)
+            | CtlMsg::SendTransfer(_)
+            | CtlMsg::SyncWallet(_)
+            | CtlMsg::CreateCheckpoint(_)
+            | CtlMsg::CommitStash(_)
+            | CtlMsg::ApproveQuarantined(_)
+            | CtlMsg::RevalidateQuarantined(_)
+            | CtlMsg::CollectGarbage(_)
+            | CtlMsg::PruneData(_)
+            | CtlMsg::ForgetContract(_)
+            | CtlMsg::RewindContract(_)
+            | CtlMsg::ReplayConsignments(_)
+            | CtlMsg::RebuildState(_)
+            | CtlMsg::RevalidateContracts(_)
+            | CtlMsg::CheckValidations
+            | CtlMsg::RecoverStore => Lane::Batch,
+            _ => Lane::Interactive,
+        }
+    }
+}
+
+/// Lock over the contract or wallet state which is held by a task modifying
+/// it.
+///
+/// Tasks modifying the state of different contracts or wallets are processed
+/// by bucket daemons in parallel, while the tasks modifying the same contract
+/// or wallet are serialized. Tasks which may modify the state of any contract,
+/// or of the contracts not known before the task is processed, lock the whole
+/// stash.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub(crate) enum StateLock {
+    #[display("contract {0}")]
+    Contract(ContractId),
+
+    /// Wallet identified by its descriptor, see [`wallet_id`]
+    #[display("wallet {0}")]
+    Wallet(ChunkId),
+
+    #[display("stash")]
+    Exclusive,
+}
+
+impl StateLock {
+    pub fn of(msg: &CtlMsg) -> Option<StateLock> {
+        Some(match msg {
+            CtlMsg::ProcessContract(ProcessReq { consignment, .. }) => {
+                StateLock::Contract(consignment.contract_id())
+            }
+            CtlMsg::ProcessTransfer(ProcessReq { consignment, .. }) => {
+                StateLock::Contract(consignment.contract_id())
+            }
+            CtlMsg::ImportContract(req) => match decode_genesis(&req.genesis) {
+                Ok(genesis) => StateLock::Contract(genesis.contract_id()),
+                // The task fails without touching the stash
+                Err(_) => return None,
+            },
+            // Id of the issued contract is known only once the bucket daemon
+            // blinds the genesis seals
+            CtlMsg::IssueContract(_) => StateLock::Exclusive,
+            CtlMsg::FinalizeTransfer(FinalizeTransferReq { consignment, .. }) => {
+                StateLock::Contract(consignment.contract_id())
+            }
+            CtlMsg::FinalizeTransfers(FinalizeTransfersReq { transfers, .. }) => {
+                match transfers.as_slice() {
+                    [(consignment, _)] => StateLock::Contract(consignment.contract_id()),
+                    _ => StateLock::Exclusive,
+                }
+            }
+            CtlMsg::SendTransfer(req) => StateLock::Contract(req.consignment.contract_id()),
+            CtlMsg::SyncWallet(req) => StateLock::Wallet(wallet_id(&req.descriptor)),
+            CtlMsg::ComposePayment(req) => StateLock::Wallet(wallet_id(&req.descriptor)),
+            CtlMsg::ContributePayjoin(req) => StateLock::Wallet(wallet_id(&req.descriptor)),
+            CtlMsg::AccelerateTransfer(req) => StateLock::Wallet(wallet_id(&req.descriptor)),
+            CtlMsg::CreateCheckpoint(req) => StateLock::Contract(req.contract_id),
+            CtlMsg::ConsolidateAllocations(req) => StateLock::Contract(req.contract_id),
+            CtlMsg::RevealSeal(req) => StateLock::Contract(req.contract_id),
+            CtlMsg::CreateInvoice(req) => StateLock::Contract(req.contract_id),
+            CtlMsg::RebuildState(req) => match req.contract_id {
+                Some(contract_id) => StateLock::Contract(contract_id),
+                None => StateLock::Exclusive,
+            },
+            CtlMsg::RevalidateContracts(req) => match req.contract_id {
+                Some(contract_id) => StateLock::Contract(contract_id),
+                None => StateLock::Exclusive,
+            },
+            CtlMsg::ProcessDisclosure(_)
+            | CtlMsg::ProcessTransferContainer(_)
+            | CtlMsg::CommitStash(_)
+            | CtlMsg::ApproveQuarantined(_)
+            | CtlMsg::RevalidateQuarantined(_)
+            | CtlMsg::CollectGarbage(_)
+            | CtlMsg::PruneData(_)
+            | CtlMsg::ForgetContract(_)
+            | CtlMsg::RewindContract(_)
+            | CtlMsg::ReplayConsignments(_)
+            | CtlMsg::RecoverStore
+            | CtlMsg::ForgetSpent(_) => StateLock::Exclusive,
+            _ => return None,
+        })
+    }
+
+    /// Detects whether the locks can't be held at the same time
+    pub fn conflicts(self, other: StateLock) -> bool {
+        match (self, other) {
+            (StateLock::Contract(a), StateLock::Contract(b)) => a == b,
+            (StateLock::Wallet(a), StateLock::Wallet(b)) => a == b,
+            (StateLock::Contract(_), StateLock::Wallet(_))
+            | (StateLock::Wallet(_), StateLock::Contract(_)) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Task assigned to a bucket daemon
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct Assignment {
+    pub task_id: TaskId,
+    pub lane: Lane,
+    /// Client which has requested the task, if any
+    pub client_id: Option<ClientId>,
+    /// Lock over the contract state held by the task, if any
+    pub lock: Option<StateLock>,
+}
+
+/// Tasks awaiting a free bucket daemon. Interactive tasks are always assigned
+/// before the batch ones.
+///
+/// Tasks which state lock conflicts with the locks held by the assigned tasks
+/// are kept in the queue, letting the following tasks to be assigned. A task
+/// never overtakes a queued task with a conflicting lock, so the tasks
+/// modifying the same contract are processed in the order of their arrival.
+#[derive(Default)]
+pub(crate) struct TaskQueue {
+    interactive: VecDeque<(TaskId, CtlMsg)>,
+    batch: VecDeque<(TaskId, CtlMsg)>,
+    /// Id which is given to the next queued task
+    next_id: TaskId,
+}
+
+impl TaskQueue {
+    /// Queues the task, returning its id
+    pub fn push_back(&mut self, msg: CtlMsg) -> TaskId {
+        let task_id = self.next_id;
+        self.next_id += 1;
+        match Lane::of(&msg) {
+            Lane::Interactive => self.interactive.push_back((task_id, msg)),
+            Lane::Batch => self.batch.push_back((task_id, msg)),
+        }
+        task_id
+    }
+
+    /// Id which will be given to the next queued task
+    pub fn next_id(&self) -> TaskId { self.next_id }
+
+    /// Detects whether there are tasks which can be assigned, taking into
+    /// account whether the batch lane has a free capacity and the state locks
+    /// held by the assigned tasks.
+    pub fn has_ready(&self, batch_allowed: bool, held: &[StateLock]) -> bool {
+        self.position(batch_allowed, held).is_some()
+    }
+
+    pub fn pop_front(
+        &mut self,
+        batch_allowed: bool,
+        held: &[StateLock],
+    ) -> Option<(TaskId, CtlMsg)> {
+        match self.position(batch_allowed, held)? {
+            (Lane::Interactive, index) => self.interactive.remove(index),
+            (Lane::Batch, index) => self.batch.remove(index),
+        }
+    }
+
+    /// Finds the first task which can be assigned, skipping the tasks which
+    /// locks conflict with the held locks or the locks of the skipped tasks.
+    fn position(&self, batch_allowed: bool, held: &[StateLock]) -> Option<(Lane, usize)> {
+        let mut blocked = held.to_vec();
+        for (lane, queue) in [(Lane::Interactive, &self.interactive), (Lane::Batch, &self.batch)] {
+            if lane == Lane::Batch && !batch_allowed {
+                break;
+            }
+            for (index, (_, msg)) in queue.iter().enumerate() {
+                match StateLock::of(msg) {
+                    Some(lock) if blocked.iter().any(|other| lock.conflicts(*other)) => {
+                        blocked.push(lock)
+                    }
+                    _ => return Some((lane, index)),
+                }
+            }
+        }
+        None
+    }
+
+    pub fn len(&self) -> usize { self.interactive.len() + self.batch.len() }
+
+    /// Removes all tasks requested by the client, returning their ids
+    pub fn remove_client(&mut self, client_id: ClientId) -> Vec<TaskId> {
+        let mut removed = vec![];
+        for queue in [&mut self.interactive, &mut self.batch] {
+            queue.retain(|(task_id, msg)| {
+                let keep = msg.client_id() != Some(client_id);
+                if !keep {
+                    removed.push(*task_id);
+                }
+                keep
+            });
+        }
+        removed
+    }
+}
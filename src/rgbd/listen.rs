@@ -0,0 +1,138 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::iter;
+
+use internet2::addr::ServiceAddr;
+use microservices::ZMQ_CONTEXT;
+
+use crate::{Config, LaunchError};
+
+/// Creates ZMQ ROUTER socket serving RPC clients.
+///
+/// If the daemon was started with systemd socket activation, the socket uses
+/// the listening sockets passed by systemd. Otherwise it is bound to the main
+/// RPC endpoint and all additional endpoints from `--rpc-listen`, setting
+/// permissions of the IPC socket files if required.
+pub(crate) fn rpc_socket(config: &Config) -> Result<zmq::Socket, LaunchError> {
+    let mut socket = ZMQ_CONTEXT.socket(zmq::ROUTER).map_err(listener_error)?;
+
+    let activated = activated_endpoints()?;
+    if !activated.is_empty() {
+        for (fd, endpoint) in activated {
+            debug!("Binding RPC socket to {} passed by systemd", endpoint);
+            use_fd(&mut socket, fd)?;
+            socket.bind(&endpoint.zmq_connect_string()).map_err(listener_error)?;
+        }
+        return Ok(socket);
+    }
+
+    for endpoint in iter::once(&config.rpc_endpoint).chain(&config.rpc_listen) {
+        debug!("Binding RPC socket to {}", endpoint);
+        socket.bind(&endpoint.zmq_connect_string()).map_err(listener_error)?;
+        if let (ServiceAddr::Ipc(path), Some(mode)) = (endpoint, config.rpc_socket_mode) {
+            set_mode(path, mode)?;
+        }
+    }
+    Ok(socket)
+}
+
+fn listener_error(err: impl ToString) -> LaunchError { LaunchError::RpcListener(err.to_string()) }
+
+#[cfg(unix)]
+fn set_mode(path: &str, mode: u32) -> Result<(), LaunchError> {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    debug!("Setting permissions of {} to {:o}", path, mode);
+    fs::set_permissions(path, fs::Permissions::from_mode(mode)).map_err(listener_error)
+}
+
+#[cfg(not(unix))]
+fn set_mode(path: &str, _mode: u32) -> Result<(), LaunchError> {
+    warn!("Permissions of {} are not set: not supported on this platform", path);
+    Ok(())
+}
+
+#[cfg(unix)]
+type RawFd = std::os::unix::io::RawFd;
+#[cfg(not(unix))]
+type RawFd = i32;
+
+/// First file descriptor passed with systemd socket activation
+#[cfg(unix)]
+const SD_LISTEN_FDS_START: RawFd = 3;
+
+/// Returns listening sockets passed with systemd socket activation together
+/// with their addresses. The activation environment variables are removed, so
+/// they are not inherited by the bucket daemons.
+#[cfg(unix)]
+fn activated_endpoints() -> Result<Vec<(RawFd, ServiceAddr)>, LaunchError> {
+    use std::env;
+    use std::mem::ManuallyDrop;
+    use std::net::TcpListener;
+    use std::os::unix::io::FromRawFd;
+    use std::os::unix::net::UnixListener;
+
+    let pid = env::var("LISTEN_PID").ok().and_then(|pid| pid.parse::<u32>().ok());
+    let count = env::var("LISTEN_FDS").ok().and_then(|fds| fds.parse::<RawFd>().ok());
+    for var in ["LISTEN_PID", "LISTEN_FDS", "LISTEN_FDNAMES"] {
+        env::remove_var(var);
+    }
+    let count = match (pid, count) {
+        (Some(pid), Some(count)) if pid == std::process::id() => count,
+        _ => return Ok(empty!()),
+    };
+
+    (SD_LISTEN_FDS_START..SD_LISTEN_FDS_START + count)
+        .map(|fd| {
+            // Listeners are used only to read the socket address and must not
+            // close the descriptor
+            let unix = ManuallyDrop::new(unsafe { UnixListener::from_raw_fd(fd) });
+            if let Ok(addr) = unix.local_addr() {
+                let path = addr.as_pathname().ok_or_else(|| {
+                    listener_error(format!("unnamed unix socket is passed as descriptor {}", fd))
+                })?;
+                return Ok((fd, ServiceAddr::Ipc(path.display().to_string())));
+            }
+            let tcp = ManuallyDrop::new(unsafe { TcpListener::from_raw_fd(fd) });
+            let addr = tcp.local_addr().map_err(|err| {
+                listener_error(format!(
+                    "unsupported socket is passed as descriptor {}: {}",
+                    fd, err
+                ))
+            })?;
+            Ok((fd, ServiceAddr::Tcp(addr)))
+        })
+        .collect()
+}
+
+#[cfg(not(unix))]
+fn activated_endpoints() -> Result<Vec<(RawFd, ServiceAddr)>, LaunchError> { Ok(empty!()) }
+
+/// Makes the next bind of the socket to use already existing listening socket.
+/// The option is not exposed by the `zmq` crate, so it is set with the libzmq
+/// API directly.
+fn use_fd(socket: &mut zmq::Socket, fd: RawFd) -> Result<(), LaunchError> {
+    use std::os::raw::{c_int, c_void};
+
+    let rc = unsafe {
+        zmq_sys::zmq_setsockopt(
+            socket.as_mut_ptr(),
+            zmq_sys::ZMQ_USE_FD as c_int,
+            &fd as *const RawFd as *const c_void,
+            std::mem::size_of::<RawFd>(),
+        )
+    };
+    if rc == -1 {
+        return Err(listener_error(zmq::Error::from_raw(unsafe { zmq_sys::zmq_errno() })));
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Versioning of the store data format.
+//!
+//! The version is kept in the store itself and checked by rgbd on start,
+//! before any of the bucket daemons is launched. Stores of the older formats
+//! are migrated automatically, while stores written by a newer node are
+//! refused, so the data are never read with a wrong encoding.
+
+use storm::ChunkId;
+
+use crate::db::{self, StoreRpcExt};
+use crate::{DaemonError, LaunchError};
+
+/// Version of the store data format. Must be incremented, together with
+/// adding a migration to [`MIGRATIONS`], each time the encoding of the stored
+/// objects changes.
+pub const STORE_VERSION: u16 = 1;
+
+/// Migration of the store from the format version equal to its index to the
+/// next version
+type Migration = fn(&mut store_rpc::Client) -> Result<(), DaemonError>;
+
+const MIGRATIONS: [Migration; STORE_VERSION as usize] = [mark_unversioned];
+
+fn version_key() -> ChunkId { ChunkId::with_fixed_fragments(db::META, "store_version") }
+
+/// Checks version of the store data format, migrating the data of the older
+/// formats. Empty stores are marked with the current version.
+pub(super) fn migrate_store(store: &mut store_rpc::Client) -> Result<(), LaunchError> {
+    let stored = store
+        .retrieve_sten::<u16>(db::META, version_key())
+        .map_err(|err| LaunchError::StoreMigration(err.to_string()))?;
+    let version = match stored {
+        Some(version) => version,
+        None if store.ids(db::GENESIS)?.is_empty() && store.ids(db::SCHEMATA)?.is_empty() => {
+            debug!("Initializing store with data format version {}", STORE_VERSION);
+            return set_version(store, STORE_VERSION);
+        }
+        None => 0,
+    };
+
+    if version > STORE_VERSION {
+        return Err(LaunchError::StoreVersion {
+            found: version,
+            supported: STORE_VERSION,
+        });
+    }
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        info!("Migrating store data from format version {} to {}", from, from + 1);
+        migration(store).map_err(|err| LaunchError::StoreMigration(err.to_string()))?;
+        // Version is updated after each step, so an interrupted migration is
+        // resumed from the last completed step
+        set_version(store, from as u16 + 1)?;
+    }
+    Ok(())
+}
+
+/// Stores created before the format was versioned have the same encoding as
+/// the version 1 and require no conversion
+fn mark_unversioned(_: &mut store_rpc::Client) -> Result<(), DaemonError> { Ok(()) }
+
+fn set_version(store: &mut store_rpc::Client, version: u16) -> Result<(), LaunchError> {
+    store
+        .store_sten(db::META, version_key(), &version)
+        .map_err(|err| LaunchError::StoreMigration(err.to_string()))?;
+    Ok(())
+}
@@ -11,9 +11,30 @@
 mod service;
 #[cfg(feature = "server")]
 mod opts;
-pub(self) mod daemons;
+mod access;
+mod daemons;
+mod events;
+mod faucet;
+mod headers;
+mod lanes;
+mod listen;
+mod migration;
+mod orders;
+mod receipt;
+mod relay;
+mod replica;
+mod supervisor;
+mod upload;
 
+pub use access::AccessError;
 pub(crate) use daemons::Daemon;
+pub use faucet::FaucetError;
 #[cfg(feature = "server")]
 pub use opts::Opts;
+pub use orders::OrderError;
+pub use receipt::ReceiptError;
+pub(crate) use relay::EnvelopeRecord;
+pub use relay::RelayError;
+pub use replica::ReplicaError;
 pub use service::{run, Runtime};
+pub use upload::UploadError;
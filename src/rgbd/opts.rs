@@ -8,8 +8,14 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
+use std::net::SocketAddr;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use bitcoin::XOnlyPublicKey;
 use clap::{Parser, ValueHint};
 use internet2::addr::ServiceAddr;
+use rgb::ContractId;
 use rgb_rpc::RGB_NODE_RPC_ENDPOINT;
 use storm_ext::STORM_NODE_EXT_ENDPOINT;
 
@@ -37,6 +43,29 @@ pub struct Opts {
     )]
     pub rpc_endpoint: ServiceAddr,
 
+    /// Additional ZMQ socket for RGB node RPC interface.
+    ///
+    /// Allows to serve RPC clients simultaneously over TCP and over IPC files.
+    /// Can be used multiple times. If the daemon is started with systemd socket
+    /// activation, the sockets passed by systemd are used instead of this
+    /// option and `--rpc`.
+    #[clap(
+        long = "rpc-listen",
+        env = "RGB_NODE_RPC_LISTEN",
+        value_delimiter = ',',
+        value_hint = ValueHint::FilePath
+    )]
+    pub rpc_listen: Vec<ServiceAddr>,
+
+    /// File permissions of the RPC IPC socket files, in octal notation (like
+    /// `660`).
+    #[clap(
+        long = "rpc-socket-mode",
+        env = "RGB_NODE_RPC_SOCKET_MODE",
+        parse(try_from_str = parse_socket_mode)
+    )]
+    pub rpc_socket_mode: Option<u32>,
+
     /// ZMQ socket for connecting RGB node message bus.
     #[clap(
         short = 'E',
@@ -47,6 +76,205 @@ pub struct Opts {
     )]
     pub storm_endpoint: ServiceAddr,
 
+    /// ZMQ socket of an external signer daemon.
+    ///
+    /// If provided, PSBT signing requests are forwarded to the signer, which may
+    /// keep the keys on a separate host. Socket can be either TCP address in form
+    /// of `<ipv4 | ipv6>:<port>` – or a path to an IPC file.
+    #[clap(long = "signer", env = "RGB_NODE_SIGNER_ENDPOINT", value_hint = ValueHint::FilePath)]
+    pub signer_endpoint: Option<ServiceAddr>,
+
+    /// ZMQ socket of an external signer daemon holding contract issuer keys.
+    ///
+    /// If provided, contract checkpoints are signed by the signer, which may
+    /// keep the keys in a hardware security module (for instance, accessed via
+    /// PKCS#11). Socket can be either TCP address in form of
+    /// `<ipv4 | ipv6>:<port>` – or a path to an IPC file.
+    #[clap(
+        long = "issuer-signer",
+        env = "RGB_NODE_ISSUER_SIGNER_ENDPOINT",
+        value_hint = ValueHint::FilePath,
+        conflicts_with = "issuer_signer_url"
+    )]
+    pub issuer_signer_endpoint: Option<ServiceAddr>,
+
+    /// URL of a remote HTTP signer holding contract issuer keys.
+    ///
+    /// If provided, contract checkpoints are signed by the signer, which
+    /// receives POST request with JSON object containing hex-encoded `message`
+    /// and must reply with JSON object containing hex-encoded `signature` and
+    /// `issuer` public key.
+    #[clap(long, env = "RGB_NODE_ISSUER_SIGNER_URL", value_hint = ValueHint::Url)]
+    pub issuer_signer_url: Option<String>,
+
+    /// URL of an OpenTimestamps calendar server anchoring stash commitments.
+    ///
+    /// If provided, commitments to the stash contents made with `rgb-cli
+    /// timestamp` can be submitted to the calendar, which includes them into
+    /// its Bitcoin transaction. Both `http://` and `https://` URLs are supported.
+    #[clap(long, env = "RGB_NODE_TIMESTAMP_CALENDAR", value_hint = ValueHint::Url)]
+    pub timestamp_calendar: Option<String>,
+
+    /// Contract issuers which checkpoints of contract state are accepted by
+    /// the node, in form of `<contract_id>:<public_key>`.
+    ///
+    /// Checkpoints are accepted only for the contract the issuer key is given
+    /// for; a contract may have several trusted issuers. Transfers of
+    /// contracts with an imported checkpoint are validated only down to the
+    /// checkpoint, unless the full validation is requested.
+    #[clap(
+        long = "trusted-issuer",
+        env = "RGB_NODE_TRUSTED_ISSUERS",
+        value_delimiter = ',',
+        value_parser = parse_trusted_issuer
+    )]
+    pub trusted_issuers: Vec<(ContractId, XOnlyPublicKey)>,
+
+    /// Public keys of the node administrators, enabling access control for
+    /// nodes shared by multiple users.
+    ///
+    /// Clients must authenticate with their identity keys and see only the
+    /// contracts they have issued, imported, received or were granted access
+    /// to with `rgb-cli access grant`. Administrators have access to the whole
+    /// stash and the node maintenance requests. Read replicas authenticate to
+    /// the primary node with their node key, which must be one of the primary
+    /// administrator keys.
+    #[clap(long = "admin-key", env = "RGB_NODE_ADMIN_KEYS", value_delimiter = ',')]
+    pub admin_keys: Vec<XOnlyPublicKey>,
+
+    /// Public keys registered for signing contract issue and transfer orders.
+    ///
+    /// If given, the node accepts contract issue and the requests moving
+    /// assets (transfers, payments, swaps and payjoins) only if they are
+    /// signed by one of the keys. Signed orders of the issued contracts and
+    /// the finalized transfers are kept in the order journal and can be
+    /// retrieved with `rgb-cli order`, proving which key has ordered the
+    /// operation.
+    #[clap(long = "order-key", env = "RGB_NODE_ORDER_KEYS", value_delimiter = ',')]
+    pub order_keys: Vec<XOnlyPublicKey>,
+
+    /// ZMQ socket publishing node events.
+    ///
+    /// Subscribers receive events about newly imported contracts, accepted
+    /// consignments and transfer status changes, with the first message frame
+    /// being the event topic (`contract`, `consignment` or `transfer`). Socket
+    /// can be either TCP address in form of `<ipv4 | ipv6>:<port>` – or a path
+    /// to an IPC file.
+    #[clap(long = "events", env = "RGB_NODE_EVENTS_ENDPOINT", value_hint = ValueHint::FilePath)]
+    pub events_endpoint: Option<ServiceAddr>,
+
+    /// Publish events serialized as JSON instead of strict encoding.
+    #[clap(long, env = "RGB_NODE_EVENTS_JSON")]
+    pub events_json: bool,
+
+    /// RPC socket of the primary node, which stash is mirrored by this node
+    /// running as a read replica.
+    ///
+    /// Replica imports contracts registered or updated by the primary and
+    /// rejects requests modifying its stash until it is promoted to primary
+    /// with `rgb-cli promote`. Requires `--replica-events`.
+    #[clap(
+        long,
+        env = "RGB_NODE_REPLICA_OF",
+        value_hint = ValueHint::FilePath,
+        requires = "replica_events"
+    )]
+    pub replica_of: Option<ServiceAddr>,
+
+    /// ZMQ socket publishing events of the primary node, configured on the
+    /// primary with `--events` option.
+    #[clap(
+        long,
+        env = "RGB_NODE_REPLICA_EVENTS",
+        value_hint = ValueHint::FilePath,
+        requires = "replica_of"
+    )]
+    pub replica_events: Option<ServiceAddr>,
+
+    /// Contract which allocations are paid out by the node running as a
+    /// faucet.
+    ///
+    /// Faucet serves HTTP endpoint `POST /invoice`, paying invoices of the
+    /// contract submitted as the request body and replying with the transfer
+    /// consignment. Can be used on test networks only; requires
+    /// `--faucet-descriptor` and `--signer`.
+    #[clap(long, env = "RGB_NODE_FAUCET", requires = "faucet_descriptor")]
+    pub faucet: Option<ContractId>,
+
+    /// Socket address of the faucet HTTP endpoint.
+    #[clap(long, env = "RGB_NODE_FAUCET_LISTEN", default_value = "127.0.0.1:8080")]
+    pub faucet_listen: SocketAddr,
+
+    /// Descriptor of the wallet holding faucet contract allocations and paying
+    /// the transaction fees.
+    #[clap(long, env = "RGB_NODE_FAUCET_DESCRIPTOR", requires = "faucet")]
+    pub faucet_descriptor: Option<String>,
+
+    /// Maximum amount paid by the faucet to a single invoice; invoices without
+    /// amount are paid this amount.
+    #[clap(long, env = "RGB_NODE_FAUCET_AMOUNT", default_value = "1000")]
+    pub faucet_amount: u64,
+
+    /// Fee of the faucet payment transactions, in satoshis.
+    #[clap(long, env = "RGB_NODE_FAUCET_FEE", default_value = "500")]
+    pub faucet_fee: u64,
+
+    /// Minimal interval between faucet payments requested from the same IP
+    /// address, in seconds.
+    #[clap(long, env = "RGB_NODE_FAUCET_INTERVAL", default_value = "3600")]
+    pub faucet_interval: u64,
+
+    /// Run as a relay holding consignments sealed to the keys of offline
+    /// recipients until they are picked up with `rgb-cli relay pickup`.
+    #[clap(long, env = "RGB_NODE_RELAY")]
+    pub relay: bool,
+
+    /// Maximum time the relay holds an envelope, in seconds; envelopes
+    /// deposited without time-to-live are held for this time.
+    #[clap(long, env = "RGB_NODE_RELAY_TTL", default_value = "604800")]
+    pub relay_ttl: u32,
+
+    /// Maximum size of a sealed consignment accepted by the relay, in bytes.
+    #[clap(long, env = "RGB_NODE_RELAY_MAX_SIZE", default_value = "4194304")]
+    pub relay_max_size: u32,
+
+    /// Maximum total size of the sealed consignments held by the relay for
+    /// all recipients, in bytes.
+    ///
+    /// Deposits are accepted from any client, so the quota bounds the storage
+    /// the relay may be made to use.
+    #[clap(long, env = "RGB_NODE_RELAY_QUOTA", default_value = "1073741824")]
+    pub relay_quota: u64,
+
+    /// RPC sockets of the peer relays, which receive copies of the envelopes
+    /// deposited to this relay.
+    ///
+    /// Recipients may pick up their envelopes from any relay of the
+    /// federation. Envelopes received from the peers are not forwarded any
+    /// further.
+    #[clap(
+        long = "relay-peer",
+        env = "RGB_NODE_RELAY_PEERS",
+        value_delimiter = ',',
+        value_hint = ValueHint::FilePath,
+        requires = "relay"
+    )]
+    pub relay_peers: Vec<ServiceAddr>,
+
+    /// Maximum number of bucket daemons serving interactive requests, like
+    /// state and balance queries, in addition to the batch lane daemons.
+    #[clap(long, default_value = "2", env = "RGB_NODE_INTERACTIVE_WORKERS")]
+    pub interactive_workers: usize,
+
+    /// Maximum number of bucket daemons performing heavy batch tasks at the
+    /// same time: processing and composing consignments, synchronizing
+    /// wallets and collecting garbage.
+    ///
+    /// Consignments of different contracts are processed in parallel, while
+    /// consignments of the same contract are processed one after another.
+    #[clap(long, default_value = "2", env = "RGB_NODE_BATCH_WORKERS")]
+    pub batch_workers: usize,
+
     /// Spawn daemons as threads and not processes
     #[clap(short = 't', long = "threaded")]
     pub threaded_daemons: bool,
@@ -55,6 +283,27 @@ pub struct Opts {
 #[cfg(feature = "server")]
 impl Opts {
     pub fn process(&mut self) {
-        self.shared.process([&mut self.rpc_endpoint, &mut self.storm_endpoint]);
+        let services = [&mut self.rpc_endpoint, &mut self.storm_endpoint]
+            .into_iter()
+            .chain(self.rpc_listen.iter_mut())
+            .chain(self.signer_endpoint.as_mut())
+            .chain(self.issuer_signer_endpoint.as_mut())
+            .chain(self.events_endpoint.as_mut())
+            .chain(self.replica_of.as_mut())
+            .chain(self.replica_events.as_mut())
+            .chain(self.relay_peers.iter_mut());
+        self.shared.process(services);
     }
 }
+
+fn parse_socket_mode(s: &str) -> Result<u32, ParseIntError> { u32::from_str_radix(s, 8) }
+
+/// Parses trusted issuer given in form of `<contract_id>:<public_key>`
+fn parse_trusted_issuer(s: &str) -> Result<(ContractId, XOnlyPublicKey), String> {
+    let (contract_id, issuer) = s.split_once(':').ok_or_else(|| {
+        format!("trusted issuer `{}` must have `<contract_id>:<public_key>` form", s)
+    })?;
+    let contract_id = ContractId::from_str(contract_id).map_err(|err| err.to_string())?;
+    let issuer = XOnlyPublicKey::from_str(issuer).map_err(|err| err.to_string())?;
+    Ok((contract_id, issuer))
+}
@@ -0,0 +1,230 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Journal of the signed contract issue and asset transfer orders.
+//!
+//! Signature of the order is checked before the request is queued for a
+//! bucket daemon, and the order is kept with the queued task. Once the daemon
+//! completes the task, the order is recorded under the id of the contract or
+//! the finalized transfer consignments reported in the events which follow
+//! the completion. Orders of the requests which do not finalize transfers,
+//! like swap and payjoin proposals, payment composition and transaction
+//! publication, are verified only; the transfers they result in are recorded
+//! under the orders of the requests finalizing them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::hashes::sha256;
+use bitcoin::secp256k1::SECP256K1;
+use bitcoin::XOnlyPublicKey;
+use microservices::esb::ClientId;
+use rgb_rpc::{
+    now, FailureCode, NodeEvent, OrderDigest, OrderKind, OrderRecord, OrderTarget, RpcMsg,
+    SignedOrder, TransferDirection, TransferStatus, ORDER_WINDOW,
+};
+use storm::chunk::ChunkIdExt;
+use storm::ChunkId;
+
+use super::Runtime;
+use crate::bus::{DaemonId, Endpoints, Responder};
+use crate::db::{self, StoreRpcExt};
+use crate::rgbd::lanes::TaskId;
+use crate::{Config, DaemonError};
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum OrderError {
+    /// node accepts only {0} orders signed with a registered key.
+    Unsigned(OrderKind),
+
+    /// key {0} which has signed the order is not registered on the node.
+    UnknownSigner(XOnlyPublicKey),
+
+    /// order signature is invalid or does not match the {0} request data.
+    InvalidSignature(OrderKind),
+
+    /// order is signed at {0}, which is too far from the node clock; check the
+    /// system clock.
+    Outdated(u64),
+
+    /// order journal has no signed order for {0}.
+    Unknown(OrderTarget),
+}
+
+impl From<&OrderError> for FailureCode {
+    fn from(err: &OrderError) -> Self {
+        match err {
+            OrderError::Unsigned(_) | OrderError::UnknownSigner(_) => FailureCode::Access,
+            OrderError::InvalidSignature(_) | OrderError::Outdated(_) => {
+                FailureCode::InvalidSignature
+            }
+            OrderError::Unknown(_) => FailureCode::Absent,
+        }
+    }
+}
+
+/// Signed orders of the tasks being processed
+#[derive(Default)]
+pub(crate) struct OrderJournal {
+    /// Keys which may sign orders, including the node key; if empty, orders
+    /// are optional and may be signed with any key
+    keys: BTreeSet<XOnlyPublicKey>,
+
+    /// Orders of the tasks queued or being processed by the bucket daemons
+    pending: BTreeMap<TaskId, SignedOrder>,
+
+    /// Orders of the tasks which were the last ones successfully completed by
+    /// each of the bucket daemons
+    completed: BTreeMap<DaemonId, SignedOrder>,
+}
+
+impl OrderJournal {
+    pub fn with(config: &Config, node_key: Option<XOnlyPublicKey>) -> OrderJournal {
+        if config.order_keys.is_empty() {
+            return default!();
+        }
+        OrderJournal {
+            keys: config.order_keys.iter().copied().chain(node_key).collect(),
+            pending: empty!(),
+            completed: empty!(),
+        }
+    }
+
+    /// Keeps the order until the task is completed
+    pub fn place(&mut self, task_id: TaskId, order: SignedOrder) {
+        self.pending.insert(task_id, order);
+    }
+
+    /// Forgets the order of the task which was dropped without completion
+    pub fn discard(&mut self, task_id: TaskId) { self.pending.remove(&task_id); }
+
+    /// Registers completion of the task by the bucket daemon
+    pub fn complete(&mut self, daemon_id: DaemonId, task_id: Option<TaskId>, success: bool) {
+        self.completed.remove(&daemon_id);
+        if let Some(order) = task_id.and_then(|task_id| self.pending.remove(&task_id)) {
+            if success {
+                self.completed.insert(daemon_id, order);
+            }
+        }
+    }
+}
+
+fn order_key(target: OrderTarget) -> ChunkId {
+    match target {
+        OrderTarget::Contract(contract_id) => {
+            ChunkId::with_fixed_fragments(db::ORDERS, contract_id)
+        }
+        OrderTarget::Transfer(consignment_id) => {
+            ChunkId::with_fixed_fragments(db::ORDERS, consignment_id)
+        }
+    }
+}
+
+/// Returns kind, order and digest of the request which may be ordered
+fn ordered<R: OrderDigest>(
+    req: &R,
+    order: Option<SignedOrder>,
+) -> (OrderKind, Option<SignedOrder>, sha256::Hash) {
+    (R::ORDER_KIND, order, req.order_digest())
+}
+
+impl Runtime {
+    /// Checks signature of the contract issue and asset transfer orders,
+    /// returning the signed order which must be kept until the request is
+    /// completed.
+    pub(super) fn check_order(
+        &mut self,
+        client_id: ClientId,
+        msg: &RpcMsg,
+    ) -> Result<Option<SignedOrder>, OrderError> {
+        let (kind, order, digest) = match msg {
+            RpcMsg::IssueContract(req) => ordered(req, req.order),
+            RpcMsg::Transfer(req) => ordered(req, req.order),
+            RpcMsg::FinalizeTransfers(req) => ordered(req, req.order),
+            RpcMsg::ConsolidateAllocations(req) => ordered(req, req.order),
+            RpcMsg::ComposePayment(req) => ordered(req, req.order),
+            RpcMsg::PublishTx(req) => ordered(req, req.order),
+            RpcMsg::ProposeSwap(req) => ordered(req, req.order),
+            RpcMsg::AcceptSwap(req) => ordered(req, req.order),
+            RpcMsg::CompleteSwap(req) => ordered(req, req.order),
+            RpcMsg::ProposePayjoin(req) => ordered(req, req.order),
+            RpcMsg::ContributePayjoin(req) => ordered(req, req.order),
+            RpcMsg::CompletePayjoin(req) => ordered(req, req.order),
+            _ => return Ok(None),
+        };
+        let order = match order {
+            Some(order) => order,
+            None if self.orders.keys.is_empty() => return Ok(None),
+            None => return Err(OrderError::Unsigned(kind)),
+        };
+        if !self.orders.keys.is_empty() && !self.orders.keys.contains(&order.signer) {
+            return Err(OrderError::UnknownSigner(order.signer));
+        }
+        if order.order.kind != kind || order.order.digest != digest || !order.verify(SECP256K1) {
+            return Err(OrderError::InvalidSignature(kind));
+        }
+        let (timestamp, now) = (order.order.timestamp, now());
+        if timestamp.max(now) - timestamp.min(now) > ORDER_WINDOW {
+            return Err(OrderError::Outdated(timestamp));
+        }
+        debug!("Client {} has placed {}", client_id, order);
+        Ok(Some(order))
+    }
+
+    /// Records the order of the task completed by the bucket daemon under the
+    /// issued contract or the finalized transfers.
+    pub(super) fn record_order(
+        &mut self,
+        daemon_id: DaemonId,
+        events: &[NodeEvent],
+    ) -> Result<(), DaemonError> {
+        let order = match self.orders.completed.remove(&daemon_id) {
+            Some(order) => order,
+            None => return Ok(()),
+        };
+        let targets = events.iter().filter_map(|event| match (order.order.kind, event) {
+            (OrderKind::Issue, NodeEvent::ContractRegistered(contract_id)) => {
+                Some(OrderTarget::Contract(*contract_id))
+            }
+            (OrderKind::Issue, _) => None,
+            (_, NodeEvent::TransferUpdated(record))
+                if record.direction == TransferDirection::Outgoing
+                    && record.status == TransferStatus::Finalized =>
+            {
+                Some(OrderTarget::Transfer(record.id))
+            }
+            _ => None,
+        });
+        for target in targets {
+            info!("Recording {} for {}", order, target);
+            let record = OrderRecord {
+                target,
+                order,
+                completed: now(),
+            };
+            self.store.store_sten(db::ORDERS, order_key(target), &record)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn get_order(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        target: OrderTarget,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.store.retrieve_sten::<OrderRecord>(db::ORDERS, order_key(target))? {
+            Some(record) => RpcMsg::Order(record),
+            None => DaemonError::from(OrderError::Unknown(target)).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+}
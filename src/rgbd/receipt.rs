@@ -0,0 +1,90 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Receipts for the incoming transfers, signed with the node key.
+
+use bitcoin::secp256k1::SECP256K1;
+use microservices::esb::ClientId;
+use rgb::ConsignmentId;
+use rgb_rpc::{
+    now, FailureCode, Receipt, RpcMsg, SignedReceipt, TransferDirection, TransferRecord,
+};
+
+use super::Runtime;
+use crate::bus::{Endpoints, Responder};
+use crate::db::{self, StoreRpcExt};
+use crate::DaemonError;
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ReceiptError {
+    /// node has no key for signing transfer receipts; it must be started with
+    /// `--consignment-key`.
+    NoKey,
+
+    /// transfer with consignment {0} is not known to the node.
+    UnknownTransfer(ConsignmentId),
+
+    /// transfer with consignment {0} is outgoing; receipts are issued only for
+    /// the transfers received by the node.
+    Outgoing(ConsignmentId),
+}
+
+impl From<&ReceiptError> for FailureCode {
+    fn from(err: &ReceiptError) -> Self {
+        match err {
+            ReceiptError::NoKey => FailureCode::Receipt,
+            ReceiptError::UnknownTransfer(_) | ReceiptError::Outgoing(_) => {
+                FailureCode::UnknownTransfer
+            }
+        }
+    }
+}
+
+impl Runtime {
+    pub(super) fn transfer_receipt(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment_id: ConsignmentId,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.sign_receipt(client_id, consignment_id) {
+            Ok(receipt) => RpcMsg::Receipt(receipt),
+            Err(err) => err.into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    fn sign_receipt(
+        &mut self,
+        client_id: ClientId,
+        consignment_id: ConsignmentId,
+    ) -> Result<SignedReceipt, DaemonError> {
+        let keypair = self.node_key.ok_or(ReceiptError::NoKey)?;
+        let record: TransferRecord = self
+            .store
+            .retrieve_sten(db::TRANSFERS, consignment_id)?
+            .ok_or(ReceiptError::UnknownTransfer(consignment_id))?;
+        // Transfers of the contracts not granted to the client are reported as
+        // unknown, so the client can't probe the stash
+        if !self.access.is_visible(client_id, record.contract_id) {
+            return Err(ReceiptError::UnknownTransfer(consignment_id).into());
+        }
+        if record.direction != TransferDirection::Incoming {
+            return Err(ReceiptError::Outgoing(consignment_id).into());
+        }
+
+        let timestamp = now();
+        let receipt = Receipt::with(&record, timestamp);
+        debug!("Issuing {}", receipt);
+        Ok(receipt.sign(SECP256K1, &keypair))
+    }
+}
@@ -0,0 +1,376 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Relay mode: the node holds consignments sealed to the keys of the offline
+//! recipients until they are picked up or expire, and forwards them to the
+//! peer relays of the federation.
+
+use std::collections::BTreeSet;
+use std::thread;
+use std::time::Duration;
+
+use bitcoin::hashes::sha256;
+use bitcoin::secp256k1::SECP256K1;
+use bitcoin::XOnlyPublicKey;
+use internet2::addr::ServiceAddr;
+use lnpbp::chain::Chain;
+use microservices::esb::ClientId;
+use rgb_rpc::{
+    now, EnvelopeId, FailureCode, NetworkMagic, RelayAuth, RelayDepositReq, RelayEnvelope,
+    RelayFetchReq, RelayPickupReq, RpcMsg, SignetChallenge, UploadChunk, UPLOAD_CHUNK_SIZE,
+};
+use storm::chunk::ChunkIdExt;
+use storm::{Chunk, ChunkId};
+
+use super::Runtime;
+use crate::bus::{Endpoints, Responder};
+use crate::db::{self, StoreRpcExt};
+use crate::{Config, DaemonError};
+
+/// Maximum number of envelopes held for a single recipient
+const MAX_INBOX_SIZE: usize = 1000;
+
+/// Number of attempts to forward an envelope to a peer relay
+const FORWARD_ATTEMPTS: u8 = 3;
+
+/// Delay before the first retry of a failed forwarding; doubled on each next
+/// attempt
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum RelayError {
+    /// node does not run as a relay; it must be started with `--relay`.
+    Disabled,
+
+    /// sealed payload exceeds the relay limit of {0} bytes.
+    Oversized(u32),
+
+    /// relay already holds too many envelopes for recipient {0}.
+    InboxFull(XOnlyPublicKey),
+
+    /// relay has reached its quota of {0} bytes held for all recipients.
+    QuotaExceeded(u64),
+
+    /// recipient authentication has invalid signature or is outdated; check
+    /// the system clock.
+    Unauthorized,
+
+    /// envelope {0} is unknown, expired or addressed to a different recipient.
+    UnknownEnvelope(EnvelopeId),
+
+    /// offset {0} is beyond the envelope payload of {1} bytes.
+    Offset(u32, usize),
+}
+
+impl From<&RelayError> for FailureCode {
+    fn from(err: &RelayError) -> Self {
+        match err {
+            RelayError::Oversized(_) => FailureCode::Oversized,
+            RelayError::InboxFull(_) | RelayError::QuotaExceeded(_) => FailureCode::RateLimited,
+            RelayError::Unauthorized => FailureCode::InvalidSignature,
+            RelayError::Disabled | RelayError::UnknownEnvelope(_) | RelayError::Offset(..) => {
+                FailureCode::Relay
+            }
+        }
+    }
+}
+
+/// Envelope held by the relay
+#[derive(Clone, Debug, StrictEncode, StrictDecode)]
+pub(crate) struct EnvelopeRecord {
+    pub recipient: XOnlyPublicKey,
+    pub envelope: RelayEnvelope,
+    /// Whether the envelope was picked up or has expired, so its payload was
+    /// removed
+    pub collected: bool,
+}
+
+fn inbox_key(recipient: XOnlyPublicKey) -> ChunkId {
+    ChunkId::with_fixed_fragments(db::RELAY_INBOX, recipient)
+}
+
+/// Computes total size of the envelope payloads held by the relay
+pub(super) fn load_relay_usage(store: &mut store_rpc::Client) -> Result<u64, DaemonError> {
+    let mut usage = 0u64;
+    for key in store.ids(db::RELAY_ENVELOPES)? {
+        match store.retrieve_sten::<EnvelopeRecord>(db::RELAY_ENVELOPES, key)? {
+            Some(record) if !record.collected => usage += record.envelope.size as u64,
+            _ => {}
+        }
+    }
+    Ok(usage)
+}
+
+impl Runtime {
+    pub(super) fn relay_deposit(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        req: RelayDepositReq,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.deposit_envelope(client_id, req) {
+            Ok(envelope) => RpcMsg::RelayDeposited(envelope),
+            Err(err) => err.into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    pub(super) fn relay_pickup(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        req: RelayPickupReq,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.pickup_envelopes(req) {
+            Ok(envelopes) => RpcMsg::RelayEnvelopes(envelopes),
+            Err(err) => err.into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    pub(super) fn relay_fetch(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        req: RelayFetchReq,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.fetch_envelope(req) {
+            Ok(chunk) => RpcMsg::RelayChunk(chunk),
+            Err(err) => err.into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    /// Removes payloads of all expired envelopes from the store, returning
+    /// the number of the removed envelopes.
+    pub(super) fn purge_relay(&mut self) -> Result<usize, DaemonError> {
+        let timestamp = now();
+        let mut count = 0usize;
+        for key in self.store.ids(db::RELAY_ENVELOPES)? {
+            let record = match self
+                .store
+                .retrieve_sten::<EnvelopeRecord>(db::RELAY_ENVELOPES, key)?
+            {
+                Some(record) if !record.collected && record.envelope.expires <= timestamp => record,
+                _ => continue,
+            };
+            self.remove_envelope(record)?;
+            count += 1;
+        }
+        if count > 0 {
+            info!("Relay has removed {} expired envelope(s)", count);
+        }
+        Ok(count)
+    }
+
+    fn deposit_envelope(
+        &mut self,
+        client_id: ClientId,
+        req: RelayDepositReq,
+    ) -> Result<RelayEnvelope, DaemonError> {
+        if !self.config.relay {
+            return Err(RelayError::Disabled.into());
+        }
+        let payload = self.uploads.take(client_id, req.size)?;
+        if payload.len() > self.config.relay_max_size as usize {
+            return Err(RelayError::Oversized(self.config.relay_max_size).into());
+        }
+
+        let recipient = req.recipient;
+        let id = EnvelopeId::with(recipient, &payload);
+        let mut inbox = self.inbox(recipient)?;
+        // The same envelope may be deposited by the sender and forwarded by a
+        // peer relay
+        if let Some(envelope) = inbox.iter().find(|envelope| envelope.id == id) {
+            debug!("Envelope {} is already held by the relay", id);
+            return Ok(*envelope);
+        }
+        if inbox.len() >= MAX_INBOX_SIZE {
+            return Err(RelayError::InboxFull(recipient).into());
+        }
+        // Deposits are not authenticated, so the relay limits the space all
+        // senders may take together
+        if self.relay_usage + payload.len() as u64 > self.config.relay_quota {
+            return Err(RelayError::QuotaExceeded(self.config.relay_quota).into());
+        }
+
+        let ttl = req.ttl.unwrap_or(self.config.relay_ttl).min(self.config.relay_ttl);
+        let envelope = RelayEnvelope {
+            id,
+            size: payload.len() as u32,
+            expires: now() + ttl as u64,
+        };
+        let chunk = Chunk::try_from(&payload[..])?;
+        self.store.store(db::RELAY_PAYLOADS, id, &chunk)?;
+        let record = EnvelopeRecord {
+            recipient,
+            envelope,
+            collected: false,
+        };
+        self.store.store_sten(db::RELAY_ENVELOPES, id, &record)?;
+        self.relay_usage += envelope.size as u64;
+        inbox.push(envelope);
+        self.store_inbox(recipient, &inbox)?;
+        info!("Relay holds envelope {} for {} during {} seconds", id, recipient, ttl);
+
+        if req.forward && !self.config.relay_peers.is_empty() {
+            forward(&self.config, recipient, payload, ttl);
+        }
+        Ok(envelope)
+    }
+
+    fn pickup_envelopes(&mut self, req: RelayPickupReq) -> Result<Vec<RelayEnvelope>, DaemonError> {
+        self.authenticate(&req.auth, RelayPickupReq::digest(&req.ack))?;
+        let recipient = req.auth.recipient;
+        let mut inbox = vec![];
+        for envelope in self.inbox(recipient)? {
+            if !req.ack.contains(&envelope.id) {
+                inbox.push(envelope);
+                continue;
+            }
+            if let Some(record) = self.store.retrieve_sten(db::RELAY_ENVELOPES, envelope.id)? {
+                self.remove_envelope(record)?;
+            }
+            debug!("Envelope {} is picked up by {}", envelope.id, recipient);
+        }
+        self.store_inbox(recipient, &inbox)?;
+        Ok(inbox)
+    }
+
+    fn fetch_envelope(&mut self, req: RelayFetchReq) -> Result<UploadChunk, DaemonError> {
+        self.authenticate(&req.auth, RelayFetchReq::digest(req.envelope_id))?;
+        let id = req.envelope_id;
+        let timestamp = now();
+        self.store
+            .retrieve_sten::<EnvelopeRecord>(db::RELAY_ENVELOPES, id)?
+            .filter(|record| {
+                !record.collected
+                    && record.recipient == req.auth.recipient
+                    && record.envelope.expires > timestamp
+            })
+            .ok_or(RelayError::UnknownEnvelope(id))?;
+        let chunk = self
+            .store
+            .retrieve_chunk(db::RELAY_PAYLOADS, id)?
+            .ok_or(RelayError::UnknownEnvelope(id))?;
+        let payload = chunk.as_slice();
+        let offset = req.offset as usize;
+        if offset >= payload.len() {
+            return Err(RelayError::Offset(req.offset, payload.len()).into());
+        }
+        let end = payload.len().min(offset + UPLOAD_CHUNK_SIZE);
+        Ok(UploadChunk {
+            offset: req.offset,
+            data: payload[offset..end].to_vec(),
+        })
+    }
+
+    fn authenticate(&self, auth: &RelayAuth, request: sha256::Hash) -> Result<(), RelayError> {
+        if !self.config.relay {
+            return Err(RelayError::Disabled);
+        }
+        if !auth.verify(SECP256K1, now(), request) {
+            return Err(RelayError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Returns envelopes held for the recipient which have not expired yet.
+    /// Envelopes which have expired are removed.
+    fn inbox(&mut self, recipient: XOnlyPublicKey) -> Result<Vec<RelayEnvelope>, DaemonError> {
+        let ids: BTreeSet<EnvelopeId> =
+            self.store.retrieve_sten(db::RELAY_INBOX, inbox_key(recipient))?.unwrap_or_default();
+        let timestamp = now();
+        let mut inbox = vec![];
+        for id in ids {
+            match self.store.retrieve_sten::<EnvelopeRecord>(db::RELAY_ENVELOPES, id)? {
+                Some(record) if record.collected => {}
+                Some(record) if record.envelope.expires <= timestamp => {
+                    self.remove_envelope(record)?
+                }
+                Some(record) => inbox.push(record.envelope),
+                None => {}
+            }
+        }
+        Ok(inbox)
+    }
+
+    fn store_inbox(
+        &mut self,
+        recipient: XOnlyPublicKey,
+        inbox: &[RelayEnvelope],
+    ) -> Result<(), DaemonError> {
+        let ids = inbox.iter().map(|envelope| envelope.id).collect::<BTreeSet<_>>();
+        self.store.store_sten(db::RELAY_INBOX, inbox_key(recipient), &ids)?;
+        Ok(())
+    }
+
+    fn remove_envelope(&mut self, mut record: EnvelopeRecord) -> Result<(), DaemonError> {
+        let id = record.envelope.id;
+        self.store.wipe_chunk(db::RELAY_PAYLOADS, id)?;
+        record.collected = true;
+        self.store.store_sten(db::RELAY_ENVELOPES, id, &record)?;
+        self.relay_usage = self.relay_usage.saturating_sub(record.envelope.size as u64);
+        Ok(())
+    }
+}
+
+/// Forwards envelope to the peer relays. Each peer is served from a separate
+/// thread retrying failed attempts with an exponential backoff, so the caller
+/// is never blocked.
+fn forward(config: &Config, recipient: XOnlyPublicKey, payload: Vec<u8>, ttl: u32) {
+    let signet = config.signet_challenge.as_ref().map(SignetChallenge::magic);
+    for peer in config.relay_peers.clone() {
+        let chain = config.chain.clone();
+        let payload = payload.clone();
+        thread::spawn(move || {
+            let mut delay = RETRY_DELAY;
+            for attempt in 1..=FORWARD_ATTEMPTS {
+                let res = forward_to(&peer, chain.clone(), signet, recipient, &payload, ttl);
+                match res {
+                    Ok(envelope) => {
+                        debug!("Envelope {} is forwarded to relay {}", envelope.id, peer);
+                        return;
+                    }
+                    Err(err) if attempt < FORWARD_ATTEMPTS => {
+                        warn!("Relay {} failed: {}; retrying in {:?}", peer, err, delay);
+                        thread::sleep(delay);
+                        delay *= 2;
+                    }
+                    Err(err) => error!("Relay {} failed: {}; giving up forwarding", peer, err),
+                }
+            }
+        });
+    }
+}
+
+fn forward_to(
+    peer: &ServiceAddr,
+    chain: Chain,
+    signet: Option<NetworkMagic>,
+    recipient: XOnlyPublicKey,
+    payload: &[u8],
+    ttl: u32,
+) -> Result<RelayEnvelope, rgb_rpc::Error> {
+    let user_agent = format!("rgbd-relay/{}", env!("CARGO_PKG_VERSION"));
+    let mut client = rgb_rpc::Client::with(peer.clone(), user_agent, chain)?;
+    client.set_signet(signet);
+    if !client.hello()? {
+        return Err(rgb_rpc::Error::LocalFailure {
+            code: FailureCode::ChainMismatch,
+            message: s!("peer relay works with a different network"),
+        });
+    }
+    client.relay_deposit(recipient, payload, Some(ttl), false)
+}
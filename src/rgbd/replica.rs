@@ -0,0 +1,332 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Read replica mode: the node follows a primary node, mirroring contracts
+//! and their state history from the primary stash, and serves read requests
+//! only. Wallets, transfer records, swaps, payjoins, policies and annotations
+//! are not replicated.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use internet2::addr::ServiceAddr;
+use microservices::esb::ClientId;
+use microservices::ZMQ_CONTEXT;
+use rgb::ContractId;
+use rgb_rpc::{ContractValidity, FailureCode, NodeEvent, ReplicaStatus, RpcMsg, SignetChallenge};
+use strict_encoding::StrictDecode;
+
+use crate::rgbd::access::authenticate_node;
+use crate::Config;
+
+/// Timeout for receiving events from the primary node, after which the
+/// replicator checks whether it was promoted or has to resynchronize
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Interval between full resynchronizations with the primary node, covering
+/// events which were lost while the primary was unreachable
+const RESYNC_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Delay before reconnecting to the primary node after a failure
+const RETRY_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ReplicaError {
+    /// node is a read replica of {0} and does not accept `{1}` request; the
+    /// node must be promoted to primary first.
+    ReadOnly(String, String),
+
+    /// node is not a read replica or is already promoted to primary.
+    NotReplica,
+
+    /// primary node works with a different chain.
+    ChainMismatch,
+
+    /// request to the primary node has failed. Details: {0}
+    Primary(String),
+
+    /// unable to connect RPC interface of the replica. Details: {0}
+    Local(String),
+
+    /// unable to import contract {0} into the replica. Details: {1}
+    Import(ContractId, String),
+
+    /// unable to receive events from the primary node. Details: {0}
+    Events(String),
+}
+
+impl From<&ReplicaError> for FailureCode {
+    fn from(_: &ReplicaError) -> Self { FailureCode::Replica }
+}
+
+#[derive(Default)]
+struct ReplicaState {
+    promoted: bool,
+    /// Client importing the mirrored contracts into the replica, which is the
+    /// only client allowed to modify the stash
+    importer: Option<ClientId>,
+    mirrored: u64,
+    synced: Option<Instant>,
+}
+
+/// Mirrors the stash of the primary node into the local node.
+///
+/// Replication runs in a separate thread connected to the primary node RPC and
+/// subscribed to its event socket. Each contract registered by the primary or
+/// updated with an accepted consignment is consigned by the primary with its
+/// full known history and imported into the local node over its own RPC
+/// interface, passing the regular validation. Full resynchronization is done
+/// on each (re)connection and periodically after that.
+#[derive(Clone)]
+pub(crate) struct Replicator {
+    primary: Option<ServiceAddr>,
+    state: Arc<Mutex<ReplicaState>>,
+}
+
+impl Replicator {
+    /// Starts replication, unless the node runs as primary
+    pub fn start(config: Config) -> Replicator {
+        let replicator = Replicator {
+            primary: config.replica_of.clone(),
+            state: default!(),
+        };
+        if replicator.primary.is_none() {
+            return replicator;
+        }
+        let clone = replicator.clone();
+        thread::Builder::new()
+            .name(s!("replica"))
+            .spawn(move || clone.run(config))
+            .expect("unable to start replication thread");
+        replicator
+    }
+
+    /// Checks that the node is a replica which was not promoted yet
+    pub fn is_following(&self) -> bool { self.primary.is_some() && !self.state().promoted }
+
+    /// Checks whether the request may be served by the node, failing on the
+    /// requests modifying the stash while the node follows the primary.
+    pub fn check(&self, client_id: ClientId, msg: &RpcMsg) -> Result<(), ReplicaError> {
+        let state = self.state();
+        let primary = match self.primary {
+            Some(ref primary) if !state.promoted => primary,
+            _ => return Ok(()),
+        };
+        if matches!(msg, RpcMsg::ConsumeContract(_)) && state.importer == Some(client_id) {
+            return Ok(());
+        }
+        match modifies_stash(msg) {
+            true => Err(ReplicaError::ReadOnly(primary.to_string(), msg.to_string())),
+            false => Ok(()),
+        }
+    }
+
+    /// Stops following the primary node, allowing requests modifying the
+    /// stash. Promotion is not persisted: the node must be restarted without
+    /// `--replica-of` option to remain primary.
+    pub fn promote(&self) -> Result<(), ReplicaError> {
+        let mut state = self.state();
+        if self.primary.is_none() || state.promoted {
+            return Err(ReplicaError::NotReplica);
+        }
+        state.promoted = true;
+        state.importer = None;
+        info!("Node is promoted to primary and stops replication");
+        Ok(())
+    }
+
+    /// Replication status reported by the node health information
+    pub fn status(&self) -> Option<ReplicaStatus> {
+        let state = self.state();
+        self.primary.as_ref().map(|primary| ReplicaStatus {
+            primary: primary.to_string(),
+            promoted: state.promoted,
+            mirrored: state.mirrored,
+            synced: state.synced.map(|synced| synced.elapsed().as_secs()),
+        })
+    }
+
+    fn state(&self) -> MutexGuard<ReplicaState> {
+        self.state.lock().expect("replication state is poisoned")
+    }
+
+    fn run(self, config: Config) {
+        while self.is_following() {
+            if let Err(err) = self.follow(&config) {
+                warn!("Replication has failed: {}", err);
+                thread::sleep(RETRY_INTERVAL);
+            }
+        }
+    }
+
+    /// Follows the primary node until the replica is promoted or the
+    /// connection fails
+    fn follow(&self, config: &Config) -> Result<(), ReplicaError> {
+        let primary_rpc = self.primary.as_ref().ok_or(ReplicaError::NotReplica)?;
+        let primary_events = config
+            .replica_events
+            .as_ref()
+            .ok_or_else(|| ReplicaError::Events(s!("event socket of the primary is not set")))?;
+        let user_agent = format!("rgbd-replica/{}", env!("CARGO_PKG_VERSION"));
+        let signet = config.signet_challenge.as_ref().map(SignetChallenge::magic);
+        let mut primary =
+            rgb_rpc::Client::with(primary_rpc.clone(), user_agent.clone(), config.chain.clone())
+                .map_err(|err| ReplicaError::Primary(err.to_string()))?;
+        primary.set_signet(signet);
+        if !primary.hello().map_err(|err| ReplicaError::Primary(err.to_string()))? {
+            return Err(ReplicaError::ChainMismatch);
+        }
+        authenticate_node(&mut primary, config).map_err(ReplicaError::Primary)?;
+        let mut local =
+            rgb_rpc::Client::with(config.rpc_endpoint.clone(), user_agent, config.chain.clone())
+                .map_err(|err| ReplicaError::Local(err.to_string()))?;
+        local.set_signet(signet);
+        local.hello().map_err(|err| ReplicaError::Local(err.to_string()))?;
+        authenticate_node(&mut local, config).map_err(ReplicaError::Local)?;
+        self.state().importer = Some(local.client_id());
+
+        // We subscribe before the synchronization, so no updates are missed
+        let subscriber = ZMQ_CONTEXT
+            .socket(zmq::SUB)
+            .and_then(|socket| {
+                socket.connect(&primary_events.zmq_connect_string())?;
+                socket.set_subscribe(b"contract")?;
+                socket.set_subscribe(b"consignment")?;
+                socket.set_rcvtimeo(POLL_INTERVAL.as_millis() as i32)?;
+                Ok(socket)
+            })
+            .map_err(|err| ReplicaError::Events(err.to_string()))?;
+        info!("Following primary node {} with events from {}", primary_rpc, primary_events);
+
+        let mut resynced = Instant::now();
+        self.resync(&mut primary, &mut local)?;
+        while self.is_following() {
+            match subscriber.recv_multipart(0) {
+                Ok(frames) => match frames.get(1).and_then(|data| decode_event(data)) {
+                    Some(NodeEvent::ContractRegistered(contract_id))
+                    | Some(NodeEvent::ConsignmentAccepted { contract_id, .. }) => {
+                        self.mirror(&mut primary, &mut local, contract_id)?;
+                        self.state().synced = Some(Instant::now());
+                    }
+                    Some(NodeEvent::TransferUpdated(_)) => {}
+                    None => warn!("Unable to decode event of the primary node"),
+                },
+                Err(zmq::Error::EAGAIN) => {}
+                Err(err) => return Err(ReplicaError::Events(err.to_string())),
+            }
+            if resynced.elapsed() >= RESYNC_INTERVAL {
+                self.resync(&mut primary, &mut local)?;
+                resynced = Instant::now();
+            }
+        }
+        Ok(())
+    }
+
+    /// Mirrors all contracts known to the primary node
+    fn resync(
+        &self,
+        primary: &mut rgb_rpc::Client,
+        local: &mut rgb_rpc::Client,
+    ) -> Result<(), ReplicaError> {
+        let contract_ids =
+            primary.list_contracts().map_err(|err| ReplicaError::Primary(err.to_string()))?;
+        debug!("Resynchronizing {} contract(s) with the primary node", contract_ids.len());
+        for contract_id in contract_ids {
+            if !self.is_following() {
+                return Ok(());
+            }
+            self.mirror(primary, local, contract_id)?;
+        }
+        self.state().synced = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Imports contract with its full history known to the primary node. The
+    /// contract failing validation is skipped, so the replication proceeds
+    /// with other contracts.
+    fn mirror(
+        &self,
+        primary: &mut rgb_rpc::Client,
+        local: &mut rgb_rpc::Client,
+        contract_id: ContractId,
+    ) -> Result<(), ReplicaError> {
+        let contract = primary
+            .contract(contract_id, vec![], |_| ())
+            .map_err(|err| ReplicaError::Primary(err.to_string()))?;
+        let validity = local
+            .register_contract(contract, false, None, |_| ())
+            .map_err(|err| ReplicaError::Import(contract_id, err.to_string()))?;
+        match validity {
+            ContractValidity::Valid(_) => {
+                debug!("Contract {} is mirrored from the primary node", contract_id);
+                self.state().mirrored += 1;
+            }
+            ContractValidity::Invalid(_) | ContractValidity::UnknownTxids(_) => {
+                warn!(
+                    "Contract {} mirrored from the primary node has failed validation",
+                    contract_id
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes event, which may be published by the primary node either with
+/// strict encoding or as JSON
+fn decode_event(data: &[u8]) -> Option<NodeEvent> {
+    NodeEvent::strict_deserialize(data).ok().or_else(|| serde_json::from_slice(data).ok())
+}
+
+/// Detects requests which modify the stash or depend on the data which are not
+/// replicated from the primary node
+fn modifies_stash(msg: &RpcMsg) -> bool {
+    matches!(
+        msg,
+        RpcMsg::IssueContract(_)
+            | RpcMsg::ImportContract(_)
+            | RpcMsg::ConsumeContract(_)
+            | RpcMsg::ConsumeTransfer(_)
+            | RpcMsg::UploadConsignment(_)
+            | RpcMsg::ConsumeUpload(_)
+            | RpcMsg::ProcessDisclosure(_)
+            | RpcMsg::RevealSeal(_)
+            | RpcMsg::CreateInvoice(_)
+            | RpcMsg::ImportCheckpoint(_)
+            | RpcMsg::SetPolicy(_)
+            | RpcMsg::ConsolidateAllocations(_)
+            | RpcMsg::ForgetContract(_)
+            | RpcMsg::RewindContract(_)
+            | RpcMsg::ReplayConsignments(_)
+            | RpcMsg::RebuildState(_)
+            | RpcMsg::RevalidateContracts(_)
+            | RpcMsg::Transfer(_)
+            | RpcMsg::FinalizeTransfers(_)
+            | RpcMsg::ProposeSwap(_)
+            | RpcMsg::AcceptSwap(_)
+            | RpcMsg::CompleteSwap(_)
+            | RpcMsg::AbortSwap(_)
+            | RpcMsg::ProposePayjoin(_)
+            | RpcMsg::ContributePayjoin(_)
+            | RpcMsg::CompletePayjoin(_)
+            | RpcMsg::ComposePayment(_)
+            | RpcMsg::PublishTx(_)
+            | RpcMsg::AccelerateTransfer(_)
+            | RpcMsg::ForgetSpent(_)
+            | RpcMsg::Annotate(_)
+            | RpcMsg::ApproveQuarantined(_)
+            | RpcMsg::RevalidateQuarantined(_)
+            | RpcMsg::PurgeQuarantined(_)
+            | RpcMsg::GetOrder(_)
+            | RpcMsg::Prune(_)
+    )
+}
@@ -8,11 +8,13 @@
 // You should have received a copy of the MIT License along with this software.
 // If not, see <https://opensource.org/licenses/MIT>.
 
-use std::collections::{BTreeSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::time::{Duration, Instant};
 
 use amplify::Wrapper;
-use bitcoin::hashes::Hash;
-use bitcoin::{OutPoint, Txid};
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::{KeyPair, SECP256K1};
+use bitcoin::{OutPoint, Script, Txid, XOnlyPublicKey};
 use internet2::addr::NodeAddr;
 use internet2::ZmqSocketType;
 use lnpbp::chain::Chain;
@@ -24,29 +26,70 @@ use microservices::{esb, rpc};
 use psbt::Psbt;
 use rgb::schema::TransitionType;
 use rgb::{
-    Contract, ContractConsignment, ContractId, SealEndpoint, StateTransfer, TransferConsignment,
+    ConsignmentId, Contract, ContractConsignment, ContractId, NodeId, SealEndpoint, StateTransfer,
+    TransferConsignment,
 };
 use rgb_rpc::{
-    AcceptReq, ComposeReq, FailureCode, HelloReq, OutpointFilter, Reveal, RpcMsg, TransferReq,
-    TransfersReq,
+    AccelerateReq, AcceptReq, AddressesReq, Annotation, AnnotationTarget, CacheStats, Capability,
+    Checkpoint, CheckpointReq, ComposeReq, Compression, ConsolidateReq, ContractPolicy,
+    DecimalContractState, DiffReq, FailureCode, ForgetReq, ForgetSpentReq, HelloReq,
+    HistoricalBalanceReq, InvoiceRecord, InvoiceReq, IssueReq, JournalEntry, NetworkMagic,
+    NodeHealth, OutpointFilter, OutpointStateQuery, PayjoinCompleteReq, PayjoinContributeReq,
+    PayjoinProposeReq, PaymentReq, PolicyReq, Pong, ProofBundle, ProveReq, PruneReq, PublishReq,
+    ReplayReq, Reveal, RevealExportReq, RevealReq, RewindReq, RpcMsg, SignedCheckpoint,
+    SignetChallenge, StoreRecovery, SwapAcceptReq, SwapCompleteReq, SwapId, SwapProposeReq,
+    TransferFilter, TransferPreviewReq, TransferRecord, TransferReq, TransferSendReq, TransfersReq,
+    UploadChunk, UploadKind, UploadReq, WalletReq, Welcome, WorkerStatus,
 };
-use storm::ContainerId;
+use storm::chunk::ChunkIdExt;
+use storm::{ChunkId, ContainerId};
 use storm_ext::ExtMsg as StormMsg;
 use storm_rpc::AddressedMsg;
+use strict_encoding::StrictDecode;
 
-use crate::bucketd::StashError;
+use crate::bucketd::{
+    contract_transitions, deadline, expired, take_quarantined, verify_checkpoint, ContractAncestry,
+    ForgottenContract, QuarantineEntry, SimulationError, StashError, SwapError, SwapRecord,
+    SwapStatus,
+};
 use crate::bus::{
-    BusMsg, ConsignReq, CtlMsg, DaemonId, Endpoints, FinalizeTransferReq, FinalizeTransfersReq,
-    OutpointStateReq, ProcessDisclosureReq, ProcessReq, Responder, ServiceBus, ServiceId,
+    AccelerateTransferReq, AcceptSwapReq, BusMsg, CompletePayjoinReq, CompleteSwapReq,
+    ComposePaymentReq, ConsignReq, ConsolidateAllocationsReq, ContractAllocationsReq,
+    ContractDepsReq, ContractStatsReq, ContributePayjoinReq, CreateCheckpointReq, CreateInvoiceReq,
+    CreateWalletReq, CtlMsg, DaemonId, DiffHistoryReq, Endpoints, ExportRevealsReq,
+    FinalizeTransferReq, FinalizeTransfersReq, ForgetContractReq, ForgetSpentOutpointsReq,
+    ImportContractReq, IssueContractReq, ListWalletsReq, OutpointStateReq, PreviewTransferReq,
+    ProcessDisclosureReq, ProcessReq, ProposePayjoinReq, ProposeSwapReq, ProveTransactionsReq,
+    PruneDataReq, PublishTxReq, QuarantineReq, RebuildStateReq, ReplayBalanceReq,
+    ReplayConsignmentsReq, Responder, RevalidateContractsReq, RevealSealReq, RewindContractReq,
+    SendTransferReq, ServiceBus, ServiceId, SignCheckpointReq, SignPsbtReq, StateDigestReq,
+    StorageUsageReq, SyncWalletReq, TimestampReq, WalletAddressesReq, WalletBalanceReq,
 };
-use crate::db::ChunkHolder;
-use crate::rgbd::daemons::Daemon;
+use crate::db::StoreRpcExt;
+use crate::entropy::Entropy;
+use crate::plugins::check_plugins;
+use crate::rgbd::access::AccessControl;
+use crate::rgbd::events::EventPublisher;
+use crate::rgbd::faucet::Faucet;
+use crate::rgbd::headers::HeaderTracker;
+use crate::rgbd::lanes::{Assignment, Lane, StateLock, TaskQueue};
+use crate::rgbd::listen;
+use crate::rgbd::migration::migrate_store;
+use crate::rgbd::orders::OrderJournal;
+use crate::rgbd::relay::load_relay_usage;
+use crate::rgbd::replica::Replicator;
+use crate::rgbd::supervisor::Supervisor;
+use crate::rgbd::upload::Uploads;
 use crate::{db, Config, DaemonError, LaunchError};
 
+/// Minimal interval between the automatic pruning of the expired data
+const PRUNING_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 pub fn run(config: Config) -> Result<(), BootstrapError<LaunchError>> {
     let storm_endpoint = config.storm_endpoint.clone();
     let rpc_endpoint = config.rpc_endpoint.clone();
     let ctl_endpoint = config.ctl_endpoint.clone();
+    let rpc_socket = listen::rpc_socket(&config)?;
     let runtime = Runtime::init(config)?;
 
     debug!("Connecting to service buses {}, {}, {}", storm_endpoint, rpc_endpoint, ctl_endpoint);
@@ -57,8 +100,8 @@ pub fn run(config: Config) -> Result<(), BootstrapError<LaunchError>> {
                 ZmqSocketType::RouterConnect,
                 Some(ServiceId::stormd())
             ),
-            ServiceBus::Rpc => esb::BusConfig::with_addr(
-                rpc_endpoint,
+            ServiceBus::Rpc => esb::BusConfig::with_socket(
+                rpc_socket,
                 ZmqSocketType::RouterBind,
                 None
             ),
@@ -84,18 +127,99 @@ pub struct Runtime {
     pub(crate) store: store_rpc::Client,
 
     pub(crate) bucketd_free: VecDeque<DaemonId>,
-    pub(crate) bucketd_busy: BTreeSet<DaemonId>,
-    pub(crate) ctl_queue: VecDeque<CtlMsg>,
+    /// Busy bucket daemons with the tasks assigned to them
+    pub(crate) bucketd_busy: BTreeMap<DaemonId, Assignment>,
+    pub(crate) ctl_queue: TaskQueue,
+
+    /// Clients which have negotiated compression of RPC messages
+    pub(crate) compressed_clients: BTreeSet<ClientId>,
+
+    /// Clients which have negotiated incremental progress reporting
+    pub(crate) progress_clients: BTreeSet<ClientId>,
+
+    /// Clients which have negotiated reporting of the outpoint spent status
+    pub(crate) spent_status_clients: BTreeSet<ClientId>,
+
+    /// Clients which have negotiated reporting of the decimal amounts
+    pub(crate) decimal_amount_clients: BTreeSet<ClientId>,
+
+    /// Socket publishing node events, if configured
+    pub(crate) publisher: Option<EventPublisher>,
+
+    /// Consignments which are being uploaded by the clients
+    pub(crate) uploads: Uploads,
+
+    /// Total size of the envelope payloads held by the relay
+    pub(crate) relay_usage: u64,
+
+    /// Supervisor launching and restarting bucket daemons
+    pub(crate) supervisor: Supervisor,
+
+    /// Tracker of the block header chain used for transaction verification
+    pub(crate) headers: HeaderTracker,
+
+    /// Replicator mirroring the stash of the primary node, if the node runs
+    /// as a read replica
+    pub(crate) replica: Replicator,
+
+    /// Node key signing transfer receipts, if configured
+    pub(crate) node_key: Option<KeyPair>,
+
+    /// Persistent identity key of the node signing pong messages; matches
+    /// the node key, if it is configured
+    pub(crate) identity: KeyPair,
+
+    /// Identities of the clients and their access to the contracts and
+    /// wallets, if the node is shared by multiple users
+    pub(crate) access: AccessControl,
+
+    /// Signed issue and transfer orders of the requests being processed
+    pub(crate) orders: OrderJournal,
+
+    /// Moment after which the node prunes the data which retention period has
+    /// passed
+    pub(crate) retention_due: Instant,
+
+    /// Repairs of the store made at the start, if the node was not stopped
+    /// cleanly
+    pub(crate) recovery: Option<StoreRecovery>,
+
+    /// Number of contracts validated by an older version of the validation
+    /// rules
+    pub(crate) outdated_validations: u32,
+
+    /// Cache hits and misses last reported by each bucket daemon
+    pub(crate) cache_stats: BTreeMap<DaemonId, CacheStats>,
+
+    /// Entropy source of the blinding factors generated by the bucket daemons
+    pub(crate) entropy: Entropy,
+
+    /// Moment the runtime was started
+    pub(crate) started: Instant,
 }
 
 impl Runtime {
     pub fn init(config: Config) -> Result<Self, BootstrapError<LaunchError>> {
+        if config.signet_challenge.is_some() && config.chain != Chain::Signet {
+            let err = format!("signet challenge can't be used with {} network", config.chain);
+            return Err(LaunchError::Config(err).into());
+        }
+        // Consignment key is used by bucketd to sign consignments, while rgbd
+        // signs transfer receipts with it
+        let node_key = config.consignment_keypair()?;
+        let identity = match node_key {
+            Some(keypair) => keypair,
+            None => config.identity_keypair()?,
+        };
+        info!("Node identity is {}", identity.x_only_public_key().0);
+
         debug!("Connecting to store service at {}", config.store_endpoint);
 
         let mut store =
             store_rpc::Client::with(&config.store_endpoint).map_err(LaunchError::from)?;
 
         for table in [
+            db::META,
             db::SCHEMATA,
             db::CONTRACTS,
             db::BUNDLES,
@@ -110,12 +234,98 @@ impl Runtime {
             db::NODE_CONTRACTS,
             db::TRANSITION_WITNESS,
             db::CONTRACT_TRANSITIONS,
+            db::ANCESTRY,
+            db::CHECKPOINTS,
+            db::POLICIES,
+            db::SWAPS,
+            db::PAYJOINS,
+            db::TRANSFERS,
+            db::QUARANTINE,
+            db::CONTAINER_REFS,
+            db::FORGOTTEN,
+            db::STORAGE_USAGE,
+            db::PENDING_REVEALS,
+            db::INVOICES,
+            db::JOURNAL,
+            db::JOURNAL_CONSIGNMENTS,
+            db::WAL,
+            db::WAL_CONSIGNMENTS,
             db::DISCLOSURES,
+            db::CONTRACT_REFS,
+            db::VALIDATIONS,
+            db::STATE_VIEWS,
+            db::STATE_ALLOCATIONS,
+            db::STATE_RIGHTS,
+            db::STATE_DATA,
+            db::STATE_ATTACHMENTS,
             db::ATTACHMENT_CONTAINER_HEADERS,
             db::ATTACHMENT_CONTAINERS,
+            db::CHAIN_TIP,
+            db::CHAIN_TXES,
+            db::HEADER_TIP,
+            db::HEADERS,
+            db::WALLETS,
+            db::WALLET_DESCRIPTORS,
+            db::SCRIPT_UTXOS,
+            db::SPENT_OUTPOINTS,
+            db::PENDING_SPENDS,
+            db::PACKAGES,
+            db::ANNOTATIONS,
+            db::RELAY_ENVELOPES,
+            db::RELAY_PAYLOADS,
+            db::RELAY_INBOX,
+            db::ACCESS_GRANTS,
+            db::ORDERS,
         ] {
             store.use_table(table.to_owned()).map_err(LaunchError::from)?;
         }
+        migrate_store(&mut store)?;
+
+        if !config.admin_keys.is_empty() && config.replica_of.is_some() && node_key.is_none() {
+            let err = s!("replica with access control requires node key to authenticate to the \
+                          primary and local nodes, which must be provided with \
+                          `--consignment-key`");
+            return Err(LaunchError::Config(err).into());
+        }
+        let node_identity = node_key.map(|keypair| keypair.x_only_public_key().0);
+        let access = AccessControl::load(&config, node_identity, &mut store)
+            .map_err(|err| LaunchError::Config(format!("unable to load access grants: {}", err)))?;
+        let orders = OrderJournal::with(&config, node_identity);
+        let relay_usage = match config.relay {
+            true => load_relay_usage(&mut store).map_err(|err| {
+                LaunchError::Config(format!("unable to load relay envelopes: {}", err))
+            })?,
+            false => 0,
+        };
+
+        let publisher = config
+            .events_endpoint
+            .as_ref()
+            .map(|endpoint| EventPublisher::bind(endpoint, config.events_json))
+            .transpose()?;
+
+        // Plugins are run by bucket daemons, but are checked here to fail early
+        check_plugins(&config).map_err(LaunchError::from)?;
+
+        // A failing entropy source does not stop the node, but operations
+        // generating blinding factors are refused until it recovers
+        let entropy = Entropy::with(config.entropy_source.clone());
+        if let Err(err) = entropy.check() {
+            warn!("{}", err);
+        }
+
+        let supervisor = Supervisor::start(config.clone());
+        let headers = HeaderTracker::start(config.clone());
+        let replica = Replicator::start(config.clone());
+        Faucet::start(&config).map_err(LaunchError::from)?;
+
+        // Consignments which were not completely stored before the node has stopped must be
+        // recovered before any other task is processed
+        let mut ctl_queue = TaskQueue::default();
+        ctl_queue.push_back(CtlMsg::RecoverStore);
+        // Contracts accepted before the validation rules were upgraded are
+        // reported in the node health until they are re-validated
+        ctl_queue.push_back(CtlMsg::CheckValidations);
 
         info!("RGBd runtime started successfully");
 
@@ -124,12 +334,39 @@ impl Runtime {
             store,
             bucketd_free: empty!(),
             bucketd_busy: empty!(),
-            ctl_queue: empty!(),
+            ctl_queue,
+            compressed_clients: empty!(),
+            progress_clients: empty!(),
+            spent_status_clients: empty!(),
+            decimal_amount_clients: empty!(),
+            publisher,
+            uploads: default!(),
+            relay_usage,
+            supervisor,
+            headers,
+            replica,
+            node_key,
+            identity,
+            access,
+            orders,
+            retention_due: Instant::now(),
+            recovery: None,
+            outdated_validations: 0,
+            cache_stats: empty!(),
+            entropy,
+            started: Instant::now(),
         })
     }
 }
 
-impl Responder for Runtime {}
+impl Responder for Runtime {
+    fn rpc_compression(&self, client_id: ClientId) -> Compression {
+        match self.compressed_clients.contains(&client_id) {
+            true => Compression::Zstd,
+            false => Compression::None,
+        }
+    }
+}
 
 impl esb::Handler<ServiceBus> for Runtime {
     type Request = BusMsg;
@@ -150,8 +387,11 @@ impl esb::Handler<ServiceBus> for Runtime {
             {
                 self.handle_storm(endpoints, msg)
             }
-            (ServiceBus::Rpc, BusMsg::Rpc(msg), ServiceId::Client(client_id)) => {
-                self.handle_rpc(endpoints, client_id, msg)
+            (ServiceBus::Rpc, BusMsg::Rpc(envelope), ServiceId::Client(client_id)) => {
+                match self.open_rpc(endpoints, client_id, envelope) {
+                    Some(msg) => self.handle_rpc(endpoints, client_id, msg),
+                    None => Ok(()),
+                }
             }
             (ServiceBus::Ctl, BusMsg::Ctl(msg), source) => self.handle_ctl(endpoints, source, msg),
             (bus, msg, _) => Err(DaemonError::wrong_esb_msg(bus, &msg)),
@@ -194,6 +434,11 @@ impl Runtime {
                 )?;
             }
 
+            // Replica receives contract updates only from the primary node
+            StormMsg::ContainerRetrieved(container_id) if self.replica.is_following() => {
+                debug!("Ignoring container {} retrieved by the read replica", container_id);
+            }
+
             // We receive this message when we asked storm daemon to download announced container
             // and the container got downloaded
             StormMsg::ContainerRetrieved(container_id) => {
@@ -215,59 +460,337 @@ impl Runtime {
         client_id: ClientId,
         message: RpcMsg,
     ) -> Result<(), DaemonError> {
+        if let Err(err) = self.replica.check(client_id, &message) {
+            let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+            return Ok(());
+        }
+        if let Err(err) = self.check_access(client_id, &message) {
+            let _ = self.send_rpc(endpoints, client_id, err);
+            return Ok(());
+        }
+        let order = match self.check_order(client_id, &message) {
+            Ok(order) => order,
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                return Ok(());
+            }
+        };
+        // Signed order is kept with the task queued for the request
+        let task_id = self.ctl_queue.next_id();
+        if let Some(order) = order {
+            self.orders.place(task_id, order);
+        }
+        // Contract time travel is allowed on regtest only
+        let simulation = matches!(
+            message,
+            RpcMsg::GetJournal(_) | RpcMsg::RewindContract(_) | RpcMsg::ReplayConsignments(_)
+        );
+        if simulation && !matches!(self.config.chain, Chain::Regtest(_)) {
+            let err = SimulationError::Unavailable(self.config.chain.clone());
+            let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+            return Ok(());
+        }
         match message {
             RpcMsg::Hello(HelloReq {
                 user_agent,
                 network,
+                signet,
+                capabilities,
             }) => {
-                self.accept_client(endpoints, client_id, user_agent, network)?;
+                self.accept_client(
+                    endpoints,
+                    client_id,
+                    user_agent,
+                    network,
+                    signet,
+                    capabilities,
+                )?;
+            }
+            RpcMsg::Authenticate(auth) => {
+                self.authenticate(endpoints, client_id, auth)?;
             }
             RpcMsg::ListContracts => {
                 self.list_contracts(endpoints, client_id)?;
             }
+            RpcMsg::ListSchemata => {
+                self.ctl_queue.push_back(CtlMsg::ListSchemata(client_id));
+                self.pick_or_start(endpoints, client_id)?;
+            }
             RpcMsg::ConsignContract(ComposeReq {
                 contract_id,
                 include,
                 outpoints,
+                known,
+                timeout,
+                armored,
             }) => {
-                self.consign_contract(endpoints, client_id, contract_id, include, outpoints)?;
+                self.consign_contract(
+                    endpoints,
+                    client_id,
+                    contract_id,
+                    include,
+                    outpoints,
+                    known,
+                    deadline(timeout),
+                    armored,
+                )?;
             }
             RpcMsg::ConsignTransfer(ComposeReq {
                 contract_id,
                 include,
                 outpoints,
+                known,
+                timeout,
+                armored,
+            }) => {
+                self.consign_transfer(
+                    endpoints,
+                    client_id,
+                    contract_id,
+                    include,
+                    outpoints,
+                    known,
+                    deadline(timeout),
+                    armored,
+                )?;
+            }
+            RpcMsg::GetKnownHistory(contract_id) => {
+                self.get_known_history(endpoints, client_id, contract_id)?;
+            }
+            RpcMsg::CreateCheckpoint(CheckpointReq {
+                contract_id,
+                height,
+            }) => {
+                self.create_checkpoint(endpoints, client_id, contract_id, height)?;
+            }
+            RpcMsg::ImportCheckpoint(checkpoint) => {
+                self.import_checkpoint(endpoints, client_id, checkpoint)?;
+            }
+            RpcMsg::SetPolicy(PolicyReq {
+                contract_id,
+                policy,
+            }) => {
+                self.set_policy(endpoints, client_id, contract_id, policy)?;
+            }
+            RpcMsg::GetPolicy(contract_id) => {
+                self.get_policy(endpoints, client_id, contract_id)?;
+            }
+            RpcMsg::ConsolidateAllocations(ConsolidateReq {
+                contract_id,
+                outpoints,
+                seal,
+                ..
             }) => {
-                self.consign_transfer(endpoints, client_id, contract_id, include, outpoints)?;
+                self.ctl_queue.push_back(CtlMsg::ConsolidateAllocations(
+                    ConsolidateAllocationsReq {
+                        client_id,
+                        contract_id,
+                        outpoints,
+                        seal,
+                    },
+                ));
+                self.pick_or_start(endpoints, client_id)?;
             }
             RpcMsg::GetContractState(contract_id) => {
                 self.get_contract_state(endpoints, client_id, contract_id)?;
             }
+            RpcMsg::GetContractStats(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::ContractStats(ContractStatsReq {
+                    client_id,
+                    contract_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::GetContractDeps(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::ContractDeps(ContractDepsReq {
+                    client_id,
+                    contract_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ListAllocations(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::ContractAllocations(ContractAllocationsReq {
+                    client_id,
+                    contract_id,
+                    spent_status: self.spent_status_clients.contains(&client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ListStorageUsage(limit) => {
+                self.ctl_queue
+                    .push_back(CtlMsg::StorageUsage(StorageUsageReq { client_id, limit }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::GetStateDigest(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::StateDigest(StateDigestReq {
+                    client_id,
+                    contract_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::DiffHistory(DiffReq {
+                contract_id,
+                bundles,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::DiffHistory(DiffHistoryReq {
+                    client_id,
+                    contract_id,
+                    bundles,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::CommitStash => {
+                self.ctl_queue.push_back(CtlMsg::CommitStash(client_id));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::Timestamp(digest) => {
+                self.timestamp(endpoints, client_id, digest)?;
+            }
             RpcMsg::GetOutpointState(outpoints) => {
-                self.outpoint_transitions(endpoints, client_id, outpoints)?;
+                self.outpoint_transitions(endpoints, client_id, outpoints, empty!())?;
+            }
+            RpcMsg::QueryOutpointState(OutpointStateQuery { outpoints, scripts }) => {
+                self.outpoint_transitions(endpoints, client_id, outpoints, scripts)?;
+            }
+            RpcMsg::IssueContract(IssueReq { spec, schema, .. }) => {
+                self.ctl_queue.push_back(CtlMsg::IssueContract(IssueContractReq {
+                    client_id,
+                    spec,
+                    schema,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::PreviewIssue(IssueReq { spec, schema, .. }) => {
+                self.ctl_queue.push_back(CtlMsg::PreviewIssue(IssueContractReq {
+                    client_id,
+                    spec,
+                    schema,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ImportContract(genesis) => {
+                self.ctl_queue
+                    .push_back(CtlMsg::ImportContract(ImportContractReq { client_id, genesis }));
+                self.pick_or_start(endpoints, client_id)?;
             }
             RpcMsg::ConsumeContract(AcceptReq {
                 consignment: contract,
                 force,
+                full,
+                timeout,
+                proofs,
                 ..
             }) => {
-                self.accept_contract(endpoints, client_id, contract, force)?;
+                self.accept_contract(
+                    endpoints,
+                    client_id,
+                    contract,
+                    force,
+                    full,
+                    deadline(timeout),
+                    proofs,
+                )?;
             }
             RpcMsg::ConsumeTransfer(AcceptReq {
                 consignment: transfer,
                 force,
+                full,
+                reveal,
+                timeout,
+                proofs,
+            }) => {
+                self.accept_transfer(
+                    endpoints,
+                    client_id,
+                    transfer,
+                    force,
+                    full,
+                    reveal,
+                    deadline(timeout),
+                    proofs,
+                )?;
+            }
+            RpcMsg::UploadConsignment(chunk) => {
+                self.upload_consignment(endpoints, client_id, chunk)?;
+            }
+            RpcMsg::ConsumeUpload(UploadReq {
+                kind,
+                size,
+                force,
+                full,
                 reveal,
+                timeout,
+                proofs,
             }) => {
-                self.accept_transfer(endpoints, client_id, transfer, force, reveal)?;
+                self.consume_upload(
+                    endpoints,
+                    client_id,
+                    kind,
+                    size,
+                    force,
+                    full,
+                    reveal,
+                    deadline(timeout),
+                    proofs,
+                )?;
             }
             RpcMsg::ProcessDisclosure(txid) => {
                 self.process_disclosure(endpoints, client_id, txid)?;
             }
+            RpcMsg::RevealSeal(RevealReq {
+                contract_id,
+                reveal,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::RevealSeal(RevealSealReq {
+                    client_id,
+                    contract_id,
+                    reveal,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::CreateInvoice(InvoiceReq {
+                contract_id,
+                amount,
+                outpoint,
+                close_method,
+                transport,
+                signet,
+                tolerance,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::CreateInvoice(CreateInvoiceReq {
+                    client_id,
+                    contract_id,
+                    amount,
+                    outpoint,
+                    close_method,
+                    transport,
+                    signet,
+                    tolerance,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ExportReveals(RevealExportReq {
+                contract_id,
+                outpoints,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ExportReveals(ExportRevealsReq {
+                    client_id,
+                    contract_id,
+                    outpoints,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ProveTransactions(ProveReq { txids, from_height }) => {
+                self.prove_transactions(endpoints, client_id, txids, from_height)?;
+            }
 
             RpcMsg::Transfer(TransferReq {
                 consignment,
                 endseals,
                 psbt,
                 beneficiary,
+                recipient_key,
+                ..
             }) => {
                 self.complete_transfer(
                     endpoints,
@@ -276,133 +799,781 @@ impl Runtime {
                     endseals,
                     psbt,
                     beneficiary,
+                    recipient_key,
                 )?;
             }
 
-            RpcMsg::FinalizeTransfers(TransfersReq { transfers, psbt }) => {
+            RpcMsg::FinalizeTransfers(TransfersReq {
+                transfers, psbt, ..
+            }) => {
                 self.complete_transfers(endpoints, client_id, transfers, psbt)?;
             }
 
-            wrong_msg => {
-                error!("Request is not supported by the RPC interface");
-                return Err(DaemonError::wrong_esb_msg(ServiceBus::Rpc, &wrong_msg));
+            RpcMsg::SendTransfer(TransferSendReq {
+                consignment,
+                beneficiary,
+                recipient_key,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::SendTransfer(SendTransferReq {
+                    client_id,
+                    consignment,
+                    beneficiary,
+                    recipient_key,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
             }
-        }
 
-        Ok(())
-    }
+            RpcMsg::PreviewTransfer(TransferPreviewReq {
+                consignment,
+                endseals,
+                psbt,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::PreviewTransfer(PreviewTransferReq {
+                    client_id,
+                    consignment,
+                    endseals,
+                    psbt,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-    fn handle_ctl(
-        &mut self,
-        endpoints: &mut Endpoints,
-        source: ServiceId,
-        message: CtlMsg,
-    ) -> Result<(), DaemonError> {
-        match message {
-            CtlMsg::Hello => {
-                self.accept_daemon(source)?;
-                self.pick_task(endpoints)?;
+            RpcMsg::SignPsbt(psbt) => {
+                self.sign_psbt(endpoints, client_id, psbt)?;
             }
-            CtlMsg::Validity(_) | CtlMsg::ProcessingFailed | CtlMsg::ProcessingComplete => {
-                if let ServiceId::Bucket(daemon_id) = source {
-                    self.bucketd_busy.remove(&daemon_id);
-                    self.bucketd_free.push_back(daemon_id);
-                    self.pick_task(endpoints)?;
-                }
+
+            RpcMsg::SignCheckpoint(checkpoint) => {
+                self.sign_checkpoint(endpoints, client_id, checkpoint)?;
             }
 
-            wrong_msg => {
-                error!("Request is not supported by the CTL interface");
-                return Err(DaemonError::wrong_esb_msg(ServiceBus::Ctl, &wrong_msg));
+            RpcMsg::ProposeSwap(SwapProposeReq {
+                terms,
+                timeout,
+                psbt,
+                consignment,
+                ..
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ProposeSwap(ProposeSwapReq {
+                    client_id,
+                    terms,
+                    timeout,
+                    psbt,
+                    consignment,
+                    owner: self.access.identity(client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::AcceptSwap(SwapAcceptReq {
+                offer,
+                psbt,
+                consignment,
+                ..
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::AcceptSwap(AcceptSwapReq {
+                    client_id,
+                    offer,
+                    psbt,
+                    consignment,
+                    owner: self.access.identity(client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::CompleteSwap(SwapCompleteReq { acceptance, .. }) => {
+                self.ctl_queue.push_back(CtlMsg::CompleteSwap(CompleteSwapReq {
+                    client_id,
+                    acceptance,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::AbortSwap(swap_id) => {
+                self.abort_swap(endpoints, client_id, swap_id)?;
             }
-        }
 
-        Ok(())
-    }
-}
+            RpcMsg::ProposePayjoin(PayjoinProposeReq {
+                psbt, consignment, ..
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ProposePayjoin(ProposePayjoinReq {
+                    client_id,
+                    psbt,
+                    consignment,
+                    owner: self.access.identity(client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::ContributePayjoin(PayjoinContributeReq {
+                proposal,
+                descriptor,
+                outpoints,
+                endseals,
+                fee,
+                ..
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ContributePayjoin(ContributePayjoinReq {
+                    client_id,
+                    proposal,
+                    descriptor,
+                    outpoints,
+                    endseals,
+                    fee,
+                    owner: self.access.identity(client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+            RpcMsg::CompletePayjoin(PayjoinCompleteReq { contribution, .. }) => {
+                self.ctl_queue.push_back(CtlMsg::CompletePayjoin(CompletePayjoinReq {
+                    client_id,
+                    contribution,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-impl Runtime {
-    fn accept_daemon(&mut self, source: ServiceId) -> Result<(), esb::Error<ServiceId>> {
-        info!("{} daemon is {}", source.ended(), "connected".ended());
+            RpcMsg::SyncWallet(WalletReq {
+                descriptor,
+                gap_limit,
+                rescan,
+            }) => {
+                self.sync_wallet(endpoints, client_id, descriptor, gap_limit, rescan)?;
+            }
 
-        match source {
-            service_id if service_id == ServiceId::rgbd() => {
-                error!("{}", "Unexpected another RGBd instance connection".err());
+            RpcMsg::GetBalance(descriptor) => {
+                self.wallet_balance(endpoints, client_id, descriptor)?;
             }
-            ServiceId::Bucket(daemon_id) => {
-                self.bucketd_free.push_back(daemon_id);
-                info!(
-                    "Bucket daemon {} is registered; total {} container processors are known",
-                    daemon_id,
-                    self.bucketd_free.len() + self.bucketd_busy.len()
-                );
+
+            RpcMsg::CreateWallet(descriptor) => {
+                self.ctl_queue.push_back(CtlMsg::CreateWallet(CreateWalletReq {
+                    client_id,
+                    descriptor,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
             }
-            _ => {
-                // Ignoring the rest of daemon/client types
+
+            RpcMsg::ListWallets => {
+                self.ctl_queue.push_back(CtlMsg::ListWallets(ListWalletsReq {
+                    client_id,
+                    wallets: self.access.visible_wallets(client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
             }
-        }
 
-        Ok(())
-    }
+            RpcMsg::ListAddresses(AddressesReq { descriptor, unused }) => {
+                self.ctl_queue.push_back(CtlMsg::WalletAddresses(WalletAddressesReq {
+                    client_id,
+                    descriptor,
+                    unused,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-    fn pick_task(&mut self, endpoints: &mut Endpoints) -> Result<bool, esb::Error<ServiceId>> {
-        if self.ctl_queue.is_empty() {
-            return Ok(true);
-        }
+            RpcMsg::GetHistoricalBalance(HistoricalBalanceReq {
+                contract_id,
+                height,
+                descriptor,
+                outpoints,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ReplayBalance(ReplayBalanceReq {
+                    client_id,
+                    contract_id,
+                    height,
+                    descriptor,
+                    outpoints,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-        let (service, daemon_id) = match self.bucketd_free.front() {
-            Some(damon_id) => (ServiceId::Bucket(*damon_id), *damon_id),
-            None => return Ok(false),
-        };
+            RpcMsg::ComposePayment(PaymentReq {
+                descriptor,
+                contract_id,
+                beneficiaries,
+                fee,
+                ..
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ComposePayment(ComposePaymentReq {
+                    client_id,
+                    descriptor,
+                    contract_id,
+                    beneficiaries,
+                    fee,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-        let msg = match self.ctl_queue.pop_front() {
-            None => return Ok(true),
-            Some(req) => req,
-        };
+            RpcMsg::PublishTx(PublishReq { psbt, .. }) => {
+                self.ctl_queue.push_back(CtlMsg::PublishTx(PublishTxReq { client_id, psbt }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-        debug!("Assigning task {} to {}", msg, service);
+            RpcMsg::AccelerateTransfer(AccelerateReq {
+                consignment_id,
+                descriptor,
+                fee_rate,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::AccelerateTransfer(AccelerateTransferReq {
+                    client_id,
+                    consignment_id,
+                    descriptor,
+                    fee_rate,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-        self.send_ctl(endpoints, service, msg)?;
-        self.bucketd_free.pop_front();
-        self.bucketd_busy.insert(daemon_id);
-        Ok(true)
-    }
+            RpcMsg::ForgetSpent(ForgetSpentReq {
+                descriptor,
+                outpoints,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ForgetSpent(ForgetSpentOutpointsReq {
+                    client_id,
+                    descriptor,
+                    outpoints,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
 
-    fn pick_or_start(
-        &mut self,
-        endpoints: &mut Endpoints,
-        client_id: ClientId,
+            RpcMsg::Annotate(annotation) => {
+                self.annotate(endpoints, client_id, annotation)?;
+            }
+
+            RpcMsg::GetAnnotations(targets) => {
+                self.get_annotations(endpoints, client_id, targets)?;
+            }
+
+            RpcMsg::ListTransfers(filter) => {
+                self.list_transfers(endpoints, client_id, filter)?;
+            }
+            RpcMsg::ListInvoices(contract_id) => {
+                self.list_invoices(endpoints, client_id, contract_id)?;
+            }
+
+            RpcMsg::GetReceipt(consignment_id) => {
+                self.transfer_receipt(endpoints, client_id, consignment_id)?;
+            }
+            RpcMsg::GetOrder(target) => {
+                self.get_order(endpoints, client_id, target)?;
+            }
+
+            RpcMsg::ListQuarantine => {
+                self.list_quarantine(endpoints, client_id)?;
+            }
+
+            RpcMsg::ApproveQuarantined(consignment_id) => {
+                self.ctl_queue.push_back(CtlMsg::ApproveQuarantined(QuarantineReq {
+                    client_id,
+                    consignment_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::RevalidateQuarantined(consignment_id) => {
+                self.ctl_queue.push_back(CtlMsg::RevalidateQuarantined(QuarantineReq {
+                    client_id,
+                    consignment_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::PurgeQuarantined(consignment_id) => {
+                self.purge_quarantined(endpoints, client_id, consignment_id)?;
+            }
+
+            RpcMsg::CollectGarbage => {
+                if self.config.relay {
+                    if let Err(err) = self.purge_relay() {
+                        warn!("Unable to remove expired relay envelopes: {}", err);
+                    }
+                }
+                self.ctl_queue.push_back(CtlMsg::CollectGarbage(client_id));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::Prune(PruneReq { dry_run }) => {
+                self.ctl_queue.push_back(CtlMsg::PruneData(PruneDataReq {
+                    client_id: Some(client_id),
+                    dry_run,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::ForgetContract(ForgetReq {
+                contract_id,
+                tombstone,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ForgetContract(ForgetContractReq {
+                    client_id,
+                    contract_id,
+                    tombstone,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::GetJournal(contract_id) => {
+                let journal: Vec<JournalEntry> =
+                    self.store.retrieve_sten(db::JOURNAL, contract_id)?.unwrap_or_default();
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Journal(journal));
+            }
+
+            RpcMsg::RewindContract(RewindReq {
+                contract_id,
+                consignment_id,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::RewindContract(RewindContractReq {
+                    client_id,
+                    contract_id,
+                    consignment_id,
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::ReplayConsignments(ReplayReq {
+                contract_id,
+                consignments,
+            }) => {
+                self.ctl_queue.push_back(CtlMsg::ReplayConsignments(ReplayConsignmentsReq {
+                    client_id,
+                    contract_id,
+                    consignments,
+                    progress: self.progress_clients.contains(&client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::RebuildState(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::RebuildState(RebuildStateReq {
+                    client_id,
+                    contract_id,
+                    progress: self.progress_clients.contains(&client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::RevalidateContracts(contract_id) => {
+                self.ctl_queue.push_back(CtlMsg::RevalidateContracts(RevalidateContractsReq {
+                    client_id,
+                    contract_id,
+                    progress: self.progress_clients.contains(&client_id),
+                }));
+                self.pick_or_start(endpoints, client_id)?;
+            }
+
+            RpcMsg::GetHealth => {
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Health(self.health()));
+            }
+            RpcMsg::Ping(nonce) => {
+                let pong = Pong::sign(SECP256K1, &self.identity, nonce);
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Pong(pong));
+            }
+            RpcMsg::GetConfig => {
+                let settings = self.config.settings.clone();
+                let _ = self.send_rpc(endpoints, client_id, RpcMsg::Config(settings));
+            }
+            RpcMsg::RelayDeposit(req) => {
+                self.relay_deposit(endpoints, client_id, req)?;
+            }
+            RpcMsg::RelayPickup(req) => {
+                self.relay_pickup(endpoints, client_id, req)?;
+            }
+            RpcMsg::RelayFetch(req) => {
+                self.relay_fetch(endpoints, client_id, req)?;
+            }
+            RpcMsg::Cancel(request_client_id) => {
+                self.cancel(endpoints, client_id, request_client_id)?;
+            }
+            RpcMsg::Promote => {
+                let msg = match self.replica.promote() {
+                    Ok(()) => RpcMsg::success(),
+                    Err(err) => DaemonError::from(err).into(),
+                };
+                let _ = self.send_rpc(endpoints, client_id, msg);
+            }
+            RpcMsg::GrantAccess(req) => {
+                self.grant_access(endpoints, client_id, req)?;
+            }
+            RpcMsg::RevokeAccess(req) => {
+                self.revoke_access(endpoints, client_id, req)?;
+            }
+            RpcMsg::ListAccess => {
+                self.list_access(endpoints, client_id)?;
+            }
+
+            wrong_msg => {
+                error!("Request is not supported by the RPC interface");
+                return Err(DaemonError::wrong_esb_msg(ServiceBus::Rpc, &wrong_msg));
+            }
+        }
+
+        if self.ctl_queue.next_id() == task_id {
+            self.orders.discard(task_id);
+        }
+        Ok(())
+    }
+
+    fn handle_ctl(
+        &mut self,
+        endpoints: &mut Endpoints,
+        source: ServiceId,
+        message: CtlMsg,
     ) -> Result<(), DaemonError> {
-        if self.pick_task(endpoints)? {
+        let success = matches!(message, CtlMsg::ProcessingComplete);
+        match message {
+            CtlMsg::Hello => {
+                self.accept_daemon(source.clone())?;
+                if !self.compressed_clients.is_empty() {
+                    self.send_ctl(
+                        endpoints,
+                        source,
+                        CtlMsg::CompressedClients(self.compressed_clients.clone()),
+                    )?;
+                }
+                self.pick_task(endpoints)?;
+            }
+            CtlMsg::Validity(_) | CtlMsg::ProcessingFailed | CtlMsg::ProcessingComplete => {
+                if let ServiceId::Bucket(daemon_id) = source {
+                    let assignment = self.bucketd_busy.remove(&daemon_id);
+                    let client_id = assignment.and_then(|assignment| assignment.client_id);
+                    self.access.complete(daemon_id, client_id);
+                    self.orders.complete(
+                        daemon_id,
+                        assignment.map(|assignment| assignment.task_id),
+                        success,
+                    );
+                    self.bucketd_free.push_back(daemon_id);
+                    self.pick_task(endpoints)?;
+                }
+            }
+            CtlMsg::DaemonTerminated(daemon_id) if source == ServiceId::Supervisor => {
+                self.daemon_terminated(endpoints, daemon_id)?;
+            }
+            CtlMsg::InvalidateStates(updates) => {
+                let daemons = self.bucketd_free.iter().chain(self.bucketd_busy.keys()).copied();
+                for daemon_id in daemons.collect::<Vec<_>>() {
+                    if source == ServiceId::Bucket(daemon_id) {
+                        continue;
+                    }
+                    self.send_ctl(
+                        endpoints,
+                        ServiceId::Bucket(daemon_id),
+                        CtlMsg::InvalidateStates(updates.clone()),
+                    )?;
+                }
+            }
+            CtlMsg::CacheStats(stats) => {
+                if let ServiceId::Bucket(daemon_id) = source {
+                    self.cache_stats.insert(daemon_id, stats);
+                }
+            }
+            CtlMsg::StoreRecovered(recovery) => {
+                if recovery.is_empty() {
+                    debug!("Store is consistent, no recovery is needed");
+                } else {
+                    warn!("Store is recovered after unclean shutdown: {}", recovery);
+                    self.recovery = Some(recovery);
+                }
+            }
+            CtlMsg::ValidationsChecked(outdated) => {
+                if outdated > 0 {
+                    warn!(
+                        "{} contract(s) were validated by an older version of the validation \
+                         rules and should be re-validated",
+                        outdated
+                    );
+                }
+                self.outdated_validations = outdated;
+            }
+            CtlMsg::Events(events) => {
+                if let ServiceId::Bucket(daemon_id) = source {
+                    self.grant_processed(daemon_id, &events)?;
+                    self.record_order(daemon_id, &events)?;
+                }
+                if let Some(publisher) = &self.publisher {
+                    events.iter().for_each(|event| publisher.publish(event));
+                }
+            }
+
+            wrong_msg => {
+                error!("Request is not supported by the CTL interface");
+                return Err(DaemonError::wrong_esb_msg(ServiceBus::Ctl, &wrong_msg));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Runtime {
+    fn accept_daemon(&mut self, source: ServiceId) -> Result<(), esb::Error<ServiceId>> {
+        info!("{} daemon is {}", source.ended(), "connected".ended());
+
+        match source {
+            service_id if service_id == ServiceId::rgbd() => {
+                error!("{}", "Unexpected another RGBd instance connection".err());
+            }
+            ServiceId::Bucket(daemon_id) => {
+                self.bucketd_free.push_back(daemon_id);
+                info!(
+                    "Bucket daemon {} is registered; total {} container processors are known",
+                    daemon_id,
+                    self.bucketd_free.len() + self.bucketd_busy.len()
+                );
+            }
+            _ => {
+                // Ignoring the rest of daemon/client types
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Assigns queued tasks to the free bucket daemons. Batch tasks are
+    /// assigned only if the batch lane has a free capacity, and tasks which
+    /// state lock conflicts with the locks held by the assigned tasks are kept
+    /// in the queue. Returns `false` if there is a task which can be assigned,
+    /// but no free daemon.
+    fn pick_task(&mut self, endpoints: &mut Endpoints) -> Result<bool, esb::Error<ServiceId>> {
+        self.schedule_pruning();
+        loop {
+            let batch_allowed = self.lane_busy(Lane::Batch) < self.config.batch_workers;
+            let held = self.held_locks();
+            if !self.ctl_queue.has_ready(batch_allowed, &held) {
+                return Ok(true);
+            }
+
+            let (service, daemon_id) = match self.bucketd_free.front() {
+                Some(damon_id) => (ServiceId::Bucket(*damon_id), *damon_id),
+                None => return Ok(false),
+            };
+
+            // Tasks which deadline has passed while they were queued are failed
+            // without being assigned
+            let (task_id, msg) = loop {
+                match self.ctl_queue.pop_front(batch_allowed, &held) {
+                    None => return Ok(true),
+                    Some((task_id, req)) if expired(req.deadline()) => {
+                        self.orders.discard(task_id);
+                        if let Some(client_id) = req.client_id() {
+                            debug!("Dropping task {} which deadline has expired", req);
+                            let _ =
+                                self.send_rpc(endpoints, client_id, DaemonError::DeadlineExpired);
+                        }
+                    }
+                    Some(task) => break task,
+                }
+            };
+
+            let assignment = Assignment {
+                task_id,
+                lane: Lane::of(&msg),
+                client_id: msg.client_id(),
+                lock: StateLock::of(&msg),
+            };
+            match assignment.lock {
+                Some(lock) => debug!(
+                    "Assigning {} task {} locking {} to {}",
+                    assignment.lane, msg, lock, service
+                ),
+                None => debug!("Assigning {} task {} to {}", assignment.lane, msg, service),
+            }
+
+            self.send_ctl(endpoints, service, msg)?;
+            self.bucketd_free.pop_front();
+            self.bucketd_busy.insert(daemon_id, assignment);
+        }
+    }
+
+    /// Queues pruning of the data which retention period has passed, unless
+    /// it was done recently. Replicas keep the data of the primary node.
+    fn schedule_pruning(&mut self) {
+        if self.retention_due > Instant::now()
+            || !self.config.prunes_data()
+            || self.replica.is_following()
+        {
+            return;
+        }
+        self.retention_due = Instant::now() + PRUNING_INTERVAL;
+        debug!("Scheduling pruning of the expired data");
+        self.ctl_queue.push_back(CtlMsg::PruneData(PruneDataReq {
+            client_id: None,
+            dry_run: false,
+        }));
+    }
+
+    /// State locks held by the tasks assigned to bucket daemons
+    fn held_locks(&self) -> Vec<StateLock> {
+        self.bucketd_busy.values().filter_map(|assignment| assignment.lock).collect()
+    }
+
+    /// Number of bucket daemons busy with the tasks of the lane
+    fn lane_busy(&self, lane: Lane) -> usize {
+        self.bucketd_busy.values().filter(|assignment| assignment.lane == lane).count()
+    }
+
+    /// Launches new bucket daemon unless the number of daemons has reached
+    /// the total capacity of the processing lanes.
+    fn start_worker(&mut self) -> Result<bool, DaemonError> {
+        if self.supervisor.count() >= self.config.interactive_workers + self.config.batch_workers {
+            return Ok(false);
+        }
+        let daemon_id = self.supervisor.spawn()?;
+        debug!("Launched bucket daemon {}", daemon_id);
+        Ok(true)
+    }
+
+    /// Forgets terminated bucket daemon, failing the request it was processing.
+    /// The daemon connects again once it is restarted by the supervisor.
+    fn daemon_terminated(
+        &mut self,
+        endpoints: &mut Endpoints,
+        daemon_id: DaemonId,
+    ) -> Result<(), esb::Error<ServiceId>> {
+        warn!("Bucket daemon {} has terminated", daemon_id);
+        self.bucketd_free.retain(|id| *id != daemon_id);
+        self.cache_stats.remove(&daemon_id);
+        if let Some(Assignment {
+            task_id, client_id, ..
+        }) = self.bucketd_busy.remove(&daemon_id)
+        {
+            self.orders.discard(task_id);
+            if let Some(client_id) = client_id {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::WorkerFailed(daemon_id));
+            }
+        }
+        self.pick_task(endpoints)?;
+        Ok(())
+    }
+
+    fn health(&self) -> NodeHealth {
+        let mut workers = self.supervisor.health(|daemon_id| {
+            if let Some(assignment) = self.bucketd_busy.get(&daemon_id) {
+                (WorkerStatus::Busy, assignment.client_id)
+            } else if self.bucketd_free.contains(&daemon_id) {
+                (WorkerStatus::Idle, None)
+            } else {
+                (WorkerStatus::Starting, None)
+            }
+        });
+        for worker in &mut workers {
+            worker.caches = self.cache_stats.get(&worker.daemon_id).copied().unwrap_or_default();
+        }
+        NodeHealth {
+            uptime: self.started.elapsed().as_secs(),
+            queued: self.ctl_queue.len() as u32,
+            workers,
+            header_tip: self.headers.tip(),
+            replica: self.replica.status(),
+            recovery: self.recovery.clone(),
+            outdated_validations: self.outdated_validations,
+            entropy_failure: self.entropy.check().err().map(|err| err.to_string()),
+            identity: self.identity.x_only_public_key().0,
+        }
+    }
+
+    /// Cancels request of a client: removes it from the queue or asks the
+    /// bucket daemon processing it to interrupt the processing.
+    fn cancel(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        request_client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let mut found = false;
+        let removed = self.ctl_queue.remove_client(request_client_id);
+        removed.iter().for_each(|task_id| self.orders.discard(*task_id));
+        if !removed.is_empty() {
+            info!("Queued request of client {} is cancelled", request_client_id);
             let _ = self.send_rpc(
                 endpoints,
-                client_id,
-                RpcMsg::Progress(s!("Task forwarded to bucket daemon")),
+                request_client_id,
+                DaemonError::Cancelled(request_client_id),
             );
-            return Ok(());
+            found = true;
         }
+        let daemon_ids = self
+            .bucketd_busy
+            .iter()
+            .filter(|(_, assignment)| assignment.client_id == Some(request_client_id))
+            .map(|(daemon_id, _)| *daemon_id)
+            .collect::<Vec<_>>();
+        for daemon_id in daemon_ids {
+            info!(
+                "Interrupting request of client {} processed by {}",
+                request_client_id, daemon_id
+            );
+            self.send_ctl(
+                endpoints,
+                ServiceId::BucketInterrupts(daemon_id),
+                CtlMsg::Cancel(request_client_id),
+            )?;
+            found = true;
+        }
+        let msg = match found {
+            true => RpcMsg::success(),
+            false => DaemonError::UnknownRequest(request_client_id).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
 
-        let _handle = self.launch_daemon(Daemon::Bucketd, self.config.clone())?;
-        let _ = self.send_rpc(
-            endpoints,
-            client_id,
-            RpcMsg::Progress(s!("A new bucket daemon instance is started")),
-        );
-
-        // TODO: Store daemon handlers
+    fn pick_or_start(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let info = if !self.pick_task(endpoints)? && self.start_worker()? {
+            s!("A new bucket daemon instance is started")
+        } else if self.bucketd_busy.values().any(|task| task.client_id == Some(client_id)) {
+            s!("Task forwarded to bucket daemon")
+        } else {
+            s!("Task is queued until a bucket daemon becomes available")
+        };
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::Progress(info));
         Ok(())
     }
 
+    /// Optional features provided by the node with its current configuration
+    fn capabilities(&self) -> BTreeSet<Capability> {
+        let mut capabilities = bset![
+            Capability::Wallet,
+            Capability::Annotations,
+            Capability::Compression,
+            Capability::ProgressSteps,
+            Capability::NodeIdentity,
+            Capability::SpentStatus,
+            Capability::DecimalAmounts
+        ];
+        if self.config.signer_endpoint.is_some() {
+            capabilities.insert(Capability::Signer);
+        }
+        if self.config.issuer_signer.is_some() {
+            capabilities.insert(Capability::IssuerSigner);
+        }
+        if self.config.timestamp_calendar.is_some() {
+            capabilities.insert(Capability::Timestamping);
+        }
+        if self.config.relay {
+            capabilities.insert(Capability::Relay);
+        }
+        capabilities
+    }
+
     fn accept_client(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
         user_agent: String,
         network: Chain,
+        signet: Option<NetworkMagic>,
+        capabilities: BTreeSet<Capability>,
     ) -> Result<(), DaemonError> {
         info!("Accepting new client with id {} ({})", client_id, user_agent);
-        let msg = match self.config.chain == network {
-            true => RpcMsg::success(),
+        // Custom signets share the same chain and are told apart by the magic
+        let same_network = self.config.chain == network
+            && self.config.signet_challenge.as_ref().map(SignetChallenge::magic) == signet;
+        let negotiated = self.capabilities().intersection(&capabilities).copied().collect();
+        let msg = match same_network {
+            true if capabilities.contains(&Capability::NodeIdentity) => RpcMsg::Welcome(Welcome {
+                capabilities: negotiated,
+                identity: self.identity.x_only_public_key().0,
+                auth_nonce: self.access.issue_nonce(client_id),
+            }),
+            // Clients which do not know about the node identity expect only
+            // the capabilities in response
+            true => RpcMsg::Capabilities(negotiated),
             false => rpc::Failure {
                 code: rpc::FailureCode::Other(FailureCode::ChainMismatch),
                 info: s!("wrong network"),
@@ -410,6 +1581,35 @@ impl Runtime {
             .into(),
         };
         let _ = self.send_rpc(endpoints, client_id, msg);
+
+        match same_network && capabilities.contains(&Capability::ProgressSteps) {
+            true => self.progress_clients.insert(client_id),
+            false => self.progress_clients.remove(&client_id),
+        };
+        match same_network && capabilities.contains(&Capability::SpentStatus) {
+            true => self.spent_status_clients.insert(client_id),
+            false => self.spent_status_clients.remove(&client_id),
+        };
+        match same_network && capabilities.contains(&Capability::DecimalAmounts) {
+            true => self.decimal_amount_clients.insert(client_id),
+            false => self.decimal_amount_clients.remove(&client_id),
+        };
+
+        let compression = same_network && capabilities.contains(&Capability::Compression);
+        let changed = match compression {
+            true => self.compressed_clients.insert(client_id),
+            false => self.compressed_clients.remove(&client_id),
+        };
+        if changed {
+            let daemons = self.bucketd_free.iter().chain(self.bucketd_busy.keys()).copied();
+            for daemon_id in daemons.collect::<Vec<_>>() {
+                self.send_ctl(
+                    endpoints,
+                    ServiceId::Bucket(daemon_id),
+                    CtlMsg::CompressedClients(self.compressed_clients.clone()),
+                )?;
+            }
+        }
         Ok(())
     }
 
@@ -418,15 +1618,25 @@ impl Runtime {
         endpoints: &mut Endpoints,
         client_id: ClientId,
     ) -> Result<(), DaemonError> {
+        // Forgotten contracts keep their keys in the store, since it has no removal operation
+        let mut forgotten = bset! {};
+        for key in self.store.ids(db::FORGOTTEN)? {
+            if self.store.retrieve_sten::<ForgottenContract>(db::FORGOTTEN, key)?.is_some() {
+                forgotten.insert(key);
+            }
+        }
         let ids = self.store.ids(db::CONTRACTS)?;
         let ids = ids
             .into_iter()
+            .filter(|id| !forgotten.contains(id))
             .map(|id| ContractId::from_inner(Hash::from_inner(id.into_inner())))
+            .filter(|contract_id| self.access.is_visible(client_id, *contract_id))
             .collect();
         let _ = self.send_rpc(endpoints, client_id, RpcMsg::ContractIds(ids));
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn consign_contract(
         &mut self,
         endpoints: &mut Endpoints,
@@ -434,17 +1644,25 @@ impl Runtime {
         contract_id: ContractId,
         include: BTreeSet<TransitionType>,
         outpoints: OutpointFilter,
+        known: BTreeSet<NodeId>,
+        deadline: Option<u64>,
+        armored: bool,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::ConsignContract(ConsignReq {
             client_id,
             contract_id,
             include,
             outpoints,
+            known,
+            deadline,
+            armored,
+            progress: self.progress_clients.contains(&client_id),
             _phantom: ContractConsignment,
         }));
         self.pick_or_start(endpoints, client_id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn consign_transfer(
         &mut self,
         endpoints: &mut Endpoints,
@@ -452,77 +1670,359 @@ impl Runtime {
         contract_id: ContractId,
         include: BTreeSet<TransitionType>,
         outpoints: OutpointFilter,
+        known: BTreeSet<NodeId>,
+        deadline: Option<u64>,
+        armored: bool,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::ConsignTranfer(ConsignReq {
             client_id,
             contract_id,
             include,
             outpoints,
+            known,
+            deadline,
+            armored,
+            progress: self.progress_clients.contains(&client_id),
             _phantom: TransferConsignment,
         }));
         self.pick_or_start(endpoints, client_id)
     }
 
+    fn get_known_history(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        let node_ids = contract_transitions(&mut self.store, contract_id)?;
+        let mut tips = ContractAncestry::load(&mut self.store, node_ids)?.tips();
+        // History preceding the checkpoint is not needed unless full validation is requested
+        let checkpoint: Option<SignedCheckpoint> =
+            self.store.retrieve_sten(db::CHECKPOINTS, contract_id)?;
+        if let Some(checkpoint) = checkpoint {
+            tips.extend(checkpoint.checkpoint.frontier_ids());
+        }
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::KnownHistory(tips));
+        Ok(())
+    }
+
+    fn create_checkpoint(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        height: u32,
+    ) -> Result<(), DaemonError> {
+        self.ctl_queue.push_back(CtlMsg::CreateCheckpoint(CreateCheckpointReq {
+            client_id,
+            contract_id,
+            height,
+        }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
+    fn import_checkpoint(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        checkpoint: SignedCheckpoint,
+    ) -> Result<(), DaemonError> {
+        let contract_id = checkpoint.checkpoint.contract_id;
+        if let Err(err) = verify_checkpoint(&checkpoint, &self.config.trusted_issuers) {
+            let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+            return Ok(());
+        }
+        let known: Option<SignedCheckpoint> =
+            self.store.retrieve_sten(db::CHECKPOINTS, contract_id)?;
+        match known {
+            Some(known) if known.checkpoint.height >= checkpoint.checkpoint.height => {
+                info!("Ignoring {}: a later checkpoint {} is known", checkpoint, known);
+            }
+            _ => {
+                info!("Importing {}", checkpoint);
+                self.store.store_sten(db::CHECKPOINTS, contract_id, &checkpoint)?;
+            }
+        }
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+        Ok(())
+    }
+
     fn get_contract_state(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
         contract_id: ContractId,
     ) -> Result<(), DaemonError> {
-        let msg = match self.store.retrieve(db::CONTRACTS, contract_id)? {
-            Some(state) => RpcMsg::ContractState(ChunkHolder::unbox(state)),
+        let msg = match self.store.retrieve_sten(db::CONTRACTS, contract_id)? {
+            Some(state) if self.decimal_amount_clients.contains(&client_id) => {
+                RpcMsg::DecimalContractState(DecimalContractState::with(state))
+            }
+            Some(state) => RpcMsg::ContractState(state),
             None => DaemonError::from(StashError::StateAbsent(contract_id)).into(),
         };
         let _ = self.send_rpc(endpoints, client_id, msg);
         Ok(())
     }
 
+    fn set_policy(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+        policy: ContractPolicy,
+    ) -> Result<(), DaemonError> {
+        info!("Setting {} for contract {}", policy, contract_id);
+        self.store.store_sten(db::POLICIES, contract_id, &policy)?;
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+        Ok(())
+    }
+
+    fn get_policy(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        let policy: ContractPolicy =
+            self.store.retrieve_sten(db::POLICIES, contract_id)?.unwrap_or_default();
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::Policy(policy));
+        Ok(())
+    }
+
+    fn abort_swap(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        swap_id: SwapId,
+    ) -> Result<(), DaemonError> {
+        let mut record: SwapRecord =
+            self.store.retrieve_sten(db::SWAPS, swap_id)?.ok_or(SwapError::Unknown(swap_id))?;
+        info!("Aborting {} swap {}", record.status, swap_id);
+        record.status = SwapStatus::Aborted;
+        self.store.store_sten(db::SWAPS, swap_id, &record)?;
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+        Ok(())
+    }
+
+    fn annotate(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        annotation: Annotation,
+    ) -> Result<(), DaemonError> {
+        // Store has no removal operation, so empty annotations are kept as tombstones
+        let key = ChunkId::with_fixed_fragments(db::ANNOTATIONS, annotation.target);
+        self.store.store_sten(db::ANNOTATIONS, key, &annotation)?;
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+        Ok(())
+    }
+
+    fn get_annotations(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        targets: BTreeSet<AnnotationTarget>,
+    ) -> Result<(), DaemonError> {
+        let keys = if targets.is_empty() {
+            self.store.ids(db::ANNOTATIONS)?
+        } else {
+            targets
+                .into_iter()
+                .map(|target| ChunkId::with_fixed_fragments(db::ANNOTATIONS, target))
+                .collect()
+        };
+        let mut annotations = vec![];
+        for key in keys {
+            let annotation: Option<Annotation> = self.store.retrieve_sten(db::ANNOTATIONS, key)?;
+            annotations.extend(annotation.filter(|annotation| !annotation.is_empty()));
+        }
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::Annotations(annotations));
+        Ok(())
+    }
+
+    fn list_transfers(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        filter: TransferFilter,
+    ) -> Result<(), DaemonError> {
+        let mut transfers = vec![];
+        for key in self.store.ids(db::TRANSFERS)? {
+            let record: Option<TransferRecord> = self.store.retrieve_sten(db::TRANSFERS, key)?;
+            transfers.extend(record.filter(|record| filter.matches(record)));
+        }
+        transfers.sort_by_key(|record| record.created);
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::TransferList(transfers));
+        Ok(())
+    }
+
+    fn list_invoices(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        contract_id: ContractId,
+    ) -> Result<(), DaemonError> {
+        let mut invoices = vec![];
+        for key in self.store.ids(db::INVOICES)? {
+            let record: Option<InvoiceRecord> = self.store.retrieve_sten(db::INVOICES, key)?;
+            invoices.extend(record.filter(|record| record.invoice.contract_id == contract_id));
+        }
+        invoices.sort_by_key(|record| record.created);
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::InvoiceList(invoices));
+        Ok(())
+    }
+
+    fn list_quarantine(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+    ) -> Result<(), DaemonError> {
+        let mut list = vec![];
+        for key in self.store.ids(db::QUARANTINE)? {
+            let entry: Option<QuarantineEntry> = self.store.retrieve_sten(db::QUARANTINE, key)?;
+            list.extend(entry.filter(|entry| entry.consignment.is_some()).map(|entry| entry.info));
+        }
+        list.sort_by_key(|info| info.received);
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::QuarantineList(list));
+        Ok(())
+    }
+
+    fn purge_quarantined(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        consignment_id: ConsignmentId,
+    ) -> Result<(), DaemonError> {
+        take_quarantined(&mut self.store, consignment_id)?;
+        info!("Consignment {} is purged from the quarantine", consignment_id);
+        let _ = self.send_rpc(endpoints, client_id, RpcMsg::success());
+        Ok(())
+    }
+
     fn outpoint_transitions(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
         outpoints: BTreeSet<OutPoint>,
+        scripts: BTreeSet<Script>,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::OutpointState(OutpointStateReq {
             client_id,
             outpoints,
+            scripts,
+            spent_status: self.spent_status_clients.contains(&client_id),
+            decimal_amounts: self.decimal_amount_clients.contains(&client_id),
+            contracts: self.access.visible_contracts(client_id),
         }));
         self.pick_or_start(endpoints, client_id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn accept_contract(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
         contract: Contract,
         force: bool,
+        full: bool,
+        deadline: Option<u64>,
+        proofs: Option<ProofBundle>,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::ProcessContract(ProcessReq {
             client_id,
             consignment: contract,
             force,
+            full,
             reveal: None,
+            deadline,
+            proofs,
+            progress: self.progress_clients.contains(&client_id),
         }));
         self.pick_or_start(endpoints, client_id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn accept_transfer(
         &mut self,
         endpoints: &mut Endpoints,
         client_id: ClientId,
         transfer: StateTransfer,
         force: bool,
+        full: bool,
         reveal: Option<Reveal>,
+        deadline: Option<u64>,
+        proofs: Option<ProofBundle>,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::ProcessTransfer(ProcessReq {
             client_id,
             consignment: transfer,
             force,
+            full,
             reveal,
+            deadline,
+            proofs,
+            progress: self.progress_clients.contains(&client_id),
         }));
         self.pick_or_start(endpoints, client_id)
     }
 
+    fn upload_consignment(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        chunk: UploadChunk,
+    ) -> Result<(), DaemonError> {
+        let msg = match self.uploads.append(client_id, chunk) {
+            Ok(size) => {
+                trace!("Client {} has uploaded {} bytes", client_id, size);
+                RpcMsg::success()
+            }
+            Err(err) => DaemonError::from(err).into(),
+        };
+        let _ = self.send_rpc(endpoints, client_id, msg);
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn consume_upload(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        kind: UploadKind,
+        size: u32,
+        force: bool,
+        full: bool,
+        reveal: Option<Reveal>,
+        deadline: Option<u64>,
+        proofs: Option<ProofBundle>,
+    ) -> Result<(), DaemonError> {
+        let data = match self.uploads.take(client_id, size) {
+            Ok(data) => data,
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                return Ok(());
+            }
+        };
+        debug!("Decoding {} consignment of {} bytes uploaded by {}", kind, size, client_id);
+        let res = match kind {
+            UploadKind::Contract => Contract::strict_deserialize(data).map(|contract| {
+                self.accept_contract(endpoints, client_id, contract, force, full, deadline, proofs)
+            }),
+            UploadKind::Transfer => StateTransfer::strict_deserialize(data).map(|transfer| {
+                self.accept_transfer(
+                    endpoints, client_id, transfer, force, full, reveal, deadline, proofs,
+                )
+            }),
+        };
+        match res {
+            Ok(res) => res,
+            Err(err) => {
+                let _ = self.send_rpc(endpoints, client_id, DaemonError::from(err));
+                Ok(())
+            }
+        }
+    }
+
     fn process_disclosure(
         &mut self,
         endpoints: &mut Endpoints,
@@ -534,6 +2034,21 @@ impl Runtime {
         self.pick_or_start(endpoints, client_id)
     }
 
+    fn prove_transactions(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        txids: BTreeSet<Txid>,
+        from_height: u32,
+    ) -> Result<(), DaemonError> {
+        self.ctl_queue.push_back(CtlMsg::ProveTransactions(ProveTransactionsReq {
+            client_id,
+            txids,
+            from_height,
+        }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
     fn process_transfer(
         &mut self,
         endpoints: &mut Endpoints,
@@ -541,11 +2056,12 @@ impl Runtime {
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::ProcessTransferContainer(container_id));
         if !self.pick_task(endpoints)? {
-            self.launch_daemon(Daemon::Bucketd, self.config.clone())?;
+            self.start_worker()?;
         }
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn complete_transfer(
         &mut self,
         endpoints: &mut Endpoints,
@@ -554,6 +2070,7 @@ impl Runtime {
         endseals: Vec<SealEndpoint>,
         psbt: Psbt,
         beneficiary: Option<NodeAddr>,
+        recipient_key: Option<XOnlyPublicKey>,
     ) -> Result<(), DaemonError> {
         self.ctl_queue.push_back(CtlMsg::FinalizeTransfer(FinalizeTransferReq {
             client_id,
@@ -561,6 +2078,7 @@ impl Runtime {
             endseals,
             psbt,
             beneficiary,
+            recipient_key,
         }));
         self.pick_or_start(endpoints, client_id)
     }
@@ -579,4 +2097,70 @@ impl Runtime {
         }));
         self.pick_or_start(endpoints, client_id)
     }
+
+    fn sync_wallet(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+        gap_limit: u16,
+        rescan: bool,
+    ) -> Result<(), DaemonError> {
+        self.ctl_queue.push_back(CtlMsg::SyncWallet(SyncWalletReq {
+            client_id,
+            descriptor,
+            gap_limit,
+            rescan,
+        }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
+    fn wallet_balance(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        descriptor: String,
+    ) -> Result<(), DaemonError> {
+        self.ctl_queue.push_back(CtlMsg::WalletBalance(WalletBalanceReq {
+            client_id,
+            descriptor,
+        }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
+    fn sign_psbt(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        psbt: Psbt,
+    ) -> Result<(), DaemonError> {
+        // Signer may take long to reply, so the request is served by a bucket daemon
+        self.ctl_queue.push_back(CtlMsg::SignPsbt(SignPsbtReq { client_id, psbt }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
+    fn sign_checkpoint(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        checkpoint: Checkpoint,
+    ) -> Result<(), DaemonError> {
+        // Signer may take long to reply, so the request is served by a bucket daemon
+        self.ctl_queue.push_back(CtlMsg::SignCheckpoint(SignCheckpointReq {
+            client_id,
+            checkpoint,
+        }));
+        self.pick_or_start(endpoints, client_id)
+    }
+
+    fn timestamp(
+        &mut self,
+        endpoints: &mut Endpoints,
+        client_id: ClientId,
+        digest: sha256::Hash,
+    ) -> Result<(), DaemonError> {
+        // Calendar may take long to reply, so the request is served by a bucket daemon
+        self.ctl_queue.push_back(CtlMsg::Timestamp(TimestampReq { client_id, digest }));
+        self.pick_or_start(endpoints, client_id)
+    }
 }
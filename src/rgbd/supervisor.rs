@@ -0,0 +1,256 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeMap;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bitcoin::secp256k1::rand::random;
+use internet2::ZmqSocketType;
+use microservices::esb::{self, ClientId, EndpointList};
+use microservices::LauncherError;
+use rgb_rpc::{WorkerHealth, WorkerStatus};
+
+use crate::bus::{BusMsg, CtlMsg, DaemonId, ServiceBus, ServiceId};
+use crate::rgbd::Daemon;
+use crate::Config;
+
+/// Delay before the first restart of a terminated worker, doubling with each
+/// subsequent restart
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+
+/// Maximal delay before restarting a terminated worker
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(60);
+
+/// Worker running for this time is considered recovered, resetting the
+/// restart delay
+const STABLE_UPTIME: Duration = Duration::from_secs(60);
+
+/// Supervision state of a worker daemon
+struct Worker {
+    started: Instant,
+    restarts: u32,
+    last_failure: Option<String>,
+    restarting: bool,
+}
+
+impl Worker {
+    fn new() -> Worker {
+        Worker {
+            started: Instant::now(),
+            restarts: 0,
+            last_failure: None,
+            restarting: false,
+        }
+    }
+}
+
+/// Launches bucket daemons and restarts them once they terminate.
+///
+/// Each daemon is watched by a separate thread waiting for its termination.
+/// Terminations are handled by the supervisor thread, which notifies RGBd
+/// over the CTL bus, such that the task processed by the daemon can be failed,
+/// and restarts the daemon with an exponential backoff.
+#[derive(Clone)]
+pub(crate) struct Supervisor {
+    config: Config,
+    workers: Arc<Mutex<BTreeMap<DaemonId, Worker>>>,
+    terminations: Sender<(DaemonId, String)>,
+}
+
+impl Supervisor {
+    pub fn start(config: Config) -> Supervisor {
+        let (sender, receiver) = mpsc::channel();
+        let supervisor = Supervisor {
+            config,
+            workers: default!(),
+            terminations: sender,
+        };
+        let clone = supervisor.clone();
+        thread::Builder::new()
+            .name(s!("supervisor"))
+            .spawn(move || clone.run(receiver))
+            .expect("unable to start supervisor thread");
+        supervisor
+    }
+
+    /// Launches new bucket daemon, returning its identifier
+    pub fn spawn(&self) -> Result<DaemonId, LauncherError<Daemon>> {
+        let daemon_id = random();
+        self.workers().insert(daemon_id, Worker::new());
+        self.launch(daemon_id).map_err(|err| {
+            self.workers().remove(&daemon_id);
+            err
+        })?;
+        Ok(daemon_id)
+    }
+
+    /// Number of supervised workers, including the ones awaiting restart
+    pub fn count(&self) -> usize { self.workers().len() }
+
+    /// Reports health of all supervised workers. The status of running
+    /// workers and the clients which requests they process are provided by
+    /// the caller.
+    pub fn health(
+        &self,
+        status: impl Fn(DaemonId) -> (WorkerStatus, Option<ClientId>),
+    ) -> Vec<WorkerHealth> {
+        self.workers()
+            .iter()
+            .map(|(daemon_id, worker)| {
+                let (status, client_id) = match worker.restarting {
+                    true => (WorkerStatus::Restarting, None),
+                    false => status(*daemon_id),
+                };
+                WorkerHealth {
+                    daemon: s!("bucketd"),
+                    daemon_id: *daemon_id,
+                    status,
+                    client_id,
+                    restarts: worker.restarts,
+                    uptime: worker.started.elapsed().as_secs(),
+                    last_failure: worker.last_failure.clone(),
+                    caches: default!(),
+                }
+            })
+            .collect()
+    }
+
+    fn workers(&self) -> MutexGuard<BTreeMap<DaemonId, Worker>> {
+        self.workers.lock().expect("supervisor state is poisoned")
+    }
+
+    fn launch(&self, daemon_id: DaemonId) -> Result<(), LauncherError<Daemon>> {
+        let handle = Daemon::Bucketd(daemon_id).launch(&self.config)?;
+        let terminations = self.terminations.clone();
+        thread::spawn(move || {
+            let reason = match handle.join() {
+                Ok(()) => s!("daemon has stopped"),
+                Err(err) => err.to_string(),
+            };
+            let _ = terminations.send((daemon_id, reason));
+        });
+        Ok(())
+    }
+
+    /// Registers worker termination, returning delay before the worker
+    /// restart
+    fn terminated(&self, daemon_id: DaemonId, reason: String) -> Duration {
+        let mut workers = self.workers();
+        let worker = workers.entry(daemon_id).or_insert_with(Worker::new);
+        if worker.started.elapsed() >= STABLE_UPTIME {
+            worker.restarts = 0;
+        }
+        let delay = RESTART_DELAY
+            .checked_mul(1 << worker.restarts.min(16))
+            .unwrap_or(MAX_RESTART_DELAY)
+            .min(MAX_RESTART_DELAY);
+        error!(
+            "Bucket daemon {} has terminated: {}; restarting in {} s",
+            daemon_id,
+            reason,
+            delay.as_secs()
+        );
+        worker.restarts += 1;
+        worker.last_failure = Some(reason);
+        worker.restarting = true;
+        delay
+    }
+
+    fn restart(&self, daemon_id: DaemonId) -> Result<(), LauncherError<Daemon>> {
+        info!("Restarting bucket daemon {}", daemon_id);
+        if let Some(worker) = self.workers().get_mut(&daemon_id) {
+            worker.started = Instant::now();
+            worker.restarting = false;
+        }
+        self.launch(daemon_id)
+    }
+
+    fn run(self, terminations: Receiver<(DaemonId, String)>) {
+        let mut esb = match esb::Controller::with(
+            map! {
+                ServiceBus::Ctl => esb::BusConfig::with_addr(
+                    self.config.ctl_endpoint.clone(),
+                    ZmqSocketType::RouterConnect,
+                    Some(ServiceId::rgbd())
+                )
+            },
+            Notifier,
+        ) {
+            Ok(esb) => esb,
+            Err(err) => {
+                error!("Supervisor is unable to connect to CTL bus: {}", err);
+                return;
+            }
+        };
+
+        let mut scheduled = Vec::<(Instant, DaemonId)>::new();
+        loop {
+            let timeout = scheduled
+                .iter()
+                .map(|(at, _)| at.saturating_duration_since(Instant::now()))
+                .min()
+                .unwrap_or(MAX_RESTART_DELAY);
+            match terminations.recv_timeout(timeout) {
+                Ok((daemon_id, reason)) => {
+                    let delay = self.terminated(daemon_id, reason);
+                    let msg = BusMsg::Ctl(CtlMsg::DaemonTerminated(daemon_id));
+                    if let Err(err) = esb.send_to(ServiceBus::Ctl, ServiceId::rgbd(), msg) {
+                        error!("Unable to notify RGBd about terminated daemon: {}", err);
+                    }
+                    scheduled.push((Instant::now() + delay, daemon_id));
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+
+            let now = Instant::now();
+            let (due, pending) = scheduled.into_iter().partition::<Vec<_>, _>(|(at, _)| *at <= now);
+            scheduled = pending;
+            for (_, daemon_id) in due {
+                if let Err(err) = self.restart(daemon_id) {
+                    let delay = self.terminated(daemon_id, err.to_string());
+                    scheduled.push((Instant::now() + delay, daemon_id));
+                }
+            }
+        }
+    }
+}
+
+/// Handler of the supervisor CTL bus connection, which is used only to send
+/// notifications to RGBd
+struct Notifier;
+
+impl esb::Handler<ServiceBus> for Notifier {
+    type Request = BusMsg;
+    type Error = esb::Error<ServiceId>;
+
+    fn identity(&self) -> ServiceId { ServiceId::Supervisor }
+
+    fn handle(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        _: ServiceBus,
+        _: ServiceId,
+        _: BusMsg,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn handle_err(
+        &mut self,
+        _: &mut EndpointList<ServiceBus>,
+        err: esb::Error<ServiceId>,
+    ) -> Result<(), Self::Error> {
+        Err(err)
+    }
+}
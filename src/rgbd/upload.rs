@@ -0,0 +1,81 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::collections::BTreeMap;
+
+use microservices::esb::ClientId;
+use rgb_rpc::{FailureCode, UploadChunk, MAX_UPLOAD_SIZE};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum UploadError {
+    /// upload chunk at offset {0} does not follow {1} bytes uploaded so far.
+    OutOfOrder(u32, usize),
+
+    /// uploaded consignment exceeds the maximal allowed size.
+    Oversized,
+
+    /// no consignment was uploaded by the client.
+    Absent,
+
+    /// uploaded consignment has {0} bytes, while {1} bytes were expected.
+    Incomplete(usize, u32),
+}
+
+impl From<&UploadError> for FailureCode {
+    fn from(err: &UploadError) -> Self {
+        match err {
+            UploadError::Oversized => FailureCode::Oversized,
+            UploadError::OutOfOrder(..) | UploadError::Absent | UploadError::Incomplete(..) => {
+                FailureCode::Upload
+            }
+        }
+    }
+}
+
+/// Consignment data uploaded by the clients and not yet consumed
+#[derive(Default)]
+pub(crate) struct Uploads(BTreeMap<ClientId, Vec<u8>>);
+
+impl Uploads {
+    /// Appends chunk to the data uploaded by the client, returning the total
+    /// uploaded size. The failed upload is discarded.
+    pub fn append(
+        &mut self,
+        client_id: ClientId,
+        chunk: UploadChunk,
+    ) -> Result<usize, UploadError> {
+        if chunk.offset == 0 {
+            self.0.remove(&client_id);
+        }
+        let data = self.0.entry(client_id).or_default();
+        let res = if chunk.offset as usize != data.len() {
+            Err(UploadError::OutOfOrder(chunk.offset, data.len()))
+        } else if data.len() + chunk.data.len() > MAX_UPLOAD_SIZE {
+            Err(UploadError::Oversized)
+        } else {
+            data.extend(chunk.data);
+            Ok(data.len())
+        };
+        if res.is_err() {
+            self.0.remove(&client_id);
+        }
+        res
+    }
+
+    /// Takes complete upload of the client.
+    pub fn take(&mut self, client_id: ClientId, size: u32) -> Result<Vec<u8>, UploadError> {
+        let data = self.0.remove(&client_id).ok_or(UploadError::Absent)?;
+        if data.len() != size as usize {
+            return Err(UploadError::Incomplete(data.len(), size));
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,217 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Layered configuration of the node daemons. Each setting is taken from the
+//! command-line option, then from the environment variable, then from the
+//! configuration file, and falls back to the default value defined by the
+//! option.
+//!
+//! Keys of the configuration file are the long names of the command-line
+//! options, so the file values are validated and applied with the same
+//! parsers as the values given in the command line.
+
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::{env, fs, io, iter};
+
+use clap::error::ErrorKind;
+use clap::{Arg, ArgMatches, CommandFactory, Parser, ValueSource};
+use rgb_rpc::{Setting, SettingSource};
+
+use crate::opts::RGB_NODE_CONFIG;
+use crate::{rgbd, LaunchError};
+
+/// Settings locating the configuration file, which can't be given in the file
+/// itself
+const LOCATION_KEYS: [&str; 2] = ["data-dir", "config"];
+
+/// Settings which values are masked in the configuration reports
+const SECRET_KEYS: [&str; 3] = ["hook-secret", "sql-export", "bdk-wallet"];
+
+#[derive(Clone, PartialEq, Eq, Debug, Display, Error)]
+#[display(doc_comments)]
+pub enum ConfigError {
+    /// unable to access configuration file {0}: {1}
+    Io(String, String),
+
+    /// configuration file {0} is invalid: {1}
+    Toml(String, String),
+
+    /// configuration file {0} has unknown key `{1}`.
+    UnknownKey(String, String),
+
+    /// configuration file {0} can't set `{1}`, which must be given in the
+    /// command line or with the environment variable.
+    LocationKey(String, String),
+
+    /// configuration file {0} has invalid value of `{1}`: {2}
+    InvalidValue(String, String, String),
+}
+
+impl From<ConfigError> for LaunchError {
+    fn from(err: ConfigError) -> Self { LaunchError::Config(err.to_string()) }
+}
+
+/// Parses daemon options from the command line, the environment variables and
+/// the node configuration file, returning them together with the report on
+/// the configuration layers providing each of the settings.
+///
+/// Errors in the command-line options and the environment variables are
+/// reported by clap, terminating the process, while errors in the
+/// configuration file are returned.
+pub fn parse<P: Parser>() -> Result<(P, Vec<Setting>), ConfigError> {
+    let mut args = env::args_os().collect::<Vec<_>>();
+    let matches = P::command().get_matches_from(&args);
+
+    let (path, required) = config_path(&matches);
+    let name = path.display().to_string();
+    let data = match fs::read_to_string(&path) {
+        Ok(data) => Some(data),
+        Err(err) if err.kind() == io::ErrorKind::NotFound && !required => None,
+        Err(err) => return Err(ConfigError::Io(name, err.to_string())),
+    };
+
+    let mut file_keys = bset! {};
+    if let Some(data) = data {
+        let table: toml::value::Table = toml::from_str(&data)
+            .map_err(|err| ConfigError::Toml(name.clone(), err.to_string()))?;
+        // Keys are checked against the options of RGBd, so the options of the
+        // daemon not known to the bucket daemons are skipped by them
+        let node = rgbd::Opts::command();
+        let command = P::command();
+        let mut file_args = vec![];
+        for (key, value) in table {
+            if LOCATION_KEYS.contains(&key.as_str()) {
+                return Err(ConfigError::LocationKey(name, key));
+            }
+            let arg = match node.get_arguments().find(|arg| arg.get_long() == Some(key.as_str())) {
+                Some(arg) => arg,
+                None => return Err(ConfigError::UnknownKey(name, key)),
+            };
+            let key_args = option_args(arg, value)
+                .and_then(|key_args| validate(&key_args).map(|_| key_args))
+                .map_err(|msg| ConfigError::InvalidValue(name.clone(), key.clone(), msg))?;
+            let source =
+                match command.get_arguments().find(|arg| arg.get_long() == Some(key.as_str())) {
+                    Some(arg) => matches.value_source(arg.get_id()),
+                    None => continue,
+                };
+            match source {
+                Some(ValueSource::CommandLine) | Some(ValueSource::EnvVariable) => {}
+                _ => {
+                    file_args.extend(key_args.into_iter().map(OsString::from));
+                    file_keys.insert(key);
+                }
+            }
+        }
+        let cli_args = args.split_off(1);
+        args.extend(file_args);
+        args.extend(cli_args);
+    }
+
+    let command = P::command();
+    let matches = command.clone().get_matches_from(&args);
+    let opts = P::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    let settings = command
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set() && !matches!(arg.get_id(), "help" | "version"))
+        .filter_map(|arg| setting(arg, &matches, &file_keys))
+        .collect();
+    Ok((opts, settings))
+}
+
+/// Detects path to the configuration file and whether the file must exist
+fn config_path(matches: &ArgMatches) -> (PathBuf, bool) {
+    let first = |id: &str| {
+        matches
+            .get_raw(id)
+            .and_then(|mut values| values.next())
+            .map(|value| shellexpand::tilde(&value.to_string_lossy()).to_string())
+    };
+    match first("config") {
+        Some(path) => (PathBuf::from(path), true),
+        None => {
+            let data_dir = first("data_dir").unwrap_or_default();
+            (PathBuf::from(RGB_NODE_CONFIG.replace("{data_dir}", &data_dir)), false)
+        }
+    }
+}
+
+/// Converts configuration file value into command-line arguments
+fn option_args(arg: &Arg, value: toml::Value) -> Result<Vec<String>, String> {
+    let long = arg.get_long().unwrap_or_default();
+    if !arg.is_takes_value_set() {
+        return match value {
+            toml::Value::Boolean(true) => Ok(vec![format!("--{}", long)]),
+            toml::Value::Boolean(false) => Ok(vec![]),
+            toml::Value::Integer(count) if count >= 0 => {
+                Ok(vec![format!("--{}", long); count as usize])
+            }
+            _ => Err(s!("boolean value is expected")),
+        };
+    }
+    let values = match value {
+        toml::Value::Array(values) if arg.is_multiple_occurrences_set() => values,
+        toml::Value::Array(_) => return Err(s!("single value is expected, not an array")),
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .map(|value| match value {
+            toml::Value::String(s) => Ok(format!("--{}={}", long, s)),
+            toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                Ok(format!("--{}={}", long, value))
+            }
+            _ => Err(s!("string, number or boolean value is expected")),
+        })
+        .collect()
+}
+
+/// Validates the value with the parser of the command-line option. Missing
+/// options required by the validated one are given elsewhere and are checked
+/// when the options are parsed.
+fn validate(key_args: &[String]) -> Result<(), String> {
+    let args = iter::once("rgbd").chain(key_args.iter().map(String::as_str));
+    match rgbd::Opts::try_parse_from(args) {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::MissingRequiredArgument => Ok(()),
+        Err(err) => {
+            let msg = err.to_string();
+            let msg = msg.lines().next().unwrap_or_default();
+            Err(msg.trim_start_matches("error: ").to_owned())
+        }
+    }
+}
+
+/// Reports setting value and the configuration layer it is taken from
+fn setting(arg: &Arg, matches: &ArgMatches, file_keys: &BTreeSet<String>) -> Option<Setting> {
+    let key = arg.get_long()?.to_owned();
+    let id = arg.get_id();
+    let source = match matches.value_source(id) {
+        _ if file_keys.contains(&key) => SettingSource::File,
+        Some(ValueSource::CommandLine) => SettingSource::CommandLine,
+        Some(ValueSource::EnvVariable) => SettingSource::Env,
+        Some(_) => SettingSource::Default,
+        // Flags which are not set are reported as disabled
+        None if !arg.is_takes_value_set() => SettingSource::Default,
+        None => return None,
+    };
+    let value = match (arg.is_takes_value_set(), matches.occurrences_of(id)) {
+        (false, count) if count > 1 => count.to_string(),
+        (false, _) => matches.value_source(id).is_some().to_string(),
+        (true, _) if SECRET_KEYS.contains(&key.as_str()) => s!("***"),
+        (true, _) => matches
+            .get_raw(id)
+            .map(|values| values.map(|value| value.to_string_lossy()).collect::<Vec<_>>().join(","))
+            .unwrap_or_default(),
+    };
+    Some(Setting { key, value, source })
+}
@@ -0,0 +1,185 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use bitcoin::secp256k1::{schnorr, SECP256K1};
+use bitcoin::XOnlyPublicKey;
+use internet2::addr::ServiceAddr;
+use internet2::session::LocalSession;
+use internet2::{
+    CreateUnmarshaller, SendRecvMessage, TypedEnum, Unmarshall, Unmarshaller, ZmqSocketType,
+};
+use microservices::ZMQ_CONTEXT;
+use psbt::Psbt;
+use rgb::ContractId;
+use rgb_rpc::{Checkpoint, FailureCode, SignedCheckpoint, SignerMsg};
+use serde_json::{json, Value};
+
+use crate::http::{self, HttpError};
+
+/// Time to wait for the signer reply before giving up, in milliseconds
+const SIGNER_TIMEOUT: i32 = 60_000;
+
+/// Maximum size of the HTTP signer reply
+const MAX_REPLY_SIZE: u64 = 64 * 1024;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum SignerError {
+    /// external signer is not configured for the node.
+    NotConfigured,
+
+    /// unable to connect to the external signer. Details: {0}
+    Connection(String),
+
+    /// external signer has refused to sign the PSBT. Details: {0}
+    Refused(String),
+
+    /// external signer has sent an unexpected reply.
+    UnexpectedReply,
+
+    /// issuer signer is not configured for the node.
+    IssuerNotConfigured,
+
+    /// request to the issuer signer has failed. Details: {0}
+    #[from]
+    Http(HttpError),
+
+    /// issuer signer has returned invalid signature of the checkpoint for contract {0}.
+    InvalidSignature(ContractId),
+}
+
+impl From<&SignerError> for FailureCode {
+    fn from(err: &SignerError) -> Self {
+        match err {
+            SignerError::InvalidSignature(_) => FailureCode::InvalidSignature,
+            _ => FailureCode::Signer,
+        }
+    }
+}
+
+/// Client connecting to an external signer daemon using [`SignerMsg`]
+/// protocol.
+pub(crate) struct SignerClient {
+    session: LocalSession,
+    unmarshaller: Unmarshaller<SignerMsg>,
+}
+
+impl SignerClient {
+    pub fn with(connect: &ServiceAddr) -> Result<Self, SignerError> {
+        debug!("Connecting to external signer at {}", connect);
+        let socket =
+            ZMQ_CONTEXT.socket(zmq::REQ).map_err(|err| SignerError::Connection(err.to_string()))?;
+        socket
+            .set_rcvtimeo(SIGNER_TIMEOUT)
+            .and_then(|_| socket.set_linger(0))
+            .and_then(|_| socket.connect(&connect.zmq_connect_string()))
+            .map_err(|err| SignerError::Connection(err.to_string()))?;
+        Ok(SignerClient {
+            session: LocalSession::with_zmq_socket(ZmqSocketType::Req, socket),
+            unmarshaller: SignerMsg::create_unmarshaller(),
+        })
+    }
+
+    pub fn sign(&mut self, psbt: Psbt) -> Result<Psbt, SignerError> {
+        match self.request(SignerMsg::SignPsbt(psbt))? {
+            SignerMsg::SignedPsbt(psbt) => Ok(psbt),
+            _ => Err(SignerError::UnexpectedReply),
+        }
+    }
+
+    pub fn sign_checkpoint(
+        &mut self,
+        checkpoint: Checkpoint,
+    ) -> Result<SignedCheckpoint, SignerError> {
+        match self.request(SignerMsg::SignCheckpoint(checkpoint))? {
+            SignerMsg::SignedCheckpoint(signed) => Ok(signed),
+            _ => Err(SignerError::UnexpectedReply),
+        }
+    }
+
+    fn request(&mut self, msg: SignerMsg) -> Result<SignerMsg, SignerError> {
+        let data = msg.serialize();
+        self.session
+            .send_raw_message(&data)
+            .map_err(|err| SignerError::Connection(err.to_string()))?;
+        let raw = self
+            .session
+            .recv_raw_message()
+            .map_err(|err| SignerError::Connection(err.to_string()))?;
+        let reply = self
+            .unmarshaller
+            .unmarshall(raw.as_slice())
+            .map_err(|_| SignerError::UnexpectedReply)?;
+        match (*reply).clone() {
+            SignerMsg::Failure(failure) => Err(SignerError::Refused(failure.info)),
+            reply => Ok(reply),
+        }
+    }
+}
+
+/// Backend signing contract checkpoints with the issuer key, which is kept off
+/// the node host.
+#[derive(Clone, PartialEq, Eq, Debug, Display)]
+pub enum IssuerSigner {
+    /// External signer daemon using [`SignerMsg`] protocol, which may keep the
+    /// keys in a hardware security module
+    #[display("{0}")]
+    Zmq(ServiceAddr),
+
+    /// Remote HTTP signer, which receives JSON object with hex-encoded
+    /// `message` to sign and replies with a JSON object containing
+    /// hex-encoded BIP-340 `signature` and x-only `issuer` public key
+    #[display("{0}")]
+    Http(String),
+}
+
+impl IssuerSigner {
+    /// Signs the checkpoint, checking the signature returned by the signer
+    pub fn sign(&self, checkpoint: Checkpoint) -> Result<SignedCheckpoint, SignerError> {
+        // We connect each time since ZMQ REQ socket can't be re-used after the timeout
+        let signed = match self {
+            IssuerSigner::Zmq(endpoint) => {
+                SignerClient::with(endpoint)?.sign_checkpoint(checkpoint.clone())?
+            }
+            IssuerSigner::Http(url) => http_sign(url, checkpoint.clone())?,
+        };
+        if signed.checkpoint != checkpoint || !signed.verify(SECP256K1) {
+            return Err(SignerError::InvalidSignature(checkpoint.contract_id));
+        }
+        Ok(signed)
+    }
+}
+
+fn http_sign(url: &str, checkpoint: Checkpoint) -> Result<SignedCheckpoint, SignerError> {
+    let payload = json!({
+        "contractId": checkpoint.contract_id.to_string(),
+        "height": checkpoint.height,
+        "message": checkpoint.commitment().to_string(),
+    })
+    .to_string();
+    let timeout = Duration::from_millis(SIGNER_TIMEOUT as u64);
+    let body =
+        http::post(url, "application/json", &[], payload.as_bytes(), timeout, MAX_REPLY_SIZE)?;
+
+    let reply: Value = serde_json::from_slice(&body).map_err(|_| SignerError::UnexpectedReply)?;
+    let field = |name: &str| reply[name].as_str().ok_or(SignerError::UnexpectedReply);
+    let issuer =
+        XOnlyPublicKey::from_str(field("issuer")?).map_err(|_| SignerError::UnexpectedReply)?;
+    let signature = schnorr::Signature::from_str(field("signature")?)
+        .map_err(|_| SignerError::UnexpectedReply)?;
+    Ok(SignedCheckpoint {
+        checkpoint,
+        issuer,
+        signature,
+    })
+}
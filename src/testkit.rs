@@ -0,0 +1,637 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Scriptable chain backend and in-process store for testing the daemon logic
+//! without electrum servers and the store service.
+//!
+//! [`MockChain`] starts with the regtest genesis block and mines blocks with
+//! the given transactions, answering the requests with the transactions, their
+//! heights and merkle proofs like an electrum server would. Responses may be
+//! overridden, and the requests may be made failing or delayed. The daemons
+//! use the mock once it is passed to [`crate::bucketd::run_with_backend`] or
+//! [`crate::bucketd::Runtime::with_backend`].
+//!
+//! [`MemStore`] keeps the store tables in memory and is used by the bucket
+//! runtime constructed with [`crate::bucketd::Runtime::with_backends`].
+//!
+//! [`regtest_config`] and [`fungible_schema`] provide the configuration of an
+//! offline regtest node and a minimal schema for the contracts used in the
+//! tests; [`regtest_runtime`] and [`fungible_history`] construct the bucket
+//! runtime over the mock backends and the synthetic contracts of the schema.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use std::{env, io, thread};
+
+use amplify::{Slice32, Wrapper};
+use bitcoin::blockdata::constants::genesis_block;
+use bitcoin::consensus::serialize;
+use bitcoin::hashes::{sha256d, Hash, HashEngine};
+use bitcoin::{BlockHeader, Network, Script, Transaction, TxMerkleNode, Txid};
+use electrum_client::{
+    Error, GetHeadersRes, GetHistoryRes, GetMerkleRes, HeaderNotification, ListUnspentRes,
+};
+use internet2::addr::ServiceAddr;
+use lnpbp::chain::Chain;
+use rgb::schema::{
+    DiscreteFiniteFieldFormat, GenesisSchema, Occurrences, OwnedRightType, StateSchema,
+    TransitionSchema, TransitionType,
+};
+use rgb::Schema;
+#[cfg(feature = "test-utils")]
+use rgb_rpc::IssueSpec;
+use rgb_rpc::{HeaderCheckpoint, RetentionPeriod};
+use storm::{Chunk, ChunkId};
+use strict_encoding::{StrictDecode, StrictEncode};
+
+use crate::bucketd::Runtime;
+#[cfg(feature = "test-utils")]
+use crate::bucketd::{HistoryShape, SyntheticHistory};
+use crate::electrum::ChainBackend;
+use crate::{Config, Store, StoreError};
+
+/// Target of the mined regtest blocks, in compact form
+const REGTEST_BITS: u32 = 0x207fffff;
+
+/// Maximal number of headers returned by a single request, as with electrum
+/// servers
+const MAX_HEADERS: usize = 2016;
+
+/// Chain data request served by the backend
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Display)]
+#[display(Debug)]
+pub enum MockRequest {
+    Ping,
+    BlockHeader,
+    BlockHeaders,
+    BlockHeadersSubscribe,
+    TransactionGet,
+    TransactionGetMerkle,
+    TransactionBroadcast,
+    ScriptGetHistory,
+    BatchScriptGetHistory,
+    BatchScriptListUnspent,
+}
+
+/// Programmable failure of a request
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display)]
+pub enum MockFailure {
+    /// The backend does not respond, so the request is retried with other
+    /// backends
+    #[display("unreachable")]
+    Unreachable,
+
+    /// The backend responds with the error, which is returned to the daemon
+    #[display("{0}")]
+    Protocol(String),
+}
+
+impl MockFailure {
+    fn error(&self, request: MockRequest) -> Error {
+        match self {
+            MockFailure::Unreachable => Error::IOError(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("mock chain is unreachable on {} request", request),
+            )),
+            MockFailure::Protocol(msg) => Error::Protocol(serde_json::Value::String(msg.clone())),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MockTx {
+    tx: Transaction,
+    /// Height of the mining block and the position in it, if mined
+    mined: Option<(u32, usize)>,
+}
+
+#[derive(Debug)]
+struct MockState {
+    /// Headers of the blocks, starting from the genesis one
+    headers: Vec<BlockHeader>,
+    /// Ids of the transactions mined by each block
+    blocks: Vec<Vec<Txid>>,
+    txes: BTreeMap<Txid, MockTx>,
+    merkle: BTreeMap<Txid, GetMerkleRes>,
+    histories: BTreeMap<Script, Vec<GetHistoryRes>>,
+    unspent: BTreeMap<Script, Vec<ListUnspentRes>>,
+    broadcasted: Vec<Transaction>,
+    failures: BTreeMap<MockRequest, VecDeque<MockFailure>>,
+    delays: BTreeMap<MockRequest, Duration>,
+}
+
+/// Chain backend answering the requests with the scripted chain state.
+#[derive(Debug)]
+pub struct MockChain {
+    state: Mutex<MockState>,
+}
+
+impl Default for MockChain {
+    fn default() -> Self { MockChain::new() }
+}
+
+impl MockChain {
+    /// Constructs chain containing only the regtest genesis block
+    pub fn new() -> MockChain {
+        MockChain {
+            state: Mutex::new(MockState {
+                headers: vec![genesis_block(Network::Regtest).header],
+                blocks: vec![vec![]],
+                txes: empty!(),
+                merkle: empty!(),
+                histories: empty!(),
+                unspent: empty!(),
+                broadcasted: empty!(),
+                failures: empty!(),
+                delays: empty!(),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<MockState> {
+        self.state.lock().expect("mock chain state is poisoned")
+    }
+
+    /// Height of the chain tip
+    pub fn height(&self) -> u32 { self.state().headers.len() as u32 - 1 }
+
+    /// Header of the block at the given height
+    pub fn header(&self, height: u32) -> Option<BlockHeader> {
+        self.state().headers.get(height as usize).copied()
+    }
+
+    /// Adds unmined transaction
+    pub fn add_mempool(&self, tx: Transaction) { self.state().add_tx(tx, None); }
+
+    /// Mines a new block with the transactions, returning its height
+    pub fn mine(&self, txes: Vec<Transaction>) -> u32 {
+        let mut state = self.state();
+        let height = state.headers.len() as u32;
+        let txids = txes.iter().map(Transaction::txid).collect::<Vec<_>>();
+        let tip = *state.headers.last().expect("genesis block is always present");
+        let mut header = BlockHeader {
+            version: 0x20000000,
+            prev_blockhash: tip.block_hash(),
+            merkle_root: merkle_branch(&txids, 0).0,
+            time: tip.time + 600,
+            bits: REGTEST_BITS,
+            nonce: 0,
+        };
+        while header.validate_pow(&header.target()).is_err() {
+            header.nonce += 1;
+        }
+        state.headers.push(header);
+        for (pos, tx) in txes.into_iter().enumerate() {
+            state.merkle.insert(tx.txid(), GetMerkleRes {
+                block_height: height as usize,
+                pos,
+                merkle: merkle_branch(&txids, pos).1,
+            });
+            state.add_tx(tx, Some((height, pos)));
+        }
+        state.blocks.push(txids);
+        height
+    }
+
+    /// Removes the blocks above the given height, returning their
+    /// transactions into the mempool
+    pub fn rewind(&self, height: u32) {
+        let mut state = self.state();
+        let len = height as usize + 1;
+        state.headers.truncate(len);
+        let from = len.min(state.blocks.len());
+        let txids = state.blocks.drain(from..).flatten().collect::<Vec<_>>();
+        for txid in txids {
+            state.merkle.remove(&txid);
+            if let Some(tx) = state.txes.remove(&txid) {
+                state.add_tx(tx.tx, None);
+            }
+        }
+    }
+
+    /// Overrides merkle proof returned for the transaction
+    pub fn set_merkle(&self, txid: Txid, merkle: GetMerkleRes) {
+        self.state().merkle.insert(txid, merkle);
+    }
+
+    /// Overrides history returned for the script
+    pub fn set_history(&self, script: Script, history: Vec<GetHistoryRes>) {
+        self.state().histories.insert(script, history);
+    }
+
+    /// Overrides unspent outputs returned for the script
+    pub fn set_unspent(&self, script: Script, unspent: Vec<ListUnspentRes>) {
+        self.state().unspent.insert(script, unspent);
+    }
+
+    /// Makes the given number of the next requests fail
+    pub fn fail(&self, request: MockRequest, failure: MockFailure, times: usize) {
+        self.state()
+            .failures
+            .entry(request)
+            .or_default()
+            .extend(std::iter::repeat(failure).take(times));
+    }
+
+    /// Delays all the subsequent requests; zero delay removes it
+    pub fn delay(&self, request: MockRequest, delay: Duration) {
+        let mut state = self.state();
+        if delay.is_zero() {
+            state.delays.remove(&request);
+        } else {
+            state.delays.insert(request, delay);
+        }
+    }
+
+    /// Transactions broadcasted by the daemons, in order
+    pub fn broadcasted(&self) -> Vec<Transaction> { self.state().broadcasted.clone() }
+
+    /// Locks the state for the request, applying the scripted delay and
+    /// failure.
+    fn request(&self, request: MockRequest) -> Result<MutexGuard<MockState>, Error> {
+        let delay = self.state().delays.get(&request).copied();
+        if let Some(delay) = delay {
+            thread::sleep(delay);
+        }
+        let mut state = self.state();
+        let failure = state.failures.get_mut(&request).and_then(VecDeque::pop_front);
+        match failure {
+            Some(failure) => Err(failure.error(request)),
+            None => Ok(state),
+        }
+    }
+}
+
+impl MockState {
+    fn add_tx(&mut self, tx: Transaction, mined: Option<(u32, usize)>) {
+        let txid = tx.txid();
+        let height = mined.map(|(height, _)| height).unwrap_or_default();
+        for input in &tx.input {
+            let prevout = input.previous_output;
+            let script = match self.txes.get(&prevout.txid) {
+                Some(prev) => match prev.tx.output.get(prevout.vout as usize) {
+                    Some(out) => out.script_pubkey.clone(),
+                    None => continue,
+                },
+                None => continue,
+            };
+            if let Some(unspent) = self.unspent.get_mut(&script) {
+                unspent.retain(|utxo| {
+                    utxo.tx_hash != prevout.txid || utxo.tx_pos != prevout.vout as usize
+                });
+            }
+            self.push_history(script, txid, height);
+        }
+        for (vout, out) in tx.output.iter().enumerate() {
+            let unspent = self.unspent.entry(out.script_pubkey.clone()).or_default();
+            unspent.retain(|utxo| utxo.tx_hash != txid || utxo.tx_pos != vout);
+            unspent.push(ListUnspentRes {
+                height: height as usize,
+                tx_pos: vout,
+                value: out.value,
+                tx_hash: txid,
+            });
+            self.push_history(out.script_pubkey.clone(), txid, height);
+        }
+        self.txes.insert(txid, MockTx { tx, mined });
+    }
+
+    fn push_history(&mut self, script: Script, txid: Txid, height: u32) {
+        let history = self.histories.entry(script).or_default();
+        history.retain(|item| item.tx_hash != txid);
+        history.push(GetHistoryRes {
+            height: height as i32,
+            tx_hash: txid,
+            fee: None,
+        });
+    }
+}
+
+impl ChainBackend for MockChain {
+    fn ping(&self) -> Result<(), Error> { self.request(MockRequest::Ping).map(|_| ()) }
+
+    fn block_header(&self, height: usize) -> Result<BlockHeader, Error> {
+        let state = self.request(MockRequest::BlockHeader)?;
+        state.headers.get(height).copied().ok_or_else(|| missing("block", height))
+    }
+
+    fn block_headers(&self, start_height: usize, count: usize) -> Result<GetHeadersRes, Error> {
+        let state = self.request(MockRequest::BlockHeaders)?;
+        let headers = state
+            .headers
+            .iter()
+            .skip(start_height)
+            .take(count.min(MAX_HEADERS))
+            .copied()
+            .collect::<Vec<_>>();
+        Ok(GetHeadersRes {
+            max: MAX_HEADERS,
+            count: headers.len(),
+            raw_headers: headers.iter().flat_map(serialize).collect(),
+            headers,
+        })
+    }
+
+    fn block_headers_subscribe(&self) -> Result<HeaderNotification, Error> {
+        let state = self.request(MockRequest::BlockHeadersSubscribe)?;
+        Ok(HeaderNotification {
+            height: state.headers.len() - 1,
+            header: *state.headers.last().expect("genesis block is always present"),
+        })
+    }
+
+    fn transaction_get(&self, txid: &Txid) -> Result<Transaction, Error> {
+        let state = self.request(MockRequest::TransactionGet)?;
+        state.txes.get(txid).map(|mock| mock.tx.clone()).ok_or_else(|| missing("transaction", txid))
+    }
+
+    fn transaction_get_merkle(&self, txid: &Txid, height: usize) -> Result<GetMerkleRes, Error> {
+        let state = self.request(MockRequest::TransactionGetMerkle)?;
+        match state.txes.get(txid).and_then(|mock| mock.mined) {
+            Some((mined, _)) if mined as usize != height => {
+                Err(missing("transaction in the block", height))
+            }
+            _ => state.merkle.get(txid).cloned().ok_or_else(|| missing("mined transaction", txid)),
+        }
+    }
+
+    fn transaction_broadcast(&self, tx: &Transaction) -> Result<Txid, Error> {
+        let mut state = self.request(MockRequest::TransactionBroadcast)?;
+        state.broadcasted.push(tx.clone());
+        if !state.txes.contains_key(&tx.txid()) {
+            state.add_tx(tx.clone(), None);
+        }
+        Ok(tx.txid())
+    }
+
+    fn script_get_history(&self, script: &Script) -> Result<Vec<GetHistoryRes>, Error> {
+        let state = self.request(MockRequest::ScriptGetHistory)?;
+        Ok(state.histories.get(script).cloned().unwrap_or_default())
+    }
+
+    fn batch_script_get_history(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<GetHistoryRes>>, Error> {
+        let state = self.request(MockRequest::BatchScriptGetHistory)?;
+        Ok(scripts
+            .iter()
+            .map(|script| state.histories.get(script).cloned().unwrap_or_default())
+            .collect())
+    }
+
+    fn batch_script_list_unspent(
+        &self,
+        scripts: &[Script],
+    ) -> Result<Vec<Vec<ListUnspentRes>>, Error> {
+        let state = self.request(MockRequest::BatchScriptListUnspent)?;
+        Ok(scripts
+            .iter()
+            .map(|script| state.unspent.get(script).cloned().unwrap_or_default())
+            .collect())
+    }
+}
+
+/// Store keeping the tables in memory. Clones share the same tables, like
+/// connections to the same store service.
+#[derive(Clone, Debug, Default)]
+pub struct MemStore {
+    tables: Arc<Mutex<BTreeMap<String, BTreeMap<Slice32, Chunk>>>>,
+}
+
+impl MemStore {
+    /// Constructs store with no data
+    pub fn new() -> MemStore { default!() }
+
+    fn tables(&self) -> MutexGuard<BTreeMap<String, BTreeMap<Slice32, Chunk>>> {
+        self.tables.lock().expect("memory store is poisoned")
+    }
+
+    /// Number of objects kept in the table, including the removed ones, which
+    /// are overwritten with empty chunks
+    pub fn count(&self, table: &str) -> usize {
+        self.tables().get(table).map(BTreeMap::len).unwrap_or_default()
+    }
+}
+
+impl Store for MemStore {
+    fn store(&mut self, table: &str, key: Slice32, chunk: &Chunk) -> Result<ChunkId, StoreError> {
+        self.tables().entry(table.to_owned()).or_default().insert(key, chunk.clone());
+        Ok(key)
+    }
+
+    fn retrieve_chunk(&mut self, table: &str, key: Slice32) -> Result<Option<Chunk>, StoreError> {
+        Ok(self.tables().get(table).and_then(|table| table.get(&key)).cloned())
+    }
+
+    fn ids(&mut self, table: &str) -> Result<BTreeSet<ChunkId>, StoreError> {
+        Ok(self
+            .tables()
+            .get(table)
+            .map(|table| table.keys().copied().collect())
+            .unwrap_or_default())
+    }
+
+    fn filter_unknown(
+        &mut self,
+        table: &str,
+        ids: BTreeSet<ChunkId>,
+    ) -> Result<BTreeSet<ChunkId>, StoreError> {
+        let tables = self.tables();
+        Ok(ids
+            .into_iter()
+            .filter(|id| !tables.get(table).map(|table| table.contains_key(id)).unwrap_or_default())
+            .collect())
+    }
+
+    fn insert_into_set(
+        &mut self,
+        table: &str,
+        key: Slice32,
+        item: Slice32,
+    ) -> Result<(), StoreError> {
+        let mut tables = self.tables();
+        let table = tables.entry(table.to_owned()).or_default();
+        // Sets removed with empty chunks are started anew
+        let mut set = table
+            .get(&key)
+            .filter(|chunk| !chunk.as_slice().is_empty())
+            .map(|chunk| {
+                BTreeSet::<[u8; 32]>::strict_deserialize(chunk.as_slice())
+                    .expect("memory store keeps a set under the key")
+            })
+            .unwrap_or_default();
+        set.insert(item.into_inner());
+        let data = set.strict_serialize().expect("memory encoders do not error");
+        table.insert(key, Chunk::try_from(data).expect("set does not exceed chunk size"));
+        Ok(())
+    }
+}
+
+/// Owned right type of the assets defined by [`fungible_schema`]
+pub const ASSETS_RIGHT: OwnedRightType = 0;
+
+/// Transition type of the transfers defined by [`fungible_schema`]
+pub const TRANSFER_TRANSITION: TransitionType = 0x100;
+
+/// Minimal schema of the fungible assets, which genesis allocates the assets
+/// and which transition transfers them. The schema satisfies the requirements
+/// of the synthetic contract histories.
+pub fn fungible_schema() -> Schema {
+    Schema {
+        owned_right_types: bmap! {
+            ASSETS_RIGHT => StateSchema::DiscreteFiniteField(DiscreteFiniteFieldFormat::Unsigned64bit)
+        },
+        genesis: GenesisSchema {
+            owned_rights: bmap! { ASSETS_RIGHT => Occurrences::OnceOrMore },
+            ..default!()
+        },
+        transitions: bmap! {
+            TRANSFER_TRANSITION => TransitionSchema {
+                closes: bmap! { ASSETS_RIGHT => Occurrences::OnceOrMore },
+                owned_rights: bmap! { ASSETS_RIGHT => Occurrences::NoneOrMore },
+                ..default!()
+            }
+        },
+        ..default!()
+    }
+}
+
+/// Configuration of an offline regtest node keeping its data in the given
+/// directory, which trusts the regtest genesis header for the verification of
+/// the transaction proofs. Bus endpoints are IPC sockets inside the data
+/// directory, so the runtimes constructed for different directories do not
+/// interfere.
+pub fn regtest_config(data_dir: PathBuf) -> Config {
+    let socket = |name: &str| ServiceAddr::Ipc(data_dir.join(name).display().to_string());
+    let genesis_hash = genesis_block(Network::Regtest).block_hash();
+    Config {
+        rpc_endpoint: socket("rpc"),
+        rpc_listen: empty!(),
+        rpc_socket_mode: None,
+        ctl_endpoint: socket("ctl"),
+        storm_endpoint: socket("storm"),
+        store_endpoint: socket("store"),
+        data_dir,
+        electrum_urls: empty!(),
+        offline: true,
+        header_checkpoint: Some(HeaderCheckpoint {
+            height: 0,
+            block_hash: genesis_hash,
+        }),
+        chain_cache_size: 10000,
+        state_cache_size: 1000,
+        store_concurrency: 1,
+        compress_relay: false,
+        consignment_key: None,
+        chain: Chain::Regtest(genesis_hash),
+        signet_challenge: None,
+        signer_endpoint: None,
+        issuer_signer: None,
+        timestamp_calendar: None,
+        events_endpoint: None,
+        events_json: false,
+        replica_of: None,
+        replica_events: None,
+        faucet_contract: None,
+        faucet_listen: None,
+        faucet_descriptor: None,
+        faucet_amount: 0,
+        faucet_fee: 0,
+        faucet_interval: 0,
+        relay: false,
+        relay_ttl: 0,
+        relay_max_size: 0,
+        relay_quota: 0,
+        relay_peers: empty!(),
+        trusted_issuers: empty!(),
+        admin_keys: empty!(),
+        order_keys: empty!(),
+        accept_contracts: empty!(),
+        accept_schemata: empty!(),
+        reject_contracts: empty!(),
+        hooks: empty!(),
+        hook_secret: None,
+        hook_retries: 0,
+        container_retention: 14,
+        retention: RetentionPeriod::Never,
+        retention_overrides: empty!(),
+        sql_export: None,
+        validation_plugins: empty!(),
+        plugin_fuel: 1_000_000_000,
+        max_script_runs: 100_000,
+        max_script_size: 1_048_576,
+        decoy_change: 0,
+        entropy_source: None,
+        bdk_wallets: empty!(),
+        interactive_workers: 1,
+        batch_workers: 1,
+        threaded: true,
+        daemon_id: None,
+        settings: empty!(),
+    }
+}
+
+/// Runtime of an offline regtest node with the given backends, keeping its
+/// data in a temporary directory named after the test.
+pub fn regtest_runtime(name: &str, chain: Arc<MockChain>, store: MemStore) -> Runtime {
+    let config = regtest_config(env::temp_dir().join(format!("rgb-node-{}", name)));
+    Runtime::with_backends(config, chain, store).expect("runtime with the mock backends")
+}
+
+/// Synthetic history of the [`fungible_schema`] contract with no genesis
+/// metadata.
+#[cfg(feature = "test-utils")]
+pub fn fungible_history(shape: HistoryShape) -> SyntheticHistory {
+    let schema = fungible_schema();
+    let spec = IssueSpec {
+        schema: schema.schema_id(),
+        metadata: empty!(),
+        assignments: empty!(),
+    };
+    SyntheticHistory::generate(schema, spec, shape)
+        .expect("synthetic history of the fungible schema")
+}
+
+fn missing(what: &str, id: impl Display) -> Error {
+    Error::Protocol(serde_json::Value::String(format!("unknown {} {}", what, id)))
+}
+
+/// Computes merkle root of the block mining the transactions and the merkle
+/// path for the transaction at the given position.
+fn merkle_branch(txids: &[Txid], pos: usize) -> (TxMerkleNode, Vec<[u8; 32]>) {
+    if txids.is_empty() {
+        return (TxMerkleNode::all_zeros(), vec![]);
+    }
+    let mut level =
+        txids.iter().map(|txid| sha256d::Hash::from_inner(txid.into_inner())).collect::<Vec<_>>();
+    let mut index = pos;
+    let mut branch = vec![];
+    while level.len() > 1 {
+        if level.len() % 2 == 1 {
+            level.push(*level.last().expect("level is not empty"));
+        }
+        branch.push(level[index ^ 1].into_inner());
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let mut engine = sha256d::Hash::engine();
+                engine.input(&pair[0][..]);
+                engine.input(&pair[1][..]);
+                sha256d::Hash::from_engine(engine)
+            })
+            .collect();
+        index /= 2;
+    }
+    (TxMerkleNode::from_inner(level[0].into_inner()), branch)
+}
@@ -0,0 +1,71 @@
+// RGB node providing smart contracts functionality for Bitcoin & Lightning.
+//
+// Written in 2022 by
+//     Dr. Maxim Orlovsky <orlovsky@lnp-bp.org>
+//
+// Copyright (C) 2022 by LNP/BP Standards Association, Switzerland.
+//
+// You should have received a copy of the MIT License along with this software.
+// If not, see <https://opensource.org/licenses/MIT>.
+
+//! Anchoring of the stash commitments into Bitcoin by submitting them to an
+//! OpenTimestamps calendar server.
+
+use std::time::Duration;
+
+use bitcoin::hashes::sha256;
+use rgb_rpc::{FailureCode, TimestampProof};
+
+use crate::http::{self, HttpError};
+
+/// Timeout for talking to the calendar server
+const CALENDAR_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum size of the calendar reply; pending attestations are much smaller
+const MAX_REPLY_SIZE: u64 = 64 * 1024;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, Error, From)]
+#[display(doc_comments)]
+pub enum TimestampError {
+    /// timestamping calendar is not configured for the node.
+    NotConfigured,
+
+    /// request to the timestamping calendar has failed. Details: {0}
+    #[from]
+    Http(HttpError),
+
+    /// timestamping calendar has sent an invalid reply.
+    InvalidReply,
+}
+
+impl From<&TimestampError> for FailureCode {
+    fn from(_: &TimestampError) -> Self { FailureCode::Timestamp }
+}
+
+/// Submits digest to the calendar, returning pending attestation of the
+/// digest. Calendar adds the digest to its next Bitcoin transaction; the
+/// attestation can be upgraded to a complete proof once the transaction is
+/// mined.
+pub(crate) fn submit(
+    calendar: &str,
+    digest: sha256::Hash,
+) -> Result<TimestampProof, TimestampError> {
+    let url = format!("{}/digest", calendar.trim_end_matches('/'));
+    let timestamp = http::post(
+        &url,
+        "application/octet-stream",
+        &[("Accept", "application/vnd.opentimestamps.v1")],
+        &digest[..],
+        CALENDAR_TIMEOUT,
+        MAX_REPLY_SIZE,
+    )?;
+    if timestamp.is_empty() {
+        return Err(TimestampError::InvalidReply);
+    }
+    debug!("Digest {} is submitted to calendar {}", digest, calendar);
+    Ok(TimestampProof {
+        calendar: calendar.to_owned(),
+        digest,
+        timestamp,
+    })
+}